@@ -0,0 +1,374 @@
+use std::sync::Arc;
+
+use tokio::sync::Semaphore;
+
+use crate::client::AxiomSync;
+use crate::error::{AxiomError, Result};
+use crate::models::{
+    AddResourceRequest, AddResourceResult, CommitResult, Entry, FindResult, MarkdownDocument,
+    MarkdownSaveResult, MetadataFilter, SearchRequest,
+};
+use crate::retrieval::CancellationToken;
+
+/// Async facade over `AxiomSync` that runs at most `max_concurrency` blocking calls at once.
+#[derive(Debug, Clone)]
+pub struct AxiomSyncAsync {
+    app: AxiomSync,
+    permits: Arc<Semaphore>,
+    max_concurrency: usize,
+}
+
+impl AxiomSyncAsync {
+    /// A `max_concurrency` of 0 is treated as 1.
+    #[must_use]
+    pub fn new(app: AxiomSync, max_concurrency: usize) -> Self {
+        let max_concurrency = max_concurrency.max(1);
+        Self {
+            app,
+            permits: Arc::new(Semaphore::new(max_concurrency)),
+            max_concurrency,
+        }
+    }
+
+    #[must_use]
+    pub const fn blocking(&self) -> &AxiomSync {
+        &self.app
+    }
+
+    #[must_use]
+    pub const fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    /// Number of blocking calls that can start right now.
+    #[must_use]
+    pub fn available_permits(&self) -> usize {
+        self.permits.available_permits()
+    }
+
+    pub async fn search(&self, request: SearchRequest) -> Result<FindResult> {
+        self.search_cancellable(request, CancellationToken::new())
+            .await
+    }
+
+    /// Stops retrieval at the next checkpoint when `cancel` fires or this future is dropped.
+    pub async fn search_cancellable(
+        &self,
+        request: SearchRequest,
+        cancel: CancellationToken,
+    ) -> Result<FindResult> {
+        let mut guard = CancelOnDrop(Some(cancel.clone()));
+        let output = self
+            .run(move |app| {
+                if cancel.is_cancelled() {
+                    return Err(AxiomError::Cancelled(
+                        "search cancelled before start".to_string(),
+                    ));
+                }
+                app.search_with_request_cancellable(request, &cancel)
+            })
+            .await;
+        guard.disarm();
+        output
+    }
+
+    pub async fn find(
+        &self,
+        query: String,
+        target_uri: Option<String>,
+        limit: Option<usize>,
+        score_threshold: Option<f32>,
+        filter: Option<MetadataFilter>,
+    ) -> Result<FindResult> {
+        self.run(move |app| {
+            app.find(
+                &query,
+                target_uri.as_deref(),
+                limit,
+                score_threshold,
+                filter,
+            )
+        })
+        .await
+    }
+
+    pub async fn add_resource(&self, request: AddResourceRequest) -> Result<AddResourceResult> {
+        self.run(move |app| app.add_resource_with_ingest_options(request))
+            .await
+    }
+
+    pub async fn ls(&self, uri: String, recursive: bool) -> Result<Vec<Entry>> {
+        self.run(move |app| app.ls(&uri, recursive, false)).await
+    }
+
+    pub async fn load_markdown(&self, uri: String) -> Result<MarkdownDocument> {
+        self.run(move |app| app.load_markdown(&uri)).await
+    }
+
+    pub async fn save_markdown(
+        &self,
+        uri: String,
+        content: String,
+        expected_etag: Option<String>,
+    ) -> Result<MarkdownSaveResult> {
+        self.run(move |app| app.save_markdown(&uri, &content, expected_etag.as_deref()))
+            .await
+    }
+
+    /// Loads the session and runs `Session::commit`.
+    pub async fn commit(&self, session_id: String) -> Result<CommitResult> {
+        self.run(move |app| {
+            let session = app.session(Some(&session_id));
+            session.load()?;
+            session.commit()
+        })
+        .await
+    }
+
+    async fn run<T, F>(&self, op: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&AxiomSync) -> Result<T> + Send + 'static,
+    {
+        let permit = Arc::clone(&self.permits)
+            .acquire_owned()
+            .await
+            .map_err(|err| AxiomError::Internal(format!("async facade closed: {err}")))?;
+        let app = self.app.clone();
+        tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            op(&app)
+        })
+        .await
+        .map_err(|err| AxiomError::Internal(format!("blocking task failed: {err}")))?
+    }
+}
+
+/// Cancels the token if dropped before `disarm`.
+struct CancelOnDrop(Option<CancellationToken>);
+
+impl CancelOnDrop {
+    fn disarm(&mut self) {
+        self.0.take();
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some(token) = self.0.take() {
+            token.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::{Context, Poll, Waker};
+    use std::time::Duration;
+
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::models::{ContextHit, SearchOptions, SearchScopeSet};
+    use crate::retrieval::Reranker;
+
+    /// Slow reranker that records peak concurrent reranks.
+    #[derive(Clone, Default)]
+    struct InFlightReranker {
+        current: Arc<AtomicUsize>,
+        peak: Arc<AtomicUsize>,
+        delay_ms: u64,
+    }
+
+    impl Reranker for InFlightReranker {
+        fn name(&self) -> &str {
+            "in-flight"
+        }
+
+        fn rerank(&self, _query: &SearchOptions, hits: Vec<ContextHit>) -> Vec<ContextHit> {
+            let now = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(self.delay_ms));
+            self.current.fetch_sub(1, Ordering::SeqCst);
+            hits
+        }
+    }
+
+    fn search_request(query: &str) -> SearchRequest {
+        SearchRequest {
+            query: query.to_string(),
+            target_uri: Some("axiom://resources/async".to_string()),
+            target_uris: Vec::new(),
+            session: None,
+            limit: Some(5),
+            score_threshold: None,
+            min_match_tokens: None,
+            filter: None,
+            budget: None,
+            runtime_hints: Vec::new(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
+        }
+    }
+
+    fn seeded_app(root: &std::path::Path, reranker: InFlightReranker) -> AxiomSync {
+        let app = AxiomSync::new(root)
+            .expect("app new")
+            .with_reranker(Box::new(reranker));
+        app.initialize().expect("init");
+        let src = root.join("async_src");
+        std::fs::create_dir_all(&src).expect("mkdir");
+        std::fs::write(
+            src.join("guide.md"),
+            "# Guide\n\nasync_facade_token rotation",
+        )
+        .expect("write");
+        // Rerank only runs with two or more hits.
+        std::fs::write(src.join("notes.md"), "async_facade_token notes").expect("write");
+        app.add_resource(
+            src.to_str().expect("src"),
+            Some("axiom://resources/async"),
+            None,
+            None,
+            true,
+            None,
+        )
+        .expect("add");
+        app
+    }
+
+    #[test]
+    fn concurrent_searches_stay_within_permit_limit_and_blocking_pool() {
+        let temp = tempdir().expect("tempdir");
+        let reranker = InFlightReranker {
+            delay_ms: 5,
+            ..InFlightReranker::default()
+        };
+        let facade = AxiomSyncAsync::new(seeded_app(temp.path(), reranker.clone()), 4);
+        // One spare blocking thread for work outside the facade.
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .max_blocking_threads(5)
+            .build()
+            .expect("runtime");
+
+        let hit_counts = runtime.block_on(async {
+            let handles = (0..32)
+                .map(|i| {
+                    let facade = facade.clone();
+                    // Distinct queries bypass the query cache.
+                    tokio::spawn(async move {
+                        facade
+                            .search(search_request(&format!("async_facade_token rotation{i}")))
+                            .await
+                    })
+                })
+                .collect::<Vec<_>>();
+            let probe = tokio::task::spawn_blocking(|| "probe");
+            let mut counts = Vec::new();
+            for handle in handles {
+                let result = handle.await.expect("join").expect("search");
+                counts.push(result.query_results.len());
+            }
+            assert_eq!(probe.await.expect("probe"), "probe");
+            counts
+        });
+
+        assert_eq!(hit_counts.len(), 32);
+        assert!(hit_counts.iter().all(|count| *count > 0), "{hit_counts:?}");
+        let peak = reranker.peak.load(Ordering::SeqCst);
+        assert!((1..=4).contains(&peak), "peak in-flight searches: {peak}");
+        assert_eq!(facade.available_permits(), 4);
+    }
+
+    #[test]
+    fn dropping_search_future_cancels_token_and_pre_cancelled_search_does_not_run() {
+        let temp = tempdir().expect("tempdir");
+        let reranker = InFlightReranker {
+            delay_ms: 200,
+            ..InFlightReranker::default()
+        };
+        let facade = AxiomSyncAsync::new(seeded_app(temp.path(), reranker.clone()), 1);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("runtime");
+
+        let cancelled = CancellationToken::new();
+        cancelled.cancel();
+        let err = runtime
+            .block_on(facade.search_cancellable(search_request("async_facade_token"), cancelled))
+            .expect_err("pre-cancelled search");
+        assert!(matches!(err, AxiomError::Cancelled(_)));
+        assert_eq!(reranker.peak.load(Ordering::SeqCst), 0);
+
+        let completed = CancellationToken::new();
+        runtime
+            .block_on(facade.search_cancellable(
+                search_request("async_facade_token rotation"),
+                completed.clone(),
+            ))
+            .expect("completed search");
+        assert!(!completed.is_cancelled());
+
+        let token = CancellationToken::new();
+        let _entered = runtime.enter();
+        let mut future = Box::pin(
+            facade.search_cancellable(search_request("async_facade_token"), token.clone()),
+        );
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(matches!(future.as_mut().poll(&mut cx), Poll::Pending));
+        drop(future);
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn markdown_round_trip_and_commit_run_through_facade() {
+        let temp = tempdir().expect("tempdir");
+        let facade = AxiomSyncAsync::new(seeded_app(temp.path(), InFlightReranker::default()), 2);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("runtime");
+        runtime.block_on(async {
+            let uri = "axiom://resources/async/guide.md".to_string();
+            let loaded = facade.load_markdown(uri.clone()).await.expect("load");
+            facade
+                .save_markdown(
+                    uri.clone(),
+                    "# Guide\n\nasync_saved_token".to_string(),
+                    Some(loaded.etag),
+                )
+                .await
+                .expect("save");
+            let found = facade
+                .find(
+                    "async_saved_token".to_string(),
+                    Some("axiom://resources/async".to_string()),
+                    Some(5),
+                    None,
+                    None,
+                )
+                .await
+                .expect("find");
+            assert!(found.query_results.iter().any(|hit| hit.uri == uri));
+            let entries = facade
+                .ls("axiom://resources/async".to_string(), false)
+                .await
+                .expect("ls");
+            assert!(entries.iter().any(|entry| entry.uri == uri));
+
+            let session = facade.blocking().session(Some("s-async"));
+            session.load().expect("session load");
+            session
+                .add_message("user", "async commit note")
+                .expect("add");
+            let committed = facade.commit("s-async".to_string()).await.expect("commit");
+            assert_eq!(committed.session_id, "s-async");
+        });
+    }
+}
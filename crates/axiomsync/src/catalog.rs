@@ -53,16 +53,16 @@ pub fn eval_query_set_uri(run_id: &str) -> Result<AxiomUri> {
         .join(&format!("{run_id}.json"))
 }
 
+pub fn eval_reports_uri() -> Result<AxiomUri> {
+    eval_base_uri()?.join("reports")
+}
+
 pub fn eval_report_json_uri(run_id: &str) -> Result<AxiomUri> {
-    eval_base_uri()?
-        .join("reports")?
-        .join(&format!("{run_id}.json"))
+    eval_reports_uri()?.join(&format!("{run_id}.json"))
 }
 
 pub fn eval_report_markdown_uri(run_id: &str) -> Result<AxiomUri> {
-    eval_base_uri()?
-        .join("reports")?
-        .join(&format!("{run_id}.md"))
+    eval_reports_uri()?.join(&format!("{run_id}.md"))
 }
 
 pub fn benchmark_base_uri() -> Result<AxiomUri> {
@@ -81,16 +81,16 @@ pub fn benchmark_case_set_uri(run_id: &str) -> Result<AxiomUri> {
         .join(&format!("{run_id}.json"))
 }
 
+pub fn benchmark_reports_uri() -> Result<AxiomUri> {
+    benchmark_base_uri()?.join("reports")
+}
+
 pub fn benchmark_report_json_uri(run_id: &str) -> Result<AxiomUri> {
-    benchmark_base_uri()?
-        .join("reports")?
-        .join(&format!("{run_id}.json"))
+    benchmark_reports_uri()?.join(&format!("{run_id}.json"))
 }
 
 pub fn benchmark_report_markdown_uri(run_id: &str) -> Result<AxiomUri> {
-    benchmark_base_uri()?
-        .join("reports")?
-        .join(&format!("{run_id}.md"))
+    benchmark_reports_uri()?.join(&format!("{run_id}.md"))
 }
 
 pub fn benchmark_gate_result_uri(run_id: &str) -> Result<AxiomUri> {
@@ -112,6 +112,12 @@ pub fn trace_metrics_snapshot_uri(snapshot_id: &str) -> Result<AxiomUri> {
     trace_metrics_snapshots_uri()?.join(&format!("{snapshot_id}.json"))
 }
 
+pub fn maintenance_report_uri(run_id: &str) -> Result<AxiomUri> {
+    AxiomUri::root(Scope::Queue)
+        .join("maintenance")?
+        .join(&format!("compact-{run_id}.json"))
+}
+
 pub fn release_check_result_uri(check_id: &str) -> Result<AxiomUri> {
     AxiomUri::root(Scope::Queue)
         .join("release")?
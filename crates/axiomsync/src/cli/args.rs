@@ -2,6 +2,9 @@ use std::path::PathBuf;
 
 use clap::{Args, ValueEnum};
 
+use super::parsers::{parse_min_one_u64, parse_min_one_usize};
+use super::search::SearchCommand;
+
 #[derive(Debug, Clone, Copy, ValueEnum)]
 #[value(rename_all = "snake_case")]
 pub enum AddWaitModeArg {
@@ -9,6 +12,13 @@ pub enum AddWaitModeArg {
     Strict,
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum)]
+#[value(rename_all = "snake_case")]
+pub enum AddDedupActionArg {
+    Skip,
+    Link,
+}
+
 #[derive(Debug, Args)]
 pub struct AddArgs {
     /// Local file/dir path or HTTP(S) URL to ingest.
@@ -33,6 +43,31 @@ pub struct AddArgs {
     /// Wait contract when `--wait=true` (`relaxed`: one replay pass, `strict`: terminal done only).
     #[arg(long, value_enum, default_value_t = AddWaitModeArg::Relaxed)]
     pub wait_mode: AddWaitModeArg,
+    /// Keep polling the source directory and ingest created/modified/deleted files until Ctrl-C.
+    #[arg(long, default_value_t = false)]
+    pub watch: bool,
+    /// Polling interval for `--watch`.
+    #[arg(long, default_value_t = 5, value_parser = parse_min_one_u64, requires = "watch")]
+    pub interval_secs: u64,
+    /// Skip files whose content hash matches an already indexed document (`--dedup` is a deprecated alias).
+    #[arg(
+        long,
+        alias = "dedup",
+        default_value_t = false,
+        conflicts_with = "watch"
+    )]
+    pub dedup_by_content: bool,
+    /// What to leave behind for `--dedup-by-content` duplicates (`skip`: nothing, `link`: a `duplicate-of` relation).
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = AddDedupActionArg::Skip,
+        requires = "dedup_by_content"
+    )]
+    pub dedup_action: AddDedupActionArg,
+    /// Tag every ingested document (repeatable, `[a-z0-9._-]`); filterable via `--tag` on search.
+    #[arg(long = "tag", value_name = "TAG", conflicts_with = "watch")]
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Args)]
@@ -40,6 +75,11 @@ pub struct ListArgs {
     pub uri: String,
     #[arg(short, long)]
     pub recursive: bool,
+    /// Return one page of entries (`{entries, total, has_more}`) instead of the full list.
+    #[arg(long, value_parser = parse_min_one_usize)]
+    pub limit: Option<usize>,
+    #[arg(long, default_value_t = 0, requires = "limit")]
+    pub offset: usize,
 }
 
 #[derive(Debug, Args)]
@@ -55,10 +95,42 @@ pub struct UriArg {
 }
 
 #[derive(Debug, Args)]
-pub struct RemoveArgs {
+pub struct ReadArgs {
     pub uri: String,
+    /// Byte offset for a ranged read; prints a JSON chunk instead of raw content.
+    #[arg(long)]
+    pub offset: Option<u64>,
+    /// Maximum bytes for a ranged read (defaults to 65536 when `--offset` is set).
+    #[arg(long)]
+    pub length: Option<usize>,
+}
+
+#[derive(Debug, Args)]
+pub struct MkdirArgs {
+    pub uri: String,
+    /// Fail instead of creating missing parent directories.
+    #[arg(long, default_value_t = false)]
+    pub no_parents: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct RemoveArgs {
+    #[arg(required_unless_present = "glob")]
+    pub uri: Option<String>,
     #[arg(long, default_value_t = false)]
     pub recursive: bool,
+    /// Remove every file under `--uri` whose relative path matches this glob pattern.
+    #[arg(long, conflicts_with_all = ["uri", "recursive"])]
+    pub glob: Option<String>,
+    /// Base URI for `--glob` (defaults to `axiom://resources`).
+    #[arg(long = "uri", id = "glob_base_uri")]
+    pub glob_base_uri: Option<String>,
+    /// Report what `--glob` would delete without touching files or index state.
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Delete immediately instead of moving the target (or every `--glob` match) to `axiom://trash`.
+    #[arg(long)]
+    pub permanent: bool,
 }
 
 #[derive(Debug, Args)]
@@ -78,6 +150,9 @@ pub struct FindArgs {
     pub tags: Vec<String>,
     #[arg(long)]
     pub mime: Option<String>,
+    /// Metadata filter JSON object (e.g. `{"tags":{"not":["archived"]}}`).
+    #[arg(long, value_name = "JSON")]
+    pub filter_json: Option<String>,
     #[arg(long)]
     pub budget_ms: Option<u64>,
     #[arg(long)]
@@ -87,11 +162,15 @@ pub struct FindArgs {
 }
 
 #[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct SearchArgs {
+    #[command(subcommand)]
+    pub command: Option<SearchCommand>,
     #[arg(allow_hyphen_values = true)]
     pub query: Option<String>,
-    #[arg(long)]
-    pub target: Option<String>,
+    /// Repeat to search several subtrees at once; hits are merged by uri.
+    #[arg(long = "target", value_name = "URI")]
+    pub targets: Vec<String>,
     #[arg(long)]
     pub session: Option<String>,
     #[arg(long)]
@@ -100,12 +179,18 @@ pub struct SearchArgs {
     pub tags: Vec<String>,
     #[arg(long)]
     pub mime: Option<String>,
+    /// Metadata filter JSON object (e.g. `{"mtime":{"gte":"2024-06-01T00:00:00Z"}}`).
+    #[arg(long, value_name = "JSON")]
+    pub filter_json: Option<String>,
     #[arg(long = "hint", value_name = "KIND:TEXT")]
     pub hints: Vec<String>,
     #[arg(long, value_name = "FILE")]
     pub hint_file: Option<PathBuf>,
     #[arg(long, value_name = "FILE")]
     pub request_json: Option<PathBuf>,
+    /// Run one search per JSONL line (each line is a search request payload).
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["query", "request_json"])]
+    pub queries_file: Option<PathBuf>,
     /// Drop hits whose normalized score is below this threshold.
     #[arg(long, value_parser = parse_score_threshold)]
     pub score_threshold: Option<f32>,
@@ -118,6 +203,36 @@ pub struct SearchArgs {
     pub budget_nodes: Option<usize>,
     #[arg(long)]
     pub budget_depth: Option<usize>,
+    /// Drop candidates in this scope during retrieval (repeatable).
+    #[arg(long = "exclude-scope", value_name = "SCOPE")]
+    pub exclude_scopes: Vec<String>,
+    /// Attach each hit's relation neighborhood up to this many hops (<= 5).
+    #[arg(long, value_name = "HOPS")]
+    pub relation_hops: Option<usize>,
+    /// Re-extract each hit's snippet around the query terms and wrap matches in this delimiter.
+    #[arg(long, value_name = "DELIMITER")]
+    pub highlight: Option<String>,
+    /// Skip this many ranked hits; pass the previous result's `next_offset` to fetch the next page.
+    #[arg(long, value_name = "N", conflicts_with = "cursor")]
+    pub offset: Option<usize>,
+    /// Resume from the previous result's `next_cursor`; the query and filters must match.
+    #[arg(long, value_name = "TOKEN")]
+    pub cursor: Option<String>,
+    /// Issue a `next_cursor` when the first page is full; pass it back with `--cursor`.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["cursor", "offset"])]
+    pub paginate: bool,
+    /// Fill unset target, filter, budget, and limit from a saved search profile.
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+    /// Print the query plan, resolved hints, and candidate estimate without running retrieval.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["queries_file", "markdown", "cite"])]
+    pub explain: bool,
+    /// Print a Markdown report (query, ranked hits, snippets, relations) instead of JSON.
+    #[arg(long, default_value_t = false, conflicts_with = "queries_file")]
+    pub markdown: bool,
+    /// Print numbered citations (`[n] <uri> — <abstract first line>`) instead of JSON.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["queries_file", "markdown"])]
+    pub cite: bool,
 }
 #[derive(Debug, Args)]
 pub struct ReconcileArgs {
@@ -127,11 +242,52 @@ pub struct ReconcileArgs {
     pub scopes: Vec<String>,
     #[arg(long, default_value_t = 50)]
     pub max_drift_sample: usize,
+    /// Remove non-canonical duplicates of index entries that normalize to the same uri.
+    #[arg(long, default_value_t = false, conflicts_with = "dry_run")]
+    pub fix: bool,
+    /// Drop index entries and search documents whose uri no longer exists on disk.
+    #[arg(long, default_value_t = false)]
+    pub check_vectors: bool,
+    /// Find relation targets that no longer exist; prune them unless --dry-run.
+    #[arg(long, default_value_t = false)]
+    pub check_relations: bool,
+}
+#[derive(Debug, Args)]
+pub struct CompactArgs {
+    /// Delete traces and their index rows older than this many days.
+    #[arg(long, default_value_t = 30)]
+    pub traces_days: u32,
+    /// Delete benchmark/eval reports older than this many days.
+    #[arg(long, default_value_t = 30)]
+    pub reports_days: u32,
+    /// Always keep this many of the most recent report runs per directory.
+    #[arg(long, default_value_t = 10)]
+    pub reports_keep: usize,
+    /// Keep only this many of the most recent request log rows.
+    #[arg(long, default_value_t = 10_000)]
+    pub request_log_max_rows: usize,
+    /// Report what would be reclaimed without deleting anything.
+    #[arg(long, default_value_t = false)]
+    pub dry_run: bool,
+}
+#[derive(Debug, Args)]
+pub struct BackupArgs {
+    /// Destination file for the state database copy; overwritten if it exists.
+    pub path: PathBuf,
 }
 #[derive(Debug, Args)]
 pub struct ExportArgs {
     pub uri: String,
     pub to: String,
+    /// Leave out files and directories matching this glob, relative to the exported root (repeatable).
+    #[arg(long = "exclude", value_name = "GLOB")]
+    pub exclude_globs: Vec<String>,
+    /// Only pack files matching this glob, relative to the exported root (repeatable).
+    #[arg(long = "include", value_name = "GLOB")]
+    pub include_globs: Vec<String>,
+    /// Leave out generated `.abstract.md` / `.overview.md` tier files.
+    #[arg(long, default_value_t = false)]
+    pub skip_tiers: bool,
 }
 
 #[derive(Debug, Args)]
@@ -142,6 +298,12 @@ pub struct ImportArgs {
     pub force: bool,
     #[arg(long, default_value_t = true)]
     pub vectorize: bool,
+    /// Skip checking archive files against the embedded manifest.
+    #[arg(long, default_value_t = false)]
+    pub no_verify: bool,
+    /// Import the pack root under this name instead of its original name.
+    #[arg(long = "as", value_name = "NAME")]
+    pub as_name: Option<String>,
 }
 
 #[derive(Debug, Args)]
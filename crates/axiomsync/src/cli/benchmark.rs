@@ -1,6 +1,9 @@
 use clap::{Args, Subcommand};
 
-use super::parsers::{parse_min_one_usize, parse_non_negative_f32, parse_unit_interval_f32};
+use super::parsers::{
+    parse_concurrency_p95_limit, parse_min_one_usize, parse_non_negative_f32,
+    parse_unit_interval_f32,
+};
 
 #[derive(Debug, Args)]
 pub struct BenchmarkArgs {
@@ -24,6 +27,12 @@ pub enum BenchmarkCommand {
         trace_expectations: bool,
         #[arg(long)]
         fixture_name: Option<String>,
+        /// Refuse to run unless the current corpus snapshot id matches.
+        #[arg(long)]
+        corpus_snapshot: Option<String>,
+        /// Re-run the case set at each concurrency level (e.g. `1,4,8`) and report per-level latency/QPS.
+        #[arg(long, value_delimiter = ',', value_parser = parse_min_one_usize)]
+        concurrency: Vec<usize>,
     },
     Amortized {
         #[arg(long, default_value_t = 100)]
@@ -40,6 +49,9 @@ pub enum BenchmarkCommand {
         trace_expectations: bool,
         #[arg(long)]
         fixture_name: Option<String>,
+        /// Refuse to run unless the current corpus snapshot id matches.
+        #[arg(long)]
+        corpus_snapshot: Option<String>,
         #[arg(long, default_value_t = 3)]
         iterations: usize,
     },
@@ -64,6 +76,8 @@ pub enum BenchmarkCommand {
         max_p95_regression_pct: Option<f32>,
         #[arg(long, value_parser = parse_non_negative_f32)]
         max_top1_regression_pct: Option<f32>,
+        #[arg(long, value_parser = parse_non_negative_f32)]
+        max_mrr_regression_pct: Option<f32>,
         #[arg(long, default_value_t = 1, value_parser = parse_min_one_usize)]
         window_size: usize,
         #[arg(long, default_value_t = 1, value_parser = parse_min_one_usize)]
@@ -72,6 +86,9 @@ pub enum BenchmarkCommand {
         record: bool,
         #[arg(long, default_value_t = false)]
         write_release_check: bool,
+        /// Fail when the concurrency profile p95 exceeds the limit (`<concurrency>:<max_p95_ms>`).
+        #[arg(long, value_parser = parse_concurrency_p95_limit)]
+        max_p95_ms_at_concurrency: Option<(usize, u128)>,
         #[arg(long, default_value_t = false)]
         enforce: bool,
     },
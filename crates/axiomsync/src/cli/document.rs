@@ -44,4 +44,26 @@ pub enum DocumentCommand {
         #[arg(long)]
         expected_etag: Option<String>,
     },
+    /// Appends to a markdown file, creating it if missing.
+    Append {
+        uri: String,
+        #[arg(long, allow_hyphen_values = true)]
+        content: Option<String>,
+        #[arg(long)]
+        from: Option<PathBuf>,
+        #[arg(long, default_value_t = false)]
+        stdin: bool,
+    },
+    /// Replaces line ranges from a JSON array `[{"start_line","end_line","replacement"}]`.
+    Patch {
+        uri: String,
+        #[arg(long, allow_hyphen_values = true)]
+        ops_json: Option<String>,
+        #[arg(long)]
+        from: Option<PathBuf>,
+        #[arg(long, default_value_t = false)]
+        stdin: bool,
+        #[arg(long)]
+        expected_etag: Option<String>,
+    },
 }
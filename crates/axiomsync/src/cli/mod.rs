@@ -6,32 +6,41 @@ mod args;
 mod benchmark;
 mod document;
 mod eval;
+mod maintenance;
 mod ontology;
 mod parsers;
 mod queue;
 mod relation;
 mod release;
+mod search;
 mod security;
 mod session;
+mod tag;
 mod trace;
+mod trash;
 
 #[cfg(test)]
 mod tests;
 
 pub use args::{
-    AddArgs, AddWaitModeArg, ExportArgs, FindArgs, GlobArgs, ImportArgs, ListArgs, MoveArgs,
-    ReconcileArgs, RemoveArgs, SearchArgs, UriArg, WebArgs,
+    AddArgs, AddDedupActionArg, AddWaitModeArg, BackupArgs, CompactArgs, ExportArgs, FindArgs,
+    GlobArgs, ImportArgs, ListArgs, MkdirArgs, MoveArgs, ReadArgs, ReconcileArgs, RemoveArgs,
+    SearchArgs, UriArg, WebArgs,
 };
 pub use benchmark::{BenchmarkArgs, BenchmarkCommand, BenchmarkFixtureCommand};
 pub use document::{DocumentArgs, DocumentCommand, DocumentMode};
 pub use eval::{EvalArgs, EvalCommand, EvalGoldenCommand};
+pub use maintenance::{MaintenanceArgs, MaintenanceCommand};
 pub use ontology::{OntologyArgs, OntologyCommand};
-pub use queue::{QueueArgs, QueueCommand};
+pub use queue::{QueueArgs, QueueCommand, QueueDeadLetterCommand};
 pub use relation::{RelationArgs, RelationCommand};
 pub use release::{ReleaseArgs, ReleaseCommand, ReleaseSecurityAuditModeArg};
+pub use search::{SearchCommand, SearchProfileCommand};
 pub use security::{SecurityArgs, SecurityAuditModeArg, SecurityCommand};
-pub use session::{SessionArgs, SessionCommand};
+pub use session::{SessionArgs, SessionCommand, SessionOmCommand};
+pub use tag::{TagArgs, TagCommand};
 pub use trace::{TraceArgs, TraceCommand};
+pub use trash::{TrashArgs, TrashCommand};
 
 #[derive(Debug, Parser)]
 #[command(name = "axiomsync")]
@@ -40,6 +49,14 @@ pub struct Cli {
     #[arg(long, default_value = ".axiomsync")]
     pub root: PathBuf,
 
+    /// TOML file with a `[roots]` table of `name = "path"`; used instead of `--root`.
+    #[arg(long, value_name = "FILE", conflicts_with = "root")]
+    pub workspace_config: Option<PathBuf>,
+
+    /// Run the command against one root of `--workspace-config` instead of fanning out `search`.
+    #[arg(long, value_name = "NAME", requires = "workspace_config")]
+    pub workspace_root: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -50,16 +67,19 @@ pub enum Commands {
     Add(AddArgs),
     Ls(ListArgs),
     Glob(GlobArgs),
-    Read(UriArg),
+    Read(ReadArgs),
     Abstract(UriArg),
     Overview(UriArg),
-    Mkdir(UriArg),
+    Mkdir(MkdirArgs),
     Rm(RemoveArgs),
+    Trash(TrashArgs),
     Mv(MoveArgs),
     Tree(UriArg),
+    Stat(UriArg),
+    Tag(TagArgs),
     Document(DocumentArgs),
     Find(FindArgs),
-    Search(SearchArgs),
+    Search(Box<SearchArgs>),
     Backend,
     Queue(QueueArgs),
     Trace(TraceArgs),
@@ -70,6 +90,9 @@ pub enum Commands {
     Security(SecurityArgs),
     Release(ReleaseArgs),
     Reconcile(ReconcileArgs),
+    Compact(CompactArgs),
+    Backup(BackupArgs),
+    Maintenance(MaintenanceArgs),
     Session(SessionArgs),
     ExportOvpack(ExportArgs),
     ImportOvpack(ImportArgs),
@@ -56,6 +56,9 @@ pub enum OntologyCommand {
         input_file: Option<PathBuf>,
         #[arg(long, default_value_t = false)]
         input_stdin: bool,
+        /// Coerce stringified booleans/numbers to the declared kinds before validating.
+        #[arg(long, default_value_t = false)]
+        coerce: bool,
     },
     ActionEnqueue {
         #[arg(long)]
@@ -79,4 +82,18 @@ pub enum OntologyCommand {
         #[arg(long, default_value_t = false)]
         enforce: bool,
     },
+    /// Print added/removed/modified definitions between two schema documents.
+    Diff {
+        #[arg(long)]
+        from: String,
+        #[arg(long)]
+        to: String,
+    },
+    /// Print the JSON Schema document for an action type's input contract.
+    ExportJsonSchema {
+        #[arg(long)]
+        uri: Option<String>,
+        #[arg(long)]
+        action_id: String,
+    },
 }
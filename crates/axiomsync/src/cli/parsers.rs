@@ -28,6 +28,16 @@ pub(super) fn parse_min_one_u32(raw: &str) -> std::result::Result<u32, String> {
     Ok(value)
 }
 
+pub(super) fn parse_min_one_u64(raw: &str) -> std::result::Result<u64, String> {
+    let value = raw
+        .parse::<u64>()
+        .map_err(|_| format!("invalid integer value '{raw}'"))?;
+    if value == 0 {
+        return Err("value must be >= 1".to_string());
+    }
+    Ok(value)
+}
+
 pub(super) fn parse_min_one_usize(raw: &str) -> std::result::Result<usize, String> {
     let value = raw
         .parse::<usize>()
@@ -37,3 +47,16 @@ pub(super) fn parse_min_one_usize(raw: &str) -> std::result::Result<usize, Strin
     }
     Ok(value)
 }
+
+/// Parses `<concurrency>:<max_p95_ms>`, e.g. `8:250`.
+pub(super) fn parse_concurrency_p95_limit(raw: &str) -> std::result::Result<(usize, u128), String> {
+    let (concurrency, max_p95_ms) = raw
+        .split_once(':')
+        .ok_or_else(|| format!("expected <concurrency>:<max_p95_ms>, got '{raw}'"))?;
+    let concurrency = parse_min_one_usize(concurrency.trim())?;
+    let max_p95_ms = max_p95_ms
+        .trim()
+        .parse::<u128>()
+        .map_err(|_| format!("invalid integer value '{max_p95_ms}'"))?;
+    Ok((concurrency, max_p95_ms))
+}
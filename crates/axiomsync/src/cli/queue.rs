@@ -1,6 +1,6 @@
 use clap::{Args, Subcommand};
 
-use super::parsers::parse_min_one_u32;
+use super::parsers::{parse_min_one_u32, parse_min_one_usize};
 
 #[derive(Debug, Args)]
 pub struct QueueArgs {
@@ -31,6 +31,9 @@ pub enum QueueCommand {
         include_dead_letter: bool,
         #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
         stop_when_idle: bool,
+        /// Replay worker threads; events for the same URI stay on one worker.
+        #[arg(long, default_value_t = 1, value_parser = parse_min_one_usize)]
+        workers: usize,
     },
     Daemon {
         #[arg(long, default_value_t = 120)]
@@ -45,6 +48,25 @@ pub enum QueueCommand {
         stop_when_idle: bool,
         #[arg(long, default_value_t = 3, value_parser = parse_min_one_u32)]
         idle_cycles: u32,
+        /// Replay worker threads; events for the same URI stay on one worker.
+        #[arg(long, default_value_t = 1, value_parser = parse_min_one_usize)]
+        workers: usize,
+    },
+    /// Print one outbox event including its payload.
+    Show {
+        event_id: i64,
+    },
+    /// Reset retry counters and move one event (e.g. dead-letter) back to `new`.
+    Requeue {
+        event_id: i64,
+    },
+    /// Delete one event from the outbox; refused while it is processing.
+    Purge {
+        event_id: i64,
+    },
+    DeadLetter {
+        #[command(subcommand)]
+        command: QueueDeadLetterCommand,
     },
     Evidence {
         #[arg(long, default_value_t = 100)]
@@ -55,3 +77,14 @@ pub enum QueueCommand {
         enforce: bool,
     },
 }
+
+#[derive(Debug, Subcommand)]
+pub enum QueueDeadLetterCommand {
+    /// List dead-lettered events with their payloads.
+    List {
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+    },
+    /// Move one dead-lettered event back to `new` with retry counters reset.
+    Requeue { event_id: i64 },
+}
@@ -28,4 +28,13 @@ pub enum RelationCommand {
         #[arg(long)]
         relation_id: String,
     },
+    /// Walk relation links breadth-first from a uri.
+    Neighborhood {
+        uri: String,
+        #[arg(long, default_value_t = 2)]
+        hops: usize,
+        /// Maximum number of nodes, including the start uri.
+        #[arg(long, default_value_t = 100)]
+        limit: usize,
+    },
 }
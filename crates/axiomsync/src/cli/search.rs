@@ -0,0 +1,39 @@
+use clap::Subcommand;
+
+#[derive(Debug, Subcommand)]
+pub enum SearchCommand {
+    /// Manage saved search profiles (`search --profile <name>`).
+    Profile {
+        #[command(subcommand)]
+        command: SearchProfileCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SearchProfileCommand {
+    /// Create or replace a profile.
+    Add {
+        name: String,
+        #[arg(long)]
+        target: Option<String>,
+        #[arg(long)]
+        limit: Option<usize>,
+        #[arg(long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
+        #[arg(long)]
+        mime: Option<String>,
+        /// Metadata filter JSON object (e.g. `{"tags":{"any":["work","infra"]}}`).
+        #[arg(long, value_name = "JSON")]
+        filter_json: Option<String>,
+        #[arg(long)]
+        budget_ms: Option<u64>,
+        #[arg(long)]
+        budget_nodes: Option<usize>,
+        #[arg(long)]
+        budget_depth: Option<usize>,
+    },
+    List,
+    Rm {
+        name: String,
+    },
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use clap::{Args, Subcommand};
 
 #[derive(Debug, Args)]
@@ -18,14 +20,76 @@ pub enum SessionCommand {
         role: String,
         #[arg(long)]
         text: String,
+        /// Structured per-turn metadata stored with the message (JSON value).
+        #[arg(long)]
+        metadata_json: Option<String>,
+        /// Axiom URI referenced by this turn. Repeat to attach several.
+        #[arg(long = "attach", value_name = "URI")]
+        attachments: Vec<String>,
     },
     Commit {
         #[arg(long)]
         id: String,
+        /// Keep the newest N messages active and archive only older ones.
+        #[arg(long, value_name = "N")]
+        keep_recent: Option<usize>,
     },
     List,
+    /// Inspect or reset the session's observational memory record.
+    Om {
+        #[command(subcommand)]
+        command: SessionOmCommand,
+    },
+    /// Run the OM reflector now over the current active observations.
+    Reflect {
+        #[arg(long)]
+        id: String,
+    },
+    /// Print active and archived messages with their metadata and attachments.
+    Show {
+        #[arg(long)]
+        id: String,
+        /// Also report promoted memory counts per category for this session.
+        #[arg(long, default_value_t = false)]
+        memory_stats: bool,
+    },
     Delete {
         #[arg(long)]
         id: String,
     },
+    Export {
+        #[arg(long)]
+        id: String,
+        #[arg(long)]
+        to: PathBuf,
+        /// Write a portable `.ovpack` archive of the whole session tree plus OM state.
+        #[arg(long, default_value_t = false)]
+        archive: bool,
+    },
+    Import {
+        file: PathBuf,
+        #[arg(long)]
+        id: Option<String>,
+        #[arg(long, default_value_t = false)]
+        force: bool,
+        /// Read a session `.ovpack` archive written by `session export --archive`.
+        #[arg(long, default_value_t = false)]
+        archive: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SessionOmCommand {
+    Show {
+        #[arg(long)]
+        id: String,
+    },
+    /// Clear active observations and trigger counters; bumps the record generation.
+    Reset {
+        #[arg(long)]
+        id: String,
+        /// Keep buffered observation chunks instead of discarding them.
+        #[arg(long, default_value_t = false)]
+        keep_buffered: bool,
+    },
 }
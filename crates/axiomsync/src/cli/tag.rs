@@ -0,0 +1,25 @@
+use clap::{Args, Subcommand};
+
+#[derive(Debug, Args)]
+pub struct TagArgs {
+    #[command(subcommand)]
+    pub command: TagCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TagCommand {
+    /// Attach tags to a document; they survive reindexing.
+    Add {
+        uri: String,
+        #[arg(required = true)]
+        tags: Vec<String>,
+    },
+    /// Detach tags from a document, including inferred ones.
+    Rm {
+        uri: String,
+        #[arg(required = true)]
+        tags: Vec<String>,
+    },
+    /// Print the current tags of a document.
+    Ls { uri: String },
+}
@@ -55,6 +55,50 @@ fn ontology_validate_parses_optional_uri() {
     }
 }
 
+#[test]
+fn ontology_diff_parses_from_and_to_uris() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "ontology",
+        "diff",
+        "--from",
+        "axiom://agent/ontology/schema.v1.json",
+        "--to",
+        "axiom://resources/drafts/schema.v1.json",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Ontology(OntologyArgs {
+            command: OntologyCommand::Diff { from, to },
+        }) => {
+            assert_eq!(from, "axiom://agent/ontology/schema.v1.json");
+            assert_eq!(to, "axiom://resources/drafts/schema.v1.json");
+        }
+        _ => panic!("expected ontology diff command"),
+    }
+}
+
+#[test]
+fn ontology_export_json_schema_parses_action_id_and_default_uri() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "ontology",
+        "export-json-schema",
+        "--action-id",
+        "sync_doc",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Ontology(OntologyArgs {
+            command: OntologyCommand::ExportJsonSchema { uri, action_id },
+        }) => {
+            assert_eq!(uri, None);
+            assert_eq!(action_id, "sync_doc");
+        }
+        _ => panic!("expected ontology export-json-schema command"),
+    }
+}
+
 #[test]
 fn ontology_pressure_parses_thresholds() {
     let cli = Cli::try_parse_from([
@@ -301,6 +345,29 @@ fn relation_link_parses_owner_id_and_uris() {
     }
 }
 
+#[test]
+fn relation_neighborhood_parses_positional_uri_and_hops() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "relation",
+        "neighborhood",
+        "axiom://resources/docs/auth.md",
+        "--hops",
+        "3",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Relation(RelationArgs {
+            command: RelationCommand::Neighborhood { uri, hops, limit },
+        }) => {
+            assert_eq!(uri, "axiom://resources/docs/auth.md");
+            assert_eq!(hops, 3);
+            assert_eq!(limit, 100);
+        }
+        _ => panic!("expected relation neighborhood command"),
+    }
+}
+
 #[test]
 fn relation_unlink_parses_owner_and_relation_id() {
     let cli = Cli::try_parse_from([
@@ -387,6 +454,71 @@ fn document_save_content_with_front_matter_parses() {
     }
 }
 
+#[test]
+fn document_append_parses_inline_content() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "document",
+        "append",
+        "axiom://resources/notes/log.md",
+        "--content",
+        "- entry",
+    ])
+    .expect("parse");
+
+    match cli.command {
+        Commands::Document(DocumentArgs {
+            command:
+                DocumentCommand::Append {
+                    uri,
+                    content,
+                    from,
+                    stdin,
+                },
+        }) => {
+            assert_eq!(uri, "axiom://resources/notes/log.md");
+            assert_eq!(content.as_deref(), Some("- entry"));
+            assert!(from.is_none());
+            assert!(!stdin);
+        }
+        _ => panic!("expected document append"),
+    }
+}
+
+#[test]
+fn document_patch_parses_inline_ops_and_etag() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "document",
+        "patch",
+        "axiom://resources/docs/guide.md",
+        "--ops-json",
+        r#"[{"start_line":2,"end_line":3,"replacement":"x"}]"#,
+        "--expected-etag",
+        "abc",
+    ])
+    .expect("parse");
+
+    match cli.command {
+        Commands::Document(DocumentArgs {
+            command:
+                DocumentCommand::Patch {
+                    uri,
+                    ops_json,
+                    from,
+                    expected_etag,
+                    ..
+                },
+        }) => {
+            assert_eq!(uri, "axiom://resources/docs/guide.md");
+            assert!(ops_json.is_some_and(|raw| raw.contains("start_line")));
+            assert!(from.is_none());
+            assert_eq!(expected_etag.as_deref(), Some("abc"));
+        }
+        _ => panic!("expected document patch"),
+    }
+}
+
 #[test]
 fn document_preview_from_uri_parses() {
     let cli = Cli::try_parse_from([
@@ -456,13 +588,52 @@ fn find_parses_filter_flags() {
     }
 }
 
+#[test]
+fn read_parses_range_flags() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "read",
+        "axiom://resources/large.jsonl",
+        "--offset",
+        "1024",
+        "--length",
+        "4096",
+    ])
+    .expect("parse");
+
+    match cli.command {
+        Commands::Read(ReadArgs {
+            uri,
+            offset,
+            length,
+        }) => {
+            assert_eq!(uri, "axiom://resources/large.jsonl");
+            assert_eq!(offset, Some(1024));
+            assert_eq!(length, Some(4096));
+        }
+        _ => panic!("expected read command"),
+    }
+}
+
+#[test]
+fn stat_parses_uri_argument() {
+    let cli =
+        Cli::try_parse_from(["axiomsync", "stat", "axiom://resources/docs/a.md"]).expect("parse");
+
+    match cli.command {
+        Commands::Stat(args) => assert_eq!(args.uri, "axiom://resources/docs/a.md"),
+        _ => panic!("expected stat command"),
+    }
+}
+
 #[test]
 fn search_query_with_leading_hyphen_parses() {
     let cli = Cli::try_parse_from(["axiomsync", "search", "--dash-prefixed", "--limit", "4"])
         .expect("parse");
 
     match cli.command {
-        Commands::Search(SearchArgs { query, limit, .. }) => {
+        Commands::Search(args) => {
+            let SearchArgs { query, limit, .. } = *args;
             assert_eq!(query.as_deref(), Some("--dash-prefixed"));
             assert_eq!(limit, Some(4));
         }
@@ -524,6 +695,50 @@ fn benchmark_gate_parses_min_stress_top1_accuracy() {
     }
 }
 
+#[test]
+fn benchmark_run_parses_concurrency_levels_and_gate_parses_concurrency_limit() {
+    let cli = Cli::try_parse_from(["axiomsync", "benchmark", "run", "--concurrency", "1,4,8"])
+        .expect("parse");
+    match cli.command {
+        Commands::Benchmark(BenchmarkArgs {
+            command: BenchmarkCommand::Run { concurrency, .. },
+        }) => assert_eq!(concurrency, vec![1, 4, 8]),
+        _ => panic!("expected benchmark run command"),
+    }
+    assert!(
+        Cli::try_parse_from(["axiomsync", "benchmark", "run", "--concurrency", "0,4"]).is_err()
+    );
+
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "benchmark",
+        "gate",
+        "--max-p95-ms-at-concurrency",
+        "8:250",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Benchmark(BenchmarkArgs {
+            command:
+                BenchmarkCommand::Gate {
+                    max_p95_ms_at_concurrency,
+                    ..
+                },
+        }) => assert_eq!(max_p95_ms_at_concurrency, Some((8, 250))),
+        _ => panic!("expected benchmark gate command"),
+    }
+    assert!(
+        Cli::try_parse_from([
+            "axiomsync",
+            "benchmark",
+            "gate",
+            "--max-p95-ms-at-concurrency",
+            "250",
+        ])
+        .is_err()
+    );
+}
+
 #[test]
 fn release_pack_parses_benchmark_min_stress_top1_accuracy() {
     let cli = Cli::try_parse_from([
@@ -591,6 +806,51 @@ fn release_pack_defaults_security_audit_mode_to_strict() {
     }
 }
 
+#[test]
+fn ovpack_commands_parse_filter_and_verify_flags() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "export-ovpack",
+        "axiom://resources/demo",
+        "out/demo",
+        "--exclude",
+        "*.bin",
+        "--exclude",
+        "tmp/**",
+        "--skip-tiers",
+    ])
+    .expect("parse export");
+    match cli.command {
+        Commands::ExportOvpack(args) => {
+            assert_eq!(
+                args.exclude_globs,
+                vec!["*.bin".to_string(), "tmp/**".to_string()]
+            );
+            assert!(args.include_globs.is_empty());
+            assert!(args.skip_tiers);
+        }
+        _ => panic!("expected export-ovpack command"),
+    }
+
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "import-ovpack",
+        "demo.ovpack",
+        "axiom://resources",
+        "--no-verify",
+        "--as",
+        "renamed",
+    ])
+    .expect("parse import");
+    match cli.command {
+        Commands::ImportOvpack(args) => {
+            assert!(args.no_verify);
+            assert_eq!(args.as_name.as_deref(), Some("renamed"));
+        }
+        _ => panic!("expected import-ovpack command"),
+    }
+}
+
 #[test]
 fn add_parses_markdown_only_filter_flags() {
     let cli = Cli::try_parse_from([
@@ -641,6 +901,107 @@ fn add_parses_wait_mode_strict() {
     }
 }
 
+#[test]
+fn add_parses_dedup_flag_and_rejects_it_with_watch() {
+    for flag in ["--dedup-by-content", "--dedup"] {
+        let cli = Cli::try_parse_from(["axiomsync", "add", "/tmp/vault", flag]).expect("parse");
+        match cli.command {
+            Commands::Add(AddArgs {
+                dedup_by_content, ..
+            }) => assert!(dedup_by_content),
+            _ => panic!("expected add command"),
+        }
+    }
+
+    assert!(Cli::try_parse_from(["axiomsync", "add", "/tmp/vault", "--dedup", "--watch"]).is_err());
+
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "add",
+        "/tmp/vault",
+        "--dedup-by-content",
+        "--dedup-action",
+        "link",
+    ])
+    .expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Add(AddArgs {
+            dedup_by_content: true,
+            dedup_action: AddDedupActionArg::Link,
+            ..
+        })
+    ));
+    assert!(
+        Cli::try_parse_from(["axiomsync", "add", "/tmp/vault", "--dedup-action", "link"]).is_err()
+    );
+}
+
+#[test]
+fn add_parses_repeatable_tag_flag() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "add",
+        "/tmp/vault",
+        "--tag",
+        "auth",
+        "--tag",
+        "oauth",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Add(AddArgs { tags, .. }) => {
+            assert_eq!(tags, vec!["auth".to_string(), "oauth".to_string()]);
+        }
+        _ => panic!("expected add command"),
+    }
+
+    assert!(
+        Cli::try_parse_from(["axiomsync", "add", "/tmp/vault", "--tag", "auth", "--watch"])
+            .is_err()
+    );
+}
+
+#[test]
+fn add_parses_watch_interval_and_requires_watch_flag() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "add",
+        "/tmp/vault",
+        "--watch",
+        "--interval-secs",
+        "2",
+    ])
+    .expect("parse");
+
+    match cli.command {
+        Commands::Add(AddArgs {
+            watch,
+            interval_secs,
+            ..
+        }) => {
+            assert!(watch);
+            assert_eq!(interval_secs, 2);
+        }
+        _ => panic!("expected add command"),
+    }
+
+    assert!(
+        Cli::try_parse_from(["axiomsync", "add", "/tmp/vault", "--interval-secs", "2"]).is_err()
+    );
+    assert!(
+        Cli::try_parse_from([
+            "axiomsync",
+            "add",
+            "/tmp/vault",
+            "--watch",
+            "--interval-secs",
+            "0"
+        ])
+        .is_err()
+    );
+}
+
 #[test]
 fn search_parses_score_and_min_match_options() {
     let cli = Cli::try_parse_from([
@@ -655,12 +1016,13 @@ fn search_parses_score_and_min_match_options() {
     .expect("parse");
 
     match cli.command {
-        Commands::Search(SearchArgs {
-            query,
-            score_threshold,
-            min_match_tokens,
-            ..
-        }) => {
+        Commands::Search(args) => {
+            let SearchArgs {
+                query,
+                score_threshold,
+                min_match_tokens,
+                ..
+            } = *args;
             assert_eq!(query.as_deref(), Some("oauth"));
             assert_eq!(score_threshold, Some(0.35));
             assert_eq!(min_match_tokens, Some(2));
@@ -689,15 +1051,16 @@ fn search_parses_filter_and_runtime_hint_flags() {
     .expect("parse");
 
     match cli.command {
-        Commands::Search(SearchArgs {
-            query,
-            tags,
-            mime,
-            hints,
-            hint_file,
-            request_json,
-            ..
-        }) => {
+        Commands::Search(args) => {
+            let SearchArgs {
+                query,
+                tags,
+                mime,
+                hints,
+                hint_file,
+                request_json,
+                ..
+            } = *args;
             assert_eq!(query.as_deref(), Some("oauth"));
             assert_eq!(tags, vec!["markdown".to_string()]);
             assert_eq!(mime.as_deref(), Some("text/markdown"));
@@ -716,30 +1079,312 @@ fn search_parses_filter_and_runtime_hint_flags() {
 }
 
 #[test]
-fn search_rejects_out_of_range_score_threshold() {
-    let parsed = Cli::try_parse_from(["axiomsync", "search", "oauth", "--score-threshold", "1.5"]);
-    assert!(
-        parsed.is_err(),
-        "score threshold above 1.0 must be rejected"
-    );
+fn search_parses_repeated_exclude_scope_flags() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "search",
+        "oauth",
+        "--exclude-scope",
+        "session",
+        "--exclude-scope",
+        "agent",
+    ])
+    .expect("parse");
+
+    match cli.command {
+        Commands::Search(args) => {
+            let SearchArgs { exclude_scopes, .. } = *args;
+            assert_eq!(
+                exclude_scopes,
+                vec!["session".to_string(), "agent".to_string()]
+            );
+        }
+        _ => panic!("expected search command"),
+    }
 }
 
 #[test]
-fn search_rejects_min_match_tokens_below_two() {
-    let parsed = Cli::try_parse_from([
+fn search_parses_queries_file_without_positional_query() {
+    let cli = Cli::try_parse_from(["axiomsync", "search", "--queries-file", "queries.jsonl"])
+        .expect("parse");
+
+    match cli.command {
+        Commands::Search(args) => {
+            let SearchArgs {
+                query,
+                queries_file,
+                ..
+            } = *args;
+            assert!(query.is_none());
+            assert_eq!(queries_file, Some(PathBuf::from("queries.jsonl")));
+        }
+        _ => panic!("expected search command"),
+    }
+
+    let conflict = Cli::try_parse_from([
         "axiomsync",
         "search",
-        "oauth callback",
-        "--min-match-tokens",
-        "1",
+        "oauth",
+        "--queries-file",
+        "queries.jsonl",
     ]);
-    assert!(parsed.is_err(), "min-match-tokens below 2 must be rejected");
+    assert!(conflict.is_err());
 }
 
 #[test]
-fn queue_daemon_rejects_zero_idle_cycles() {
-    let parsed = Cli::try_parse_from(["axiomsync", "queue", "daemon", "--idle-cycles", "0"]);
-    assert!(parsed.is_err(), "idle-cycles must be >= 1");
+fn search_parses_markdown_flag() {
+    let cli = Cli::try_parse_from(["axiomsync", "search", "oauth", "--markdown"]).expect("parse");
+
+    match cli.command {
+        Commands::Search(args) => {
+            let SearchArgs {
+                query, markdown, ..
+            } = *args;
+            assert_eq!(query.as_deref(), Some("oauth"));
+            assert!(markdown);
+        }
+        _ => panic!("expected search command"),
+    }
+
+    let conflict = Cli::try_parse_from([
+        "axiomsync",
+        "search",
+        "--queries-file",
+        "queries.jsonl",
+        "--markdown",
+    ]);
+    assert!(conflict.is_err());
+}
+
+#[test]
+fn search_parses_cite_flag() {
+    let cli = Cli::try_parse_from(["axiomsync", "search", "oauth", "--cite"]).expect("parse");
+
+    match cli.command {
+        Commands::Search(args) => {
+            let SearchArgs { cite, markdown, .. } = *args;
+            assert!(cite);
+            assert!(!markdown);
+        }
+        _ => panic!("expected search command"),
+    }
+
+    let conflict = Cli::try_parse_from(["axiomsync", "search", "oauth", "--cite", "--markdown"]);
+    assert!(conflict.is_err());
+}
+
+#[test]
+fn search_parses_highlight_delimiter() {
+    let cli =
+        Cli::try_parse_from(["axiomsync", "search", "oauth", "--highlight", "**"]).expect("parse");
+
+    match cli.command {
+        Commands::Search(args) => {
+            let SearchArgs { highlight, .. } = *args;
+            assert_eq!(highlight.as_deref(), Some("**"));
+        }
+        _ => panic!("expected search command"),
+    }
+}
+
+#[test]
+fn search_parses_offset() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "search",
+        "oauth",
+        "--limit",
+        "5",
+        "--offset",
+        "10",
+    ])
+    .expect("parse");
+
+    match cli.command {
+        Commands::Search(args) => {
+            let SearchArgs { limit, offset, .. } = *args;
+            assert_eq!(limit, Some(5));
+            assert_eq!(offset, Some(10));
+        }
+        _ => panic!("expected search command"),
+    }
+}
+
+#[test]
+fn search_parses_cursor_and_rejects_offset_combination() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "search",
+        "oauth",
+        "--cursor",
+        "sc1.abc.2.1700000000.0123456789abcdef",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Search(args) => {
+            assert_eq!(
+                args.cursor.as_deref(),
+                Some("sc1.abc.2.1700000000.0123456789abcdef")
+            );
+        }
+        _ => panic!("expected search command"),
+    }
+
+    assert!(
+        Cli::try_parse_from([
+            "axiomsync",
+            "search",
+            "oauth",
+            "--cursor",
+            "sc1.abc.2.1700000000.0123456789abcdef",
+            "--offset",
+            "2",
+        ])
+        .is_err()
+    );
+
+    let cli = Cli::try_parse_from(["axiomsync", "search", "oauth", "--paginate"]).expect("parse");
+    match cli.command {
+        Commands::Search(args) => assert!(args.paginate),
+        _ => panic!("expected search command"),
+    }
+    assert!(
+        Cli::try_parse_from([
+            "axiomsync",
+            "search",
+            "oauth",
+            "--paginate",
+            "--cursor",
+            "sc1.abc.2.1700000000.0123456789abcdef",
+        ])
+        .is_err()
+    );
+}
+
+#[test]
+fn search_parses_explain_and_rejects_markdown_combination() {
+    let cli = Cli::try_parse_from(["axiomsync", "search", "oauth", "--explain"]).expect("parse");
+    match cli.command {
+        Commands::Search(args) => {
+            let SearchArgs { explain, .. } = *args;
+            assert!(explain);
+        }
+        _ => panic!("expected search command"),
+    }
+
+    let conflict = Cli::try_parse_from(["axiomsync", "search", "oauth", "--explain", "--markdown"]);
+    assert!(conflict.is_err());
+}
+
+#[test]
+fn search_parses_repeatable_target_flag() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "search",
+        "oauth",
+        "--target",
+        "axiom://resources/docs",
+        "--target",
+        "axiom://user/memories",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Search(args) => {
+            let SearchArgs { targets, .. } = *args;
+            assert_eq!(
+                targets,
+                vec!["axiom://resources/docs", "axiom://user/memories"]
+            );
+        }
+        _ => panic!("expected search command"),
+    }
+}
+
+#[test]
+fn search_parses_profile_flag_and_profile_subcommands() {
+    let cli =
+        Cli::try_parse_from(["axiomsync", "search", "oauth", "--profile", "work"]).expect("parse");
+    match cli.command {
+        Commands::Search(args) => {
+            assert_eq!(args.query.as_deref(), Some("oauth"));
+            assert_eq!(args.profile.as_deref(), Some("work"));
+            assert!(args.command.is_none());
+        }
+        _ => panic!("expected search command"),
+    }
+
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "search",
+        "profile",
+        "add",
+        "work",
+        "--target",
+        "axiom://resources/work",
+        "--tag",
+        "auth",
+        "--limit",
+        "5",
+    ])
+    .expect("parse profile add");
+    match cli.command {
+        Commands::Search(args) => match args.command {
+            Some(SearchCommand::Profile {
+                command:
+                    SearchProfileCommand::Add {
+                        name,
+                        target,
+                        tags,
+                        limit,
+                        ..
+                    },
+            }) => {
+                assert_eq!(name, "work");
+                assert_eq!(target.as_deref(), Some("axiom://resources/work"));
+                assert_eq!(tags, vec!["auth".to_string()]);
+                assert_eq!(limit, Some(5));
+            }
+            other => panic!("expected search profile add, got {other:?}"),
+        },
+        _ => panic!("expected search command"),
+    }
+
+    let cli = Cli::try_parse_from(["axiomsync", "search", "profile", "rm", "work"])
+        .expect("parse profile rm");
+    assert!(matches!(
+        cli.command,
+        Commands::Search(args) if matches!(
+            args.command,
+            Some(SearchCommand::Profile { command: SearchProfileCommand::Rm { ref name } }) if name == "work"
+        )
+    ));
+}
+
+#[test]
+fn search_rejects_out_of_range_score_threshold() {
+    let parsed = Cli::try_parse_from(["axiomsync", "search", "oauth", "--score-threshold", "1.5"]);
+    assert!(
+        parsed.is_err(),
+        "score threshold above 1.0 must be rejected"
+    );
+}
+
+#[test]
+fn search_rejects_min_match_tokens_below_two() {
+    let parsed = Cli::try_parse_from([
+        "axiomsync",
+        "search",
+        "oauth callback",
+        "--min-match-tokens",
+        "1",
+    ]);
+    assert!(parsed.is_err(), "min-match-tokens below 2 must be rejected");
+}
+
+#[test]
+fn queue_daemon_rejects_zero_idle_cycles() {
+    let parsed = Cli::try_parse_from(["axiomsync", "queue", "daemon", "--idle-cycles", "0"]);
+    assert!(parsed.is_err(), "idle-cycles must be >= 1");
 }
 
 #[test]
@@ -804,3 +1449,550 @@ fn release_pack_rejects_zero_benchmark_window_size() {
     ]);
     assert!(parsed.is_err(), "benchmark-window-size must be >= 1");
 }
+
+#[test]
+fn session_import_parses_bundle_path_with_optional_id_and_force() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "session",
+        "import",
+        "bundle.json",
+        "--id",
+        "s-copy",
+        "--force",
+    ])
+    .expect("parse");
+
+    match cli.command {
+        Commands::Session(SessionArgs {
+            command:
+                SessionCommand::Import {
+                    file,
+                    id,
+                    force,
+                    archive,
+                },
+        }) => {
+            assert_eq!(file, PathBuf::from("bundle.json"));
+            assert_eq!(id.as_deref(), Some("s-copy"));
+            assert!(force);
+            assert!(!archive);
+        }
+        _ => panic!("expected session import command"),
+    }
+
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "session",
+        "export",
+        "--id",
+        "s-1",
+        "--to",
+        "s-1.ovpack",
+        "--archive",
+    ])
+    .expect("parse archive export");
+    assert!(matches!(
+        cli.command,
+        Commands::Session(SessionArgs {
+            command: SessionCommand::Export { archive: true, .. },
+        })
+    ));
+
+    let export = Cli::try_parse_from(["axiomsync", "session", "export", "--id", "s-1"]);
+    assert!(export.is_err(), "session export requires --to");
+}
+
+#[test]
+fn session_commit_parses_keep_recent() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "session",
+        "commit",
+        "--id",
+        "s-1",
+        "--keep-recent",
+        "20",
+    ])
+    .expect("parse");
+
+    match cli.command {
+        Commands::Session(SessionArgs {
+            command: SessionCommand::Commit { id, keep_recent },
+        }) => {
+            assert_eq!(id, "s-1");
+            assert_eq!(keep_recent, Some(20));
+        }
+        _ => panic!("expected session commit command"),
+    }
+}
+
+#[test]
+fn mkdir_parses_no_parents_flag() {
+    let cli = Cli::try_parse_from(["axiomsync", "mkdir", "axiom://resources/a/b"]).expect("parse");
+    match cli.command {
+        Commands::Mkdir(MkdirArgs { uri, no_parents }) => {
+            assert_eq!(uri, "axiom://resources/a/b");
+            assert!(!no_parents);
+        }
+        _ => panic!("expected mkdir command"),
+    }
+
+    let cli = Cli::try_parse_from(["axiomsync", "mkdir", "--no-parents", "axiom://resources/a"])
+        .expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Mkdir(MkdirArgs {
+            no_parents: true,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn ls_parses_page_flags_and_requires_limit_for_offset() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "ls",
+        "axiom://resources",
+        "--limit",
+        "20",
+        "--offset",
+        "40",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Ls(args) => {
+            assert_eq!(args.limit, Some(20));
+            assert_eq!(args.offset, 40);
+        }
+        _ => panic!("expected ls command"),
+    }
+
+    assert!(
+        Cli::try_parse_from(["axiomsync", "ls", "axiom://resources", "--offset", "5"]).is_err()
+    );
+    assert!(Cli::try_parse_from(["axiomsync", "ls", "axiom://resources", "--limit", "0"]).is_err());
+}
+
+#[test]
+fn rm_parses_glob_mode_and_keeps_single_uri_mode() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "rm",
+        "--glob",
+        "*.tmp.md",
+        "--uri",
+        "axiom://user/notes",
+        "--dry-run",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Rm(args) => {
+            assert_eq!(args.glob.as_deref(), Some("*.tmp.md"));
+            assert_eq!(args.glob_base_uri.as_deref(), Some("axiom://user/notes"));
+            assert!(args.dry_run);
+            assert!(args.uri.is_none());
+        }
+        _ => panic!("expected rm command"),
+    }
+
+    let cli = Cli::try_parse_from(["axiomsync", "rm", "axiom://resources/a", "--recursive"])
+        .expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Rm(RemoveArgs {
+            uri: Some(_),
+            recursive: true,
+            glob: None,
+            ..
+        })
+    ));
+
+    assert!(Cli::try_parse_from(["axiomsync", "rm"]).is_err());
+    assert!(
+        Cli::try_parse_from(["axiomsync", "rm", "axiom://resources/a", "--glob", "*.md"]).is_err()
+    );
+}
+
+#[test]
+fn rm_permanent_flag_and_trash_subcommands_parse() {
+    let cli = Cli::try_parse_from(["axiomsync", "rm", "axiom://resources/a", "--permanent"])
+        .expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Rm(RemoveArgs {
+            permanent: true,
+            ..
+        })
+    ));
+    let cli =
+        Cli::try_parse_from(["axiomsync", "rm", "--glob", "*.md", "--permanent"]).expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Rm(RemoveArgs {
+            permanent: true,
+            glob: Some(_),
+            ..
+        })
+    ));
+
+    let cli = Cli::try_parse_from(["axiomsync", "trash", "list", "--limit", "5"]).expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Trash(TrashArgs {
+            command: TrashCommand::List { limit: 5 }
+        })
+    ));
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "trash",
+        "restore",
+        "axiom://trash/20260101T000000000000000Z/resources/a",
+    ])
+    .expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Trash(TrashArgs {
+            command: TrashCommand::Restore { ref trash_uri }
+        }) if trash_uri.ends_with("/resources/a")
+    ));
+    let cli = Cli::try_parse_from(["axiomsync", "trash", "empty", "--older-than-days", "30"])
+        .expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Trash(TrashArgs {
+            command: TrashCommand::Empty {
+                older_than_days: 30
+            }
+        })
+    ));
+}
+
+#[test]
+fn queue_show_requeue_and_purge_parse_event_id() {
+    for (action, expected) in [("show", 0), ("requeue", 1), ("purge", 2)] {
+        let cli = Cli::try_parse_from(["axiomsync", "queue", action, "42"]).expect("parse");
+        let Commands::Queue(QueueArgs { command }) = cli.command else {
+            panic!("expected queue command");
+        };
+        let parsed = match command {
+            QueueCommand::Show { event_id } => (0, event_id),
+            QueueCommand::Requeue { event_id } => (1, event_id),
+            QueueCommand::Purge { event_id } => (2, event_id),
+            _ => panic!("unexpected queue command"),
+        };
+        assert_eq!(parsed, (expected, 42));
+    }
+
+    assert!(Cli::try_parse_from(["axiomsync", "queue", "purge"]).is_err());
+}
+
+#[test]
+fn queue_dead_letter_list_and_requeue_parse() {
+    let cli = Cli::try_parse_from(["axiomsync", "queue", "dead-letter", "list", "--limit", "5"])
+        .expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Queue(QueueArgs {
+            command: QueueCommand::DeadLetter {
+                command: QueueDeadLetterCommand::List { limit: 5 }
+            }
+        })
+    ));
+
+    let cli =
+        Cli::try_parse_from(["axiomsync", "queue", "dead-letter", "requeue", "42"]).expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Queue(QueueArgs {
+            command: QueueCommand::DeadLetter {
+                command: QueueDeadLetterCommand::Requeue { event_id: 42 }
+            }
+        })
+    ));
+    assert!(Cli::try_parse_from(["axiomsync", "queue", "dead-letter", "requeue"]).is_err());
+}
+
+#[test]
+fn tag_add_rm_and_ls_parse_uri_and_tags() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "tag",
+        "add",
+        "axiom://resources/a.md",
+        "auth",
+        "review",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Tag(TagArgs {
+            command: TagCommand::Add { uri, tags },
+        }) => {
+            assert_eq!(uri, "axiom://resources/a.md");
+            assert_eq!(tags, vec!["auth".to_string(), "review".to_string()]);
+        }
+        _ => panic!("expected tag add command"),
+    }
+    let cli = Cli::try_parse_from(["axiomsync", "tag", "rm", "axiom://resources/a.md", "auth"])
+        .expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Tag(TagArgs {
+            command: TagCommand::Rm { .. }
+        })
+    ));
+    let cli =
+        Cli::try_parse_from(["axiomsync", "tag", "ls", "axiom://resources/a.md"]).expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Tag(TagArgs {
+            command: TagCommand::Ls { .. }
+        })
+    ));
+    assert!(Cli::try_parse_from(["axiomsync", "tag", "add", "axiom://resources/a.md"]).is_err());
+}
+
+#[test]
+fn queue_work_and_daemon_parse_workers_flag() {
+    let cli = Cli::try_parse_from(["axiomsync", "queue", "work", "--workers", "4"]).expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Queue(QueueArgs {
+            command: QueueCommand::Work { workers: 4, .. },
+        })
+    ));
+
+    let cli = Cli::try_parse_from(["axiomsync", "queue", "daemon"]).expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Queue(QueueArgs {
+            command: QueueCommand::Daemon { workers: 1, .. },
+        })
+    ));
+
+    assert!(Cli::try_parse_from(["axiomsync", "queue", "work", "--workers", "0"]).is_err());
+}
+
+#[test]
+fn reconcile_parses_fix_and_rejects_fix_with_dry_run() {
+    let cli = Cli::try_parse_from(["axiomsync", "reconcile", "--fix"]).expect("parse");
+    match cli.command {
+        Commands::Reconcile(args) => {
+            assert!(args.fix);
+            assert!(!args.dry_run);
+        }
+        _ => panic!("expected reconcile command"),
+    }
+
+    let conflict = Cli::try_parse_from(["axiomsync", "reconcile", "--fix", "--dry-run"]);
+    assert!(conflict.is_err());
+}
+
+#[test]
+fn compact_parses_retention_flags_and_dry_run() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "compact",
+        "--traces-days",
+        "7",
+        "--reports-keep",
+        "3",
+        "--dry-run",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Compact(args) => {
+            assert_eq!(args.traces_days, 7);
+            assert_eq!(args.reports_keep, 3);
+            assert_eq!(args.reports_days, 30);
+            assert_eq!(args.request_log_max_rows, 10_000);
+            assert!(args.dry_run);
+        }
+        _ => panic!("expected compact command"),
+    }
+}
+
+#[test]
+fn workspace_config_flags_parse_and_conflict_with_root() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "--workspace-config",
+        "workspace.toml",
+        "--workspace-root",
+        "work",
+        "search",
+        "oauth",
+    ])
+    .expect("parse");
+    assert_eq!(
+        cli.workspace_config,
+        Some(std::path::PathBuf::from("workspace.toml"))
+    );
+    assert_eq!(cli.workspace_root.as_deref(), Some("work"));
+    assert!(matches!(cli.command, Commands::Search(_)));
+
+    let fanout = Cli::try_parse_from([
+        "axiomsync",
+        "--workspace-config",
+        "workspace.toml",
+        "search",
+        "oauth",
+    ])
+    .expect("parse fanout");
+    assert_eq!(fanout.workspace_root, None);
+
+    let conflict = Cli::try_parse_from([
+        "axiomsync",
+        "--root",
+        ".axiomsync",
+        "--workspace-config",
+        "workspace.toml",
+        "search",
+        "oauth",
+    ]);
+    assert!(conflict.is_err());
+    let orphan = Cli::try_parse_from(["axiomsync", "--workspace-root", "work", "search", "oauth"]);
+    assert!(orphan.is_err());
+}
+
+#[test]
+fn maintenance_compact_parses() {
+    let cli = Cli::try_parse_from(["axiomsync", "maintenance", "compact"]).expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Maintenance(crate::cli::MaintenanceArgs {
+            command: crate::cli::MaintenanceCommand::Compact
+        })
+    ));
+}
+
+#[test]
+fn backup_parses_destination_path() {
+    let cli = Cli::try_parse_from(["axiomsync", "backup", "snapshots/context.db"]).expect("parse");
+    match cli.command {
+        Commands::Backup(args) => {
+            assert_eq!(args.path, std::path::PathBuf::from("snapshots/context.db"));
+        }
+        _ => panic!("expected backup command"),
+    }
+    assert!(Cli::try_parse_from(["axiomsync", "backup"]).is_err());
+}
+
+#[test]
+fn session_add_parses_metadata_json_and_repeatable_attach_flags() {
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "session",
+        "add",
+        "--id",
+        "s1",
+        "--role",
+        "assistant",
+        "--text",
+        "ran the tool",
+        "--metadata-json",
+        "{\"tool\":\"grep\"}",
+        "--attach",
+        "axiom://resources/a.md",
+        "--attach",
+        "axiom://resources/b.md",
+    ])
+    .expect("parse");
+    match cli.command {
+        Commands::Session(SessionArgs {
+            command:
+                SessionCommand::Add {
+                    metadata_json,
+                    attachments,
+                    ..
+                },
+        }) => {
+            assert_eq!(metadata_json.as_deref(), Some("{\"tool\":\"grep\"}"));
+            assert_eq!(
+                attachments,
+                vec!["axiom://resources/a.md", "axiom://resources/b.md"]
+            );
+        }
+        _ => panic!("expected session add command"),
+    }
+
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "session",
+        "om",
+        "reset",
+        "--id",
+        "s1",
+        "--keep-buffered",
+    ])
+    .expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Session(SessionArgs {
+            command: SessionCommand::Om {
+                command: SessionOmCommand::Reset {
+                    ref id,
+                    keep_buffered: true,
+                },
+            },
+        }) if id == "s1"
+    ));
+
+    let cli =
+        Cli::try_parse_from(["axiomsync", "session", "reflect", "--id", "s1"]).expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Session(SessionArgs {
+            command: SessionCommand::Reflect { ref id },
+        }) if id == "s1"
+    ));
+
+    let cli = Cli::try_parse_from(["axiomsync", "session", "show", "--id", "s1"]).expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Session(SessionArgs {
+            command: SessionCommand::Show {
+                ref id,
+                memory_stats: false,
+            },
+        }) if id == "s1"
+    ));
+
+    let cli = Cli::try_parse_from([
+        "axiomsync",
+        "session",
+        "show",
+        "--id",
+        "s1",
+        "--memory-stats",
+    ])
+    .expect("parse");
+    assert!(matches!(
+        cli.command,
+        Commands::Session(SessionArgs {
+            command: SessionCommand::Show {
+                ref id,
+                memory_stats: true,
+            },
+        }) if id == "s1"
+    ));
+}
+
+#[test]
+fn trace_diff_parses_two_positional_trace_ids() {
+    let cli = Cli::try_parse_from(["axiomsync", "trace", "diff", "t-old", "t-new"]).expect("parse");
+    match cli.command {
+        Commands::Trace(TraceArgs {
+            command:
+                TraceCommand::Diff {
+                    trace_id_a,
+                    trace_id_b,
+                },
+        }) => {
+            assert_eq!(trace_id_a, "t-old");
+            assert_eq!(trace_id_b, "t-new");
+        }
+        _ => panic!("expected trace diff command"),
+    }
+}
@@ -14,6 +14,9 @@ pub enum TraceCommand {
         operation: Option<String>,
         #[arg(long)]
         status: Option<String>,
+        /// Emit matching entries as NDJSON lines instead of a JSON array.
+        #[arg(long, default_value_t = false)]
+        export: bool,
     },
     List {
         #[arg(long, default_value_t = 20)]
@@ -27,6 +30,11 @@ pub enum TraceCommand {
         #[arg(long)]
         limit: Option<usize>,
     },
+    /// Compare the final hit lists of two persisted traces.
+    Diff {
+        trace_id_a: String,
+        trace_id_b: String,
+    },
     Stats {
         #[arg(long, default_value_t = 100)]
         limit: usize,
@@ -0,0 +1,24 @@
+use clap::{Args, Subcommand};
+
+#[derive(Debug, Args)]
+pub struct TrashArgs {
+    #[command(subcommand)]
+    pub command: TrashCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TrashCommand {
+    /// List trashed entries, most recently deleted first.
+    List {
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+    },
+    /// Move a trashed entry back to its original uri.
+    Restore { trash_uri: String },
+    /// Permanently delete trashed entries.
+    Empty {
+        /// Only purge entries trashed more than this many days ago (0 purges everything).
+        #[arg(long, default_value_t = 0)]
+        older_than_days: u32,
+    },
+}
@@ -11,27 +11,43 @@ use crate::fs::LocalContextFs;
 use crate::index::InMemoryIndex;
 use crate::ontology::CompiledOntologySchema;
 use crate::parse::ParserRegistry;
-use crate::retrieval::{DrrConfig, DrrEngine};
+use crate::quota::ScopeQuota;
+use crate::remote::{HttpRemoteFetcher, RemoteFetcher};
+use crate::retrieval::{DrrConfig, DrrEngine, Reranker};
 use crate::state::SqliteStateStore;
 use crate::uri::AxiomUri;
 
 mod benchmark;
 mod eval;
+mod fault;
 mod indexing;
+mod maintenance;
 mod markdown_editor;
 mod mirror_outbox;
 mod om_bridge;
 mod ontology;
 mod queue_reconcile;
+mod reconcile_integrity;
 mod relation;
 mod release;
 mod request_log;
 mod resource;
 mod runtime;
 mod search;
+mod stat;
+mod tags;
 mod trace;
+mod trash;
+mod watch;
+mod workspace;
 
 pub use benchmark::BenchmarkFixtureCreateOptions;
+pub use watch::WatchHandle;
+pub use workspace::AxiomSyncWorkspace;
+
+pub(crate) use fault::FaultInjector;
+use indexing::EmbeddingCacheCounters;
+use search::{QueryResultCache, default_reranker};
 
 type DocumentEditGate = Arc<RwLock<()>>;
 type WeakDocumentEditGate = Weak<RwLock<()>>;
@@ -94,6 +110,12 @@ pub struct AxiomSync {
     ontology_schema_cache: Arc<RwLock<Option<OntologySchemaCacheEntry>>>,
     parser_registry: ParserRegistry,
     drr: DrrEngine,
+    embedding_cache_counters: Arc<EmbeddingCacheCounters>,
+    query_result_cache: Arc<QueryResultCache>,
+    reranker: Arc<dyn Reranker>,
+    custom_reranker: bool,
+    remote_fetcher: Arc<dyn RemoteFetcher>,
+    fault_injector: Arc<FaultInjector>,
 }
 
 impl std::fmt::Debug for AxiomSync {
@@ -108,9 +130,22 @@ impl AxiomSync {
         fs::create_dir_all(&root)?;
         let config = Arc::new(AppConfig::from_env()?);
         crate::embedding::configure_runtime(config.embedding.clone())?;
-        let fs = LocalContextFs::new(&root);
         let state = SqliteStateStore::open(resolve_state_store_path(&root)?)?;
+        let fault_injector = Arc::new(FaultInjector::new(config.fault.clone()));
+        let mut fs = LocalContextFs::new(&root).with_fault_injector(Arc::clone(&fault_injector));
+        if !config.quota.is_empty() {
+            fs = fs.with_quota(Arc::new(ScopeQuota::new(
+                config.quota.clone(),
+                state.clone(),
+                root.clone(),
+            )));
+        }
         let index = Arc::new(RwLock::new(InMemoryIndex::new()));
+        let reranker = default_reranker(config.search.reranker.as_deref(), &index);
+        let drr = DrrEngine::new(DrrConfig {
+            hybrid_weights: config.search.hybrid_weights,
+            ..DrrConfig::default()
+        });
 
         Ok(Self {
             fs,
@@ -120,10 +155,36 @@ impl AxiomSync {
             markdown_edit_gates: Arc::new(MarkdownEditGates::default()),
             ontology_schema_cache: Arc::new(RwLock::new(None)),
             parser_registry: ParserRegistry::new(),
-            drr: DrrEngine::new(DrrConfig::default()),
+            drr,
+            embedding_cache_counters: Arc::new(EmbeddingCacheCounters::default()),
+            query_result_cache: Arc::new(QueryResultCache::default()),
+            reranker,
+            custom_reranker: false,
+            remote_fetcher: Arc::new(HttpRemoteFetcher),
+            fault_injector,
         })
     }
 
+    /// Replaces the `AXIOMSYNC_RERANKER` default. Existing clones keep their reranker.
+    #[must_use]
+    pub fn with_reranker(mut self, reranker: Box<dyn Reranker>) -> Self {
+        self.reranker = Arc::from(reranker);
+        self.custom_reranker = true;
+        self
+    }
+
+    /// Replaces the default HTTP fetcher; host allowlist and size checks still apply.
+    #[must_use]
+    pub fn with_remote_fetcher(mut self, fetcher: Box<dyn RemoteFetcher>) -> Self {
+        self.remote_fetcher = Arc::from(fetcher);
+        self
+    }
+
+    #[must_use]
+    pub fn reranker_name(&self) -> &str {
+        self.reranker.name()
+    }
+
     pub fn bootstrap(&self) -> Result<()> {
         self.fs.initialize()?;
         self.ensure_default_ontology_schema()?;
@@ -23,11 +23,13 @@ struct GateConfig {
     min_stress_top1_accuracy: Option<f32>,
     max_p95_regression_pct: Option<f32>,
     max_top1_regression_pct: Option<f32>,
+    max_mrr_regression_pct: Option<f32>,
     window_size: usize,
     required_passes: usize,
     record: bool,
     write_release_check: bool,
     require_release_embedder: bool,
+    max_p95_ms_at_concurrency: Option<(usize, u128)>,
 }
 
 impl GateConfig {
@@ -39,10 +41,12 @@ impl GateConfig {
             min_stress_top1_accuracy,
             max_p95_regression_pct,
             max_top1_regression_pct,
+            max_mrr_regression_pct,
             window_size,
             required_passes,
             record,
             write_release_check,
+            max_p95_ms_at_concurrency,
         } = options;
         let gate_profile = normalize_gate_profile(&gate_profile);
         let window_size = window_size.max(1);
@@ -57,11 +61,13 @@ impl GateConfig {
             min_stress_top1_accuracy,
             max_p95_regression_pct,
             max_top1_regression_pct,
+            max_mrr_regression_pct,
             window_size,
             required_passes,
             record,
             write_release_check,
             require_release_embedder,
+            max_p95_ms_at_concurrency,
         }
     }
 
@@ -81,34 +87,43 @@ struct GateSnapshot {
     semantic_recall_regression_pct: Option<f32>,
     embedding_provider: Option<String>,
     embedding_strict_error: Option<String>,
+    corpus_snapshot_mismatch: Option<String>,
 }
 
 impl GateSnapshot {
     fn from_reports(reports: &[BenchmarkReport]) -> Self {
         let latest = reports.first().cloned().map(to_benchmark_summary);
         let previous = reports.get(1).cloned().map(to_benchmark_summary);
+        let corpus_snapshot_mismatch = match (reports.first(), reports.get(1)) {
+            (Some(current), Some(prev)) => corpus_snapshot_mismatch(current, prev),
+            _ => None,
+        };
+        // Runs over different corpora are not compared for regressions.
+        let comparable_previous = reports
+            .get(1)
+            .filter(|_| corpus_snapshot_mismatch.is_none());
 
-        let regression_pct = match (reports.first(), reports.get(1)) {
+        let regression_pct = match (reports.first(), comparable_previous) {
             (Some(current), Some(prev)) => percent_delta_u128(
                 p95_latency_for_regression(current),
                 p95_latency_for_regression(prev),
             ),
             _ => None,
         };
-        let top1_regression_pct = match (latest.as_ref(), previous.as_ref()) {
+        let top1_regression_pct = match (reports.first(), comparable_previous) {
             (Some(current), Some(prev)) => {
-                percent_drop_f32(current.top1_accuracy, prev.top1_accuracy)
+                percent_drop_f32(current.quality.top1_accuracy, prev.quality.top1_accuracy)
             }
             _ => None,
         };
 
-        let semantic_ndcg_regression_pct = match (reports.first(), reports.get(1)) {
+        let semantic_ndcg_regression_pct = match (reports.first(), comparable_previous) {
             (Some(current), Some(prev)) if semantic_quality_regression_eligible(current, prev) => {
                 percent_drop_f32(current.quality.ndcg_at_10, prev.quality.ndcg_at_10)
             }
             _ => None,
         };
-        let semantic_recall_regression_pct = match (reports.first(), reports.get(1)) {
+        let semantic_recall_regression_pct = match (reports.first(), comparable_previous) {
             (Some(current), Some(prev)) if semantic_quality_regression_eligible(current, prev) => {
                 percent_drop_f32(current.quality.recall_at_10, prev.quality.recall_at_10)
             }
@@ -129,6 +144,7 @@ impl GateSnapshot {
             embedding_strict_error: reports
                 .first()
                 .and_then(|report| report.environment.embedding_strict_error.clone()),
+            corpus_snapshot_mismatch,
         }
     }
 }
@@ -167,10 +183,12 @@ impl AxiomSync {
             min_stress_top1_accuracy: None,
             max_p95_regression_pct,
             max_top1_regression_pct: None,
+            max_mrr_regression_pct: None,
             window_size,
             required_passes,
             record,
             write_release_check: false,
+            max_p95_ms_at_concurrency: None,
         })
     }
 
@@ -217,6 +235,8 @@ impl AxiomSync {
                         "min_stress_top1_accuracy": result.thresholds.min_stress_top1_accuracy,
                         "max_p95_regression_pct": result.thresholds.max_p95_regression_pct,
                         "max_top1_regression_pct": result.thresholds.max_top1_regression_pct,
+                        "max_mrr_regression_pct": result.thresholds.max_mrr_regression_pct,
+                        "max_p95_ms_at_concurrency": result.thresholds.max_p95_ms_at_concurrency,
                         "semantic_regression_pct_max": MAX_SEMANTIC_QUALITY_REGRESSION_PCT,
                         "window_size": result.quorum.window_size,
                         "required_passes": result.quorum.required_passes,
@@ -247,6 +267,8 @@ impl AxiomSync {
                         "min_stress_top1_accuracy": config.min_stress_top1_accuracy,
                         "max_p95_regression_pct": config.max_p95_regression_pct,
                         "max_top1_regression_pct": config.max_top1_regression_pct,
+                        "max_mrr_regression_pct": config.max_mrr_regression_pct,
+                        "max_p95_ms_at_concurrency": config.max_p95_ms_at_concurrency,
                         "semantic_regression_pct_max": MAX_SEMANTIC_QUALITY_REGRESSION_PCT,
                         "window_size": config.window_size,
                         "required_passes": config.required_passes,
@@ -284,6 +306,8 @@ fn empty_gate_result(config: &GateConfig, snapshot: &GateSnapshot) -> BenchmarkG
             min_stress_top1_accuracy: config.min_stress_top1_accuracy,
             max_p95_regression_pct: config.max_p95_regression_pct,
             max_top1_regression_pct: config.max_top1_regression_pct,
+            max_mrr_regression_pct: config.max_mrr_regression_pct,
+            max_p95_ms_at_concurrency: config.max_p95_ms_at_concurrency,
         },
         quorum: BenchmarkGateQuorum {
             window_size: config.window_size,
@@ -295,6 +319,7 @@ fn empty_gate_result(config: &GateConfig, snapshot: &GateSnapshot) -> BenchmarkG
             regression_pct: snapshot.regression_pct,
             top1_regression_pct: snapshot.top1_regression_pct,
             stress_top1_accuracy: snapshot.stress_top1_accuracy,
+            corpus_snapshot_mismatch: snapshot.corpus_snapshot_mismatch.clone(),
         },
         execution: BenchmarkGateExecution {
             evaluated_runs: 0,
@@ -330,6 +355,8 @@ fn build_gate_result(
             min_stress_top1_accuracy: config.min_stress_top1_accuracy,
             max_p95_regression_pct: config.max_p95_regression_pct,
             max_top1_regression_pct: config.max_top1_regression_pct,
+            max_mrr_regression_pct: config.max_mrr_regression_pct,
+            max_p95_ms_at_concurrency: config.max_p95_ms_at_concurrency,
         },
         quorum: BenchmarkGateQuorum {
             window_size: config.window_size,
@@ -341,6 +368,7 @@ fn build_gate_result(
             regression_pct: snapshot.regression_pct,
             top1_regression_pct: snapshot.top1_regression_pct,
             stress_top1_accuracy: snapshot.stress_top1_accuracy,
+            corpus_snapshot_mismatch: snapshot.corpus_snapshot_mismatch.clone(),
         },
         execution: BenchmarkGateExecution {
             evaluated_runs,
@@ -412,6 +440,10 @@ fn evaluate_gate_outcome(
         ));
     }
 
+    if let Some(mismatch) = snapshot.corpus_snapshot_mismatch.as_deref() {
+        reasons.push(format!("corpus_snapshot_mismatch:{mismatch}"));
+    }
+
     if passed {
         reasons.push("ok".to_string());
     }
@@ -442,6 +474,27 @@ fn evaluate_gate_run(
         ));
     }
 
+    if let Some((concurrency, max_p95_ms)) = config.max_p95_ms_at_concurrency {
+        match report
+            .concurrency_profiles
+            .iter()
+            .find(|profile| profile.concurrency == concurrency)
+        {
+            Some(profile) if profile.latency.p95_ms > max_p95_ms => {
+                passed = false;
+                reasons.push(format!(
+                    "concurrency_p95_latency_exceeded:{concurrency}:{}>{max_p95_ms}",
+                    profile.latency.p95_ms
+                ));
+            }
+            Some(_) => {}
+            None => {
+                passed = false;
+                reasons.push(format!("concurrency_profile_missing:{concurrency}"));
+            }
+        }
+    }
+
     let run_stress_top1_accuracy = stress_top1_accuracy(report);
     if let Some(min_stress_top1_accuracy) = config.min_stress_top1_accuracy {
         if let Some(stress_accuracy) = run_stress_top1_accuracy {
@@ -457,6 +510,12 @@ fn evaluate_gate_run(
         }
     }
 
+    let run_corpus_mismatch = prev_report.and_then(|prev| corpus_snapshot_mismatch(report, prev));
+    if let Some(mismatch) = run_corpus_mismatch.as_deref() {
+        reasons.push(format!("corpus_snapshot_mismatch:{mismatch}"));
+    }
+    let prev_report = prev_report.filter(|_| run_corpus_mismatch.is_none());
+
     let run_regression_pct = prev_report.and_then(|prev| {
         percent_delta_u128(
             p95_latency_for_regression(report),
@@ -485,6 +544,18 @@ fn evaluate_gate_run(
         ));
     }
 
+    let run_mrr_regression_pct =
+        prev_report.and_then(|prev| percent_drop_f32(report.quality.mrr, prev.quality.mrr));
+    if let (Some(max_regression), Some(pct)) =
+        (config.max_mrr_regression_pct, run_mrr_regression_pct)
+        && pct > max_regression
+    {
+        passed = false;
+        reasons.push(format!(
+            "mrr_regression_exceeded:{pct:.2}%>{max_regression:.2}%"
+        ));
+    }
+
     if let Some(prev) = prev_report
         && semantic_quality_regression_eligible(report, prev)
     {
@@ -519,6 +590,7 @@ fn evaluate_gate_run(
         stress_top1_accuracy: run_stress_top1_accuracy,
         regression_pct: run_regression_pct,
         top1_regression_pct: run_top1_regression_pct,
+        mrr_regression_pct: run_mrr_regression_pct,
         reasons,
     }
 }
@@ -544,6 +616,15 @@ fn stress_top1_accuracy(report: &BenchmarkReport) -> Option<f32> {
     }
 }
 
+fn corpus_snapshot_mismatch(current: &BenchmarkReport, prev: &BenchmarkReport) -> Option<String> {
+    (current.corpus.snapshot_id != prev.corpus.snapshot_id).then(|| {
+        format!(
+            "{}!={}",
+            prev.corpus.snapshot_id, current.corpus.snapshot_id
+        )
+    })
+}
+
 fn p95_latency_for_regression(report: &BenchmarkReport) -> u128 {
     regression_latency_value(report.latency.find.p95_ms, report.latency.find.p95_us)
 }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::quality::{average_latency_ms, percentile_u128};
 
 pub(super) struct LatencySummary {
@@ -42,6 +44,15 @@ pub(super) fn safe_ratio_u128(num: u128, denom: usize) -> f32 {
     }
 }
 
+pub(super) fn per_second(count: usize, elapsed: Duration) -> f32 {
+    let seconds = elapsed.as_secs_f32();
+    if seconds <= 0.0 {
+        0.0
+    } else {
+        usize_to_f32(count) / seconds
+    }
+}
+
 pub(super) fn percent_delta_u128(current: u128, previous: u128) -> Option<f32> {
     if previous == 0 {
         None
@@ -1,4 +1,6 @@
-use std::time::Instant;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use chrono::Utc;
 
@@ -7,8 +9,9 @@ use crate::error::{AxiomError, Result};
 use crate::models::{
     BenchmarkAmortizedQualitySummary, BenchmarkAmortizedReport, BenchmarkAmortizedRunSummary,
     BenchmarkAmortizedSelection, BenchmarkAmortizedTiming, BenchmarkArtifacts, BenchmarkCaseResult,
-    BenchmarkLatencyProfile, BenchmarkLatencySummary, BenchmarkQualityMetrics, BenchmarkReport,
-    BenchmarkRunOptions, BenchmarkRunSelection, EvalQueryCase,
+    BenchmarkConcurrencyProfile, BenchmarkLatencyProfile, BenchmarkLatencySummary,
+    BenchmarkQualityMetrics, BenchmarkReport, BenchmarkRunOptions, BenchmarkRunSelection,
+    EvalQueryCase,
 };
 use crate::quality::{
     build_benchmark_acceptance_result, build_benchmark_query_set_metadata, duration_to_latency_ms,
@@ -19,9 +22,13 @@ use crate::uri::uri_equivalent;
 use super::{
     AxiomSync,
     logging_service::BenchmarkRunLogContext,
-    metrics_service::{safe_ratio, safe_ratio_f32, safe_ratio_u128, summarize_latencies},
+    metrics_service::{
+        per_second, safe_ratio, safe_ratio_f32, safe_ratio_u128, summarize_latencies,
+    },
 };
 
+const MAX_BENCHMARK_CONCURRENCY: usize = 64;
+
 struct BenchmarkCaseMeasurement {
     result: BenchmarkCaseResult,
     find_latency_ms: u128,
@@ -31,6 +38,7 @@ struct BenchmarkCaseMeasurement {
     has_expectation: bool,
     recall_hit: bool,
     ndcg_gain: f32,
+    reciprocal_rank: f32,
 }
 
 #[derive(Default)]
@@ -45,6 +53,7 @@ struct BenchmarkEvaluation {
     graded_cases: usize,
     recall_hits: usize,
     ndcg_total: f32,
+    reciprocal_rank_total: f32,
 }
 
 #[derive(Debug, Clone)]
@@ -189,12 +198,23 @@ impl AxiomSync {
                 "benchmark must include at least one source (golden or trace)".to_string(),
             ));
         }
+        if let Some(levels) = options.concurrency.as_deref() {
+            validate_benchmark_concurrency_levels(levels)?;
+        }
 
         let created_at = Utc::now().to_rfc3339();
         let query_cases = self.collect_benchmark_query_cases(options, run.query_limit)?;
         let query_set =
             build_benchmark_query_set_metadata(&query_cases, options.fixture_name.as_deref());
         let corpus = self.collect_benchmark_corpus_metadata()?;
+        if let Some(pinned) = options.corpus_snapshot_id.as_deref()
+            && pinned != corpus.snapshot_id
+        {
+            return Err(AxiomError::Conflict(format!(
+                "benchmark corpus snapshot mismatch: pinned {pinned}, current {}",
+                corpus.snapshot_id
+            )));
+        }
 
         let case_set_uri = self.write_benchmark_case_set(&run.run_id, &query_cases)?;
         let evaluation = self.evaluate_benchmark_cases(&query_cases, run.search_limit)?;
@@ -202,6 +222,15 @@ impl AxiomSync {
         let find_summary_us = summarize_latencies(&evaluation.find_latencies_us);
         let search_summary = summarize_latencies(&evaluation.search_latencies);
         let search_summary_us = summarize_latencies(&evaluation.search_latencies_us);
+        let concurrency_profiles = options
+            .concurrency
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|&concurrency| {
+                self.measure_benchmark_concurrency(&query_cases, run.search_limit, concurrency)
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         let (commit_latencies, commit_latencies_us) =
             self.measure_benchmark_commit_latencies_with_units(5)?;
@@ -213,6 +242,7 @@ impl AxiomSync {
         let top1_accuracy = safe_ratio(evaluation.passed, evaluation.graded_cases);
         let ndcg_at_10 = safe_ratio_f32(evaluation.ndcg_total, evaluation.graded_cases);
         let recall_at_10 = safe_ratio(evaluation.recall_hits, evaluation.graded_cases);
+        let mrr = safe_ratio_f32(evaluation.reciprocal_rank_total, evaluation.graded_cases);
         let error_rate = safe_ratio(evaluation.failed, executed_cases);
         let acceptance = build_benchmark_acceptance_result(
             find_summary.p95,
@@ -241,6 +271,7 @@ impl AxiomSync {
                 top1_accuracy,
                 ndcg_at_10,
                 recall_at_10,
+                mrr,
                 error_rate,
             },
             latency: BenchmarkLatencyProfile {
@@ -282,6 +313,7 @@ impl AxiomSync {
                 case_set_uri,
             },
             results: evaluation.results,
+            concurrency_profiles,
         };
         self.write_benchmark_report_artifacts(&report)?;
 
@@ -313,6 +345,7 @@ impl AxiomSync {
             }
             if measurement.has_expectation {
                 evaluation.graded_cases += 1;
+                evaluation.reciprocal_rank_total += measurement.reciprocal_rank;
                 if measurement.recall_hit {
                     evaluation.recall_hits += 1;
                     evaluation.ndcg_total += measurement.ndcg_gain;
@@ -323,6 +356,77 @@ impl AxiomSync {
         Ok(evaluation)
     }
 
+    /// Runs the case set once across `concurrency` workers sharing cloned handles.
+    fn measure_benchmark_concurrency(
+        &self,
+        query_cases: &[EvalQueryCase],
+        search_limit: usize,
+        concurrency: usize,
+    ) -> Result<BenchmarkConcurrencyProfile> {
+        let next_case = AtomicUsize::new(0);
+        let started = Instant::now();
+        let worker_samples = thread::scope(|scope| {
+            let workers = (0..concurrency)
+                .map(|_| {
+                    let app = self.clone();
+                    let next_case = &next_case;
+                    scope.spawn(move || -> Result<Vec<Duration>> {
+                        let mut samples = Vec::new();
+                        while let Some(case) =
+                            query_cases.get(next_case.fetch_add(1, Ordering::Relaxed))
+                        {
+                            let query_started = Instant::now();
+                            app.eval_result_uris(
+                                &case.query,
+                                case.target_uri.as_deref(),
+                                search_limit,
+                                "benchmark_concurrency",
+                            )?;
+                            samples.push(query_started.elapsed());
+                        }
+                        Ok(samples)
+                    })
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .map(|worker| {
+                    worker.join().map_err(|_| {
+                        AxiomError::Internal("benchmark concurrency worker panicked".to_string())
+                    })?
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+        let wall = started.elapsed();
+
+        let samples = worker_samples.into_iter().flatten().collect::<Vec<_>>();
+        let latencies = samples
+            .iter()
+            .map(|elapsed| duration_to_latency_ms(*elapsed))
+            .collect::<Vec<_>>();
+        let latencies_us = samples
+            .iter()
+            .map(|elapsed| duration_to_latency_us(*elapsed))
+            .collect::<Vec<_>>();
+        let summary = summarize_latencies(&latencies);
+        let summary_us = summarize_latencies(&latencies_us);
+        Ok(BenchmarkConcurrencyProfile {
+            concurrency,
+            executed_queries: samples.len(),
+            wall_ms: duration_to_latency_ms(wall),
+            queries_per_second: per_second(samples.len(), wall),
+            latency: BenchmarkLatencySummary {
+                p50_ms: summary.p50,
+                p95_ms: summary.p95,
+                p99_ms: summary.p99,
+                p50_us: Some(summary_us.p50),
+                p95_us: Some(summary_us.p95),
+                p99_us: Some(summary_us.p99),
+                avg_ms: summary.avg,
+            },
+        })
+    }
+
     fn measure_benchmark_case(
         &self,
         case: &EvalQueryCase,
@@ -362,6 +466,9 @@ impl AxiomSync {
                 0.0
             }
         });
+        let reciprocal_rank = expected_rank.map_or(0.0, |rank| {
+            1.0 / f32::from(u16::try_from(rank).unwrap_or(u16::MAX))
+        });
 
         Ok(BenchmarkCaseMeasurement {
             result: BenchmarkCaseResult {
@@ -382,6 +489,24 @@ impl AxiomSync {
             has_expectation,
             recall_hit,
             ndcg_gain,
+            reciprocal_rank,
         })
     }
 }
+
+fn validate_benchmark_concurrency_levels(levels: &[usize]) -> Result<()> {
+    if levels.is_empty() {
+        return Err(AxiomError::Validation(
+            "benchmark concurrency must list at least one level".to_string(),
+        ));
+    }
+    if let Some(invalid) = levels
+        .iter()
+        .find(|level| !(1..=MAX_BENCHMARK_CONCURRENCY).contains(*level))
+    {
+        return Err(AxiomError::Validation(format!(
+            "benchmark concurrency must be within 1..={MAX_BENCHMARK_CONCURRENCY}: {invalid}"
+        )));
+    }
+    Ok(())
+}
@@ -50,8 +50,14 @@ impl AxiomSync {
             include_stress: options.include_stress,
             trace_expectations: options.trace_expectations,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         };
-        let cases = self.collect_benchmark_query_cases(&run_options, options.query_limit.max(1))?;
+        let mut cases =
+            self.collect_benchmark_query_cases(&run_options, options.query_limit.max(1))?;
+        for case in &mut cases {
+            case.query = self.redact_query_text(case.target_uri.as_deref(), &case.query);
+        }
         let fixture_uri = benchmark_fixture_uri(trimmed)?;
         let document = BenchmarkFixtureDocument {
             version: 1,
@@ -142,6 +148,7 @@ impl AxiomSync {
                 delta_p95_latency_ms: None,
                 delta_p95_latency_us: None,
                 delta_top1_accuracy: None,
+                delta_mrr: None,
                 status: "no_data".to_string(),
             });
         }
@@ -172,6 +179,10 @@ impl AxiomSync {
             (Some(l), Some(p)) => Some(l.top1_accuracy - p.top1_accuracy),
             _ => None,
         };
+        let delta_mrr = latest
+            .as_ref()
+            .zip(previous.as_ref())
+            .map(|(latest, previous)| latest.mrr - previous.mrr);
 
         let status = match (delta_p95_latency_ms, delta_top1_accuracy) {
             (None, None) => "insufficient_history",
@@ -187,6 +198,7 @@ impl AxiomSync {
             delta_p95_latency_ms,
             delta_p95_latency_us,
             delta_top1_accuracy,
+            delta_mrr,
             status,
         })
     }
@@ -0,0 +1,81 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::config::{FaultConfig, FaultPoint};
+use crate::error::{AxiomError, Result};
+
+/// Injects failures per `FaultConfig`; decisions depend only on seed and call order.
+#[derive(Debug, Default)]
+pub(crate) struct FaultInjector {
+    config: FaultConfig,
+    calls: [AtomicU64; FaultPoint::ALL.len()],
+}
+
+impl FaultInjector {
+    pub(crate) fn new(config: FaultConfig) -> Self {
+        Self {
+            config,
+            calls: Default::default(),
+        }
+    }
+
+    pub(crate) fn check(&self, point: FaultPoint) -> Result<()> {
+        let Some(rule) = self.config.rule(point) else {
+            return Ok(());
+        };
+        let call = self.calls[point as usize].fetch_add(1, Ordering::Relaxed);
+        if !should_fail(rule.seed, point, call, rule.fail_rate) {
+            return Ok(());
+        }
+        let message = format!("injected {} fault (call {call})", point.as_str());
+        Err(match point {
+            FaultPoint::Embedding => AxiomError::Embedding(message),
+            FaultPoint::SqliteLock => AxiomError::Sqlite(rusqlite::Error::SqliteFailure(
+                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+                Some(message),
+            )),
+            FaultPoint::FsWrite => AxiomError::Io(std::io::Error::other(message)),
+        })
+    }
+}
+
+fn should_fail(seed: u64, point: FaultPoint, call: u64, fail_rate: f64) -> bool {
+    if fail_rate >= 1.0 {
+        return true;
+    }
+    if fail_rate <= 0.0 {
+        return false;
+    }
+    let hash = blake3::hash(format!("{seed}:{}:{call}", point.as_str()).as_bytes());
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&hash.as_bytes()[..8]);
+    let sample = (u64::from_le_bytes(bytes) >> 11) as f64 / (1u64 << 53) as f64;
+    sample < fail_rate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::parse_fault_spec;
+
+    #[test]
+    fn fault_injector_is_deterministic_for_a_seed() {
+        let outcomes = |spec: &str| {
+            let injector = FaultInjector::new(parse_fault_spec(spec).expect("spec"));
+            (0..64)
+                .map(|_| injector.check(FaultPoint::Embedding).is_err())
+                .collect::<Vec<_>>()
+        };
+        let first = outcomes("embedding:fail_rate=0.5,seed=7");
+        assert_eq!(first, outcomes("embedding:fail_rate=0.5,seed=7"));
+        assert_ne!(first, outcomes("embedding:fail_rate=0.5,seed=8"));
+        assert!(first.iter().any(|failed| *failed) && first.iter().any(|failed| !*failed));
+
+        let injector =
+            FaultInjector::new(parse_fault_spec("sqlite_lock:fail_rate=1").expect("spec"));
+        assert!(injector.check(FaultPoint::Embedding).is_ok());
+        let err = injector
+            .check(FaultPoint::SqliteLock)
+            .expect_err("sqlite fault");
+        assert_eq!(err.code(), "SQLITE_ERROR");
+    }
+}
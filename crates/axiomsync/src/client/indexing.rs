@@ -1,17 +1,21 @@
 use std::fmt::Write as _;
 use std::fs;
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use walkdir::WalkDir;
 
-use crate::config::{InternalTierPolicy, TierSynthesisMode, should_persist_scope_tiers};
+use crate::config::{
+    FaultPoint, InternalTierPolicy, TierSynthesisMode, should_persist_scope_tiers,
+};
 #[cfg(test)]
 use crate::config::{resolve_internal_tier_policy, resolve_tier_synthesis_mode};
 use crate::context_ops::{RecordInput, build_record, classify_context, infer_tags};
 use crate::error::{AxiomError, Result};
+use crate::index::{InMemoryIndex, embedding_text_hash};
 use crate::mime::infer_mime_from_name;
-use crate::models::IndexRecord;
-use crate::models::QueueEventStatus;
+use crate::models::{EmbeddingCacheStatus, IndexRecord, QueueEventStatus};
+use crate::state::PersistedSearchVector;
 use crate::tier_documents::{
     abstract_path, overview_path, read_abstract, read_overview, write_tiers,
 };
@@ -30,6 +34,25 @@ use helpers::{
     synthesize_directory_tiers,
 };
 
+/// Per-process embedding cache hit/miss counters.
+#[derive(Debug, Default)]
+pub(super) struct EmbeddingCacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl EmbeddingCacheCounters {
+    fn record(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+pub(super) fn persisted_vector_version() -> String {
+    let embed = crate::embedding::embedding_profile();
+    format!("{}@{}:{}", embed.provider, embed.vector_version, embed.dim)
+}
+
 fn append_truncated_markdown_heading_index(text: &mut String, headings: &[String]) {
     if headings.is_empty() {
         return;
@@ -271,6 +294,7 @@ impl AxiomSync {
             Scope::Session,
             Scope::Temp,
             Scope::Queue,
+            Scope::Trash,
         ] {
             if !should_persist_scope_tiers(scope, internal_policy) {
                 self.prune_generated_tiers_recursive(&AxiomUri::root(scope))?;
@@ -334,6 +358,65 @@ impl AxiomSync {
         Ok(())
     }
 
+    /// Upserts into the memory index, reusing the record's persisted embedding when its embedded
+    /// text is unchanged. With the embedding cache enabled, any stored document vector with the
+    /// same text hash is reused too. New vectors are written back to `search_doc_vectors`.
+    pub(super) fn upsert_index_record_with_vector(
+        &self,
+        index: &mut InMemoryIndex,
+        record: IndexRecord,
+        persisted: Option<PersistedSearchVector>,
+    ) -> Result<()> {
+        self.fault_injector.check(FaultPoint::Embedding)?;
+        let persistence = self.config.indexing.vector_persistence;
+        let cache = self.config.indexing.embedding_cache;
+        if !persistence && !cache {
+            index.upsert(record);
+            return Ok(());
+        }
+        let text_hash = embedding_text_hash(&record);
+        let vector_version = persisted_vector_version();
+        let dim = crate::embedding::embedding_profile().dim;
+        let mut reusable = persisted
+            .filter(|stored| stored.text_hash == text_hash && stored.vector.len() == dim)
+            .map(|stored| stored.vector);
+        let reused_persisted = reusable.is_some();
+        if cache && reusable.is_none() {
+            reusable = self
+                .state
+                .get_cached_embedding(&text_hash, &vector_version)?
+                .filter(|vector| vector.len() == dim);
+            self.embedding_cache_counters.record(reusable.is_some());
+        }
+        let uri = record.uri.clone();
+        index.upsert_with_vector(record, reusable);
+        let Some(vector) = index.vector(&uri) else {
+            return Ok(());
+        };
+        if !reused_persisted {
+            self.state
+                .upsert_search_vector(&uri, &vector_version, &text_hash, vector)?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn embedding_cache_status(&self) -> Result<EmbeddingCacheStatus> {
+        Ok(EmbeddingCacheStatus {
+            enabled: self.config.indexing.embedding_cache,
+            entries: self
+                .state
+                .search_vector_count(&persisted_vector_version())?,
+            hits: self.embedding_cache_counters.hits.load(Ordering::Relaxed),
+            misses: self.embedding_cache_counters.misses.load(Ordering::Relaxed),
+        })
+    }
+
+    /// Drops every stored document vector and returns the number removed.
+    /// Counters in `backend_status().embedding_cache` are left untouched.
+    pub fn clear_embedding_cache(&self) -> Result<usize> {
+        self.state.clear_search_vectors()
+    }
+
     fn maybe_upsert_index_record(
         &self,
         record: IndexRecord,
@@ -344,22 +427,32 @@ impl AxiomSync {
         let uri = record.uri.clone();
         let current_state = self.state.get_index_state(&uri)?;
         let state_changed = index_state_changed(current_state.as_ref(), hash, mtime);
-        let index_missing = self
+        let (index_missing, tags_changed) = self
             .index
             .read()
             .map_err(|_| AxiomError::lock_poisoned("index"))?
             .get(&uri)
-            .is_none();
-        let needs_upsert = state_changed || index_missing;
+            .map_or((true, false), |existing| {
+                (false, existing.tags != record.tags)
+            });
+        let needs_upsert = state_changed || index_missing || tags_changed;
         if !needs_upsert {
             return Ok(());
         }
 
         self.state.upsert_search_document(&record)?;
-        self.index
+        let persisted = if self.config.indexing.vector_persistence {
+            self.state
+                .get_search_vector(&uri, &persisted_vector_version())?
+        } else {
+            None
+        };
+        let mut index = self
+            .index
             .write()
-            .map_err(|_| AxiomError::lock_poisoned("index"))?
-            .upsert(record);
+            .map_err(|_| AxiomError::lock_poisoned("index"))?;
+        self.upsert_index_record_with_vector(&mut index, record, persisted)?;
+        drop(index);
 
         if state_changed {
             self.state
@@ -423,7 +516,7 @@ impl AxiomSync {
         self.maybe_upsert_index_record(record, &hash, mtime, "dir")
     }
 
-    fn index_file_entry(&self, uri: &AxiomUri, path: &Path) -> Result<()> {
+    pub(super) fn index_file_entry(&self, uri: &AxiomUri, path: &Path) -> Result<()> {
         let name = path
             .file_name()
             .and_then(|segment| segment.to_str())
@@ -486,8 +579,10 @@ impl AxiomSync {
             "doc_class:{}",
             infer_doc_class_tag(&context_type, &name, &parser)
         ));
-        tags.sort();
-        tags.dedup();
+        tags.extend(self.state.resource_tags_for_uri(&uri.to_string())?);
+        self.state
+            .document_tag_overrides(&uri.to_string())?
+            .apply(&mut tags);
         let record = build_record(RecordInput {
             uri,
             parent_uri: uri.parent().as_ref(),
@@ -577,6 +672,34 @@ impl AxiomSync {
         Ok(())
     }
 
+    /// Per-file `semantic_scan` target; also refreshes tiers of parents whose listing changed.
+    pub(super) fn reindex_file_target(&self, file_uri: &AxiomUri) -> Result<()> {
+        if file_uri.scope().is_internal() {
+            return Ok(());
+        }
+        let file_path = self.fs.resolve_uri(file_uri);
+        if file_path.is_file() {
+            self.index_file_entry(file_uri, &file_path)?;
+        }
+        let Some(parent_uri) = file_uri.parent() else {
+            return Ok(());
+        };
+
+        let internal_policy = self.config.indexing.internal_tier_policy;
+        let tier_mode = self.config.indexing.tier_synthesis_mode;
+        for dir_uri in directory_ancestor_chain(&parent_uri) {
+            let dir_path = self.fs.resolve_uri(&dir_uri);
+            if !dir_path.is_dir() {
+                continue;
+            }
+            if should_persist_scope_tiers(dir_uri.scope(), internal_policy) {
+                self.ensure_directory_tiers(&dir_uri)?;
+            }
+            self.index_directory_entry(&dir_uri, &dir_path, internal_policy, tier_mode)?;
+        }
+        Ok(())
+    }
+
     pub(super) fn reindex_scopes(&self, scopes: &[Scope]) -> Result<()> {
         let scope_set = scopes
             .iter()
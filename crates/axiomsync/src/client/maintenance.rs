@@ -0,0 +1,321 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::catalog::{
+    benchmark_reports_uri, eval_reports_uri, maintenance_report_uri, request_log_uri,
+};
+use crate::error::{AxiomError, Result};
+use crate::models::{
+    CompactCategoryReport, CompactOptions, CompactReport, StateBackupReport, StateCompactReport,
+};
+use crate::uri::AxiomUri;
+
+use super::AxiomSync;
+
+const COMPACT_CATEGORY_TRACES: &str = "traces";
+const COMPACT_CATEGORY_REQUEST_LOGS: &str = "request_logs";
+const COMPACT_CATEGORY_REPORTS: &str = "reports";
+const COMPACT_CATEGORY_SQLITE: &str = "sqlite";
+
+/// A run's `.json` and `.md` reports are kept or removed together.
+#[derive(Debug, Default)]
+struct ReportRun {
+    modified: Option<DateTime<Utc>>,
+    paths: Vec<PathBuf>,
+    bytes: u64,
+}
+
+impl AxiomSync {
+    /// Prunes old traces, excess request log lines and old reports, then `VACUUM`s SQLite.
+    /// The report is written under `axiom://queue/maintenance/` even on dry runs.
+    pub fn compact(&self, options: CompactOptions) -> Result<CompactReport> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+
+        let output = (|| -> Result<CompactReport> {
+            let now = Utc::now();
+            let categories = vec![
+                self.compact_traces(
+                    now - Duration::days(i64::from(options.trace_retention_days)),
+                    options.dry_run,
+                )?,
+                self.compact_reports(
+                    now - Duration::days(i64::from(options.report_retention_days)),
+                    options.reports_keep,
+                    options.dry_run,
+                )?,
+                self.compact_request_log(options.request_log_max_rows, options.dry_run)?,
+                self.compact_sqlite(options.dry_run)?,
+            ];
+            let run_id = uuid::Uuid::new_v4().to_string();
+            let report_uri = maintenance_report_uri(&run_id)?;
+            let report = CompactReport {
+                run_id,
+                dry_run: options.dry_run,
+                options,
+                reclaimed_bytes_total: categories
+                    .iter()
+                    .map(|category| category.reclaimed_bytes)
+                    .sum(),
+                categories,
+                report_uri: report_uri.to_string(),
+                created_at: now.to_rfc3339(),
+            };
+            self.fs
+                .write(&report_uri, &serde_json::to_string_pretty(&report)?, true)?;
+            Ok(report)
+        })();
+
+        match output {
+            Ok(report) => {
+                self.log_request_status(
+                    request_id,
+                    "maintenance.compact",
+                    "ok",
+                    started,
+                    None,
+                    Some(serde_json::json!({
+                        "dry_run": report.dry_run,
+                        "reclaimed_bytes_total": report.reclaimed_bytes_total,
+                        "report_uri": report.report_uri,
+                    })),
+                );
+                Ok(report)
+            }
+            Err(err) => {
+                self.log_request_error(
+                    request_id,
+                    "maintenance.compact",
+                    started,
+                    None,
+                    &err,
+                    Some(serde_json::json!({ "dry_run": options.dry_run })),
+                );
+                Err(err)
+            }
+        }
+    }
+
+    /// `VACUUM`s the state DB and truncates its WAL without pruning traces or reports.
+    pub fn compact_state(&self) -> Result<StateCompactReport> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+
+        match self.state.compact() {
+            Ok(report) => {
+                self.log_request_status(
+                    request_id,
+                    "maintenance.compact_state",
+                    "ok",
+                    started,
+                    None,
+                    Some(serde_json::json!({
+                        "before_bytes": report.before_bytes,
+                        "after_bytes": report.after_bytes,
+                        "reclaimed_bytes": report.reclaimed_bytes,
+                    })),
+                );
+                Ok(report)
+            }
+            Err(err) => {
+                self.log_request_error(
+                    request_id,
+                    "maintenance.compact_state",
+                    started,
+                    None,
+                    &err,
+                    None,
+                );
+                Err(err)
+            }
+        }
+    }
+
+    /// Copies the live state DB to `path` with SQLite online backup; a directory or the DB itself is rejected.
+    pub fn backup_state(&self, path: impl AsRef<Path>) -> Result<StateBackupReport> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let path = path.as_ref();
+
+        let output = (|| -> Result<StateBackupReport> {
+            if path.is_dir() {
+                return Err(AxiomError::Validation(format!(
+                    "backup target is a directory: {}",
+                    path.display()
+                )));
+            }
+            let live = fs::canonicalize(super::resolve_state_store_path(self.fs.root())?)?;
+            if fs::canonicalize(path).is_ok_and(|target| target == live) {
+                return Err(AxiomError::Validation(format!(
+                    "backup target is the live state database: {}",
+                    path.display()
+                )));
+            }
+            self.state.backup_to(path)?;
+            Ok(StateBackupReport {
+                path: path.display().to_string(),
+                size_bytes: fs::metadata(path)?.len(),
+                created_at: Utc::now().to_rfc3339(),
+            })
+        })();
+
+        match output {
+            Ok(report) => {
+                self.log_request_status(
+                    request_id,
+                    "maintenance.backup",
+                    "ok",
+                    started,
+                    None,
+                    Some(serde_json::json!({
+                        "path": report.path,
+                        "size_bytes": report.size_bytes,
+                    })),
+                );
+                Ok(report)
+            }
+            Err(err) => {
+                self.log_request_error(
+                    request_id,
+                    "maintenance.backup",
+                    started,
+                    None,
+                    &err,
+                    Some(serde_json::json!({ "path": path.display().to_string() })),
+                );
+                Err(err)
+            }
+        }
+    }
+
+    fn compact_traces(
+        &self,
+        cutoff: DateTime<Utc>,
+        dry_run: bool,
+    ) -> Result<CompactCategoryReport> {
+        let expired = self.state.list_trace_index_before(&cutoff.to_rfc3339())?;
+        let mut reclaimed_bytes = 0u64;
+        for entry in &expired {
+            let Ok(uri) = AxiomUri::parse(&entry.uri) else {
+                continue;
+            };
+            reclaimed_bytes += fs::metadata(self.fs.resolve_uri(&uri)).map_or(0, |meta| meta.len());
+            if !dry_run {
+                self.fs.rm(&uri, false, true)?;
+            }
+        }
+        if !dry_run {
+            let trace_ids = expired
+                .iter()
+                .map(|entry| entry.trace_id.clone())
+                .collect::<Vec<_>>();
+            self.state.remove_trace_index(&trace_ids)?;
+        }
+        Ok(CompactCategoryReport {
+            category: COMPACT_CATEGORY_TRACES.to_string(),
+            removed: expired.len(),
+            reclaimed_bytes,
+        })
+    }
+
+    fn compact_reports(
+        &self,
+        cutoff: DateTime<Utc>,
+        keep: usize,
+        dry_run: bool,
+    ) -> Result<CompactCategoryReport> {
+        let mut removed = 0usize;
+        let mut reclaimed_bytes = 0u64;
+        for dir_uri in [benchmark_reports_uri()?, eval_reports_uri()?] {
+            let dir = self.fs.resolve_uri(&dir_uri);
+            if !dir.is_dir() {
+                continue;
+            }
+            let mut runs = BTreeMap::<String, ReportRun>::new();
+            for item in fs::read_dir(&dir)? {
+                let path = item?.path();
+                let meta = fs::symlink_metadata(&path)?;
+                if !meta.is_file() {
+                    continue;
+                }
+                let Some(stem) = path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                else {
+                    continue;
+                };
+                let run = runs.entry(stem).or_default();
+                let modified = meta.modified().ok().map(DateTime::<Utc>::from);
+                run.modified = run.modified.max(modified);
+                run.bytes += meta.len();
+                run.paths.push(path);
+            }
+
+            let mut runs = runs.into_values().collect::<Vec<_>>();
+            runs.sort_by_key(|run| std::cmp::Reverse(run.modified));
+            for run in runs.into_iter().skip(keep) {
+                if run.modified.is_some_and(|modified| modified >= cutoff) {
+                    continue;
+                }
+                removed += run.paths.len();
+                reclaimed_bytes += run.bytes;
+                if !dry_run {
+                    for path in &run.paths {
+                        self.fs.rm(&self.fs.uri_from_path(path)?, false, true)?;
+                    }
+                }
+            }
+        }
+        Ok(CompactCategoryReport {
+            category: COMPACT_CATEGORY_REPORTS.to_string(),
+            removed,
+            reclaimed_bytes,
+        })
+    }
+
+    fn compact_request_log(&self, max_rows: usize, dry_run: bool) -> Result<CompactCategoryReport> {
+        let uri = request_log_uri()?;
+        let mut report = CompactCategoryReport {
+            category: COMPACT_CATEGORY_REQUEST_LOGS.to_string(),
+            removed: 0,
+            reclaimed_bytes: 0,
+        };
+        if !self.fs.exists(&uri) {
+            return Ok(report);
+        }
+        let content = self.fs.read(&uri)?;
+        let lines = content.lines().collect::<Vec<_>>();
+        let Some(overflow) = lines.len().checked_sub(max_rows).filter(|n| *n > 0) else {
+            return Ok(report);
+        };
+        let (dropped, kept) = lines.split_at(overflow);
+        report.removed = dropped.len();
+        report.reclaimed_bytes = dropped.iter().map(|line| line.len() as u64 + 1).sum();
+        if !dry_run {
+            let mut rewritten = kept.join("\n");
+            if !rewritten.is_empty() {
+                rewritten.push('\n');
+            }
+            self.fs.write_atomic(&uri, &rewritten, true)?;
+        }
+        Ok(report)
+    }
+
+    fn compact_sqlite(&self, dry_run: bool) -> Result<CompactCategoryReport> {
+        let (_, free) = self.state.database_size_bytes()?;
+        let reclaimed_bytes = if dry_run {
+            free
+        } else {
+            self.state.compact()?.reclaimed_bytes
+        };
+        Ok(CompactCategoryReport {
+            category: COMPACT_CATEGORY_SQLITE.to_string(),
+            removed: 0,
+            reclaimed_bytes,
+        })
+    }
+}
@@ -5,7 +5,7 @@ use chrono::{DateTime, Utc};
 use serde_json::json;
 
 use crate::error::{AxiomError, Result};
-use crate::models::{MarkdownDocument, MarkdownSaveResult};
+use crate::models::{MarkdownDocument, MarkdownPatchOp, MarkdownPatchResult, MarkdownSaveResult};
 use crate::uri::{AxiomUri, Scope};
 
 use super::AxiomSync;
@@ -36,6 +36,22 @@ impl AxiomSync {
     ) -> Result<MarkdownSaveResult> {
         save_editor_document(self, uri, content, expected_etag, EditorMode::Markdown)
     }
+
+    /// Appends `content` and reindexes, creating the file if missing.
+    /// Writes with a single append so concurrent appends do not lose data.
+    pub fn append_markdown(&self, uri: &str, content: &str) -> Result<MarkdownSaveResult> {
+        append_markdown_document(self, uri, content)
+    }
+
+    /// Applies line-range ops atomically after the etag check; overlapping ranges are rejected.
+    pub fn patch_markdown(
+        &self,
+        uri: &str,
+        ops: Vec<MarkdownPatchOp>,
+        expected_etag: Option<&str>,
+    ) -> Result<MarkdownPatchResult> {
+        patch_markdown_document(self, uri, &ops, expected_etag)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -209,60 +225,255 @@ fn save_editor_document(
             .map_err(|_| AxiomError::lock_poisoned("markdown document edit gate"))?;
 
         let previous = app.fs.read(&uri)?;
-        let current_etag = markdown_etag(&previous);
-        if let Some(expected_etag) = expected_etag
-            && current_etag != expected_etag
-        {
-            return Err(AxiomError::Conflict(format!("etag mismatch for {uri}")));
+        ensure_etag_matches(&uri, &previous, expected_etag)?;
+        let (save_ms, reindex_ms) = commit_editor_write(app, &uri, &previous, content, mode)?;
+
+        let committed = app.fs.read(&uri)?;
+        Ok(MarkdownSaveResult {
+            uri: uri.to_string(),
+            etag: markdown_etag(&committed),
+            updated_at: uri_updated_at(app, &uri),
+            reindexed_root: parent_uri.to_string(),
+            save_ms,
+            reindex_ms,
+        })
+    })();
+
+    match output {
+        Ok(saved) => {
+            app.log_request_status(
+                request_id,
+                mode.save_operation(),
+                "ok",
+                started,
+                Some(target_uri),
+                Some(json!({
+                    "etag": &saved.etag,
+                    "content_bytes": content_bytes,
+                    "save_ms": saved.save_ms,
+                    "reindex_ms": saved.reindex_ms,
+                    "total_ms": started.elapsed().as_millis(),
+                    "reindexed_root": &saved.reindexed_root,
+                })),
+            );
+            Ok(saved)
         }
+        Err(err) => {
+            app.log_request_error(
+                request_id,
+                mode.save_operation(),
+                started,
+                Some(target_uri),
+                &err,
+                Some(json!({
+                    "content_bytes": content_bytes,
+                    "expected_etag_provided": expected_etag.is_some(),
+                })),
+            );
+            Err(err)
+        }
+    }
+}
+
+fn ensure_etag_matches(uri: &AxiomUri, previous: &str, expected_etag: Option<&str>) -> Result<()> {
+    let current_etag = markdown_etag(previous);
+    if let Some(expected_etag) = expected_etag
+        && current_etag != expected_etag
+    {
+        return Err(AxiomError::EtagMismatch {
+            uri: uri.to_string(),
+            expected_etag: expected_etag.to_string(),
+            current_etag,
+        });
+    }
+    Ok(())
+}
+
+/// Writes atomically and reindexes, restoring the old content if reindexing fails.
+fn commit_editor_write(
+    app: &AxiomSync,
+    uri: &AxiomUri,
+    previous: &str,
+    content: &str,
+    mode: EditorMode,
+) -> Result<(u128, u128)> {
+    let save_started = Instant::now();
+    app.fs.write_atomic(uri, content, false)?;
+    let save_ms = save_started.elapsed().as_millis();
+
+    let reindex_started = Instant::now();
+    if let Err(reindex_err) = app.reindex_document_with_ancestors(uri) {
+        let rollback_write = app.fs.write_atomic(uri, previous, false);
+        let rollback_reindex = if rollback_write.is_ok() {
+            app.reindex_document_with_ancestors(uri).err()
+        } else {
+            None
+        };
+        let rollback_write_status = rollback_write
+            .as_ref()
+            .map_or_else(|err| format!("err:{err}"), |()| "ok".to_string());
+        let rollback_reindex_status = rollback_reindex
+            .as_ref()
+            .map_or_else(|| "ok_or_skipped".to_string(), |err| format!("err:{err}"));
+        let label = mode.label();
+        return Err(AxiomError::Internal(format!(
+            "{label} save failed during reindex for {uri}: reindex_err={reindex_err}; rollback_write={rollback_write_status}; rollback_reindex={rollback_reindex_status}",
+        )));
+    }
+    Ok((save_ms, reindex_started.elapsed().as_millis()))
+}
+
+fn patch_markdown_document(
+    app: &AxiomSync,
+    uri: &str,
+    ops: &[MarkdownPatchOp],
+    expected_etag: Option<&str>,
+) -> Result<MarkdownPatchResult> {
+    let request_id = uuid::Uuid::new_v4().to_string();
+    let started = Instant::now();
+    let target_uri = uri.to_string();
+    let mode = EditorMode::Markdown;
+
+    let output = (|| -> Result<MarkdownPatchResult> {
+        let uri = AxiomUri::parse(uri)?;
+        validate_editor_target(app, &uri, mode, true)?;
+        let parent_uri = uri.parent().ok_or_else(|| {
+            AxiomError::Validation(format!("{} target must not be a scope root", mode.label()))
+        })?;
+        let uri_gate = app.markdown_gate_for_uri(&uri)?;
+
+        let _guard = uri_gate
+            .write()
+            .map_err(|_| AxiomError::lock_poisoned("markdown document edit gate"))?;
+
+        let previous = app.fs.read(&uri)?;
+        ensure_etag_matches(&uri, &previous, expected_etag)?;
+        let (content, changed_lines) = apply_markdown_patch(&previous, ops)?;
+        let (save_ms, reindex_ms) = commit_editor_write(app, &uri, &previous, &content, mode)?;
 
+        let committed = app.fs.read(&uri)?;
+        Ok(MarkdownPatchResult {
+            uri: uri.to_string(),
+            etag: markdown_etag(&committed),
+            updated_at: uri_updated_at(app, &uri),
+            reindexed_root: parent_uri.to_string(),
+            applied_ops: ops.len(),
+            changed_lines,
+            save_ms,
+            reindex_ms,
+        })
+    })();
+
+    match output {
+        Ok(patched) => {
+            app.log_request_status(
+                request_id,
+                "markdown.patch",
+                "ok",
+                started,
+                Some(target_uri),
+                Some(json!({
+                    "etag": &patched.etag,
+                    "ops": patched.applied_ops,
+                    "changed_lines": patched.changed_lines,
+                    "save_ms": patched.save_ms,
+                    "reindex_ms": patched.reindex_ms,
+                    "total_ms": started.elapsed().as_millis(),
+                })),
+            );
+            Ok(patched)
+        }
+        Err(err) => {
+            app.log_request_error(
+                request_id,
+                "markdown.patch",
+                started,
+                Some(target_uri),
+                &err,
+                Some(json!({
+                    "ops": ops.len(),
+                    "expected_etag_provided": expected_etag.is_some(),
+                })),
+            );
+            Err(err)
+        }
+    }
+}
+
+fn append_markdown_document(
+    app: &AxiomSync,
+    uri: &str,
+    content: &str,
+) -> Result<MarkdownSaveResult> {
+    let request_id = uuid::Uuid::new_v4().to_string();
+    let started = Instant::now();
+    let target_uri = uri.to_string();
+    let content_bytes = content.len();
+    let mode = EditorMode::Markdown;
+
+    let output = (|| -> Result<(MarkdownSaveResult, bool)> {
+        let uri = AxiomUri::parse(uri)?;
+        validate_editor_scope(&uri, mode)?;
+        validate_editor_file_target(app, &uri, mode, true)?;
+        let parent_uri = uri.parent().ok_or_else(|| {
+            AxiomError::Validation(format!("{} target must not be a scope root", mode.label()))
+        })?;
+        let uri_gate = app.markdown_gate_for_uri(&uri)?;
+
+        let _guard = uri_gate
+            .write()
+            .map_err(|_| AxiomError::lock_poisoned("markdown document edit gate"))?;
+
+        let previous = if app.fs.exists(&uri) {
+            Some(app.fs.read(&uri)?)
+        } else {
+            None
+        };
         let save_started = Instant::now();
-        app.fs.write_atomic(&uri, content, false)?;
+        app.fs.append(&uri, content, false)?;
         let save_ms = save_started.elapsed().as_millis();
 
         let reindex_started = Instant::now();
         if let Err(reindex_err) = app.reindex_document_with_ancestors(&uri) {
-            let rollback_write = app.fs.write_atomic(&uri, &previous, false);
-            let rollback_reindex = if rollback_write.is_ok() {
-                app.reindex_document_with_ancestors(&uri).err()
-            } else {
-                None
+            let rollback = match previous.as_deref() {
+                Some(previous) => app.fs.write_atomic(&uri, previous, false),
+                None => app.fs.rm(&uri, false, false),
             };
-            let rollback_write_status = rollback_write
+            let rollback_status = rollback
                 .as_ref()
                 .map_or_else(|err| format!("err:{err}"), |()| "ok".to_string());
-            let rollback_reindex_status = rollback_reindex
-                .as_ref()
-                .map_or_else(|| "ok_or_skipped".to_string(), |err| format!("err:{err}"));
-            let label = mode.label();
             return Err(AxiomError::Internal(format!(
-                "{label} save failed during reindex for {uri}: reindex_err={reindex_err}; rollback_write={rollback_write_status}; rollback_reindex={rollback_reindex_status}",
+                "markdown append failed during reindex for {uri}: reindex_err={reindex_err}; rollback={rollback_status}",
             )));
         }
         let reindex_ms = reindex_started.elapsed().as_millis();
 
         let committed = app.fs.read(&uri)?;
-        Ok(MarkdownSaveResult {
-            uri: uri.to_string(),
-            etag: markdown_etag(&committed),
-            updated_at: uri_updated_at(app, &uri),
-            reindexed_root: parent_uri.to_string(),
-            save_ms,
-            reindex_ms,
-        })
+        Ok((
+            MarkdownSaveResult {
+                uri: uri.to_string(),
+                etag: markdown_etag(&committed),
+                updated_at: uri_updated_at(app, &uri),
+                reindexed_root: parent_uri.to_string(),
+                save_ms,
+                reindex_ms,
+            },
+            previous.is_none(),
+        ))
     })();
 
     match output {
-        Ok(saved) => {
+        Ok((saved, created)) => {
             app.log_request_status(
                 request_id,
-                mode.save_operation(),
+                "markdown.append",
                 "ok",
                 started,
                 Some(target_uri),
                 Some(json!({
                     "etag": &saved.etag,
                     "content_bytes": content_bytes,
+                    "created": created,
                     "save_ms": saved.save_ms,
                     "reindex_ms": saved.reindex_ms,
                     "total_ms": started.elapsed().as_millis(),
@@ -274,13 +485,12 @@ fn save_editor_document(
         Err(err) => {
             app.log_request_error(
                 request_id,
-                mode.save_operation(),
+                "markdown.append",
                 started,
                 Some(target_uri),
                 &err,
                 Some(json!({
                     "content_bytes": content_bytes,
-                    "expected_etag_provided": expected_etag.is_some(),
                 })),
             );
             Err(err)
@@ -288,12 +498,87 @@ fn save_editor_document(
     }
 }
 
+/// Validates all ops against original line numbers, then applies them at once.
+/// Returns the new content and the changed line count.
+fn apply_markdown_patch(content: &str, ops: &[MarkdownPatchOp]) -> Result<(String, usize)> {
+    if ops.is_empty() {
+        return Err(AxiomError::Validation(
+            "markdown patch requires at least one op".to_string(),
+        ));
+    }
+
+    let trailing_newline = content.ends_with('\n');
+    let body = content.strip_suffix('\n').unwrap_or(content);
+    let lines: Vec<&str> = if content.is_empty() {
+        Vec::new()
+    } else {
+        body.split('\n').collect()
+    };
+
+    let mut sorted = ops.iter().collect::<Vec<_>>();
+    sorted.sort_by_key(|op| (op.start_line, op.end_line));
+    let mut previous_end: Option<(usize, usize)> = None;
+    for op in &sorted {
+        if op.start_line == 0 || op.end_line + 1 < op.start_line || op.end_line > lines.len() {
+            return Err(AxiomError::Validation(format!(
+                "markdown patch range out of bounds: {}..={} (document has {} lines)",
+                op.start_line,
+                op.end_line,
+                lines.len()
+            )));
+        }
+        if let Some((start, end)) = previous_end
+            && (op.start_line <= end || op.start_line == start)
+        {
+            return Err(AxiomError::Validation(format!(
+                "markdown patch ranges overlap: {start}..={end} and {}..={}",
+                op.start_line, op.end_line
+            )));
+        }
+        previous_end = Some((op.start_line, op.end_line));
+    }
+
+    let mut out: Vec<&str> = Vec::with_capacity(lines.len());
+    let mut cursor = 0usize;
+    let mut changed_lines = 0usize;
+    for op in sorted {
+        let start = op.start_line - 1;
+        out.extend_from_slice(&lines[cursor..start]);
+        let replacement = op.replacement.strip_suffix('\n').unwrap_or(&op.replacement);
+        let inserted = if op.replacement.is_empty() {
+            0
+        } else {
+            let before = out.len();
+            out.extend(replacement.split('\n'));
+            out.len() - before
+        };
+        let removed = op.end_line + 1 - op.start_line;
+        changed_lines += removed.max(inserted);
+        cursor = op.end_line;
+    }
+    out.extend_from_slice(&lines[cursor..]);
+
+    let mut patched = out.join("\n");
+    if !out.is_empty() && (trailing_newline || content.is_empty()) {
+        patched.push('\n');
+    }
+    Ok((patched, changed_lines))
+}
+
 fn validate_editor_target(
     app: &AxiomSync,
     uri: &AxiomUri,
     mode: EditorMode,
     for_save: bool,
 ) -> Result<String> {
+    validate_editor_scope(uri, mode)?;
+    if !app.fs.exists(uri) {
+        return Err(AxiomError::NotFound(uri.to_string()));
+    }
+    validate_editor_file_target(app, uri, mode, for_save)
+}
+
+fn validate_editor_scope(uri: &AxiomUri, mode: EditorMode) -> Result<()> {
     if !matches!(
         uri.scope(),
         Scope::Resources | Scope::User | Scope::Agent | Scope::Session
@@ -304,10 +589,16 @@ fn validate_editor_target(
             uri.scope()
         )));
     }
+    Ok(())
+}
 
-    if !app.fs.exists(uri) {
-        return Err(AxiomError::NotFound(uri.to_string()));
-    }
+/// Ignores existence; rejects directories, tier files and unsupported extensions.
+fn validate_editor_file_target(
+    app: &AxiomSync,
+    uri: &AxiomUri,
+    mode: EditorMode,
+    for_save: bool,
+) -> Result<String> {
     if app.fs.is_dir(uri) {
         return Err(AxiomError::Validation(format!(
             "{} target must be a file: {}",
@@ -1,6 +1,6 @@
 use crate::error::Result;
 use crate::models::OutboxEvent;
-use crate::state::{OmReflectionApplyContext, OmReflectionBufferPayload};
+use crate::state::{OmReflectionApplyContext, OmReflectionApplyOutcome, OmReflectionBufferPayload};
 use crate::uri::AxiomUri;
 
 use super::AxiomSync;
@@ -24,8 +24,12 @@ impl AxiomSync {
                     .remove_search_documents_with_prefix(&target.to_string())?;
                 self.state
                     .remove_index_state_with_prefix(&target.to_string())?;
-                self.ensure_tiers_recursive(&target)?;
-                self.reindex_uri_tree(&target)?;
+                if self.fs.resolve_uri(&target).is_dir() {
+                    self.ensure_tiers_recursive(&target)?;
+                    self.reindex_uri_tree(&target)?;
+                } else {
+                    self.reindex_file_target(&target)?;
+                }
                 Ok(true)
             }
             "upsert" | "reindex" | "delete" => Ok(true),
@@ -108,14 +112,27 @@ impl AxiomSync {
 
     fn handle_om_reflect_requested(&self, event: &OutboxEvent) -> Result<bool> {
         let payload = parse_om_reflect_requested_payload(&event.payload_json)?;
-        let scope_key = payload.scope_key.as_str();
-        let expected_generation = payload.expected_generation;
+        let _outcome = self.apply_om_reflect_request(
+            payload.scope_key.as_str(),
+            payload.expected_generation,
+            event.id,
+        )?;
+        Ok(true)
+    }
 
+    /// Reflects the observation as of `expected_generation` and applies it by generation CAS.
+    /// Returns `StaleGeneration` without calling the reflector if the record is gone or moved on.
+    pub(super) fn apply_om_reflect_request(
+        &self,
+        scope_key: &str,
+        expected_generation: u32,
+        outbox_event_id: i64,
+    ) -> Result<OmReflectionApplyOutcome> {
         let Some(record) = self.state.get_om_record_by_scope_key(scope_key)? else {
-            return Ok(true);
+            return Ok(OmReflectionApplyOutcome::StaleGeneration);
         };
         if record.generation_count != expected_generation {
-            return Ok(true);
+            return Ok(OmReflectionApplyOutcome::StaleGeneration);
         }
         let active_entries = self.state.list_om_active_entries(scope_key)?;
 
@@ -133,17 +150,16 @@ impl AxiomSync {
             &self.config.om.reflector,
             &active_entries,
         );
-        let _applied = self.state.apply_om_reflection_with_cas(
+        self.state.apply_om_reflection_with_cas(
             scope_key,
             expected_generation,
-            event.id,
+            outbox_event_id,
             &reflection.reflection,
             &covers_entry_ids,
             OmReflectionApplyContext {
                 current_task: reflection.current_task.as_deref(),
                 suggested_response: reflection.suggested_response.as_deref(),
             },
-        )?;
-        Ok(true)
+        )
     }
 }
@@ -1,16 +1,20 @@
 use std::time::Instant;
 
+use std::collections::HashSet;
+
 use crate::om::build_scope_key;
 
 use crate::error::{AxiomError, Result};
 use crate::models::{OutboxEvent, QueueEventStatus, ReplayReport};
 use crate::om_bridge::{
-    OM_OUTBOX_SCHEMA_VERSION_V1, OmHintReadRequestV1, OmHintReadStateV1, OmMessageAppendRequestV1,
-    OmMessageAppendResultV1, OmObserveBufferRequestedV1, OmOutboxEnqueueResultV1,
-    OmReflectBufferRequestedV1, OmReflectRequestedV1, OmReplayModeV1, OmReplayRequestV1,
-    OmReplayResultV1, OmScopeBindingInputV1, OmScopeV1,
+    OM_OUTBOX_SCHEMA_VERSION_V1, OM_STATE_EXPORT_VERSION_V1, OmForcedReflectionV1,
+    OmHintReadRequestV1, OmHintReadStateV1, OmMessageAppendRequestV1, OmMessageAppendResultV1,
+    OmMetricsV1, OmObserveBufferRequestedV1, OmOutboxEnqueueResultV1, OmReflectBufferRequestedV1,
+    OmReflectRequestedV1, OmReplayModeV1, OmReplayRequestV1, OmReplayResultV1,
+    OmScopeBindingInputV1, OmScopeV1, OmStateExportV1, OmStateThreadV1, OmStateView,
 };
-use crate::queue_policy::{retry_backoff_seconds, should_retry_event_error};
+use crate::session::resolve_om_scope_binding_for_session_with_config;
+use crate::state::{OmContinuationHints, OmReflectionApplyOutcome};
 
 use super::AxiomSync;
 
@@ -64,6 +68,324 @@ impl AxiomSync {
         self.fetch_session_om_state(session_id.as_str())
     }
 
+    /// Trigger totals and active observation tokens for the session OM record; `None` when OM is off or absent.
+    pub fn om_metrics(&self, session_id: &str) -> Result<Option<OmMetricsV1>> {
+        let session_id = non_empty("session_id", session_id)?;
+        Ok(self
+            .fetch_session_om_state(session_id.as_str())?
+            .map(|state| OmMetricsV1 {
+                scope_key: state.scope_key,
+                observer_trigger_count_total: state.observer_trigger_count_total,
+                reflector_trigger_count_total: state.reflector_trigger_count_total,
+                observation_tokens_active: state.observation_tokens_active,
+                last_activated_message_ids: state.activated_message_ids,
+            }))
+    }
+
+    /// Reads the session OM record's observation, buffered chunks and counters; `None` if absent.
+    pub fn om_state(&self, session_id: &str) -> Result<Option<OmStateView>> {
+        let session_id = non_empty("session_id", session_id)?;
+        let scope_binding =
+            resolve_om_scope_binding_for_session_with_config(&session_id, &self.config.om.scope)?;
+        let Some(record) = self.resolve_session_om_record(&session_id, &scope_binding)? else {
+            return Ok(None);
+        };
+        Ok(Some(OmStateView {
+            buffered_chunks: self.state.list_om_observation_chunks(&record.id)?,
+            scope_key: record.scope_key,
+            generation_count: record.generation_count,
+            active_observations: record.active_observations,
+            observation_token_count: record.observation_token_count,
+            pending_message_tokens: record.pending_message_tokens,
+            buffered_reflection: record.buffered_reflection,
+            observer_trigger_count_total: record.observer_trigger_count_total,
+            reflector_trigger_count_total: record.reflector_trigger_count_total,
+            last_activated_message_ids: record.last_activated_message_ids,
+            updated_at: record.updated_at.to_rfc3339(),
+        }))
+    }
+
+    /// Clears the session OM observation and counters, plus buffered chunks unless `keep_buffered`.
+    /// Bumps the generation so in-flight reflections go stale; false if there is no record.
+    pub fn om_reset(&self, session_id: &str, keep_buffered: bool) -> Result<bool> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let session_id = non_empty("session_id", session_id)?;
+
+        let output = (|| -> Result<bool> {
+            let scope_binding = resolve_om_scope_binding_for_session_with_config(
+                &session_id,
+                &self.config.om.scope,
+            )?;
+            let Some(record) = self.resolve_session_om_record(&session_id, &scope_binding)? else {
+                return Ok(false);
+            };
+            if !self
+                .state
+                .reset_om_record(&record.scope_key, keep_buffered)?
+            {
+                return Ok(false);
+            }
+            let record = self
+                .state
+                .get_om_record_by_scope_key(&record.scope_key)?
+                .ok_or_else(|| {
+                    AxiomError::Internal(format!(
+                        "om record missing after reset: {}",
+                        record.scope_key
+                    ))
+                })?;
+            self.index
+                .write()
+                .map_err(|_| AxiomError::lock_poisoned("index"))?
+                .upsert_om_record(record);
+            Ok(true)
+        })();
+
+        match output {
+            Ok(reset) => {
+                self.log_request_status(
+                    request_id,
+                    "om.reset",
+                    "ok",
+                    started,
+                    None,
+                    Some(serde_json::json!({
+                        "session_id": session_id,
+                        "keep_buffered": keep_buffered,
+                        "reset": reset,
+                    })),
+                );
+                Ok(reset)
+            }
+            Err(err) => {
+                self.log_request_error(
+                    request_id,
+                    "om.reset",
+                    started,
+                    None,
+                    &err,
+                    Some(serde_json::json!({
+                        "session_id": session_id,
+                        "keep_buffered": keep_buffered,
+                    })),
+                );
+                Err(err)
+            }
+        }
+    }
+
+    /// Runs the reflector on the current observation now, through the same outbox event and
+    /// generation CAS as automatic triggers; replays of the event are idempotent.
+    pub fn force_om_reflection(&self, session_id: &str) -> Result<OmForcedReflectionV1> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let session_id = non_empty("session_id", session_id)?;
+
+        let output = (|| -> Result<OmForcedReflectionV1> {
+            let scope_binding = resolve_om_scope_binding_for_session_with_config(
+                &session_id,
+                &self.config.om.scope,
+            )?;
+            let record = self
+                .resolve_session_om_record(&session_id, &scope_binding)?
+                .ok_or_else(|| {
+                    AxiomError::NotFound(format!("om state for session {session_id}"))
+                })?;
+            self.force_om_reflection_at_generation(&record.scope_key, record.generation_count)
+        })();
+
+        match output {
+            Ok(forced) => {
+                self.log_request_status(
+                    request_id,
+                    "om.reflect",
+                    "ok",
+                    started,
+                    None,
+                    Some(serde_json::json!({
+                        "session_id": session_id,
+                        "scope_key": forced.scope_key,
+                        "event_id": forced.event_id,
+                        "outcome": forced.outcome,
+                    })),
+                );
+                Ok(forced)
+            }
+            Err(err) => {
+                self.log_request_error(
+                    request_id,
+                    "om.reflect",
+                    started,
+                    None,
+                    &err,
+                    Some(serde_json::json!({
+                        "session_id": session_id,
+                    })),
+                );
+                Err(err)
+            }
+        }
+    }
+
+    pub(super) fn force_om_reflection_at_generation(
+        &self,
+        scope_key: &str,
+        expected_generation: u32,
+    ) -> Result<OmForcedReflectionV1> {
+        let enqueued = self.om_bridge_enqueue_reflect_request(OmReflectRequestedV1::new(
+            scope_key,
+            expected_generation,
+            chrono::Utc::now().to_rfc3339(),
+        ))?;
+        self.state
+            .increment_om_reflector_trigger_with_cas(scope_key, expected_generation)?;
+        // On failure the event stays so replay retries it under the normal policy.
+        let outcome =
+            self.apply_om_reflect_request(scope_key, expected_generation, enqueued.event_id)?;
+        self.state
+            .mark_outbox_status(enqueued.event_id, QueueEventStatus::Done, false)?;
+
+        let record = self
+            .state
+            .get_om_record_by_scope_key(scope_key)?
+            .ok_or_else(|| AxiomError::NotFound(format!("om record for scope {scope_key}")))?;
+        let generation_count = record.generation_count;
+        let reflector_trigger_count_total = record.reflector_trigger_count_total;
+        if outcome == OmReflectionApplyOutcome::Applied {
+            self.index
+                .write()
+                .map_err(|_| AxiomError::lock_poisoned("index"))?
+                .upsert_om_record(record);
+        }
+        Ok(OmForcedReflectionV1 {
+            scope_key: scope_key.to_string(),
+            event_id: enqueued.event_id,
+            outcome,
+            generation_count,
+            reflector_trigger_count_total,
+            apply_metrics: self.state.om_reflection_apply_metrics_snapshot()?,
+        })
+    }
+
+    /// Exports a session's OM state (records, buffered chunks, entries, thread state) as a versioned document.
+    pub fn export_om_state(&self, session_id: &str) -> Result<OmStateExportV1> {
+        let session_id = non_empty("session_id", session_id)?;
+        let scope_binding =
+            resolve_om_scope_binding_for_session_with_config(&session_id, &self.config.om.scope)?;
+        let record = self
+            .resolve_session_om_record(&session_id, &scope_binding)?
+            .ok_or_else(|| AxiomError::NotFound(format!("om state for session {session_id}")))?;
+        let scope_key = record.scope_key.clone();
+        let thread_states = self
+            .state
+            .list_om_thread_states(&scope_key)?
+            .into_iter()
+            .map(|state| OmStateThreadV1 {
+                thread_id: state.thread_id,
+                last_observed_at: state.last_observed_at.map(|at| at.to_rfc3339()),
+                current_task: state.current_task,
+                suggested_response: state.suggested_response,
+            })
+            .collect();
+        Ok(OmStateExportV1 {
+            version: OM_STATE_EXPORT_VERSION_V1,
+            scope_sessions: self.state.list_om_scope_sessions(&scope_key, usize::MAX)?,
+            observation_chunks: self.state.list_om_observation_chunks(&record.id)?,
+            entries: self.state.list_om_entries(&scope_key)?,
+            continuation_states: self.state.list_om_continuation_rows(&scope_key)?,
+            thread_states,
+            session_id,
+            scope_key,
+            record,
+        })
+    }
+
+    /// Applies an `export_om_state` document idempotently; other versions are `AxiomError::Validation`.
+    pub fn import_om_state(&self, doc: &OmStateExportV1) -> Result<String> {
+        if doc.version != OM_STATE_EXPORT_VERSION_V1 {
+            return Err(AxiomError::Validation(format!(
+                "unsupported om state export version: {} (expected {OM_STATE_EXPORT_VERSION_V1})",
+                doc.version
+            )));
+        }
+        let session_id = non_empty("session_id", &doc.session_id)?;
+        let scope_key = non_empty("scope_key", &doc.scope_key)?;
+        if doc.record.scope_key != scope_key {
+            return Err(AxiomError::Validation(format!(
+                "om state record scope_key {} does not match document scope_key {scope_key}",
+                doc.record.scope_key
+            )));
+        }
+
+        self.state.upsert_om_record(&doc.record)?;
+        let record = self
+            .state
+            .get_om_record_by_scope_key(&scope_key)?
+            .ok_or_else(|| {
+                AxiomError::Internal(format!("om record missing after import: {scope_key}"))
+            })?;
+
+        let existing_chunk_ids = self
+            .state
+            .list_om_observation_chunks(&record.id)?
+            .into_iter()
+            .map(|chunk| chunk.id)
+            .collect::<HashSet<_>>();
+        for chunk in &doc.observation_chunks {
+            if existing_chunk_ids.contains(&chunk.id) {
+                continue;
+            }
+            let mut chunk = chunk.clone();
+            chunk.record_id.clone_from(&record.id);
+            self.state.append_om_observation_chunk(&chunk)?;
+        }
+        // Restore superseded flags on entries recreated by chunk appends.
+        for entry in &doc.entries {
+            self.state.upsert_om_entry(&scope_key, entry)?;
+        }
+        for thread in &doc.thread_states {
+            let last_observed_at = thread
+                .last_observed_at
+                .as_deref()
+                .map(parse_rfc3339_field)
+                .transpose()?;
+            self.state.upsert_om_thread_state(
+                &scope_key,
+                &thread.thread_id,
+                last_observed_at,
+                thread.current_task.as_deref(),
+                thread.suggested_response.as_deref(),
+            )?;
+        }
+        for continuation in &doc.continuation_states {
+            self.state.upsert_om_continuation_state(
+                &scope_key,
+                &continuation.canonical_thread_id,
+                OmContinuationHints {
+                    current_task: continuation.current_task.as_deref(),
+                    suggested_response: continuation.suggested_response.as_deref(),
+                },
+                continuation.confidence,
+                &continuation.source_kind,
+                Some(parse_rfc3339_field(&continuation.updated_at)?),
+            )?;
+        }
+        self.state
+            .upsert_om_scope_session(&scope_key, &session_id)?;
+        for bound_session_id in &doc.scope_sessions {
+            self.state
+                .upsert_om_scope_session(&scope_key, bound_session_id)?;
+        }
+
+        let mut index = self
+            .index
+            .write()
+            .map_err(|_| AxiomError::lock_poisoned("index"))?;
+        index.upsert_om_record(record);
+        Ok(scope_key)
+    }
+
     pub fn om_bridge_enqueue_observe_request(
         &self,
         request: OmObserveBufferRequestedV1,
@@ -279,6 +601,12 @@ fn non_empty(field: &str, raw: &str) -> Result<String> {
     Ok(value)
 }
 
+fn parse_rfc3339_field(raw: &str) -> Result<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(raw)
+        .map(|at| at.with_timezone(&chrono::Utc))
+        .map_err(|err| AxiomError::Validation(format!("invalid rfc3339 timestamp '{raw}': {err}")))
+}
+
 fn normalize_scope_binding(input: OmScopeBindingInputV1) -> OmScopeBindingInputV1 {
     OmScopeBindingInputV1 {
         scope: input.scope,
@@ -392,10 +720,11 @@ fn process_replay_events(
                 app.state.set_checkpoint(checkpoint_name, event.id)?;
             }
             Err(err) => {
-                if should_retry_event_error(event.event_type.as_str(), attempt, &err) {
+                let policy = app.config.queue.retry_policy(event.event_type.as_str());
+                if policy.should_retry_error(event.event_type.as_str(), attempt, &err) {
                     app.state.requeue_outbox_with_delay(
                         event.id,
-                        retry_backoff_seconds(event.event_type.as_str(), attempt, event.id),
+                        policy.backoff_seconds(event.event_type.as_str(), attempt, event.id),
                     )?;
                     report.requeued += 1;
                 } else {
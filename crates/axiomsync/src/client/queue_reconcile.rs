@@ -1,23 +1,39 @@
+use std::collections::{BTreeMap, HashMap};
 use std::time::Instant;
 
+use crate::config::FaultPoint;
 use crate::error::{AxiomError, Result};
+use crate::evidence::accumulate_replay_report;
 use crate::models::{
-    OutboxEvent, QueueEventStatus, ReconcileOptions, ReconcileReport, ReconcileRunStatus,
-    ReplayReport,
-};
-use crate::queue_policy::{
-    default_scope_set, push_drift_sample, retry_backoff_seconds, should_retry_event_error,
+    OutboxEvent, QueueEventStatus, ReconcileDuplicateGroup, ReconcileOptions, ReconcileReport,
+    ReconcileRunStatus, ReplayReport,
 };
+use crate::queue_policy::{default_scope_set, push_drift_sample};
+use crate::state::SqliteStateStore;
 use crate::uri::{AxiomUri, Scope};
 
 use super::AxiomSync;
 
 const PROCESSING_TIMEOUT_RECOVERY_SECS: i64 = 300;
+const UNHANDLED_EVENT_REASON: &str = "UNHANDLED_EVENT";
 
 impl AxiomSync {
     pub fn replay_outbox(&self, limit: usize, include_dead_letter: bool) -> Result<ReplayReport> {
+        self.replay_outbox_parallel(limit, include_dead_letter, 1)
+    }
+
+    /// Replays events on `workers` scoped threads, partitioned by target URI.
+    /// Events for a URI and its ancestors or descendants run in id order on a single worker.
+    /// With one worker a state error aborts the batch; parallel workers record it and continue.
+    pub fn replay_outbox_parallel(
+        &self,
+        limit: usize,
+        include_dead_letter: bool,
+        workers: usize,
+    ) -> Result<ReplayReport> {
         let request_id = uuid::Uuid::new_v4().to_string();
         let started = Instant::now();
+        let workers = workers.max(1);
         let mut recovered_processing = 0u64;
 
         let output = (|| -> Result<ReplayReport> {
@@ -32,57 +48,25 @@ impl AxiomSync {
                     .fetch_outbox(QueueEventStatus::DeadLetter, remaining)?;
                 events.append(&mut dead);
             }
+            let fetched = events.len();
 
-            let mut report = ReplayReport {
-                fetched: events.len(),
-                ..ReplayReport::default()
-            };
-
-            for event in events {
-                self.state
-                    .mark_outbox_status(event.id, QueueEventStatus::Processing, true)?;
-                let attempt = event.attempt_count.saturating_add(1);
-                match self.handle_outbox_event(&event) {
-                    Ok(handled) => {
-                        report.processed += 1;
-                        if handled {
-                            self.state.mark_outbox_status(
-                                event.id,
-                                QueueEventStatus::Done,
-                                false,
-                            )?;
-                            report.done += 1;
-                        } else {
-                            self.state.mark_outbox_status(
-                                event.id,
-                                QueueEventStatus::DeadLetter,
-                                false,
-                            )?;
-                            report.dead_letter += 1;
-                        }
-                        self.state.set_checkpoint("replay", event.id)?;
-                    }
-                    Err(err) => {
-                        if should_retry_event_error(&event.event_type, attempt, &err) {
-                            self.state.requeue_outbox_with_delay(
-                                event.id,
-                                retry_backoff_seconds(&event.event_type, attempt, event.id),
-                            )?;
-                            report.requeued += 1;
-                        } else {
-                            self.state.mark_outbox_status(
-                                event.id,
-                                QueueEventStatus::DeadLetter,
-                                false,
-                            )?;
-                            self.try_cleanup_om_reflection_flags_after_terminal_failure(&event)?;
-                            report.dead_letter += 1;
-                        }
-                        self.state.set_checkpoint("replay", event.id)?;
-                    }
+            let mut report = if workers == 1 {
+                let mut report = ReplayReport::default();
+                for event in &events {
+                    self.replay_outbox_event(event, &mut report)?;
+                    self.state.set_checkpoint("replay", event.id)?;
                 }
-            }
-
+                report
+            } else {
+                let last_event_id = events.iter().map(|event| event.id).max();
+                let report =
+                    self.replay_outbox_partitions(&partition_events_by_uri(events, workers));
+                if let Some(last_event_id) = last_event_id {
+                    self.state.set_checkpoint("replay", last_event_id)?;
+                }
+                report
+            };
+            report.fetched = fetched;
             Ok(report)
         })();
 
@@ -97,12 +81,15 @@ impl AxiomSync {
                     Some(serde_json::json!({
                         "limit": limit,
                         "include_dead_letter": include_dead_letter,
+                        "workers": workers,
                         "fetched": report.fetched,
                         "processed": report.processed,
                         "done": report.done,
                         "dead_letter": report.dead_letter,
                         "requeued": report.requeued,
                         "skipped": report.skipped,
+                        "dead_letter_reasons": report.dead_letter_reasons,
+                        "failed_reasons": report.failed_reasons,
                         "recovered_processing": recovered_processing,
                     })),
                 );
@@ -118,6 +105,7 @@ impl AxiomSync {
                     Some(serde_json::json!({
                         "limit": limit,
                         "include_dead_letter": include_dead_letter,
+                        "workers": workers,
                         "recovered_processing": recovered_processing,
                     })),
                 );
@@ -126,6 +114,233 @@ impl AxiomSync {
         }
     }
 
+    fn replay_outbox_partitions(&self, partitions: &[Vec<OutboxEvent>]) -> ReplayReport {
+        let reports = std::thread::scope(|scope| {
+            let handles = partitions
+                .iter()
+                .map(|events| {
+                    scope.spawn(move || {
+                        let mut report = ReplayReport::default();
+                        for event in events {
+                            self.replay_outbox_event_or_record(event, &mut report);
+                        }
+                        report
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .zip(partitions)
+                .map(|(handle, events)| {
+                    handle.join().unwrap_or_else(|_| {
+                        let err = AxiomError::Internal("replay worker panicked".to_string());
+                        let mut report = ReplayReport::default();
+                        for event in events {
+                            self.release_failed_replay_event(event, &err, &mut report);
+                        }
+                        report
+                    })
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let mut merged = ReplayReport::default();
+        for report in &reports {
+            accumulate_replay_report(&mut merged, report);
+        }
+        merged
+    }
+
+    /// Replays one event; a state error is counted in `failed_reasons` instead of aborting the batch.
+    fn replay_outbox_event_or_record(&self, event: &OutboxEvent, report: &mut ReplayReport) {
+        if let Err(err) = self.replay_outbox_event(event, report) {
+            self.release_failed_replay_event(event, &err, report);
+        }
+    }
+
+    /// Puts a claimed event back to `new` so it does not wait for the processing timeout.
+    fn release_failed_replay_event(
+        &self,
+        event: &OutboxEvent,
+        err: &AxiomError,
+        report: &mut ReplayReport,
+    ) {
+        *report
+            .failed_reasons
+            .entry(err.code().to_string())
+            .or_default() += 1;
+        let still_processing = self
+            .state
+            .get_outbox_event(event.id)
+            .ok()
+            .flatten()
+            .is_some_and(|current| current.status == QueueEventStatus::Processing);
+        if still_processing {
+            let _ = self.state.requeue_outbox_with_delay(event.id, 0);
+        }
+    }
+
+    fn replay_outbox_event(&self, event: &OutboxEvent, report: &mut ReplayReport) -> Result<()> {
+        if !self.state.claim_outbox_event(event.id, event.status)? {
+            report.skipped += 1;
+            return Ok(());
+        }
+        let attempt = event.attempt_count.saturating_add(1);
+        let handled = self
+            .fault_injector
+            .check(FaultPoint::SqliteLock)
+            .and_then(|()| self.handle_outbox_event(event));
+        match handled {
+            Ok(handled) => {
+                report.processed += 1;
+                if handled {
+                    self.state
+                        .mark_outbox_status(event.id, QueueEventStatus::Done, false)?;
+                    report.done += 1;
+                } else {
+                    self.state
+                        .mark_outbox_status(event.id, QueueEventStatus::DeadLetter, false)?;
+                    report.dead_letter += 1;
+                    *report
+                        .dead_letter_reasons
+                        .entry(UNHANDLED_EVENT_REASON.to_string())
+                        .or_default() += 1;
+                }
+            }
+            Err(err) => {
+                let policy = self.config.queue.retry_policy(&event.event_type);
+                if policy.should_retry_error(&event.event_type, attempt, &err) {
+                    self.state.requeue_outbox_with_delay(
+                        event.id,
+                        policy.backoff_seconds(&event.event_type, attempt, event.id),
+                    )?;
+                    report.requeued += 1;
+                } else {
+                    self.state
+                        .mark_outbox_status(event.id, QueueEventStatus::DeadLetter, false)?;
+                    self.try_cleanup_om_reflection_flags_after_terminal_failure(event)?;
+                    report.dead_letter += 1;
+                    *report
+                        .dead_letter_reasons
+                        .entry(err.code().to_string())
+                        .or_default() += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_queue_event(&self, event_id: i64) -> Result<Option<OutboxEvent>> {
+        self.state.get_outbox_event(event_id)
+    }
+
+    /// Resets one event to new with zero retries, e.g. to retry a dead letter.
+    /// `false` if missing; conflict while processing.
+    pub fn requeue_event(&self, event_id: i64) -> Result<bool> {
+        self.run_queue_event_action(event_id, "queue.requeue", None, |state| {
+            state.reset_outbox_event(event_id)
+        })
+    }
+
+    /// Dead-letter events by priority then id, for picking out poison events before replay.
+    pub fn list_dead_letter(&self, limit: usize) -> Result<Vec<OutboxEvent>> {
+        self.state.fetch_outbox(QueueEventStatus::DeadLetter, limit)
+    }
+
+    /// Resets one dead-letter event to new with zero retries.
+    /// `false` if missing or not dead-lettered; conflict while processing.
+    pub fn requeue_dead_letter(&self, event_id: i64) -> Result<bool> {
+        self.run_queue_event_action(
+            event_id,
+            "queue.dead_letter.requeue",
+            Some(QueueEventStatus::DeadLetter),
+            |state| state.requeue_dead_letter_event(event_id),
+        )
+    }
+
+    /// Deletes one event; `false` if missing, conflict while processing.
+    pub fn purge_event(&self, event_id: i64) -> Result<bool> {
+        self.run_queue_event_action(event_id, "queue.purge", None, |state| {
+            state.delete_outbox_event(event_id)
+        })
+    }
+
+    fn run_queue_event_action(
+        &self,
+        event_id: i64,
+        operation: &str,
+        required_status: Option<QueueEventStatus>,
+        action: impl FnOnce(&SqliteStateStore) -> Result<bool>,
+    ) -> Result<bool> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let event = match self.state.get_outbox_event(event_id) {
+            Ok(event) => event,
+            Err(err) => {
+                self.log_request_error(
+                    request_id,
+                    operation,
+                    started,
+                    None,
+                    &err,
+                    Some(serde_json::json!({ "event_id": event_id })),
+                );
+                return Err(err);
+            }
+        };
+        let target_uri = event.as_ref().map(|event| event.uri.clone());
+        let mut details = serde_json::json!({
+            "event_id": event_id,
+            "event_type": event.as_ref().map(|event| event.event_type.clone()),
+            "previous_status": event.as_ref().map(|event| event.status),
+            "previous_attempt_count": event.as_ref().map(|event| event.attempt_count),
+        });
+
+        let output = match event {
+            None => Ok(false),
+            Some(event) if event.status == QueueEventStatus::Processing => {
+                Err(processing_event_conflict(event_id))
+            }
+            Some(event) if required_status.is_some_and(|status| status != event.status) => {
+                Ok(false)
+            }
+            Some(_) => action(&self.state).and_then(|applied| {
+                // A worker claim after the lookup trips the SQL guard and skips the update.
+                if applied {
+                    Ok(true)
+                } else {
+                    Err(processing_event_conflict(event_id))
+                }
+            }),
+        };
+
+        match output {
+            Ok(applied) => {
+                details["applied"] = serde_json::Value::Bool(applied);
+                self.log_request_status(
+                    request_id,
+                    operation,
+                    "ok",
+                    started,
+                    target_uri,
+                    Some(details),
+                );
+                Ok(applied)
+            }
+            Err(err) => {
+                self.log_request_error(
+                    request_id,
+                    operation,
+                    started,
+                    target_uri,
+                    &err,
+                    Some(details),
+                );
+                Err(err)
+            }
+        }
+    }
+
     pub fn reconcile_state(&self) -> Result<ReconcileReport> {
         self.reconcile_state_with_options(&ReconcileOptions::default())
     }
@@ -160,16 +375,39 @@ impl AxiomSync {
         selected_scopes: &[Scope],
     ) -> Result<ReconcileReport> {
         let stats = self.collect_reconcile_drift_stats(options, selected_scopes)?;
+        let vectors = self.reconcile_orphan_vectors(options, selected_scopes)?;
+        let relations = self.reconcile_dangling_relations(options, selected_scopes)?;
+        let tiers = self.reconcile_missing_tiers(options, selected_scopes)?;
         let reindexed_scopes = self.reindex_reconcile_scopes(options, selected_scopes)?;
+        let quota_usage = self
+            .fs
+            .quota()
+            .map(|quota| quota.recompute(selected_scopes, !options.dry_run))
+            .transpose()?
+            .unwrap_or_default();
         Ok(ReconcileReport {
             run_id: run_id.to_string(),
-            drift_count: stats.drift_count,
+            drift_count: stats
+                .drift_count
+                .saturating_add(vectors.detected)
+                .saturating_add(relations.detected)
+                .saturating_add(tiers.detected),
             invalid_uri_entries: stats.invalid_uri_entries,
             missing_uri_entries: stats.missing_uri_entries,
             missing_files_pruned: stats.missing_files_pruned,
+            duplicate_uri_entries: stats.duplicate_uri_entries,
+            duplicates_merged: stats.duplicates_merged,
+            duplicate_uri_groups: stats.duplicate_uri_groups,
             reindexed_scopes,
             dry_run: options.dry_run,
             drift_uris_sample: stats.drift_uris_sample,
+            quota_usage,
+            orphan_vectors_removed: vectors.repaired,
+            orphan_vector_samples: vectors.samples,
+            dangling_relations_pruned: relations.repaired,
+            dangling_relation_samples: relations.samples,
+            missing_tiers_regenerated: tiers.repaired,
+            missing_tier_samples: tiers.samples,
             status: reconcile_status(options.dry_run),
         })
     }
@@ -180,12 +418,50 @@ impl AxiomSync {
         selected_scopes: &[Scope],
     ) -> Result<ReconcileDriftStats> {
         let mut stats = ReconcileDriftStats::default();
+        let uris = self.state.list_index_state_uris()?;
+        for group in duplicate_index_state_groups(&uris, selected_scopes) {
+            self.record_duplicate_reconcile_group(group, options, &mut stats)?;
+        }
         for uri_str in self.state.list_index_state_uris()? {
             self.process_reconcile_uri(&uri_str, options, selected_scopes, &mut stats)?;
         }
         Ok(stats)
     }
 
+    /// Counts non-canonical entries as drift and deletes them with `fix_duplicates`.
+    fn record_duplicate_reconcile_group(
+        &self,
+        group: ReconcileDuplicateGroup,
+        options: &ReconcileOptions,
+        stats: &mut ReconcileDriftStats,
+    ) -> Result<()> {
+        let extra = group.uris.len().saturating_sub(1);
+        stats.drift_count = stats.drift_count.saturating_add(extra);
+        stats.duplicate_uri_entries = stats.duplicate_uri_entries.saturating_add(extra);
+        for uri_str in group.uris.iter().filter(|uri| **uri != group.canonical_uri) {
+            push_drift_sample(
+                &mut stats.drift_uris_sample,
+                uri_str,
+                options.max_drift_sample,
+            );
+            if options.dry_run || !options.fix_duplicates {
+                continue;
+            }
+            let _ = self.state.remove_index_state(uri_str)?;
+            self.state.remove_search_document(uri_str)?;
+            {
+                let mut index = self
+                    .index
+                    .write()
+                    .map_err(|_| AxiomError::lock_poisoned("index"))?;
+                index.remove(uri_str);
+            }
+            stats.duplicates_merged = stats.duplicates_merged.saturating_add(1);
+        }
+        stats.duplicate_uri_groups.push(group);
+        Ok(())
+    }
+
     fn process_reconcile_uri(
         &self,
         uri_str: &str,
@@ -312,6 +588,11 @@ impl AxiomSync {
                         "invalid_uri_entries": report.invalid_uri_entries,
                         "missing_uri_entries": report.missing_uri_entries,
                         "missing_files_pruned": report.missing_files_pruned,
+                        "duplicate_uri_entries": report.duplicate_uri_entries,
+                        "duplicates_merged": report.duplicates_merged,
+                        "orphan_vectors_removed": report.orphan_vectors_removed,
+                        "dangling_relations_pruned": report.dangling_relations_pruned,
+                        "missing_tiers_regenerated": report.missing_tiers_regenerated,
                         "reindexed_scopes": report.reindexed_scopes,
                     })),
                 );
@@ -360,9 +641,41 @@ struct ReconcileDriftStats {
     invalid_uri_entries: usize,
     missing_uri_entries: usize,
     missing_files_pruned: usize,
+    duplicate_uri_entries: usize,
+    duplicates_merged: usize,
+    duplicate_uri_groups: Vec<ReconcileDuplicateGroup>,
     drift_uris_sample: Vec<String>,
 }
 
+/// Groups entries in the selected scopes that share an `AxiomUri` canonical form.
+/// Case is not folded since its meaning depends on the filesystem.
+fn duplicate_index_state_groups(
+    uris: &[String],
+    selected_scopes: &[Scope],
+) -> Vec<ReconcileDuplicateGroup> {
+    let mut by_canonical = BTreeMap::<String, Vec<String>>::new();
+    for raw in uris {
+        let Ok(parsed) = AxiomUri::parse(raw) else {
+            continue;
+        };
+        if !selected_scopes.contains(&parsed.scope()) {
+            continue;
+        }
+        by_canonical
+            .entry(parsed.to_string())
+            .or_default()
+            .push(raw.clone());
+    }
+    by_canonical
+        .into_iter()
+        .filter(|(_, uris)| uris.len() > 1)
+        .map(|(canonical_uri, uris)| ReconcileDuplicateGroup {
+            canonical_uri,
+            uris,
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 struct ReconcileScopeSelection {
     selected_scopes: Vec<Scope>,
@@ -422,3 +735,108 @@ fn parse_payload_generation(value: &serde_json::Value) -> Option<u32> {
         .filter(|raw| !raw.is_empty())
         .and_then(|raw| raw.parse::<u32>().ok())
 }
+
+// Keeps overlapping URIs (same, ancestor or descendant) in one partition and assigns each
+// group to the least loaded partition.
+fn partition_events_by_uri(events: Vec<OutboxEvent>, workers: usize) -> Vec<Vec<OutboxEvent>> {
+    let mut distinct = Vec::<(String, Option<AxiomUri>)>::new();
+    let mut uri_index = HashMap::<String, usize>::new();
+    for event in &events {
+        if !uri_index.contains_key(&event.uri) {
+            uri_index.insert(event.uri.clone(), distinct.len());
+            distinct.push((event.uri.clone(), AxiomUri::parse(&event.uri).ok()));
+        }
+    }
+
+    let mut parent = (0..distinct.len()).collect::<Vec<_>>();
+    for right in 0..distinct.len() {
+        for left in 0..right {
+            let overlaps = match (&distinct[left].1, &distinct[right].1) {
+                (Some(a), Some(b)) => a.starts_with(b) || b.starts_with(a),
+                _ => false,
+            };
+            if overlaps {
+                let (a, b) = (
+                    partition_root(&mut parent, left),
+                    partition_root(&mut parent, right),
+                );
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+    }
+
+    let mut groups = Vec::<Vec<OutboxEvent>>::new();
+    let mut group_by_root = HashMap::<usize, usize>::new();
+    for event in events {
+        let group_root = partition_root(&mut parent, uri_index[&event.uri]);
+        let index = *group_by_root.entry(group_root).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[index].push(event);
+    }
+
+    let mut partitions = vec![Vec::new(); workers.min(groups.len())];
+    for group in groups {
+        if let Some(partition) = partitions
+            .iter_mut()
+            .min_by_key(|partition| partition.len())
+        {
+            partition.extend(group);
+        }
+    }
+    partitions
+}
+
+fn partition_root(parent: &mut [usize], mut index: usize) -> usize {
+    while parent[index] != index {
+        parent[index] = parent[parent[index]];
+        index = parent[index];
+    }
+    index
+}
+
+fn processing_event_conflict(event_id: i64) -> AxiomError {
+    AxiomError::Conflict(format!(
+        "queue event {event_id} is processing; wait for the worker to finish"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::QueuePriority;
+
+    fn event(id: i64, uri: &str) -> OutboxEvent {
+        OutboxEvent {
+            id,
+            event_type: "reindex".to_string(),
+            uri: uri.to_string(),
+            payload_json: serde_json::json!({}),
+            status: QueueEventStatus::New,
+            attempt_count: 0,
+            next_attempt_at: None,
+            priority: QueuePriority::default(),
+        }
+    }
+
+    #[test]
+    fn partition_events_keeps_ancestor_and_descendant_uris_together_in_id_order() {
+        let events = vec![
+            event(1, "axiom://resources/docs/a.md"),
+            event(2, "axiom://resources/other/x.md"),
+            event(3, "axiom://resources/docs/b.md"),
+            event(4, "axiom://resources/docs"),
+            event(5, "axiom://resources/docs-2/c.md"),
+        ];
+        let partitions = partition_events_by_uri(events, 4);
+        let ids = partitions
+            .iter()
+            .map(|partition| partition.iter().map(|event| event.id).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(ids.len(), 3);
+        assert!(ids.contains(&vec![1, 3, 4]));
+        assert!(ids.contains(&vec![2]));
+        assert!(ids.contains(&vec![5]));
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::BTreeSet;
+
+use crate::config::should_persist_scope_tiers;
+use crate::error::{AxiomError, Result};
+use crate::models::ReconcileOptions;
+use crate::queue_policy::push_drift_sample;
+use crate::relation_documents::{read_relations, write_relations};
+use crate::tier_documents::{abstract_path, abstract_uri, overview_path, overview_uri};
+use crate::uri::{AxiomUri, Scope};
+
+use super::AxiomSync;
+use super::resource::DUPLICATE_OF_RELATION;
+
+/// Vector/relation check counts; `detected` counts on dry runs, `repaired` only real fixes.
+#[derive(Debug, Default)]
+pub(super) struct ReconcileIntegrityStats {
+    pub(super) detected: usize,
+    pub(super) repaired: usize,
+    pub(super) samples: Vec<String>,
+}
+
+impl AxiomSync {
+    /// Removes index and search documents for URIs missing on disk, including ones `index_state` no longer tracks.
+    pub(super) fn reconcile_orphan_vectors(
+        &self,
+        options: &ReconcileOptions,
+        selected_scopes: &[Scope],
+    ) -> Result<ReconcileIntegrityStats> {
+        let mut stats = ReconcileIntegrityStats::default();
+        if !options.check_vectors {
+            return Ok(stats);
+        }
+
+        let mut uris = {
+            let index = self
+                .index
+                .read()
+                .map_err(|_| AxiomError::lock_poisoned("index"))?;
+            index
+                .all_records()
+                .into_iter()
+                .map(|record| record.uri)
+                .collect::<BTreeSet<_>>()
+        };
+        uris.extend(
+            self.state
+                .list_search_documents()?
+                .into_iter()
+                .map(|record| record.uri),
+        );
+
+        for uri_str in uris {
+            let Ok(parsed) = AxiomUri::parse(&uri_str) else {
+                continue;
+            };
+            if !selected_scopes.contains(&parsed.scope()) || self.fs.exists(&parsed) {
+                continue;
+            }
+            stats.detected = stats.detected.saturating_add(1);
+            push_drift_sample(&mut stats.samples, &uri_str, options.max_drift_sample);
+            if options.dry_run {
+                continue;
+            }
+            let _ = self.state.remove_index_state(&uri_str)?;
+            self.state.remove_search_document(&uri_str)?;
+            {
+                let mut index = self
+                    .index
+                    .write()
+                    .map_err(|_| AxiomError::lock_poisoned("index"))?;
+                index.remove(&uri_str);
+            }
+            stats.repaired = stats.repaired.saturating_add(1);
+        }
+        Ok(stats)
+    }
+
+    /// Drops missing targets from `.relations.json` in the selected scopes; relations left with
+    /// fewer than two targets are removed. Samples read `<owner>#<relation_id> -> <target>`.
+    pub(super) fn reconcile_dangling_relations(
+        &self,
+        options: &ReconcileOptions,
+        selected_scopes: &[Scope],
+    ) -> Result<ReconcileIntegrityStats> {
+        let mut stats = ReconcileIntegrityStats::default();
+        if !options.check_relations {
+            return Ok(stats);
+        }
+
+        for scope in selected_scopes.iter().filter(|scope| !scope.is_internal()) {
+            let root = AxiomUri::root(*scope);
+            if !self.fs.is_dir(&root) {
+                continue;
+            }
+            let mut owners = vec![root.clone()];
+            for entry in self.fs.list(&root, true)? {
+                if entry.is_dir {
+                    owners.push(AxiomUri::parse(&entry.uri)?);
+                }
+            }
+            for owner in owners {
+                self.prune_owner_dangling_relations(&owner, options, &mut stats)?;
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Regenerates missing or empty `.abstract.md`/`.overview.md` in tiered scopes; samples are the tier file URIs.
+    pub(super) fn reconcile_missing_tiers(
+        &self,
+        options: &ReconcileOptions,
+        selected_scopes: &[Scope],
+    ) -> Result<ReconcileIntegrityStats> {
+        let mut stats = ReconcileIntegrityStats::default();
+        let internal_policy = self.config.indexing.internal_tier_policy;
+        for scope in selected_scopes
+            .iter()
+            .filter(|scope| should_persist_scope_tiers(**scope, internal_policy))
+        {
+            let root = AxiomUri::root(*scope);
+            if !self.fs.is_dir(&root) {
+                continue;
+            }
+            let mut dirs = vec![root.clone()];
+            for entry in self.fs.list(&root, true)? {
+                if entry.is_dir {
+                    dirs.push(AxiomUri::parse(&entry.uri)?);
+                }
+            }
+            for dir in dirs {
+                let mut missing = false;
+                for (path, tier_uri) in [
+                    (abstract_path(&self.fs, &dir), abstract_uri(&dir)?),
+                    (overview_path(&self.fs, &dir), overview_uri(&dir)?),
+                ] {
+                    if std::fs::metadata(&path).is_ok_and(|meta| meta.is_file() && meta.len() > 0) {
+                        continue;
+                    }
+                    missing = true;
+                    push_drift_sample(
+                        &mut stats.samples,
+                        &tier_uri.to_string(),
+                        options.max_drift_sample,
+                    );
+                }
+                if !missing {
+                    continue;
+                }
+                stats.detected = stats.detected.saturating_add(1);
+                if options.dry_run {
+                    continue;
+                }
+                self.ensure_directory_tiers(&dir)?;
+                stats.repaired = stats.repaired.saturating_add(1);
+            }
+        }
+        Ok(stats)
+    }
+
+    fn prune_owner_dangling_relations(
+        &self,
+        owner: &AxiomUri,
+        options: &ReconcileOptions,
+        stats: &mut ReconcileIntegrityStats,
+    ) -> Result<()> {
+        let relations = read_relations(&self.fs, owner)?;
+        let mut dangling_total = 0usize;
+        let mut kept = Vec::with_capacity(relations.len());
+        for mut relation in relations {
+            let before = relation.uris.len();
+            // A dedup link's first URI is the skipped duplicate and is absent by design.
+            let skipped_duplicate = relation
+                .id
+                .strip_prefix(DUPLICATE_OF_RELATION)
+                .is_some_and(|rest| rest.starts_with(':'))
+                .then(|| relation.uris.first().cloned())
+                .flatten();
+            relation.uris.retain(|target| {
+                let exists = skipped_duplicate.as_ref() == Some(target)
+                    || AxiomUri::parse(target).is_ok_and(|uri| self.fs.exists(&uri));
+                if !exists {
+                    push_drift_sample(
+                        &mut stats.samples,
+                        &format!("{owner}#{} -> {target}", relation.id),
+                        options.max_drift_sample,
+                    );
+                }
+                exists
+            });
+            dangling_total = dangling_total.saturating_add(before - relation.uris.len());
+            if relation.uris.len() >= 2 {
+                kept.push(relation);
+            }
+        }
+        stats.detected = stats.detected.saturating_add(dangling_total);
+        if dangling_total == 0 || options.dry_run {
+            return Ok(());
+        }
+        write_relations(&self.fs, owner, &kept, true)?;
+        stats.repaired = stats.repaired.saturating_add(dangling_total);
+        Ok(())
+    }
+}
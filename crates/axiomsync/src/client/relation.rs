@@ -1,9 +1,12 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::fs;
 use std::sync::Arc;
 
 use crate::error::{AxiomError, Result};
-use crate::models::{ContextHit, FindResult, RelationLink, RelationSummary};
+use crate::models::{
+    ContextHit, FindResult, RelationGraph, RelationGraphEdge, RelationGraphNode, RelationLink,
+    RelationSummary,
+};
 use crate::ontology::{
     CompiledOntologySchema, ONTOLOGY_SCHEMA_URI_V1, compile_schema, parse_schema_v1,
     validate_relation_link,
@@ -13,6 +16,13 @@ use crate::uri::AxiomUri;
 
 use super::{AxiomSync, OntologySchemaCacheEntry, OntologySchemaFingerprint};
 
+const MAX_RELATION_NEIGHBORHOOD_HOPS: usize = 5;
+const MAX_RELATION_NEIGHBORHOOD_LIMIT: usize = 1_000;
+/// Node cap for the neighborhood attached to each search hit.
+pub(super) const SEARCH_RELATION_NEIGHBORHOOD_LIMIT: usize = 32;
+
+type OwnerRelationsCache = HashMap<AxiomUri, Arc<Vec<RelationLink>>>;
+
 impl AxiomSync {
     pub fn relations(&self, owner_uri: &str) -> Result<Vec<RelationLink>> {
         let owner = AxiomUri::parse(owner_uri)?;
@@ -98,6 +108,160 @@ impl AxiomSync {
         Ok(true)
     }
 
+    /// Walks `.relations.json` links breadth-first from `uri`.
+    /// Each node records its first hop distance and relation path; missing uris are
+    /// marked `dangling` and not expanded. `limit` counts the root.
+    pub fn relation_neighborhood(
+        &self,
+        uri: &str,
+        max_hops: usize,
+        limit: usize,
+    ) -> Result<RelationGraph> {
+        let root = AxiomUri::parse(uri)?;
+        validate_relation_owner_scope(&root)?;
+        validate_relation_neighborhood_bounds(max_hops, limit)?;
+        if !self.fs.exists(&root) {
+            return Err(AxiomError::NotFound(root.to_string()));
+        }
+        let mut owner_relations_cache = OwnerRelationsCache::new();
+        self.walk_relation_neighborhood(&root, max_hops, limit, &mut owner_relations_cache)
+    }
+
+    pub(super) fn enrich_find_result_relation_neighborhoods(
+        &self,
+        result: &mut FindResult,
+        max_hops: usize,
+    ) -> Result<()> {
+        let mut owner_relations_cache = OwnerRelationsCache::new();
+        for hit in &mut result.query_results {
+            let Ok(root) = AxiomUri::parse(&hit.uri) else {
+                continue;
+            };
+            if root.scope().is_internal() || !self.fs.exists(&root) {
+                continue;
+            }
+            hit.relation_neighborhood = Some(self.walk_relation_neighborhood(
+                &root,
+                max_hops,
+                SEARCH_RELATION_NEIGHBORHOOD_LIMIT,
+                &mut owner_relations_cache,
+            )?);
+        }
+        Ok(())
+    }
+
+    fn walk_relation_neighborhood(
+        &self,
+        root: &AxiomUri,
+        max_hops: usize,
+        limit: usize,
+        owner_relations_cache: &mut OwnerRelationsCache,
+    ) -> Result<RelationGraph> {
+        let root_uri = root.to_string();
+        let mut nodes = vec![RelationGraphNode {
+            uri: root_uri.clone(),
+            hops: 0,
+            relation_path: Vec::new(),
+            dangling: false,
+        }];
+        let mut node_index = HashMap::from([(root_uri.clone(), 0usize)]);
+        let mut edges = BTreeMap::<(String, String, String), RelationGraphEdge>::new();
+        let mut queue = VecDeque::from([0usize]);
+        let mut truncated = false;
+
+        while let Some(current) = queue.pop_front() {
+            let (current_uri, hops, dangling) = {
+                let node = &nodes[current];
+                (node.uri.clone(), node.hops, node.dangling)
+            };
+            if dangling || hops >= max_hops {
+                continue;
+            }
+            let parsed = AxiomUri::parse(&current_uri)?;
+            for (owner, relation) in self.relations_touching(&parsed, owner_relations_cache)? {
+                for related in &relation.uris {
+                    if related == &current_uri {
+                        continue;
+                    }
+                    if !node_index.contains_key(related) {
+                        if nodes.len() >= limit {
+                            truncated = true;
+                            continue;
+                        }
+                        let mut relation_path = nodes[current].relation_path.clone();
+                        relation_path.push(relation.id.clone());
+                        let dangling = AxiomUri::parse(related)
+                            .map_or(true, |target| !self.fs.exists(&target));
+                        node_index.insert(related.clone(), nodes.len());
+                        queue.push_back(nodes.len());
+                        nodes.push(RelationGraphNode {
+                            uri: related.clone(),
+                            hops: hops + 1,
+                            relation_path,
+                            dangling,
+                        });
+                    }
+                    let key = (current_uri.clone(), related.clone(), relation.id.clone());
+                    let reverse = (related.clone(), current_uri.clone(), relation.id.clone());
+                    if !edges.contains_key(&reverse) {
+                        edges.entry(key).or_insert_with(|| RelationGraphEdge {
+                            from: current_uri.clone(),
+                            to: related.clone(),
+                            relation_id: relation.id.clone(),
+                            owner_uri: owner.to_string(),
+                            reason: relation.reason.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        nodes.sort_by(|a, b| a.hops.cmp(&b.hops).then_with(|| a.uri.cmp(&b.uri)));
+        Ok(RelationGraph {
+            root_uri,
+            max_hops,
+            limit,
+            nodes,
+            edges: edges.into_values().collect(),
+            truncated,
+        })
+    }
+
+    pub(super) fn relation_count_touching(&self, uri: &AxiomUri) -> Result<usize> {
+        let mut owner_relations_cache = OwnerRelationsCache::new();
+        Ok(self
+            .relations_touching(uri, &mut owner_relations_cache)?
+            .len())
+    }
+
+    /// Relations of `uri` itself and its owners that include `uri`.
+    fn relations_touching(
+        &self,
+        uri: &AxiomUri,
+        owner_relations_cache: &mut OwnerRelationsCache,
+    ) -> Result<Vec<(AxiomUri, RelationLink)>> {
+        let target = uri.to_string();
+        let mut owner_candidates = Vec::new();
+        if self.fs.is_dir(uri) {
+            owner_candidates.push(uri.clone());
+        }
+        let mut cursor = uri.parent();
+        while let Some(parent) = cursor {
+            owner_candidates.push(parent.clone());
+            cursor = parent.parent();
+        }
+        let mut out = Vec::new();
+        for owner in owner_candidates {
+            let relations = self.load_owner_relations_cached(&owner, owner_relations_cache)?;
+            for relation in relations.iter() {
+                if relation.uris.iter().any(|item| item == &target) {
+                    out.push((owner.clone(), relation.clone()));
+                }
+            }
+        }
+        Ok(out)
+    }
+
     pub(super) fn enrich_find_result_relations(
         &self,
         result: &mut FindResult,
@@ -110,7 +274,7 @@ impl AxiomSync {
             None
         };
         let ontology_schema = ontology_schema.as_deref();
-        let mut owner_relations_cache = HashMap::<AxiomUri, Arc<Vec<RelationLink>>>::new();
+        let mut owner_relations_cache = OwnerRelationsCache::new();
         let mut object_type_cache = HashMap::<String, Option<String>>::new();
         self.enrich_hits_with_relations(
             &mut result.query_results,
@@ -140,7 +304,7 @@ impl AxiomSync {
         hits: &mut [ContextHit],
         max_per_hit: usize,
         ontology_schema: Option<&CompiledOntologySchema>,
-        owner_relations_cache: &mut HashMap<AxiomUri, Arc<Vec<RelationLink>>>,
+        owner_relations_cache: &mut OwnerRelationsCache,
         object_type_cache: &mut HashMap<String, Option<String>>,
     ) -> Result<()> {
         for hit in hits {
@@ -160,7 +324,7 @@ impl AxiomSync {
         hit_uri: &str,
         max_per_hit: usize,
         ontology_schema: Option<&CompiledOntologySchema>,
-        owner_relations_cache: &mut HashMap<AxiomUri, Arc<Vec<RelationLink>>>,
+        owner_relations_cache: &mut OwnerRelationsCache,
         object_type_cache: &mut HashMap<String, Option<String>>,
     ) -> Result<Vec<RelationSummary>> {
         if max_per_hit == 0 {
@@ -234,7 +398,7 @@ impl AxiomSync {
     fn load_owner_relations_cached(
         &self,
         owner: &AxiomUri,
-        owner_relations_cache: &mut HashMap<AxiomUri, Arc<Vec<RelationLink>>>,
+        owner_relations_cache: &mut OwnerRelationsCache,
     ) -> Result<Arc<Vec<RelationLink>>> {
         if let Some(cached) = owner_relations_cache.get(owner) {
             return Ok(Arc::clone(cached));
@@ -352,6 +516,20 @@ fn dedupe_relation_uris(uris: Vec<AxiomUri>) -> Vec<AxiomUri> {
     out
 }
 
+pub(super) fn validate_relation_neighborhood_bounds(max_hops: usize, limit: usize) -> Result<()> {
+    if max_hops > MAX_RELATION_NEIGHBORHOOD_HOPS {
+        return Err(AxiomError::Validation(format!(
+            "relation neighborhood hops must be <= {MAX_RELATION_NEIGHBORHOOD_HOPS}: {max_hops}"
+        )));
+    }
+    if limit == 0 || limit > MAX_RELATION_NEIGHBORHOOD_LIMIT {
+        return Err(AxiomError::Validation(format!(
+            "relation neighborhood limit must be between 1 and {MAX_RELATION_NEIGHBORHOOD_LIMIT}: {limit}"
+        )));
+    }
+    Ok(())
+}
+
 fn validate_relation_owner_scope(owner: &AxiomUri) -> Result<()> {
     if owner.scope().is_internal() {
         return Err(AxiomError::PermissionDenied(format!(
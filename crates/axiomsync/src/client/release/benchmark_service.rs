@@ -93,7 +93,11 @@ impl AxiomSync {
         let rustc_version =
             command_stdout("rustc", &["--version"]).unwrap_or_else(|| "unknown".to_string());
         let retrieval_backend = RETRIEVAL_BACKEND_MEMORY.to_string();
-        let reranker_profile = normalize_reranker_profile(self.config.search.reranker.as_deref());
+        let reranker_profile = if self.custom_reranker {
+            self.reranker_name().to_string()
+        } else {
+            normalize_reranker_profile(self.config.search.reranker.as_deref())
+        };
         let embedding = crate::embedding::embedding_profile();
 
         let machine_profile = if hw_model.to_ascii_lowercase().contains("macmini") {
@@ -357,6 +361,7 @@ mod tests {
 
     use super::AxiomSync;
     use super::normalize_reranker_profile;
+    use crate::retrieval::NoopReranker;
 
     #[cfg(unix)]
     use std::os::unix::fs::symlink;
@@ -371,6 +376,17 @@ mod tests {
         assert_eq!(normalize_reranker_profile(Some("OFF")), "off");
     }
 
+    #[test]
+    fn benchmark_environment_reports_installed_reranker_name() {
+        let temp = tempdir().expect("tempdir");
+        let app = AxiomSync::new(temp.path())
+            .expect("app")
+            .with_reranker(Box::new(NoopReranker));
+        let environment = app.collect_benchmark_environment_metadata();
+        assert_eq!(environment.reranker_profile, "off");
+        assert_eq!(environment.reranker_profile, app.reranker_name());
+    }
+
     #[test]
     fn persist_release_check_result_copies_structured_embedding_fields() {
         let temp = tempdir().expect("tempdir");
@@ -386,6 +402,8 @@ mod tests {
                 min_stress_top1_accuracy: None,
                 max_p95_regression_pct: None,
                 max_top1_regression_pct: None,
+                max_mrr_regression_pct: None,
+                max_p95_ms_at_concurrency: None,
             },
             quorum: BenchmarkGateQuorum {
                 window_size: 2,
@@ -397,6 +415,7 @@ mod tests {
                     created_at: "2026-01-01T00:00:00Z".to_string(),
                     executed_cases: 10,
                     top1_accuracy: 0.5,
+                    mrr: 0.0,
                     p95_latency_ms: 900,
                     p95_latency_us: Some(899_750),
                     report_uri: "axiom://queue/benchmarks/reports/a.json".to_string(),
@@ -405,6 +424,7 @@ mod tests {
                 regression_pct: None,
                 top1_regression_pct: None,
                 stress_top1_accuracy: None,
+                corpus_snapshot_mismatch: None,
             },
             execution: BenchmarkGateExecution {
                 evaluated_runs: 2,
@@ -418,6 +438,7 @@ mod tests {
                     stress_top1_accuracy: None,
                     regression_pct: None,
                     top1_regression_pct: None,
+                    mrr_regression_pct: None,
                     reasons: vec![
                         "release_embedding_provider_required:semantic-lite!=semantic-model-http"
                             .to_string(),
@@ -130,6 +130,8 @@ impl AxiomSync {
             include_stress: false,
             trace_expectations: true,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })?;
         let benchmark_gate = self.benchmark_gate_with_options(BenchmarkGateOptions {
             gate_profile: "rc-candidate".to_string(),
@@ -138,10 +140,12 @@ impl AxiomSync {
             min_stress_top1_accuracy: options.benchmark_gate.benchmark_min_stress_top1_accuracy,
             max_p95_regression_pct: options.benchmark_gate.benchmark_max_p95_regression_pct,
             max_top1_regression_pct: options.benchmark_gate.benchmark_max_top1_regression_pct,
+            max_mrr_regression_pct: None,
             window_size: options.benchmark_gate.benchmark_window_size.max(1),
             required_passes: options.benchmark_gate.benchmark_required_passes.max(1),
             record: true,
             write_release_check: false,
+            max_p95_ms_at_concurrency: None,
         })?;
         decisions.push(benchmark_release_gate_decision(&benchmark_gate));
 
@@ -1,17 +1,27 @@
+use std::borrow::Cow;
 use std::time::Instant;
 
 use chrono::Utc;
 
 use crate::catalog::request_log_uri;
+use crate::config::QueryLogMode;
 use crate::error::AxiomError;
 use crate::models::RequestLogEntry;
 
 use super::AxiomSync;
 
 impl AxiomSync {
+    /// Rewrites `query` in details per `query_log` mode before logging.
     pub(super) fn try_log_request(&self, entry: &RequestLogEntry) {
+        let mode = self.config.query_log;
+        let mut entry = Cow::Borrowed(entry);
+        if !mode.is_raw()
+            && let Some(details) = entry.to_mut().details.as_mut()
+        {
+            rewrite_logged_queries(details, mode);
+        }
         if let Ok(uri) = request_log_uri()
-            && let Ok(serialized) = serde_json::to_string(entry)
+            && let Ok(serialized) = serde_json::to_string(entry.as_ref())
         {
             let mut line = serialized;
             line.push('\n');
@@ -88,3 +98,25 @@ impl AxiomSync {
         });
     }
 }
+
+fn rewrite_logged_queries(value: &mut serde_json::Value, mode: QueryLogMode) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                if key == "query"
+                    && let serde_json::Value::String(query) = field
+                {
+                    *query = mode.persisted_query(query).into_owned();
+                } else {
+                    rewrite_logged_queries(field, mode);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                rewrite_logged_queries(item, mode);
+            }
+        }
+        _ => {}
+    }
+}
@@ -1,4 +1,4 @@
-use std::io::Read;
+use std::collections::HashMap;
 use std::path::Path;
 use std::thread;
 use std::time::Duration;
@@ -6,12 +6,15 @@ use std::time::Instant;
 
 use chrono::{DateTime, Utc};
 
+use crate::config::ENV_REMOTE_SOURCE_HOSTS;
 use crate::context_ops::default_resource_target;
 use crate::error::{AxiomError, Result};
 use crate::ingest::{IngestFinalizeMode, IngestManager, IngestSession};
 use crate::models::{
-    AddResourceIngestOptions, AddResourceRequest, AddResourceResult, AddResourceWaitMode,
-    GlobResult, QueueCounts, QueueEventStatus, QueueStatus,
+    AddResourceDedupReport, AddResourceDuplicate, AddResourceIngestOptions, AddResourceRequest,
+    AddResourceResult, AddResourceWaitMode, DedupAction, EntryPage, ExportOvpackOptions,
+    GlobResult, ImportOvpackOptions, QueueCounts, QueueEventStatus, QueueStatus, ReadChunk,
+    RmGlobReport,
 };
 use crate::pack;
 use crate::tier_documents::{read_abstract, read_overview};
@@ -19,35 +22,76 @@ use crate::uri::{AxiomUri, Scope};
 
 use super::AxiomSync;
 
-const MAX_REMOTE_TEXT_BYTES: usize = 5 * 1024 * 1024;
+/// Relation id prefix and reason written by `DedupAction::Link`.
+pub(super) const DUPLICATE_OF_RELATION: &str = "duplicate-of";
+/// Staged file name for a fetched remote source.
+const REMOTE_SOURCE_FILE_NAME: &str = "source.txt";
+/// System tag prefix recording the origin URL of a remote source.
+const SOURCE_URL_TAG_PREFIX: &str = "source_url:";
+const MAX_RESOURCE_TAGS: usize = 32;
+const MAX_RESOURCE_TAG_CHARS: usize = 64;
+const MAX_LS_PAGE_LIMIT: usize = 1_000;
 const WAIT_PROCESSED_MIN_SLEEP: Duration = Duration::from_millis(100);
 const WAIT_PROCESSED_MAX_SLEEP: Duration = Duration::from_secs(1);
 
 impl AxiomSync {
-    fn add_resource_core(
-        &self,
-        path_or_url: &str,
-        target: Option<&str>,
-        wait: bool,
-        timeout_secs: Option<u64>,
-        wait_mode: AddResourceWaitMode,
-        ingest_options: &AddResourceIngestOptions,
-    ) -> Result<AddResourceResult> {
-        let target_uri = target
+    fn add_resource_core(&self, request: &AddResourceRequest) -> Result<AddResourceResult> {
+        let path_or_url = request.source.as_str();
+        let wait = request.wait;
+        if !wait {
+            self.ensure_queue_below_high_water_mark()?;
+        }
+        let wait_mode = request.wait_mode;
+        let target_uri = request
+            .target
+            .as_deref()
             .map(AxiomUri::parse)
             .transpose()?
             .map_or_else(|| default_resource_target(path_or_url), Ok)?;
+        let tags = normalize_resource_tags(&request.tags)?;
         let ingest_manager = IngestManager::new(self.fs.clone(), self.parser_registry.clone());
         let mut ingest = ingest_manager.start_session()?;
-        let finalize_mode =
-            match stage_add_resource_source(path_or_url, timeout_secs, &mut ingest, ingest_options)
-            {
-                Ok(mode) => mode,
+        let finalize_mode = match self.stage_add_resource_source(
+            path_or_url,
+            request.timeout_secs,
+            &mut ingest,
+            &request.ingest_options,
+        ) {
+            Ok(mode) => mode,
+            Err(err) => {
+                ingest.abort();
+                return Err(err);
+            }
+        };
+        let dedup_action = request.ingest_options.dedup_action;
+        let dedup = if request.ingest_options.dedup_by_content {
+            match self.dedup_staged_files(&mut ingest, path_or_url, &target_uri) {
+                Ok(report) => Some(report),
                 Err(err) => {
                     ingest.abort();
                     return Err(err);
                 }
-            };
+            }
+        } else {
+            None
+        };
+        if let Some(mut report) = dedup.clone()
+            && report.kept_files == 0
+        {
+            ingest.abort();
+            let (duplicates_skipped, duplicates_linked) =
+                self.record_duplicates(&target_uri, &mut report, dedup_action)?;
+            return Ok(AddResourceResult {
+                root_uri: target_uri.to_string(),
+                queued: false,
+                message: "all staged files duplicate indexed content; nothing ingested".to_string(),
+                wait_mode: None,
+                wait_contract: None,
+                dedup: Some(report),
+                duplicates_skipped,
+                duplicates_linked,
+            });
+        }
         if let Err(err) = ingest.write_manifest(path_or_url) {
             ingest.abort();
             return Err(err);
@@ -56,6 +100,18 @@ impl AxiomSync {
             ingest.abort();
             return Err(err);
         }
+        if !tags.is_empty() {
+            self.state
+                .upsert_resource_tags(&target_uri.to_string(), &tags)?;
+        }
+        if is_remote_source(path_or_url) {
+            self.record_remote_source_url(&target_uri, path_or_url)?;
+        }
+        let mut dedup = dedup;
+        let (duplicates_skipped, duplicates_linked) = match dedup.as_mut() {
+            Some(report) => self.record_duplicates(&target_uri, report, dedup_action)?,
+            None => (0, 0),
+        };
         let outbox_event_id = self.state.enqueue(
             "semantic_scan",
             &target_uri.to_string(),
@@ -67,7 +123,7 @@ impl AxiomSync {
                     let _ = self.replay_outbox(256, false)?;
                 }
                 AddResourceWaitMode::Strict => {
-                    self.wait_for_outbox_event_done_strict(outbox_event_id, timeout_secs)?;
+                    self.wait_for_outbox_event_done_strict(outbox_event_id, request.timeout_secs)?;
                 }
             }
         }
@@ -82,9 +138,106 @@ impl AxiomSync {
             },
             wait_mode: wait.then_some(wait_mode),
             wait_contract: wait.then_some(wait_mode.contract_label().to_string()),
+            dedup,
+            duplicates_skipped,
+            duplicates_linked,
         })
     }
 
+    /// Rejects with `QueueBackpressure` before staging once `new` + `processing` reaches
+    /// `AXIOMSYNC_QUEUE_HIGH_WATER_MARK`; waiting adds drain the queue themselves and skip this.
+    fn ensure_queue_below_high_water_mark(&self) -> Result<()> {
+        let Some(high_water_mark) = self.config.queue.high_water_mark else {
+            return Ok(());
+        };
+        let counts = self.state.queue_counts()?;
+        let depth = counts.new_total.saturating_add(counts.processing);
+        if depth >= high_water_mark {
+            return Err(AxiomError::QueueBackpressure {
+                depth,
+                high_water_mark,
+            });
+        }
+        Ok(())
+    }
+
+    /// Records duplicates per `action` and returns `(skipped, linked)`.
+    /// `Link` writes a `duplicate-of` relation in the nearest common directory; cross-scope pairs count as skipped.
+    /// The duplicate URI comes first and is never written, so reconcile does not treat it as dangling.
+    fn record_duplicates(
+        &self,
+        target_uri: &AxiomUri,
+        report: &mut AddResourceDedupReport,
+        action: DedupAction,
+    ) -> Result<(usize, usize)> {
+        if action == DedupAction::Skip {
+            return Ok((report.skipped.len(), 0));
+        }
+        let mut linked = 0;
+        for duplicate in &mut report.skipped {
+            let duplicate_uri = target_uri.join(&duplicate.relative_path)?;
+            let existing_uri = AxiomUri::parse(&duplicate.existing_uri)?;
+            let Some(owner) = common_relation_owner(&duplicate_uri, &existing_uri) else {
+                continue;
+            };
+            let relation_id = format!(
+                "{DUPLICATE_OF_RELATION}:{}",
+                &blake3::hash(duplicate_uri.to_string().as_bytes()).to_hex()[..16]
+            );
+            self.link(
+                &owner.to_string(),
+                &relation_id,
+                vec![duplicate_uri.to_string(), existing_uri.to_string()],
+                &format!(
+                    "{DUPLICATE_OF_RELATION} (content_hash={})",
+                    duplicate.content_hash
+                ),
+            )?;
+            duplicate.relation_id = Some(relation_id);
+            linked += 1;
+        }
+        Ok((report.skipped.len() - linked, linked))
+    }
+
+    /// Drops staged files whose content hash matches a document outside the target or an earlier
+    /// staged file; documents under the target are being replaced and are not compared.
+    fn dedup_staged_files(
+        &self,
+        ingest: &mut IngestSession,
+        source: &str,
+        target_uri: &AxiomUri,
+    ) -> Result<AddResourceDedupReport> {
+        let manifest = ingest.write_manifest(source)?;
+        let mut first_by_hash = HashMap::<String, String>::new();
+        let mut report = AddResourceDedupReport::default();
+        for file in manifest.files {
+            let existing_uri = if let Some(uri) = first_by_hash.get(&file.content_hash) {
+                Some(uri.clone())
+            } else {
+                self.state
+                    .list_index_state_uris_by_hash(&file.content_hash)?
+                    .into_iter()
+                    .find(|uri| {
+                        AxiomUri::parse(uri).is_ok_and(|existing| !existing.starts_with(target_uri))
+                    })
+            };
+            if let Some(existing_uri) = existing_uri {
+                ingest.remove_staged_file(&file.relative_path)?;
+                report.skipped.push(AddResourceDuplicate {
+                    relative_path: file.relative_path,
+                    content_hash: file.content_hash,
+                    existing_uri,
+                    relation_id: None,
+                });
+            } else {
+                let destination = target_uri.join(&file.relative_path)?;
+                first_by_hash.insert(file.content_hash, destination.to_string());
+                report.kept_files += 1;
+            }
+        }
+        Ok(report)
+    }
+
     pub fn add_resource(
         &self,
         path_or_url: &str,
@@ -106,31 +259,24 @@ impl AxiomSync {
         &self,
         request: AddResourceRequest,
     ) -> Result<AddResourceResult> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let output = self.add_resource_core(&request);
         let AddResourceRequest {
             source,
-            target,
+            target: target_raw,
             wait,
-            timeout_secs,
             wait_mode,
             ingest_options,
+            tags,
+            ..
         } = request;
-        let request_id = uuid::Uuid::new_v4().to_string();
-        let started = Instant::now();
-        let target_raw = target.clone();
-        let target_ref = target.as_deref();
-        let output = self.add_resource_core(
-            source.as_str(),
-            target_ref,
-            wait,
-            timeout_secs,
-            wait_mode,
-            &ingest_options,
-        );
         let ingest_options_json = serde_json::to_value(&ingest_options).unwrap_or_else(|_| {
             serde_json::json!({
                 "markdown_only": ingest_options.markdown_only,
                 "include_hidden": ingest_options.include_hidden,
                 "exclude_globs": ingest_options.exclude_globs,
+                "dedup_by_content": ingest_options.dedup_by_content,
             })
         });
 
@@ -149,6 +295,9 @@ impl AxiomSync {
                         "queued": result.queued,
                         "wait_contract": result.wait_contract,
                         "ingest_options": ingest_options_json,
+                        "dedup_skipped": result.duplicates_skipped,
+                        "dedup_linked": result.duplicates_linked,
+                        "tags": tags,
                     })),
                 );
                 Ok(result)
@@ -165,6 +314,7 @@ impl AxiomSync {
                         "wait": wait,
                         "wait_mode": wait_mode,
                         "ingest_options": ingest_options_json,
+                        "tags": tags,
                     })),
                 );
                 Err(err)
@@ -273,6 +423,36 @@ impl AxiomSync {
         self.fs.list(&uri, recursive)
     }
 
+    /// Pages `ls` output in stable uri order; `limit` must be 1..=`MAX_LS_PAGE_LIMIT`.
+    pub fn ls_page(
+        &self,
+        uri: &str,
+        recursive: bool,
+        offset: usize,
+        limit: usize,
+    ) -> Result<EntryPage> {
+        if limit == 0 {
+            return Err(AxiomError::Validation(
+                "ls page limit must be >= 1".to_string(),
+            ));
+        }
+        let limit = limit.min(MAX_LS_PAGE_LIMIT);
+        let entries = self.ls(uri, recursive, false)?;
+        let total = entries.len();
+        let entries = entries
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect::<Vec<_>>();
+        Ok(EntryPage {
+            has_more: offset.saturating_add(entries.len()) < total,
+            entries,
+            offset,
+            limit,
+            total,
+        })
+    }
+
     pub fn glob(&self, pattern: &str, uri: Option<&str>) -> Result<GlobResult> {
         let base = if let Some(raw) = uri {
             Some(AxiomUri::parse(raw)?)
@@ -288,6 +468,11 @@ impl AxiomSync {
         self.fs.read(&uri)
     }
 
+    pub fn read_range(&self, uri: &str, offset: u64, len: usize) -> Result<ReadChunk> {
+        let uri = AxiomUri::parse(uri)?;
+        self.fs.read_range(&uri, offset, len)
+    }
+
     pub fn abstract_text(&self, uri: &str) -> Result<String> {
         let uri = AxiomUri::parse(uri)?;
         read_abstract(&self.fs, &uri)
@@ -299,6 +484,11 @@ impl AxiomSync {
     }
 
     pub fn mkdir(&self, uri: &str) -> Result<()> {
+        self.mkdir_with_parents(uri, true)
+    }
+
+    /// Without `parents`, a missing parent is `NotFound`; a file in the way is `Conflict`.
+    pub fn mkdir_with_parents(&self, uri: &str, parents: bool) -> Result<()> {
         let uri = AxiomUri::parse(uri)?;
         if !matches!(
             uri.scope(),
@@ -310,6 +500,32 @@ impl AxiomSync {
             )));
         }
 
+        let path = self.fs.resolve_uri(&uri);
+        if path.is_dir() {
+            return Ok(());
+        }
+        if path.exists() {
+            return Err(AxiomError::Conflict(format!(
+                "mkdir target exists as a file: {uri}"
+            )));
+        }
+        let mut ancestor = uri.parent();
+        while let Some(dir_uri) = ancestor {
+            let dir_path = self.fs.resolve_uri(&dir_uri);
+            if dir_path.is_dir() {
+                break;
+            }
+            if dir_path.exists() {
+                return Err(AxiomError::Conflict(format!(
+                    "mkdir parent exists as a file: {dir_uri}"
+                )));
+            }
+            if !parents {
+                return Err(AxiomError::NotFound(dir_uri.to_string()));
+            }
+            ancestor = dir_uri.parent();
+        }
+
         self.fs.create_dir_all(&uri, false)?;
         self.reindex_uri_tree(&uri)?;
         self.state.enqueue(
@@ -320,15 +536,16 @@ impl AxiomSync {
         Ok(())
     }
 
+    /// Moves `uri` to `axiom://trash`; internal scopes are deleted directly.
     pub fn rm(&self, uri: &str, recursive: bool) -> Result<()> {
+        self.trash(uri, recursive).map(|_| ())
+    }
+
+    /// Deletes `uri` immediately without keeping a trash entry.
+    pub fn rm_permanent(&self, uri: &str, recursive: bool) -> Result<()> {
         let uri = AxiomUri::parse(uri)?;
         self.fs.rm(&uri, recursive, false)?;
-
-        self.prune_index_prefix_from_memory(&uri)?;
-        self.state
-            .remove_search_documents_with_prefix(&uri.to_string())?;
-        self.state
-            .remove_index_state_with_prefix(&uri.to_string())?;
+        self.forget_removed_prefix(&uri)?;
 
         self.state.enqueue(
             "delete",
@@ -338,6 +555,145 @@ impl AxiomSync {
         Ok(())
     }
 
+    pub(super) fn forget_removed_prefix(&self, uri: &AxiomUri) -> Result<()> {
+        self.prune_index_prefix_from_memory(uri)?;
+        self.state
+            .remove_search_documents_with_prefix(&uri.to_string())?;
+        self.state
+            .remove_index_state_with_prefix(&uri.to_string())?;
+        self.state
+            .remove_resource_tags_with_prefix(&uri.to_string())?;
+        self.state
+            .remove_document_tags_with_prefix(&uri.to_string())?;
+        Ok(())
+    }
+
+    /// Moves files under `base_uri` matching `pattern` to trash, or deletes them when
+    /// `permanent`; directories are skipped.
+    /// All scopes are checked before deleting; `dry_run` only reports.
+    pub fn rm_glob(
+        &self,
+        pattern: &str,
+        base_uri: Option<&str>,
+        dry_run: bool,
+        permanent: bool,
+    ) -> Result<RmGlobReport> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let base_raw = base_uri.map(ToString::to_string);
+
+        let output = self.rm_glob_inner(pattern, base_uri, dry_run, permanent);
+        match output {
+            Ok(report) => {
+                self.log_request_status(
+                    request_id,
+                    "fs.rm_glob",
+                    "ok",
+                    started,
+                    Some(report.base_uri.clone()),
+                    Some(serde_json::json!({
+                        "pattern": pattern,
+                        "dry_run": dry_run,
+                        "permanent": permanent,
+                        "matched": report.uris.len(),
+                        "removed": report.removed,
+                        "index_state_removed": report.index_state_removed,
+                    })),
+                );
+                Ok(report)
+            }
+            Err(err) => {
+                self.log_request_error(
+                    request_id,
+                    "fs.rm_glob",
+                    started,
+                    base_raw,
+                    &err,
+                    Some(serde_json::json!({
+                        "pattern": pattern,
+                        "dry_run": dry_run,
+                        "permanent": permanent,
+                    })),
+                );
+                Err(err)
+            }
+        }
+    }
+
+    fn rm_glob_inner(
+        &self,
+        pattern: &str,
+        base_uri: Option<&str>,
+        dry_run: bool,
+        permanent: bool,
+    ) -> Result<RmGlobReport> {
+        let base = base_uri
+            .map(AxiomUri::parse)
+            .transpose()?
+            .unwrap_or_else(|| AxiomUri::root(Scope::Resources));
+        let mut targets = Vec::new();
+        for raw in self.fs.glob(Some(&base), pattern)? {
+            let uri = AxiomUri::parse(&raw)?;
+            if !matches!(
+                uri.scope(),
+                Scope::Resources | Scope::User | Scope::Agent | Scope::Session
+            ) {
+                return Err(AxiomError::PermissionDenied(format!(
+                    "rm is not allowed for scope: {}",
+                    uri.scope()
+                )));
+            }
+            if self.fs.resolve_uri(&uri).is_file() {
+                targets.push(uri);
+            }
+        }
+
+        let mut report = RmGlobReport {
+            pattern: pattern.to_string(),
+            base_uri: base.to_string(),
+            dry_run,
+            permanent,
+            uris: targets.iter().map(ToString::to_string).collect(),
+            removed: 0,
+            index_state_removed: 0,
+        };
+        if dry_run {
+            for uri in &report.uris {
+                if self.state.get_index_state_hash(uri)?.is_some() {
+                    report.index_state_removed += 1;
+                }
+            }
+            return Ok(report);
+        }
+
+        for uri in &targets {
+            let uri_raw = uri.to_string();
+            let trash_uri = if permanent {
+                self.fs.rm(uri, false, false)?;
+                None
+            } else {
+                self.fs
+                    .move_to_trash(uri, false)?
+                    .map(|entry| entry.trash_uri)
+            };
+            if self.state.get_index_state_hash(&uri_raw)?.is_some() {
+                report.index_state_removed += 1;
+            }
+            self.forget_removed_prefix(uri)?;
+            self.state.enqueue(
+                "delete",
+                &uri_raw,
+                serde_json::json!({
+                    "op": "rm_glob",
+                    "pattern": pattern,
+                    "trash_uri": trash_uri,
+                }),
+            )?;
+            report.removed += 1;
+        }
+        Ok(report)
+    }
+
     pub fn mv(&self, from_uri: &str, to_uri: &str) -> Result<()> {
         let from = AxiomUri::parse(from_uri)?;
         let to = AxiomUri::parse(to_uri)?;
@@ -349,6 +705,8 @@ impl AxiomSync {
             )));
         }
         self.fs.mv(&from, &to, false)?;
+        self.state
+            .move_tags_with_prefix(&from.to_string(), &to.to_string())?;
         self.prune_index_prefix_from_memory(&from)?;
         self.state
             .remove_search_documents_with_prefix(&from.to_string())?;
@@ -370,6 +728,15 @@ impl AxiomSync {
     }
 
     pub fn export_ovpack(&self, uri: &str, to: &str) -> Result<String> {
+        self.export_ovpack_with_options(uri, to, &ExportOvpackOptions::default())
+    }
+
+    pub fn export_ovpack_with_options(
+        &self,
+        uri: &str,
+        to: &str,
+        options: &ExportOvpackOptions,
+    ) -> Result<String> {
         let request_id = uuid::Uuid::new_v4().to_string();
         let started = Instant::now();
         let uri_raw = uri.to_string();
@@ -385,7 +752,13 @@ impl AxiomSync {
                     "ovpack export is not allowed for internal scopes".to_string(),
                 ));
             }
-            let out = pack::export_ovpack(&self.fs, &uri, Path::new(to))?;
+            let out = pack::export_ovpack_redacted(
+                &self.fs,
+                &uri,
+                Path::new(to),
+                options,
+                &self.config.redaction,
+            )?;
             Ok(out.display().to_string())
         })();
 
@@ -400,6 +773,7 @@ impl AxiomSync {
                     Some(serde_json::json!({
                         "to": to_path,
                         "output": export_path,
+                        "options": options,
                     })),
                 );
                 Ok(export_path)
@@ -427,6 +801,30 @@ impl AxiomSync {
         force: bool,
         vectorize: bool,
     ) -> Result<String> {
+        self.import_ovpack_with_options(
+            file_path,
+            parent,
+            ImportOvpackOptions {
+                force,
+                vectorize,
+                verify: true,
+                as_name: None,
+            },
+        )
+    }
+
+    pub fn import_ovpack_with_options(
+        &self,
+        file_path: &str,
+        parent: &str,
+        options: ImportOvpackOptions,
+    ) -> Result<String> {
+        let ImportOvpackOptions {
+            force,
+            vectorize,
+            verify,
+            as_name,
+        } = options;
         let request_id = uuid::Uuid::new_v4().to_string();
         let started = Instant::now();
         let file_path_raw = file_path.to_string();
@@ -442,7 +840,14 @@ impl AxiomSync {
                     "ovpack import is not allowed for internal scopes".to_string(),
                 ));
             }
-            let imported = pack::import_ovpack(&self.fs, Path::new(file_path), &parent_uri, force)?;
+            let imported = pack::import_ovpack_with_verify(
+                &self.fs,
+                Path::new(file_path),
+                &parent_uri,
+                force,
+                verify,
+                as_name.as_deref(),
+            )?;
             if vectorize {
                 self.prune_index_prefix_from_memory(&imported)?;
                 self.state
@@ -467,6 +872,8 @@ impl AxiomSync {
                         "file_path": file_path_raw,
                         "force": force,
                         "vectorize": vectorize,
+                        "verify": verify,
+                        "as_name": as_name,
                         "imported_uri": imported_uri,
                     })),
                 );
@@ -483,6 +890,8 @@ impl AxiomSync {
                         "file_path": file_path_raw,
                         "force": force,
                         "vectorize": vectorize,
+                        "verify": verify,
+                        "as_name": as_name,
                     })),
                 );
                 Err(err)
@@ -508,61 +917,77 @@ impl AxiomSync {
     }
 }
 
-fn stage_add_resource_source(
-    path_or_url: &str,
-    timeout_secs: Option<u64>,
-    ingest: &mut IngestSession,
-    ingest_options: &AddResourceIngestOptions,
-) -> Result<IngestFinalizeMode> {
-    if path_or_url.starts_with("http://") || path_or_url.starts_with("https://") {
-        let timeout = std::time::Duration::from_secs(timeout_secs.unwrap_or(30).max(1));
-        let client = reqwest::blocking::Client::builder()
-            .timeout(timeout)
-            .build()?;
-        let resp = client.get(path_or_url).send()?;
-        if !resp.status().is_success() {
-            return Err(AxiomError::Internal(format!(
-                "failed to fetch {path_or_url}: status {}",
-                resp.status()
+impl AxiomSync {
+    fn stage_add_resource_source(
+        &self,
+        path_or_url: &str,
+        timeout_secs: Option<u64>,
+        ingest: &mut IngestSession,
+        ingest_options: &AddResourceIngestOptions,
+    ) -> Result<IngestFinalizeMode> {
+        if is_remote_source(path_or_url) {
+            let text = self.fetch_remote_source(path_or_url, timeout_secs)?;
+            ingest.stage_text(REMOTE_SOURCE_FILE_NAME, &text)?;
+            return Ok(IngestFinalizeMode::MergeIntoTarget);
+        }
+
+        let src = Path::new(path_or_url);
+        if !src.exists() {
+            return Err(AxiomError::NotFound(path_or_url.to_string()));
+        }
+        ingest.stage_local_path_with_options(src, ingest_options)?;
+        if src.is_dir() {
+            Ok(IngestFinalizeMode::ReplaceTarget)
+        } else {
+            Ok(IngestFinalizeMode::MergeIntoTarget)
+        }
+    }
+
+    /// Rejects hosts outside the allowlist with `PermissionDenied` before fetching.
+    fn fetch_remote_source(&self, url: &str, timeout_secs: Option<u64>) -> Result<String> {
+        let remote = &self.config.remote;
+        if !remote.is_enabled() {
+            return Err(AxiomError::PermissionDenied(format!(
+                "remote sources are disabled; set {ENV_REMOTE_SOURCE_HOSTS} to allow hosts"
             )));
         }
-        if let Some(bytes) = resp.content_length()
-            && bytes > MAX_REMOTE_TEXT_BYTES as u64
-        {
+        let parsed = reqwest::Url::parse(url)
+            .map_err(|err| AxiomError::Validation(format!("invalid remote source {url}: {err}")))?;
+        let limits = remote.limits(timeout_secs);
+        if !limits.allows(&parsed) {
+            return Err(AxiomError::PermissionDenied(format!(
+                "remote source host is not allowed: {}",
+                parsed.host_str().unwrap_or_default()
+            )));
+        }
+        let text = self.remote_fetcher.fetch(&parsed, &limits)?;
+        if text.len() > limits.max_bytes {
             return Err(AxiomError::Validation(format!(
-                "remote resource too large: {bytes} bytes (limit {MAX_REMOTE_TEXT_BYTES})"
+                "remote resource too large: {} bytes (limit {})",
+                text.len(),
+                limits.max_bytes
             )));
         }
-        let text = read_remote_text_limited(resp, MAX_REMOTE_TEXT_BYTES)?;
-        ingest.stage_text("source.txt", &text)?;
-        return Ok(IngestFinalizeMode::MergeIntoTarget);
+        Ok(text)
     }
 
-    let src = Path::new(path_or_url);
-    if !src.exists() {
-        return Err(AxiomError::NotFound(path_or_url.to_string()));
-    }
-    ingest.stage_local_path_with_options(src, ingest_options)?;
-    if src.is_dir() {
-        Ok(IngestFinalizeMode::ReplaceTarget)
-    } else {
-        Ok(IngestFinalizeMode::MergeIntoTarget)
+    /// Stores `source_url:<url>` as a tag override so it survives reindexing.
+    fn record_remote_source_url(&self, target_uri: &AxiomUri, url: &str) -> Result<()> {
+        let document_uri = target_uri.join(REMOTE_SOURCE_FILE_NAME)?.to_string();
+        let mut overrides = self.state.document_tag_overrides(&document_uri)?;
+        overrides
+            .added
+            .retain(|tag| !tag.starts_with(SOURCE_URL_TAG_PREFIX));
+        overrides
+            .added
+            .push(format!("{SOURCE_URL_TAG_PREFIX}{url}"));
+        self.state
+            .upsert_document_tag_overrides(&document_uri, &overrides)
     }
 }
 
-fn read_remote_text_limited<R: Read>(mut reader: R, max_bytes: usize) -> Result<String> {
-    let mut body = Vec::new();
-    let mut limited = (&mut reader).take((max_bytes as u64) + 1);
-    limited.read_to_end(&mut body)?;
-    if body.len() > max_bytes {
-        return Err(AxiomError::Validation(format!(
-            "remote resource too large after download: {} bytes (limit {max_bytes})",
-            body.len()
-        )));
-    }
-    String::from_utf8(body).map_err(|err| {
-        AxiomError::Validation(format!("remote resource is not valid utf-8 text: {err}"))
-    })
+fn is_remote_source(path_or_url: &str) -> bool {
+    path_or_url.starts_with("http://") || path_or_url.starts_with("https://")
 }
 
 fn wait_processed_sleep_duration(counts: &QueueCounts, timeout_remaining: Duration) -> Duration {
@@ -596,10 +1021,46 @@ fn wait_processed_sleep_duration(counts: &QueueCounts, timeout_remaining: Durati
         .min(timeout_remaining)
 }
 
+/// Nearest ancestor of `duplicate` that also contains `existing`; `None` across scopes.
+fn common_relation_owner(duplicate: &AxiomUri, existing: &AxiomUri) -> Option<AxiomUri> {
+    let mut owner = duplicate.parent()?;
+    while !existing.starts_with(&owner) {
+        owner = owner.parent()?;
+    }
+    Some(owner)
+}
+
+/// Lowercases tags and allows only `[a-z0-9._-]`; `:` is reserved for system tags.
+pub(super) fn normalize_resource_tags(tags: &[String]) -> Result<Vec<String>> {
+    let mut out = Vec::with_capacity(tags.len());
+    for raw in tags {
+        let tag = raw.trim().to_lowercase();
+        if tag.is_empty()
+            || tag.chars().count() > MAX_RESOURCE_TAG_CHARS
+            || !tag.chars().all(|ch| {
+                ch.is_ascii_lowercase() || ch.is_ascii_digit() || matches!(ch, '-' | '_' | '.')
+            })
+        {
+            return Err(AxiomError::Validation(format!(
+                "resource tag must be 1-{MAX_RESOURCE_TAG_CHARS} chars of [a-z0-9._-]: {raw}"
+            )));
+        }
+        out.push(tag);
+    }
+    out.sort();
+    out.dedup();
+    if out.len() > MAX_RESOURCE_TAGS {
+        return Err(AxiomError::Validation(format!(
+            "at most {MAX_RESOURCE_TAGS} resource tags are allowed, got {}",
+            out.len()
+        )));
+    }
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
-    use std::io::Cursor;
 
     use chrono::Duration as ChronoDuration;
     use tempfile::tempdir;
@@ -609,22 +1070,6 @@ mod tests {
     use crate::models::{AddResourceIngestOptions, AddResourceWaitMode};
     use crate::uri::AxiomUri;
 
-    #[test]
-    fn read_remote_text_limited_rejects_payload_over_limit() {
-        let data = vec![b'a'; MAX_REMOTE_TEXT_BYTES + 1];
-        let err = read_remote_text_limited(Cursor::new(data), MAX_REMOTE_TEXT_BYTES)
-            .expect_err("must reject oversized payload");
-        assert!(matches!(err, AxiomError::Validation(_)));
-    }
-
-    #[test]
-    fn read_remote_text_limited_accepts_payload_within_limit() {
-        let data = b"hello remote".to_vec();
-        let text =
-            read_remote_text_limited(Cursor::new(data.clone()), MAX_REMOTE_TEXT_BYTES).expect("ok");
-        assert_eq!(text, String::from_utf8(data).expect("utf8"));
-    }
-
     #[test]
     fn wait_processed_drains_pending_queue_work() {
         let temp = tempdir().expect("tempdir");
@@ -893,4 +1338,56 @@ mod tests {
         );
         assert!(app.fs.exists(&second_uri), "second file must be present");
     }
+
+    #[test]
+    fn add_resource_past_scope_quota_fails_and_keeps_usage() {
+        let temp = tempdir().expect("tempdir");
+        let mut app = AxiomSync::new(temp.path()).expect("app");
+        app.initialize().expect("init");
+        let baseline = crate::quota::tree_size_bytes(&temp.path().join("resources"));
+        let quota = crate::quota::ScopeQuota::new(
+            crate::config::QuotaConfig::default()
+                .with_limit_bytes(crate::uri::Scope::Resources, baseline + 64),
+            app.state.clone(),
+            temp.path().to_path_buf(),
+        );
+        app.fs = app.fs.clone().with_quota(std::sync::Arc::new(quota));
+        let usage = |app: &AxiomSync| app.backend_status().expect("status").quotas[0].usage_bytes;
+
+        let small = temp.path().join("small.md");
+        fs::write(&small, "x".repeat(40)).expect("write small");
+        app.add_resource(
+            small.to_str().expect("small path"),
+            Some("axiom://resources/quota"),
+            None,
+            None,
+            false,
+            None,
+        )
+        .expect("within quota");
+        assert_eq!(usage(&app), baseline + 40);
+
+        let large = temp.path().join("large.md");
+        fs::write(&large, "y".repeat(40)).expect("write large");
+        let err = app
+            .add_resource(
+                large.to_str().expect("large path"),
+                Some("axiom://resources/quota"),
+                None,
+                None,
+                false,
+                None,
+            )
+            .expect_err("past quota");
+        assert!(matches!(
+            err,
+            AxiomError::QuotaExceeded {
+                requested_bytes: 40,
+                ..
+            }
+        ));
+        assert_eq!(usage(&app), baseline + 40);
+        let large_uri = AxiomUri::parse("axiom://resources/quota/large.md").expect("uri");
+        assert!(!app.fs.exists(&large_uri));
+    }
 }
@@ -1,23 +1,30 @@
+use std::collections::HashMap;
 use std::fs;
+use std::io::Write;
 use std::path::Path;
-use std::time::UNIX_EPOCH;
+use std::time::{Instant, UNIX_EPOCH};
 
 use chrono::{DateTime, Utc};
 
 use crate::catalog::request_log_uri;
 use crate::config::{RETRIEVAL_BACKEND_MEMORY, RETRIEVAL_BACKEND_POLICY_MEMORY_ONLY};
+use crate::embedding::EmbedderRuntimeConfig;
 use crate::error::{AxiomError, Result};
 use crate::jsonl::{jsonl_all_lines_invalid, parse_jsonl_tolerant};
 use crate::models::{
-    BackendStatus, CommitMode, CommitResult, EmbeddingBackendStatus, MemoryPromotionRequest,
-    MemoryPromotionResult, OmV2MigrationReport, QueueDiagnostics, QueueOverview, RequestLogEntry,
-    SessionInfo, SessionMeta,
+    BackendStatus, CommitMode, CommitResult, EmbeddingBackendStatus, ExportOvpackOptions,
+    MemoryPromotionRequest, MemoryPromotionResult, OmV2MigrationReport, QueueDiagnostics,
+    QueueOverview, RequestLogEntry, SESSION_BUNDLE_VERSION, SessionBundle, SessionInfo,
+    SessionMeta,
 };
+use crate::pack;
 use crate::queue_policy::default_scope_set;
+use crate::quota::ScopeQuota;
 use crate::session::Session;
 use crate::uri::{AxiomUri, Scope};
 
 use super::AxiomSync;
+use super::indexing::persisted_vector_version;
 
 const INDEX_PROFILE_STAMP_KEY: &str = "index_profile_stamp";
 const SEARCH_STACK_VERSION: &str = "drr-memory-v1";
@@ -50,9 +57,74 @@ impl AxiomSync {
                 vector_version: embed.vector_version,
                 dim: embed.dim,
             },
+            embedding_cache: self.embedding_cache_status()?,
+            query_cache: self
+                .query_result_cache
+                .status(self.config.search.result_cache_capacity)?,
+            quotas: self
+                .fs
+                .quota()
+                .map(ScopeQuota::status)
+                .transpose()?
+                .unwrap_or_default(),
         })
     }
 
+    /// Swaps the process-wide embedding provider without a restart.
+    /// Existing vectors are kept, so a vector version change warns and needs `reindex_all`.
+    pub fn set_embedding_provider(&self, config: EmbedderRuntimeConfig) -> Result<()> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let requested_kind = config.kind.clone();
+
+        match crate::embedding::switch_runtime(config) {
+            Ok(previous) => {
+                let current = crate::embedding::embedding_profile();
+                let details = serde_json::json!({
+                    "requested_kind": requested_kind,
+                    "previous_provider": previous.provider,
+                    "previous_vector_version": previous.vector_version,
+                    "provider": current.provider,
+                    "vector_version": current.vector_version,
+                });
+                if previous.vector_version == current.vector_version {
+                    self.log_request_status(
+                        request_id,
+                        "embedding.switch",
+                        "ok",
+                        started,
+                        None,
+                        Some(details),
+                    );
+                } else {
+                    self.log_request_warning(
+                        request_id,
+                        "embedding.switch",
+                        started,
+                        None,
+                        &format!(
+                            "existing vectors were built with {}; run reindex_all to re-embed them with {}",
+                            previous.vector_version, current.vector_version
+                        ),
+                        Some(details),
+                    );
+                }
+                Ok(())
+            }
+            Err(err) => {
+                self.log_request_error(
+                    request_id,
+                    "embedding.switch",
+                    started,
+                    None,
+                    &err,
+                    Some(serde_json::json!({ "requested_kind": requested_kind })),
+                );
+                Err(err)
+            }
+        }
+    }
+
     pub fn queue_diagnostics(&self) -> Result<QueueDiagnostics> {
         let queue_dead_letter_rate = self
             .state
@@ -63,6 +135,7 @@ impl AxiomSync {
         Ok(QueueDiagnostics {
             counts: self.state.queue_counts()?,
             checkpoints: self.state.list_checkpoints()?,
+            priority_lanes: self.state.queue_priority_lane_counts()?,
             queue_dead_letter_rate,
             om_status: self.state.om_status_snapshot()?,
             om_reflection_apply_metrics: self.state.om_reflection_apply_metrics_snapshot()?,
@@ -147,6 +220,25 @@ impl AxiomSync {
         Ok(entries)
     }
 
+    /// Writes the filtered request log as NDJSON (one `RequestLogEntry` per line).
+    ///
+    /// Selection matches `list_request_logs_filtered`: newest entries first, capped by `limit`.
+    pub fn export_request_logs_jsonl(
+        &self,
+        limit: usize,
+        operation: Option<&str>,
+        status: Option<&str>,
+        writer: &mut impl Write,
+    ) -> Result<usize> {
+        let entries = self.list_request_logs_filtered(limit, operation, status)?;
+        for entry in &entries {
+            serde_json::to_writer(&mut *writer, entry)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(entries.len())
+    }
+
     pub fn sessions(&self) -> Result<Vec<SessionInfo>> {
         let root = AxiomUri::root(Scope::Session);
         let mut out = Vec::new();
@@ -208,6 +300,157 @@ impl AxiomSync {
         Ok(true)
     }
 
+    /// Restores a `Session::export_bundle`; existing sessions need `force`.
+    pub fn import_session_bundle(
+        &self,
+        bundle: &SessionBundle,
+        new_id: Option<&str>,
+        force: bool,
+    ) -> Result<SessionInfo> {
+        if bundle.version != SESSION_BUNDLE_VERSION {
+            return Err(AxiomError::Validation(format!(
+                "unsupported session bundle version: {} (expected {SESSION_BUNDLE_VERSION})",
+                bundle.version
+            )));
+        }
+        let session_id = new_id
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .unwrap_or(bundle.session_id.as_str());
+        let session_uri = AxiomUri::root(Scope::Session).join(session_id)?;
+        if self.fs.exists(&session_uri) {
+            if !force {
+                return Err(AxiomError::Conflict(format!(
+                    "session already exists: {session_id}"
+                )));
+            }
+            self.delete(session_id)?;
+        }
+
+        self.session(Some(session_id)).restore_bundle(bundle)?;
+        self.reindex_uri_tree(&session_uri)?;
+
+        Ok(SessionInfo {
+            session_id: session_id.to_string(),
+            uri: session_uri.to_string(),
+            updated_at: self.session_updated_at(&session_uri),
+        })
+    }
+
+    /// Packs the session tree with its OM bundle; rejected when session redaction is on.
+    pub fn export_session(&self, session_id: &str, to: &str) -> Result<String> {
+        if self.config.redaction.mode_for(Scope::Session).is_some() {
+            return Err(AxiomError::PermissionDenied(
+                "session archive export is not allowed while session scope redaction is enabled"
+                    .to_string(),
+            ));
+        }
+        let session_uri = AxiomUri::root(Scope::Session).join(session_id)?;
+        let bundle = self.session(Some(session_id)).export_bundle()?;
+        let out = pack::export_ovpack_with_sidecar(
+            &self.fs,
+            &session_uri,
+            Path::new(to),
+            &ExportOvpackOptions::default(),
+            &self.config.redaction,
+            Some((
+                pack::OVPACK_SESSION_BUNDLE_FILE_NAME,
+                &serde_json::to_vec_pretty(&bundle)?,
+            )),
+        )?;
+        Ok(out.display().to_string())
+    }
+
+    /// Restores an `export_session` archive as `new_id`; existing sessions need `force`.
+    pub fn import_session(
+        &self,
+        file_path: &str,
+        new_id: Option<&str>,
+        force: bool,
+    ) -> Result<SessionInfo> {
+        let file_path = Path::new(file_path);
+        let raw = pack::read_ovpack_root_file(file_path, pack::OVPACK_SESSION_BUNDLE_FILE_NAME)?
+            .ok_or_else(|| {
+                AxiomError::InvalidArchive(format!(
+                    "not a session archive (missing {}): {}",
+                    pack::OVPACK_SESSION_BUNDLE_FILE_NAME,
+                    file_path.display()
+                ))
+            })?;
+        let bundle: SessionBundle = serde_json::from_slice(&raw).map_err(|err| {
+            AxiomError::InvalidArchive(format!("invalid session archive bundle: {err}"))
+        })?;
+        if bundle.version != SESSION_BUNDLE_VERSION {
+            return Err(AxiomError::Validation(format!(
+                "unsupported session bundle version: {} (expected {SESSION_BUNDLE_VERSION})",
+                bundle.version
+            )));
+        }
+        let session_id = new_id
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .unwrap_or(bundle.session_id.as_str());
+        let session_uri = AxiomUri::root(Scope::Session).join(session_id)?;
+        let session_exists = self.fs.exists(&session_uri);
+        if session_exists && !force {
+            let existing = self.session(Some(session_id)).show()?;
+            if !existing.messages.is_empty() || !existing.archives.is_empty() {
+                return Err(AxiomError::Conflict(format!(
+                    "session already exists: {session_id}"
+                )));
+            }
+        }
+
+        let staging_parent = AxiomUri::root(Scope::Temp)
+            .join(&format!("session-import-{}", uuid::Uuid::new_v4().simple()))?;
+        let staged = self.stage_session_archive(file_path, &staging_parent, session_id);
+        let staged = match staged {
+            Ok(staged) => staged,
+            Err(err) => {
+                let _ = self.fs.rm(&staging_parent, true, true);
+                return Err(err);
+            }
+        };
+        if session_exists {
+            self.delete(session_id)?;
+        }
+        let moved = self.fs.mv(&staged, &session_uri, true);
+        let _ = self.fs.rm(&staging_parent, true, true);
+        moved?;
+        self.session(Some(session_id)).restore_bundle(&bundle)?;
+        self.reindex_uri_tree(&session_uri)?;
+
+        Ok(SessionInfo {
+            session_id: session_id.to_string(),
+            uri: session_uri.to_string(),
+            updated_at: self.session_updated_at(&session_uri),
+        })
+    }
+
+    /// Verifies and unpacks a session archive under `staging_parent`, without the bundle sidecar.
+    fn stage_session_archive(
+        &self,
+        file_path: &Path,
+        staging_parent: &AxiomUri,
+        session_id: &str,
+    ) -> Result<AxiomUri> {
+        let staged = pack::import_ovpack_with_verify(
+            &self.fs,
+            file_path,
+            staging_parent,
+            false,
+            true,
+            Some(session_id),
+        )?;
+        let sidecar = self
+            .fs
+            .resolve_uri(&staged.join(pack::OVPACK_SESSION_BUNDLE_FILE_NAME)?);
+        if sidecar.exists() {
+            fs::remove_file(sidecar)?;
+        }
+        Ok(staged)
+    }
+
     pub fn reindex_all(&self) -> Result<()> {
         self.state.clear_search_index()?;
         self.state.clear_index_state()?;
@@ -236,6 +479,10 @@ impl AxiomSync {
     }
 
     pub(super) fn initialize_runtime_index(&self) -> Result<()> {
+        if self.config.indexing.embedding_cache || self.config.indexing.vector_persistence {
+            self.state
+                .prune_search_vectors(&persisted_vector_version())?;
+        }
         let current_stamp = self.current_index_profile_stamp();
         let stored_stamp = self.state.get_system_value(INDEX_PROFILE_STAMP_KEY)?;
 
@@ -261,6 +508,12 @@ impl AxiomSync {
     fn restore_index_from_state(&self) -> Result<usize> {
         let records = self.state.list_search_documents()?;
         let om_records = self.state.list_om_records()?;
+        let mut persisted_vectors = if self.config.indexing.vector_persistence {
+            self.state
+                .list_search_vectors(&persisted_vector_version())?
+        } else {
+            HashMap::new()
+        };
         let mut restored_search_documents = 0usize;
         let mut index = self
             .index
@@ -274,7 +527,8 @@ impl AxiomSync {
             if uri.scope().is_internal() {
                 continue;
             }
-            index.upsert(record);
+            let persisted = persisted_vectors.remove(&record.uri);
+            self.upsert_index_record_with_vector(&mut index, record, persisted)?;
             restored_search_documents = restored_search_documents.saturating_add(1);
         }
         for om in om_records {
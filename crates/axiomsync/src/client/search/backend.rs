@@ -1,18 +1,58 @@
 use crate::config::QUERY_PLAN_BACKEND_POLICY_MEMORY_ONLY;
 use crate::error::{AxiomError, Result};
-use crate::models::SearchOptions;
+use crate::models::{FindResult, NoResultsDiagnosis, SearchOptions};
+use crate::retrieval::{BUDGET_EXHAUSTED_NOTE, CancellationToken};
 
 use super::AxiomSync;
-use super::reranker::resolve_reranker_mode;
-use super::result::append_query_plan_note;
+use super::cache::query_result_cache_key;
+use super::reranker::apply_reranker;
+use super::result::{append_query_plan_note, sync_trace_final_topk};
 
 impl AxiomSync {
-    pub(super) fn run_retrieval_memory_only(
+    pub(super) fn run_retrieval_memory_only(&self, options: &SearchOptions) -> Result<FindResult> {
+        self.run_retrieval_memory_only_cancellable(options, None)
+    }
+
+    pub(super) fn run_retrieval_memory_only_cancellable(
         &self,
         options: &SearchOptions,
-    ) -> Result<crate::models::FindResult> {
+        cancel: Option<&CancellationToken>,
+    ) -> Result<FindResult> {
+        let capacity = self.config.search.result_cache_capacity;
+        let cache_key = if capacity > 0 {
+            let key = query_result_cache_key(options, Some(self.reranker.name()))?;
+            let index = self
+                .index
+                .read()
+                .map_err(|_| AxiomError::lock_poisoned("index"))?;
+            if let Some(mut cached) = self.query_result_cache.get(&key, &index)? {
+                append_query_plan_note(&mut cached, "query_cache:hit");
+                return Ok(cached);
+            }
+            Some((key, index.version()))
+        } else {
+            None
+        };
+
         let requested_limit = options.limit.max(1);
-        let mut result = self.run_memory_retrieval(options)?;
+        // Apply offset after rerank so concatenated pages match a larger top-k.
+        let window_limit = requested_limit.saturating_add(options.offset);
+        let windowed;
+        let retrieval_options = if options.offset == 0 {
+            options
+        } else {
+            windowed = SearchOptions {
+                limit: window_limit,
+                ..options.clone()
+            };
+            &windowed
+        };
+        let mut result = if retrieval_options.target_uris.is_empty() {
+            self.run_memory_retrieval(retrieval_options, cancel)?
+        } else {
+            self.run_multi_target_retrieval(retrieval_options, cancel)?
+        };
+        ensure_not_cancelled(cancel, "retrieval")?;
         append_query_plan_note(&mut result, "backend:memory");
         append_query_plan_note(&mut result, QUERY_PLAN_BACKEND_POLICY_MEMORY_ONLY);
         if let Some(threshold) = options.score_threshold {
@@ -22,18 +62,59 @@ impl AxiomSync {
             append_query_plan_note(&mut result, &format!("min_match_tokens:{min_match_tokens}"));
         }
 
-        let reranker_mode = resolve_reranker_mode(self.config.search.reranker.as_deref());
-        self.apply_reranker_with_mode(&options.query, &mut result, requested_limit, reranker_mode)?;
+        apply_reranker(self.reranker.as_ref(), options, &mut result, window_limit);
+        apply_result_page(&mut result, options.offset, requested_limit);
+        // Partial results cut by the deadline are not cached.
+        if let Some((key, version)) = cache_key
+            && !is_budget_exhausted(&result)
+        {
+            self.query_result_cache
+                .insert(key, options, &result, version, capacity)?;
+        }
         Ok(result)
     }
 
-    fn run_memory_retrieval(&self, options: &SearchOptions) -> Result<crate::models::FindResult> {
+    fn run_multi_target_retrieval(
+        &self,
+        options: &SearchOptions,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<FindResult> {
+        let mut results = Vec::with_capacity(options.target_uris.len());
+        for target in &options.target_uris {
+            ensure_not_cancelled(cancel, "retrieval")?;
+            let per_target = SearchOptions {
+                target_uri: Some(target.clone()),
+                target_uris: Vec::new(),
+                ..options.clone()
+            };
+            results.push(self.run_memory_retrieval(&per_target, cancel)?);
+        }
+        let mut merged = merge_target_results(results, options.limit).ok_or_else(|| {
+            AxiomError::Internal("multi-target retrieval ran without targets".to_string())
+        })?;
+        let targets = options
+            .target_uris
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        append_query_plan_note(
+            &mut merged,
+            &format!("multi_target:{}:{}", targets.len(), targets.join(",")),
+        );
+        Ok(merged)
+    }
+
+    fn run_memory_retrieval(
+        &self,
+        options: &SearchOptions,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<FindResult> {
         let mut memory_result = {
             let index = self
                 .index
                 .read()
                 .map_err(|_| AxiomError::lock_poisoned("index"))?;
-            self.drr.run(&index, options)
+            self.drr.run_cancellable(&index, options, cancel)
         };
         let embed_profile = crate::embedding::embedding_profile();
         append_query_plan_note(
@@ -46,3 +127,114 @@ impl AxiomSync {
         Ok(memory_result)
     }
 }
+
+/// Merges per-target results, keeping the best hit per uri and extending the first
+/// target's plan and trace; empty results combine per-target diagnoses.
+fn merge_target_results(results: Vec<FindResult>, limit: usize) -> Option<FindResult> {
+    let mut results = results.into_iter();
+    let mut merged = results.next()?;
+    for result in results {
+        for scope in result.query_plan.scopes {
+            if !merged.query_plan.scopes.contains(&scope) {
+                merged.query_plan.scopes.push(scope);
+            }
+        }
+        for note in result.query_plan.notes {
+            if !merged.query_plan.notes.contains(&note) {
+                merged.query_plan.notes.push(note);
+            }
+        }
+        if let (Some(trace), Some(other)) = (merged.trace.as_mut(), result.trace) {
+            trace.start_points.extend(other.start_points);
+            trace.steps.extend(other.steps);
+            trace.metrics.explored_nodes = trace
+                .metrics
+                .explored_nodes
+                .saturating_add(other.metrics.explored_nodes);
+            trace.metrics.latency_ms = trace
+                .metrics
+                .latency_ms
+                .saturating_add(other.metrics.latency_ms);
+        }
+        merged.no_results_diagnosis =
+            merge_no_results_diagnosis(merged.no_results_diagnosis, result.no_results_diagnosis);
+        for hit in result.query_results {
+            match merged
+                .query_results
+                .iter_mut()
+                .find(|existing| existing.uri == hit.uri)
+            {
+                Some(existing) if existing.score < hit.score => *existing = hit,
+                Some(_) => {}
+                None => merged.query_results.push(hit),
+            }
+        }
+    }
+    merged
+        .query_results
+        .sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.uri.cmp(&b.uri)));
+    merged.query_results.truncate(limit);
+    if !merged.query_results.is_empty() {
+        merged.no_results_diagnosis = None;
+    }
+    merged.rebuild_hit_buckets();
+    if let Some(trace) = merged.trace.as_mut() {
+        trace.target_uri = None;
+    }
+    if let Some(effective) = merged.query_plan.effective_options.as_mut() {
+        effective.target_uri = None;
+    }
+    sync_trace_final_topk(&mut merged);
+    Some(merged)
+}
+
+fn merge_no_results_diagnosis(
+    left: Option<NoResultsDiagnosis>,
+    right: Option<NoResultsDiagnosis>,
+) -> Option<NoResultsDiagnosis> {
+    match (left, right) {
+        (Some(mut left), Some(right)) => {
+            for cause in right.causes {
+                if !left.causes.contains(&cause) {
+                    left.causes.push(cause);
+                }
+            }
+            left.scoped_nodes = left.scoped_nodes.saturating_add(right.scoped_nodes);
+            left.candidate_nodes = left.candidate_nodes.saturating_add(right.candidate_nodes);
+            Some(left)
+        }
+        (left, right) => left.or(right),
+    }
+}
+
+fn apply_result_page(result: &mut FindResult, offset: usize, limit: usize) {
+    if offset > 0 {
+        let skipped = offset.min(result.query_results.len());
+        result.query_results.drain(..skipped);
+        result.rebuild_hit_buckets();
+        sync_trace_final_topk(result);
+        if let Some(effective) = result.query_plan.effective_options.as_mut() {
+            effective.limit = limit;
+        }
+        append_query_plan_note(result, &format!("offset:{offset}"));
+    }
+    result.next_offset =
+        (result.query_results.len() >= limit).then(|| offset.saturating_add(limit));
+}
+
+pub(super) fn is_budget_exhausted(result: &FindResult) -> bool {
+    result
+        .query_plan
+        .notes
+        .iter()
+        .any(|note| note == BUDGET_EXHAUSTED_NOTE)
+}
+
+pub(super) fn ensure_not_cancelled(cancel: Option<&CancellationToken>, stage: &str) -> Result<()> {
+    if cancel.is_some_and(CancellationToken::is_cancelled) {
+        return Err(AxiomError::Cancelled(format!(
+            "search cancelled during {stage}"
+        )));
+    }
+    Ok(())
+}
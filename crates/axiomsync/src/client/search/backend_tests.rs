@@ -1,19 +1,26 @@
 use chrono::{DateTime, Utc};
+use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
+use std::sync::Arc;
 use tempfile::{TempDir, tempdir};
 
+use crate::error::AxiomError;
 use crate::models::{
-    ContextHit, FindResult, IndexRecord, QueryPlan, QueueEventStatus, RuntimeHint, RuntimeHintKind,
-    SearchBudget, SearchOptions, SearchRequest, classify_hit_buckets,
+    ContextHit, FindResult, IndexRecord, MetadataFilter, NoResultsCause, QueryPlan,
+    QueueEventStatus, RuntimeHint, RuntimeHintKind, SearchBudget, SearchOptions, SearchProfile,
+    SearchRequest, SearchScopeSet, classify_hit_buckets,
 };
 use crate::om::{OmObservationChunk, OmOriginType, OmRecord, OmScope, build_scope_key};
+use crate::retrieval::{CancellationToken, NoopReranker, Reranker};
 use crate::state::{OmContinuationHints, OmReflectionApplyContext, OmReflectionApplyOutcome};
+use crate::uri::Scope;
 
-use super::reranker::{RerankerMode, resolve_reranker_mode};
+use super::reranker::{DocAwareReranker, RerankerMode, apply_reranker, resolve_reranker_mode};
 use super::{
-    AxiomSync, OmHintPolicy, merge_observation_hint_with_suggested_response,
-    merge_recent_and_om_hints, merge_runtime_om_recent_hints, normalize_runtime_hints,
+    AxiomSync, MAX_SEARCH_BATCH_SIZE, MAX_SEARCH_RESULT_LIMIT, OmHintPolicy,
+    merge_observation_hint_with_suggested_response, merge_recent_and_om_hints,
+    merge_runtime_om_recent_hints, normalize_runtime_hints,
 };
 
 #[test]
@@ -77,6 +84,7 @@ fn search_with_budget_propagates_budget_notes() {
         .search_with_request(SearchRequest {
             query: "oauth".to_string(),
             target_uri: Some("axiom://resources".to_string()),
+            target_uris: Vec::new(),
             session: None,
             limit: Some(5),
             score_threshold: None,
@@ -88,6 +96,13 @@ fn search_with_budget_propagates_budget_notes() {
                 max_depth: Some(3),
             }),
             runtime_hints: Vec::new(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
         })
         .expect("search with budget");
 
@@ -96,6 +111,56 @@ fn search_with_budget_propagates_budget_notes() {
     assert!(notes.iter().any(|x| x == "budget_depth:3"));
 }
 
+#[test]
+fn query_plan_reports_clamped_effective_search_options() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let result = app
+        .search_with_request(SearchRequest {
+            query: "oauth".to_string(),
+            target_uri: Some("axiom://resources".to_string()),
+            target_uris: Vec::new(),
+            session: None,
+            limit: Some(MAX_SEARCH_RESULT_LIMIT * 10),
+            score_threshold: Some(0.25),
+            min_match_tokens: Some(2),
+            filter: None,
+            budget: Some(SearchBudget {
+                max_ms: Some(900),
+                max_nodes: Some(0),
+                max_depth: None,
+            }),
+            runtime_hints: Vec::new(),
+            exclude_scopes: vec![Scope::Session],
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
+        })
+        .expect("search");
+
+    let effective = result
+        .query_plan
+        .effective_options
+        .expect("effective options");
+    assert_eq!(effective.limit, MAX_SEARCH_RESULT_LIMIT);
+    assert_eq!(effective.request_type, "search");
+    assert_eq!(effective.target_uri.as_deref(), Some("axiom://resources"));
+    assert_eq!(effective.score_threshold, Some(0.25));
+    assert_eq!(effective.min_match_tokens, Some(2));
+    assert_eq!(effective.budget_ms, Some(900));
+    assert_eq!(effective.budget_nodes, 1);
+    assert_eq!(
+        effective.budget_depth,
+        crate::retrieval::DrrConfig::default().max_depth
+    );
+    assert_eq!(effective.exclude_scopes, vec!["session".to_string()]);
+}
+
 #[test]
 fn memory_backend_reads_in_memory_index() {
     let temp = tempdir().expect("tempdir");
@@ -129,6 +194,7 @@ fn memory_backend_reads_in_memory_index() {
             target_uri: Some(
                 crate::uri::AxiomUri::parse("axiom://resources").expect("target parse"),
             ),
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: None,
@@ -137,6 +203,9 @@ fn memory_backend_reads_in_memory_index() {
             min_match_tokens: None,
             filter: None,
             request_type: "search".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         })
         .expect("memory retrieval");
 
@@ -244,13 +313,12 @@ fn doc_aware_reranker_prioritizes_config_documents() {
         hit("axiom://resources/app/guide.md", 0.92),
         hit("axiom://resources/app/settings.toml", 0.86),
     ]);
-    app.apply_reranker_with_mode(
-        "config env settings",
+    apply_reranker(
+        &DocAwareReranker::new(Arc::clone(&app.index)),
+        &rerank_options("config env settings"),
         &mut result,
         2,
-        RerankerMode::DocAwareV1,
-    )
-    .expect("rerank");
+    );
 
     assert_eq!(
         result.query_results[0].uri,
@@ -306,13 +374,12 @@ fn doc_aware_reranker_prefers_doc_class_tag_before_uri_heuristics() {
         hit("axiom://resources/spec/schema.md", 0.93),
         hit("axiom://resources/spec/guide.md", 0.86),
     ]);
-    app.apply_reranker_with_mode(
-        "config queue dead_letter_rate",
+    apply_reranker(
+        &DocAwareReranker::new(Arc::clone(&app.index)),
+        &rerank_options("config queue dead_letter_rate"),
         &mut result,
         2,
-        RerankerMode::DocAwareV1,
-    )
-    .expect("rerank");
+    );
 
     assert_eq!(
         result.query_results[0].uri,
@@ -320,6 +387,87 @@ fn doc_aware_reranker_prefers_doc_class_tag_before_uri_heuristics() {
     );
 }
 
+/// Test reranker that orders by uri descending, ignoring scores.
+struct UriDescendingReranker;
+
+impl Reranker for UriDescendingReranker {
+    fn name(&self) -> &str {
+        "uri-desc-test"
+    }
+
+    fn rerank(&self, _query: &SearchOptions, mut hits: Vec<ContextHit>) -> Vec<ContextHit> {
+        hits.sort_by(|a, b| b.uri.cmp(&a.uri));
+        hits
+    }
+}
+
+#[test]
+fn noop_reranker_keeps_retrieval_order_and_records_name() {
+    let mut result = sample_find_result(vec![
+        hit("axiom://resources/app/a.md", 0.4),
+        hit("axiom://resources/app/b.md", 0.9),
+    ]);
+    apply_reranker(&NoopReranker, &rerank_options("anything"), &mut result, 2);
+
+    let uris = result
+        .query_results
+        .iter()
+        .map(|hit| hit.uri.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        uris,
+        vec!["axiom://resources/app/a.md", "axiom://resources/app/b.md"]
+    );
+    assert!(result.query_plan.notes.iter().any(|x| x == "reranker:off"));
+}
+
+#[test]
+fn with_reranker_installs_custom_ordering_for_search() {
+    let (_temp, app) = setup_test_app();
+    let app = app.with_reranker(Box::new(UriDescendingReranker));
+    upsert_records(
+        &app,
+        &[
+            resources_root_record("root-rerank"),
+            resources_leaf_record(
+                "leaf-a",
+                "alpha.md",
+                "rerank",
+                "plugin rerank alpha plugin rerank",
+                &[],
+            ),
+            resources_leaf_record("leaf-z", "zulu.md", "rerank", "plugin rerank zulu", &[]),
+        ],
+    );
+
+    let result = app
+        .find(
+            "plugin rerank",
+            Some("axiom://resources"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find");
+    assert_eq!(app.reranker_name(), "uri-desc-test");
+    assert!(
+        result
+            .query_plan
+            .notes
+            .iter()
+            .any(|x| x == "reranker:uri-desc-test")
+    );
+    let uris = result
+        .query_results
+        .iter()
+        .map(|hit| hit.uri.clone())
+        .collect::<Vec<_>>();
+    let mut expected = uris.clone();
+    expected.sort_by(|a, b| b.cmp(a));
+    assert!(uris.len() >= 2);
+    assert_eq!(uris, expected);
+}
+
 #[test]
 fn search_injects_om_hint_and_records_om_metrics_in_request_log() {
     let (_temp, app) = setup_test_app();
@@ -565,6 +713,952 @@ fn search_with_session_context_always_records_om_metrics_without_om_record() {
     );
 }
 
+#[test]
+fn assemble_session_context_respects_token_budget_and_keeps_newest_messages() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let session = app.session(Some("s-assemble-context"));
+    session.load().expect("session load");
+    for idx in 0..12 {
+        session
+            .add_message(
+                "user",
+                format!("assembled context message number {idx:02} oauth"),
+            )
+            .expect("session append");
+    }
+
+    let context = app
+        .assemble_session_context("s-assemble-context", "oauth", 60)
+        .expect("assemble");
+    assert!(context.tokens.total_tokens <= 60);
+    assert_eq!(
+        context.tokens.total_tokens,
+        context.tokens.hint_tokens + context.tokens.message_tokens
+    );
+    assert!(!context.om_hint_applied);
+    assert!(!context.messages.is_empty());
+    assert!(context.tokens.dropped_messages > 0);
+    assert_eq!(context.messages.len() + context.tokens.dropped_messages, 12);
+    assert!(
+        context
+            .messages
+            .last()
+            .is_some_and(|message| message.text.contains("number 11"))
+    );
+    assert!(
+        context
+            .messages
+            .windows(2)
+            .all(|pair| pair[0].created_at <= pair[1].created_at)
+    );
+
+    let unbounded = app
+        .assemble_session_context("s-assemble-context", "oauth", 100_000)
+        .expect("assemble unbounded");
+    assert_eq!(unbounded.messages.len(), 12);
+    assert_eq!(unbounded.tokens.dropped_messages, 0);
+    assert_eq!(unbounded.tokens.dropped_hints, 0);
+    assert!(!unbounded.hints.is_empty());
+
+    let empty = app
+        .assemble_session_context("s-assemble-context", "oauth", 0)
+        .expect("assemble empty");
+    assert!(empty.messages.is_empty());
+    assert!(empty.hints.is_empty());
+    assert_eq!(empty.tokens.total_tokens, 0);
+}
+
+#[test]
+fn export_search_result_markdown_lists_each_hit_uri_and_score() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let corpus = temp.path().join("markdown_report_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    fs::write(
+        corpus.join("auth.md"),
+        "# OAuth\n\noauth token refresh flow",
+    )
+    .expect("write auth");
+    fs::write(
+        corpus.join("queue.md"),
+        "# Queue\n\noauth replay queue notes",
+    )
+    .expect("write queue");
+    app.add_resource(
+        corpus.to_str().expect("corpus str"),
+        Some("axiom://resources/markdown-report"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let result = app
+        .find(
+            "oauth",
+            Some("axiom://resources/markdown-report"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find");
+    assert!(!result.query_results.is_empty());
+
+    let markdown = crate::export_search_result_markdown(&result);
+    assert!(markdown.starts_with("# Search Report\n"));
+    assert!(markdown.contains("- query: `oauth`"));
+    for hit in &result.query_results {
+        assert!(markdown.contains(&format!("`{}`", hit.uri)), "{markdown}");
+        assert!(
+            markdown.contains(&format!("- score: `{:.4}`", hit.score)),
+            "{markdown}"
+        );
+    }
+
+    let empty = crate::export_search_result_markdown(&sample_find_result(Vec::new()));
+    assert!(empty.contains("- query: `-`"));
+    assert!(empty.contains("## Hits\n\n- none\n"));
+}
+
+#[test]
+fn format_citations_numbers_each_hit_with_uri_and_abstract_line() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let corpus = temp.path().join("citation_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    fs::write(
+        corpus.join("auth.md"),
+        "# OAuth\n\noauth token refresh flow",
+    )
+    .expect("write auth");
+    fs::write(
+        corpus.join("queue.md"),
+        "# Queue\n\noauth replay queue notes",
+    )
+    .expect("write queue");
+    app.add_resource(
+        corpus.to_str().expect("corpus str"),
+        Some("axiom://resources/citations"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let result = app
+        .find(
+            "oauth",
+            Some("axiom://resources/citations"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find");
+    assert!(!result.query_results.is_empty());
+
+    let citations = crate::format_citations(&result);
+    let lines = citations.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), result.query_results.len(), "{citations}");
+    for (rank, (line, hit)) in lines.iter().zip(&result.query_results).enumerate() {
+        assert!(
+            line.starts_with(&format!("[{}] {}", rank + 1, hit.uri)),
+            "{citations}"
+        );
+        if let Some(first) = hit
+            .abstract_text
+            .lines()
+            .find(|line| !line.trim().is_empty())
+        {
+            assert!(
+                line.ends_with(&format!(" — {}", first.trim())),
+                "{citations}"
+            );
+        }
+    }
+
+    assert!(crate::format_citations(&sample_find_result(Vec::new())).is_empty());
+}
+
+#[test]
+fn find_diagnoses_zero_result_queries() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let corpus = temp.path().join("diagnosis_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    fs::write(corpus.join("auth.md"), "oauth token refresh").expect("write corpus");
+    app.add_resource(
+        corpus.to_str().expect("corpus str"),
+        Some("axiom://resources/diagnosis"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let found = app
+        .find(
+            "oauth",
+            Some("axiom://resources/diagnosis"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find");
+    assert!(!found.query_results.is_empty());
+    assert!(found.no_results_diagnosis.is_none());
+
+    let filtered = app
+        .find(
+            "oauth",
+            Some("axiom://resources/diagnosis"),
+            Some(5),
+            None,
+            Some(MetadataFilter {
+                fields: std::collections::HashMap::from([(
+                    "mime".to_string(),
+                    serde_json::json!("application/x-no-such-type"),
+                )]),
+            }),
+        )
+        .expect("filtered find");
+    assert!(filtered.query_results.is_empty());
+    let diagnosis = filtered.no_results_diagnosis.expect("diagnosis");
+    assert_eq!(diagnosis.causes, vec![NoResultsCause::FilterEliminatedAll]);
+    assert!(diagnosis.scoped_nodes > 0);
+    assert_eq!(diagnosis.candidate_nodes, 0);
+
+    let empty = app
+        .find(
+            "oauth",
+            Some("axiom://resources/missing"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("empty scope find");
+    let diagnosis = empty.no_results_diagnosis.expect("diagnosis");
+    assert_eq!(diagnosis.causes, vec![NoResultsCause::ScopeEmpty]);
+    assert_eq!(diagnosis.scoped_nodes, 0);
+}
+
+#[test]
+fn search_with_profile_applies_saved_target_and_filter() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    for (dir, file, body) in [
+        ("work", "auth.md", "oauth token refresh for the work tenant"),
+        ("work", "legacy.json", "{\"oauth\": \"legacy work config\"}"),
+        (
+            "personal",
+            "auth.md",
+            "oauth token refresh for personal mail",
+        ),
+    ] {
+        let corpus = temp.path().join("profile_corpus").join(dir);
+        fs::create_dir_all(&corpus).expect("mkdir corpus");
+        fs::write(corpus.join(file), body).expect("write corpus");
+    }
+    app.add_resource(
+        temp.path()
+            .join("profile_corpus")
+            .to_str()
+            .expect("corpus str"),
+        Some("axiom://resources/profiles"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let saved = app
+        .save_search_profile(SearchProfile {
+            name: " work ".to_string(),
+            target_uri: Some("axiom://resources/profiles/work".to_string()),
+            filter: Some(MetadataFilter {
+                fields: std::collections::HashMap::from([(
+                    "mime".to_string(),
+                    serde_json::json!("text/markdown"),
+                )]),
+            }),
+            budget: None,
+            limit: Some(5),
+        })
+        .expect("save profile");
+    assert_eq!(saved.name, "work");
+    assert_eq!(app.list_search_profiles().expect("list").len(), 1);
+
+    let result = app
+        .search_with_profile("work", "oauth token")
+        .expect("search");
+    let uris = result
+        .query_results
+        .iter()
+        .map(|hit| hit.uri.as_str())
+        .collect::<Vec<_>>();
+    assert!(
+        uris.contains(&"axiom://resources/profiles/work/auth.md"),
+        "{uris:?}"
+    );
+    assert!(
+        uris.iter()
+            .all(|uri| uri.starts_with("axiom://resources/profiles/work")),
+        "{uris:?}"
+    );
+    assert!(!uris.contains(&"axiom://resources/profiles/work/legacy.json"));
+
+    assert!(app.remove_search_profile("work").expect("remove"));
+    assert!(!app.remove_search_profile("work").expect("remove again"));
+    let err = app
+        .search_with_profile("work", "oauth")
+        .expect_err("missing profile");
+    assert!(matches!(err, AxiomError::NotFound(_)));
+}
+
+#[test]
+fn search_snippet_highlight_is_opt_in_and_marks_query_tokens() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let corpus = temp.path().join("highlight_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    fs::write(
+        corpus.join("auth.md"),
+        "# Auth\n\nintro paragraph\nRotate the OAuth refresh token daily",
+    )
+    .expect("write auth");
+    app.add_resource(
+        corpus.to_str().expect("corpus str"),
+        Some("axiom://resources/highlight"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let mut request = SearchRequest {
+        query: "oauth refresh".to_string(),
+        target_uri: Some("axiom://resources/highlight".to_string()),
+        target_uris: Vec::new(),
+        session: None,
+        limit: Some(5),
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        budget: None,
+        runtime_hints: Vec::new(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        relation_hops: None,
+        snippet_highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+    };
+    let plain = app
+        .search_with_request(request.clone())
+        .expect("plain search");
+    assert!(
+        plain
+            .query_results
+            .iter()
+            .filter_map(|hit| hit.snippet.as_deref())
+            .all(|snippet| !snippet.contains("[["))
+    );
+
+    request.snippet_highlight = Some("[[".to_string());
+    let highlighted = app
+        .search_with_request(request.clone())
+        .expect("highlight search");
+    let hit = highlighted
+        .query_results
+        .iter()
+        .find(|hit| hit.uri == "axiom://resources/highlight/auth.md")
+        .expect("auth hit");
+    let snippet = hit.snippet.as_deref().expect("snippet");
+    assert!(snippet.contains("[[OAuth[["), "{snippet}");
+    assert!(snippet.contains("[[refresh[["), "{snippet}");
+
+    request.snippet_highlight = Some(String::new());
+    let err = app
+        .search_with_request(request)
+        .expect_err("empty delimiter");
+    assert!(matches!(err, AxiomError::Validation(_)));
+}
+
+#[test]
+fn search_offset_pages_concatenate_to_larger_top_k() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let corpus = temp.path().join("paging_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    for (name, body) in [
+        ("a.md", "oauth refresh token rotation oauth refresh"),
+        ("b.md", "oauth refresh token"),
+        ("c.md", "oauth token storage"),
+        ("d.md", "oauth login flow"),
+        ("e.md", "refresh schedule for oauth clients"),
+        ("f.md", "oauth"),
+    ] {
+        fs::write(corpus.join(name), body).expect("write doc");
+    }
+    app.add_resource(
+        corpus.to_str().expect("corpus str"),
+        Some("axiom://resources/paging"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let request = |limit: usize, offset: Option<usize>| SearchRequest {
+        query: "oauth refresh".to_string(),
+        target_uri: Some("axiom://resources/paging".to_string()),
+        target_uris: Vec::new(),
+        session: None,
+        limit: Some(limit),
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        budget: None,
+        runtime_hints: Vec::new(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        relation_hops: None,
+        snippet_highlight: None,
+        offset,
+        cursor: None,
+        paginate: false,
+    };
+    let ranked = |result: &FindResult| {
+        result
+            .query_results
+            .iter()
+            .map(|hit| (hit.uri.clone(), hit.score))
+            .collect::<Vec<_>>()
+    };
+
+    let top = app.search_with_request(request(4, None)).expect("top-4");
+    assert_eq!(top.query_results.len(), 4);
+    let first = app.search_with_request(request(2, None)).expect("page 1");
+    assert_eq!(first.next_offset, Some(2));
+    let second = app
+        .search_with_request(request(2, first.next_offset))
+        .expect("page 2");
+    assert_eq!(second.next_offset, Some(4));
+
+    let mut pages = ranked(&first);
+    pages.extend(ranked(&second));
+    assert_eq!(pages, ranked(&top));
+    assert!(
+        second
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "offset:2")
+    );
+
+    let past_end = app
+        .search_with_request(request(2, Some(100)))
+        .expect("past end");
+    assert!(past_end.query_results.is_empty());
+    assert_eq!(past_end.next_offset, None);
+
+    let err = app
+        .search_with_request(request(10, Some(495)))
+        .expect_err("window too large");
+    assert!(matches!(err, AxiomError::Validation(_)));
+}
+
+#[test]
+fn search_cursor_replays_ranked_snapshot_and_rejects_foreign_or_expired_tokens() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let corpus = temp.path().join("cursor_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    for (name, body) in [
+        ("a.md", "oauth refresh token rotation oauth refresh"),
+        ("b.md", "oauth refresh token"),
+        ("c.md", "oauth token storage"),
+        ("d.md", "oauth login flow"),
+        ("e.md", "refresh schedule for oauth clients"),
+        ("f.md", "oauth"),
+    ] {
+        fs::write(corpus.join(name), body).expect("write doc");
+    }
+    app.add_resource(
+        corpus.to_str().expect("corpus str"),
+        Some("axiom://resources/cursor"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let request = |query: &str, limit: usize, cursor: Option<String>| SearchRequest {
+        query: query.to_string(),
+        target_uri: Some("axiom://resources/cursor".to_string()),
+        target_uris: Vec::new(),
+        session: None,
+        limit: Some(limit),
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        budget: None,
+        runtime_hints: Vec::new(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        relation_hops: None,
+        snippet_highlight: None,
+        offset: None,
+        paginate: cursor.is_none(),
+        cursor,
+    };
+    let uris = |result: &FindResult| {
+        result
+            .query_results
+            .iter()
+            .map(|hit| hit.uri.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let cursor_rows = || {
+        rusqlite::Connection::open(temp.path().join(crate::client::STATE_DB_FILE_NAME))
+            .expect("open raw db")
+            .query_row("SELECT COUNT(*) FROM search_cursors", [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .expect("count cursors")
+    };
+    let mut unpaged = request("oauth refresh", 2, None);
+    unpaged.paginate = false;
+    let unpaged = app.search_with_request(unpaged).expect("unpaged");
+    assert_eq!(unpaged.next_offset, Some(2));
+    assert!(unpaged.next_cursor.is_none());
+    assert_eq!(cursor_rows(), 0);
+
+    let mut top = request("oauth refresh", 6, None);
+    top.paginate = false;
+    let top = app.search_with_request(top).expect("top-6");
+    let first = app
+        .search_with_request(request("oauth refresh", 2, None))
+        .expect("page 1");
+    let first_cursor = first.next_cursor.clone().expect("page 1 cursor");
+    assert_eq!(cursor_rows(), 1);
+    let second = app
+        .search_with_request(request("oauth refresh", 2, Some(first_cursor.clone())))
+        .expect("page 2");
+    assert!(
+        second
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "cursor:2")
+    );
+    let second_cursor = second.next_cursor.clone().expect("page 2 cursor");
+
+    // Documents added between pages must not appear in later pages.
+    fs::write(
+        corpus.join("g.md"),
+        "oauth refresh oauth refresh oauth refresh token",
+    )
+    .expect("write late doc");
+    app.add_resource(
+        corpus.join("g.md").to_str().expect("late str"),
+        Some("axiom://resources/cursor/g.md"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add late doc");
+    let third = app
+        .search_with_request(request("oauth refresh", 2, Some(second_cursor)))
+        .expect("page 3");
+
+    let mut pages = uris(&first);
+    pages.extend(uris(&second));
+    pages.extend(uris(&third));
+    assert_eq!(pages, uris(&top)[..pages.len()]);
+    assert!(!pages.iter().any(|uri| uri.ends_with("/g.md")));
+
+    let err = app
+        .search_with_request(request("oauth login", 2, Some(first_cursor.clone())))
+        .expect_err("different query");
+    assert!(matches!(err, AxiomError::Validation(_)));
+
+    let mut parts = first_cursor.split('.').collect::<Vec<_>>();
+    parts[3] = "1";
+    let expired = parts.join(".");
+    let err = app
+        .search_with_request(request("oauth refresh", 2, Some(expired)))
+        .expect_err("expired cursor");
+    assert!(matches!(err, AxiomError::Validation(_)));
+
+    let mut combined = request("oauth refresh", 2, Some(first_cursor));
+    combined.offset = Some(2);
+    let err = app
+        .search_with_request(combined)
+        .expect_err("cursor with offset");
+    assert!(matches!(err, AxiomError::Validation(_)));
+}
+
+#[test]
+fn explain_search_returns_plan_without_trace_and_logs_explain_operation() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let corpus = temp.path().join("explain_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    fs::write(corpus.join("auth.md"), "# Auth\n\nOAuth refresh flow").expect("write auth");
+    fs::write(corpus.join("notes.txt"), "storage notes").expect("write notes");
+    app.add_resource(
+        corpus.to_str().expect("corpus str"),
+        Some("axiom://resources/explain"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let explanation = app
+        .explain_search(SearchRequest {
+            query: "oauth refresh".to_string(),
+            target_uri: Some("axiom://resources/explain".to_string()),
+            target_uris: Vec::new(),
+            session: None,
+            limit: Some(3),
+            score_threshold: None,
+            min_match_tokens: None,
+            filter: None,
+            budget: Some(SearchBudget {
+                max_ms: None,
+                max_nodes: Some(7),
+                max_depth: None,
+            }),
+            runtime_hints: vec![RuntimeHint {
+                kind: RuntimeHintKind::Observation,
+                text: "user prefers pkce".to_string(),
+                source: None,
+            }],
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
+        })
+        .expect("explain");
+
+    assert!(!explanation.query_plan.typed_queries.is_empty());
+    let effective = explanation
+        .query_plan
+        .effective_options
+        .as_ref()
+        .expect("effective options");
+    assert_eq!(effective.limit, 3);
+    assert_eq!(effective.budget_nodes, 7);
+    assert_eq!(explanation.session_hints.len(), 1);
+    assert_eq!(
+        explanation
+            .budget
+            .as_ref()
+            .and_then(|budget| budget.max_nodes),
+        Some(7)
+    );
+    // The target directory plus its two files.
+    assert_eq!(explanation.estimated_candidate_nodes, 3);
+
+    assert!(
+        app.list_traces(10).expect("traces").is_empty(),
+        "explain must not persist a trace"
+    );
+    let logs = app.list_request_logs(10).expect("logs");
+    let entry = logs
+        .iter()
+        .find(|entry| entry.operation == "search.explain")
+        .expect("explain log");
+    assert_eq!(entry.status, "ok");
+    assert!(entry.trace_id.is_none());
+    assert!(logs.iter().all(|entry| entry.operation != "search"));
+}
+
+#[test]
+fn query_result_cache_serves_repeat_search_until_reindex() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let corpus = temp.path().join("cache_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    fs::write(
+        corpus.join("auth.md"),
+        "# Auth\n\nOAuth refresh token rotation",
+    )
+    .expect("write");
+    app.add_resource(
+        corpus.to_str().expect("corpus str"),
+        Some("axiom://resources/cache"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let find = |app: &AxiomSync| {
+        app.find(
+            "oauth   rotation",
+            Some("axiom://resources/cache"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find")
+    };
+    let leaf_uris = |result: &FindResult| {
+        result
+            .query_results
+            .iter()
+            .filter(|hit| hit.uri.ends_with(".md"))
+            .map(|hit| hit.uri.clone())
+            .collect::<Vec<_>>()
+    };
+    let before = app.backend_status().expect("status").query_cache;
+
+    let first = find(&app);
+    assert!(first.trace_uri.is_some());
+    assert!(
+        !first
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "query_cache:hit")
+    );
+    let second = find(&app);
+    assert!(
+        second
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "query_cache:hit")
+    );
+    assert!(second.trace_uri.is_none());
+    assert_eq!(leaf_uris(&second), leaf_uris(&first));
+    let cached = app.backend_status().expect("status").query_cache;
+    assert!(cached.enabled);
+    assert_eq!(cached.hits, before.hits + 1);
+    assert_eq!(cached.misses, before.misses + 1);
+
+    let indexed_dir = app
+        .fs
+        .resolve_uri(&crate::uri::AxiomUri::parse("axiom://resources/cache").expect("uri"));
+    fs::write(
+        indexed_dir.join("rotation.md"),
+        "# Rotation\n\nOAuth key rotation schedule",
+    )
+    .expect("write new file");
+    app.reindex_all().expect("reindex");
+
+    let third = find(&app);
+    assert!(
+        !third
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "query_cache:hit")
+    );
+    assert!(
+        leaf_uris(&third).contains(&"axiom://resources/cache/rotation.md".to_string()),
+        "{:?}",
+        leaf_uris(&third)
+    );
+    let busted = app.backend_status().expect("status").query_cache;
+    assert_eq!(busted.hits, cached.hits);
+    assert_eq!(busted.misses, cached.misses + 1);
+}
+
+#[test]
+fn query_result_cache_edit_invalidates_affected_queries_only() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    for (name, file, body) in [
+        (
+            "cache_auth",
+            "auth.md",
+            "# Auth\n\nOAuth refresh token rotation",
+        ),
+        ("cache_ops", "ops.md", "# Ops\n\nDeploy rollback runbook"),
+    ] {
+        let corpus = temp.path().join(name);
+        fs::create_dir_all(&corpus).expect("mkdir corpus");
+        fs::write(corpus.join(file), body).expect("write");
+        app.add_resource(
+            corpus.to_str().expect("corpus str"),
+            Some(&format!("axiom://resources/{name}")),
+            None,
+            None,
+            true,
+            None,
+        )
+        .expect("add");
+    }
+
+    let cache_hit = |query: &str, target: &str| {
+        app.find(query, Some(target), Some(5), None, None)
+            .expect("find")
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "query_cache:hit")
+    };
+    assert!(!cache_hit("oauth rotation", "axiom://resources/cache_auth"));
+    assert!(!cache_hit(
+        "rollback runbook",
+        "axiom://resources/cache_ops"
+    ));
+    assert!(cache_hit("oauth rotation", "axiom://resources/cache_auth"));
+    assert!(cache_hit("rollback runbook", "axiom://resources/cache_ops"));
+
+    app.append_markdown(
+        "axiom://resources/cache_auth/auth.md",
+        "\nRotation now happens hourly.",
+    )
+    .expect("edit auth");
+    assert!(!cache_hit("oauth rotation", "axiom://resources/cache_auth"));
+    assert!(cache_hit("rollback runbook", "axiom://resources/cache_ops"));
+}
+
+#[test]
+fn search_and_find_reject_queries_over_max_query_chars_unless_truncating() {
+    let temp = tempdir().expect("tempdir");
+    let mut app = AxiomSync::new(temp.path()).expect("app");
+    let mut config = (*app.config).clone();
+    config.search.max_query_chars = 16;
+    app.config = std::sync::Arc::new(config);
+    app.initialize().expect("init");
+
+    let long_query = "oauth refresh token rotation schedule";
+    let err = app
+        .search(long_query, None, None, Some(5), None, None)
+        .expect_err("search over limit");
+    match err {
+        AxiomError::Validation(message) => {
+            assert!(message.contains("37 characters"), "{message}");
+            assert!(message.contains("limit of 16"), "{message}");
+        }
+        other => panic!("expected validation error, got {other:?}"),
+    }
+    let err = app
+        .find(long_query, None, Some(5), None, None)
+        .expect_err("find over limit");
+    assert!(matches!(err, AxiomError::Validation(_)));
+    app.search("oauth refresh", None, None, Some(5), None, None)
+        .expect("search within limit");
+
+    let mut config = (*app.config).clone();
+    config.search.query_overflow = crate::config::QueryOverflowPolicy::Truncate;
+    app.config = std::sync::Arc::new(config);
+    let truncated = app
+        .search(long_query, None, None, Some(5), None, None)
+        .expect("truncated search");
+    assert!(
+        truncated
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "query_truncated:37")
+    );
+}
+
+#[test]
+fn search_freshness_check_flags_hits_edited_after_indexing() {
+    let temp = tempdir().expect("tempdir");
+    let mut app = AxiomSync::new(temp.path()).expect("app");
+    let mut config = (*app.config).clone();
+    config.search.freshness_check = true;
+    app.config = std::sync::Arc::new(config);
+    app.initialize().expect("init");
+
+    let corpus = temp.path().join("freshness_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    fs::write(
+        corpus.join("auth.md"),
+        "# Auth\n\nOAuth refresh token rotation",
+    )
+    .expect("write");
+    app.add_resource(
+        corpus.to_str().expect("corpus str"),
+        Some("axiom://resources/freshness"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let find_auth_hit = |app: &AxiomSync| {
+        app.find(
+            "oauth refresh",
+            Some("axiom://resources/freshness"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find")
+        .query_results
+        .into_iter()
+        .find(|hit| hit.uri == "axiom://resources/freshness/auth.md")
+        .expect("auth hit")
+    };
+    let fresh = find_auth_hit(&app).freshness.expect("freshness");
+    assert!(!fresh.possibly_stale);
+    assert_eq!(fresh.index_age_ms, 0);
+
+    let indexed_path = app.fs.resolve_uri(
+        &crate::uri::AxiomUri::parse("axiom://resources/freshness/auth.md").expect("uri"),
+    );
+    let file = fs::OpenOptions::new()
+        .write(true)
+        .open(&indexed_path)
+        .expect("open indexed file");
+    file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(5))
+        .expect("set mtime");
+    drop(file);
+
+    let stale = find_auth_hit(&app).freshness.expect("freshness");
+    assert!(stale.possibly_stale);
+    assert!(stale.index_age_ms >= 1_000, "{stale:?}");
+    assert!(stale.file_mtime.expect("file mtime") > stale.indexed_mtime);
+}
+
 #[test]
 fn fetch_session_om_state_returns_none_when_om_disabled() {
     let (_temp, app) = setup_test_app();
@@ -1327,6 +2421,7 @@ fn search_with_runtime_hints_has_no_message_or_outbox_side_effect() {
         .search_with_request(SearchRequest {
             query: "runtime".to_string(),
             target_uri: Some("axiom://resources".to_string()),
+            target_uris: Vec::new(),
             session: None,
             limit: Some(5),
             score_threshold: None,
@@ -1338,6 +2433,13 @@ fn search_with_runtime_hints_has_no_message_or_outbox_side_effect() {
                 text: "ephemeral runtime hint".to_string(),
                 source: Some("episodic".to_string()),
             }],
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
         })
         .expect("search");
     assert!(!result.query_results.is_empty());
@@ -1377,6 +2479,7 @@ fn search_with_runtime_hints_without_session_uses_runtime_query_not_memory_focus
         .search_with_request(SearchRequest {
             query: "runtime".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             limit: Some(5),
             score_threshold: None,
@@ -1388,6 +2491,13 @@ fn search_with_runtime_hints_without_session_uses_runtime_query_not_memory_focus
                 text: "ephemeral preference hint".to_string(),
                 source: Some("episodic".to_string()),
             }],
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
         })
         .expect("search");
 
@@ -1401,6 +2511,375 @@ fn search_with_runtime_hints_without_session_uses_runtime_query_not_memory_focus
     assert!(!kinds.contains(&"memory_focus"));
 }
 
+#[test]
+fn search_exclude_session_scope_drops_session_hits_and_keeps_resources() {
+    let (_temp, app) = setup_test_app();
+    upsert_records(
+        &app,
+        &[
+            resources_root_record("root-exclude-scope"),
+            resources_leaf_record(
+                "leaf-exclude-scope",
+                "oauth-guide.md",
+                "oauth guide",
+                "oauth refresh flow for resources",
+                &["oauth"],
+            ),
+            session_root_record("session-root-exclude-scope"),
+            session_leaf_record(
+                "session-leaf-exclude-scope",
+                "oauth-notes.md",
+                "oauth session notes",
+                "oauth refresh flow discussed in session",
+            ),
+        ],
+    );
+    let request = |exclude_scopes: Vec<Scope>| SearchRequest {
+        query: "oauth refresh".to_string(),
+        target_uri: None,
+        target_uris: Vec::new(),
+        session: Some("s-exclude".to_string()),
+        limit: Some(10),
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        budget: None,
+        runtime_hints: Vec::new(),
+        exclude_scopes,
+        scope_set: SearchScopeSet::AllContent,
+        relation_hops: None,
+        snippet_highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+    };
+
+    let baseline = app
+        .search_with_request(request(Vec::new()))
+        .expect("search");
+    assert!(
+        baseline
+            .query_results
+            .iter()
+            .any(|hit| hit.uri.starts_with("axiom://session"))
+    );
+
+    let excluded = app
+        .search_with_request(request(vec![Scope::Session]))
+        .expect("search excluding session");
+    assert!(
+        excluded
+            .query_results
+            .iter()
+            .all(|hit| !hit.uri.starts_with("axiom://session"))
+    );
+    assert!(
+        excluded
+            .query_results
+            .iter()
+            .any(|hit| hit.uri == "axiom://resources/oauth-guide.md")
+    );
+    assert!(!excluded.query_plan.scopes.contains(&"session".to_string()));
+    assert!(
+        excluded
+            .query_plan
+            .notes
+            .contains(&"exclude_scopes:session".to_string())
+    );
+}
+
+#[test]
+fn search_rejects_excluding_target_scope() {
+    let (_temp, app) = setup_test_app();
+    let err = app
+        .search_with_request(SearchRequest {
+            query: "oauth".to_string(),
+            target_uri: Some("axiom://resources".to_string()),
+            target_uris: Vec::new(),
+            session: None,
+            limit: Some(5),
+            score_threshold: None,
+            min_match_tokens: None,
+            filter: None,
+            budget: None,
+            runtime_hints: Vec::new(),
+            exclude_scopes: vec![Scope::Resources],
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
+        })
+        .expect_err("contradicting exclusion must fail");
+    assert!(matches!(err, AxiomError::Validation(_)));
+}
+
+#[test]
+fn search_batch_preserves_order_reports_item_errors_and_logs_once() {
+    let (_temp, app) = setup_test_app();
+    upsert_records(
+        &app,
+        &[
+            resources_root_record("root-batch"),
+            resources_leaf_record(
+                "leaf-batch-oauth",
+                "oauth-guide.md",
+                "oauth guide",
+                "oauth refresh flow",
+                &["oauth"],
+            ),
+            resources_leaf_record(
+                "leaf-batch-deploy",
+                "deploy-guide.md",
+                "deploy guide",
+                "deploy rollback checklist",
+                &["deploy"],
+            ),
+        ],
+    );
+    let request = |query: &str, target_uri: Option<&str>| SearchRequest {
+        query: query.to_string(),
+        target_uri: target_uri.map(ToString::to_string),
+        target_uris: Vec::new(),
+        session: Some("s-batch".to_string()),
+        limit: Some(5),
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        budget: None,
+        runtime_hints: Vec::new(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        relation_hops: None,
+        snippet_highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+    };
+
+    let items = app
+        .search_batch(vec![
+            request("oauth refresh", Some("axiom://resources")),
+            request("deploy rollback", Some("not-a-uri")),
+            request("deploy rollback", Some("axiom://resources")),
+        ])
+        .expect("batch");
+    assert_eq!(items.len(), 3);
+    let first = items[0].result.as_ref().expect("first ok");
+    assert!(
+        first
+            .query_results
+            .iter()
+            .any(|hit| hit.uri.ends_with("oauth-guide.md"))
+    );
+    assert!(items[1].result.is_err());
+    let third = items[2].result.as_ref().expect("third ok");
+    assert!(
+        third
+            .query_results
+            .iter()
+            .any(|hit| hit.uri.ends_with("deploy-guide.md"))
+    );
+
+    let batch_logs = app
+        .list_request_logs_filtered(10, Some("search.batch"), None)
+        .expect("logs");
+    assert_eq!(batch_logs.len(), 1);
+    let details = batch_logs[0].details.as_ref().expect("details");
+    assert_eq!(details["count"], 3);
+    assert_eq!(details["error_count"], 1);
+    assert_eq!(details["distinct_sessions"], 1);
+    assert_eq!(details["items"][1]["status"], "error");
+    assert_eq!(details["items"][1]["error_code"], "INVALID_URI");
+    assert!(details["items"][2]["latency_ms"].is_u64());
+    let single_logs = app
+        .list_request_logs_filtered(10, Some("search"), None)
+        .expect("logs");
+    assert!(single_logs.is_empty());
+}
+
+#[test]
+fn search_batch_results_match_individual_search_with_request_calls() {
+    let (_temp, app) = setup_test_app();
+    upsert_records(
+        &app,
+        &[
+            resources_root_record("root-batch-parity"),
+            resources_leaf_record(
+                "leaf-parity-oauth",
+                "oauth-guide.md",
+                "oauth guide",
+                "oauth refresh flow",
+                &["oauth"],
+            ),
+            resources_leaf_record(
+                "leaf-parity-deploy",
+                "deploy-guide.md",
+                "deploy guide",
+                "deploy rollback checklist",
+                &["deploy"],
+            ),
+        ],
+    );
+    let requests = ["oauth refresh", "deploy rollback", "guide"]
+        .into_iter()
+        .map(|query| SearchRequest {
+            query: query.to_string(),
+            target_uri: Some("axiom://resources".to_string()),
+            target_uris: Vec::new(),
+            session: Some("s-batch-parity".to_string()),
+            limit: Some(5),
+            score_threshold: None,
+            min_match_tokens: None,
+            filter: None,
+            budget: None,
+            runtime_hints: Vec::new(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
+        })
+        .collect::<Vec<_>>();
+
+    let items = app.search_batch(requests.clone()).expect("batch");
+    assert_eq!(items.len(), requests.len());
+
+    let mut trace_ids = HashSet::new();
+    for (item, request) in items.iter().zip(requests) {
+        let batched = item.result.as_ref().expect("batched result");
+        let single = app.search_with_request(request).expect("single search");
+        let hits = |result: &FindResult| {
+            result
+                .query_results
+                .iter()
+                .map(|hit| (hit.uri.clone(), hit.score))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(hits(batched), hits(&single));
+        let trace = batched.trace.as_ref().expect("batched item trace");
+        assert!(trace_ids.insert(trace.trace_id.clone()));
+    }
+}
+
+/// Reranker that cancels the search token while retrieval is in flight.
+struct CancelOnRerank(CancellationToken);
+
+impl Reranker for CancelOnRerank {
+    fn name(&self) -> &str {
+        "cancel-on-rerank-test"
+    }
+
+    fn rerank(&self, _query: &SearchOptions, hits: Vec<ContextHit>) -> Vec<ContextHit> {
+        self.0.cancel();
+        hits
+    }
+}
+
+#[test]
+fn search_cancellable_returns_cancelled_and_logs_cancellation() {
+    let (_temp, app) = setup_test_app();
+    upsert_records(
+        &app,
+        &[
+            resources_root_record("root-cancel"),
+            resources_leaf_record(
+                "leaf-cancel",
+                "oauth-guide.md",
+                "oauth guide",
+                "oauth refresh flow",
+                &["oauth"],
+            ),
+            resources_leaf_record(
+                "leaf-cancel-faq",
+                "oauth-faq.md",
+                "oauth faq",
+                "oauth token rotation",
+                &["oauth"],
+            ),
+        ],
+    );
+    let request = || SearchRequest {
+        query: "oauth".to_string(),
+        target_uri: None,
+        target_uris: Vec::new(),
+        session: None,
+        limit: Some(5),
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        budget: None,
+        runtime_hints: Vec::new(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        relation_hops: None,
+        snippet_highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+    };
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+
+    let started = std::time::Instant::now();
+    let err = app
+        .search_with_request_cancellable(request(), &cancel)
+        .expect_err("cancelled search");
+    assert!(matches!(err, AxiomError::Cancelled(_)));
+    assert!(started.elapsed() < std::time::Duration::from_secs(2));
+
+    let logs = app
+        .list_request_logs_filtered(5, Some("search"), Some("cancelled"))
+        .expect("logs");
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].error_code.as_deref(), Some("CANCELLED"));
+    assert!(logs[0].trace_id.is_none());
+
+    let mid_cancel = CancellationToken::new();
+    let app = app.with_reranker(Box::new(CancelOnRerank(mid_cancel.clone())));
+    let err = app
+        .search_with_request_cancellable(request(), &mid_cancel)
+        .expect_err("search cancelled mid retrieval");
+    assert!(matches!(err, AxiomError::Cancelled(_)));
+    assert!(mid_cancel.is_cancelled());
+
+    let logs = app
+        .list_request_logs_filtered(5, Some("search"), Some("cancelled"))
+        .expect("logs");
+    assert_eq!(logs.len(), 2);
+}
+
+#[test]
+fn search_batch_rejects_oversized_batches() {
+    let (_temp, app) = setup_test_app();
+    let requests = (0..=MAX_SEARCH_BATCH_SIZE)
+        .map(|idx| SearchRequest {
+            query: format!("q{idx}"),
+            target_uri: None,
+            target_uris: Vec::new(),
+            session: None,
+            limit: None,
+            score_threshold: None,
+            min_match_tokens: None,
+            filter: None,
+            budget: None,
+            runtime_hints: Vec::new(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
+        })
+        .collect::<Vec<_>>();
+    let err = app.search_batch(requests).expect_err("oversized batch");
+    assert!(matches!(err, AxiomError::Validation(_)));
+}
+
 #[test]
 fn merge_observation_hint_with_suggested_response_appends_next_hint() {
     let merged = merge_observation_hint_with_suggested_response(
@@ -1452,6 +2931,7 @@ fn search_options(query: &str) -> SearchOptions {
     SearchOptions {
         query: query.to_string(),
         target_uri: Some(crate::uri::AxiomUri::root(crate::uri::Scope::Resources)),
+        target_uris: Vec::new(),
         session: None,
         session_hints: Vec::new(),
         budget: None,
@@ -1460,6 +2940,9 @@ fn search_options(query: &str) -> SearchOptions {
         min_match_tokens: None,
         filter: None,
         request_type: "search".to_string(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        offset: 0,
     }
 }
 
@@ -1522,6 +3005,38 @@ fn resources_leaf_record(
     }
 }
 
+fn session_root_record(id: &str) -> IndexRecord {
+    IndexRecord {
+        id: id.to_string(),
+        uri: "axiom://session".to_string(),
+        parent_uri: None,
+        is_leaf: false,
+        context_type: "session".to_string(),
+        name: "session".to_string(),
+        abstract_text: "session root".to_string(),
+        content: "session root".to_string(),
+        tags: Vec::new(),
+        updated_at: Utc::now(),
+        depth: 0,
+    }
+}
+
+fn session_leaf_record(id: &str, name: &str, abstract_text: &str, content: &str) -> IndexRecord {
+    IndexRecord {
+        id: id.to_string(),
+        uri: format!("axiom://session/{name}"),
+        parent_uri: Some("axiom://session".to_string()),
+        is_leaf: true,
+        context_type: "session".to_string(),
+        name: name.to_string(),
+        abstract_text: abstract_text.to_string(),
+        content: content.to_string(),
+        tags: Vec::new(),
+        updated_at: Utc::now(),
+        depth: 1,
+    }
+}
+
 fn base_om_record(id: &str, scope: OmScope, scope_key: &str, now: DateTime<Utc>) -> OmRecord {
     OmRecord {
         id: id.to_string(),
@@ -1566,6 +3081,27 @@ fn hit(uri: &str, score: f32) -> ContextHit {
         snippet: None,
         matched_heading: None,
         score_components: crate::models::ScoreComponents::default(),
+        relation_neighborhood: None,
+        freshness: None,
+    }
+}
+
+fn rerank_options(query: &str) -> SearchOptions {
+    SearchOptions {
+        query: query.to_string(),
+        target_uri: None,
+        target_uris: Vec::new(),
+        session: None,
+        session_hints: Vec::new(),
+        budget: None,
+        limit: 10,
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        request_type: "find".to_string(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        offset: 0,
     }
 }
 
@@ -1595,5 +3131,186 @@ fn sample_find_result(hits: Vec<ContextHit>) -> FindResult {
         skills,
         trace: None,
         trace_uri: None,
+        next_offset: None,
+        next_cursor: None,
+        no_results_diagnosis: None,
+    }
+}
+
+#[test]
+fn search_with_multiple_targets_unions_subtrees_and_rejects_internal_scopes() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    for (dir, target, body) in [
+        (
+            "docs",
+            "axiom://resources/docs",
+            "oauth refresh token rotation",
+        ),
+        (
+            "notes",
+            "axiom://resources/notes",
+            "oauth refresh meeting notes",
+        ),
+        (
+            "other",
+            "axiom://resources/other",
+            "oauth refresh outside targets",
+        ),
+    ] {
+        let corpus = temp.path().join(dir);
+        fs::create_dir_all(&corpus).expect("mkdir corpus");
+        fs::write(corpus.join("entry.md"), body).expect("write doc");
+        app.add_resource(
+            corpus.to_str().expect("corpus str"),
+            Some(target),
+            None,
+            None,
+            true,
+            None,
+        )
+        .expect("add");
     }
+
+    let request = |target_uris: Vec<String>| SearchRequest {
+        query: "oauth refresh".to_string(),
+        target_uri: Some("axiom://resources/docs".to_string()),
+        target_uris,
+        session: None,
+        limit: Some(10),
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        budget: None,
+        runtime_hints: Vec::new(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        relation_hops: None,
+        snippet_highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+    };
+
+    let result = app
+        .search_with_request(request(vec![
+            "axiom://resources/notes".to_string(),
+            "axiom://resources/docs".to_string(),
+        ]))
+        .expect("multi-target search");
+    let uris = result
+        .query_results
+        .iter()
+        .map(|hit| hit.uri.as_str())
+        .collect::<Vec<_>>();
+    assert!(
+        uris.iter()
+            .any(|uri| uri.starts_with("axiom://resources/docs"))
+    );
+    assert!(
+        uris.iter()
+            .any(|uri| uri.starts_with("axiom://resources/notes"))
+    );
+    assert!(
+        !uris
+            .iter()
+            .any(|uri| uri.starts_with("axiom://resources/other"))
+    );
+    assert_eq!(
+        uris.len(),
+        uris.iter().collect::<HashSet<_>>().len(),
+        "hits must be deduped by uri"
+    );
+    assert!(
+        result
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "multi_target:2:axiom://resources/docs,axiom://resources/notes")
+    );
+
+    let err = app
+        .search_with_request(request(vec!["axiom://queue".to_string()]))
+        .expect_err("internal target must be rejected");
+    assert!(matches!(err, AxiomError::Validation(_)));
+}
+
+#[test]
+fn search_budget_deadline_returns_partial_result_logged_as_truncated_ok() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let corpus = temp.path().join("deadline_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    fs::write(
+        corpus.join("auth.md"),
+        "# Auth\n\nOAuth refresh token rotation",
+    )
+    .expect("write");
+    app.add_resource(
+        corpus.to_str().expect("corpus str"),
+        Some("axiom://resources/deadline"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let request = || SearchRequest {
+        query: "oauth rotation".to_string(),
+        target_uri: Some("axiom://resources/deadline".to_string()),
+        target_uris: Vec::new(),
+        session: None,
+        limit: Some(5),
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        budget: Some(SearchBudget {
+            max_ms: Some(0),
+            max_nodes: None,
+            max_depth: None,
+        }),
+        runtime_hints: Vec::new(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        relation_hops: None,
+        snippet_highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+    };
+    let first = app.search_with_request(request()).expect("search");
+    assert!(
+        first
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "budget_exhausted")
+    );
+    let second = app.search_with_request(request()).expect("repeat search");
+    assert!(
+        !second
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "query_cache:hit")
+    );
+
+    let logs = app
+        .list_request_logs_filtered(5, Some("search"), Some("ok"))
+        .expect("request logs");
+    let details = logs
+        .first()
+        .and_then(|entry| entry.details.as_ref())
+        .expect("search details");
+    assert_eq!(
+        details
+            .get("truncated")
+            .and_then(serde_json::Value::as_bool),
+        Some(true)
+    );
 }
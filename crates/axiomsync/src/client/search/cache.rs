@@ -0,0 +1,325 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::error::{AxiomError, Result};
+use crate::index::InMemoryIndex;
+use crate::models::{FindResult, QueryResultCacheStatus, SearchOptions};
+
+/// LRU cache of retrieval results; traces are not cached.
+/// An entry is dropped once the index touches one of its hits or a URI under its targets.
+#[derive(Debug, Default)]
+pub(in crate::client) struct QueryResultCache {
+    entries: Mutex<LruEntries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct LruEntries {
+    results: HashMap<String, CachedResult>,
+    order: VecDeque<String>,
+}
+
+#[derive(Debug)]
+struct CachedResult {
+    result: FindResult,
+    /// Index version the result was computed against.
+    version: u64,
+    /// Search targets; empty means every scope.
+    targets: Vec<String>,
+    hit_uris: HashSet<String>,
+}
+
+impl CachedResult {
+    fn depends_on(&self, uri: &str) -> bool {
+        self.targets.is_empty()
+            || self.hit_uris.contains(uri)
+            || self.targets.iter().any(|target| {
+                uri.strip_prefix(target.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+    }
+}
+
+impl LruEntries {
+    fn remove(&mut self, key: &str) {
+        self.results.remove(key);
+        self.order.retain(|existing| existing != key);
+    }
+
+    fn touch(&mut self, key: &str) {
+        if let Some(position) = self.order.iter().position(|existing| existing == key)
+            && let Some(existing) = self.order.remove(position)
+        {
+            self.order.push_back(existing);
+        }
+    }
+}
+
+impl QueryResultCache {
+    /// Revalidates the entry against URIs `index` changed since it was cached.
+    pub(super) fn get(&self, key: &str, index: &InMemoryIndex) -> Result<Option<FindResult>> {
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|_| AxiomError::mutex_poisoned("query result cache"))?;
+        let current = index.version();
+        let stale = entries.results.get_mut(key).is_some_and(|entry| {
+            if entry.version == current {
+                return false;
+            }
+            let affected = index
+                .touched_since(entry.version)
+                .is_none_or(|uris| uris.into_iter().any(|uri| entry.depends_on(uri)));
+            if !affected {
+                entry.version = current;
+            }
+            affected
+        });
+        if stale {
+            entries.remove(key);
+        }
+        let cached = entries.results.get(key).map(|entry| entry.result.clone());
+        if cached.is_some() {
+            entries.touch(key);
+        }
+        let counter = if cached.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        Ok(cached)
+    }
+
+    /// `version` is the index version read before retrieval ran.
+    pub(super) fn insert(
+        &self,
+        key: String,
+        options: &SearchOptions,
+        result: &FindResult,
+        version: u64,
+        capacity: usize,
+    ) -> Result<()> {
+        if capacity == 0 {
+            return Ok(());
+        }
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|_| AxiomError::mutex_poisoned("query result cache"))?;
+        let mut stored = result.clone();
+        stored.trace = None;
+        stored.trace_uri = None;
+        let entry = CachedResult {
+            hit_uris: stored
+                .query_results
+                .iter()
+                .map(|hit| hit.uri.clone())
+                .collect(),
+            result: stored,
+            version,
+            targets: options
+                .target_uri
+                .iter()
+                .chain(&options.target_uris)
+                .map(ToString::to_string)
+                .collect(),
+        };
+        if entries.results.insert(key.clone(), entry).is_some() {
+            entries.touch(&key);
+        } else {
+            entries.order.push_back(key);
+        }
+        while entries.results.len() > capacity {
+            let Some(evicted) = entries.order.pop_front() else {
+                break;
+            };
+            entries.results.remove(&evicted);
+        }
+        Ok(())
+    }
+
+    pub(in crate::client) fn status(&self, capacity: usize) -> Result<QueryResultCacheStatus> {
+        let entries = self
+            .entries
+            .lock()
+            .map_err(|_| AxiomError::mutex_poisoned("query result cache"))?
+            .results
+            .len();
+        Ok(QueryResultCacheStatus {
+            enabled: capacity > 0,
+            capacity,
+            entries,
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        })
+    }
+}
+
+/// Normalizes whitespace only; case can affect the exact-match fast path.
+pub(super) fn query_result_cache_key(
+    options: &SearchOptions,
+    reranker: Option<&str>,
+) -> Result<String> {
+    let normalized = SearchOptions {
+        query: options
+            .query
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+        ..options.clone()
+    };
+    let embed = crate::embedding::embedding_profile();
+    let material = serde_json::to_vec(&serde_json::json!({
+        "options": normalized,
+        "reranker": reranker,
+        "embedder": format!("{}@{}:{}", embed.provider, embed.vector_version, embed.dim),
+    }))?;
+    Ok(blake3::hash(&material).to_hex().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ContextHit, HitBuckets, QueryPlan, SearchScopeSet};
+    use crate::uri::AxiomUri;
+
+    fn result_with_query(query: &str) -> FindResult {
+        FindResult {
+            query_plan: QueryPlan {
+                notes: vec![query.to_string()],
+                ..QueryPlan::default()
+            },
+            query_results: Vec::new(),
+            hit_buckets: HitBuckets::default(),
+            memories: Vec::new(),
+            resources: Vec::new(),
+            skills: Vec::new(),
+            trace: None,
+            trace_uri: None,
+            next_offset: None,
+            next_cursor: None,
+            no_results_diagnosis: None,
+        }
+    }
+
+    fn result_with_hit(uri: &str) -> FindResult {
+        let mut result = result_with_query(uri);
+        result.query_results.push(
+            serde_json::from_value::<ContextHit>(serde_json::json!({
+                "uri": uri,
+                "score": 1.0,
+                "abstract": "",
+                "context_type": "resource",
+                "relations": [],
+            }))
+            .expect("hit"),
+        );
+        result
+    }
+
+    fn options(target: Option<&str>) -> SearchOptions {
+        SearchOptions {
+            query: "  oauth   flow ".to_string(),
+            target_uri: target.map(|raw| AxiomUri::parse(raw).expect("target")),
+            target_uris: Vec::new(),
+            session: None,
+            session_hints: Vec::new(),
+            budget: None,
+            limit: 5,
+            score_threshold: None,
+            min_match_tokens: None,
+            filter: None,
+            request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
+        }
+    }
+
+    #[test]
+    fn query_result_cache_evicts_least_recently_used_entry() {
+        let cache = QueryResultCache::default();
+        let index = InMemoryIndex::new();
+        let options = options(None);
+        for key in ["a", "b"] {
+            cache
+                .insert(key.to_string(), &options, &result_with_query(key), 0, 2)
+                .expect("insert");
+        }
+        assert!(cache.get("a", &index).expect("get a").is_some());
+        cache
+            .insert("c".to_string(), &options, &result_with_query("c"), 0, 2)
+            .expect("insert c");
+
+        assert!(cache.get("b", &index).expect("get b").is_none());
+        assert!(cache.get("a", &index).expect("get a").is_some());
+        assert!(cache.get("c", &index).expect("get c").is_some());
+        let status = cache.status(2).expect("status");
+        assert_eq!((status.entries, status.hits, status.misses), (2, 3, 1));
+    }
+
+    #[test]
+    fn query_result_cache_drops_only_entries_touching_changed_uris() {
+        let cache = QueryResultCache::default();
+        let mut index = InMemoryIndex::new();
+        let version = index.version();
+        let insert = |key: &str, target: Option<&str>, result: FindResult| {
+            cache
+                .insert(key.to_string(), &options(target), &result, version, 8)
+                .expect("insert");
+        };
+        insert(
+            "auth",
+            Some("axiom://resources/auth"),
+            result_with_query("auth"),
+        );
+        insert(
+            "ops",
+            Some("axiom://resources/ops"),
+            result_with_hit("axiom://resources/shared/runbook.md"),
+        );
+        insert("all", None, result_with_query("all"));
+
+        index.remove("axiom://resources/auth/login.md");
+        assert!(cache.get("auth", &index).expect("get auth").is_none());
+        assert!(cache.get("ops", &index).expect("get ops").is_some());
+        assert!(cache.get("all", &index).expect("get all").is_none());
+
+        index.remove("axiom://resources/shared/runbook.md");
+        assert!(cache.get("ops", &index).expect("get ops").is_none());
+
+        cache
+            .insert(
+                "ops".to_string(),
+                &options(Some("axiom://resources/ops")),
+                &result_with_query("ops"),
+                index.version(),
+                8,
+            )
+            .expect("insert ops");
+        index.clear();
+        assert!(cache.get("ops", &index).expect("get after clear").is_none());
+        assert_eq!(cache.status(8).expect("status").entries, 0);
+    }
+
+    #[test]
+    fn query_result_cache_key_normalizes_whitespace() {
+        let options = options(None);
+        let compact = SearchOptions {
+            query: "oauth flow".to_string(),
+            ..options.clone()
+        };
+        let key = query_result_cache_key(&options, None).expect("key");
+        assert_eq!(
+            key,
+            query_result_cache_key(&compact, None).expect("compact key")
+        );
+        assert_ne!(
+            key,
+            query_result_cache_key(&options, Some("doc-aware-v1")).expect("reranker key")
+        );
+    }
+}
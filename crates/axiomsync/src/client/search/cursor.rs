@@ -0,0 +1,292 @@
+use std::collections::HashSet;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AxiomError, Result};
+use crate::models::{ContextHit, FindResult, HitBuckets, QueryPlan, SearchOptions};
+use crate::retrieval::CancellationToken;
+use crate::state::SearchCursorRecord;
+
+use super::backend::is_budget_exhausted;
+use super::result::append_query_plan_note;
+use super::{AxiomSync, MAX_SEARCH_RESULT_LIMIT, validate_search_offset};
+
+const SEARCH_CURSOR_VERSION: &str = "sc1";
+const SEARCH_CURSOR_FINGERPRINT_CHARS: usize = 16;
+/// Pages to prefetch when the snapshot runs short.
+const SEARCH_CURSOR_PREFETCH_PAGES: usize = 4;
+
+/// `sc1.<snapshot_id>.<position>.<expires_at>.<fingerprint>`; `expires_at` is unix seconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct SearchCursorToken {
+    snapshot_id: String,
+    position: usize,
+    expires_at: i64,
+    fingerprint: String,
+}
+
+impl SearchCursorToken {
+    pub(super) fn parse(raw: &str) -> Result<Self> {
+        let invalid = || AxiomError::Validation(format!("invalid search cursor: {raw}"));
+        let mut parts = raw.trim().split('.');
+        if parts.next() != Some(SEARCH_CURSOR_VERSION) {
+            return Err(invalid());
+        }
+        let (Some(snapshot_id), Some(position), Some(expires_at), Some(fingerprint), None) = (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        ) else {
+            return Err(invalid());
+        };
+        if snapshot_id.is_empty() || fingerprint.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            snapshot_id: snapshot_id.to_string(),
+            position: position.parse().map_err(|_| invalid())?,
+            expires_at: expires_at.parse().map_err(|_| invalid())?,
+            fingerprint: fingerprint.to_string(),
+        })
+    }
+
+    fn encode(&self) -> String {
+        format!(
+            "{SEARCH_CURSOR_VERSION}.{}.{}.{}.{}",
+            self.snapshot_id, self.position, self.expires_at, self.fingerprint
+        )
+    }
+}
+
+/// Ranked hits a cursor pages through; served entries are never reordered.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SearchCursorSnapshot {
+    query_plan: QueryPlan,
+    ranked: Vec<ContextHit>,
+    /// True when retrieval has no more hits.
+    complete: bool,
+}
+
+impl AxiomSync {
+    /// Serves the next page from the cursor snapshot, or runs retrieval and issues a cursor
+    /// when `paginate` asks for one.
+    pub(super) fn run_paged_retrieval(
+        &self,
+        options: &SearchOptions,
+        cursor: Option<&SearchCursorToken>,
+        paginate: bool,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<FindResult> {
+        let Some(cursor) = cursor else {
+            let mut result = self.run_retrieval_memory_only_cancellable(options, cancel)?;
+            if paginate
+                && let Some(position) = result.next_offset
+                && !is_budget_exhausted(&result)
+            {
+                let snapshot = SearchCursorSnapshot {
+                    query_plan: result.query_plan.clone(),
+                    ranked: if options.offset == 0 {
+                        result.query_results.clone()
+                    } else {
+                        Vec::new()
+                    },
+                    complete: false,
+                };
+                result.next_cursor = self.issue_search_cursor(
+                    &uuid::Uuid::new_v4().simple().to_string(),
+                    &search_cursor_fingerprint(options)?,
+                    &snapshot,
+                    position,
+                )?;
+            }
+            return Ok(result);
+        };
+
+        let now = Utc::now().timestamp();
+        if cursor.expires_at <= now {
+            return Err(AxiomError::Validation("search cursor expired".to_string()));
+        }
+        let fingerprint = search_cursor_fingerprint(options)?;
+        if !fingerprint.starts_with(&cursor.fingerprint) {
+            return Err(different_query_error());
+        }
+        let record = self
+            .state
+            .get_search_cursor(&cursor.snapshot_id)?
+            .filter(|record| record.expires_at > now)
+            .ok_or_else(|| {
+                AxiomError::Validation("search cursor expired or unknown".to_string())
+            })?;
+        if record.fingerprint != fingerprint {
+            return Err(different_query_error());
+        }
+        let mut snapshot = serde_json::from_str::<SearchCursorSnapshot>(&record.snapshot_json)?;
+
+        let limit = options.limit;
+        let position = cursor.position;
+        validate_search_offset(position, limit)?;
+        let page_end = position.saturating_add(limit);
+        if snapshot.ranked.len() < page_end && !snapshot.complete {
+            self.refill_search_cursor_snapshot(&mut snapshot, options, position, cancel)?;
+        }
+
+        let hits = snapshot
+            .ranked
+            .iter()
+            .skip(position)
+            .take(limit)
+            .cloned()
+            .collect::<Vec<_>>();
+        let has_more = snapshot.ranked.len() > page_end
+            || (!snapshot.complete && hits.len() == limit && page_end < MAX_SEARCH_RESULT_LIMIT);
+        let mut result = FindResult {
+            query_plan: snapshot.query_plan.clone(),
+            query_results: hits,
+            hit_buckets: HitBuckets::default(),
+            memories: Vec::new(),
+            resources: Vec::new(),
+            skills: Vec::new(),
+            trace: None,
+            trace_uri: None,
+            next_offset: has_more.then_some(page_end),
+            next_cursor: None,
+            no_results_diagnosis: None,
+        };
+        result.rebuild_hit_buckets();
+        if let Some(effective) = result.query_plan.effective_options.as_mut() {
+            effective.limit = limit;
+        }
+        append_query_plan_note(&mut result, &format!("cursor:{position}"));
+        if has_more {
+            result.next_cursor =
+                self.issue_search_cursor(&cursor.snapshot_id, &fingerprint, &snapshot, page_end)?;
+        }
+        Ok(result)
+    }
+
+    fn refill_search_cursor_snapshot(
+        &self,
+        snapshot: &mut SearchCursorSnapshot,
+        options: &SearchOptions,
+        position: usize,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<()> {
+        let window = position
+            .saturating_add(options.limit.saturating_mul(SEARCH_CURSOR_PREFETCH_PAGES))
+            .min(MAX_SEARCH_RESULT_LIMIT);
+        let window_options = SearchOptions {
+            limit: window,
+            offset: 0,
+            ..options.clone()
+        };
+        let fresh = self.run_retrieval_memory_only_cancellable(&window_options, cancel)?;
+        snapshot.complete = fresh.query_results.len() < window || window >= MAX_SEARCH_RESULT_LIMIT;
+        snapshot.ranked.truncate(position);
+        let served = snapshot
+            .ranked
+            .iter()
+            .map(|hit| hit.uri.clone())
+            .collect::<HashSet<_>>();
+        snapshot.ranked.extend(
+            fresh
+                .query_results
+                .into_iter()
+                .filter(|hit| !served.contains(&hit.uri)),
+        );
+        Ok(())
+    }
+
+    fn issue_search_cursor(
+        &self,
+        snapshot_id: &str,
+        fingerprint: &str,
+        snapshot: &SearchCursorSnapshot,
+        position: usize,
+    ) -> Result<Option<String>> {
+        let ttl = self.config.search.cursor_ttl_secs;
+        if ttl == 0 {
+            return Ok(None);
+        }
+        let now = Utc::now().timestamp();
+        let expires_at = now.saturating_add(i64::try_from(ttl).unwrap_or(i64::MAX));
+        self.state.upsert_search_cursor(
+            &SearchCursorRecord {
+                snapshot_id: snapshot_id.to_string(),
+                fingerprint: fingerprint.to_string(),
+                snapshot_json: serde_json::to_string(snapshot)?,
+                expires_at,
+            },
+            now,
+        )?;
+        let token = SearchCursorToken {
+            snapshot_id: snapshot_id.to_string(),
+            position,
+            expires_at,
+            fingerprint: fingerprint
+                .chars()
+                .take(SEARCH_CURSOR_FINGERPRINT_CHARS)
+                .collect(),
+        };
+        Ok(Some(token.encode()))
+    }
+}
+
+fn different_query_error() -> AxiomError {
+    AxiomError::Validation("search cursor was issued for a different query".to_string())
+}
+
+/// Excludes per-page fields: limit, offset, session hints and budget.
+fn search_cursor_fingerprint(options: &SearchOptions) -> Result<String> {
+    let normalized = SearchOptions {
+        query: options
+            .query
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+        session_hints: Vec::new(),
+        budget: None,
+        limit: 0,
+        offset: 0,
+        ..options.clone()
+    };
+    let material = serde_json::to_vec(&normalized)?;
+    Ok(blake3::hash(&material).to_hex().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_cursor_token_round_trips_and_rejects_malformed_input() {
+        let token = SearchCursorToken {
+            snapshot_id: "abc123".to_string(),
+            position: 20,
+            expires_at: 1_700_000_000,
+            fingerprint: "0123456789abcdef".to_string(),
+        };
+        let encoded = token.encode();
+        assert_eq!(encoded, "sc1.abc123.20.1700000000.0123456789abcdef");
+        assert_eq!(SearchCursorToken::parse(&encoded).expect("parse"), token);
+
+        for raw in [
+            "",
+            "sc2.abc123.20.1700000000.0123",
+            "sc1.abc123.x.1700000000.0123",
+            "sc1.abc123.20.1700000000",
+            "sc1.abc123.20.1700000000.0123.extra",
+            "sc1..20.1700000000.0123",
+        ] {
+            assert!(
+                matches!(
+                    SearchCursorToken::parse(raw),
+                    Err(AxiomError::Validation(_))
+                ),
+                "{raw}"
+            );
+        }
+    }
+}
@@ -1,39 +1,51 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::time::Instant;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 
 use crate::config::{
-    OmHintBounds, OmHintPolicy, OmHintReaderMode, RETRIEVAL_BACKEND_MEMORY,
-    RETRIEVAL_BACKEND_POLICY_MEMORY_ONLY,
+    ENV_SEARCH_MAX_QUERY_CHARS, OmHintBounds, OmHintPolicy, OmHintReaderMode, QueryOverflowPolicy,
+    RETRIEVAL_BACKEND_MEMORY, RETRIEVAL_BACKEND_POLICY_MEMORY_ONLY,
 };
 use crate::context_ops::validate_filter;
 use crate::error::{AxiomError, Result};
+use crate::index::InMemoryIndex;
 use crate::llm_io::estimate_text_tokens;
 use crate::models::{
-    FindResult, Message, MetadataFilter, RequestLogEntry, RuntimeHint, SearchBudget, SearchOptions,
-    SearchRequest,
+    BatchSearchItem, FindResult, HitFreshness, Message, MetadataFilter, QueryPlan, RequestLogEntry,
+    RuntimeHint, SearchBudget, SearchExplanation, SearchOptions, SearchRequest, SearchScopeSet,
 };
 use crate::om::{
     OmHintPolicyV2, OmObservationEntryV2, OmScope, build_bounded_observation_hint,
     materialize_search_visible_snapshot, resolve_canonical_thread_id,
 };
 use crate::om_bridge::OmHintReadStateV1;
-use crate::session::resolve_om_scope_binding_for_session_with_config;
+use crate::retrieval::{CancellationToken, DrrEngine};
+use crate::session::{OmScopeBinding, resolve_om_scope_binding_for_session_with_config};
 use crate::state::OmActiveEntry;
-use crate::uri::AxiomUri;
+use crate::uri::{AxiomUri, Scope};
 
 use super::AxiomSync;
+use super::relation::{SEARCH_RELATION_NEIGHBORHOOD_LIMIT, validate_relation_neighborhood_bounds};
 
 mod backend;
+mod cache;
+mod cursor;
+mod profile;
 mod reranker;
 mod result;
+mod session_context;
 mod snapshot;
 mod telemetry;
 
+use backend::{ensure_not_cancelled, is_budget_exhausted};
+pub(super) use cache::QueryResultCache;
+use cursor::SearchCursorToken;
+pub(super) use reranker::default_reranker;
 use result::{
-    annotate_trace_relation_metrics, annotate_typed_edge_query_plan_visibility, budget_to_json,
-    metadata_filter_to_search_filter, normalize_budget,
+    annotate_trace_relation_metrics, annotate_typed_edge_query_plan_visibility,
+    append_query_plan_note, budget_to_json, build_highlighted_snippet, highlight_query_tokens,
+    metadata_filter_to_search_filter, normalize_budget, validate_snippet_highlight,
 };
 use snapshot::{
     build_snapshot_activated_entries, build_snapshot_buffered_entries,
@@ -48,8 +60,10 @@ use telemetry::{
 };
 
 const DEFAULT_OM_SCOPE_LOOKUP_FALLBACK_LIMIT: usize = 4;
+const MAX_SEARCH_RESULT_LIMIT: usize = 500;
 const OM_HINT_SNAPSHOT_BUFFERED_TAIL_LIMIT: usize = 2;
 const OM_HINT_COMPACTION_RESERVED_HIGH_LIMIT: usize = 1;
+const MAX_SEARCH_BATCH_SIZE: usize = 64;
 
 #[derive(Debug, Clone, Default)]
 struct OmSearchMetrics {
@@ -73,6 +87,27 @@ struct OmSearchMetrics {
     om_filtered_message_count: u32,
 }
 
+/// Setup shared across a search batch: session OM snapshots and parsed target URIs.
+#[derive(Debug, Default)]
+struct SearchSetupCache {
+    om_snapshots: HashMap<String, Option<OmHintSnapshotV2>>,
+    targets: HashMap<String, AxiomUri>,
+}
+
+impl SearchSetupCache {
+    fn target_uri(&mut self, raw: Option<&str>) -> Result<Option<AxiomUri>> {
+        let Some(raw) = raw else {
+            return Ok(None);
+        };
+        if let Some(target) = self.targets.get(raw) {
+            return Ok(Some(target.clone()));
+        }
+        let target = parse_search_target_uri(raw)?;
+        self.targets.insert(raw.to_string(), target.clone());
+        Ok(Some(target))
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 struct SearchHintLayers {
     runtime: Vec<String>,
@@ -136,6 +171,7 @@ impl OmHintSnapshotV2 {
 struct SearchOptionsInput {
     query: String,
     target_uri: Option<AxiomUri>,
+    target_uris: Vec<AxiomUri>,
     session: Option<String>,
     session_hints: Vec<String>,
     budget: Option<SearchBudget>,
@@ -144,6 +180,21 @@ struct SearchOptionsInput {
     min_match_tokens: Option<usize>,
     filter: Option<MetadataFilter>,
     request_type: &'static str,
+    exclude_scopes: Vec<Scope>,
+    scope_set: SearchScopeSet,
+    offset: usize,
+}
+
+/// Validated search request with hints resolved, plus options for post-retrieval steps.
+#[derive(Debug)]
+struct PreparedSearch {
+    options: SearchOptions,
+    relation_hops: Option<usize>,
+    snippet_highlight: Option<(String, String)>,
+    cursor: Option<SearchCursorToken>,
+    paginate: bool,
+    /// Original query length when `QueryOverflowPolicy::Truncate` applied.
+    truncated_query_chars: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -194,12 +245,14 @@ impl AxiomSync {
         let typed_edge_enrichment = self.config.search.typed_edge_enrichment;
 
         let output = (|| -> Result<FindResult> {
+            let (query, truncated_query_chars) = self.bound_search_query(query.to_string())?;
             validate_filter(filter.as_ref())?;
             validate_search_cutoff_options(score_threshold, None)?;
             let target = parse_optional_target_uri(target_uri)?;
             let options = build_search_options(SearchOptionsInput {
-                query: query.to_string(),
+                query,
                 target_uri: target,
+                target_uris: Vec::new(),
                 session: None,
                 session_hints: Vec::new(),
                 budget: budget.clone(),
@@ -208,10 +261,19 @@ impl AxiomSync {
                 min_match_tokens: None,
                 filter,
                 request_type: "find",
+                exclude_scopes: Vec::new(),
+                scope_set: SearchScopeSet::AllContent,
+                offset: 0,
             });
 
             let mut result = self.run_retrieval_memory_only(&options)?;
+            if let Some(chars) = truncated_query_chars {
+                append_query_plan_note(&mut result, &format!("query_truncated:{chars}"));
+            }
             self.enrich_find_result_relations(&mut result, 5, typed_edge_enrichment)?;
+            if self.config.search.freshness_check {
+                self.annotate_find_result_freshness(&mut result)?;
+            }
             annotate_trace_relation_metrics(&mut result);
             annotate_typed_edge_query_plan_visibility(&mut result, typed_edge_enrichment);
             self.persist_trace_result(&mut result)?;
@@ -281,6 +343,7 @@ impl AxiomSync {
         self.search_with_request(SearchRequest {
             query: query.to_string(),
             target_uri: target_uri.map(ToString::to_string),
+            target_uris: Vec::new(),
             session: session.map(ToString::to_string),
             limit,
             score_threshold,
@@ -288,6 +351,13 @@ impl AxiomSync {
             filter,
             budget: None,
             runtime_hints: Vec::new(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
         })
     }
 
@@ -297,6 +367,7 @@ impl AxiomSync {
         query: &str,
         hint_policy: OmHintPolicy,
         hint_bounds: OmHintBounds,
+        setup: &mut SearchSetupCache,
     ) -> Result<SessionHintSnapshot> {
         let snapshot_reader_enabled = self.om_snapshot_hint_reader_enabled();
         let mut metrics = OmSearchMetrics {
@@ -308,8 +379,16 @@ impl AxiomSync {
             hint_policy.context_max_archives,
             hint_policy.context_max_messages,
         )?;
-        let om_snapshot =
-            self.fetch_session_om_hint_snapshot_with_enabled(session_id, snapshot_reader_enabled)?;
+        let om_snapshot = if let Some(cached) = setup.om_snapshots.get(session_id) {
+            cached.clone()
+        } else {
+            let fetched = self
+                .fetch_session_om_hint_snapshot_with_enabled(session_id, snapshot_reader_enabled)?;
+            setup
+                .om_snapshots
+                .insert(session_id.to_string(), fetched.clone());
+            fetched
+        };
         metrics.om_snapshot_buffered_chunk_count = om_snapshot.as_ref().map_or(0, |snapshot| {
             saturating_usize_to_u32(snapshot.buffered_chunk_count)
         });
@@ -403,6 +482,7 @@ impl AxiomSync {
         runtime_hints: &[RuntimeHint],
         hint_policy: OmHintPolicy,
         hint_bounds: OmHintBounds,
+        setup: &mut SearchSetupCache,
     ) -> Result<(Vec<String>, OmSearchMetrics)> {
         let normalized_runtime_hints = normalize_runtime_hints(
             runtime_hints,
@@ -415,8 +495,13 @@ impl AxiomSync {
         };
         let mut om_metrics = OmSearchMetrics::default();
         if let Some(session_id) = session_id {
-            let snapshot =
-                self.build_search_session_hints(session_id, query, hint_policy, hint_bounds)?;
+            let snapshot = self.build_search_session_hints(
+                session_id,
+                query,
+                hint_policy,
+                hint_bounds,
+                setup,
+            )?;
             hint_layers.recent = snapshot.recent_hints;
             hint_layers.om_hint = snapshot.om_hint;
             om_metrics = snapshot.metrics;
@@ -451,9 +536,265 @@ impl AxiomSync {
     }
 
     pub fn search_with_request(&self, request: SearchRequest) -> Result<FindResult> {
+        self.search_with_request_inner(request, None)
+    }
+
+    /// `search_with_request` that stops with `AxiomError::Cancelled` once `cancel` fires.
+    pub fn search_with_request_cancellable(
+        &self,
+        request: SearchRequest,
+        cancel: &CancellationToken,
+    ) -> Result<FindResult> {
+        self.search_with_request_inner(request, Some(cancel))
+    }
+
+    fn search_with_request_inner(
+        &self,
+        request: SearchRequest,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<FindResult> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let mut setup = SearchSetupCache::default();
+        let mut om_metrics = OmSearchMetrics::default();
+        let query = request.query.clone();
+        let target_raw = request.target_uri.clone();
+        let session_raw = request.session.clone();
+        let requested_limit = request.limit.unwrap_or(10);
+        let budget = normalize_budget(request.budget.clone());
+        let score_threshold = request.score_threshold;
+        let min_match_tokens = request.min_match_tokens;
+        let hint_policy = self.config.search.om_hint_policy;
+        let typed_edge_enrichment = self.config.search.typed_edge_enrichment;
+
+        let output = self.execute_search_request(request, &mut setup, &mut om_metrics, cancel);
+
+        let (trace_id, status, error, result_count) = match &output {
+            Ok(result) => (
+                result.trace.as_ref().map(|x| x.trace_id.clone()),
+                "ok",
+                None,
+                Some(result.query_results.len()),
+            ),
+            Err(err @ AxiomError::Cancelled(_)) => (None, "cancelled", Some(err), None),
+            Err(err) => (None, "error", Some(err), None),
+        };
+        let truncated = output.as_ref().is_ok_and(is_budget_exhausted);
+        let details = search_request_details(SearchRequestLogInput {
+            query: &query,
+            requested_limit,
+            session: session_raw.as_deref(),
+            budget: budget.as_ref(),
+            score_threshold,
+            min_match_tokens,
+            metrics: om_metrics,
+            hint_policy,
+            typed_edge_enrichment,
+            result_count,
+            truncated,
+        });
+        self.try_log_search_request(SearchRequestLogEvent {
+            request_id: &request_id,
+            started,
+            target_uri: target_raw.as_deref(),
+            trace_id: trace_id.as_deref(),
+            status,
+            error,
+            details,
+        });
+        output
+    }
+
+    /// Runs several searches, sharing session OM snapshots and target URI parsing.
+    /// Results keep input order; the batch is logged once as `search.batch`.
+    pub fn search_batch(&self, requests: Vec<SearchRequest>) -> Result<Vec<BatchSearchItem>> {
+        if requests.len() > MAX_SEARCH_BATCH_SIZE {
+            return Err(AxiomError::Validation(format!(
+                "search batch size {} exceeds limit {MAX_SEARCH_BATCH_SIZE}",
+                requests.len()
+            )));
+        }
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let mut setup = SearchSetupCache::default();
+        let mut items = Vec::with_capacity(requests.len());
+        let mut item_details = Vec::with_capacity(requests.len());
+
+        for (index, request) in requests.into_iter().enumerate() {
+            let item_started = Instant::now();
+            let query = request.query.clone();
+            let session = request.session.clone();
+            let mut om_metrics = OmSearchMetrics::default();
+            let result = self.execute_search_request(request, &mut setup, &mut om_metrics, None);
+            let latency_ms = item_started.elapsed().as_millis();
+            item_details.push(match &result {
+                Ok(found) => serde_json::json!({
+                    "index": index,
+                    "query": query,
+                    "session": session,
+                    "status": "ok",
+                    "latency_ms": latency_ms,
+                    "result_count": found.query_results.len(),
+                    "trace_id": found.trace.as_ref().map(|x| x.trace_id.clone()),
+                }),
+                Err(err) => serde_json::json!({
+                    "index": index,
+                    "query": query,
+                    "session": session,
+                    "status": "error",
+                    "latency_ms": latency_ms,
+                    "error_code": err.code(),
+                }),
+            });
+            items.push(BatchSearchItem { result });
+        }
+
+        let error_count = items.iter().filter(|item| item.result.is_err()).count();
+        self.try_log_request(&RequestLogEntry {
+            request_id,
+            operation: "search.batch".to_string(),
+            status: "ok".to_string(),
+            latency_ms: started.elapsed().as_millis(),
+            created_at: Utc::now().to_rfc3339(),
+            trace_id: None,
+            target_uri: None,
+            error_code: None,
+            error_message: None,
+            details: Some(serde_json::json!({
+                "count": items.len(),
+                "error_count": error_count,
+                "distinct_sessions": setup.om_snapshots.len(),
+                "distinct_targets": setup.targets.len(),
+                "items": item_details,
+            })),
+        });
+        Ok(items)
+    }
+
+    /// Returns the query plan `search_with_request` would use without running retrieval.
+    /// No trace is written; the request is logged as `search.explain`.
+    pub fn explain_search(&self, request: SearchRequest) -> Result<SearchExplanation> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let query = request.query.clone();
+        let target_raw = request.target_uri.clone();
+        let session_raw = request.session.clone();
+        let mut setup = SearchSetupCache::default();
+        let mut om_metrics = OmSearchMetrics::default();
+
+        let output = (|| -> Result<SearchExplanation> {
+            let prepared =
+                self.prepare_search_request(request, &mut setup, &mut om_metrics, None)?;
+            let options = prepared.options;
+            let (query_plan, estimated_candidate_nodes) = {
+                let index = self
+                    .index
+                    .read()
+                    .map_err(|_| AxiomError::lock_poisoned("index"))?;
+                if options.target_uris.is_empty() {
+                    self.drr.explain(&index, &options)
+                } else {
+                    explain_multi_target(&self.drr, &index, &options)
+                }
+            };
+            Ok(SearchExplanation {
+                query_plan,
+                session_hints: options.session_hints,
+                budget: options.budget,
+                estimated_candidate_nodes,
+            })
+        })();
+
+        let (status, error_code, error_message, details) = match &output {
+            Ok(explanation) => (
+                "ok",
+                None,
+                None,
+                serde_json::json!({
+                    "query": query,
+                    "session": session_raw,
+                    "typed_query_count": explanation.query_plan.typed_queries.len(),
+                    "session_hint_count": explanation.session_hints.len(),
+                    "estimated_candidate_nodes": explanation.estimated_candidate_nodes,
+                }),
+            ),
+            Err(err) => (
+                "error",
+                Some(err.code().to_string()),
+                Some(err.to_string()),
+                serde_json::json!({
+                    "query": query,
+                    "session": session_raw,
+                }),
+            ),
+        };
+        self.try_log_request(&RequestLogEntry {
+            request_id,
+            operation: "search.explain".to_string(),
+            status: status.to_string(),
+            latency_ms: started.elapsed().as_millis(),
+            created_at: Utc::now().to_rfc3339(),
+            trace_id: None,
+            target_uri: target_raw,
+            error_code,
+            error_message,
+            details: Some(details),
+        });
+        output
+    }
+
+    fn execute_search_request(
+        &self,
+        request: SearchRequest,
+        setup: &mut SearchSetupCache,
+        om_metrics: &mut OmSearchMetrics,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<FindResult> {
+        let hint_policy = self.config.search.om_hint_policy;
+        let typed_edge_enrichment = self.config.search.typed_edge_enrichment;
+        let PreparedSearch {
+            options,
+            relation_hops,
+            snippet_highlight,
+            cursor,
+            paginate,
+            truncated_query_chars,
+        } = self.prepare_search_request(request, setup, om_metrics, cancel)?;
+
+        ensure_not_cancelled(cancel, "hint resolution")?;
+        let mut result = self.run_paged_retrieval(&options, cursor.as_ref(), paginate, cancel)?;
+        if let Some(chars) = truncated_query_chars {
+            append_query_plan_note(&mut result, &format!("query_truncated:{chars}"));
+        }
+        self.enrich_find_result_relations(&mut result, 5, typed_edge_enrichment)?;
+        if let Some(hops) = relation_hops {
+            self.enrich_find_result_relation_neighborhoods(&mut result, hops)?;
+        }
+        if let Some((delimiter, query)) = snippet_highlight {
+            self.highlight_find_result_snippets(&mut result, &query, &delimiter)?;
+        }
+        if self.config.search.freshness_check {
+            self.annotate_find_result_freshness(&mut result)?;
+        }
+        ensure_not_cancelled(cancel, "relation enrichment")?;
+        annotate_trace_relation_metrics(&mut result);
+        annotate_typed_edge_query_plan_visibility(&mut result, typed_edge_enrichment);
+        annotate_om_query_plan_visibility(&mut result, om_metrics, hint_policy);
+        self.persist_trace_result(&mut result)?;
+        Ok(result)
+    }
+
+    fn prepare_search_request(
+        &self,
+        request: SearchRequest,
+        setup: &mut SearchSetupCache,
+        om_metrics: &mut OmSearchMetrics,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<PreparedSearch> {
         let SearchRequest {
             query,
             target_uri,
+            target_uris,
             session,
             limit,
             score_threshold,
@@ -461,104 +802,169 @@ impl AxiomSync {
             filter,
             budget,
             runtime_hints,
+            exclude_scopes,
+            scope_set,
+            relation_hops,
+            snippet_highlight,
+            offset,
+            cursor,
+            paginate,
         } = request;
-        let request_id = uuid::Uuid::new_v4().to_string();
-        let started = Instant::now();
-        let target_raw = target_uri.clone();
-        let session_raw = session.clone();
-        let requested_limit = limit.unwrap_or(10);
-        let budget = normalize_budget(budget);
         let hint_policy = self.config.search.om_hint_policy;
         let hint_bounds = self.config.search.om_hint_bounds;
-        let typed_edge_enrichment = self.config.search.typed_edge_enrichment;
-        let mut om_metrics = OmSearchMetrics::default();
 
-        let output = (|| -> Result<FindResult> {
-            validate_filter(filter.as_ref())?;
-            validate_search_cutoff_options(score_threshold, min_match_tokens)?;
-            let target = parse_optional_target_uri(target_uri.as_deref())?;
-            let (session_hints, resolved_metrics) = self.resolve_search_hints(
-                session.as_deref(),
-                &query,
-                &runtime_hints,
-                hint_policy,
-                hint_bounds,
-            )?;
-            om_metrics = resolved_metrics;
+        let (query, truncated_query_chars) = self.bound_search_query(query)?;
+        validate_filter(filter.as_ref())?;
+        validate_search_cutoff_options(score_threshold, min_match_tokens)?;
+        let target = setup.target_uri(target_uri.as_deref())?;
+        validate_exclude_scopes(target.as_ref(), &exclude_scopes)?;
+        validate_scope_set(&scope_set)?;
+        let mut extra_targets = Vec::with_capacity(target_uris.len());
+        for raw in &target_uris {
+            let extra = setup.target_uri(Some(raw))?;
+            validate_exclude_scopes(extra.as_ref(), &exclude_scopes)?;
+            extra_targets.extend(extra);
+        }
+        if cursor.is_some() && offset.is_some() {
+            return Err(AxiomError::Validation(
+                "search cursor and offset cannot be combined".to_string(),
+            ));
+        }
+        let cursor = cursor
+            .as_deref()
+            .map(SearchCursorToken::parse)
+            .transpose()?;
+        let offset = offset.unwrap_or(0);
+        let requested_limit = limit.unwrap_or(10);
+        validate_search_offset(offset, requested_limit)?;
+        if let Some(hops) = relation_hops {
+            validate_relation_neighborhood_bounds(hops, SEARCH_RELATION_NEIGHBORHOOD_LIMIT)?;
+        }
+        validate_snippet_highlight(snippet_highlight.as_deref())?;
+        let snippet_highlight = snippet_highlight.map(|delimiter| (delimiter, query.clone()));
+        ensure_not_cancelled(cancel, "setup")?;
+        let (session_hints, resolved_metrics) = self.resolve_search_hints(
+            session.as_deref(),
+            &query,
+            &runtime_hints,
+            hint_policy,
+            hint_bounds,
+            setup,
+        )?;
+        *om_metrics = resolved_metrics;
 
-            let options = build_search_options(SearchOptionsInput {
-                query: query.clone(),
-                target_uri: target,
-                session: session.clone(),
-                session_hints,
-                budget: budget.clone(),
-                requested_limit,
-                score_threshold,
-                min_match_tokens,
-                filter,
-                request_type: "search",
-            });
+        let options = build_search_options(SearchOptionsInput {
+            query,
+            target_uri: target,
+            target_uris: extra_targets,
+            session,
+            session_hints,
+            budget: normalize_budget(budget),
+            requested_limit,
+            score_threshold,
+            min_match_tokens,
+            filter,
+            request_type: "search",
+            exclude_scopes,
+            scope_set,
+            offset,
+        });
+        Ok(PreparedSearch {
+            options,
+            relation_hops,
+            snippet_highlight,
+            cursor,
+            paginate,
+            truncated_query_chars,
+        })
+    }
 
-            let mut result = self.run_retrieval_memory_only(&options)?;
-            self.enrich_find_result_relations(&mut result, 5, typed_edge_enrichment)?;
-            annotate_trace_relation_metrics(&mut result);
-            annotate_typed_edge_query_plan_visibility(&mut result, typed_edge_enrichment);
-            annotate_om_query_plan_visibility(&mut result, &om_metrics, hint_policy);
-            self.persist_trace_result(&mut result)?;
-            Ok(result)
-        })();
+    /// Rejects or truncates queries over `max_query_chars` before tokenization.
+    fn bound_search_query(&self, query: String) -> Result<(String, Option<usize>)> {
+        let max_chars = self.config.search.max_query_chars;
+        if max_chars == 0 {
+            return Ok((query, None));
+        }
+        let chars = query.chars().count();
+        if chars <= max_chars {
+            return Ok((query, None));
+        }
+        match self.config.search.query_overflow {
+            QueryOverflowPolicy::Reject => Err(AxiomError::Validation(format!(
+                "search query has {chars} characters, exceeding the limit of {max_chars} \
+                 ({ENV_SEARCH_MAX_QUERY_CHARS})"
+            ))),
+            QueryOverflowPolicy::Truncate => {
+                Ok((query.chars().take(max_chars).collect(), Some(chars)))
+            }
+        }
+    }
 
-        match output {
-            Ok(result) => {
-                let trace_id = result.trace.as_ref().map(|x| x.trace_id.clone());
-                let details = search_request_details(SearchRequestLogInput {
-                    query: &query,
-                    requested_limit,
-                    session: session_raw.as_deref(),
-                    budget: budget.as_ref(),
-                    score_threshold,
-                    min_match_tokens,
-                    metrics: om_metrics,
-                    hint_policy,
-                    typed_edge_enrichment,
-                    result_count: Some(result.query_results.len()),
-                });
-                self.try_log_search_request(SearchRequestLogEvent {
-                    request_id: &request_id,
-                    started,
-                    target_uri: target_raw.as_deref(),
-                    trace_id: trace_id.as_deref(),
-                    status: "ok",
-                    error: None,
-                    details,
-                });
-                Ok(result)
+    fn annotate_find_result_freshness(&self, result: &mut FindResult) -> Result<()> {
+        let mut stale_hits = 0usize;
+        for hit in &mut result.query_results {
+            let Ok(uri) = AxiomUri::parse(&hit.uri) else {
+                continue;
+            };
+            if uri.scope().is_internal() {
+                continue;
             }
-            Err(err) => {
-                let details = search_request_details(SearchRequestLogInput {
-                    query: &query,
-                    requested_limit,
-                    session: session_raw.as_deref(),
-                    budget: budget.as_ref(),
-                    score_threshold,
-                    min_match_tokens,
-                    metrics: om_metrics,
-                    hint_policy,
-                    typed_edge_enrichment,
-                    result_count: None,
-                });
-                self.try_log_search_request(SearchRequestLogEvent {
-                    request_id: &request_id,
-                    started,
-                    target_uri: target_raw.as_deref(),
-                    trace_id: None,
-                    status: "error",
-                    error: Some(&err),
-                    details,
-                });
-                Err(err)
+            let path = self.fs.resolve_uri(&uri);
+            if path.is_dir() {
+                continue;
+            }
+            let Some((_, indexed_nanos)) = self.state.get_index_state(&hit.uri)? else {
+                continue;
+            };
+            let file_nanos = std::fs::metadata(&path)
+                .ok()
+                .and_then(|metadata| metadata.modified().ok())
+                .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|duration| i64::try_from(duration.as_nanos()).unwrap_or(i64::MAX));
+            let possibly_stale = file_nanos != Some(indexed_nanos);
+            if possibly_stale {
+                stale_hits += 1;
+            }
+            let index_age_ms = file_nanos.map_or(0, |file_nanos| {
+                u64::try_from(file_nanos.saturating_sub(indexed_nanos).max(0) / 1_000_000)
+                    .unwrap_or(0)
+            });
+            hit.freshness = Some(HitFreshness {
+                indexed_mtime: DateTime::from_timestamp_nanos(indexed_nanos),
+                file_mtime: file_nanos.map(DateTime::from_timestamp_nanos),
+                index_age_ms,
+                possibly_stale,
+            });
+        }
+        append_query_plan_note(result, &format!("freshness_stale_hits:{stale_hits}"));
+        Ok(())
+    }
+
+    fn highlight_find_result_snippets(
+        &self,
+        result: &mut FindResult,
+        query: &str,
+        delimiter: &str,
+    ) -> Result<()> {
+        let tokens = highlight_query_tokens(query);
+        let index = self
+            .index
+            .read()
+            .map_err(|_| AxiomError::lock_poisoned("index"))?;
+        for hit in &mut result.query_results {
+            let Some(record) = index.get(&hit.uri) else {
+                continue;
+            };
+            if let Some(snippet) = build_highlighted_snippet(
+                &record.content,
+                &record.abstract_text,
+                &tokens,
+                delimiter,
+            ) {
+                hit.snippet = Some(snippet);
             }
         }
+        Ok(())
     }
 
     pub(crate) fn fetch_session_om_state(
@@ -657,33 +1063,7 @@ impl AxiomSync {
         }
         let scope_binding =
             resolve_om_scope_binding_for_session_with_config(session_id, &self.config.om.scope)?;
-        let record = if let Some(record) = self
-            .state
-            .get_om_record_by_scope_key(&scope_binding.scope_key)?
-        {
-            record
-        } else if scope_binding.scope == OmScope::Session {
-            let mut resolved = None;
-            let fallback_scope_keys = self.state.list_om_scope_keys_for_session(
-                session_id,
-                DEFAULT_OM_SCOPE_LOOKUP_FALLBACK_LIMIT,
-            )?;
-            for fallback_scope_key in fallback_scope_keys {
-                if fallback_scope_key == scope_binding.scope_key {
-                    continue;
-                }
-                if let Some(candidate) =
-                    self.state.get_om_record_by_scope_key(&fallback_scope_key)?
-                {
-                    resolved = Some(candidate);
-                    break;
-                }
-            }
-            let Some(record) = resolved else {
-                return Ok(None);
-            };
-            record
-        } else {
+        let Some(record) = self.resolve_session_om_record(session_id, &scope_binding)? else {
             return Ok(None);
         };
 
@@ -706,6 +1086,35 @@ impl AxiomSync {
         )?))
     }
 
+    /// Finds the session's OM record by scope binding, falling back to its other scope keys, newest first.
+    pub(crate) fn resolve_session_om_record(
+        &self,
+        session_id: &str,
+        scope_binding: &OmScopeBinding,
+    ) -> Result<Option<crate::om::OmRecord>> {
+        if let Some(record) = self
+            .state
+            .get_om_record_by_scope_key(&scope_binding.scope_key)?
+        {
+            return Ok(Some(record));
+        }
+        if scope_binding.scope != OmScope::Session {
+            return Ok(None);
+        }
+        let fallback_scope_keys = self
+            .state
+            .list_om_scope_keys_for_session(session_id, DEFAULT_OM_SCOPE_LOOKUP_FALLBACK_LIMIT)?;
+        for fallback_scope_key in fallback_scope_keys {
+            if fallback_scope_key == scope_binding.scope_key {
+                continue;
+            }
+            if let Some(candidate) = self.state.get_om_record_by_scope_key(&fallback_scope_key)? {
+                return Ok(Some(candidate));
+            }
+        }
+        Ok(None)
+    }
+
     fn build_om_hint_snapshot_from_record(
         &self,
         record: &crate::om::OmRecord,
@@ -957,14 +1366,70 @@ fn non_empty_trimmed(value: &str) -> Option<&str> {
     }
 }
 
+/// Unions per-target scopes and sums candidates; overlapping subtrees overestimate.
+fn explain_multi_target(
+    drr: &DrrEngine,
+    index: &InMemoryIndex,
+    options: &SearchOptions,
+) -> (QueryPlan, usize) {
+    let mut merged: Option<QueryPlan> = None;
+    let mut estimated = 0usize;
+    for target in &options.target_uris {
+        let per_target = SearchOptions {
+            target_uri: Some(target.clone()),
+            target_uris: Vec::new(),
+            ..options.clone()
+        };
+        let (plan, nodes) = drr.explain(index, &per_target);
+        estimated = estimated.saturating_add(nodes);
+        match merged.as_mut() {
+            None => merged = Some(plan),
+            Some(acc) => {
+                for scope in plan.scopes {
+                    if !acc.scopes.contains(&scope) {
+                        acc.scopes.push(scope);
+                    }
+                }
+            }
+        }
+    }
+    let mut plan = merged.unwrap_or_default();
+    if let Some(effective) = plan.effective_options.as_mut() {
+        effective.target_uri = None;
+    }
+    let targets = options
+        .target_uris
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    plan.notes.push(format!(
+        "multi_target:{}:{}",
+        targets.len(),
+        targets.join(",")
+    ));
+    (plan, estimated)
+}
+
 fn parse_optional_target_uri(target_uri: Option<&str>) -> Result<Option<AxiomUri>> {
-    target_uri.map(AxiomUri::parse).transpose()
+    target_uri.map(parse_search_target_uri).transpose()
+}
+
+/// Internal scopes are not indexed, so reject them instead of returning nothing.
+fn parse_search_target_uri(raw: &str) -> Result<AxiomUri> {
+    let target = AxiomUri::parse(raw)?;
+    if target.scope().is_internal() {
+        return Err(AxiomError::Validation(format!(
+            "search target must not be in an internal scope: {target}"
+        )));
+    }
+    Ok(target)
 }
 
 fn build_search_options(input: SearchOptionsInput) -> SearchOptions {
     let SearchOptionsInput {
         query,
         target_uri,
+        target_uris,
         session,
         session_hints,
         budget,
@@ -973,18 +1438,37 @@ fn build_search_options(input: SearchOptionsInput) -> SearchOptions {
         min_match_tokens,
         filter,
         request_type,
+        exclude_scopes,
+        scope_set,
+        offset,
     } = input;
+    // A single target after dedup takes the single-target path.
+    let mut targets = Vec::<AxiomUri>::new();
+    for target in target_uri.into_iter().chain(target_uris) {
+        if !targets.contains(&target) {
+            targets.push(target);
+        }
+    }
+    let (target_uri, target_uris) = if targets.len() > 1 {
+        (None, targets)
+    } else {
+        (targets.pop(), Vec::new())
+    };
     SearchOptions {
         query,
         target_uri,
+        target_uris,
         session,
         session_hints,
         budget,
-        limit: requested_limit,
+        limit: requested_limit.clamp(1, MAX_SEARCH_RESULT_LIMIT),
         score_threshold,
         min_match_tokens,
         filter: metadata_filter_to_search_filter(filter),
         request_type: request_type.to_string(),
+        exclude_scopes,
+        scope_set,
+        offset,
     }
 }
 
@@ -1182,6 +1666,39 @@ fn validate_search_cutoff_options(
     Ok(())
 }
 
+fn validate_search_offset(offset: usize, limit: usize) -> Result<()> {
+    let window = offset.saturating_add(limit.clamp(1, MAX_SEARCH_RESULT_LIMIT));
+    if window > MAX_SEARCH_RESULT_LIMIT {
+        return Err(AxiomError::Validation(format!(
+            "search offset + limit must be <= {MAX_SEARCH_RESULT_LIMIT} (got {window})"
+        )));
+    }
+    Ok(())
+}
+
+fn validate_exclude_scopes(target: Option<&AxiomUri>, exclude_scopes: &[Scope]) -> Result<()> {
+    if let Some(target) = target
+        && exclude_scopes.contains(&target.scope())
+    {
+        return Err(AxiomError::Validation(format!(
+            "exclude_scopes contains target scope {}",
+            target.scope().as_str()
+        )));
+    }
+    Ok(())
+}
+
+fn validate_scope_set(scope_set: &SearchScopeSet) -> Result<()> {
+    if let SearchScopeSet::Only(scopes) = scope_set
+        && scopes.is_empty()
+    {
+        return Err(AxiomError::Validation(
+            "scope_set only requires at least one scope".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 fn bounded_om_hint_from_snapshot(
     search_visible_observations: &str,
     current_task: Option<&str>,
@@ -1262,12 +1779,13 @@ mod tests {
     use super::{
         SearchOptionsInput, build_search_options, infer_buffered_entry_priority,
         normalize_hint_text, parse_optional_target_uri, snapshot_visible_entry_selection,
-        snapshot_visible_entry_source_key, validate_search_cutoff_options,
+        snapshot_visible_entry_source_key, validate_exclude_scopes, validate_scope_set,
+        validate_search_cutoff_options,
     };
     use crate::error::AxiomError;
-    use crate::models::{MetadataFilter, SearchBudget};
+    use crate::models::{MetadataFilter, SearchBudget, SearchScopeSet};
     use crate::om::{OmObservationEntryV2, OmObservationOriginKind, OmObservationPriority};
-    use crate::uri::AxiomUri;
+    use crate::uri::{AxiomUri, Scope};
 
     #[test]
     fn validate_search_cutoff_options_accepts_supported_values() {
@@ -1312,6 +1830,27 @@ mod tests {
         assert!(normalize_hint_text("hint", 0).is_none());
     }
 
+    #[test]
+    fn validate_exclude_scopes_rejects_target_scope_contradiction() {
+        let target = AxiomUri::parse("axiom://session/s-1").expect("target parse");
+        let err = validate_exclude_scopes(Some(&target), &[Scope::Session])
+            .expect_err("excluding target scope must fail");
+        assert!(matches!(err, AxiomError::Validation(_)));
+        validate_exclude_scopes(Some(&target), &[Scope::Resources])
+            .expect("unrelated scope exclusion remains valid");
+        validate_exclude_scopes(None, &[Scope::Session]).expect("no target remains valid");
+    }
+
+    #[test]
+    fn validate_scope_set_rejects_empty_only_list() {
+        let err = validate_scope_set(&SearchScopeSet::Only(Vec::new()))
+            .expect_err("empty scope list must fail");
+        assert!(matches!(err, AxiomError::Validation(_)));
+        validate_scope_set(&SearchScopeSet::Only(vec![Scope::Queue]))
+            .expect("internal scope list remains valid");
+        validate_scope_set(&SearchScopeSet::AllContent).expect("default remains valid");
+    }
+
     #[test]
     fn parse_optional_target_uri_returns_none_when_missing() {
         let target = parse_optional_target_uri(None).expect("parse none");
@@ -1328,6 +1867,7 @@ mod tests {
         let options = build_search_options(SearchOptionsInput {
             query: "oauth".to_string(),
             target_uri: Some(AxiomUri::parse("axiom://resources").expect("target parse")),
+            target_uris: Vec::new(),
             session: Some("s-1".to_string()),
             session_hints: vec!["recent hint".to_string()],
             budget: Some(SearchBudget {
@@ -1340,6 +1880,9 @@ mod tests {
             min_match_tokens: Some(2),
             filter: Some(filter),
             request_type: "search",
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         });
 
         assert_eq!(options.query, "oauth");
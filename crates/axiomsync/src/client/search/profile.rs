@@ -0,0 +1,189 @@
+use crate::context_ops::validate_filter;
+use crate::error::{AxiomError, Result};
+use crate::models::{FindResult, SearchProfile, SearchRequest, SearchScopeSet};
+use crate::uri::AxiomUri;
+
+use super::AxiomSync;
+
+const MAX_SEARCH_PROFILE_NAME_CHARS: usize = 64;
+
+impl AxiomSync {
+    /// Overwrites any profile with the same name.
+    pub fn save_search_profile(&self, profile: SearchProfile) -> Result<SearchProfile> {
+        let name = validate_search_profile_name(&profile.name)?;
+        if let Some(target_uri) = profile.target_uri.as_deref() {
+            AxiomUri::parse(target_uri)?;
+        }
+        validate_filter(profile.filter.as_ref())?;
+        if profile.limit == Some(0) {
+            return Err(AxiomError::Validation(
+                "search profile limit must be >= 1".to_string(),
+            ));
+        }
+        let profile = SearchProfile {
+            name: name.to_string(),
+            ..profile
+        };
+        let raw = serde_json::to_string(&profile)?;
+        self.state.upsert_search_profile(&profile.name, &raw)?;
+        Ok(profile)
+    }
+
+    pub fn search_profile(&self, name: &str) -> Result<SearchProfile> {
+        let name = validate_search_profile_name(name)?;
+        let Some(raw) = self.state.get_search_profile(name)? else {
+            return Err(AxiomError::NotFound(format!("search profile: {name}")));
+        };
+        Ok(serde_json::from_str(&raw)?)
+    }
+
+    pub fn list_search_profiles(&self) -> Result<Vec<SearchProfile>> {
+        self.state
+            .list_search_profiles()?
+            .iter()
+            .map(|raw| serde_json::from_str(raw).map_err(Into::into))
+            .collect()
+    }
+
+    pub fn remove_search_profile(&self, name: &str) -> Result<bool> {
+        let name = validate_search_profile_name(name)?;
+        self.state.remove_search_profile(name)
+    }
+
+    /// Fills empty request fields from the profile; filters merge per field, request wins.
+    pub fn apply_search_profile(
+        &self,
+        profile_name: &str,
+        request: SearchRequest,
+    ) -> Result<SearchRequest> {
+        let profile = self.search_profile(profile_name)?;
+        Ok(merge_search_profile(profile, request))
+    }
+
+    pub fn search_with_profile(&self, profile_name: &str, query: &str) -> Result<FindResult> {
+        let request = self.apply_search_profile(
+            profile_name,
+            SearchRequest {
+                query: query.to_string(),
+                target_uri: None,
+                target_uris: Vec::new(),
+                session: None,
+                limit: None,
+                score_threshold: None,
+                min_match_tokens: None,
+                filter: None,
+                budget: None,
+                runtime_hints: Vec::new(),
+                exclude_scopes: Vec::new(),
+                scope_set: SearchScopeSet::AllContent,
+                relation_hops: None,
+                snippet_highlight: None,
+                offset: None,
+                cursor: None,
+                paginate: false,
+            },
+        )?;
+        self.search_with_request(request)
+    }
+}
+
+fn validate_search_profile_name(name: &str) -> Result<&str> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AxiomError::Validation(
+            "search profile name must not be empty".to_string(),
+        ));
+    }
+    if name.chars().count() > MAX_SEARCH_PROFILE_NAME_CHARS
+        || !name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'))
+    {
+        return Err(AxiomError::Validation(format!(
+            "search profile name must be 1-{MAX_SEARCH_PROFILE_NAME_CHARS} chars of [A-Za-z0-9._-]: {name}"
+        )));
+    }
+    Ok(name)
+}
+
+fn merge_search_profile(profile: SearchProfile, mut request: SearchRequest) -> SearchRequest {
+    if request.target_uri.is_none() {
+        request.target_uri = profile.target_uri;
+    }
+    if request.limit.is_none() {
+        request.limit = profile.limit;
+    }
+    if request.budget.is_none() {
+        request.budget = profile.budget;
+    }
+    request.filter = match (profile.filter, request.filter) {
+        (Some(mut merged), Some(explicit)) => {
+            merged.fields.extend(explicit.fields);
+            Some(merged)
+        }
+        (profile_filter, explicit) => explicit.or(profile_filter),
+    };
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::models::MetadataFilter;
+
+    #[test]
+    fn merge_search_profile_prefers_explicit_values_and_merges_filter_fields() {
+        let profile = SearchProfile {
+            name: "work".to_string(),
+            target_uri: Some("axiom://resources/work".to_string()),
+            filter: Some(MetadataFilter {
+                fields: HashMap::from([
+                    ("tags".to_string(), serde_json::json!(["work"])),
+                    ("mime".to_string(), serde_json::json!("text/markdown")),
+                ]),
+            }),
+            budget: None,
+            limit: Some(3),
+        };
+        let request = SearchRequest {
+            query: "oauth".to_string(),
+            target_uri: None,
+            target_uris: Vec::new(),
+            session: None,
+            limit: Some(7),
+            score_threshold: None,
+            min_match_tokens: None,
+            filter: Some(MetadataFilter {
+                fields: HashMap::from([("mime".to_string(), serde_json::json!("text/plain"))]),
+            }),
+            budget: None,
+            runtime_hints: Vec::new(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
+        };
+
+        let merged = merge_search_profile(profile, request);
+        assert_eq!(merged.target_uri.as_deref(), Some("axiom://resources/work"));
+        assert_eq!(merged.limit, Some(7));
+        let fields = merged.filter.expect("filter").fields;
+        assert_eq!(fields.get("tags"), Some(&serde_json::json!(["work"])));
+        assert_eq!(fields.get("mime"), Some(&serde_json::json!("text/plain")));
+    }
+
+    #[test]
+    fn search_profile_name_rejects_empty_and_path_like_names() {
+        assert_eq!(
+            validate_search_profile_name(" work ").expect("valid"),
+            "work"
+        );
+        assert!(validate_search_profile_name("  ").is_err());
+        assert!(validate_search_profile_name("a/b").is_err());
+    }
+}
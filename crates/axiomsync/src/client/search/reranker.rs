@@ -1,7 +1,9 @@
-use crate::error::{AxiomError, Result};
-use crate::models::{ContextHit, FindResult, IndexRecord};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::index::InMemoryIndex;
+use crate::models::{ContextHit, FindResult, IndexRecord, SearchOptions};
+use crate::retrieval::{NoopReranker, Reranker};
 
-use super::AxiomSync;
 use super::result::{append_query_plan_note, sync_trace_final_topk};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -403,52 +405,85 @@ fn doc_aware_boost(intent: QueryIntent, needs: QueryNeeds, signals: DocSignals)
     boost.clamp(0.0, 0.65)
 }
 
-impl AxiomSync {
-    pub(super) fn apply_reranker_with_mode(
-        &self,
-        query: &str,
-        result: &mut FindResult,
-        limit: usize,
-        mode: RerankerMode,
-    ) -> Result<()> {
-        append_query_plan_note(result, &format!("reranker:{}", mode.as_str()));
-        if mode == RerankerMode::Off || result.query_results.len() <= 1 {
-            sync_trace_final_topk(result);
-            return Ok(());
-        }
+/// Default `doc-aware-v1` reranker: boosts by document kind from tags, parser metadata and query intent.
+pub(in crate::client) struct DocAwareReranker {
+    index: Arc<RwLock<InMemoryIndex>>,
+}
 
-        let query_tokens = crate::embedding::tokenize_vec(query);
-        let intent = classify_query_intent(query, &query_tokens);
+impl DocAwareReranker {
+    pub(in crate::client) const fn new(index: Arc<RwLock<InMemoryIndex>>) -> Self {
+        Self { index }
+    }
+}
+
+impl Reranker for DocAwareReranker {
+    fn name(&self) -> &str {
+        RerankerMode::DocAwareV1.as_str()
+    }
+
+    fn plan_notes(&self, query: &SearchOptions) -> Vec<String> {
+        let query_tokens = crate::embedding::tokenize_vec(&query.query);
+        let intent = classify_query_intent(&query.query, &query_tokens);
+        vec![format!("reranker_intent:{}", intent.as_str())]
+    }
+
+    fn rerank(&self, query: &SearchOptions, mut hits: Vec<ContextHit>) -> Vec<ContextHit> {
+        let query_tokens = crate::embedding::tokenize_vec(&query.query);
+        let intent = classify_query_intent(&query.query, &query_tokens);
         let needs = detect_query_needs(&query_tokens, intent);
-        append_query_plan_note(result, &format!("reranker_intent:{}", intent.as_str()));
 
         let signals = {
-            let index = self
-                .index
-                .read()
-                .map_err(|_| AxiomError::lock_poisoned("index"))?;
-            let mut out = Vec::<DocSignals>::with_capacity(result.query_results.len());
-            for hit in &result.query_results {
-                out.push(collect_doc_signals(&query_tokens, hit, index.get(&hit.uri)));
-            }
-            out
+            let index = self.index.read().unwrap_or_else(PoisonError::into_inner);
+            hits.iter()
+                .map(|hit| collect_doc_signals(&query_tokens, hit, index.get(&hit.uri)))
+                .collect::<Vec<_>>()
         };
 
-        for (hit, signals) in result.query_results.iter_mut().zip(signals.into_iter()) {
+        for (hit, signals) in hits.iter_mut().zip(signals) {
             let boost = doc_aware_boost(intent, needs, signals);
             hit.score = (hit.score * (1.0 + boost)).max(0.0);
         }
 
-        result.query_results.sort_by(|a, b| {
+        hits.sort_by(|a, b| {
             b.score
                 .partial_cmp(&a.score)
                 .unwrap_or(std::cmp::Ordering::Equal)
                 .then_with(|| a.uri.cmp(&b.uri))
         });
-        result.query_results.truncate(limit.max(1));
-        result.rebuild_hit_buckets();
+        hits
+    }
+}
+
+/// Default reranker for `AXIOMSYNC_RERANKER`.
+pub(in crate::client) fn default_reranker(
+    raw: Option<&str>,
+    index: &Arc<RwLock<InMemoryIndex>>,
+) -> Arc<dyn Reranker> {
+    match resolve_reranker_mode(raw) {
+        RerankerMode::Off => Arc::new(NoopReranker),
+        RerankerMode::DocAwareV1 => Arc::new(DocAwareReranker::new(Arc::clone(index))),
+    }
+}
+
+/// Records the reranker name, truncates to `limit` and rebuilds buckets and trace top-k.
+pub(super) fn apply_reranker(
+    reranker: &dyn Reranker,
+    options: &SearchOptions,
+    result: &mut FindResult,
+    limit: usize,
+) {
+    append_query_plan_note(result, &format!("reranker:{}", reranker.name()));
+    if result.query_results.len() <= 1 {
         sync_trace_final_topk(result);
+        return;
+    }
 
-        Ok(())
+    for note in reranker.plan_notes(options) {
+        append_query_plan_note(result, &note);
     }
+    let hits = std::mem::take(&mut result.query_results);
+    result.query_results = reranker.rerank(options, hits);
+    result.query_results.truncate(limit.max(1));
+    result.rebuild_hit_buckets();
+    sync_trace_final_topk(result);
 }
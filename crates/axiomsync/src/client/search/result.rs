@@ -1,27 +1,20 @@
+use std::collections::HashSet;
+
 use serde_json::json;
 
+use crate::context_ops::parse_metadata_filter;
+use crate::error::{AxiomError, Result};
 use crate::models::{FindResult, MetadataFilter, SearchBudget, SearchFilter, TracePoint};
 
+const HIGHLIGHT_SNIPPET_MAX_CHARS: usize = 240;
+const HIGHLIGHT_SNIPPET_LEAD_CHARS: usize = 40;
+const MAX_SNIPPET_HIGHLIGHT_DELIMITER_CHARS: usize = 16;
+
 pub(super) fn metadata_filter_to_search_filter(
     filter: Option<MetadataFilter>,
 ) -> Option<SearchFilter> {
-    let filter = filter?;
-    Some(SearchFilter {
-        tags: filter
-            .fields
-            .get("tags")
-            .and_then(|v| v.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|x| x.as_str().map(ToString::to_string))
-                    .collect::<Vec<_>>()
-            })
-            .unwrap_or_default(),
-        mime: filter
-            .fields
-            .get("mime")
-            .and_then(|v| v.as_str().map(ToString::to_string)),
-    })
+    // Filters were validated upfront, so a parse failure means no filter.
+    parse_metadata_filter(&filter?).ok()
 }
 
 pub(super) fn normalize_budget(budget: Option<SearchBudget>) -> Option<SearchBudget> {
@@ -92,9 +85,133 @@ pub(super) fn annotate_typed_edge_query_plan_visibility(result: &mut FindResult,
     append_query_plan_note(result, &format!("typed_edge_links:{typed_edges}"));
 }
 
+pub(super) fn validate_snippet_highlight(delimiter: Option<&str>) -> Result<()> {
+    let Some(delimiter) = delimiter else {
+        return Ok(());
+    };
+    if delimiter.trim().is_empty() {
+        return Err(AxiomError::Validation(
+            "snippet_highlight delimiter must not be empty".to_string(),
+        ));
+    }
+    if delimiter.chars().count() > MAX_SNIPPET_HIGHLIGHT_DELIMITER_CHARS {
+        return Err(AxiomError::Validation(format!(
+            "snippet_highlight delimiter must be at most {MAX_SNIPPET_HIGHLIGHT_DELIMITER_CHARS} chars"
+        )));
+    }
+    Ok(())
+}
+
+/// Query tokens to highlight, longest first so longer tokens match first.
+pub(super) fn highlight_query_tokens(query: &str) -> Vec<String> {
+    let mut seen = HashSet::<String>::new();
+    let mut tokens = query
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty() && seen.insert((*token).to_string()))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    tokens.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    tokens
+}
+
+/// Cuts a window around the first match in the line with the most query tokens and wraps
+/// matches in `delimiter`; falls back to the abstract, `None` if both are empty.
+pub(super) fn build_highlighted_snippet(
+    content: &str,
+    abstract_text: &str,
+    tokens: &[String],
+    delimiter: &str,
+) -> Option<String> {
+    let best_line = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| (line, matched_token_count(line, tokens)))
+        .filter(|(_, matched)| *matched > 0)
+        .fold(None::<(&str, usize)>, |best, candidate| match best {
+            Some((_, best_matched)) if best_matched >= candidate.1 => best,
+            _ => Some(candidate),
+        });
+    let window = match best_line {
+        Some((line, _)) => snippet_window(line, tokens),
+        None => {
+            let fallback = abstract_text.trim();
+            if fallback.is_empty() {
+                return None;
+            }
+            snippet_window(fallback, tokens)
+        }
+    };
+    Some(mark_tokens(&window, tokens, delimiter))
+}
+
+fn matched_token_count(line: &str, tokens: &[String]) -> usize {
+    let lowered = line.to_ascii_lowercase();
+    tokens
+        .iter()
+        .filter(|token| lowered.contains(token.as_str()))
+        .count()
+}
+
+fn snippet_window(line: &str, tokens: &[String]) -> String {
+    let total = line.chars().count();
+    if total <= HIGHLIGHT_SNIPPET_MAX_CHARS {
+        return line.to_string();
+    }
+    let lowered = line.to_ascii_lowercase();
+    let first_match_chars = tokens
+        .iter()
+        .filter_map(|token| lowered.find(token.as_str()))
+        .min()
+        .map_or(0, |byte_index| line[..byte_index].chars().count());
+    let start = first_match_chars
+        .saturating_sub(HIGHLIGHT_SNIPPET_LEAD_CHARS)
+        .min(total - HIGHLIGHT_SNIPPET_MAX_CHARS);
+    let end = start + HIGHLIGHT_SNIPPET_MAX_CHARS;
+    let mut out = String::new();
+    if start > 0 {
+        out.push('…');
+    }
+    out.extend(line.chars().skip(start).take(HIGHLIGHT_SNIPPET_MAX_CHARS));
+    if end < total {
+        out.push('…');
+    }
+    out
+}
+
+fn mark_tokens(text: &str, tokens: &[String], delimiter: &str) -> String {
+    // ASCII lowercasing keeps byte lengths, so offsets in `lowered` apply to `text`.
+    let lowered = text.to_ascii_lowercase();
+    let mut out = String::with_capacity(text.len());
+    let mut offset = 0usize;
+    while offset < text.len() {
+        if let Some(token) = tokens
+            .iter()
+            .find(|token| lowered[offset..].starts_with(token.as_str()))
+        {
+            let end = offset + token.len();
+            out.push_str(delimiter);
+            out.push_str(&text[offset..end]);
+            out.push_str(delimiter);
+            offset = end;
+            continue;
+        }
+        let Some(ch) = text[offset..].chars().next() else {
+            break;
+        };
+        out.push(ch);
+        offset += ch.len_utf8();
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
-    use super::annotate_typed_edge_query_plan_visibility;
+    use super::{
+        annotate_typed_edge_query_plan_visibility, build_highlighted_snippet,
+        highlight_query_tokens, validate_snippet_highlight,
+    };
     use crate::models::{ContextHit, FindResult, HitBuckets, QueryPlan, RelationSummary};
 
     fn hit_with_relation(relation_type: Option<&str>) -> ContextHit {
@@ -113,6 +230,8 @@ mod tests {
             snippet: None,
             matched_heading: None,
             score_components: crate::models::ScoreComponents::default(),
+            relation_neighborhood: None,
+            freshness: None,
         }
     }
 
@@ -127,6 +246,9 @@ mod tests {
             skills: Vec::new(),
             trace: None,
             trace_uri: None,
+            next_offset: None,
+            next_cursor: None,
+            no_results_diagnosis: None,
         };
         annotate_typed_edge_query_plan_visibility(&mut result, false);
         assert!(result.query_plan.notes.is_empty());
@@ -146,6 +268,9 @@ mod tests {
             skills: Vec::new(),
             trace: None,
             trace_uri: None,
+            next_offset: None,
+            next_cursor: None,
+            no_results_diagnosis: None,
         };
         annotate_typed_edge_query_plan_visibility(&mut result, true);
         assert!(
@@ -163,4 +288,32 @@ mod tests {
                 .any(|value| value == "typed_edge_links:1")
         );
     }
+
+    #[test]
+    fn highlighted_snippet_marks_query_tokens_in_best_matching_window() {
+        let tokens = highlight_query_tokens("OAuth refresh");
+        let filler = "x".repeat(300);
+        let content = format!(
+            "# Guide\nintro about oauth only\n{filler} then the OAuth refresh token rotates {filler}"
+        );
+        let snippet =
+            build_highlighted_snippet(&content, "auth guide", &tokens, "**").expect("snippet");
+        assert!(snippet.contains("**OAuth** **refresh**"));
+        assert!(snippet.starts_with('…'));
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.chars().count() <= 240 + 2 + 8);
+
+        let fallback = build_highlighted_snippet("nothing relevant", "auth guide", &tokens, "**")
+            .expect("abstract");
+        assert_eq!(fallback, "auth guide");
+        assert!(build_highlighted_snippet("", "  ", &tokens, "**").is_none());
+    }
+
+    #[test]
+    fn snippet_highlight_delimiter_is_validated() {
+        assert!(validate_snippet_highlight(None).is_ok());
+        assert!(validate_snippet_highlight(Some("<<")).is_ok());
+        assert!(validate_snippet_highlight(Some(" ")).is_err());
+        assert!(validate_snippet_highlight(Some(&"=".repeat(17))).is_err());
+    }
 }
@@ -0,0 +1,94 @@
+use crate::error::Result;
+use crate::llm_io::estimate_text_tokens;
+use crate::models::{AssembledContext, AssembledContextTokens, Message};
+
+use super::{AxiomSync, SearchSetupCache, merge_runtime_om_recent_hints, normalize_hint_text};
+
+// Wider than the hint context; the token budget decides the final count.
+const ASSEMBLED_CONTEXT_MAX_MESSAGES: usize = 256;
+
+impl AxiomSync {
+    /// Assembles recent messages, related archive messages and OM hints within `budget_tokens`.
+    /// Hints use search's merge rules and take budget first; messages fill the rest newest first.
+    pub fn assemble_session_context(
+        &self,
+        session_id: &str,
+        query: &str,
+        budget_tokens: u32,
+    ) -> Result<AssembledContext> {
+        let hint_policy = self.config.search.om_hint_policy;
+        let hint_bounds = self.config.search.om_hint_bounds;
+        let snapshot = self.build_search_session_hints(
+            session_id,
+            query,
+            hint_policy,
+            hint_bounds,
+            &mut SearchSetupCache::default(),
+        )?;
+        let merged_hints = merge_runtime_om_recent_hints(
+            &[],
+            snapshot.om_hint.as_deref(),
+            &snapshot.recent_hints,
+            hint_policy,
+            hint_bounds.max_chars,
+        );
+        let context = self.session(Some(session_id)).get_context_for_search(
+            query,
+            hint_policy.context_max_archives,
+            ASSEMBLED_CONTEXT_MAX_MESSAGES,
+        )?;
+
+        let mut tokens = AssembledContextTokens::default();
+        let mut hints = Vec::with_capacity(merged_hints.len());
+        for hint in merged_hints {
+            let cost = estimate_text_tokens(&hint);
+            if tokens.total_tokens.saturating_add(cost) > budget_tokens {
+                tokens.dropped_hints += 1;
+                continue;
+            }
+            tokens.hint_tokens += cost;
+            tokens.total_tokens += cost;
+            hints.push(hint);
+        }
+        let om_hint_applied = snapshot
+            .om_hint
+            .as_deref()
+            .and_then(|om_hint| normalize_hint_text(om_hint, hint_bounds.max_chars))
+            .is_some_and(|om_hint| hints.contains(&om_hint));
+
+        let messages =
+            take_newest_within_budget(context.recent_messages, budget_tokens, &mut tokens);
+
+        Ok(AssembledContext {
+            session_id: session_id.to_string(),
+            query: query.to_string(),
+            budget_tokens,
+            messages,
+            hints,
+            om_hint_applied,
+            tokens,
+        })
+    }
+}
+
+// Newest first, stopping at the first message over budget so no gaps appear.
+fn take_newest_within_budget(
+    messages: Vec<Message>,
+    budget_tokens: u32,
+    tokens: &mut AssembledContextTokens,
+) -> Vec<Message> {
+    let total = messages.len();
+    let mut kept = Vec::new();
+    for message in messages.into_iter().rev() {
+        let cost = estimate_text_tokens(&message.text);
+        if tokens.total_tokens.saturating_add(cost) > budget_tokens {
+            break;
+        }
+        tokens.message_tokens += cost;
+        tokens.total_tokens += cost;
+        kept.push(message);
+    }
+    tokens.dropped_messages = total - kept.len();
+    kept.reverse();
+    kept
+}
@@ -19,6 +19,7 @@ pub(super) struct SearchRequestLogInput<'a> {
     pub(super) hint_policy: OmHintPolicy,
     pub(super) typed_edge_enrichment: bool,
     pub(super) result_count: Option<usize>,
+    pub(super) truncated: bool,
 }
 
 #[derive(Debug)]
@@ -163,6 +164,7 @@ pub(super) fn search_request_details(input: SearchRequestLogInput<'_>) -> serde_
         hint_policy,
         typed_edge_enrichment,
         result_count,
+        truncated,
     } = input;
     let mut details = serde_json::json!({
         "query": query,
@@ -195,6 +197,9 @@ pub(super) fn search_request_details(input: SearchRequestLogInput<'_>) -> serde_
         "om_hint_policy": hint_policy_to_json(hint_policy),
         "typed_edge_enrichment": typed_edge_enrichment,
     });
+    if truncated {
+        details["truncated"] = serde_json::json!(true);
+    }
     if let Some(result_count) = result_count {
         details["result_count"] = serde_json::json!(result_count);
     }
@@ -0,0 +1,92 @@
+use std::fs;
+use std::time::UNIX_EPOCH;
+
+use chrono::{DateTime, Utc};
+
+use crate::error::{AxiomError, Result};
+use crate::mime::infer_mime_from_name;
+use crate::models::{EntryIndexStatus, EntryStat};
+use crate::tier_documents::{abstract_path, overview_path};
+use crate::uri::AxiomUri;
+
+use super::AxiomSync;
+
+impl AxiomSync {
+    /// Size, mtime, index state and relation count for one URI; `NotFound` if missing.
+    pub fn stat(&self, uri: &str) -> Result<EntryStat> {
+        let uri = AxiomUri::parse(uri)?;
+        let path = self.fs.resolve_uri(&uri);
+        let metadata = fs::metadata(&path).map_err(|err| {
+            if err.kind() == std::io::ErrorKind::NotFound {
+                AxiomError::NotFound(uri.to_string())
+            } else {
+                AxiomError::Io(err)
+            }
+        })?;
+        let uri_raw = uri.to_string();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let index_state = self.state.get_index_state(&uri_raw)?;
+
+        let (size, mime, content_hash, index_status, child_count) = if metadata.is_dir() {
+            let size = self
+                .fs
+                .list(&uri, true)?
+                .iter()
+                .filter(|entry| !entry.is_dir)
+                .map(|entry| entry.size)
+                .sum();
+            let child_count = self.fs.list(&uri, false)?.len();
+            let index_status = if index_state.is_some() {
+                EntryIndexStatus::Indexed
+            } else {
+                EntryIndexStatus::Missing
+            };
+            (size, None, None, index_status, Some(child_count))
+        } else {
+            let hash = blake3::hash(&self.fs.read_bytes(&uri)?)
+                .to_hex()
+                .to_string();
+            let index_status = match index_state {
+                None => EntryIndexStatus::Missing,
+                Some((indexed_hash, indexed_mtime))
+                    if indexed_hash == hash || indexed_mtime == mtime_nanos(&metadata) =>
+                {
+                    EntryIndexStatus::Indexed
+                }
+                Some(_) => EntryIndexStatus::Pending,
+            };
+            let mime = infer_mime_from_name(&name).map(ToString::to_string);
+            (metadata.len(), mime, Some(hash), index_status, None)
+        };
+
+        Ok(EntryStat {
+            name,
+            is_dir: metadata.is_dir(),
+            size,
+            mtime: metadata.modified().ok().map(DateTime::<Utc>::from),
+            mime,
+            content_hash,
+            index_status,
+            has_abstract: metadata.is_dir() && abstract_path(&self.fs, &uri).is_file(),
+            has_overview: metadata.is_dir() && overview_path(&self.fs, &uri).is_file(),
+            relation_count: self.relation_count_touching(&uri)?,
+            mutable: !uri.scope().is_internal(),
+            child_count,
+            uri: uri_raw,
+        })
+    }
+}
+
+/// Nanoseconds since the UNIX epoch, matching index_state mtimes.
+fn mtime_nanos(metadata: &fs::Metadata) -> i64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |duration| {
+            i64::try_from(duration.as_nanos()).unwrap_or(i64::MAX)
+        })
+}
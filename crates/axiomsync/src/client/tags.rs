@@ -0,0 +1,123 @@
+use std::time::Instant;
+
+use crate::error::{AxiomError, Result};
+use crate::state::DocumentTagOverrides;
+use crate::uri::AxiomUri;
+
+use super::AxiomSync;
+use super::resource::normalize_resource_tags;
+
+impl AxiomSync {
+    /// Current tags: inferred plus add-request plus added, minus removed.
+    pub fn list_tags(&self, uri: &str) -> Result<Vec<String>> {
+        let uri = self.resolve_tag_target(uri)?;
+        let uri_raw = uri.to_string();
+        if let Some(record) = self
+            .index
+            .read()
+            .map_err(|_| AxiomError::lock_poisoned("index"))?
+            .get(&uri_raw)
+        {
+            return Ok(record.tags.clone());
+        }
+        let mut tags = self.state.resource_tags_for_uri(&uri_raw)?;
+        self.state
+            .document_tag_overrides(&uri_raw)?
+            .apply(&mut tags);
+        Ok(tags)
+    }
+
+    /// Adds normalized tags and reindexes only this document; they survive reindexing.
+    pub fn add_tags(&self, uri: &str, tags: &[String]) -> Result<Vec<String>> {
+        self.mutate_document_tags(uri, tags, "tags.add", |overrides, tags| {
+            overrides.removed.retain(|tag| !tags.contains(tag));
+            overrides.added.extend(tags.iter().cloned());
+        })
+    }
+
+    /// Removes tags, including inferred ones, until they are added back.
+    pub fn remove_tags(&self, uri: &str, tags: &[String]) -> Result<Vec<String>> {
+        self.mutate_document_tags(uri, tags, "tags.remove", |overrides, tags| {
+            overrides.added.retain(|tag| !tags.contains(tag));
+            overrides.removed.extend(tags.iter().cloned());
+        })
+    }
+
+    fn mutate_document_tags(
+        &self,
+        uri: &str,
+        tags: &[String],
+        operation: &str,
+        mutate: impl FnOnce(&mut DocumentTagOverrides, &[String]),
+    ) -> Result<Vec<String>> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let output = (|| -> Result<(AxiomUri, Vec<String>, Vec<String>)> {
+            let uri = self.resolve_tag_target(uri)?;
+            let tags = normalize_resource_tags(tags)?;
+            if tags.is_empty() {
+                return Err(AxiomError::Validation(
+                    "at least one tag is required".to_string(),
+                ));
+            }
+            let uri_raw = uri.to_string();
+            let mut overrides = self.state.document_tag_overrides(&uri_raw)?;
+            mutate(&mut overrides, &tags);
+            overrides.added.sort();
+            overrides.added.dedup();
+            overrides.removed.sort();
+            overrides.removed.dedup();
+            self.state
+                .upsert_document_tag_overrides(&uri_raw, &overrides)?;
+            self.index_file_entry(&uri, &self.fs.resolve_uri(&uri))?;
+            let effective = self.list_tags(&uri_raw)?;
+            Ok((uri, tags, effective))
+        })();
+
+        match output {
+            Ok((uri, tags, effective)) => {
+                self.log_request_status(
+                    request_id,
+                    operation,
+                    "ok",
+                    started,
+                    Some(uri.to_string()),
+                    Some(serde_json::json!({
+                        "tags": tags,
+                        "effective_tags": effective,
+                    })),
+                );
+                Ok(effective)
+            }
+            Err(err) => {
+                self.log_request_error(
+                    request_id,
+                    operation,
+                    started,
+                    Some(uri.to_string()),
+                    &err,
+                    Some(serde_json::json!({ "tags": tags })),
+                );
+                Err(err)
+            }
+        }
+    }
+
+    fn resolve_tag_target(&self, uri: &str) -> Result<AxiomUri> {
+        let uri = AxiomUri::parse(uri)?;
+        if uri.scope().is_internal() {
+            return Err(AxiomError::PermissionDenied(format!(
+                "internal scope does not support document tags: {uri}"
+            )));
+        }
+        if !self.fs.exists(&uri) {
+            return Err(AxiomError::NotFound(uri.to_string()));
+        }
+        if self.fs.is_dir(&uri) {
+            return Err(AxiomError::Validation(format!(
+                "document tags require a file uri: {uri}"
+            )));
+        }
+        Ok(uri)
+    }
+}
@@ -36,6 +36,8 @@ fn benchmark_suite_generates_report_and_artifacts() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark");
     assert!(report.quality.executed_cases >= 1);
@@ -53,6 +55,99 @@ fn benchmark_suite_generates_report_and_artifacts() {
     assert!(app.fs.exists(&case_set_uri));
 }
 
+#[test]
+fn benchmark_concurrency_sweep_reports_profiles_and_gates_on_level_p95() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let src = temp.path().join("benchmark_concurrency_input.txt");
+    fs::write(&src, "OAuth benchmark concurrency sweep content.").expect("write input");
+    app.add_resource(
+        src.to_str().expect("src str"),
+        Some("axiom://resources/bench-concurrency"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add failed");
+    let _ = app
+        .find(
+            "oauth",
+            Some("axiom://resources/bench-concurrency"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find");
+
+    let options = BenchmarkRunOptions {
+        query_limit: 20,
+        search_limit: 5,
+        include_golden: false,
+        include_trace: true,
+        include_stress: true,
+        trace_expectations: false,
+        fixture_name: None,
+        corpus_snapshot_id: None,
+        concurrency: Some(vec![1, 4]),
+    };
+    let report = app.run_benchmark_suite(&options).expect("benchmark");
+    let levels = report
+        .concurrency_profiles
+        .iter()
+        .map(|profile| profile.concurrency)
+        .collect::<Vec<_>>();
+    assert_eq!(levels, vec![1, 4]);
+    for profile in &report.concurrency_profiles {
+        assert_eq!(profile.executed_queries, report.quality.executed_cases);
+        assert!(profile.queries_per_second > 0.0);
+        assert!(profile.latency.p95_ms >= profile.latency.p50_ms);
+        assert!(profile.latency.p99_us >= profile.latency.p95_us);
+    }
+    let markdown_uri =
+        AxiomUri::parse(&report.artifacts.markdown_report_uri).expect("markdown uri");
+    assert!(
+        app.fs
+            .read(&markdown_uri)
+            .expect("read markdown")
+            .contains("Concurrency Profiles")
+    );
+
+    let gate_at = |limit: (usize, u128)| {
+        app.benchmark_gate_with_options(BenchmarkGateOptions {
+            threshold_p95_ms: 10_000,
+            min_top1_accuracy: 0.0,
+            record: false,
+            max_p95_ms_at_concurrency: Some(limit),
+            ..BenchmarkGateOptions::default()
+        })
+        .expect("gate")
+    };
+    let passing = gate_at((4, 10_000));
+    assert!(passing.passed, "{:?}", passing.execution.run_results);
+    assert_eq!(
+        passing.thresholds.max_p95_ms_at_concurrency,
+        Some((4, 10_000))
+    );
+    let missing = gate_at((8, 10_000));
+    assert!(!missing.passed);
+    assert!(
+        missing.execution.run_results[0]
+            .reasons
+            .contains(&"concurrency_profile_missing:8".to_string())
+    );
+
+    let err = app
+        .run_benchmark_suite(&BenchmarkRunOptions {
+            concurrency: Some(vec![0]),
+            ..options
+        })
+        .expect_err("zero concurrency must be rejected");
+    assert!(matches!(err, AxiomError::Validation(_)));
+}
+
 #[test]
 fn benchmark_trace_latency_reuses_single_retrieval_measurement() {
     let temp = tempdir().expect("tempdir");
@@ -89,6 +184,8 @@ fn benchmark_trace_latency_reuses_single_retrieval_measurement() {
             include_stress: false,
             trace_expectations: true,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark");
 
@@ -136,6 +233,8 @@ fn benchmark_report_includes_protocol_metadata_and_acceptance_mapping() {
             include_stress: true,
             trace_expectations: true,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark");
 
@@ -206,6 +305,8 @@ fn benchmark_trace_expectations_can_be_disabled() {
             include_stress: false,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark");
 
@@ -281,6 +382,8 @@ fn benchmark_top1_accuracy_uses_only_graded_cases() {
             include_stress: false,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark");
 
@@ -348,6 +451,8 @@ fn benchmark_top1_treats_duplicate_leaf_uri_as_equivalent() {
             include_stress: false,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark");
 
@@ -402,6 +507,8 @@ fn benchmark_stress_queries_are_included_from_golden_seed() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark");
 
@@ -455,6 +562,8 @@ fn benchmark_results_include_expected_rank_for_expected_cases() {
             include_stress: false,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark");
     assert!(report.results.iter().any(|x| x.expected_top_uri.is_some()));
@@ -467,6 +576,68 @@ fn benchmark_results_include_expected_rank_for_expected_cases() {
     );
 }
 
+#[test]
+fn benchmark_mrr_is_one_when_expected_ranks_first_and_lower_otherwise() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let src = temp.path().join("bench_mrr_input.txt");
+    fs::write(&src, "OAuth benchmark reciprocal rank coverage.").expect("write input");
+    app.add_resource(
+        src.to_str().expect("src str"),
+        Some("axiom://resources/bench-mrr"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add failed");
+    let first = app
+        .find(
+            "oauth reciprocal",
+            Some("axiom://resources/bench-mrr"),
+            Some(10),
+            None,
+            None,
+        )
+        .expect("find");
+    app.add_eval_golden_query(
+        "oauth reciprocal",
+        Some("axiom://resources/bench-mrr"),
+        first.query_results.first().map(|hit| hit.uri.as_str()),
+    )
+    .expect("golden add");
+
+    let options = BenchmarkRunOptions {
+        query_limit: 20,
+        search_limit: 10,
+        include_golden: true,
+        include_trace: false,
+        include_stress: false,
+        trace_expectations: false,
+        fixture_name: None,
+        corpus_snapshot_id: None,
+        concurrency: None,
+    };
+    let report = app.run_benchmark_suite(&options).expect("benchmark");
+    assert!((report.quality.mrr - 1.0).abs() < f32::EPSILON);
+
+    app.add_eval_golden_query(
+        "oauth coverage",
+        Some("axiom://resources/bench-mrr"),
+        Some("axiom://resources/bench-mrr/missing.txt"),
+    )
+    .expect("golden add missing");
+    let report = app.run_benchmark_suite(&options).expect("benchmark");
+    assert!(report.quality.mrr < 1.0);
+    assert!((report.quality.mrr - 0.5).abs() < f32::EPSILON);
+
+    let trend = app.benchmark_trend(2).expect("trend");
+    assert!((trend.latest.expect("latest").mrr - 0.5).abs() < f32::EPSILON);
+    assert!((trend.delta_mrr.expect("delta mrr") + 0.5).abs() < f32::EPSILON);
+}
+
 #[test]
 fn benchmark_suite_requires_at_least_one_source() {
     let temp = tempdir().expect("tempdir");
@@ -482,6 +653,8 @@ fn benchmark_suite_requires_at_least_one_source() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect_err("must fail");
     assert!(matches!(err, AxiomError::Validation(_)));
@@ -541,6 +714,8 @@ fn benchmark_list_and_trend_return_recent_reports() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("bench 1");
     let _ = app
@@ -552,6 +727,8 @@ fn benchmark_list_and_trend_return_recent_reports() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("bench 2");
 
@@ -602,6 +779,8 @@ fn benchmark_gate_enforces_thresholds() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark");
 
@@ -650,6 +829,8 @@ fn benchmark_gate_enforces_top1_regression_threshold() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark template");
 
@@ -690,10 +871,12 @@ fn benchmark_gate_enforces_top1_regression_threshold() {
             min_stress_top1_accuracy: None,
             max_p95_regression_pct: None,
             max_top1_regression_pct: Some(10.0),
+            max_mrr_regression_pct: None,
             window_size: 1,
             required_passes: 1,
             record: false,
             write_release_check: false,
+            max_p95_ms_at_concurrency: None,
         })
         .expect("gate");
 
@@ -709,6 +892,135 @@ fn benchmark_gate_enforces_top1_regression_threshold() {
     );
 }
 
+#[test]
+fn benchmark_gate_flags_corpus_snapshot_mismatch_and_pinned_run_rejects_drift() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let src = temp.path().join("bench_corpus_pin_input.txt");
+    fs::write(&src, "OAuth benchmark corpus pin content.").expect("write input");
+    app.add_resource(
+        src.to_str().expect("src str"),
+        Some("axiom://resources/bench-corpus-pin"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add failed");
+    let _ = app
+        .find(
+            "oauth",
+            Some("axiom://resources/bench-corpus-pin"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find");
+
+    let options = BenchmarkRunOptions {
+        query_limit: 10,
+        search_limit: 5,
+        include_golden: false,
+        include_trace: true,
+        include_stress: true,
+        trace_expectations: false,
+        fixture_name: None,
+        corpus_snapshot_id: None,
+        concurrency: None,
+    };
+    let template = app
+        .run_benchmark_suite(&options)
+        .expect("benchmark template");
+
+    let pinned = app
+        .run_benchmark_suite(&BenchmarkRunOptions {
+            corpus_snapshot_id: Some(template.corpus.snapshot_id.clone()),
+            concurrency: None,
+            ..options.clone()
+        })
+        .expect("pinned run on same corpus");
+    assert_eq!(pinned.corpus.snapshot_id, template.corpus.snapshot_id);
+    let err = app
+        .run_benchmark_suite(&BenchmarkRunOptions {
+            corpus_snapshot_id: Some("resources-000000000000".to_string()),
+            concurrency: None,
+            ..options
+        })
+        .expect_err("pinned run on different corpus");
+    assert!(matches!(err, AxiomError::Conflict(_)));
+
+    let mut previous = template.clone();
+    previous.run_id = "corpus-prev".to_string();
+    previous.created_at = "2999-01-01T00:00:01Z".to_string();
+    previous.quality.top1_accuracy = 1.0;
+
+    let mut latest = template;
+    latest.run_id = "corpus-latest".to_string();
+    latest.created_at = "2999-01-01T00:00:02Z".to_string();
+    latest.quality.top1_accuracy = 0.4;
+    latest.corpus.snapshot_id = "resources-changed0000".to_string();
+
+    for report in [&previous, &latest] {
+        let uri = AxiomUri::parse(&format!(
+            "axiom://queue/benchmarks/reports/{}.json",
+            report.run_id
+        ))
+        .expect("report uri");
+        app.fs
+            .write(
+                &uri,
+                &serde_json::to_string_pretty(report).expect("serialize report"),
+                true,
+            )
+            .expect("write report");
+    }
+
+    let gate = app
+        .benchmark_gate_with_options(BenchmarkGateOptions {
+            gate_profile: "corpus-pin-test".to_string(),
+            threshold_p95_ms: 10_000,
+            min_top1_accuracy: 0.0,
+            min_stress_top1_accuracy: None,
+            max_p95_regression_pct: None,
+            max_top1_regression_pct: Some(10.0),
+            max_mrr_regression_pct: None,
+            window_size: 1,
+            required_passes: 1,
+            record: false,
+            write_release_check: false,
+            max_p95_ms_at_concurrency: None,
+        })
+        .expect("gate");
+
+    let expected_mismatch = format!("{}!=resources-changed0000", previous.corpus.snapshot_id);
+    assert_eq!(
+        gate.snapshot.corpus_snapshot_mismatch.as_deref(),
+        Some(expected_mismatch.as_str())
+    );
+    assert!(gate.snapshot.top1_regression_pct.is_none());
+    assert!(gate.execution.run_results[0].top1_regression_pct.is_none());
+    assert!(
+        gate.execution.run_results[0]
+            .reasons
+            .iter()
+            .any(|r| r == &format!("corpus_snapshot_mismatch:{expected_mismatch}"))
+    );
+    assert!(
+        !gate.execution.run_results[0]
+            .reasons
+            .iter()
+            .any(|r| r.starts_with("top1_regression_exceeded:"))
+    );
+    assert!(
+        gate.execution
+            .reasons
+            .iter()
+            .any(|r| r.starts_with("corpus_snapshot_mismatch:"))
+    );
+}
+
 #[test]
 fn benchmark_gate_enforces_semantic_quality_regression_threshold() {
     let temp = tempdir().expect("tempdir");
@@ -745,6 +1057,8 @@ fn benchmark_gate_enforces_semantic_quality_regression_threshold() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark template");
 
@@ -795,10 +1109,12 @@ fn benchmark_gate_enforces_semantic_quality_regression_threshold() {
             min_stress_top1_accuracy: None,
             max_p95_regression_pct: None,
             max_top1_regression_pct: None,
+            max_mrr_regression_pct: None,
             window_size: 1,
             required_passes: 1,
             record: false,
             write_release_check: false,
+            max_p95_ms_at_concurrency: None,
         })
         .expect("gate");
 
@@ -815,6 +1131,92 @@ fn benchmark_gate_enforces_semantic_quality_regression_threshold() {
     }));
 }
 
+#[test]
+fn benchmark_gate_enforces_mrr_regression_threshold() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let src = temp.path().join("bench_mrr_regression_input.txt");
+    fs::write(&src, "OAuth benchmark mrr regression content.").expect("write input");
+    app.add_resource(
+        src.to_str().expect("src str"),
+        Some("axiom://resources/bench-mrr-regression"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add failed");
+    let _ = app
+        .find(
+            "oauth",
+            Some("axiom://resources/bench-mrr-regression"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find");
+
+    let template = app
+        .run_benchmark_suite(&BenchmarkRunOptions {
+            query_limit: 10,
+            search_limit: 5,
+            include_golden: false,
+            include_trace: true,
+            include_stress: false,
+            trace_expectations: false,
+            fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
+        })
+        .expect("benchmark template");
+
+    let mut previous = template.clone();
+    previous.run_id = "mrr-prev".to_string();
+    previous.created_at = "2999-03-01T00:00:01Z".to_string();
+    previous.quality.mrr = 0.9;
+    let mut latest = template;
+    latest.run_id = "mrr-latest".to_string();
+    latest.created_at = "2999-03-01T00:00:02Z".to_string();
+    latest.quality.mrr = 0.6;
+
+    for report in [&previous, &latest] {
+        let uri = AxiomUri::parse(&format!(
+            "axiom://queue/benchmarks/reports/{}.json",
+            report.run_id
+        ))
+        .expect("report uri");
+        app.fs
+            .write(
+                &uri,
+                &serde_json::to_string_pretty(report).expect("serialize report"),
+                true,
+            )
+            .expect("write report");
+    }
+
+    let gate = app
+        .benchmark_gate_with_options(BenchmarkGateOptions {
+            gate_profile: "mrr-regression-test".to_string(),
+            threshold_p95_ms: 10_000,
+            min_top1_accuracy: 0.0,
+            max_mrr_regression_pct: Some(10.0),
+            ..BenchmarkGateOptions::default()
+        })
+        .expect("gate");
+
+    assert!(!gate.passed);
+    let run = &gate.execution.run_results[0];
+    assert!(run.mrr_regression_pct.is_some_and(|pct| pct > 30.0));
+    assert!(
+        run.reasons
+            .iter()
+            .any(|r| r.starts_with("mrr_regression_exceeded:"))
+    );
+    assert_eq!(gate.thresholds.max_mrr_regression_pct, Some(10.0));
+}
+
 #[test]
 fn benchmark_gate_enforces_stress_top1_floor() {
     let temp = tempdir().expect("tempdir");
@@ -851,6 +1253,8 @@ fn benchmark_gate_enforces_stress_top1_floor() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark template");
 
@@ -885,10 +1289,12 @@ fn benchmark_gate_enforces_stress_top1_floor() {
             min_stress_top1_accuracy: Some(0.9),
             max_p95_regression_pct: None,
             max_top1_regression_pct: None,
+            max_mrr_regression_pct: None,
             window_size: 1,
             required_passes: 1,
             record: false,
             write_release_check: false,
+            max_p95_ms_at_concurrency: None,
         })
         .expect("gate");
 
@@ -956,6 +1362,8 @@ fn benchmark_fixture_create_list_and_run() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: Some("release-smoke".to_string()),
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("run fixture benchmark");
     assert!(report.quality.executed_cases >= 1);
@@ -997,6 +1405,8 @@ fn benchmark_gate_with_policy_records_result() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("bench 1");
     let _ = app
@@ -1008,6 +1418,8 @@ fn benchmark_gate_with_policy_records_result() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("bench 2");
 
@@ -1061,6 +1473,8 @@ fn benchmark_gate_with_profile_writes_release_check() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("bench 1");
     let _ = app
@@ -1072,6 +1486,8 @@ fn benchmark_gate_with_profile_writes_release_check() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("bench 2");
 
@@ -1083,10 +1499,12 @@ fn benchmark_gate_with_profile_writes_release_check() {
             min_stress_top1_accuracy: None,
             max_p95_regression_pct: None,
             max_top1_regression_pct: None,
+            max_mrr_regression_pct: None,
             window_size: 2,
             required_passes: 2,
             record: true,
             write_release_check: true,
+            max_p95_ms_at_concurrency: None,
         })
         .expect("profile gate");
     assert_eq!(gate.gate_profile, "macmini-release");
@@ -1157,6 +1575,8 @@ fn benchmark_gate_release_propagates_structured_embedding_strict_error() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("bench");
 
@@ -1182,10 +1602,12 @@ fn benchmark_gate_release_propagates_structured_embedding_strict_error() {
             min_stress_top1_accuracy: None,
             max_p95_regression_pct: None,
             max_top1_regression_pct: None,
+            max_mrr_regression_pct: None,
             window_size: 1,
             required_passes: 1,
             record: true,
             write_release_check: true,
+            max_p95_ms_at_concurrency: None,
         })
         .expect("profile gate");
     assert!(!gate.passed);
@@ -1255,6 +1677,8 @@ fn benchmark_gate_with_policy_reports_insufficient_history() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark");
 
@@ -1307,6 +1731,8 @@ fn benchmark_amortized_mode_runs_multiple_iterations_in_process() {
                 include_stress: true,
                 trace_expectations: false,
                 fixture_name: None,
+                corpus_snapshot_id: None,
+                concurrency: None,
             },
             3,
         )
@@ -1319,3 +1745,74 @@ fn benchmark_amortized_mode_runs_multiple_iterations_in_process() {
     assert!(report.timing.wall_total_ms >= report.timing.p95_latency_ms_median);
     assert!(report.timing.p95_latency_us_median.is_some());
 }
+
+#[test]
+fn redaction_policy_masks_secrets_in_fixture_and_ovpack_but_keeps_original() {
+    let temp = tempdir().expect("tempdir");
+    let mut app = AxiomSync::new(temp.path()).expect("app new");
+    let mut config = (*app.config).clone();
+    config.redaction = crate::config::RedactionPolicy::new(
+        vec![(Scope::User, crate::config::ScopeRedaction::Patterns)],
+        &[],
+    )
+    .expect("redaction policy");
+    app.config = std::sync::Arc::new(config);
+    app.initialize().expect("init failed");
+
+    let secret = "api_key=sk-live0123456789abcdefXYZ";
+    let doc_uri = "axiom://user/notes/creds.md";
+    app.fs
+        .write(
+            &AxiomUri::parse(doc_uri).expect("doc uri"),
+            &format!("# Credentials\n\noauth client uses {secret}\n"),
+            true,
+        )
+        .expect("write doc");
+    app.reindex_all().expect("reindex");
+    let _ = app
+        .find(
+            &format!("oauth {secret}"),
+            Some("axiom://user/notes"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find");
+
+    app.create_benchmark_fixture(
+        "redacted",
+        BenchmarkFixtureCreateOptions {
+            query_limit: 10,
+            include_golden: false,
+            include_trace: true,
+            include_stress: false,
+            trace_expectations: false,
+        },
+    )
+    .expect("create fixture");
+    let fixture_raw = app
+        .fs
+        .read(&crate::catalog::benchmark_fixture_uri("redacted").expect("fixture uri"))
+        .expect("read fixture");
+    assert!(!fixture_raw.contains("sk-live"), "{fixture_raw}");
+    assert!(fixture_raw.contains("oauth [REDACTED]"));
+
+    let pack_path = app
+        .export_ovpack(
+            "axiom://user/notes",
+            temp.path().join("notes").to_str().expect("pack path"),
+        )
+        .expect("export ovpack");
+    let mut archive =
+        zip::ZipArchive::new(fs::File::open(pack_path).expect("open pack")).expect("zip");
+    let mut packed = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name("notes/creds.md").expect("packed doc"),
+        &mut packed,
+    )
+    .expect("read packed doc");
+    assert!(!packed.contains("sk-live"));
+    assert!(packed.contains("oauth client uses [REDACTED]"));
+
+    assert!(app.read(doc_uri).expect("read original").contains(secret));
+}
@@ -1,5 +1,7 @@
 use super::*;
-use crate::models::QueueEventStatus;
+use crate::models::{
+    AddResourceRequest, DedupAction, EntryIndexStatus, MarkdownPatchOp, QueueEventStatus,
+};
 
 #[test]
 fn end_to_end_add_and_find() {
@@ -108,6 +110,332 @@ fn mkdir_enforces_scope_policy_and_enqueues_reindex_event() {
     }));
 }
 
+#[test]
+fn mkdir_creates_parents_by_default_and_is_idempotent_for_existing_dirs() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let nested = "axiom://resources/mkdir-p/a/b/c";
+    app.mkdir(nested).expect("mkdir nested");
+    assert!(app.fs.exists(&AxiomUri::parse(nested).expect("nested uri")));
+
+    let before = app
+        .state
+        .fetch_outbox(QueueEventStatus::New, 100)
+        .expect("fetch before")
+        .len();
+    app.mkdir(nested).expect("mkdir existing dir is a no-op");
+    app.mkdir_with_parents(nested, false)
+        .expect("mkdir existing dir without parents is a no-op");
+    let after = app
+        .state
+        .fetch_outbox(QueueEventStatus::New, 100)
+        .expect("fetch after")
+        .len();
+    assert_eq!(before, after, "no-op mkdir must not enqueue reindex");
+
+    let err = app
+        .mkdir_with_parents("axiom://resources/mkdir-p/missing/child", false)
+        .expect_err("missing parent must fail without parents");
+    assert!(matches!(err, AxiomError::NotFound(_)));
+    assert!(
+        !app.fs
+            .exists(&AxiomUri::parse("axiom://resources/mkdir-p/missing").expect("missing uri"))
+    );
+    app.mkdir_with_parents("axiom://resources/mkdir-p/a/sibling", false)
+        .expect("existing parent allows mkdir without parents");
+}
+
+#[test]
+fn mkdir_rejects_existing_file_at_target_or_parent() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let file_uri = AxiomUri::parse("axiom://resources/mkdir-file/note.md").expect("file uri");
+    app.fs.write(&file_uri, "# Note", true).expect("write file");
+
+    let err = app
+        .mkdir("axiom://resources/mkdir-file/note.md")
+        .expect_err("mkdir over file must fail");
+    assert!(matches!(err, AxiomError::Conflict(_)));
+
+    let err = app
+        .mkdir("axiom://resources/mkdir-file/note.md/child")
+        .expect_err("mkdir under file must fail");
+    assert!(matches!(err, AxiomError::Conflict(_)));
+    assert_eq!(
+        app.read("axiom://resources/mkdir-file/note.md")
+            .expect("read"),
+        "# Note"
+    );
+}
+
+#[test]
+fn ls_page_walks_fifty_entries_in_stable_non_overlapping_pages() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    for idx in 0..50 {
+        let uri = AxiomUri::parse(&format!("axiom://resources/ls-page/entry-{idx:02}.md"))
+            .expect("entry uri");
+        app.fs
+            .write(&uri, &format!("# Entry {idx}"), true)
+            .expect("write entry");
+    }
+    let full = app
+        .ls("axiom://resources/ls-page", false, false)
+        .expect("ls full");
+    assert_eq!(full.len(), 50);
+
+    let mut paged = Vec::new();
+    let mut offset = 0;
+    loop {
+        let page = app
+            .ls_page("axiom://resources/ls-page", false, offset, 15)
+            .expect("ls page");
+        assert_eq!(page.total, 50);
+        assert_eq!(page.offset, offset);
+        assert!(page.entries.len() <= 15);
+        offset += page.entries.len();
+        paged.extend(page.entries.into_iter().map(|entry| entry.uri));
+        if !page.has_more {
+            break;
+        }
+    }
+    assert_eq!(
+        paged,
+        full.iter()
+            .map(|entry| entry.uri.clone())
+            .collect::<Vec<_>>()
+    );
+
+    let past_end = app
+        .ls_page("axiom://resources/ls-page", false, 80, 10)
+        .expect("page past end");
+    assert!(past_end.entries.is_empty());
+    assert!(!past_end.has_more);
+
+    let err = app
+        .ls_page("axiom://resources/ls-page", false, 0, 0)
+        .expect_err("zero limit");
+    assert!(matches!(err, AxiomError::Validation(_)));
+}
+
+#[test]
+fn stat_reports_file_and_directory_metadata_with_index_status() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let source = temp.path().join("stat-src");
+    fs::create_dir_all(&source).expect("mkdir source");
+    fs::write(source.join("a.md"), "# Alpha\n\nstat body").expect("write a");
+    fs::write(source.join("b.txt"), "bravo").expect("write b");
+    app.add_resource(
+        source.to_str().expect("source"),
+        Some("axiom://resources/stat"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+    app.link(
+        "axiom://resources/stat",
+        "see-also",
+        vec![
+            "axiom://resources/stat/a.md".to_string(),
+            "axiom://resources/stat/b.txt".to_string(),
+        ],
+        "related",
+    )
+    .expect("link");
+
+    let file = app.stat("axiom://resources/stat/a.md").expect("stat file");
+    assert!(!file.is_dir);
+    assert_eq!(file.size, "# Alpha\n\nstat body".len() as u64);
+    assert_eq!(file.mime.as_deref(), Some("text/markdown"));
+    assert_eq!(
+        file.content_hash.as_deref(),
+        Some(blake3::hash(b"# Alpha\n\nstat body").to_hex().as_str())
+    );
+    assert_eq!(file.index_status, EntryIndexStatus::Indexed);
+    assert_eq!(file.relation_count, 1);
+    assert!(file.mutable);
+    assert!(file.mtime.is_some());
+    assert_eq!(file.child_count, None);
+
+    let dir = app.stat("axiom://resources/stat").expect("stat dir");
+    let children = app.ls("axiom://resources/stat", false, false).expect("ls");
+    let files = app
+        .ls("axiom://resources/stat", true, false)
+        .expect("ls -r");
+    assert!(dir.is_dir);
+    assert_eq!(dir.child_count, Some(children.len()));
+    assert_eq!(
+        dir.size,
+        files
+            .iter()
+            .filter(|entry| !entry.is_dir)
+            .map(|entry| entry.size)
+            .sum::<u64>()
+    );
+    assert!(dir.has_abstract && dir.has_overview);
+    assert_eq!(dir.index_status, EntryIndexStatus::Indexed);
+
+    let path = app
+        .fs
+        .resolve_uri(&AxiomUri::parse("axiom://resources/stat/b.txt").expect("uri"));
+    fs::write(&path, "bravo changed on disk").expect("rewrite b");
+    let changed = app.stat("axiom://resources/stat/b.txt").expect("stat b");
+    assert_eq!(changed.index_status, EntryIndexStatus::Pending);
+
+    let unindexed = AxiomUri::parse("axiom://resources/stat/new.md").expect("uri");
+    app.fs.write(&unindexed, "# New", false).expect("write new");
+    let missing_index = app.stat("axiom://resources/stat/new.md").expect("stat new");
+    assert_eq!(missing_index.index_status, EntryIndexStatus::Missing);
+
+    let internal = AxiomUri::parse("axiom://temp/stat/scratch.txt").expect("uri");
+    app.fs
+        .write(&internal, "scratch", true)
+        .expect("write temp");
+    let internal = app
+        .stat("axiom://temp/stat/scratch.txt")
+        .expect("stat temp");
+    assert!(!internal.mutable);
+
+    let err = app
+        .stat("axiom://resources/stat/absent.md")
+        .expect_err("missing path");
+    assert!(matches!(err, AxiomError::NotFound(_)));
+}
+
+#[test]
+fn rm_moves_to_trash_by_default_and_rm_permanent_deletes() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let soft = AxiomUri::parse("axiom://resources/rm-soft/a.md").expect("uri");
+    let hard = AxiomUri::parse("axiom://resources/rm-hard/b.md").expect("uri");
+    app.fs.write(&soft, "# A", true).expect("write soft");
+    app.fs.write(&hard, "# B", true).expect("write hard");
+
+    app.rm("axiom://resources/rm-soft", true).expect("rm");
+    assert!(!app.fs.resolve_uri(&soft).exists());
+    let trashed = app.list_trash(10).expect("list trash");
+    assert_eq!(trashed.len(), 1);
+    assert_eq!(trashed[0].original_uri, "axiom://resources/rm-soft");
+
+    app.rm_permanent("axiom://resources/rm-hard", true)
+        .expect("rm permanent");
+    assert!(!app.fs.resolve_uri(&hard).exists());
+    assert_eq!(app.list_trash(10).expect("list trash").len(), 1);
+
+    let temp_file = AxiomUri::parse("axiom://temp/rm-internal.md").expect("uri");
+    app.fs.write(&temp_file, "tmp", true).expect("write temp");
+    app.rm("axiom://temp/rm-internal.md", false)
+        .expect("rm internal");
+    assert!(!app.fs.resolve_uri(&temp_file).exists());
+    assert_eq!(app.list_trash(10).expect("list trash").len(), 1);
+}
+
+#[test]
+fn rm_glob_permanent_skips_trash() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    for path in ["a.tmp.md", "b.tmp.md"] {
+        let uri = AxiomUri::parse(&format!("axiom://resources/glob-hard/{path}")).expect("uri");
+        app.fs.write(&uri, "# tmp", true).expect("write");
+    }
+    let report = app
+        .rm_glob("*.tmp.md", Some("axiom://resources/glob-hard"), false, true)
+        .expect("rm glob permanent");
+    assert!(report.permanent);
+    assert_eq!(report.removed, 2);
+    for uri in &report.uris {
+        assert!(app.read(uri).is_err(), "{uri} must be removed");
+    }
+    assert!(app.list_trash(10).expect("list trash").is_empty());
+}
+
+#[test]
+fn rm_glob_dry_run_reports_then_removes_matching_files_and_index_state() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    for (path, body) in [
+        ("a.tmp.md", "# A"),
+        ("b.tmp.md", "# B"),
+        ("keep.md", "# Keep"),
+        ("sub/c.tmp.md", "# C"),
+    ] {
+        let uri = AxiomUri::parse(&format!("axiom://user/notes/{path}")).expect("uri");
+        app.fs.write(&uri, body, true).expect("write note");
+    }
+    app.reindex_all().expect("reindex");
+
+    let preview = app
+        .rm_glob("*.tmp.md", Some("axiom://user/notes"), true, false)
+        .expect("dry run");
+    assert!(preview.dry_run);
+    assert_eq!(
+        preview.uris,
+        vec![
+            "axiom://user/notes/a.tmp.md".to_string(),
+            "axiom://user/notes/b.tmp.md".to_string(),
+            "axiom://user/notes/sub/c.tmp.md".to_string(),
+        ]
+    );
+    assert_eq!(preview.removed, 0);
+    assert_eq!(preview.index_state_removed, 3);
+    assert!(app.read("axiom://user/notes/a.tmp.md").is_ok());
+
+    let report = app
+        .rm_glob("*.tmp.md", Some("axiom://user/notes"), false, false)
+        .expect("rm glob");
+    assert_eq!(report.uris, preview.uris);
+    assert_eq!(report.removed, 3);
+    assert_eq!(report.index_state_removed, 3);
+    for uri in &report.uris {
+        assert!(app.read(uri).is_err(), "{uri} must be removed");
+        assert!(
+            app.state
+                .get_index_state_hash(uri)
+                .expect("index state")
+                .is_none()
+        );
+    }
+    let mut trashed = app
+        .list_trash(10)
+        .expect("list trash")
+        .into_iter()
+        .map(|entry| entry.original_uri)
+        .collect::<Vec<_>>();
+    trashed.sort();
+    assert_eq!(trashed, report.uris);
+    assert_eq!(
+        app.read("axiom://user/notes/keep.md").expect("keep"),
+        "# Keep"
+    );
+
+    let queue_file = AxiomUri::parse("axiom://queue/glob.tmp.md").expect("queue uri");
+    app.fs
+        .write(&queue_file, "queue", true)
+        .expect("write queue file");
+    let err = app
+        .rm_glob("*.tmp.md", Some("axiom://queue"), false, false)
+        .expect_err("queue scope must be rejected");
+    assert!(matches!(err, AxiomError::PermissionDenied(_)));
+    assert!(app.fs.resolve_uri(&queue_file).is_file());
+}
+
 #[test]
 fn tree_and_glob_reflect_resource_view_for_client_api() {
     let temp = tempdir().expect("tempdir");
@@ -289,7 +617,173 @@ fn markdown_editor_rejects_etag_conflict() {
     let err = app
         .save_markdown(uri, "# Guide\n\netag_v3", Some(&loaded.etag))
         .expect_err("must conflict");
-    assert!(matches!(err, AxiomError::Conflict(_)));
+    let current = app.load_markdown(uri).expect("reload");
+    match &err {
+        AxiomError::EtagMismatch {
+            expected_etag,
+            current_etag,
+            ..
+        } => {
+            assert_eq!(expected_etag, &loaded.etag);
+            assert_eq!(current_etag, &current.etag);
+        }
+        other => panic!("expected etag mismatch, got {other:?}"),
+    }
+
+    let payload = err.to_payload("markdown.save", Some(uri.to_string()));
+    assert_eq!(payload.code, "CONFLICT");
+    let details = payload.details.expect("conflict payload details");
+    assert_eq!(details["current_etag"], current.etag);
+    assert_eq!(details["expected_etag"], loaded.etag);
+}
+
+#[test]
+fn markdown_patch_applies_line_ranges_atomically_with_etag_guard() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let corpus_dir = temp.path().join("markdown_patch_corpus");
+    fs::create_dir_all(&corpus_dir).expect("mkdir");
+    fs::write(
+        corpus_dir.join("guide.md"),
+        "# Guide\n\nalpha\nbeta\ngamma\n",
+    )
+    .expect("write md");
+    app.add_resource(
+        corpus_dir.to_str().expect("corpus str"),
+        Some("axiom://resources/markdown-patch"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add failed");
+
+    let uri = "axiom://resources/markdown-patch/guide.md";
+    let loaded = app.load_markdown(uri).expect("load");
+
+    let overlap = app
+        .patch_markdown(
+            uri,
+            vec![
+                MarkdownPatchOp {
+                    start_line: 3,
+                    end_line: 4,
+                    replacement: "x".to_string(),
+                },
+                MarkdownPatchOp {
+                    start_line: 4,
+                    end_line: 5,
+                    replacement: "y".to_string(),
+                },
+            ],
+            Some(&loaded.etag),
+        )
+        .expect_err("overlap must fail");
+    assert!(matches!(overlap, AxiomError::Validation(_)));
+    assert_eq!(
+        app.read(uri).expect("read"),
+        "# Guide\n\nalpha\nbeta\ngamma\n"
+    );
+
+    let patched = app
+        .patch_markdown(
+            uri,
+            vec![
+                MarkdownPatchOp {
+                    start_line: 5,
+                    end_line: 5,
+                    replacement: "patchomega one\npatchomega two".to_string(),
+                },
+                MarkdownPatchOp {
+                    start_line: 3,
+                    end_line: 3,
+                    replacement: String::new(),
+                },
+            ],
+            Some(&loaded.etag),
+        )
+        .expect("patch");
+    assert_eq!(patched.changed_lines, 3);
+    assert_eq!(patched.applied_ops, 2);
+    assert_eq!(
+        app.read(uri).expect("read"),
+        "# Guide\n\nbeta\npatchomega one\npatchomega two\n"
+    );
+    assert_eq!(patched.etag, app.load_markdown(uri).expect("reload").etag);
+
+    let stale = app
+        .patch_markdown(
+            uri,
+            vec![MarkdownPatchOp {
+                start_line: 1,
+                end_line: 0,
+                replacement: "stale".to_string(),
+            }],
+            Some(&loaded.etag),
+        )
+        .expect_err("stale etag must conflict");
+    assert_eq!(stale.code(), "CONFLICT");
+
+    let found = app
+        .find(
+            "patchomega",
+            Some("axiom://resources/markdown-patch"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find");
+    assert!(found.query_results.iter().any(|x| x.uri == uri));
+}
+
+#[test]
+fn append_markdown_accumulates_content_and_keeps_both_tokens_searchable() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+    app.mkdir("axiom://resources/journal").expect("mkdir");
+
+    let uri = "axiom://resources/journal/log.md";
+    let first = app
+        .append_markdown(uri, "- appendalpha entry\n")
+        .expect("first append creates file");
+    assert_eq!(first.reindexed_root, "axiom://resources/journal");
+    let second = app
+        .append_markdown(uri, "- appendbravo entry\n")
+        .expect("second append");
+    assert_ne!(first.etag, second.etag);
+    assert_eq!(
+        app.read(uri).expect("read"),
+        "- appendalpha entry\n- appendbravo entry\n"
+    );
+    assert_eq!(second.etag, app.load_markdown(uri).expect("load").etag);
+
+    for token in ["appendalpha", "appendbravo"] {
+        let found = app
+            .find(
+                token,
+                Some("axiom://resources/journal"),
+                Some(5),
+                None,
+                None,
+            )
+            .expect("find");
+        assert!(
+            found.query_results.iter().any(|hit| hit.uri == uri),
+            "appended token {token} must be searchable"
+        );
+    }
+
+    let tier = app
+        .append_markdown("axiom://resources/journal/.overview.md", "x")
+        .expect_err("tier target");
+    assert!(matches!(tier, AxiomError::PermissionDenied(_)));
+    let internal = app
+        .append_markdown("axiom://temp/journal/log.md", "x")
+        .expect_err("internal target");
+    assert!(matches!(internal, AxiomError::PermissionDenied(_)));
 }
 
 #[test]
@@ -403,6 +897,46 @@ fn move_rejects_cross_scope_transfer() {
     assert!(matches!(err, AxiomError::PermissionDenied(_)));
 }
 
+#[test]
+fn mv_rejects_existing_destination_and_internal_scope() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let source = AxiomUri::parse("axiom://resources/rename/draft.md").expect("source uri");
+    let taken = AxiomUri::parse("axiom://resources/rename/taken.md").expect("taken uri");
+    app.fs
+        .write(&source, "# Draft", true)
+        .expect("write source");
+    app.fs.write(&taken, "# Taken", true).expect("write taken");
+
+    let err = app
+        .mv(
+            "axiom://resources/rename/draft.md",
+            "axiom://resources/rename/taken.md",
+        )
+        .expect_err("existing destination must conflict");
+    assert!(matches!(err, AxiomError::Conflict(_)));
+    assert_eq!(app.fs.read(&taken).expect("read taken"), "# Taken");
+
+    app.mv(
+        "axiom://resources/rename/draft.md",
+        "axiom://resources/rename/final.md",
+    )
+    .expect("rename within resources");
+    assert!(!app.fs.exists(&source));
+    assert_eq!(
+        app.read("axiom://resources/rename/final.md")
+            .expect("read renamed"),
+        "# Draft"
+    );
+
+    let err = app
+        .mv("axiom://queue/a.log", "axiom://queue/b.log")
+        .expect_err("queue move must be rejected");
+    assert!(matches!(err, AxiomError::PermissionDenied(_)));
+}
+
 #[test]
 fn rm_prunes_index_state_prefix_entries() {
     let temp = tempdir().expect("tempdir");
@@ -441,6 +975,83 @@ fn rm_prunes_index_state_prefix_entries() {
     );
 }
 
+#[test]
+fn rm_moves_to_trash_and_restore_reindexes_with_relations() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let corpus_dir = temp.path().join("trash_corpus");
+    fs::create_dir_all(&corpus_dir).expect("mkdir");
+    fs::write(corpus_dir.join("guide.md"), "# Guide\n\ntrashable_token").expect("write md");
+    fs::write(corpus_dir.join("notes.md"), "# Notes\n\nrelated").expect("write notes");
+    app.add_resource(
+        corpus_dir.to_str().expect("corpus str"),
+        Some("axiom://resources/trash-demo"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add failed");
+    app.link(
+        "axiom://resources/trash-demo",
+        "see-also",
+        vec![
+            "axiom://resources/trash-demo/guide.md".to_string(),
+            "axiom://resources/trash-demo/notes.md".to_string(),
+        ],
+        "guide and notes",
+    )
+    .expect("link");
+
+    let entry = app
+        .trash("axiom://resources/trash-demo", true)
+        .expect("trash")
+        .expect("trash entry");
+    assert_eq!(entry.original_uri, "axiom://resources/trash-demo");
+    assert!(entry.trash_uri.starts_with("axiom://trash/"));
+    assert!(!temp.path().join("resources").join("trash-demo").exists());
+    assert_eq!(app.list_trash(10).expect("list trash"), vec![entry.clone()]);
+    let hidden = app
+        .find("trashable_token", None, Some(5), None, None)
+        .expect("find after rm");
+    assert!(hidden.query_results.iter().all(|hit| {
+        !hit.uri.starts_with("axiom://resources/trash-demo")
+            && !hit.uri.starts_with("axiom://trash")
+    }));
+
+    app.mkdir("axiom://resources/trash-demo").expect("recreate");
+    let err = app
+        .restore_from_trash(&entry.trash_uri)
+        .expect_err("restore over existing path");
+    assert!(matches!(err, AxiomError::Conflict(_)));
+    app.rm_permanent("axiom://resources/trash-demo", true)
+        .expect("rm permanent");
+
+    app.restore_from_trash(&entry.trash_uri).expect("restore");
+    assert!(app.list_trash(10).expect("list after restore").is_empty());
+    let restored = app
+        .find("trashable_token", None, Some(5), None, None)
+        .expect("find after restore");
+    assert!(
+        restored
+            .query_results
+            .iter()
+            .any(|hit| hit.uri == "axiom://resources/trash-demo/guide.md")
+    );
+    let relations = app
+        .relations("axiom://resources/trash-demo")
+        .expect("relations");
+    assert_eq!(relations.len(), 1);
+
+    app.trash("axiom://resources/trash-demo", true)
+        .expect("trash again");
+    assert_eq!(app.empty_trash(1).expect("keep recent").len(), 0);
+    assert_eq!(app.empty_trash(0).expect("empty").len(), 1);
+    assert!(app.list_trash(10).expect("list after empty").is_empty());
+}
+
 #[test]
 fn mv_prunes_old_prefix_from_index_state_and_search_results() {
     let temp = tempdir().expect("tempdir");
@@ -582,6 +1193,284 @@ fn session_delete_removes_scope_tree_and_index_prefix() {
     );
 }
 
+#[test]
+fn session_bundle_round_trips_into_new_root_with_new_id() {
+    let source_dir = tempdir().expect("source tempdir");
+    let source = AxiomSync::new(source_dir.path()).expect("source app");
+    source.initialize().expect("source init");
+
+    let session = source.session(Some("s-bundle-src"));
+    session.load().expect("load");
+    session
+        .add_message("user", "archived bundle_archive_token note")
+        .expect("add archived");
+    session.commit().expect("commit");
+    session
+        .add_message("assistant", "active bundle message")
+        .expect("add active");
+
+    let scope_key = crate::om::build_scope_key(
+        crate::om::OmScope::Session,
+        Some("s-bundle-src"),
+        None,
+        None,
+    )
+    .expect("scope key");
+    let now = Utc::now();
+    source
+        .state
+        .upsert_om_record(&crate::om::OmRecord {
+            id: "om-bundle-src".to_string(),
+            scope: crate::om::OmScope::Session,
+            scope_key,
+            session_id: Some("s-bundle-src".to_string()),
+            thread_id: Some("s-bundle-src".to_string()),
+            resource_id: None,
+            generation_count: 2,
+            last_applied_outbox_event_id: Some(7),
+            origin_type: crate::om::OmOriginType::Initial,
+            active_observations: "bundle observation".to_string(),
+            observation_token_count: 3,
+            pending_message_tokens: 0,
+            last_observed_at: Some(now),
+            current_task: None,
+            suggested_response: None,
+            last_activated_message_ids: Vec::new(),
+            observer_trigger_count_total: 0,
+            reflector_trigger_count_total: 0,
+            is_observing: false,
+            is_reflecting: false,
+            is_buffering_observation: false,
+            is_buffering_reflection: false,
+            last_buffered_at_tokens: 0,
+            last_buffered_at_time: None,
+            buffered_reflection: None,
+            buffered_reflection_tokens: None,
+            buffered_reflection_input_tokens: None,
+            created_at: now,
+            updated_at: now,
+        })
+        .expect("seed om record");
+
+    let bundle = session.export_bundle().expect("export");
+    assert_eq!(bundle.messages.len(), 1);
+    assert_eq!(bundle.archives.len(), 1);
+    assert!(bundle.om.is_some());
+    let raw = serde_json::to_string(&bundle).expect("serialize bundle");
+    let bundle: crate::models::SessionBundle = serde_json::from_str(&raw).expect("parse bundle");
+
+    let target_dir = tempdir().expect("target tempdir");
+    let target = AxiomSync::new(target_dir.path()).expect("target app");
+    target.initialize().expect("target init");
+    let info = target
+        .import_session_bundle(&bundle, Some("s-bundle-dst"), false)
+        .expect("import");
+    assert_eq!(info.session_id, "s-bundle-dst");
+    assert_eq!(info.uri, "axiom://session/s-bundle-dst");
+
+    let imported = target
+        .session(Some("s-bundle-dst"))
+        .export_bundle()
+        .expect("re-export");
+    assert_eq!(imported.meta.session_id, "s-bundle-dst");
+    assert_eq!(imported.messages[0].text, "active bundle message");
+    assert_eq!(imported.archives[0].number, 1);
+    assert_eq!(
+        imported.archives[0].messages[0].text,
+        "archived bundle_archive_token note"
+    );
+
+    let om = imported.om.expect("imported om");
+    assert_eq!(om.record.scope_key, "session:s-bundle-dst");
+    assert_eq!(om.record.session_id.as_deref(), Some("s-bundle-dst"));
+    assert_eq!(om.record.thread_id.as_deref(), Some("s-bundle-dst"));
+    assert_eq!(om.record.last_applied_outbox_event_id, None);
+    assert_eq!(om.record.active_observations, "bundle observation");
+
+    let indexed = target.state.list_index_state_uris().expect("index uris");
+    assert!(
+        indexed
+            .iter()
+            .any(|uri| uri.starts_with("axiom://session/s-bundle-dst/history/archive_001")),
+        "imported archives must be reindexed"
+    );
+}
+
+#[test]
+fn session_bundle_import_requires_force_to_replace_existing_session() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let session = app.session(Some("s-bundle-force"));
+    session.load().expect("load");
+    session.add_message("user", "original").expect("add");
+    let mut bundle = session.export_bundle().expect("export");
+    bundle.messages[0].text = "replaced".to_string();
+
+    let err = app
+        .import_session_bundle(&bundle, None, false)
+        .expect_err("existing session must conflict");
+    assert!(matches!(err, AxiomError::Conflict(_)));
+
+    app.import_session_bundle(&bundle, None, true)
+        .expect("forced import");
+    let restored = session.export_bundle().expect("re-export");
+    assert_eq!(restored.messages.len(), 1);
+    assert_eq!(restored.messages[0].text, "replaced");
+
+    bundle.version = 99;
+    let err = app
+        .import_session_bundle(&bundle, Some("s-bundle-other"), false)
+        .expect_err("unsupported version");
+    assert!(matches!(err, AxiomError::Validation(_)));
+}
+
+#[test]
+fn session_archive_round_trips_messages_and_om_hint_after_delete() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let session = app.session(Some("s-archive-src"));
+    session.load().expect("load");
+    session
+        .add_message("user", "archived session_archive_token note")
+        .expect("add archived");
+    session.commit().expect("commit");
+    session
+        .add_message("assistant", "active archive message")
+        .expect("add active");
+    let source = &app;
+    let scope_key = crate::om::build_scope_key(
+        crate::om::OmScope::Session,
+        Some("s-archive-src"),
+        None,
+        None,
+    )
+    .expect("scope key");
+    let now = Utc::now();
+    source
+        .state
+        .upsert_om_record(&crate::om::OmRecord {
+            id: "om-archive-src".to_string(),
+            scope: crate::om::OmScope::Session,
+            scope_key,
+            session_id: Some("s-archive-src".to_string()),
+            thread_id: Some("s-archive-src".to_string()),
+            resource_id: None,
+            generation_count: 2,
+            last_applied_outbox_event_id: Some(7),
+            origin_type: crate::om::OmOriginType::Initial,
+            active_observations: "archive observation".to_string(),
+            observation_token_count: 3,
+            pending_message_tokens: 0,
+            last_observed_at: Some(now),
+            current_task: None,
+            suggested_response: None,
+            last_activated_message_ids: Vec::new(),
+            observer_trigger_count_total: 0,
+            reflector_trigger_count_total: 0,
+            is_observing: false,
+            is_reflecting: false,
+            is_buffering_observation: false,
+            is_buffering_reflection: false,
+            last_buffered_at_tokens: 0,
+            last_buffered_at_time: None,
+            buffered_reflection: None,
+            buffered_reflection_tokens: None,
+            buffered_reflection_input_tokens: None,
+            created_at: now,
+            updated_at: now,
+        })
+        .expect("seed om record");
+
+    let hints_for = |session_id: &str| {
+        app.explain_search(crate::models::SearchRequest {
+            query: "session_archive_token".to_string(),
+            target_uri: None,
+            target_uris: Vec::new(),
+            session: Some(session_id.to_string()),
+            limit: Some(5),
+            score_threshold: None,
+            min_match_tokens: None,
+            filter: None,
+            budget: None,
+            runtime_hints: Vec::new(),
+            exclude_scopes: Vec::new(),
+            scope_set: crate::models::SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
+        })
+        .expect("explain")
+        .session_hints
+    };
+    let before = session.show().expect("show before");
+    let hints_before = hints_for("s-archive-src");
+    let observations_for = |session_id: &str| {
+        app.om_state(session_id)
+            .expect("om state")
+            .map(|state| state.active_observations)
+    };
+    assert_eq!(
+        observations_for("s-archive-src").as_deref(),
+        Some("archive observation")
+    );
+
+    let archive_path = temp.path().join("exports").join("s-archive-src.ovpack");
+    let written = app
+        .export_session("s-archive-src", archive_path.to_str().expect("path str"))
+        .expect("export session");
+    assert!(app.delete("s-archive-src").expect("delete"));
+
+    let info = app
+        .import_session(&written, None, false)
+        .expect("import session");
+    assert_eq!(info.session_id, "s-archive-src");
+    let restored = session.show().expect("show restored");
+    assert_eq!(restored.messages.len(), before.messages.len());
+    assert_eq!(restored.archives.len(), before.archives.len());
+    assert_eq!(hints_for("s-archive-src"), hints_before);
+    assert_eq!(
+        observations_for("s-archive-src").as_deref(),
+        Some("archive observation")
+    );
+    assert!(
+        !temp
+            .path()
+            .join("session/s-archive-src/.session-bundle.json")
+            .exists()
+    );
+
+    let err = app
+        .import_session(&written, None, false)
+        .expect_err("non-empty session must conflict");
+    assert!(matches!(err, AxiomError::Conflict(_)));
+
+    let copy = app
+        .import_session(&written, Some("s-archive-copy"), false)
+        .expect("import as new id");
+    assert_eq!(copy.uri, "axiom://session/s-archive-copy");
+    let copied = app
+        .session(Some("s-archive-copy"))
+        .show()
+        .expect("show copy");
+    assert_eq!(copied.meta.session_id, "s-archive-copy");
+    assert_eq!(copied.messages.len(), before.messages.len());
+    assert_eq!(
+        hints_for("s-archive-copy"),
+        hints_before,
+        "OM record must be restored under the new session id"
+    );
+    assert_eq!(
+        observations_for("s-archive-copy").as_deref(),
+        Some("archive observation")
+    );
+}
+
 #[test]
 fn add_resource_replacing_target_prunes_stale_index_entries() {
     let temp = tempdir().expect("tempdir");
@@ -657,6 +1546,675 @@ fn add_resource_replacing_target_prunes_stale_index_entries() {
     );
 }
 
+#[test]
+fn add_resource_dedup_skips_content_already_indexed_elsewhere() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let body = "dedup_token shared across two sources";
+    let first = temp.path().join("dedup_first");
+    fs::create_dir_all(&first).expect("mkdir first");
+    fs::write(first.join("note.md"), body).expect("write first");
+    let mut request = AddResourceRequest::new(first.to_str().expect("first str").to_string());
+    request.target = Some("axiom://resources/dedup-a".to_string());
+    request.wait = true;
+    request.ingest_options.dedup_by_content = true;
+    let first_result = app
+        .add_resource_with_ingest_options(request)
+        .expect("add first");
+    let first_report = first_result.dedup.expect("dedup report");
+    assert_eq!(first_report.kept_files, 1);
+    assert!(first_report.skipped.is_empty());
+
+    let second = temp.path().join("dedup_second");
+    fs::create_dir_all(&second).expect("mkdir second");
+    fs::write(second.join("copy.md"), body).expect("write second");
+    fs::write(second.join("copy-again.md"), body).expect("write second copy");
+    let mut request = AddResourceRequest::new(second.to_str().expect("second str").to_string());
+    request.target = Some("axiom://resources/dedup-b".to_string());
+    request.wait = true;
+    request.ingest_options.dedup_by_content = true;
+    let second_result = app
+        .add_resource_with_ingest_options(request)
+        .expect("add second");
+    assert!(!second_result.queued);
+    let second_report = second_result.dedup.expect("dedup report");
+    assert_eq!(second_report.kept_files, 0);
+    assert_eq!(second_report.skipped.len(), 2);
+    assert!(
+        second_report
+            .skipped
+            .iter()
+            .all(|dup| dup.existing_uri == "axiom://resources/dedup-a/note.md")
+    );
+
+    let hash = blake3::hash(body.as_bytes()).to_hex().to_string();
+    let indexed = app
+        .state
+        .list_index_state_uris_by_hash(&hash)
+        .expect("list by hash");
+    assert_eq!(
+        indexed,
+        vec!["axiom://resources/dedup-a/note.md".to_string()]
+    );
+
+    let found = app
+        .find(
+            "dedup_token",
+            Some("axiom://resources"),
+            Some(10),
+            None,
+            None,
+        )
+        .expect("find");
+    assert!(
+        !found
+            .query_results
+            .iter()
+            .any(|hit| hit.uri.starts_with("axiom://resources/dedup-b"))
+    );
+}
+
+#[test]
+fn add_resource_dedup_by_content_links_duplicates_and_forgets_removed_canonical() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let body = "linked_dedup_token shared across exports";
+    let first = temp.path().join("export_a");
+    fs::create_dir_all(&first).expect("mkdir first");
+    fs::write(first.join("note.md"), body).expect("write first");
+    let mut request = AddResourceRequest::new(first.to_str().expect("first str").to_string());
+    request.target = Some("axiom://resources/exports/a".to_string());
+    request.wait = true;
+    request.ingest_options.dedup_by_content = true;
+    let first_result = app
+        .add_resource_with_ingest_options(request)
+        .expect("add first");
+    assert_eq!(first_result.duplicates_skipped, 0);
+    assert_eq!(first_result.duplicates_linked, 0);
+
+    let second = temp.path().join("export_b");
+    fs::create_dir_all(&second).expect("mkdir second");
+    fs::write(second.join("copy.md"), body).expect("write copy");
+    fs::write(second.join("other.md"), "unrelated content").expect("write other");
+    let mut request = AddResourceRequest::new(second.to_str().expect("second str").to_string());
+    request.target = Some("axiom://resources/exports/b".to_string());
+    request.wait = true;
+    request.ingest_options.dedup_by_content = true;
+    request.ingest_options.dedup_action = DedupAction::Link;
+    let second_result = app
+        .add_resource_with_ingest_options(request.clone())
+        .expect("add second");
+    assert_eq!(second_result.duplicates_skipped, 0);
+    assert_eq!(second_result.duplicates_linked, 1);
+    let report = second_result.dedup.expect("dedup report");
+    assert_eq!(report.kept_files, 1);
+    let relation_id = report.skipped[0].relation_id.clone().expect("relation id");
+    assert!(relation_id.starts_with("duplicate-of:"));
+
+    let relations = app
+        .relations("axiom://resources/exports")
+        .expect("relations");
+    let link = relations
+        .iter()
+        .find(|link| link.id == relation_id)
+        .expect("duplicate-of link");
+    assert!(
+        link.uris
+            .contains(&"axiom://resources/exports/b/copy.md".to_string())
+    );
+    assert!(
+        link.uris
+            .contains(&"axiom://resources/exports/a/note.md".to_string())
+    );
+    assert!(app.read("axiom://resources/exports/b/copy.md").is_err());
+
+    let reconcile = ReconcileOptions {
+        dry_run: false,
+        scopes: Some(vec![Scope::Resources]),
+        check_relations: true,
+        ..ReconcileOptions::default()
+    };
+    let report = app
+        .reconcile_state_with_options(&reconcile)
+        .expect("reconcile relations");
+    assert_eq!(report.dangling_relations_pruned, 0);
+    assert!(
+        app.relations("axiom://resources/exports")
+            .expect("relations after reconcile")
+            .iter()
+            .any(|link| link.id == relation_id),
+        "dedup link must survive reconcile"
+    );
+
+    app.rm("axiom://resources/exports/a", true)
+        .expect("rm canonical");
+    let hash = blake3::hash(body.as_bytes()).to_hex().to_string();
+    assert!(
+        app.state
+            .list_index_state_uris_by_hash(&hash)
+            .expect("list by hash")
+            .is_empty()
+    );
+
+    let third_result = app
+        .add_resource_with_ingest_options(request)
+        .expect("add second again");
+    assert_eq!(third_result.duplicates_skipped, 0);
+    assert_eq!(third_result.duplicates_linked, 0);
+    assert_eq!(third_result.dedup.expect("dedup report").kept_files, 2);
+    assert!(app.read("axiom://resources/exports/b/copy.md").is_ok());
+}
+
+#[test]
+fn forced_session_archive_import_of_tampered_archive_keeps_existing_session() {
+    use std::io::{Read, Write};
+
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let session = app.session(Some("s-tamper"));
+    session.load().expect("load");
+    session
+        .add_message("user", "original tamper message")
+        .expect("add message");
+    let archive_path = temp.path().join("s-tamper.ovpack");
+    let written = app
+        .export_session("s-tamper", archive_path.to_str().expect("path str"))
+        .expect("export session");
+
+    let tampered = temp.path().join("s-tamper-tampered.ovpack");
+    let mut source = zip::ZipArchive::new(fs::File::open(&written).expect("open")).expect("zip");
+    let mut writer = zip::ZipWriter::new(fs::File::create(&tampered).expect("create"));
+    let options = zip::write::SimpleFileOptions::default();
+    for i in 0..source.len() {
+        let mut entry = source.by_index(i).expect("entry");
+        let name = entry.name().to_string();
+        if entry.is_dir() {
+            writer.add_directory(name, options).expect("dir");
+            continue;
+        }
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes).expect("read");
+        if name.ends_with("messages.jsonl") {
+            bytes.extend_from_slice(b"{\"tampered\":true}\n");
+        }
+        writer.start_file(name, options).expect("start");
+        writer.write_all(&bytes).expect("write");
+    }
+    writer.finish().expect("finish");
+
+    session
+        .add_message("assistant", "message added after export")
+        .expect("add later message");
+    let before = session.show().expect("show before");
+
+    let err = app
+        .import_session(tampered.to_str().expect("tampered str"), None, true)
+        .expect_err("tampered archive must fail verification");
+    assert!(matches!(err, AxiomError::Validation(_)), "{err:?}");
+
+    let after = session.show().expect("show after");
+    assert_eq!(after.messages.len(), before.messages.len());
+    assert_eq!(after.messages.len(), 2);
+    let temp_root = app.fs.resolve_uri(&AxiomUri::root(Scope::Temp));
+    let leftovers = fs::read_dir(&temp_root)
+        .map(|entries| {
+            entries
+                .filter_map(std::result::Result::ok)
+                .filter(|entry| {
+                    entry
+                        .file_name()
+                        .to_string_lossy()
+                        .starts_with("session-import-")
+                })
+                .count()
+        })
+        .unwrap_or(0);
+    assert_eq!(leftovers, 0, "staging directory must be cleaned up");
+
+    let info = app
+        .import_session(&written, None, true)
+        .expect("forced import of valid archive");
+    assert_eq!(info.session_id, "s-tamper");
+    assert_eq!(session.show().expect("show replaced").messages.len(), 1);
+}
+
+#[derive(Default)]
+struct StubRemoteFetcher {
+    fetched: std::sync::Mutex<Vec<String>>,
+}
+
+impl crate::remote::RemoteFetcher for std::sync::Arc<StubRemoteFetcher> {
+    fn fetch(
+        &self,
+        url: &reqwest::Url,
+        limits: &crate::remote::RemoteFetchLimits,
+    ) -> crate::error::Result<String> {
+        assert_eq!(limits.timeout, std::time::Duration::from_secs(30));
+        self.fetched
+            .lock()
+            .expect("fetched lock")
+            .push(url.to_string());
+        if url.path() == "/huge" {
+            return Ok("x".repeat(limits.max_bytes + 1));
+        }
+        Ok(format!("remote_fetch_token guide from {url}"))
+    }
+}
+
+#[test]
+fn remote_source_is_fetched_through_allowlist_and_searchable() {
+    let temp = tempdir().expect("tempdir");
+    let fetcher = std::sync::Arc::new(StubRemoteFetcher::default());
+    let mut app = AxiomSync::new(temp.path())
+        .expect("app new")
+        .with_remote_fetcher(Box::new(fetcher.clone()));
+    app.initialize().expect("init failed");
+
+    let url = "https://docs.example.com/guide";
+    let err = app
+        .add_resource(
+            url,
+            Some("axiom://resources/remote"),
+            None,
+            None,
+            true,
+            None,
+        )
+        .expect_err("remote sources are disabled by default");
+    assert!(matches!(err, AxiomError::PermissionDenied(_)));
+
+    let mut config = (*app.config).clone();
+    config.remote.allowed_hosts = vec!["docs.example.com".to_string()];
+    config.remote.max_bytes = 1024;
+    app.config = std::sync::Arc::new(config);
+
+    let err = app
+        .add_resource(
+            "https://evil.example.com/guide",
+            Some("axiom://resources/remote"),
+            None,
+            None,
+            true,
+            None,
+        )
+        .expect_err("host outside allowlist");
+    assert!(matches!(err, AxiomError::PermissionDenied(_)));
+    let err = app
+        .add_resource(
+            "https://docs.example.com/huge",
+            Some("axiom://resources/remote"),
+            None,
+            None,
+            true,
+            None,
+        )
+        .expect_err("oversized body");
+    assert!(matches!(err, AxiomError::Validation(_)));
+
+    app.add_resource(
+        url,
+        Some("axiom://resources/remote"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add remote");
+    assert_eq!(
+        *fetcher.fetched.lock().expect("fetched lock"),
+        vec!["https://docs.example.com/huge".to_string(), url.to_string()]
+    );
+
+    let found = app
+        .find(
+            "remote_fetch_token",
+            Some("axiom://resources/remote"),
+            Some(5),
+            None,
+            Some(MetadataFilter {
+                fields: HashMap::from([(
+                    "tags".to_string(),
+                    serde_json::json!([format!("source_url:{url}")]),
+                )]),
+            }),
+        )
+        .expect("find");
+    assert_eq!(
+        found.query_results.first().map(|hit| hit.uri.as_str()),
+        Some("axiom://resources/remote/source.txt")
+    );
+    assert!(
+        app.list_tags("axiom://resources/remote/source.txt")
+            .expect("list tags")
+            .contains(&format!("source_url:{url}"))
+    );
+}
+
+#[test]
+fn front_matter_tags_title_and_scalars_are_indexed_and_filterable() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let notes = temp.path().join("fm_notes");
+    fs::create_dir_all(&notes).expect("mkdir notes");
+    fs::write(
+        notes.join("draft.md"),
+        "---\ntitle: Key Rotation Draft\ntags: [security]\nstatus: draft\nreview:\n  owner: kim\n---\nfm_rotation_token body\n",
+    )
+    .expect("write draft");
+    fs::write(
+        notes.join("final.md"),
+        "---\ntitle: Key Rotation Final\nstatus: final\n---\nfm_rotation_token body\n",
+    )
+    .expect("write final");
+    fs::write(
+        notes.join("broken.md"),
+        "---\nstatus: [unclosed\n---\nfm_rotation_token broken body\n",
+    )
+    .expect("write broken");
+    app.add_resource(
+        notes.to_str().expect("notes str"),
+        Some("axiom://resources/fm-notes"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add notes");
+
+    let abstract_text = app
+        .index
+        .read()
+        .expect("index read")
+        .get("axiom://resources/fm-notes/draft.md")
+        .map(|record| record.abstract_text.clone());
+    assert_eq!(abstract_text.as_deref(), Some("Key Rotation Draft"));
+
+    let find_uris = |fields: Vec<(&str, serde_json::Value)>| {
+        let filter = MetadataFilter {
+            fields: fields
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        };
+        let mut uris = app
+            .find(
+                "fm_rotation_token",
+                Some("axiom://resources/fm-notes"),
+                Some(10),
+                None,
+                Some(filter),
+            )
+            .expect("find")
+            .query_results
+            .into_iter()
+            .map(|hit| hit.uri)
+            .filter(|uri| uri.ends_with(".md"))
+            .collect::<Vec<_>>();
+        uris.sort_unstable();
+        uris
+    };
+    let draft_uri = vec!["axiom://resources/fm-notes/draft.md".to_string()];
+    assert_eq!(
+        find_uris(vec![("fm.status", serde_json::json!("draft"))]),
+        draft_uri
+    );
+    assert_eq!(
+        find_uris(vec![("fm.review.owner", serde_json::json!("kim"))]),
+        draft_uri
+    );
+    assert_eq!(
+        find_uris(vec![("tags", serde_json::json!(["security"]))]),
+        draft_uri
+    );
+    assert_eq!(
+        find_uris(vec![("fm.status", serde_json::json!({"not": "draft"}))]),
+        vec![
+            "axiom://resources/fm-notes/broken.md".to_string(),
+            "axiom://resources/fm-notes/final.md".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn add_resource_tags_make_content_filterable_without_front_matter() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let tagged = temp.path().join("tagged");
+    fs::create_dir_all(tagged.join("nested")).expect("mkdir tagged");
+    fs::write(tagged.join("login.md"), "token_rotation policy for login").expect("write");
+    fs::write(
+        tagged.join("nested/refresh.md"),
+        "token_rotation on refresh",
+    )
+    .expect("write");
+    let plain = temp.path().join("plain");
+    fs::create_dir_all(&plain).expect("mkdir plain");
+    fs::write(plain.join("other.md"), "token_rotation for other systems").expect("write");
+
+    let mut request = AddResourceRequest::new(tagged.to_str().expect("tagged str"));
+    request.target = Some("axiom://resources/tagged".to_string());
+    request.wait = true;
+    request.tags = vec!["Auth".to_string(), "oauth".to_string()];
+    app.add_resource_with_ingest_options(request)
+        .expect("add tagged");
+    let mut request = AddResourceRequest::new(plain.to_str().expect("plain str"));
+    request.target = Some("axiom://resources/plain".to_string());
+    request.wait = true;
+    app.add_resource_with_ingest_options(request)
+        .expect("add plain");
+
+    let filter = MetadataFilter {
+        fields: HashMap::from([("tags".to_string(), serde_json::json!(["auth"]))]),
+    };
+    let found = app
+        .find(
+            "token_rotation",
+            Some("axiom://resources"),
+            Some(10),
+            None,
+            Some(filter),
+        )
+        .expect("find");
+    let mut leaf_uris = found
+        .query_results
+        .iter()
+        .map(|hit| hit.uri.as_str())
+        .filter(|uri| uri.ends_with(".md"))
+        .collect::<Vec<_>>();
+    leaf_uris.sort_unstable();
+    assert_eq!(
+        leaf_uris,
+        vec![
+            "axiom://resources/tagged/login.md",
+            "axiom://resources/tagged/nested/refresh.md",
+        ]
+    );
+    assert!(
+        !fs::read_to_string(temp.path().join("resources/tagged/login.md"))
+            .expect("read login")
+            .contains("auth")
+    );
+
+    let mut invalid = AddResourceRequest::new(plain.to_str().expect("plain str"));
+    invalid.tags = vec!["parser:markdown".to_string()];
+    let err = app
+        .add_resource_with_ingest_options(invalid)
+        .expect_err("system-style tag must be rejected");
+    assert!(matches!(err, AxiomError::Validation(_)));
+}
+
+#[test]
+fn mv_carries_tag_rows_and_rm_glob_forgets_them() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let corpus = temp.path().join("tag_moves");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    fs::write(corpus.join("a.md"), "token_rotation moved doc").expect("write a");
+    fs::write(corpus.join("b.tmp.md"), "token_rotation scratch doc").expect("write b");
+    let mut request = AddResourceRequest::new(corpus.to_str().expect("corpus str"));
+    request.target = Some("axiom://resources/tag-src".to_string());
+    request.wait = true;
+    request.tags = vec!["moved".to_string()];
+    app.add_resource_with_ingest_options(request)
+        .expect("add corpus");
+    app.add_tags("axiom://resources/tag-src/a.md", &["curated".to_string()])
+        .expect("add tags");
+    app.add_tags(
+        "axiom://resources/tag-src/b.tmp.md",
+        &["scratch".to_string()],
+    )
+    .expect("add tags");
+
+    app.mv("axiom://resources/tag-src", "axiom://resources/tag-dst")
+        .expect("mv");
+    let tags = app
+        .list_tags("axiom://resources/tag-dst/a.md")
+        .expect("list moved");
+    assert!(tags.contains(&"moved".to_string()));
+    assert!(tags.contains(&"curated".to_string()));
+    assert!(
+        app.state
+            .resource_tags_for_uri("axiom://resources/tag-src/a.md")
+            .expect("old resource tags")
+            .is_empty()
+    );
+    assert!(
+        app.state
+            .document_tag_overrides("axiom://resources/tag-src/a.md")
+            .expect("old overrides")
+            .added
+            .is_empty()
+    );
+
+    app.rm_glob("*.tmp.md", Some("axiom://resources/tag-dst"), false, false)
+        .expect("rm glob");
+    assert!(
+        app.state
+            .document_tag_overrides("axiom://resources/tag-dst/b.tmp.md")
+            .expect("removed overrides")
+            .added
+            .is_empty()
+    );
+    assert!(
+        app.state
+            .document_tag_overrides("axiom://resources/tag-dst/a.md")
+            .expect("kept overrides")
+            .added
+            .contains(&"curated".to_string())
+    );
+}
+
+#[test]
+fn document_tags_apply_to_filters_immediately_and_survive_reindex() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let corpus = temp.path().join("curated");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    fs::write(corpus.join("a.md"), "token_rotation for curated docs").expect("write a");
+    fs::write(corpus.join("b.md"), "token_rotation for other docs").expect("write b");
+    let mut request = AddResourceRequest::new(corpus.to_str().expect("corpus str"));
+    request.target = Some("axiom://resources/curated".to_string());
+    request.wait = true;
+    app.add_resource_with_ingest_options(request)
+        .expect("add corpus");
+    let doc = "axiom://resources/curated/a.md";
+    assert!(
+        app.list_tags(doc)
+            .expect("list")
+            .contains(&"markdown".to_string())
+    );
+
+    let tags = app
+        .add_tags(
+            doc,
+            &[
+                "  Curated ".to_string(),
+                "curated".to_string(),
+                "Review".to_string(),
+            ],
+        )
+        .expect("add tags");
+    assert!(tags.contains(&"curated".to_string()));
+    assert!(tags.contains(&"review".to_string()));
+    assert_eq!(tags.iter().filter(|tag| *tag == "curated").count(), 1);
+
+    let curated_uris = |app: &AxiomSync| {
+        let filter = MetadataFilter {
+            fields: HashMap::from([("tags".to_string(), serde_json::json!(["curated"]))]),
+        };
+        app.find(
+            "token_rotation",
+            Some("axiom://resources/curated"),
+            Some(10),
+            None,
+            Some(filter),
+        )
+        .expect("find")
+        .query_results
+        .into_iter()
+        .map(|hit| hit.uri)
+        .filter(|uri| uri.ends_with(".md"))
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(curated_uris(&app), vec![doc.to_string()]);
+
+    let tags = app
+        .remove_tags(doc, &["markdown".to_string(), "review".to_string()])
+        .expect("remove tags");
+    assert!(tags.contains(&"curated".to_string()));
+    assert!(!tags.contains(&"markdown".to_string()));
+    assert!(!tags.contains(&"review".to_string()));
+
+    app.reindex_scopes(&[Scope::Resources]).expect("reindex");
+    assert_eq!(app.list_tags(doc).expect("list after reindex"), tags);
+    drop(app);
+    let app = AxiomSync::new(temp.path()).expect("app reopen");
+    app.initialize().expect("init reopen");
+    assert_eq!(app.list_tags(doc).expect("list after reopen"), tags);
+    assert_eq!(curated_uris(&app), vec![doc.to_string()]);
+
+    for invalid in [
+        vec![],
+        vec!["parser:markdown".to_string()],
+        vec!["x".repeat(65)],
+    ] {
+        let err = app.add_tags(doc, &invalid).expect_err("invalid tags");
+        assert!(matches!(err, AxiomError::Validation(_)));
+    }
+    let err = app
+        .add_tags("axiom://resources/curated", &["dir".to_string()])
+        .expect_err("directory target");
+    assert!(matches!(err, AxiomError::Validation(_)));
+    let err = app
+        .list_tags("axiom://resources/curated/missing.md")
+        .expect_err("missing target");
+    assert!(matches!(err, AxiomError::NotFound(_)));
+
+    for operation in ["tags.add", "tags.remove"] {
+        let logs = app
+            .list_request_logs_filtered(20, Some(operation), Some("ok"))
+            .expect("logs");
+        assert!(
+            logs.iter()
+                .any(|entry| entry.target_uri.as_deref() == Some(doc))
+        );
+    }
+}
+
 #[test]
 fn document_editor_json_load_save_updates_search_index() {
     let temp = tempdir().expect("tempdir");
@@ -1073,6 +2631,66 @@ fn markdown_editor_load_latency_under_unrelated_save_pressure_reference() {
     );
 }
 
+#[test]
+fn shared_app_reference_serves_concurrent_ls_load_and_save() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<AxiomSync>();
+
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+    let corpus_dir = temp.path().join("shared_handle_corpus");
+    fs::create_dir_all(&corpus_dir).expect("mkdir corpus");
+    fs::write(corpus_dir.join("a.md"), "# A\n\nseed a").expect("write a");
+    fs::write(corpus_dir.join("b.md"), "# B\n\nseed b").expect("write b");
+    app.add_resource(
+        corpus_dir.to_str().expect("corpus str"),
+        Some("axiom://resources/shared-handle"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add failed");
+
+    let shared = &app;
+    std::thread::scope(|scope| {
+        let handles = (0..2)
+            .map(|worker| {
+                scope.spawn(move || {
+                    for idx in 0..20 {
+                        let entries = shared
+                            .ls("axiom://resources/shared-handle", false, false)
+                            .expect("concurrent ls");
+                        assert!(entries.len() >= 2);
+                        let uri = if worker == 0 {
+                            "axiom://resources/shared-handle/a.md"
+                        } else {
+                            "axiom://resources/shared-handle/b.md"
+                        };
+                        let doc = shared.load_markdown(uri).expect("concurrent load");
+                        shared
+                            .save_markdown(
+                                uri,
+                                &format!("# Worker {worker}\n\niteration {idx}"),
+                                Some(&doc.etag),
+                            )
+                            .expect("concurrent save");
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().expect("worker join");
+        }
+    });
+
+    let a = app
+        .load_markdown("axiom://resources/shared-handle/a.md")
+        .expect("load a");
+    assert_eq!(a.content, "# Worker 0\n\niteration 19");
+}
+
 fn percentile_p95(values: &[u128]) -> u128 {
     if values.is_empty() {
         return 0;
@@ -9,6 +9,7 @@ fn bootstrap_initializes_filesystem_without_runtime_index() {
     app.bootstrap().expect("bootstrap");
     assert!(temp.path().join("resources").exists());
     assert!(temp.path().join("queue").exists());
+    assert!(temp.path().join("trash").is_dir());
     assert!(temp.path().join("context.db").exists());
     assert!(!temp.path().join(".axiomsync_state.sqlite3").exists());
     assert!(!temp.path().join(".axiomme_state.sqlite3").exists());
@@ -55,3 +56,282 @@ fn deleting_root_and_reinitializing_recreates_runtime_state() {
     assert!(!root.join(".axiomsync_state.sqlite3").exists());
     assert!(!root.join(".axiomme_state.sqlite3").exists());
 }
+
+#[test]
+fn vector_persistence_restart_reuses_unchanged_document_vectors() {
+    let temp = tempdir().expect("tempdir");
+    let doc_uri = AxiomUri::parse("axiom://resources/docs/vectors.md").expect("parse");
+
+    let app = with_vector_persistence(AxiomSync::new(temp.path()).expect("app new"));
+    app.bootstrap().expect("bootstrap");
+    app.fs
+        .write(&doc_uri, "# Vectors\n\npersisted embedding reuse", true)
+        .expect("write doc");
+    app.initialize().expect("init");
+    let first_embedded = app
+        .index
+        .read()
+        .expect("index read")
+        .embedded_vector_count();
+    assert!(first_embedded > 0, "first start must embed documents");
+    drop(app);
+
+    let restarted = with_vector_persistence(AxiomSync::new(temp.path()).expect("app restart"));
+    restarted.initialize().expect("restart init");
+    let index = restarted.index.read().expect("index read");
+    assert!(index.get(&doc_uri.to_string()).is_some());
+    assert!(index.vector(&doc_uri.to_string()).is_some());
+    assert_eq!(
+        index.embedded_vector_count(),
+        0,
+        "unchanged documents must not be re-embedded on restart"
+    );
+}
+
+#[test]
+fn restart_without_vector_persistence_re_embeds_documents() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init");
+    drop(app);
+
+    let restarted = AxiomSync::new(temp.path()).expect("app restart");
+    restarted.initialize().expect("restart init");
+    assert!(
+        restarted
+            .index
+            .read()
+            .expect("index read")
+            .embedded_vector_count()
+            > 0
+    );
+}
+
+#[test]
+fn embedding_cache_skips_embedder_for_unchanged_chunks_on_re_add() {
+    let temp = tempdir().expect("tempdir");
+    let app = with_embedding_cache(AxiomSync::new(temp.path()).expect("app new"));
+    app.initialize().expect("init");
+
+    let corpus = temp.path().join("cache_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    for (name, body) in [
+        ("auth.md", "# Auth\n\noauth token refresh flow"),
+        ("queue.md", "# Queue\n\nreplay dead letter events"),
+        ("notes.txt", "plain notes about embedding cache reuse"),
+    ] {
+        fs::write(corpus.join(name), body).expect("write corpus file");
+    }
+    let add = |target: &str| {
+        app.add_resource(
+            corpus.to_str().expect("corpus str"),
+            Some(target),
+            None,
+            None,
+            true,
+            None,
+        )
+        .expect("add");
+    };
+    let embedded = || {
+        app.index
+            .read()
+            .expect("index read")
+            .embedded_vector_count()
+    };
+
+    add("axiom://resources/cache-a");
+    let first = app.backend_status().expect("status").embedding_cache;
+    assert!(first.enabled);
+    assert!(first.entries > 0);
+    let embedded_before = embedded();
+
+    add("axiom://resources/cache-b");
+    let second = app.backend_status().expect("status").embedding_cache;
+    let embedded_after = embedded();
+    assert!(
+        second.hits >= first.hits + 3,
+        "unchanged files must be served from the cache: {second:?}"
+    );
+    assert_eq!(
+        embedded_after - embedded_before,
+        usize::try_from(second.misses - first.misses).expect("misses"),
+        "only cache misses may reach the embedder"
+    );
+    for name in ["auth.md", "queue.md", "notes.txt"] {
+        let uri = format!("axiom://resources/cache-b/{name}");
+        assert!(app.index.read().expect("index read").vector(&uri).is_some());
+    }
+
+    let entries_before_rm = app
+        .backend_status()
+        .expect("status")
+        .embedding_cache
+        .entries;
+    app.rm("axiom://resources/cache-a", true).expect("rm");
+    assert!(
+        app.backend_status()
+            .expect("status")
+            .embedding_cache
+            .entries
+            < entries_before_rm,
+        "removed documents drop their stored vectors"
+    );
+
+    app.state
+        .upsert_search_vector(
+            "axiom://resources/stale.md",
+            "old-provider@v0:64",
+            "stale-hash",
+            &[0.0; 64],
+        )
+        .expect("seed stale entry");
+    drop(app);
+
+    let restarted = with_embedding_cache(AxiomSync::new(temp.path()).expect("app restart"));
+    restarted.initialize().expect("restart init");
+    assert!(
+        restarted
+            .state
+            .get_cached_embedding("stale-hash", "old-provider@v0:64")
+            .expect("lookup")
+            .is_none(),
+        "entries from other vector versions are pruned"
+    );
+    let status = restarted.backend_status().expect("status").embedding_cache;
+    assert!(status.hits > 0);
+    assert_eq!(
+        restarted
+            .index
+            .read()
+            .expect("index read")
+            .embedded_vector_count(),
+        usize::try_from(status.misses).expect("misses")
+    );
+    assert_eq!(
+        restarted.clear_embedding_cache().expect("clear"),
+        status.entries
+    );
+    assert_eq!(
+        restarted
+            .backend_status()
+            .expect("status")
+            .embedding_cache
+            .entries,
+        0
+    );
+}
+
+fn with_embedding_cache(mut app: AxiomSync) -> AxiomSync {
+    let mut config = (*app.config).clone();
+    config.indexing.embedding_cache = true;
+    app.config = std::sync::Arc::new(config);
+    app
+}
+
+fn with_vector_persistence(mut app: AxiomSync) -> AxiomSync {
+    let mut config = (*app.config).clone();
+    config.indexing.vector_persistence = true;
+    app.config = std::sync::Arc::new(config);
+    app
+}
+
+#[test]
+fn workspace_fans_search_across_roots_and_isolates_broken_root() {
+    let temp = tempdir().expect("tempdir");
+    let broken = temp.path().join("broken-root");
+    fs::write(&broken, "not a directory").expect("write broken root");
+    let config_path = temp.path().join("workspace.toml");
+    fs::write(
+        &config_path,
+        format!(
+            "[roots]\npersonal = \"personal\"\nwork = \"work\"\nbroken = \"{}\"\n",
+            broken.display()
+        ),
+    )
+    .expect("write config");
+
+    let roots = crate::AxiomSyncWorkspace::roots_from_config_file(&config_path).expect("config");
+    assert_eq!(
+        roots,
+        vec![
+            ("broken".to_string(), broken.clone()),
+            ("personal".to_string(), temp.path().join("personal")),
+            ("work".to_string(), temp.path().join("work")),
+        ]
+    );
+    let workspace =
+        crate::AxiomSyncWorkspace::from_config_file(&config_path).expect("open workspace");
+    assert_eq!(
+        workspace.names().collect::<Vec<_>>(),
+        vec!["personal", "work"]
+    );
+    assert_eq!(
+        workspace.failures().keys().collect::<Vec<_>>(),
+        vec!["broken"]
+    );
+    assert!(workspace.get("broken").is_none());
+
+    for (name, body) in [
+        ("personal", "# Garden\n\nworkspace fanout tomato notes"),
+        ("work", "# Standup\n\nworkspace fanout sprint notes"),
+    ] {
+        let app = workspace.get(name).expect("root");
+        app.fs
+            .write(
+                &AxiomUri::parse(&format!("axiom://resources/notes/{name}.md")).expect("uri"),
+                body,
+                true,
+            )
+            .expect("write doc");
+        app.reindex_all().expect("reindex");
+    }
+
+    let request = crate::models::SearchRequest {
+        query: "workspace fanout".to_string(),
+        target_uri: None,
+        target_uris: Vec::new(),
+        session: None,
+        limit: Some(5),
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        budget: None,
+        runtime_hints: Vec::new(),
+        exclude_scopes: Vec::new(),
+        scope_set: crate::models::SearchScopeSet::AllContent,
+        relation_hops: None,
+        snippet_highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+    };
+    let items = workspace.search_all(&request);
+    assert_eq!(
+        items
+            .iter()
+            .map(|item| item.root.as_str())
+            .collect::<Vec<_>>(),
+        vec!["personal", "work"]
+    );
+    for item in &items {
+        let result = item.result.as_ref().expect("root search");
+        let expected = format!("axiom://resources/notes/{}.md", item.root);
+        assert!(
+            result.query_results.iter().any(|hit| hit.uri == expected),
+            "{}: {:?}",
+            item.root,
+            result.query_results
+        );
+    }
+
+    let duplicate = crate::AxiomSyncWorkspace::open([
+        ("same", temp.path().join("a")),
+        ("same", temp.path().join("b")),
+    ]);
+    assert!(matches!(duplicate, Err(AxiomError::Validation(_))));
+    let bad_name = crate::AxiomSyncWorkspace::open([("Work Root", temp.path().join("c"))]);
+    assert!(matches!(bad_name, Err(AxiomError::Validation(_))));
+    let empty = crate::AxiomSyncWorkspace::open(Vec::<(String, PathBuf)>::new());
+    assert!(matches!(empty, Err(AxiomError::Validation(_))));
+}
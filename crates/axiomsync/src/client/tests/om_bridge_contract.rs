@@ -640,3 +640,252 @@ fn om_bridge_replay_om_only_clears_reflection_flags_after_dead_lettered_reflect_
     assert!(!record_after.is_reflecting);
     assert!(!record_after.is_buffering_reflection);
 }
+
+#[test]
+fn om_metrics_reports_trigger_counters_after_session_commit() {
+    let temp = tempdir().expect("tempdir");
+    let mut app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+    let session_id = "s-om-metrics";
+    assert!(app.om_metrics(session_id).expect("metrics").is_none());
+
+    let session = app.session(Some(session_id));
+    session.load().expect("load");
+    for text in ["x".repeat(26_000), "y".repeat(26_000)] {
+        session.add_message("user", text).expect("append");
+    }
+    session.commit().expect("commit");
+    app.replay_outbox(50, false).expect("replay");
+
+    let metrics = app
+        .om_metrics(session_id)
+        .expect("metrics")
+        .expect("om record");
+    assert_eq!(metrics.scope_key, format!("session:{session_id}"));
+    assert_eq!(metrics.observer_trigger_count_total, 2);
+    let state = app
+        .om_bridge_read_hint_state(crate::om_bridge::OmHintReadRequestV1 {
+            session_id: session_id.to_string(),
+            scope_binding: None,
+        })
+        .expect("read hint")
+        .expect("hint state");
+    assert_eq!(
+        metrics.reflector_trigger_count_total,
+        state.reflector_trigger_count_total
+    );
+    assert_eq!(
+        metrics.observation_tokens_active,
+        state.observation_tokens_active
+    );
+    assert_eq!(
+        metrics.last_activated_message_ids,
+        state.activated_message_ids
+    );
+
+    let mut config = (*app.config).clone();
+    config.om.enabled = false;
+    app.config = std::sync::Arc::new(config);
+    assert!(app.om_metrics(session_id).expect("disabled").is_none());
+}
+
+#[test]
+fn om_reset_clears_observations_and_stales_in_flight_reflection() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+    let session_id = "s-om-reset";
+    assert!(app.om_state(session_id).expect("state").is_none());
+    assert!(!app.om_reset(session_id, false).expect("reset missing"));
+
+    for text in ["x".repeat(26_000), "y".repeat(26_000)] {
+        app.om_bridge_append_message(crate::om_bridge::OmMessageAppendRequestV1 {
+            session_id: session_id.to_string(),
+            role: "user".to_string(),
+            text,
+            scope_binding: None,
+        })
+        .expect("append");
+    }
+    app.replay_outbox(50, false).expect("replay");
+    let before = app.om_state(session_id).expect("state").expect("om record");
+    assert_eq!(before.observer_trigger_count_total, 2);
+    assert!(!before.buffered_chunks.is_empty());
+
+    assert!(app.om_reset(session_id, true).expect("reset keep buffered"));
+    let kept = app.om_state(session_id).expect("state").expect("om record");
+    assert_eq!(kept.generation_count, before.generation_count + 1);
+    assert!(kept.active_observations.is_empty());
+    assert_eq!(kept.observation_token_count, 0);
+    assert_eq!(kept.observer_trigger_count_total, 0);
+    assert_eq!(kept.reflector_trigger_count_total, 0);
+    assert!(kept.last_activated_message_ids.is_empty());
+    assert_eq!(kept.buffered_chunks, before.buffered_chunks);
+    let metrics = app
+        .om_metrics(session_id)
+        .expect("metrics")
+        .expect("metrics record");
+    assert_eq!(metrics.observer_trigger_count_total, 0);
+
+    let outcome = app
+        .state
+        .apply_om_reflection_with_cas(
+            &before.scope_key,
+            before.generation_count,
+            9_001,
+            "stale reflection",
+            &[],
+            crate::state::OmReflectionApplyContext::default(),
+        )
+        .expect("apply");
+    assert_eq!(
+        outcome,
+        crate::state::OmReflectionApplyOutcome::StaleGeneration
+    );
+
+    assert!(app.om_reset(session_id, false).expect("reset all"));
+    let cleared = app.om_state(session_id).expect("state").expect("om record");
+    assert!(cleared.buffered_chunks.is_empty());
+    assert_eq!(cleared.generation_count, before.generation_count + 2);
+}
+
+#[test]
+fn force_om_reflection_counts_trigger_and_reports_stale_generation() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+    let session_id = "s-om-force-reflect";
+    assert!(matches!(
+        app.force_om_reflection(session_id),
+        Err(AxiomError::NotFound(_))
+    ));
+
+    for text in ["x".repeat(26_000), "y".repeat(26_000)] {
+        app.om_bridge_append_message(crate::om_bridge::OmMessageAppendRequestV1 {
+            session_id: session_id.to_string(),
+            role: "user".to_string(),
+            text,
+            scope_binding: None,
+        })
+        .expect("append");
+    }
+    app.replay_outbox(50, false).expect("replay");
+    let before = app.om_state(session_id).expect("state").expect("om record");
+
+    let forced = app.force_om_reflection(session_id).expect("force");
+    assert_eq!(forced.scope_key, before.scope_key);
+    assert_eq!(
+        forced.outcome,
+        crate::state::OmReflectionApplyOutcome::Applied
+    );
+    assert_eq!(forced.generation_count, before.generation_count + 1);
+    assert_eq!(
+        forced.reflector_trigger_count_total,
+        before.reflector_trigger_count_total + 1
+    );
+    assert!(forced.apply_metrics.applied_total >= 1);
+    let metrics = app
+        .om_metrics(session_id)
+        .expect("metrics")
+        .expect("metrics record");
+    assert_eq!(
+        metrics.reflector_trigger_count_total,
+        forced.reflector_trigger_count_total
+    );
+    let event = app
+        .state
+        .get_outbox_event(forced.event_id)
+        .expect("event")
+        .expect("event exists");
+    assert_eq!(event.status, QueueEventStatus::Done);
+
+    let stale = app
+        .force_om_reflection_at_generation(&before.scope_key, before.generation_count)
+        .expect("stale force");
+    assert_eq!(
+        stale.outcome,
+        crate::state::OmReflectionApplyOutcome::StaleGeneration
+    );
+    assert_eq!(stale.generation_count, forced.generation_count);
+    assert_eq!(
+        stale.reflector_trigger_count_total,
+        forced.reflector_trigger_count_total
+    );
+}
+
+#[test]
+fn om_state_export_import_round_trips_hint_read_state() {
+    let source_dir = tempdir().expect("tempdir");
+    let source = AxiomSync::new(source_dir.path()).expect("app");
+    source.initialize().expect("init");
+    let session_id = "s-om-state-export";
+    for text in ["x".repeat(26_000), "y".repeat(26_000)] {
+        source
+            .om_bridge_append_message(crate::om_bridge::OmMessageAppendRequestV1 {
+                session_id: session_id.to_string(),
+                role: "user".to_string(),
+                text,
+                scope_binding: None,
+            })
+            .expect("append");
+    }
+    source.replay_outbox(50, false).expect("replay");
+    let read_request = crate::om_bridge::OmHintReadRequestV1 {
+        session_id: session_id.to_string(),
+        scope_binding: None,
+    };
+    let expected = source
+        .om_bridge_read_hint_state(read_request.clone())
+        .expect("read hint")
+        .expect("hint state");
+
+    let exported = source.export_om_state(session_id).expect("export");
+    assert_eq!(
+        exported.version,
+        crate::om_bridge::OM_STATE_EXPORT_VERSION_V1
+    );
+    assert_eq!(exported.scope_key, format!("session:{session_id}"));
+    assert!(!exported.observation_chunks.is_empty());
+    assert_eq!(exported.record.observer_trigger_count_total, 2);
+    let doc = serde_json::from_str::<crate::om_bridge::OmStateExportV1>(
+        &serde_json::to_string(&exported).expect("serialize"),
+    )
+    .expect("deserialize");
+
+    let target_dir = tempdir().expect("tempdir");
+    let target = AxiomSync::new(target_dir.path()).expect("app");
+    target.initialize().expect("init");
+    assert!(
+        target
+            .om_bridge_read_hint_state(read_request.clone())
+            .expect("read empty")
+            .is_none()
+    );
+    for _ in 0..2 {
+        assert_eq!(
+            target.import_om_state(&doc).expect("import"),
+            exported.scope_key
+        );
+    }
+    let imported = target
+        .om_bridge_read_hint_state(read_request)
+        .expect("read imported")
+        .expect("imported hint state");
+    assert_eq!(imported, expected);
+    assert_eq!(
+        target
+            .export_om_state(session_id)
+            .expect("re-export")
+            .entries,
+        exported.entries
+    );
+
+    let mismatched = crate::om_bridge::OmStateExportV1 {
+        version: crate::om_bridge::OM_STATE_EXPORT_VERSION_V1 + 1,
+        ..doc
+    };
+    let err = target
+        .import_om_state(&mismatched)
+        .expect_err("version mismatch");
+    assert!(matches!(err, AxiomError::Validation(_)), "{err:?}");
+}
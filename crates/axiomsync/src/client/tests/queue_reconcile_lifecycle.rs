@@ -1,5 +1,5 @@
 use super::*;
-use crate::models::QueueEventStatus;
+use crate::models::{QueueEventStatus, QueuePriority, ReplayReport, WatchOptions, WatchStats};
 
 fn om_reflect_requested_payload(scope_key: &str, expected_generation: u32) -> serde_json::Value {
     serde_json::json!({
@@ -236,6 +236,64 @@ fn replay_outbox_marks_event_done() {
     );
 }
 
+#[test]
+fn replay_outbox_processes_high_priority_event_before_older_low_priority_backlog() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let backlog = (0..3)
+        .map(|idx| {
+            app.state
+                .enqueue_with_priority(
+                    "delete",
+                    &format!("axiom://resources/backlog-{idx}"),
+                    serde_json::json!({}),
+                    QueuePriority::Low,
+                )
+                .expect("enqueue low")
+        })
+        .collect::<Vec<_>>();
+    let urgent = app
+        .state
+        .enqueue_with_priority(
+            "delete",
+            "axiom://resources/urgent",
+            serde_json::json!({}),
+            QueuePriority::High,
+        )
+        .expect("enqueue high");
+
+    let report = app.replay_outbox(1, false).expect("replay failed");
+    assert_eq!(report.fetched, 1);
+    assert_eq!(report.done, 1);
+    let status_of = |id| {
+        app.state
+            .get_outbox_event(id)
+            .expect("event lookup")
+            .expect("event missing")
+            .status
+    };
+    assert_eq!(status_of(urgent), QueueEventStatus::Done);
+    for id in &backlog {
+        assert_eq!(status_of(*id), QueueEventStatus::New);
+    }
+
+    let diagnostics = app.queue_diagnostics().expect("diagnostics");
+    let low_lane = diagnostics
+        .priority_lanes
+        .iter()
+        .find(|lane| lane.priority == QueuePriority::Low)
+        .expect("low lane");
+    assert_eq!(low_lane.new_total, 3);
+    assert!(
+        diagnostics
+            .priority_lanes
+            .iter()
+            .any(|lane| lane.priority == QueuePriority::High && lane.new_total == 0)
+    );
+}
+
 #[test]
 fn replay_outbox_recovers_stale_processing_event() {
     let temp = tempdir().expect("tempdir");
@@ -305,6 +363,233 @@ fn replay_outbox_dead_letters_unknown_event_type() {
     assert_eq!(event.attempt_count, 1);
 }
 
+#[test]
+fn queue_event_inspect_requeue_and_purge_by_id() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let event_id = app
+        .state
+        .enqueue(
+            "unknown_event_type",
+            "axiom://resources/ghost",
+            serde_json::json!({"marker": "inspect_me"}),
+        )
+        .expect("enqueue failed");
+    app.replay_outbox(10, false).expect("replay failed");
+
+    let event = app
+        .get_queue_event(event_id)
+        .expect("get event")
+        .expect("event missing");
+    assert_eq!(event.status, QueueEventStatus::DeadLetter);
+    assert_eq!(event.payload_json["marker"], "inspect_me");
+    assert!(app.get_queue_event(event_id + 1000).expect("get").is_none());
+
+    assert!(app.requeue_event(event_id).expect("requeue"));
+    let event = app
+        .get_queue_event(event_id)
+        .expect("get event")
+        .expect("event missing");
+    assert_eq!(event.status, QueueEventStatus::New);
+    assert_eq!(event.attempt_count, 0);
+    assert!(!app.requeue_event(event_id + 1000).expect("requeue missing"));
+
+    assert!(
+        app.state
+            .claim_outbox_event(event_id, QueueEventStatus::New)
+            .expect("claim")
+    );
+    let err = app.purge_event(event_id).expect_err("purge processing");
+    assert!(matches!(err, AxiomError::Conflict(_)));
+    let err = app.requeue_event(event_id).expect_err("requeue processing");
+    assert!(matches!(err, AxiomError::Conflict(_)));
+
+    app.state
+        .mark_outbox_status(event_id, QueueEventStatus::DeadLetter, false)
+        .expect("mark dead letter");
+    assert!(app.purge_event(event_id).expect("purge"));
+    assert!(app.get_queue_event(event_id).expect("get").is_none());
+    assert!(!app.purge_event(event_id).expect("purge again"));
+
+    let requeue_logs = app
+        .list_request_logs_filtered(50, Some("queue.requeue"), Some("ok"))
+        .expect("requeue logs");
+    assert!(requeue_logs.iter().any(|entry| {
+        entry.target_uri.as_deref() == Some("axiom://resources/ghost")
+            && entry
+                .details
+                .as_ref()
+                .is_some_and(|details| details["previous_status"] == "dead_letter")
+    }));
+    let purge_errors = app
+        .list_request_logs_filtered(50, Some("queue.purge"), Some("error"))
+        .expect("purge error logs");
+    assert_eq!(purge_errors.len(), 1);
+    let purge_logs = app
+        .list_request_logs_filtered(50, Some("queue.purge"), Some("ok"))
+        .expect("purge logs");
+    assert!(purge_logs.iter().any(|entry| {
+        entry
+            .details
+            .as_ref()
+            .is_some_and(|details| details["applied"] == true)
+    }));
+}
+
+#[test]
+fn replay_outbox_parallel_processes_partitioned_events_once() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let mut ids = Vec::new();
+    for index in 0..12 {
+        let uri = format!("axiom://resources/parallel-{}", index % 5);
+        ids.push(
+            app.state
+                .enqueue("delete", &uri, serde_json::json!({}))
+                .expect("enqueue failed"),
+        );
+    }
+    ids.push(
+        app.state
+            .enqueue(
+                "unknown_event_type",
+                "axiom://resources/parallel-0",
+                serde_json::json!({}),
+            )
+            .expect("enqueue unknown"),
+    );
+
+    let report = app
+        .replay_outbox_parallel(100, false, 4)
+        .expect("parallel replay failed");
+    assert_eq!(report.fetched, 13);
+    assert_eq!(report.processed, 13);
+    assert_eq!(report.done, 12);
+    assert_eq!(report.dead_letter, 1);
+    assert_eq!(report.skipped, 0);
+    assert_eq!(
+        app.state.get_checkpoint("replay").expect("checkpoint"),
+        ids.last().copied()
+    );
+
+    for id in ids {
+        let event = app
+            .state
+            .get_outbox_event(id)
+            .expect("event lookup")
+            .expect("event missing");
+        assert_eq!(event.attempt_count, 1, "event {id} must be claimed once");
+        assert_ne!(event.status, QueueEventStatus::New);
+    }
+
+    let logs = app
+        .list_request_logs_filtered(5, Some("queue.replay"), Some("ok"))
+        .expect("logs");
+    assert_eq!(logs[0].details.as_ref().expect("details")["workers"], 4);
+}
+
+#[test]
+fn replay_outbox_parallel_records_failed_event_and_keeps_replaying_its_partition() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let mut ids = Vec::new();
+    for index in 0..6 {
+        let uri = format!("axiom://resources/partial-{}", index % 3);
+        ids.push(
+            app.state
+                .enqueue("delete", &uri, serde_json::json!({}))
+                .expect("enqueue failed"),
+        );
+    }
+    let failing = ids[0];
+    let conn = rusqlite::Connection::open(temp.path().join(crate::client::STATE_DB_FILE_NAME))
+        .expect("open raw db");
+    conn.execute_batch(&format!(
+        "CREATE TRIGGER fail_done BEFORE UPDATE OF status ON outbox \
+         WHEN NEW.id = {failing} AND NEW.status = 'done' \
+         BEGIN SELECT RAISE(ABORT, 'injected done failure'); END;"
+    ))
+    .expect("create trigger");
+
+    let report = app
+        .replay_outbox_parallel(100, false, 3)
+        .expect("partial failure still returns a report");
+    assert_eq!(report.fetched, 6);
+    assert_eq!(report.processed, 6);
+    assert_eq!(report.done, 5);
+    assert_eq!(report.failed_reasons.values().sum::<usize>(), 1);
+    let status = |id: i64| {
+        app.state
+            .get_outbox_event(id)
+            .expect("event lookup")
+            .expect("event missing")
+            .status
+    };
+    assert_eq!(status(failing), QueueEventStatus::New);
+    assert_eq!(status(ids[3]), QueueEventStatus::Done);
+
+    conn.execute_batch("DROP TRIGGER fail_done;")
+        .expect("drop trigger");
+    app.state.force_outbox_due_now(failing).expect("force due");
+    let retried = app.replay_outbox_parallel(100, false, 3).expect("retry");
+    assert_eq!(retried.done, 1);
+    assert!(retried.failed_reasons.is_empty());
+    assert_eq!(status(failing), QueueEventStatus::Done);
+}
+
+#[test]
+fn replay_outbox_serial_propagates_state_error() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let failing = app
+        .state
+        .enqueue(
+            "delete",
+            "axiom://resources/serial-0",
+            serde_json::json!({}),
+        )
+        .expect("enqueue failed");
+    let next = app
+        .state
+        .enqueue(
+            "delete",
+            "axiom://resources/serial-1",
+            serde_json::json!({}),
+        )
+        .expect("enqueue failed");
+    let conn = rusqlite::Connection::open(temp.path().join(crate::client::STATE_DB_FILE_NAME))
+        .expect("open raw db");
+    conn.execute_batch(&format!(
+        "CREATE TRIGGER fail_done BEFORE UPDATE OF status ON outbox \
+         WHEN NEW.id = {failing} AND NEW.status = 'done' \
+         BEGIN SELECT RAISE(ABORT, 'injected done failure'); END;"
+    ))
+    .expect("create trigger");
+
+    app.replay_outbox(100, false)
+        .expect_err("serial replay must surface the state error");
+    let status = |id: i64| {
+        app.state
+            .get_outbox_event(id)
+            .expect("event lookup")
+            .expect("event missing")
+            .status
+    };
+    assert_eq!(status(next), QueueEventStatus::New);
+    assert_eq!(
+        app.state.get_checkpoint("replay").expect("checkpoint"),
+        None
+    );
+}
+
 #[test]
 fn add_resource_wait_false_requires_replay_for_searchability() {
     let temp = tempdir().expect("tempdir");
@@ -990,6 +1275,78 @@ fn reconcile_prunes_missing_index_state() {
     assert!(hash.is_none());
 }
 
+#[test]
+fn reconcile_recomputes_scope_quota_usage_from_disk() {
+    let temp = tempdir().expect("tempdir");
+    let mut app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+    let baseline = crate::quota::tree_size_bytes(&temp.path().join("session"));
+    let limit = baseline + 4096;
+    let quota = crate::quota::ScopeQuota::new(
+        crate::config::QuotaConfig::default().with_limit_bytes(Scope::Session, limit),
+        app.state.clone(),
+        temp.path().to_path_buf(),
+    );
+    app.fs = app.fs.clone().with_quota(std::sync::Arc::new(quota));
+
+    let uri = AxiomUri::parse("axiom://session/s-quota/notes.md").expect("uri");
+    app.fs.write(&uri, "0123456789", true).expect("write");
+    let quotas = app.backend_status().expect("status").quotas;
+    assert_eq!(quotas.len(), 1);
+    assert_eq!(quotas[0].scope, "session");
+    assert_eq!(quotas[0].usage_bytes, baseline + 10);
+    assert_eq!(quotas[0].limit_bytes, limit);
+
+    fs::write(
+        temp.path().join("session/s-quota/outside.md"),
+        "x".repeat(30),
+    )
+    .expect("out-of-band write");
+
+    let preview = app
+        .reconcile_state_with_options(&ReconcileOptions {
+            dry_run: true,
+            scopes: Some(vec![Scope::Session]),
+            ..ReconcileOptions::default()
+        })
+        .expect("dry run");
+    assert_eq!(preview.quota_usage[0].usage_bytes, baseline + 40);
+    assert_eq!(
+        app.backend_status().expect("status").quotas[0].usage_bytes,
+        baseline + 10
+    );
+
+    let report = app.reconcile_state().expect("reconcile");
+    let on_disk = crate::quota::tree_size_bytes(&temp.path().join("session"));
+    assert!(on_disk >= baseline + 40);
+    assert_eq!(report.quota_usage[0].usage_bytes, on_disk);
+    assert_eq!(
+        app.backend_status().expect("status").quotas[0].usage_bytes,
+        on_disk
+    );
+
+    let remaining = usize::try_from(limit - on_disk).expect("remaining");
+    let extra = AxiomUri::parse("axiom://session/s-quota/extra.md").expect("uri");
+    let err = app
+        .fs
+        .write(&extra, &"y".repeat(remaining + 1), true)
+        .expect_err("recomputed usage is enforced");
+    assert!(matches!(
+        err,
+        AxiomError::QuotaExceeded {
+            current_bytes,
+            requested_bytes,
+            limit_bytes,
+            ..
+        } if current_bytes == on_disk
+            && requested_bytes == remaining as u64 + 1
+            && limit_bytes == limit
+    ));
+    app.fs
+        .write(&extra, &"y".repeat(remaining), true)
+        .expect("exactly at limit");
+}
+
 #[test]
 fn replay_requeues_then_dead_letters_after_retry_budget() {
     let temp = tempdir().expect("tempdir");
@@ -1034,6 +1391,68 @@ fn replay_requeues_then_dead_letters_after_retry_budget() {
     );
 }
 
+#[test]
+fn injected_embedding_faults_dead_letter_semantic_scan_with_reason() {
+    let temp = tempdir().expect("tempdir");
+    let mut app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+    let file_uri = AxiomUri::parse("axiom://resources/fault/notes.md").expect("uri");
+    app.fs
+        .write(&file_uri, "fault injection payload", true)
+        .expect("write file");
+    app.fault_injector = std::sync::Arc::new(crate::client::FaultInjector::new(
+        crate::config::parse_fault_spec("embedding:fail_rate=1,seed=3").expect("fault spec"),
+    ));
+
+    let event_id = app
+        .state
+        .enqueue(
+            "semantic_scan",
+            &file_uri.to_string(),
+            serde_json::json!({}),
+        )
+        .expect("enqueue failed");
+    let mut total = ReplayReport::default();
+    for _ in 0..5 {
+        app.state.force_outbox_due_now(event_id).expect("force due");
+        let report = app.replay_outbox(10, false).expect("replay");
+        crate::evidence::accumulate_replay_report(&mut total, &report);
+    }
+
+    assert_eq!(total.requeued, 4);
+    assert_eq!(total.dead_letter, 1);
+    assert_eq!(
+        total.dead_letter_reasons.get("EMBEDDING_FAILED").copied(),
+        Some(1)
+    );
+    let event = app
+        .state
+        .get_outbox_event(event_id)
+        .expect("get event")
+        .expect("missing event");
+    assert_eq!(event.status, QueueEventStatus::DeadLetter);
+
+    let invalid_id = app
+        .state
+        .enqueue("semantic_scan", "invalid://uri", serde_json::json!({}))
+        .expect("enqueue invalid");
+    let mut invalid_total = ReplayReport::default();
+    for _ in 0..5 {
+        app.state
+            .force_outbox_due_now(invalid_id)
+            .expect("force due");
+        let report = app.replay_outbox(10, false).expect("replay invalid");
+        crate::evidence::accumulate_replay_report(&mut invalid_total, &report);
+    }
+    assert_eq!(
+        invalid_total
+            .dead_letter_reasons
+            .get("INVALID_URI")
+            .copied(),
+        Some(1)
+    );
+}
+
 #[test]
 fn replay_handles_om_reflection_event_with_cas_and_stale_noop() {
     let temp = tempdir().expect("tempdir");
@@ -1821,6 +2240,9 @@ fn reconcile_dry_run_preserves_index_state() {
             dry_run: true,
             scopes: Some(vec![Scope::Resources]),
             max_drift_sample: 10,
+            fix_duplicates: false,
+            check_vectors: false,
+            check_relations: false,
         })
         .expect("reconcile dry run");
     assert!(report.dry_run);
@@ -1840,3 +2262,481 @@ fn reconcile_dry_run_preserves_index_state() {
         .expect("query failed");
     assert_eq!(hash.as_deref(), Some("hash"));
 }
+
+#[test]
+fn reconcile_detects_and_fixes_normalization_colliding_index_entries() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let src = temp.path().join("dup_input.md");
+    fs::write(&src, "# Dup\n\nnormalization drift").expect("write input");
+    app.add_resource(
+        src.to_str().expect("src str"),
+        Some("axiom://resources/dup-demo"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add failed");
+    let canonical = app
+        .state
+        .list_index_state_uris()
+        .expect("list")
+        .into_iter()
+        .find(|uri| uri.starts_with("axiom://resources/dup-demo/") && uri.ends_with(".md"))
+        .expect("indexed file");
+    let drifted = canonical.replacen("dup-demo/", "dup-demo//./", 1);
+    app.state
+        .upsert_index_state(&drifted, "hash", 1, "indexed")
+        .expect("upsert drifted");
+
+    let report = app
+        .reconcile_state_with_options(&ReconcileOptions {
+            dry_run: true,
+            scopes: Some(vec![Scope::Resources]),
+            max_drift_sample: 10,
+            fix_duplicates: false,
+            check_vectors: false,
+            check_relations: false,
+        })
+        .expect("reconcile dry run");
+    assert_eq!(report.duplicate_uri_entries, 1);
+    assert_eq!(report.duplicates_merged, 0);
+    assert_eq!(
+        report.duplicate_uri_groups,
+        vec![crate::models::ReconcileDuplicateGroup {
+            canonical_uri: canonical.clone(),
+            uris: {
+                let mut uris = vec![canonical.clone(), drifted.clone()];
+                uris.sort();
+                uris
+            },
+        }]
+    );
+    assert!(report.drift_uris_sample.contains(&drifted));
+    assert!(report.drift_count >= 1);
+
+    let report = app
+        .reconcile_state_with_options(&ReconcileOptions {
+            scopes: Some(vec![Scope::Resources]),
+            fix_duplicates: true,
+            ..ReconcileOptions::default()
+        })
+        .expect("reconcile fix");
+    assert_eq!(report.duplicates_merged, 1);
+    let uris = app.state.list_index_state_uris().expect("list");
+    assert!(uris.contains(&canonical));
+    assert!(!uris.contains(&drifted));
+
+    let clean = app
+        .reconcile_state_with_options(&ReconcileOptions {
+            dry_run: true,
+            scopes: Some(vec![Scope::Resources]),
+            max_drift_sample: 10,
+            fix_duplicates: false,
+            check_vectors: false,
+            check_relations: false,
+        })
+        .expect("reconcile after fix");
+    assert_eq!(clean.duplicate_uri_entries, 0);
+    assert!(clean.duplicate_uri_groups.is_empty());
+}
+
+#[test]
+fn reconcile_checks_remove_orphan_vectors_and_prune_dangling_relations() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let owner = "axiom://resources/integrity";
+    let kept = "axiom://resources/integrity/kept.md";
+    let removed = "axiom://resources/integrity/removed.md";
+    let ghost = "axiom://resources/integrity/ghost.md";
+    for uri in [kept, removed] {
+        app.fs
+            .write(&AxiomUri::parse(uri).expect("uri"), "# Integrity", false)
+            .expect("write doc");
+    }
+    app.link(
+        owner,
+        "pair",
+        vec![kept.to_string(), removed.to_string()],
+        "pair",
+    )
+    .expect("link pair");
+    app.link(
+        owner,
+        "triple",
+        vec![kept.to_string(), removed.to_string(), owner.to_string()],
+        "triple",
+    )
+    .expect("link triple");
+    fs::remove_file(app.fs.resolve_uri(&AxiomUri::parse(removed).expect("uri")))
+        .expect("remove doc");
+
+    let ghost_record = crate::models::IndexRecord {
+        id: "ghost".to_string(),
+        uri: ghost.to_string(),
+        parent_uri: Some(owner.to_string()),
+        is_leaf: true,
+        context_type: "resource".to_string(),
+        name: "ghost.md".to_string(),
+        abstract_text: "ghost".to_string(),
+        content: "ghost vector".to_string(),
+        tags: vec![],
+        updated_at: chrono::Utc::now(),
+        depth: 2,
+    };
+    app.state
+        .upsert_search_document(&ghost_record)
+        .expect("upsert search document");
+    app.index.write().expect("index lock").upsert(ghost_record);
+
+    let options = ReconcileOptions {
+        dry_run: true,
+        scopes: Some(vec![Scope::Resources]),
+        check_vectors: true,
+        check_relations: true,
+        ..ReconcileOptions::default()
+    };
+    let report = app
+        .reconcile_state_with_options(&options)
+        .expect("reconcile dry run");
+    assert_eq!(report.orphan_vectors_removed, 0);
+    assert!(report.orphan_vector_samples.iter().any(|uri| uri == ghost));
+    assert_eq!(report.dangling_relations_pruned, 0);
+    assert_eq!(report.dangling_relation_samples.len(), 2);
+    assert!(
+        report
+            .dangling_relation_samples
+            .contains(&format!("{owner}#pair -> {removed}"))
+    );
+    assert_eq!(app.relations(owner).expect("relations").len(), 2);
+
+    let report = app
+        .reconcile_state_with_options(&ReconcileOptions {
+            dry_run: false,
+            ..options.clone()
+        })
+        .expect("reconcile");
+    assert_eq!(report.orphan_vectors_removed, 1);
+    assert_eq!(report.orphan_vector_samples, vec![ghost.to_string()]);
+    assert_eq!(report.dangling_relations_pruned, 2);
+    let relations = app.relations(owner).expect("relations");
+    assert_eq!(relations.len(), 1);
+    assert_eq!(relations[0].id, "triple");
+    assert_eq!(relations[0].uris, vec![kept.to_string(), owner.to_string()]);
+    assert!(
+        app.state
+            .list_search_documents()
+            .expect("search documents")
+            .iter()
+            .all(|record| record.uri != ghost)
+    );
+    assert!(app.index.read().expect("index lock").get(ghost).is_none());
+
+    let clean = app
+        .reconcile_state_with_options(&options)
+        .expect("reconcile after repair");
+    assert!(clean.orphan_vector_samples.is_empty());
+    assert!(clean.dangling_relation_samples.is_empty());
+}
+
+#[test]
+fn reconcile_regenerates_missing_scope_tier_files() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let root = AxiomUri::root(Scope::Resources);
+    let overview = app.overview("axiom://resources").expect("overview");
+    let overview_path = crate::tier_documents::overview_path(&app.fs, &root);
+    fs::remove_file(&overview_path).expect("remove overview");
+    fs::write(crate::tier_documents::abstract_path(&app.fs, &root), "").expect("truncate abstract");
+    assert!(app.overview("axiom://resources").is_err());
+
+    let options = ReconcileOptions {
+        dry_run: true,
+        scopes: Some(vec![Scope::Resources]),
+        ..ReconcileOptions::default()
+    };
+    let report = app
+        .reconcile_state_with_options(&options)
+        .expect("reconcile dry run");
+    assert_eq!(report.missing_tiers_regenerated, 0);
+    assert_eq!(
+        report.missing_tier_samples,
+        vec![
+            "axiom://resources/.abstract.md".to_string(),
+            "axiom://resources/.overview.md".to_string(),
+        ]
+    );
+    assert!(report.drift_count >= 1);
+    assert!(!overview_path.exists());
+
+    let report = app
+        .reconcile_state_with_options(&ReconcileOptions {
+            dry_run: false,
+            ..options.clone()
+        })
+        .expect("reconcile");
+    assert_eq!(report.missing_tiers_regenerated, 1);
+    assert_eq!(
+        app.overview("axiom://resources").expect("overview"),
+        overview
+    );
+    assert!(
+        !app.abstract_text("axiom://resources")
+            .expect("abstract")
+            .is_empty()
+    );
+
+    let clean = app
+        .reconcile_state_with_options(&options)
+        .expect("reconcile after repair");
+    assert!(clean.missing_tier_samples.is_empty());
+}
+
+#[test]
+fn async_add_resource_signals_backpressure_at_queue_high_water_mark() {
+    let temp = tempdir().expect("tempdir");
+    let mut app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+    let mut config = (*app.config).clone();
+    config.queue.high_water_mark = Some(2);
+    app.config = std::sync::Arc::new(config);
+
+    let source_dir = tempdir().expect("source dir");
+    let source = |name: &str| {
+        let path = source_dir.path().join(format!("{name}.md"));
+        fs::write(&path, format!("# {name}\n\nbackpressure probe")).expect("write source");
+        path.to_string_lossy().to_string()
+    };
+    for name in ["first", "second"] {
+        let result = app
+            .add_resource(
+                &source(name),
+                Some(&format!("axiom://resources/backpressure/{name}.md")),
+                None,
+                None,
+                false,
+                None,
+            )
+            .expect("async add below mark");
+        assert!(result.queued);
+    }
+
+    let blocked_target = "axiom://resources/backpressure/third.md";
+    let err = app
+        .add_resource(
+            &source("third"),
+            Some(blocked_target),
+            None,
+            None,
+            false,
+            None,
+        )
+        .expect_err("async add at mark");
+    assert!(
+        matches!(
+            err,
+            AxiomError::QueueBackpressure {
+                depth: 2,
+                high_water_mark: 2
+            }
+        ),
+        "{err:?}"
+    );
+    assert_eq!(err.code(), "QUEUE_BACKPRESSURE");
+    assert!(
+        !app.fs
+            .exists(&AxiomUri::parse(blocked_target).expect("uri"))
+    );
+
+    app.add_resource(
+        &source("third"),
+        Some(blocked_target),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("waiting add ignores mark");
+    let _ = app.replay_outbox(64, false).expect("drain queue");
+    app.add_resource(
+        &source("fourth"),
+        Some("axiom://resources/backpressure/fourth.md"),
+        None,
+        None,
+        false,
+        None,
+    )
+    .expect("async add after drain");
+}
+
+fn run_single_watch_cycle(app: &AxiomSync, source: &std::path::Path, target: &str) -> WatchStats {
+    let handle = app
+        .watch_source(
+            source.to_str().expect("source str"),
+            Some(target),
+            WatchOptions {
+                interval_ms: 100,
+                max_cycles: Some(1),
+                ..WatchOptions::default()
+            },
+        )
+        .expect("watch source");
+    let started = std::time::Instant::now();
+    while !handle.is_finished() {
+        assert!(
+            started.elapsed() < std::time::Duration::from_secs(30),
+            "watch cycle timed out"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+    let stats = handle.stop();
+    assert_eq!(stats.cycles, 1);
+    assert_eq!(stats.errors, 0, "{:?}", stats.last_error);
+    stats
+}
+
+#[test]
+fn watch_source_ingests_created_modified_and_deleted_files() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let source = temp.path().join("watched_notes");
+    fs::create_dir_all(&source).expect("mkdir");
+    fs::write(source.join("alpha.md"), "# Alpha\n\nwatchalpha original").expect("write alpha");
+    fs::write(source.join("beta.md"), "# Beta\n\nwatchbeta doomed").expect("write beta");
+    let target = "axiom://resources/watched";
+
+    let first = run_single_watch_cycle(&app, &source, target);
+    assert_eq!((first.scanned, first.enqueued, first.deleted), (2, 2, 0));
+    let found = app
+        .find("watchbeta", Some(target), Some(5), None, None)
+        .expect("find beta");
+    assert!(
+        found
+            .query_results
+            .iter()
+            .any(|hit| hit.uri == "axiom://resources/watched/beta.md")
+    );
+
+    let unchanged = run_single_watch_cycle(&app, &source, target);
+    assert_eq!((unchanged.scanned, unchanged.enqueued), (2, 0));
+    assert_eq!(unchanged.skipped, 2);
+
+    fs::write(source.join("alpha.md"), "# Alpha\n\nwatchalpha revised").expect("rewrite alpha");
+    fs::remove_file(source.join("beta.md")).expect("remove beta");
+    fs::write(source.join("gamma.md"), "# Gamma\n\nwatchgamma fresh").expect("write gamma");
+
+    let second = run_single_watch_cycle(&app, &source, target);
+    assert_eq!((second.scanned, second.enqueued, second.deleted), (2, 3, 1));
+    assert!(
+        app.read("axiom://resources/watched/alpha.md")
+            .expect("read alpha")
+            .contains("revised")
+    );
+    assert!(
+        !app.fs
+            .exists(&AxiomUri::parse("axiom://resources/watched/beta.md").expect("uri"))
+    );
+    assert!(
+        app.state
+            .get_index_state("axiom://resources/watched/beta.md")
+            .expect("index state")
+            .is_none()
+    );
+    let gone = app
+        .find("watchbeta", Some(target), Some(5), None, None)
+        .expect("find beta after delete");
+    assert!(
+        gone.query_results
+            .iter()
+            .all(|hit| hit.uri != "axiom://resources/watched/beta.md")
+    );
+    let fresh = app
+        .find("watchgamma", Some(target), Some(5), None, None)
+        .expect("find gamma");
+    assert!(
+        fresh
+            .query_results
+            .iter()
+            .any(|hit| hit.uri == "axiom://resources/watched/gamma.md")
+    );
+}
+
+#[test]
+fn dead_letter_list_and_requeue_by_id() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let poison = app
+        .state
+        .enqueue(
+            "unknown_event_type",
+            "axiom://resources/poison",
+            serde_json::json!({"marker": "poison"}),
+        )
+        .expect("enqueue poison");
+    let other = app
+        .state
+        .enqueue(
+            "unknown_event_type",
+            "axiom://resources/other",
+            serde_json::json!({"marker": "other"}),
+        )
+        .expect("enqueue other");
+    app.replay_outbox(10, false).expect("replay failed");
+
+    let dead = app.list_dead_letter(10).expect("list dead letter");
+    let ids = dead.iter().map(|event| event.id).collect::<Vec<_>>();
+    assert_eq!(ids, vec![poison, other]);
+    assert!(
+        dead.iter()
+            .all(|event| event.status == QueueEventStatus::DeadLetter)
+    );
+    assert_eq!(app.list_dead_letter(1).expect("list limited").len(), 1);
+
+    assert!(app.requeue_dead_letter(poison).expect("requeue poison"));
+    let event = app
+        .get_queue_event(poison)
+        .expect("get event")
+        .expect("event missing");
+    assert_eq!(event.status, QueueEventStatus::New);
+    assert_eq!(event.attempt_count, 0);
+    let remaining = app.list_dead_letter(10).expect("list after requeue");
+    assert_eq!(
+        remaining.iter().map(|event| event.id).collect::<Vec<_>>(),
+        vec![other]
+    );
+
+    // Already-requeued and unknown ids change nothing.
+    assert!(!app.requeue_dead_letter(poison).expect("requeue new event"));
+    assert!(
+        !app.requeue_dead_letter(other + 1000)
+            .expect("requeue missing")
+    );
+    let event = app
+        .get_queue_event(other)
+        .expect("get other")
+        .expect("other missing");
+    assert_eq!(event.status, QueueEventStatus::DeadLetter);
+
+    let logs = app
+        .list_request_logs_filtered(50, Some("queue.dead_letter.requeue"), Some("ok"))
+        .expect("requeue logs");
+    assert_eq!(logs.len(), 3);
+    assert!(logs.iter().any(|entry| {
+        entry.target_uri.as_deref() == Some("axiom://resources/poison")
+            && entry
+                .details
+                .as_ref()
+                .is_some_and(|details| details["applied"] == true)
+    }));
+}
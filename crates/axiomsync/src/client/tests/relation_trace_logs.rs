@@ -751,6 +751,145 @@ fn relation_api_refreshes_ontology_schema_cache_after_schema_update() {
     .expect("updated link should pass");
 }
 
+#[test]
+fn relation_neighborhood_walks_hops_through_cycles_and_flags_dangling_links() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let corpus_dir = temp.path().join("graph_corpus");
+    fs::create_dir_all(&corpus_dir).expect("mkdir");
+    for name in ["a.md", "b.md", "c.md", "d.md"] {
+        fs::write(corpus_dir.join(name), format!("graph node {name} oauth")).expect("write");
+    }
+    app.add_resource(
+        corpus_dir.to_str().expect("corpus"),
+        Some("axiom://resources/graph"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add");
+
+    let uri = |name: &str| format!("axiom://resources/graph/{name}");
+    for (id, from, to) in [
+        ("r-ab", "a.md", "b.md"),
+        ("r-bc", "b.md", "c.md"),
+        ("r-ca", "c.md", "a.md"),
+        ("r-cd", "c.md", "d.md"),
+        ("r-ghost", "d.md", "ghost.md"),
+    ] {
+        app.link(
+            "axiom://resources/graph",
+            id,
+            vec![uri(from), uri(to)],
+            "graph edge",
+        )
+        .expect("link");
+    }
+
+    let two_hops = app
+        .relation_neighborhood(&uri("a.md"), 2, 100)
+        .expect("neighborhood");
+    let hops_of = |graph: &crate::models::RelationGraph| {
+        graph
+            .nodes
+            .iter()
+            .map(|node| (node.uri.clone(), node.hops))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        hops_of(&two_hops),
+        vec![
+            (uri("a.md"), 0),
+            (uri("b.md"), 1),
+            (uri("c.md"), 1),
+            (uri("d.md"), 2),
+        ]
+    );
+    assert!(!two_hops.truncated);
+    let d = two_hops
+        .nodes
+        .iter()
+        .find(|node| node.uri == uri("d.md"))
+        .expect("d node");
+    assert_eq!(
+        d.relation_path,
+        vec!["r-ca".to_string(), "r-cd".to_string()]
+    );
+    assert!(
+        two_hops
+            .edges
+            .iter()
+            .any(|edge| edge.relation_id == "r-bc" && edge.owner_uri == "axiom://resources/graph")
+    );
+
+    let three_hops = app
+        .relation_neighborhood(&uri("a.md"), 3, 100)
+        .expect("neighborhood");
+    let ghost = three_hops
+        .nodes
+        .iter()
+        .find(|node| node.uri == uri("ghost.md"))
+        .expect("dangling node kept");
+    assert!(ghost.dangling);
+    assert_eq!(ghost.hops, 3);
+    assert!(three_hops.nodes.iter().filter(|node| node.dangling).count() == 1);
+
+    let capped = app
+        .relation_neighborhood(&uri("a.md"), 5, 2)
+        .expect("capped neighborhood");
+    assert_eq!(capped.nodes.len(), 2);
+    assert!(capped.truncated);
+
+    let err = app
+        .relation_neighborhood(&uri("a.md"), 6, 10)
+        .expect_err("hops above 5");
+    assert!(matches!(err, AxiomError::Validation(_)));
+    let err = app
+        .relation_neighborhood(&uri("missing.md"), 1, 10)
+        .expect_err("missing root");
+    assert!(matches!(err, AxiomError::NotFound(_)));
+
+    let mut request = crate::models::SearchRequest {
+        query: "graph node a.md".to_string(),
+        target_uri: Some("axiom://resources/graph".to_string()),
+        target_uris: Vec::new(),
+        session: None,
+        limit: Some(10),
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        budget: None,
+        runtime_hints: Vec::new(),
+        exclude_scopes: Vec::new(),
+        scope_set: crate::models::SearchScopeSet::AllContent,
+        relation_hops: None,
+        snippet_highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+    };
+    let plain = app.search_with_request(request.clone()).expect("search");
+    assert!(
+        plain
+            .query_results
+            .iter()
+            .all(|hit| hit.relation_neighborhood.is_none())
+    );
+    request.relation_hops = Some(1);
+    let enriched = app.search_with_request(request).expect("search");
+    let hit = enriched
+        .query_results
+        .iter()
+        .find(|hit| hit.uri == uri("a.md"))
+        .expect("a.md hit");
+    let graph = hit.relation_neighborhood.as_ref().expect("neighborhood");
+    assert_eq!(graph.max_hops, 1);
+    assert_eq!(graph.nodes.len(), 3);
+}
+
 #[test]
 fn find_and_search_enrich_hits_with_relations() {
     let temp = tempdir().expect("tempdir");
@@ -1085,6 +1224,74 @@ fn find_persists_trace_and_supports_replay_lookup() {
     );
 }
 
+#[test]
+fn query_log_hash_mode_persists_fingerprint_instead_of_query_text() {
+    let temp = tempdir().expect("tempdir");
+    let mut app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+    let mut config = (*app.config).clone();
+    config.query_log = crate::config::QueryLogMode::Hash;
+    app.config = std::sync::Arc::new(config);
+
+    let src = temp.path().join("query_log_input.txt");
+    fs::write(&src, "OAuth private ledger notes.").expect("write input");
+    app.add_resource(
+        src.to_str().expect("src str"),
+        Some("axiom://resources/query-log-demo"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add failed");
+
+    let query = "oauth private ledger";
+    let result = app
+        .find(
+            query,
+            Some("axiom://resources/query-log-demo"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find failed");
+    let trace = result.trace.expect("trace missing");
+    assert_eq!(trace.query, query);
+    let fingerprint = crate::config::QueryLogMode::Hash
+        .persisted_query(query)
+        .into_owned();
+
+    let logs = app
+        .list_request_logs_filtered(5, Some("find"), Some("ok"))
+        .expect("request logs");
+    let details = logs
+        .first()
+        .and_then(|entry| entry.details.as_ref())
+        .expect("find details");
+    assert_eq!(
+        details.get("query").and_then(serde_json::Value::as_str),
+        Some(fingerprint.as_str())
+    );
+    let raw_log = app
+        .fs
+        .read(&crate::catalog::request_log_uri().expect("log uri"))
+        .expect("read request log");
+    assert!(!raw_log.contains(query));
+
+    let persisted = app
+        .get_trace(&trace.trace_id)
+        .expect("get trace")
+        .expect("trace not found");
+    assert_eq!(persisted.query, fingerprint);
+    let listed = app.list_traces(10).expect("list traces");
+    assert!(
+        listed
+            .iter()
+            .filter(|entry| entry.trace_id == trace.trace_id)
+            .all(|entry| entry.query == fingerprint)
+    );
+}
+
 #[test]
 fn replay_trace_reexecutes_query_and_persists_new_trace() {
     let temp = tempdir().expect("tempdir");
@@ -1132,6 +1339,97 @@ fn replay_trace_reexecutes_query_and_persists_new_trace() {
     );
 }
 
+#[test]
+fn compare_traces_reports_rank_movement_after_corpus_change() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let corpus = temp.path().join("trace_diff_corpus");
+    fs::create_dir_all(&corpus).expect("mkdir corpus");
+    fs::write(corpus.join("alpha.md"), "OAuth token refresh flow.").expect("write alpha");
+    fs::write(
+        corpus.join("beta.md"),
+        "Session cookie notes mention oauth once.",
+    )
+    .expect("write beta");
+    app.add_resource(
+        corpus.to_str().expect("corpus str"),
+        Some("axiom://resources/trace-diff"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add corpus");
+
+    let original = app
+        .find(
+            "oauth token refresh",
+            Some("axiom://resources/trace-diff"),
+            Some(5),
+            None,
+            None,
+        )
+        .expect("find failed");
+    let original_trace_id = original
+        .trace
+        .as_ref()
+        .map(|t| t.trace_id.clone())
+        .expect("trace missing");
+
+    let extra = temp.path().join("gamma.md");
+    fs::write(
+        &extra,
+        "OAuth token refresh flow: oauth token refresh rotation.",
+    )
+    .expect("write gamma");
+    app.add_resource(
+        extra.to_str().expect("extra str"),
+        Some("axiom://resources/trace-diff"),
+        None,
+        None,
+        true,
+        None,
+    )
+    .expect("add gamma");
+
+    let replay = app
+        .replay_trace(&original_trace_id, Some(5))
+        .expect("replay failed")
+        .expect("replay missing");
+    let replay_trace_id = replay
+        .trace
+        .as_ref()
+        .map(|t| t.trace_id.clone())
+        .expect("replay trace missing");
+
+    let diff = app
+        .compare_traces(&original_trace_id, &replay_trace_id)
+        .expect("compare");
+    assert_eq!(diff.trace_id_a, original_trace_id);
+    assert_eq!(diff.trace_id_b, replay_trace_id);
+    assert!(diff.removed.is_empty());
+    let gamma = diff
+        .added
+        .iter()
+        .find(|hit| hit.uri == "axiom://resources/trace-diff/gamma.md")
+        .expect("gamma added");
+    assert_eq!(gamma.rank, 1);
+    let alpha = diff
+        .changed
+        .iter()
+        .find(|change| change.uri == "axiom://resources/trace-diff/alpha.md")
+        .expect("alpha moved");
+    assert_eq!(alpha.rank_delta, -1);
+    assert_eq!(alpha.rank_b, alpha.rank_a + 1);
+
+    let err = app
+        .compare_traces(&original_trace_id, "missing-trace")
+        .expect_err("missing trace");
+    assert!(matches!(err, AxiomError::NotFound(_)));
+}
+
 #[test]
 fn request_logs_include_request_and_trace_ids_for_find() {
     let temp = tempdir().expect("tempdir");
@@ -1222,6 +1520,41 @@ fn request_logs_support_operation_status_filters_case_insensitive() {
     );
 }
 
+#[test]
+fn request_logs_export_jsonl_matches_filtered_entries() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+
+    let _ = app.replay_outbox(10, false).expect("replay");
+    let _ = app.replay_outbox(10, false).expect("replay");
+    let _ = app
+        .find("oauth", Some("invalid://bad-target"), Some(5), None, None)
+        .expect_err("find should fail");
+
+    let filtered = app
+        .list_request_logs_filtered(20, Some("queue.replay"), Some("ok"))
+        .expect("list replay logs");
+    assert_eq!(filtered.len(), 2);
+
+    let mut buffer = Vec::new();
+    let written = app
+        .export_request_logs_jsonl(20, Some("queue.replay"), Some("ok"), &mut buffer)
+        .expect("export logs");
+    assert_eq!(written, filtered.len());
+
+    let raw = String::from_utf8(buffer).expect("utf8 export");
+    let lines = raw.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), filtered.len());
+    for (line, expected) in lines.iter().zip(&filtered) {
+        let parsed: crate::models::RequestLogEntry =
+            serde_json::from_str(line).expect("parse exported line");
+        assert_eq!(parsed.request_id, expected.request_id);
+        assert_eq!(parsed.operation, "queue.replay");
+        assert_eq!(parsed.status, "ok");
+    }
+}
+
 #[test]
 #[expect(
     clippy::too_many_lines,
@@ -1274,6 +1607,8 @@ fn request_logs_capture_extended_core_operations() {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         })
         .expect("benchmark");
     assert!(benchmark.quality.executed_cases >= 1);
@@ -1286,10 +1621,12 @@ fn request_logs_capture_extended_core_operations() {
             min_stress_top1_accuracy: None,
             max_p95_regression_pct: None,
             max_top1_regression_pct: None,
+            max_mrr_regression_pct: None,
             window_size: 1,
             required_passes: 1,
             record: false,
             write_release_check: false,
+            max_p95_ms_at_concurrency: None,
         })
         .expect("gate");
     assert!(gate.passed);
@@ -1299,6 +1636,9 @@ fn request_logs_capture_extended_core_operations() {
             dry_run: true,
             scopes: Some(vec![Scope::Resources]),
             max_drift_sample: 20,
+            fix_duplicates: false,
+            check_vectors: false,
+            check_relations: false,
         })
         .expect("reconcile");
     assert_eq!(reconcile.status, crate::models::ReconcileRunStatus::DryRun);
@@ -1340,3 +1680,154 @@ fn request_logs_capture_extended_core_operations() {
         .expect("filter reconcile");
     assert!(!dry_run_logs.is_empty());
 }
+
+#[test]
+fn compact_prunes_old_traces_reports_and_request_log_rows() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+
+    let now = chrono::Utc::now();
+    for (trace_id, created_at) in [
+        ("trace-old", now - chrono::Duration::days(60)),
+        ("trace-new", now),
+    ] {
+        let uri = AxiomUri::parse(&format!("axiom://queue/traces/{trace_id}.json")).expect("uri");
+        app.fs
+            .write(&uri, "{\"trace\":true}", true)
+            .expect("write trace");
+        app.state
+            .upsert_trace_index(&crate::models::TraceIndexEntry {
+                trace_id: trace_id.to_string(),
+                uri: uri.to_string(),
+                request_type: "find".to_string(),
+                query: "q".to_string(),
+                target_uri: None,
+                created_at: created_at.to_rfc3339(),
+            })
+            .expect("trace index");
+    }
+
+    let reports = crate::catalog::benchmark_reports_uri().expect("reports uri");
+    let old_mtime = std::time::SystemTime::now() - std::time::Duration::from_secs(90 * 86_400);
+    for (run_id, old) in [("run-a", true), ("run-b", true), ("run-c", false)] {
+        for ext in ["json", "md"] {
+            let uri = reports
+                .join(&format!("{run_id}.{ext}"))
+                .expect("report uri");
+            app.fs.write(&uri, "report", true).expect("write report");
+            if old {
+                std::fs::File::options()
+                    .write(true)
+                    .open(app.fs.resolve_uri(&uri))
+                    .and_then(|file| file.set_modified(old_mtime))
+                    .expect("set mtime");
+            }
+        }
+    }
+
+    let log_uri = crate::catalog::request_log_uri().expect("log uri");
+    app.fs
+        .write(
+            &log_uri,
+            "{\"n\":1}\n{\"n\":2}\n{\"n\":3}\n{\"n\":4}\n{\"n\":5}\n",
+            true,
+        )
+        .expect("write log");
+
+    let options = crate::models::CompactOptions {
+        dry_run: true,
+        trace_retention_days: 30,
+        request_log_max_rows: 2,
+        report_retention_days: 30,
+        reports_keep: 1,
+    };
+    let removed = |report: &crate::models::CompactReport, category: &str| {
+        report
+            .categories
+            .iter()
+            .find(|entry| entry.category == category)
+            .map(|entry| (entry.removed, entry.reclaimed_bytes))
+            .expect("category")
+    };
+
+    let dry = app.compact(options).expect("dry run");
+    assert!(dry.dry_run);
+    assert_eq!(removed(&dry, "traces").0, 1);
+    assert_eq!(removed(&dry, "reports"), (4, 24));
+    assert_eq!(removed(&dry, "request_logs"), (3, 24));
+    assert!(dry.report_uri.starts_with("axiom://queue/maintenance/"));
+    assert!(
+        app.fs
+            .exists(&AxiomUri::parse(&dry.report_uri).expect("report uri"))
+    );
+    assert!(
+        app.state
+            .get_trace_index("trace-old")
+            .expect("get")
+            .is_some()
+    );
+    assert!(app.fs.exists(&reports.join("run-a.json").expect("uri")));
+
+    let applied = app
+        .compact(crate::models::CompactOptions {
+            dry_run: false,
+            ..options
+        })
+        .expect("compact");
+    assert_eq!(removed(&applied, "traces").0, 1);
+    assert_eq!(removed(&applied, "reports").0, 4);
+    assert!(
+        app.state
+            .get_trace_index("trace-old")
+            .expect("get")
+            .is_none()
+    );
+    assert!(
+        app.state
+            .get_trace_index("trace-new")
+            .expect("get")
+            .is_some()
+    );
+    assert!(
+        !app.fs
+            .exists(&AxiomUri::parse("axiom://queue/traces/trace-old.json").expect("uri"))
+    );
+    assert!(!app.fs.exists(&reports.join("run-a.md").expect("uri")));
+    assert!(!app.fs.exists(&reports.join("run-b.json").expect("uri")));
+    assert!(app.fs.exists(&reports.join("run-c.json").expect("uri")));
+
+    let log = app.fs.read(&log_uri).expect("read log");
+    let lines = log.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3, "two kept rows plus the compact request log");
+    assert_eq!(lines[0], "{\"n\":5}");
+    assert!(lines[2].contains("maintenance.compact"));
+}
+
+#[test]
+fn backup_state_copies_live_database_and_rejects_self_target() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app new");
+    app.initialize().expect("init failed");
+    app.state
+        .set_system_value("backup_probe", "kept")
+        .expect("set value");
+
+    let target = temp.path().join("snapshots").join("context.db");
+    let report = app.backup_state(&target).expect("backup");
+    assert_eq!(report.path, target.display().to_string());
+    assert!(report.size_bytes > 0);
+    let restored = crate::state::SqliteStateStore::open(&target).expect("open backup");
+    assert_eq!(
+        restored
+            .get_system_value("backup_probe")
+            .expect("get value")
+            .as_deref(),
+        Some("kept")
+    );
+
+    let live = app.backup_state(temp.path().join("context.db"));
+    assert!(matches!(live, Err(AxiomError::Validation(_))), "{live:?}");
+    let dir = app.backup_state(temp.path().join("snapshots"));
+    assert!(matches!(dir, Err(AxiomError::Validation(_))), "{dir:?}");
+}
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::HashSet;
 
 use chrono::Utc;
@@ -9,7 +10,7 @@ use crate::catalog::{
 use crate::error::{AxiomError, Result};
 use crate::models::{
     BenchmarkRunOptions, EvalGoldenDocument, EvalQueryCase, FindResult, RetrievalTrace,
-    SearchOptions, TraceIndexEntry,
+    SearchOptions, SearchScopeSet, TraceIndexEntry,
 };
 use crate::uri::{AxiomUri, Scope};
 
@@ -33,7 +34,20 @@ impl AxiomSync {
         let trace_uri = AxiomUri::root(Scope::Queue)
             .join("traces")?
             .join(&format!("{}.json", trace.trace_id))?;
-        let serialized = serde_json::to_string_pretty(trace)?;
+        let mut trace = Cow::Borrowed(trace);
+        if self.config.redaction.is_enabled() {
+            let query = self.redact_query_text(trace.target_uri.as_deref(), &trace.query);
+            trace.to_mut().query = query;
+        }
+        if !self.config.query_log.is_raw() {
+            let query = self
+                .config
+                .query_log
+                .persisted_query(&trace.query)
+                .into_owned();
+            trace.to_mut().query = query;
+        }
+        let serialized = serde_json::to_string_pretty(trace.as_ref())?;
         self.fs.write(&trace_uri, &serialized, true)?;
 
         self.state.upsert_trace_index(&TraceIndexEntry {
@@ -48,6 +62,17 @@ impl AxiomSync {
         Ok(trace_uri.to_string())
     }
 
+    /// Redacts query text copied into traces and fixtures.
+    pub(crate) fn redact_query_text(&self, target_uri: Option<&str>, query: &str) -> String {
+        let target_scope = target_uri
+            .and_then(|raw| AxiomUri::parse(raw).ok())
+            .map(|uri| uri.scope());
+        self.config
+            .redaction
+            .redact_artifact_text(target_scope, query)
+            .into_owned()
+    }
+
     pub(crate) fn collect_trace_eval_cases(
         &self,
         trace_limit: usize,
@@ -192,6 +217,7 @@ impl AxiomSync {
         let options = SearchOptions {
             query: query.to_string(),
             target_uri: target,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: None,
@@ -200,6 +226,9 @@ impl AxiomSync {
             min_match_tokens: None,
             filter: None,
             request_type: request_type.to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         };
         let result = {
             let index = self
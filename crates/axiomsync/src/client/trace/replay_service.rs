@@ -1,9 +1,13 @@
+use std::collections::HashMap;
 use std::time::Instant;
 
 use chrono::Utc;
 
 use crate::error::{AxiomError, Result};
-use crate::models::{FindResult, RequestLogEntry, RetrievalTrace, SearchOptions};
+use crate::models::{
+    FindResult, RequestLogEntry, RetrievalTrace, SearchOptions, SearchScopeSet, TraceDiff,
+    TraceDiffHit, TracePoint, TraceRankChange,
+};
 use crate::uri::AxiomUri;
 
 use super::AxiomSync;
@@ -19,6 +23,21 @@ impl AxiomSync {
         Ok(Some(trace))
     }
 
+    /// Compares two persisted traces' final hits by uri; `NotFound` if either is missing.
+    pub fn compare_traces(&self, trace_id_a: &str, trace_id_b: &str) -> Result<TraceDiff> {
+        let a = self.load_trace_for_compare(trace_id_a)?;
+        let b = self.load_trace_for_compare(trace_id_b)?;
+        Ok(diff_trace_results(&a, &b))
+    }
+
+    fn load_trace_for_compare(&self, trace_id: &str) -> Result<RetrievalTrace> {
+        match self.get_trace(trace_id) {
+            Ok(Some(trace)) => Ok(trace),
+            Ok(None) => Err(AxiomError::NotFound(format!("trace {trace_id}"))),
+            Err(err) => Err(AxiomError::NotFound(format!("trace {trace_id}: {err}"))),
+        }
+    }
+
     pub fn replay_trace(&self, trace_id: &str, limit: Option<usize>) -> Result<Option<FindResult>> {
         let request_id = uuid::Uuid::new_v4().to_string();
         let started = Instant::now();
@@ -44,6 +63,7 @@ impl AxiomSync {
                 let options = SearchOptions {
                     query: stored_trace.query,
                     target_uri,
+                    target_uris: Vec::new(),
                     session: None,
                     session_hints: Vec::new(),
                     budget: None,
@@ -52,6 +72,9 @@ impl AxiomSync {
                     min_match_tokens: None,
                     filter: None,
                     request_type,
+                    exclude_scopes: Vec::new(),
+                    scope_set: SearchScopeSet::AllContent,
+                    offset: 0,
                 };
                 self.drr.run(&index, &options)
             };
@@ -118,3 +141,68 @@ impl AxiomSync {
         }
     }
 }
+
+fn diff_trace_results(a: &RetrievalTrace, b: &RetrievalTrace) -> TraceDiff {
+    let ranks_a = rank_by_uri(&a.final_topk);
+    let ranks_b = rank_by_uri(&b.final_topk);
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    let mut unchanged = 0;
+    for (index, point) in b.final_topk.iter().enumerate() {
+        let rank_b = index + 1;
+        match ranks_a.get(point.uri.as_str()) {
+            None => added.push(TraceDiffHit {
+                uri: point.uri.clone(),
+                rank: rank_b,
+                score: point.score,
+            }),
+            Some(&(rank_a, score_a)) => {
+                if rank_a == rank_b && score_a == point.score {
+                    unchanged += 1;
+                    continue;
+                }
+                changed.push(TraceRankChange {
+                    uri: point.uri.clone(),
+                    rank_a,
+                    rank_b,
+                    rank_delta: i64::try_from(rank_a).unwrap_or(i64::MAX)
+                        - i64::try_from(rank_b).unwrap_or(i64::MAX),
+                    score_a,
+                    score_b: point.score,
+                    score_delta: point.score - score_a,
+                });
+            }
+        }
+    }
+    let removed = a
+        .final_topk
+        .iter()
+        .enumerate()
+        .filter(|(_, point)| !ranks_b.contains_key(point.uri.as_str()))
+        .map(|(index, point)| TraceDiffHit {
+            uri: point.uri.clone(),
+            rank: index + 1,
+            score: point.score,
+        })
+        .collect();
+    TraceDiff {
+        trace_id_a: a.trace_id.clone(),
+        trace_id_b: b.trace_id.clone(),
+        query_a: a.query.clone(),
+        query_b: b.query.clone(),
+        added,
+        removed,
+        changed,
+        unchanged,
+    }
+}
+
+/// 1-based rank and score per uri; the first rank wins on repeats.
+fn rank_by_uri(points: &[TracePoint]) -> HashMap<&str, (usize, f32)> {
+    let mut out = HashMap::with_capacity(points.len());
+    for (index, point) in points.iter().enumerate() {
+        out.entry(point.uri.as_str())
+            .or_insert((index + 1, point.score));
+    }
+    out
+}
@@ -0,0 +1,60 @@
+use chrono::{Duration, Utc};
+
+use crate::error::Result;
+use crate::models::TrashEntry;
+use crate::uri::AxiomUri;
+
+use super::AxiomSync;
+
+impl AxiomSync {
+    /// Moves a content-scope item to `axiom://trash` and drops its index state.
+    /// Internal scopes are deleted directly and return `None`.
+    pub fn trash(&self, uri: &str, recursive: bool) -> Result<Option<TrashEntry>> {
+        let uri = AxiomUri::parse(uri)?;
+        if uri.scope().is_internal() {
+            self.rm_permanent(&uri.to_string(), recursive)?;
+            return Ok(None);
+        }
+        let entry = self.fs.move_to_trash(&uri, recursive)?;
+        self.forget_removed_prefix(&uri)?;
+
+        self.state.enqueue(
+            "delete",
+            &uri.to_string(),
+            serde_json::json!({
+                "op": "trash",
+                "recursive": recursive,
+                "trash_uri": entry.as_ref().map(|entry| entry.trash_uri.as_str()),
+            }),
+        )?;
+        Ok(entry)
+    }
+
+    /// Most recently trashed first.
+    pub fn list_trash(&self, limit: usize) -> Result<Vec<TrashEntry>> {
+        let mut entries = self.fs.list_trash()?;
+        entries.truncate(limit);
+        Ok(entries)
+    }
+
+    /// Fails with `Conflict` if the original path exists again.
+    pub fn restore_from_trash(&self, trash_uri: &str) -> Result<TrashEntry> {
+        let trash_uri = AxiomUri::parse(trash_uri)?;
+        let entry = self.fs.restore_from_trash(&trash_uri)?;
+        let original = AxiomUri::parse(&entry.original_uri)?;
+        self.reindex_uri_tree(&original)?;
+
+        self.state.enqueue(
+            "reindex",
+            &entry.original_uri,
+            serde_json::json!({"op": "restore", "trash_uri": entry.trash_uri}),
+        )?;
+        Ok(entry)
+    }
+
+    /// Permanently deletes entries older than `older_than_days`; 0 empties the trash.
+    pub fn empty_trash(&self, older_than_days: u32) -> Result<Vec<TrashEntry>> {
+        let deleted_before = Utc::now() - Duration::days(i64::from(older_than_days));
+        self.fs.purge_trash(deleted_before)
+    }
+}
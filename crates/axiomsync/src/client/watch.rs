@@ -0,0 +1,352 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, UNIX_EPOCH};
+
+use serde_json::json;
+use walkdir::WalkDir;
+
+use crate::context_ops::default_resource_target;
+use crate::error::{AxiomError, Result};
+use crate::ingest::{IngestPathFilter, relative_to_unix_path};
+use crate::models::{WatchOptions, WatchStats};
+use crate::uri::AxiomUri;
+
+use super::AxiomSync;
+
+const WATCH_MIN_INTERVAL_MS: u64 = 100;
+const WATCH_STOP_POLL: Duration = Duration::from_millis(50);
+const WATCH_REPLAY_LIMIT: usize = 256;
+
+/// Handle for a `watch_source` worker; dropping it stops and joins the worker.
+pub struct WatchHandle {
+    stop: Arc<AtomicBool>,
+    stats: Arc<Mutex<WatchStats>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl std::fmt::Debug for WatchHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WatchHandle")
+            .field("stats", &self.stats())
+            .finish_non_exhaustive()
+    }
+}
+
+impl WatchHandle {
+    #[must_use]
+    pub fn stats(&self) -> WatchStats {
+        self.stats.lock().map_or_else(
+            |poisoned| poisoned.into_inner().clone(),
+            |stats| stats.clone(),
+        )
+    }
+
+    /// True once `max_cycles` is reached or `stop()` ended the worker.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.worker.as_ref().is_none_or(JoinHandle::is_finished)
+    }
+
+    pub fn stop(mut self) -> WatchStats {
+        self.shutdown();
+        self.stats()
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl AxiomSync {
+    /// Polls a local directory, mirrors changes into `target_uri` and enqueues per-file `semantic_scan` events.
+    pub fn watch_source(
+        &self,
+        source_dir: &str,
+        target_uri: Option<&str>,
+        options: WatchOptions,
+    ) -> Result<WatchHandle> {
+        let source = PathBuf::from(source_dir);
+        if !source.is_dir() {
+            return Err(AxiomError::Validation(format!(
+                "watch source must be a local directory: {source_dir}"
+            )));
+        }
+        let target = target_uri
+            .map(AxiomUri::parse)
+            .transpose()?
+            .map_or_else(|| default_resource_target(source_dir), Ok)?;
+        if target.scope().is_internal() {
+            return Err(AxiomError::PermissionDenied(format!(
+                "watch target must not be an internal scope: {target}"
+            )));
+        }
+
+        let mut scanner = SourceScanner {
+            filter: IngestPathFilter::new(&options.ingest_options)?,
+            source,
+            target,
+            seen_mtimes: HashMap::new(),
+        };
+        let stop = Arc::new(AtomicBool::new(false));
+        let stats = Arc::new(Mutex::new(WatchStats {
+            target_uri: scanner.target.to_string(),
+            ..WatchStats::default()
+        }));
+        let app = self.clone();
+        let worker_stop = Arc::clone(&stop);
+        let worker_stats = Arc::clone(&stats);
+        let interval = Duration::from_millis(options.interval_ms.max(WATCH_MIN_INTERVAL_MS));
+
+        let worker = thread::Builder::new()
+            .name("axiomsync-watch".to_string())
+            .spawn(move || {
+                while !worker_stop.load(Ordering::SeqCst) {
+                    let outcome = app.run_watch_cycle(&mut scanner, options.process_queue);
+                    let cycles = {
+                        let mut stats = worker_stats
+                            .lock()
+                            .unwrap_or_else(std::sync::PoisonError::into_inner);
+                        stats.cycles += 1;
+                        match outcome {
+                            Ok(report) => {
+                                stats.scanned += report.scanned;
+                                stats.enqueued += report.enqueued;
+                                stats.skipped += report.skipped;
+                                stats.deleted += report.deleted;
+                            }
+                            Err(err) => {
+                                stats.errors += 1;
+                                stats.last_error = Some(err.to_string());
+                            }
+                        }
+                        stats.cycles
+                    };
+                    if options.max_cycles.is_some_and(|max| cycles >= max) {
+                        break;
+                    }
+                    sleep_until_stopped(&worker_stop, interval);
+                }
+            })?;
+
+        Ok(WatchHandle {
+            stop,
+            stats,
+            worker: Some(worker),
+        })
+    }
+
+    fn run_watch_cycle(
+        &self,
+        scanner: &mut SourceScanner,
+        process_queue: bool,
+    ) -> Result<WatchCycleReport> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let started = Instant::now();
+        let target_uri = scanner.target.to_string();
+
+        let output = scanner.scan_once(self).and_then(|report| {
+            if process_queue && report.enqueued > 0 {
+                self.replay_outbox(WATCH_REPLAY_LIMIT, false)?;
+            }
+            Ok(report)
+        });
+
+        match output {
+            Ok(report) => {
+                if report.enqueued > 0 {
+                    self.log_request_status(
+                        request_id,
+                        "watch.cycle",
+                        "ok",
+                        started,
+                        Some(target_uri),
+                        Some(json!({
+                            "source": scanner.source.display().to_string(),
+                            "scanned": report.scanned,
+                            "enqueued": report.enqueued,
+                            "skipped": report.skipped,
+                            "deleted": report.deleted,
+                        })),
+                    );
+                }
+                Ok(report)
+            }
+            Err(err) => {
+                self.log_request_error(
+                    request_id,
+                    "watch.cycle",
+                    started,
+                    Some(target_uri),
+                    &err,
+                    Some(json!({
+                        "source": scanner.source.display().to_string(),
+                    })),
+                );
+                Err(err)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct WatchCycleReport {
+    scanned: u64,
+    enqueued: u64,
+    skipped: u64,
+    deleted: u64,
+}
+
+struct SourceScanner {
+    source: PathBuf,
+    target: AxiomUri,
+    filter: IngestPathFilter,
+    // mtimes from the last cycle; unchanged files skip hashing.
+    seen_mtimes: HashMap<String, i64>,
+}
+
+impl SourceScanner {
+    fn scan_once(&mut self, app: &AxiomSync) -> Result<WatchCycleReport> {
+        let mut report = WatchCycleReport::default();
+        let mut present = HashSet::<String>::new();
+
+        let source = self.source.clone();
+        let entries = WalkDir::new(&source)
+            .follow_links(false)
+            .into_iter()
+            .filter_entry(|entry| {
+                if entry.path() == source || !entry.file_type().is_dir() {
+                    return true;
+                }
+                entry
+                    .path()
+                    .strip_prefix(&source)
+                    .map_or(true, |relative| self.filter.allows_directory(relative))
+            });
+        for entry in entries {
+            let entry = entry.map_err(|e| AxiomError::Validation(e.to_string()))?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let relative = entry
+                .path()
+                .strip_prefix(&source)
+                .map_err(|e| AxiomError::Validation(e.to_string()))?;
+            if !self.filter.allows_file(relative) || is_generated_tier_file(relative) {
+                continue;
+            }
+            let relative_unix = relative_to_unix_path(relative);
+            present.insert(relative_unix.clone());
+            report.scanned += 1;
+
+            let mtime = file_mtime_nanos(entry.path());
+            if self.seen_mtimes.get(&relative_unix) == Some(&mtime) {
+                report.skipped += 1;
+                continue;
+            }
+
+            let bytes = fs::read(entry.path())?;
+            let hash = blake3::hash(&bytes).to_hex().to_string();
+            let target_uri = self.target.join(&relative_unix)?;
+            let target_path = app.fs.resolve_uri(&target_uri);
+            let indexed_hash = app.state.get_index_state_hash(&target_uri.to_string())?;
+            if target_path.is_file() && indexed_hash.as_deref() == Some(hash.as_str()) {
+                report.skipped += 1;
+                self.seen_mtimes.insert(relative_unix, mtime);
+                continue;
+            }
+
+            let change = if target_path.exists() {
+                "modified"
+            } else {
+                "created"
+            };
+            app.fs.write_bytes(&target_uri, &bytes, false)?;
+            app.state.enqueue(
+                "semantic_scan",
+                &target_uri.to_string(),
+                json!({"op": "watch", "change": change}),
+            )?;
+            report.enqueued += 1;
+            self.seen_mtimes.insert(relative_unix, mtime);
+        }
+
+        for relative_unix in self.removed_target_files(app, &present) {
+            let target_uri = self.target.join(&relative_unix)?;
+            app.fs.rm(&target_uri, false, false)?;
+            app.state.enqueue(
+                "semantic_scan",
+                &target_uri.to_string(),
+                json!({"op": "watch", "change": "deleted"}),
+            )?;
+            self.seen_mtimes.remove(&relative_unix);
+            report.enqueued += 1;
+            report.deleted += 1;
+        }
+
+        Ok(report)
+    }
+
+    // Only files gone from the source are removed; filtered-out files are left alone.
+    fn removed_target_files(&self, app: &AxiomSync, present: &HashSet<String>) -> Vec<String> {
+        let target_root = app.fs.resolve_uri(&self.target);
+        if !target_root.is_dir() {
+            return Vec::new();
+        }
+        WalkDir::new(&target_root)
+            .follow_links(false)
+            .into_iter()
+            .filter_map(std::result::Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+            .filter_map(|entry| {
+                let relative = entry.path().strip_prefix(&target_root).ok()?;
+                if is_generated_tier_file(relative) {
+                    return None;
+                }
+                let relative_unix = relative_to_unix_path(relative);
+                (!present.contains(&relative_unix) && !self.source.join(relative).exists())
+                    .then_some(relative_unix)
+            })
+            .collect()
+    }
+}
+
+fn is_generated_tier_file(relative: &Path) -> bool {
+    relative
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| matches!(name, ".abstract.md" | ".overview.md"))
+}
+
+fn file_mtime_nanos(path: &Path) -> i64 {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |elapsed| {
+            i64::try_from(elapsed.as_nanos()).unwrap_or(i64::MAX)
+        })
+}
+
+fn sleep_until_stopped(stop: &AtomicBool, interval: Duration) {
+    let deadline = Instant::now() + interval;
+    while !stop.load(Ordering::SeqCst) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        thread::sleep(remaining.min(WATCH_STOP_POLL));
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::{AxiomError, Result};
+use crate::models::{SearchRequest, WorkspaceSearchItem};
+
+use super::AxiomSync;
+
+/// Several named roots opened in one process; roots that fail to open are listed in `failures`.
+/// Cross-root relations are not supported.
+pub struct AxiomSyncWorkspace {
+    roots: BTreeMap<String, AxiomSync>,
+    failures: BTreeMap<String, AxiomError>,
+}
+
+/// Workspace config with `name = "path"` entries under `[roots]`; relative paths resolve from the file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WorkspaceConfigFile {
+    roots: BTreeMap<String, PathBuf>,
+}
+
+impl AxiomSyncWorkspace {
+    /// Names must be unique `[a-z0-9_-]` of 1-64 chars; a bad name fails the whole open,
+    /// while a root that fails to open only lands in `failures`.
+    pub fn open<I, N, P>(roots: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (N, P)>,
+        N: Into<String>,
+        P: Into<PathBuf>,
+    {
+        let mut opened = BTreeMap::new();
+        let mut failures = BTreeMap::new();
+        for (name, path) in roots {
+            let name = name.into();
+            validate_root_name(&name)?;
+            if opened.contains_key(&name) || failures.contains_key(&name) {
+                return Err(AxiomError::Validation(format!(
+                    "duplicate workspace root name: {name}"
+                )));
+            }
+            match AxiomSync::new(path.into()).and_then(|app| app.initialize().map(|()| app)) {
+                Ok(app) => {
+                    opened.insert(name, app);
+                }
+                Err(err) => {
+                    failures.insert(name, err);
+                }
+            }
+        }
+        if opened.is_empty() && failures.is_empty() {
+            return Err(AxiomError::Validation(
+                "workspace requires at least one root".to_string(),
+            ));
+        }
+        Ok(Self {
+            roots: opened,
+            failures,
+        })
+    }
+
+    pub fn from_config_file(path: &Path) -> Result<Self> {
+        Self::open(Self::roots_from_config_file(path)?)
+    }
+
+    /// `(name, path)` pairs from the config file, sorted by name; roots are not opened.
+    pub fn roots_from_config_file(path: &Path) -> Result<Vec<(String, PathBuf)>> {
+        let raw = std::fs::read_to_string(path)?;
+        let config = toml::from_str::<WorkspaceConfigFile>(&raw).map_err(|err| {
+            AxiomError::Validation(format!(
+                "invalid workspace config {}: {err}",
+                path.display()
+            ))
+        })?;
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+        Ok(config
+            .roots
+            .into_iter()
+            .map(|(name, root)| {
+                let root = if root.is_absolute() {
+                    root
+                } else {
+                    base.join(root)
+                };
+                (name, root)
+            })
+            .collect())
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&AxiomSync> {
+        self.roots.get(name)
+    }
+
+    /// Open root names, sorted.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.roots.keys().map(String::as_str)
+    }
+
+    /// Roots that failed to open and why.
+    #[must_use]
+    pub const fn failures(&self) -> &BTreeMap<String, AxiomError> {
+        &self.failures
+    }
+
+    /// Runs the request on every open root in parallel; results are sorted by root name.
+    #[must_use]
+    pub fn search_all(&self, request: &SearchRequest) -> Vec<WorkspaceSearchItem> {
+        std::thread::scope(|scope| {
+            let handles = self
+                .roots
+                .iter()
+                .map(|(name, app)| {
+                    let request = request.clone();
+                    (name, scope.spawn(move || app.search_with_request(request)))
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|(name, handle)| WorkspaceSearchItem {
+                    root: name.clone(),
+                    result: handle.join().unwrap_or_else(|_| {
+                        Err(AxiomError::Internal(format!(
+                            "workspace search worker panicked for root {name}"
+                        )))
+                    }),
+                })
+                .collect()
+        })
+    }
+}
+
+fn validate_root_name(name: &str) -> Result<()> {
+    let valid = (1..=64).contains(&name.len())
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_' || b == b'-');
+    if valid {
+        Ok(())
+    } else {
+        Err(AxiomError::Validation(format!(
+            "invalid workspace root name '{name}': use 1-64 chars of [a-z0-9_-]"
+        )))
+    }
+}
@@ -1,19 +1,48 @@
-use anyhow::Result;
+use std::fs;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
 use axiomsync::AxiomSync;
 use axiomsync::client::BenchmarkFixtureCreateOptions;
 use axiomsync::models::{
-    BenchmarkGateOptions, BenchmarkRunOptions, EvalRunOptions, ReleaseGateBenchmarkGatePlan,
-    ReleaseGateBenchmarkRunPlan, ReleaseGateEvalPlan, ReleaseGateOperabilityPlan,
-    ReleaseGatePackOptions, ReleaseGateReplayPlan, ReleaseSecurityAuditMode,
+    BenchmarkGateOptions, BenchmarkRunOptions, CommitMode, EvalRunOptions,
+    ReleaseGateBenchmarkGatePlan, ReleaseGateBenchmarkRunPlan, ReleaseGateEvalPlan,
+    ReleaseGateOperabilityPlan, ReleaseGatePackOptions, ReleaseGateReplayPlan,
+    ReleaseSecurityAuditMode, SearchProfile, SessionBundle, WatchOptions,
 };
 
 use crate::cli::{
-    BenchmarkCommand, BenchmarkFixtureCommand, EvalCommand, EvalGoldenCommand, RelationCommand,
-    ReleaseCommand, ReleaseSecurityAuditModeArg, SecurityAuditModeArg, SecurityCommand,
-    SessionCommand, TraceCommand,
+    BenchmarkCommand, BenchmarkFixtureCommand, EvalCommand, EvalGoldenCommand, MaintenanceCommand,
+    RelationCommand, ReleaseCommand, ReleaseSecurityAuditModeArg, SearchProfileCommand,
+    SecurityAuditModeArg, SecurityCommand, SessionCommand, SessionOmCommand, TagCommand,
+    TraceCommand, TrashCommand,
 };
 
 use super::print_json;
+use super::support::{build_metadata_filter, parse_search_budget};
+
+/// `add --watch`: prints cumulative stats as one JSON line per cycle until interrupted.
+pub(super) fn run_add_watch(
+    app: &AxiomSync,
+    source: &str,
+    target: Option<&str>,
+    options: WatchOptions,
+) -> Result<()> {
+    let handle = app.watch_source(source, target, options)?;
+    let mut printed_cycles = 0;
+    loop {
+        let stats = handle.stats();
+        if stats.cycles > printed_cycles {
+            printed_cycles = stats.cycles;
+            println!("{}", serde_json::to_string(&stats)?);
+        }
+        if handle.is_finished() {
+            return Ok(());
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+}
 
 pub(super) fn handle_session(app: &AxiomSync, command: SessionCommand) -> Result<()> {
     match command {
@@ -22,26 +51,121 @@ pub(super) fn handle_session(app: &AxiomSync, command: SessionCommand) -> Result
             session.load()?;
             println!("{}", session.session_id);
         }
-        SessionCommand::Add { id, role, text } => {
+        SessionCommand::Add {
+            id,
+            role,
+            text,
+            metadata_json,
+            attachments,
+        } => {
+            let metadata = metadata_json
+                .as_deref()
+                .map(serde_json::from_str::<serde_json::Value>)
+                .transpose()
+                .map_err(|err| anyhow::anyhow!("invalid --metadata-json value: {err}"))?;
             let session = app.session(Some(&id));
             session.load()?;
-            let message = session.add_message(&role, text)?;
+            let message = session.add_message_with_metadata(&role, text, metadata, attachments)?;
             print_json(&message)?;
         }
-        SessionCommand::Commit { id } => {
+        SessionCommand::Commit { id, keep_recent } => {
             let session = app.session(Some(&id));
             session.load()?;
-            let result = session.commit()?;
+            let result = match keep_recent {
+                Some(keep_last) => {
+                    session.commit_with_mode(CommitMode::ExtractKeepRecent { keep_last })?
+                }
+                None => session.commit()?,
+            };
             print_json(&result)?;
         }
         SessionCommand::List => {
             let sessions = app.sessions()?;
             print_json(&sessions)?;
         }
+        SessionCommand::Om { command } => match command {
+            SessionOmCommand::Show { id } => {
+                let state = app.om_state(&id)?;
+                print_json(&state)?;
+            }
+            SessionOmCommand::Reset { id, keep_buffered } => {
+                let reset = app.om_reset(&id, keep_buffered)?;
+                print_json(&serde_json::json!({
+                    "session_id": id,
+                    "reset": reset,
+                    "keep_buffered": keep_buffered,
+                }))?;
+            }
+        },
+        SessionCommand::Reflect { id } => {
+            let forced = app.force_om_reflection(&id)?;
+            print_json(&forced)?;
+        }
+        SessionCommand::Show { id, memory_stats } => {
+            let session = app.session(Some(&id));
+            let view = session.show()?;
+            if memory_stats {
+                let counts = session
+                    .memory_category_counts()?
+                    .into_iter()
+                    .map(|(category, count)| (category.as_str(), count))
+                    .collect::<std::collections::BTreeMap<_, _>>();
+                let mut value = serde_json::to_value(&view)?;
+                if let Some(object) = value.as_object_mut() {
+                    object.insert(
+                        "memory_category_counts".to_string(),
+                        serde_json::to_value(counts)?,
+                    );
+                }
+                print_json(&value)?;
+            } else {
+                print_json(&view)?;
+            }
+        }
         SessionCommand::Delete { id } => {
             let deleted = app.delete(&id)?;
             println!("{deleted}");
         }
+        SessionCommand::Export {
+            id,
+            to,
+            archive: true,
+        } => {
+            let written = app.export_session(&id, &to.to_string_lossy())?;
+            println!("{written}");
+        }
+        SessionCommand::Export {
+            id,
+            to,
+            archive: false,
+        } => {
+            let bundle = app.session(Some(&id)).export_bundle()?;
+            fs::write(&to, serde_json::to_string_pretty(&bundle)?)
+                .with_context(|| format!("failed to write session bundle: {}", to.display()))?;
+            println!("{}", to.display());
+        }
+        SessionCommand::Import {
+            file,
+            id,
+            force,
+            archive: true,
+        } => {
+            let info = app.import_session(&file.to_string_lossy(), id.as_deref(), force)?;
+            print_json(&info)?;
+        }
+        SessionCommand::Import {
+            file,
+            id,
+            force,
+            archive: false,
+        } => {
+            let raw = fs::read_to_string(&file)
+                .with_context(|| format!("failed to read session bundle: {}", file.display()))?;
+            let bundle: SessionBundle = serde_json::from_str(&raw)
+                .with_context(|| format!("invalid session bundle: {}", file.display()))?;
+            let info = app.import_session_bundle(&bundle, id.as_deref(), force)?;
+            print_json(&info)?;
+        }
     }
     Ok(())
 }
@@ -82,10 +206,21 @@ pub(super) fn handle_trace(app: &AxiomSync, command: TraceCommand) -> Result<()>
             limit,
             operation,
             status,
+            export,
         } => {
-            let logs =
-                app.list_request_logs_filtered(limit, operation.as_deref(), status.as_deref())?;
-            print_json(&logs)?;
+            if export {
+                let mut stdout = std::io::stdout().lock();
+                app.export_request_logs_jsonl(
+                    limit,
+                    operation.as_deref(),
+                    status.as_deref(),
+                    &mut stdout,
+                )?;
+            } else {
+                let logs =
+                    app.list_request_logs_filtered(limit, operation.as_deref(), status.as_deref())?;
+                print_json(&logs)?;
+            }
         }
         TraceCommand::List { limit } => {
             let traces = app.list_traces(limit)?;
@@ -99,6 +234,13 @@ pub(super) fn handle_trace(app: &AxiomSync, command: TraceCommand) -> Result<()>
             let replay = app.replay_trace(&trace_id, limit)?;
             print_json(&replay)?;
         }
+        TraceCommand::Diff {
+            trace_id_a,
+            trace_id_b,
+        } => {
+            let diff = app.compare_traces(&trace_id_a, &trace_id_b)?;
+            print_json(&diff)?;
+        }
         TraceCommand::Stats {
             limit,
             include_replays,
@@ -139,6 +281,95 @@ pub(super) fn handle_trace(app: &AxiomSync, command: TraceCommand) -> Result<()>
     Ok(())
 }
 
+pub(super) fn handle_search_profile(app: &AxiomSync, command: SearchProfileCommand) -> Result<()> {
+    match command {
+        SearchProfileCommand::Add {
+            name,
+            target,
+            limit,
+            tags,
+            mime,
+            filter_json,
+            budget_ms,
+            budget_nodes,
+            budget_depth,
+        } => {
+            let profile = app.save_search_profile(SearchProfile {
+                name,
+                target_uri: target,
+                filter: build_metadata_filter(&tags, mime.as_deref(), filter_json.as_deref())?,
+                budget: parse_search_budget(budget_ms, budget_nodes, budget_depth),
+                limit,
+            })?;
+            print_json(&profile)?;
+        }
+        SearchProfileCommand::List => {
+            let profiles = app.list_search_profiles()?;
+            print_json(&profiles)?;
+        }
+        SearchProfileCommand::Rm { name } => {
+            let removed = app.remove_search_profile(&name)?;
+            print_json(&serde_json::json!({
+                "status": "ok",
+                "name": name,
+                "removed": removed
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn handle_tag(app: &AxiomSync, command: TagCommand) -> Result<()> {
+    match command {
+        TagCommand::Add { uri, tags } => {
+            let tags = app.add_tags(&uri, &tags)?;
+            print_json(&serde_json::json!({ "uri": uri, "tags": tags }))?;
+        }
+        TagCommand::Rm { uri, tags } => {
+            let tags = app.remove_tags(&uri, &tags)?;
+            print_json(&serde_json::json!({ "uri": uri, "tags": tags }))?;
+        }
+        TagCommand::Ls { uri } => {
+            let tags = app.list_tags(&uri)?;
+            print_json(&serde_json::json!({ "uri": uri, "tags": tags }))?;
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn handle_maintenance(app: &AxiomSync, command: MaintenanceCommand) -> Result<()> {
+    match command {
+        MaintenanceCommand::Compact => print_json(&app.compact_state()?)?,
+    }
+    Ok(())
+}
+
+pub(super) fn handle_trash(app: &AxiomSync, command: TrashCommand) -> Result<()> {
+    match command {
+        TrashCommand::List { limit } => {
+            let entries = app.list_trash(limit)?;
+            print_json(&entries)?;
+        }
+        TrashCommand::Restore { trash_uri } => {
+            let entry = app.restore_from_trash(&trash_uri)?;
+            print_json(&serde_json::json!({
+                "status": "ok",
+                "restored": entry,
+            }))?;
+        }
+        TrashCommand::Empty { older_than_days } => {
+            let purged = app.empty_trash(older_than_days)?;
+            print_json(&serde_json::json!({
+                "status": "ok",
+                "older_than_days": older_than_days,
+                "purged": purged.len(),
+                "entries": purged,
+            }))?;
+        }
+    }
+    Ok(())
+}
+
 pub(super) fn handle_relation(app: &AxiomSync, command: RelationCommand) -> Result<()> {
     match command {
         RelationCommand::List { owner_uri } => {
@@ -174,6 +405,10 @@ pub(super) fn handle_relation(app: &AxiomSync, command: RelationCommand) -> Resu
                 "removed": removed
             }))?;
         }
+        RelationCommand::Neighborhood { uri, hops, limit } => {
+            let graph = app.relation_neighborhood(&uri, hops, limit)?;
+            print_json(&graph)?;
+        }
     }
     Ok(())
 }
@@ -232,6 +467,8 @@ pub(super) fn handle_benchmark(app: &AxiomSync, command: BenchmarkCommand) -> Re
             include_stress,
             trace_expectations,
             fixture_name,
+            corpus_snapshot,
+            concurrency,
         } => {
             let options = BenchmarkRunOptions {
                 query_limit,
@@ -241,6 +478,8 @@ pub(super) fn handle_benchmark(app: &AxiomSync, command: BenchmarkCommand) -> Re
                 include_stress,
                 trace_expectations,
                 fixture_name,
+                corpus_snapshot_id: corpus_snapshot,
+                concurrency: (!concurrency.is_empty()).then_some(concurrency),
             };
             let report = app.run_benchmark_suite(&options)?;
             print_json(&report)?;
@@ -253,6 +492,7 @@ pub(super) fn handle_benchmark(app: &AxiomSync, command: BenchmarkCommand) -> Re
             include_stress,
             trace_expectations,
             fixture_name,
+            corpus_snapshot,
             iterations,
         } => {
             let options = BenchmarkRunOptions {
@@ -263,6 +503,8 @@ pub(super) fn handle_benchmark(app: &AxiomSync, command: BenchmarkCommand) -> Re
                 include_stress,
                 trace_expectations,
                 fixture_name,
+                corpus_snapshot_id: corpus_snapshot,
+                concurrency: None,
             };
             let report = app.run_benchmark_suite_amortized(options, iterations)?;
             print_json(&report)?;
@@ -282,10 +524,12 @@ pub(super) fn handle_benchmark(app: &AxiomSync, command: BenchmarkCommand) -> Re
             gate_profile,
             max_p95_regression_pct,
             max_top1_regression_pct,
+            max_mrr_regression_pct,
             window_size,
             required_passes,
             record,
             write_release_check,
+            max_p95_ms_at_concurrency,
             enforce,
         } => {
             let result = app.benchmark_gate_with_options(BenchmarkGateOptions {
@@ -295,10 +539,12 @@ pub(super) fn handle_benchmark(app: &AxiomSync, command: BenchmarkCommand) -> Re
                 min_stress_top1_accuracy,
                 max_p95_regression_pct,
                 max_top1_regression_pct,
+                max_mrr_regression_pct,
                 window_size,
                 required_passes,
                 record,
                 write_release_check,
+                max_p95_ms_at_concurrency,
             })?;
             print_json(&result)?;
             if enforce && !result.passed {
@@ -1,11 +1,17 @@
 use std::path::Path;
 
 use anyhow::{Context, Result};
-use axiomsync::AxiomSync;
 use axiomsync::markdown_preview::render_markdown_html as render_preview_html;
-use axiomsync::models::{AddResourceRequest, AddResourceWaitMode, ReconcileOptions, SearchRequest};
+use axiomsync::models::{
+    AddResourceRequest, AddResourceWaitMode, CompactOptions, DedupAction, ExportOvpackOptions,
+    ImportOvpackOptions, ReconcileOptions, SearchRequest, SearchScopeSet, WatchOptions,
+};
+use axiomsync::{AxiomSync, AxiomSyncWorkspace};
 
-use crate::cli::{AddWaitModeArg, Commands, DocumentMode, QueueCommand};
+use crate::cli::{
+    AddDedupActionArg, AddWaitModeArg, Commands, DocumentMode, QueueCommand,
+    QueueDeadLetterCommand, SearchArgs, SearchCommand,
+};
 
 mod handlers;
 mod ontology;
@@ -15,19 +21,23 @@ mod validation;
 mod web;
 
 use self::handlers::{
-    handle_benchmark, handle_eval, handle_relation, handle_release, handle_security,
-    handle_session, handle_trace,
+    handle_benchmark, handle_eval, handle_maintenance, handle_relation, handle_release,
+    handle_search_profile, handle_security, handle_session, handle_tag, handle_trace, handle_trash,
+    run_add_watch,
 };
 use self::ontology::handle_ontology_command;
-use self::queue::{run_queue_daemon, run_queue_worker};
+use self::queue::{QueueReplayBatch, run_queue_daemon, run_queue_worker};
 use self::support::{
-    build_add_ingest_options, build_metadata_filter, parse_runtime_hints, parse_scope_args,
-    parse_search_budget, parse_search_request_file, print_json, read_document_content,
-    read_preview_content,
+    batch_search_items_json, build_add_ingest_options, build_metadata_filter,
+    parse_exclude_scope_args, parse_runtime_hints, parse_scope_args, parse_search_budget,
+    parse_search_queries_file, parse_search_request_file, print_json, read_document_content,
+    read_markdown_patch_ops, read_preview_content, workspace_search_items_json,
 };
 use self::validation::{apply_bootstrap_mode, resolve_bootstrap_mode, validate_command_preflight};
 use self::web::{WebServeOptions, serve};
 
+const DEFAULT_READ_RANGE_LENGTH: usize = 64 * 1024;
+
 pub(crate) fn run_from_root(root: &Path, command: Commands) -> Result<()> {
     validate_command_preflight(&command)?;
 
@@ -39,6 +49,34 @@ pub(crate) fn run_from_root(root: &Path, command: Commands) -> Result<()> {
     run_validated(&app, root, command)
 }
 
+/// `--workspace-config` entry point: runs normally on `--workspace-root`, else fans out `search` only.
+pub(crate) fn run_from_workspace(
+    config: &Path,
+    root_name: Option<&str>,
+    command: Commands,
+) -> Result<()> {
+    let roots = AxiomSyncWorkspace::roots_from_config_file(config)
+        .with_context(|| format!("failed to read workspace config {}", config.display()))?;
+    if let Some(name) = root_name {
+        let Some((_, root)) = roots.iter().find(|(candidate, _)| candidate == name) else {
+            anyhow::bail!(
+                "workspace root '{name}' is not defined in {}",
+                config.display()
+            );
+        };
+        return run_from_root(root, command);
+    }
+
+    validate_command_preflight(&command)?;
+    let Commands::Search(args) = command else {
+        anyhow::bail!(
+            "only `search` fans out across workspace roots; pass --workspace-root <name> for other commands"
+        );
+    };
+    let workspace = AxiomSyncWorkspace::open(roots).context("failed to open workspace")?;
+    run_workspace_search(&workspace, *args)
+}
+
 fn run_validated(app: &AxiomSync, root: &Path, command: Commands) -> Result<()> {
     if !matches!(&command, Commands::Web(_)) {
         let mode = resolve_bootstrap_mode(app, &command);
@@ -52,6 +90,15 @@ fn run_validated(app: &AxiomSync, root: &Path, command: Commands) -> Result<()>
         Commands::Add(args) => {
             let ingest_options =
                 build_add_ingest_options(args.markdown_only, args.include_hidden, &args.exclude)?;
+            if args.watch {
+                let options = WatchOptions {
+                    interval_ms: args.interval_secs.saturating_mul(1_000),
+                    ingest_options,
+                    process_queue: args.wait,
+                    max_cycles: None,
+                };
+                return run_add_watch(app, &args.source, args.target.as_deref(), options);
+            }
             let mut request = AddResourceRequest::new(args.source.clone());
             request.target = args.target.clone();
             request.wait = args.wait;
@@ -60,19 +107,39 @@ fn run_validated(app: &AxiomSync, root: &Path, command: Commands) -> Result<()>
                 AddWaitModeArg::Strict => AddResourceWaitMode::Strict,
             };
             request.ingest_options = ingest_options;
+            request.ingest_options.dedup_by_content = args.dedup_by_content;
+            request.ingest_options.dedup_action = match args.dedup_action {
+                AddDedupActionArg::Skip => DedupAction::Skip,
+                AddDedupActionArg::Link => DedupAction::Link,
+            };
+            request.tags = args.tags.clone();
             let result = app.add_resource_with_ingest_options(request)?;
             print_json(&result)?;
         }
         Commands::Ls(args) => {
-            let entries = app.ls(&args.uri, args.recursive, false)?;
-            print_json(&entries)?;
+            if let Some(limit) = args.limit {
+                let page = app.ls_page(&args.uri, args.recursive, args.offset, limit)?;
+                print_json(&page)?;
+            } else {
+                let entries = app.ls(&args.uri, args.recursive, false)?;
+                print_json(&entries)?;
+            }
         }
         Commands::Glob(args) => {
             let result = app.glob(&args.pattern, args.uri.as_deref())?;
             print_json(&result)?;
         }
         Commands::Read(args) => {
-            println!("{}", app.read(&args.uri)?);
+            if args.offset.is_none() && args.length.is_none() {
+                println!("{}", app.read(&args.uri)?);
+            } else {
+                let chunk = app.read_range(
+                    &args.uri,
+                    args.offset.unwrap_or(0),
+                    args.length.unwrap_or(DEFAULT_READ_RANGE_LENGTH),
+                )?;
+                print_json(&chunk)?;
+            }
         }
         Commands::Abstract(args) => {
             println!("{}", app.abstract_text(&args.uri)?);
@@ -81,19 +148,47 @@ fn run_validated(app: &AxiomSync, root: &Path, command: Commands) -> Result<()>
             println!("{}", app.overview(&args.uri)?);
         }
         Commands::Mkdir(args) => {
-            app.mkdir(&args.uri)?;
+            app.mkdir_with_parents(&args.uri, !args.no_parents)?;
             print_json(&serde_json::json!({
                 "status": "ok",
                 "uri": args.uri,
+                "parents": !args.no_parents,
             }))?;
         }
         Commands::Rm(args) => {
-            app.rm(&args.uri, args.recursive)?;
-            print_json(&serde_json::json!({
-                "status": "ok",
-                "uri": args.uri,
-                "recursive": args.recursive,
-            }))?;
+            if let Some(pattern) = args.glob.as_deref() {
+                let report = app.rm_glob(
+                    pattern,
+                    args.glob_base_uri.as_deref(),
+                    args.dry_run,
+                    args.permanent,
+                )?;
+                print_json(&report)?;
+            } else {
+                let Some(uri) = args.uri else {
+                    anyhow::bail!("rm requires a uri or --glob");
+                };
+                let trash_uri = if args.permanent {
+                    app.rm_permanent(&uri, args.recursive)?;
+                    None
+                } else {
+                    app.trash(&uri, args.recursive)?
+                        .map(|entry| entry.trash_uri)
+                };
+                print_json(&serde_json::json!({
+                    "status": "ok",
+                    "uri": uri,
+                    "recursive": args.recursive,
+                    "permanent": args.permanent,
+                    "trash_uri": trash_uri,
+                }))?;
+            }
+        }
+        Commands::Trash(args) => {
+            handle_trash(app, args.command)?;
+        }
+        Commands::Tag(args) => {
+            handle_tag(app, args.command)?;
         }
         Commands::Mv(args) => {
             app.mv(&args.from_uri, &args.to_uri)?;
@@ -107,6 +202,10 @@ fn run_validated(app: &AxiomSync, root: &Path, command: Commands) -> Result<()>
             let tree = app.tree(&args.uri)?;
             print_json(&tree)?;
         }
+        Commands::Stat(args) => {
+            let stat = app.stat(&args.uri)?;
+            print_json(&stat)?;
+        }
         Commands::Document(args) => match args.command {
             crate::cli::DocumentCommand::Load { uri, mode } => {
                 let document = match mode {
@@ -143,10 +242,35 @@ fn run_validated(app: &AxiomSync, root: &Path, command: Commands) -> Result<()>
                 };
                 print_json(&saved)?;
             }
+            crate::cli::DocumentCommand::Append {
+                uri,
+                content,
+                from,
+                stdin,
+            } => {
+                let content = read_document_content(content, from, stdin)?;
+                let saved = app.append_markdown(&uri, &content)?;
+                print_json(&saved)?;
+            }
+            crate::cli::DocumentCommand::Patch {
+                uri,
+                ops_json,
+                from,
+                stdin,
+                expected_etag,
+            } => {
+                let ops = read_markdown_patch_ops(ops_json, from, stdin)?;
+                let patched = app.patch_markdown(&uri, ops, expected_etag.as_deref())?;
+                print_json(&patched)?;
+            }
         },
         Commands::Find(args) => {
             let budget = parse_search_budget(args.budget_ms, args.budget_nodes, args.budget_depth);
-            let filter = build_metadata_filter(&args.tags, args.mime.as_deref())?;
+            let filter = build_metadata_filter(
+                &args.tags,
+                args.mime.as_deref(),
+                args.filter_json.as_deref(),
+            )?;
             let result = app.find_with_budget(
                 &args.query,
                 args.target.as_deref(),
@@ -157,63 +281,7 @@ fn run_validated(app: &AxiomSync, root: &Path, command: Commands) -> Result<()>
             )?;
             print_json(&result)?;
         }
-        Commands::Search(args) => {
-            let budget = parse_search_budget(args.budget_ms, args.budget_nodes, args.budget_depth);
-            let cli_filter = build_metadata_filter(&args.tags, args.mime.as_deref())?;
-            let cli_hints = parse_runtime_hints(&args.hints, args.hint_file.as_deref())?;
-
-            let mut request = if let Some(path) = args.request_json.as_deref() {
-                parse_search_request_file(path)?
-            } else {
-                SearchRequest {
-                    query: String::new(),
-                    target_uri: None,
-                    session: None,
-                    limit: None,
-                    score_threshold: None,
-                    min_match_tokens: None,
-                    filter: None,
-                    budget: None,
-                    runtime_hints: Vec::new(),
-                }
-            };
-
-            if let Some(query) = args.query {
-                request.query = query;
-            }
-            if request.query.trim().is_empty() {
-                anyhow::bail!("search query is required unless --request-json provides query");
-            }
-            if let Some(target) = args.target {
-                request.target_uri = Some(target);
-            }
-            if let Some(session) = args.session {
-                request.session = Some(session);
-            }
-            if let Some(limit) = args.limit {
-                request.limit = Some(limit);
-            } else if request.limit.is_none() {
-                request.limit = Some(10);
-            }
-            if args.score_threshold.is_some() {
-                request.score_threshold = args.score_threshold;
-            }
-            if args.min_match_tokens.is_some() {
-                request.min_match_tokens = args.min_match_tokens;
-            }
-            if budget.is_some() {
-                request.budget = budget;
-            }
-            if cli_filter.is_some() {
-                request.filter = cli_filter;
-            }
-            if !cli_hints.is_empty() {
-                request.runtime_hints.extend(cli_hints);
-            }
-
-            let result = app.search_with_request(request)?;
-            print_json(&result)?;
-        }
+        Commands::Search(args) => run_search(app, *args)?,
         Commands::Backend => {
             let status = app.backend_status()?;
             print_json(&status)?;
@@ -241,14 +309,18 @@ fn run_validated(app: &AxiomSync, root: &Path, command: Commands) -> Result<()>
                 sleep_ms,
                 include_dead_letter,
                 stop_when_idle,
+                workers,
             } => {
                 let report = run_queue_worker(
                     app,
                     iterations,
-                    limit,
                     sleep_ms,
-                    include_dead_letter,
                     stop_when_idle,
+                    QueueReplayBatch {
+                        limit,
+                        include_dead_letter,
+                        workers,
+                    },
                 )?;
                 print_json(&report)?;
             }
@@ -259,18 +331,55 @@ fn run_validated(app: &AxiomSync, root: &Path, command: Commands) -> Result<()>
                 include_dead_letter,
                 stop_when_idle,
                 idle_cycles,
+                workers,
             } => {
                 let report = run_queue_daemon(
                     app,
                     max_cycles,
-                    limit,
                     sleep_ms,
-                    include_dead_letter,
                     stop_when_idle,
                     idle_cycles,
+                    QueueReplayBatch {
+                        limit,
+                        include_dead_letter,
+                        workers,
+                    },
                 )?;
                 print_json(&report)?;
             }
+            QueueCommand::Show { event_id } => {
+                let Some(event) = app.get_queue_event(event_id)? else {
+                    anyhow::bail!("queue event not found: {event_id}");
+                };
+                print_json(&event)?;
+            }
+            QueueCommand::Requeue { event_id } => {
+                let requeued = app.requeue_event(event_id)?;
+                print_json(&serde_json::json!({
+                    "event_id": event_id,
+                    "requeued": requeued,
+                }))?;
+            }
+            QueueCommand::Purge { event_id } => {
+                let purged = app.purge_event(event_id)?;
+                print_json(&serde_json::json!({
+                    "event_id": event_id,
+                    "purged": purged,
+                }))?;
+            }
+            QueueCommand::DeadLetter { command } => match command {
+                QueueDeadLetterCommand::List { limit } => {
+                    let events = app.list_dead_letter(limit)?;
+                    print_json(&events)?;
+                }
+                QueueDeadLetterCommand::Requeue { event_id } => {
+                    let requeued = app.requeue_dead_letter(event_id)?;
+                    print_json(&serde_json::json!({
+                        "event_id": event_id,
+                        "requeued": requeued,
+                    }))?;
+                }
+            },
             QueueCommand::Evidence {
                 replay_limit,
                 max_cycles,
@@ -310,18 +419,54 @@ fn run_validated(app: &AxiomSync, root: &Path, command: Commands) -> Result<()>
                 dry_run: args.dry_run,
                 scopes,
                 max_drift_sample: args.max_drift_sample,
+                fix_duplicates: args.fix,
+                check_vectors: args.check_vectors,
+                check_relations: args.check_relations,
             })?;
             print_json(&report)?;
         }
+        Commands::Compact(args) => {
+            let report = app.compact(CompactOptions {
+                dry_run: args.dry_run,
+                trace_retention_days: args.traces_days,
+                request_log_max_rows: args.request_log_max_rows,
+                report_retention_days: args.reports_days,
+                reports_keep: args.reports_keep,
+            })?;
+            print_json(&report)?;
+        }
+        Commands::Backup(args) => {
+            print_json(&app.backup_state(&args.path)?)?;
+        }
+        Commands::Maintenance(args) => {
+            handle_maintenance(app, args.command)?;
+        }
         Commands::Session(args) => {
             handle_session(app, args.command)?;
         }
         Commands::ExportOvpack(args) => {
-            let out = app.export_ovpack(&args.uri, &args.to)?;
+            let out = app.export_ovpack_with_options(
+                &args.uri,
+                &args.to,
+                &ExportOvpackOptions {
+                    exclude_globs: args.exclude_globs,
+                    include_globs: args.include_globs,
+                    skip_tier_files: args.skip_tiers,
+                },
+            )?;
             println!("{out}");
         }
         Commands::ImportOvpack(args) => {
-            let out = app.import_ovpack(&args.file, &args.parent, args.force, args.vectorize)?;
+            let out = app.import_ovpack_with_options(
+                &args.file,
+                &args.parent,
+                ImportOvpackOptions {
+                    force: args.force,
+                    vectorize: args.vectorize,
+                    verify: !args.no_verify,
+                    as_name: args.as_name,
+                },
+            )?;
             println!("{out}");
         }
         Commands::Web(args) => {
@@ -332,6 +477,148 @@ fn run_validated(app: &AxiomSync, root: &Path, command: Commands) -> Result<()>
     Ok(())
 }
 
+fn run_search(app: &AxiomSync, args: SearchArgs) -> Result<()> {
+    if let Some(SearchCommand::Profile { command }) = args.command {
+        return handle_search_profile(app, command);
+    }
+    if let Some(path) = args.queries_file.as_deref() {
+        let requests = parse_search_queries_file(path)?;
+        let items = app.search_batch(requests)?;
+        return print_json(&batch_search_items_json(&items)?);
+    }
+
+    let profile = args.profile.clone();
+    let (explain, markdown, cite) = (args.explain, args.markdown, args.cite);
+    let mut request = search_request_from_args(args)?;
+    if let Some(profile) = profile.as_deref() {
+        request = app.apply_search_profile(profile, request)?;
+    }
+    if request.limit.is_none() {
+        request.limit = Some(10);
+    }
+    if explain {
+        return print_json(&app.explain_search(request)?);
+    }
+
+    let result = app.search_with_request(request)?;
+    if markdown {
+        print!("{}", axiomsync::export_search_result_markdown(&result));
+    } else if cite {
+        print!("{}", axiomsync::format_citations(&result));
+    } else {
+        print_json(&result)?;
+    }
+    Ok(())
+}
+
+/// Sends one query to every root; profile, batch, explain and Markdown/citation output need `--workspace-root`.
+fn run_workspace_search(workspace: &AxiomSyncWorkspace, args: SearchArgs) -> Result<()> {
+    if args.command.is_some()
+        || args.queries_file.is_some()
+        || args.profile.is_some()
+        || args.explain
+        || args.markdown
+        || args.cite
+    {
+        anyhow::bail!(
+            "workspace search fans out plain queries only; pass --workspace-root <name> for profiles, batches, --explain, --markdown or --cite"
+        );
+    }
+    let mut request = search_request_from_args(args)?;
+    if request.limit.is_none() {
+        request.limit = Some(10);
+    }
+    let items = workspace.search_all(&request);
+    print_json(&workspace_search_items_json(workspace, &items)?)
+}
+
+fn search_request_from_args(args: SearchArgs) -> Result<SearchRequest> {
+    let budget = parse_search_budget(args.budget_ms, args.budget_nodes, args.budget_depth);
+    let cli_filter = build_metadata_filter(
+        &args.tags,
+        args.mime.as_deref(),
+        args.filter_json.as_deref(),
+    )?;
+    let cli_hints = parse_runtime_hints(&args.hints, args.hint_file.as_deref())?;
+    let cli_exclude_scopes = parse_exclude_scope_args(&args.exclude_scopes)?;
+
+    let mut request = if let Some(path) = args.request_json.as_deref() {
+        parse_search_request_file(path)?
+    } else {
+        SearchRequest {
+            query: String::new(),
+            target_uri: None,
+            target_uris: Vec::new(),
+            session: None,
+            limit: None,
+            score_threshold: None,
+            min_match_tokens: None,
+            filter: None,
+            budget: None,
+            runtime_hints: Vec::new(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            relation_hops: None,
+            snippet_highlight: None,
+            offset: None,
+            cursor: None,
+            paginate: false,
+        }
+    };
+
+    if let Some(query) = args.query {
+        request.query = query;
+    }
+    if request.query.trim().is_empty() {
+        anyhow::bail!("search query is required unless --request-json provides query");
+    }
+    let mut cli_targets = args.targets.into_iter();
+    if let Some(target) = cli_targets.next() {
+        request.target_uri = Some(target);
+        request.target_uris = cli_targets.collect();
+    }
+    if let Some(session) = args.session {
+        request.session = Some(session);
+    }
+    if let Some(limit) = args.limit {
+        request.limit = Some(limit);
+    }
+    if args.score_threshold.is_some() {
+        request.score_threshold = args.score_threshold;
+    }
+    if args.min_match_tokens.is_some() {
+        request.min_match_tokens = args.min_match_tokens;
+    }
+    if budget.is_some() {
+        request.budget = budget;
+    }
+    if cli_filter.is_some() {
+        request.filter = cli_filter;
+    }
+    if !cli_hints.is_empty() {
+        request.runtime_hints.extend(cli_hints);
+    }
+    if !cli_exclude_scopes.is_empty() {
+        request.exclude_scopes = cli_exclude_scopes;
+    }
+    if args.relation_hops.is_some() {
+        request.relation_hops = args.relation_hops;
+    }
+    if args.highlight.is_some() {
+        request.snippet_highlight = args.highlight;
+    }
+    if args.offset.is_some() {
+        request.offset = args.offset;
+    }
+    if args.cursor.is_some() {
+        request.cursor = args.cursor;
+    }
+    if args.paginate {
+        request.paginate = true;
+    }
+    Ok(request)
+}
+
 fn run_web_handoff(root: &Path, host: &str, port: u16) -> Result<()> {
     serve(root, WebServeOptions { host, port })
 }
@@ -85,6 +85,7 @@ pub(super) fn handle_ontology_command(app: &AxiomSync, command: OntologyCommand)
             input_json,
             input_file,
             input_stdin,
+            coerce,
         } => {
             let uri =
                 uri.unwrap_or_else(|| axiomsync::ontology::ONTOLOGY_SCHEMA_URI_V1.to_string());
@@ -97,7 +98,11 @@ pub(super) fn handle_ontology_command(app: &AxiomSync, command: OntologyCommand)
                 queue_event_type,
                 input,
             };
-            let report = axiomsync::ontology::validate_action_request(&compiled, &request)?;
+            let report = axiomsync::ontology::validate_action_request_with_options(
+                &compiled,
+                &request,
+                axiomsync::ontology::OntologyActionValidationOptions { coerce },
+            )?;
             print_json(&serde_json::json!({
                 "status": "ok",
                 "uri": uri,
@@ -150,6 +155,27 @@ pub(super) fn handle_ontology_command(app: &AxiomSync, command: OntologyCommand)
                 );
             }
         }
+        OntologyCommand::Diff { from, to } => {
+            let old = axiomsync::ontology::parse_schema_v1(&app.read(&from)?)?;
+            let new = axiomsync::ontology::parse_schema_v1(&app.read(&to)?)?;
+            let diff = axiomsync::ontology::diff_schemas(&old, &new);
+            print_json(&serde_json::json!({
+                "status": "ok",
+                "from": from,
+                "to": to,
+                "changed": !diff.is_empty(),
+                "diff": diff,
+            }))?;
+        }
+        OntologyCommand::ExportJsonSchema { uri, action_id } => {
+            let uri =
+                uri.unwrap_or_else(|| axiomsync::ontology::ONTOLOGY_SCHEMA_URI_V1.to_string());
+            let raw = app.read(&uri)?;
+            let parsed = axiomsync::ontology::parse_schema_v1(&raw)?;
+            let compiled = axiomsync::ontology::compile_schema(parsed)?;
+            let document = axiomsync::ontology::export_json_schema(&compiled, &action_id)?;
+            print_json(&document)?;
+        }
     }
     Ok(())
 }
@@ -16,6 +16,7 @@ enum QueueRunMode {
 #[derive(Debug, serde::Serialize)]
 pub(super) struct QueueWorkReport {
     mode: QueueRunMode,
+    workers: usize,
     iterations: u32,
     fetched: usize,
     processed: usize,
@@ -26,9 +27,10 @@ pub(super) struct QueueWorkReport {
 }
 
 impl QueueWorkReport {
-    const fn new(mode: QueueRunMode) -> Self {
+    const fn new(mode: QueueRunMode, workers: usize) -> Self {
         Self {
             mode,
+            workers,
             iterations: 0,
             fetched: 0,
             processed: 0,
@@ -49,17 +51,29 @@ impl QueueWorkReport {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub(super) struct QueueReplayBatch {
+    pub(super) limit: usize,
+    pub(super) include_dead_letter: bool,
+    pub(super) workers: usize,
+}
+
+impl QueueReplayBatch {
+    fn replay(self, app: &AxiomSync) -> axiomsync::Result<ReplayReport> {
+        app.replay_outbox_parallel(self.limit, self.include_dead_letter, self.workers)
+    }
+}
+
 pub(super) fn run_queue_worker(
     app: &AxiomSync,
     iterations: u32,
-    limit: usize,
     sleep_ms: u64,
-    include_dead_letter: bool,
     stop_when_idle: bool,
+    batch: QueueReplayBatch,
 ) -> Result<QueueWorkReport> {
-    let mut total = QueueWorkReport::new(QueueRunMode::Work);
+    let mut total = QueueWorkReport::new(QueueRunMode::Work, batch.workers);
     for i in 0..iterations {
-        let report = app.replay_outbox(limit, include_dead_letter)?;
+        let report = batch.replay(app)?;
         total.iterations = i + 1;
         total.absorb_replay(&report);
 
@@ -76,13 +90,12 @@ pub(super) fn run_queue_worker(
 pub(super) fn run_queue_daemon(
     app: &AxiomSync,
     max_cycles: u32,
-    limit: usize,
     sleep_ms: u64,
-    include_dead_letter: bool,
     stop_when_idle: bool,
     idle_cycles: u32,
+    batch: QueueReplayBatch,
 ) -> Result<QueueWorkReport> {
-    let mut total = QueueWorkReport::new(QueueRunMode::Daemon);
+    let mut total = QueueWorkReport::new(QueueRunMode::Daemon, batch.workers);
     let max_cycles = non_zero_u32(max_cycles);
     let mut idle_streak = 0u32;
     let mut cycle = 0u32;
@@ -95,7 +108,7 @@ pub(super) fn run_queue_daemon(
         }
         cycle += 1;
 
-        let report = app.replay_outbox(limit, include_dead_letter)?;
+        let report = batch.replay(app)?;
         total.iterations = cycle;
         total.absorb_replay(&report);
 
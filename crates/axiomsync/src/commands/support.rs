@@ -2,12 +2,12 @@ use std::io::{Read, Write};
 use std::path::Path;
 use std::{fs, io};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use axiomsync::models::{
-    AddResourceIngestOptions, MetadataFilter, RuntimeHint, RuntimeHintKind, SearchBudget,
-    SearchRequest,
+    AddResourceIngestOptions, BatchSearchItem, MarkdownPatchOp, MetadataFilter, RuntimeHint,
+    RuntimeHintKind, SearchBudget, SearchRequest, WorkspaceSearchItem,
 };
-use axiomsync::{AxiomSync, Scope};
+use axiomsync::{AxiomSync, AxiomSyncWorkspace, Scope};
 
 pub(super) fn print_json<T: serde::Serialize>(value: &T) -> Result<()> {
     let mut stdout = io::stdout().lock();
@@ -20,15 +20,22 @@ pub(super) fn parse_scope_args(values: &[String]) -> Result<Option<Vec<Scope>>>
     if values.is_empty() {
         return Ok(None);
     }
+    parse_scope_flag_values("--scope", values).map(Some)
+}
+
+pub(super) fn parse_exclude_scope_args(values: &[String]) -> Result<Vec<Scope>> {
+    parse_scope_flag_values("--exclude-scope", values)
+}
 
+fn parse_scope_flag_values(flag: &str, values: &[String]) -> Result<Vec<Scope>> {
     let mut scopes = Vec::new();
     for raw in values {
         let scope = raw
             .parse::<Scope>()
-            .map_err(|e| anyhow::anyhow!("invalid --scope value '{raw}': {e}"))?;
+            .map_err(|e| anyhow::anyhow!("invalid {flag} value '{raw}': {e}"))?;
         scopes.push(scope);
     }
-    Ok(Some(scopes))
+    Ok(scopes)
 }
 
 pub(super) fn build_add_ingest_options(
@@ -89,6 +96,7 @@ pub(super) const fn parse_search_budget(
 pub(super) fn build_metadata_filter(
     tags: &[String],
     mime: Option<&str>,
+    filter_json: Option<&str>,
 ) -> Result<Option<MetadataFilter>> {
     let normalized_tags = tags
         .iter()
@@ -98,17 +106,22 @@ pub(super) fn build_metadata_filter(
         .collect::<Vec<_>>();
     let normalized_mime = mime.map(str::trim).filter(|value| !value.is_empty());
 
-    if normalized_tags.is_empty() && normalized_mime.is_none() {
-        return Ok(None);
-    }
-
-    let mut fields = std::collections::HashMap::new();
+    let mut fields = match filter_json.map(str::trim).filter(|raw| !raw.is_empty()) {
+        Some(raw) => {
+            serde_json::from_str::<std::collections::HashMap<String, serde_json::Value>>(raw)
+                .map_err(|err| anyhow::anyhow!("invalid --filter-json object: {err}"))?
+        }
+        None => std::collections::HashMap::new(),
+    };
     if !normalized_tags.is_empty() {
         fields.insert("tags".to_string(), serde_json::json!(normalized_tags));
     }
     if let Some(value) = normalized_mime {
         fields.insert("mime".to_string(), serde_json::json!(value));
     }
+    if fields.is_empty() {
+        return Ok(None);
+    }
     Ok(Some(MetadataFilter { fields }))
 }
 
@@ -123,6 +136,83 @@ pub(super) fn parse_search_request_file(path: &Path) -> Result<SearchRequest> {
     Ok(request)
 }
 
+pub(super) fn parse_search_queries_file(path: &Path) -> Result<Vec<SearchRequest>> {
+    let raw = fs::read_to_string(path)?;
+    let mut requests = Vec::new();
+    for (idx, line) in raw.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let request = serde_json::from_str::<SearchRequest>(trimmed).map_err(|err| {
+            anyhow::anyhow!(
+                "invalid --queries-file line {} at {}: {err}",
+                idx + 1,
+                path.to_string_lossy()
+            )
+        })?;
+        requests.push(request);
+    }
+    if requests.is_empty() {
+        anyhow::bail!(
+            "--queries-file contains no search requests: {}",
+            path.to_string_lossy()
+        );
+    }
+    Ok(requests)
+}
+
+pub(super) fn batch_search_items_json(items: &[BatchSearchItem]) -> Result<serde_json::Value> {
+    let mut out = Vec::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        out.push(match &item.result {
+            Ok(result) => serde_json::json!({
+                "index": index,
+                "status": "ok",
+                "result": serde_json::to_value(result)?,
+            }),
+            Err(err) => serde_json::json!({
+                "index": index,
+                "status": "error",
+                "error": err.to_payload("search", None),
+            }),
+        });
+    }
+    Ok(serde_json::Value::Array(out))
+}
+
+pub(super) fn workspace_search_items_json(
+    workspace: &AxiomSyncWorkspace,
+    items: &[WorkspaceSearchItem],
+) -> Result<serde_json::Value> {
+    let mut roots = Vec::with_capacity(items.len());
+    for item in items {
+        roots.push(match &item.result {
+            Ok(result) => serde_json::json!({
+                "root": item.root,
+                "status": "ok",
+                "result": serde_json::to_value(result)?,
+            }),
+            Err(err) => serde_json::json!({
+                "root": item.root,
+                "status": "error",
+                "error": err.to_payload("search", None),
+            }),
+        });
+    }
+    let failed_roots = workspace
+        .failures()
+        .iter()
+        .map(|(root, err)| {
+            serde_json::json!({
+                "root": root,
+                "error": err.to_payload("workspace.open", None),
+            })
+        })
+        .collect::<Vec<_>>();
+    Ok(serde_json::json!({ "roots": roots, "failed_roots": failed_roots }))
+}
+
 pub(super) fn parse_runtime_hints(
     hints: &[String],
     hint_file: Option<&Path>,
@@ -246,6 +336,25 @@ pub(super) fn read_document_content(
     Ok(buffer)
 }
 
+pub(super) fn read_markdown_patch_ops(
+    inline: Option<String>,
+    from: Option<std::path::PathBuf>,
+    stdin: bool,
+) -> Result<Vec<MarkdownPatchOp>> {
+    validate_document_patch_source_selection(inline.as_deref(), from.as_deref(), stdin)?;
+
+    let raw = if let Some(raw) = inline {
+        raw
+    } else if let Some(path) = from {
+        fs::read_to_string(path)?
+    } else {
+        let mut buffer = String::new();
+        io::stdin().read_to_string(&mut buffer)?;
+        buffer
+    };
+    serde_json::from_str(&raw).context("invalid document patch ops json")
+}
+
 pub(super) fn read_preview_content(
     app: &AxiomSync,
     uri: Option<String>,
@@ -290,6 +399,20 @@ pub(super) fn validate_document_save_source_selection(
     )
 }
 
+pub(super) fn validate_document_patch_source_selection(
+    inline: Option<&str>,
+    from: Option<&Path>,
+    stdin: bool,
+) -> Result<()> {
+    let selected =
+        bool_to_count(inline.is_some()) + bool_to_count(from.is_some()) + bool_to_count(stdin);
+    ensure_single_source_selection(
+        selected,
+        "document patch ops source is required: use one of --ops-json, --from <path>, --stdin",
+        "document patch accepts exactly one ops source: choose one of --ops-json, --from, --stdin",
+    )
+}
+
 pub(super) fn validate_document_preview_source_selection(
     uri: Option<&str>,
     inline: Option<&str>,
@@ -330,12 +453,13 @@ mod tests {
     use tempfile::tempdir;
 
     use super::{
-        build_metadata_filter, parse_runtime_hints, parse_search_budget, parse_search_request_file,
+        build_metadata_filter, parse_runtime_hints, parse_search_budget, parse_search_queries_file,
+        parse_search_request_file,
     };
 
     #[test]
     fn build_metadata_filter_returns_none_for_empty_inputs() {
-        let filter = build_metadata_filter(&[], None).expect("filter");
+        let filter = build_metadata_filter(&[], None, None).expect("filter");
         assert!(filter.is_none());
     }
 
@@ -344,6 +468,7 @@ mod tests {
         let filter = build_metadata_filter(
             &["markdown".to_string(), "auth".to_string()],
             Some("text/markdown"),
+            None,
         )
         .expect("filter")
         .expect("filter payload");
@@ -357,6 +482,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_metadata_filter_merges_filter_json_with_flags() {
+        let filter = build_metadata_filter(
+            &[],
+            Some("text/markdown"),
+            Some(r#"{"tags":{"not":["archived"]},"mtime":{"gte":"2024-06-01T00:00:00Z"}}"#),
+        )
+        .expect("filter")
+        .expect("filter payload");
+        assert_eq!(
+            filter.fields.get("tags"),
+            Some(&serde_json::json!({"not": ["archived"]}))
+        );
+        assert_eq!(
+            filter.fields.get("mime"),
+            Some(&serde_json::json!("text/markdown"))
+        );
+        assert!(filter.fields.contains_key("mtime"));
+
+        let err = build_metadata_filter(&[], None, Some("[1,2]")).expect_err("array rejected");
+        assert!(err.to_string().contains("--filter-json"));
+    }
+
     #[test]
     fn parse_runtime_hints_reads_cli_and_file_values() {
         let temp = tempdir().expect("tempdir");
@@ -392,6 +540,26 @@ mod tests {
         assert_eq!(request.runtime_hints.len(), 1);
     }
 
+    #[test]
+    fn parse_search_queries_file_reads_jsonl_and_skips_blank_lines() {
+        let temp = tempdir().expect("tempdir");
+        let file_path = temp.path().join("queries.jsonl");
+        std::fs::write(
+            &file_path,
+            "{\"query\":\"oauth\",\"limit\":3}\n\n# comment\n{\"query\":\"refresh\",\"session\":\"s-1\"}\n",
+        )
+        .expect("write");
+        let requests = parse_search_queries_file(file_path.as_path()).expect("requests");
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].query, "oauth");
+        assert_eq!(requests[0].limit, Some(3));
+        assert_eq!(requests[1].session.as_deref(), Some("s-1"));
+
+        std::fs::write(&file_path, "{\"query\":\"ok\"}\nnot-json\n").expect("write");
+        let err = parse_search_queries_file(file_path.as_path()).expect_err("invalid line");
+        assert!(err.to_string().contains("line 2"));
+    }
+
     #[test]
     fn parse_search_budget_none_when_unset() {
         assert!(parse_search_budget(None, None, None).is_none());
@@ -5,10 +5,10 @@ use tempfile::tempdir;
 
 use super::validation::command_needs_runtime;
 use crate::cli::{
-    AddArgs, AddWaitModeArg, BenchmarkArgs, BenchmarkCommand, Commands, DocumentArgs,
-    DocumentCommand, DocumentMode, EvalArgs, EvalCommand, FindArgs, OntologyArgs, OntologyCommand,
-    QueueArgs, QueueCommand, ReconcileArgs, RelationArgs, RelationCommand, TraceArgs, TraceCommand,
-    WebArgs,
+    AddArgs, AddDedupActionArg, AddWaitModeArg, BenchmarkArgs, BenchmarkCommand, Commands,
+    DocumentArgs, DocumentCommand, DocumentMode, EvalArgs, EvalCommand, FindArgs, OntologyArgs,
+    OntologyCommand, QueueArgs, QueueCommand, ReconcileArgs, RelationArgs, RelationCommand,
+    RemoveArgs, TraceArgs, TraceCommand, WebArgs,
 };
 use axiomsync::AxiomSync;
 use axiomsync::models::QueueEventStatus;
@@ -125,6 +125,7 @@ fn find_requires_runtime_prepare() {
         budget_ms: None,
         budget_nodes: None,
         budget_depth: None,
+        filter_json: None,
     });
     assert!(command_needs_runtime(&command));
 }
@@ -164,6 +165,11 @@ fn backend_runs_runtime_prepare_and_reflects_local_records() {
             include_hidden: false,
             exclude: Vec::new(),
             wait_mode: AddWaitModeArg::Relaxed,
+            watch: false,
+            interval_secs: 5,
+            dedup_by_content: false,
+            dedup_action: AddDedupActionArg::Skip,
+            tags: Vec::new(),
         }),
     )
     .expect("add");
@@ -181,9 +187,9 @@ fn search_runs_runtime_prepare_for_memory_backend() {
     let temp = tempdir().expect("tempdir");
     let app = AxiomSync::new(temp.path()).expect("app");
 
-    let command = Commands::Search(crate::cli::SearchArgs {
+    let command = Commands::Search(Box::new(crate::cli::SearchArgs {
         query: Some("oauth".to_string()),
-        target: Some("axiom://resources".to_string()),
+        targets: vec!["axiom://resources".to_string()],
         session: None,
         limit: Some(5),
         tags: Vec::new(),
@@ -196,7 +202,20 @@ fn search_runs_runtime_prepare_for_memory_backend() {
         budget_ms: None,
         budget_nodes: None,
         budget_depth: None,
-    });
+        exclude_scopes: Vec::new(),
+        relation_hops: None,
+        highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+        profile: None,
+        command: None,
+        explain: false,
+        markdown: false,
+        cite: false,
+        queries_file: None,
+        filter_json: None,
+    }));
     run(&app, temp.path(), command).expect("search");
 
     assert!(
@@ -210,9 +229,9 @@ fn search_preflight_requires_query_or_request_json() {
     let temp = tempdir().expect("tempdir");
     let app = AxiomSync::new(temp.path()).expect("app");
 
-    let command = Commands::Search(crate::cli::SearchArgs {
+    let command = Commands::Search(Box::new(crate::cli::SearchArgs {
         query: None,
-        target: None,
+        targets: Vec::new(),
         session: None,
         limit: None,
         tags: Vec::new(),
@@ -225,7 +244,20 @@ fn search_preflight_requires_query_or_request_json() {
         budget_ms: None,
         budget_nodes: None,
         budget_depth: None,
-    });
+        exclude_scopes: Vec::new(),
+        relation_hops: None,
+        highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+        profile: None,
+        command: None,
+        explain: false,
+        markdown: false,
+        cite: false,
+        queries_file: None,
+        filter_json: None,
+    }));
     let err = run(&app, temp.path(), command).expect_err("must reject empty query");
     assert!(
         format!("{err:#}").contains("search requires a positional query or --request-json <file>")
@@ -243,9 +275,9 @@ fn search_accepts_request_json_without_positional_query() {
     )
     .expect("write request");
 
-    let command = Commands::Search(crate::cli::SearchArgs {
+    let command = Commands::Search(Box::new(crate::cli::SearchArgs {
         query: None,
-        target: None,
+        targets: Vec::new(),
         session: None,
         limit: None,
         tags: Vec::new(),
@@ -258,7 +290,20 @@ fn search_accepts_request_json_without_positional_query() {
         budget_ms: None,
         budget_nodes: None,
         budget_depth: None,
-    });
+        exclude_scopes: Vec::new(),
+        relation_hops: None,
+        highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+        profile: None,
+        command: None,
+        explain: false,
+        markdown: false,
+        cite: false,
+        queries_file: None,
+        filter_json: None,
+    }));
     run(&app, temp.path(), command).expect("search from request json");
 }
 
@@ -267,9 +312,9 @@ fn search_rejects_invalid_hint_syntax() {
     let temp = tempdir().expect("tempdir");
     let app = AxiomSync::new(temp.path()).expect("app");
 
-    let command = Commands::Search(crate::cli::SearchArgs {
+    let command = Commands::Search(Box::new(crate::cli::SearchArgs {
         query: Some("oauth".to_string()),
-        target: Some("axiom://resources".to_string()),
+        targets: vec!["axiom://resources".to_string()],
         session: None,
         limit: Some(5),
         tags: Vec::new(),
@@ -282,7 +327,20 @@ fn search_rejects_invalid_hint_syntax() {
         budget_ms: None,
         budget_nodes: None,
         budget_depth: None,
-    });
+        exclude_scopes: Vec::new(),
+        relation_hops: None,
+        highlight: None,
+        offset: None,
+        cursor: None,
+        paginate: false,
+        profile: None,
+        command: None,
+        explain: false,
+        markdown: false,
+        cite: false,
+        queries_file: None,
+        filter_json: None,
+    }));
     let err = run(&app, temp.path(), command).expect_err("invalid hint must fail");
     assert!(format!("{err:#}").contains("invalid --hint value"));
 }
@@ -308,10 +366,12 @@ fn benchmark_gate_does_not_require_runtime_prepare() {
             gate_profile: "custom".to_string(),
             max_p95_regression_pct: None,
             max_top1_regression_pct: None,
+            max_mrr_regression_pct: None,
             window_size: 1,
             required_passes: 1,
             record: true,
             write_release_check: false,
+            max_p95_ms_at_concurrency: None,
             enforce: false,
         },
     });
@@ -380,8 +440,9 @@ fn relation_commands_roundtrip_link_list_unlink() {
     run(
         &app,
         temp.path(),
-        Commands::Mkdir(crate::cli::UriArg {
+        Commands::Mkdir(crate::cli::MkdirArgs {
             uri: "axiom://resources/docs".to_string(),
+            no_parents: false,
         }),
     )
     .expect("mkdir owner");
@@ -490,6 +551,7 @@ fn find_runs_runtime_prepare_and_generates_root_tiers() {
         budget_ms: None,
         budget_nodes: None,
         budget_depth: None,
+        filter_json: None,
     });
     run(&app, temp.path(), command).expect("find");
 
@@ -600,6 +662,7 @@ fn ontology_action_validate_and_enqueue_run_with_schema_contract() {
                 input_json: Some("{\"uri\":\"axiom://resources/docs/a.md\"}".to_string()),
                 input_file: None,
                 input_stdin: false,
+                coerce: false,
             },
         }),
     )
@@ -694,6 +757,7 @@ fn ontology_action_input_rejects_multiple_sources() {
                 input_json: Some("{\"uri\":\"axiom://resources/docs/a.md\"}".to_string()),
                 input_file: Some(input_file),
                 input_stdin: false,
+                coerce: false,
             },
         }),
     )
@@ -808,10 +872,12 @@ fn benchmark_gate_enforce_propagates_failure_as_cli_error() {
                 gate_profile: "custom".to_string(),
                 max_p95_regression_pct: None,
                 max_top1_regression_pct: None,
+                max_mrr_regression_pct: None,
                 window_size: 1,
                 required_passes: 1,
                 record: false,
                 write_release_check: false,
+                max_p95_ms_at_concurrency: None,
                 enforce: true,
             },
         }),
@@ -858,6 +924,11 @@ fn add_markdown_flag_validation_runs_before_bootstrap_side_effects() {
             include_hidden: false,
             exclude: vec!["**/*.json".to_string()],
             wait_mode: AddWaitModeArg::Relaxed,
+            watch: false,
+            interval_secs: 5,
+            dedup_by_content: false,
+            dedup_action: AddDedupActionArg::Skip,
+            tags: Vec::new(),
         }),
     )
     .expect_err("must fail");
@@ -865,6 +936,30 @@ fn add_markdown_flag_validation_runs_before_bootstrap_side_effects() {
     assert!(!temp.path().join("resources").exists());
 }
 
+#[test]
+fn rm_glob_only_flags_without_glob_are_rejected_before_removing() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+    app.mkdir("axiom://resources/keep").expect("mkdir");
+
+    let err = run(
+        &app,
+        temp.path(),
+        Commands::Rm(RemoveArgs {
+            uri: Some("axiom://resources/keep".to_string()),
+            recursive: true,
+            glob: None,
+            glob_base_uri: None,
+            dry_run: true,
+            permanent: false,
+        }),
+    )
+    .expect_err("must fail");
+    assert!(format!("{err:#}").contains("--dry-run and --uri require --glob"));
+    assert!(temp.path().join("resources").join("keep").is_dir());
+}
+
 #[test]
 fn reconcile_scope_validation_runs_before_bootstrap_side_effects() {
     let temp = tempdir().expect("tempdir");
@@ -877,6 +972,9 @@ fn reconcile_scope_validation_runs_before_bootstrap_side_effects() {
             dry_run: true,
             scopes: vec!["not-a-scope".to_string()],
             max_drift_sample: 50,
+            fix: false,
+            check_vectors: false,
+            check_relations: false,
         }),
     )
     .expect_err("invalid scope must fail");
@@ -890,7 +988,18 @@ fn queue_work_zero_iterations_has_stable_mode_value() {
     let app = AxiomSync::new(temp.path()).expect("app");
     run(&app, temp.path(), Commands::Init).expect("init");
 
-    let report = super::queue::run_queue_worker(&app, 0, 10, 0, false, true).expect("report");
+    let report = super::queue::run_queue_worker(
+        &app,
+        0,
+        0,
+        true,
+        super::queue::QueueReplayBatch {
+            limit: 10,
+            include_dead_letter: false,
+            workers: 1,
+        },
+    )
+    .expect("report");
     let payload = serde_json::to_value(report).expect("serialize");
     assert_eq!(payload["mode"], "work");
     assert_eq!(payload["iterations"], 0);
@@ -902,7 +1011,19 @@ fn queue_daemon_zero_max_cycles_still_reports_daemon_mode() {
     let app = AxiomSync::new(temp.path()).expect("app");
     run(&app, temp.path(), Commands::Init).expect("init");
 
-    let report = super::queue::run_queue_daemon(&app, 0, 10, 0, false, true, 1).expect("report");
+    let report = super::queue::run_queue_daemon(
+        &app,
+        0,
+        0,
+        true,
+        1,
+        super::queue::QueueReplayBatch {
+            limit: 10,
+            include_dead_letter: false,
+            workers: 1,
+        },
+    )
+    .expect("report");
     let payload = serde_json::to_value(report).expect("serialize");
     assert_eq!(payload["mode"], "daemon");
 }
@@ -923,10 +1044,12 @@ fn benchmark_gate_rejects_required_passes_over_window_before_runtime_prepare() {
                 gate_profile: "custom".to_string(),
                 max_p95_regression_pct: None,
                 max_top1_regression_pct: None,
+                max_mrr_regression_pct: None,
                 window_size: 1,
                 required_passes: 2,
                 record: false,
                 write_release_check: false,
+                max_p95_ms_at_concurrency: None,
                 enforce: false,
             },
         }),
@@ -7,7 +7,8 @@ use crate::cli::{
 
 use super::ontology::validate_ontology_action_input_source_selection;
 use super::support::{
-    parse_scope_args, validate_add_ingest_flags, validate_document_preview_source_selection,
+    parse_exclude_scope_args, parse_scope_args, validate_add_ingest_flags,
+    validate_document_patch_source_selection, validate_document_preview_source_selection,
     validate_document_save_source_selection,
 };
 
@@ -38,12 +39,12 @@ pub(super) fn apply_bootstrap_mode(app: &AxiomSync, mode: BootstrapMode) -> Resu
     }
 }
 
-pub(super) const fn command_needs_runtime(command: &Commands) -> bool {
+pub(super) fn command_needs_runtime(command: &Commands) -> bool {
     match command {
+        Commands::Search(args) => args.command.is_none(),
         Commands::Abstract(_)
         | Commands::Overview(_)
         | Commands::Find(_)
-        | Commands::Search(_)
         | Commands::Backend
         | Commands::Release(_) => true,
         Commands::Trace(args) => matches!(args.command, crate::cli::TraceCommand::Replay { .. }),
@@ -59,7 +60,9 @@ pub(super) const fn command_needs_runtime(command: &Commands) -> bool {
 }
 
 pub(super) fn command_needs_runtime_prepare(app: &AxiomSync, command: &Commands) -> bool {
-    if matches!(command, Commands::Search(_)) {
+    if let Commands::Search(args) = command
+        && args.command.is_none()
+    {
         return app.search_requires_runtime_prepare();
     }
     command_needs_runtime(command)
@@ -68,10 +71,22 @@ pub(super) fn command_needs_runtime_prepare(app: &AxiomSync, command: &Commands)
 pub(super) fn validate_command_preflight(command: &Commands) -> Result<()> {
     match command {
         Commands::Add(args) => {
+            if args.watch
+                && (args.source.starts_with("http://") || args.source.starts_with("https://"))
+            {
+                anyhow::bail!("--watch requires a local directory source");
+            }
             validate_add_ingest_flags(args.markdown_only, args.include_hidden, &args.exclude)
         }
         Commands::Benchmark(args) => validate_benchmark_command(&args.command),
         Commands::Release(args) => validate_release_command(&args.command),
+        Commands::Rm(args) => {
+            // Clap `requires` cannot express this since the positional uri conflicts with --glob.
+            if args.glob.is_none() && (args.dry_run || args.glob_base_uri.is_some()) {
+                anyhow::bail!("--dry-run and --uri require --glob");
+            }
+            Ok(())
+        }
         Commands::Reconcile(args) => {
             let _ = parse_scope_args(&args.scopes)?;
             Ok(())
@@ -85,9 +100,15 @@ pub(super) fn validate_command_preflight(command: &Commands) -> Result<()> {
 }
 
 fn validate_search_command(args: &SearchArgs) -> Result<()> {
-    if args.query.is_none() && args.request_json.is_none() {
-        anyhow::bail!("search requires a positional query or --request-json <file>");
+    if args.command.is_some() {
+        return Ok(());
     }
+    if args.query.is_none() && args.request_json.is_none() && args.queries_file.is_none() {
+        anyhow::bail!(
+            "search requires a positional query or --request-json <file> (or --queries-file <file> for batch)"
+        );
+    }
+    let _ = parse_exclude_scope_args(&args.exclude_scopes)?;
     Ok(())
 }
 
@@ -111,6 +132,18 @@ fn validate_document_command(command: &crate::cli::DocumentCommand) -> Result<()
             stdin,
             ..
         } => validate_document_save_source_selection(content.as_deref(), from.as_deref(), *stdin),
+        crate::cli::DocumentCommand::Append {
+            content,
+            from,
+            stdin,
+            ..
+        } => validate_document_save_source_selection(content.as_deref(), from.as_deref(), *stdin),
+        crate::cli::DocumentCommand::Patch {
+            ops_json,
+            from,
+            stdin,
+            ..
+        } => validate_document_patch_source_selection(ops_json.as_deref(), from.as_deref(), *stdin),
     }
 }
 
@@ -175,7 +208,9 @@ fn validate_relation_command(command: &RelationCommand) -> Result<()> {
             }
             Ok(())
         }
-        RelationCommand::List { .. } | RelationCommand::Unlink { .. } => Ok(()),
+        RelationCommand::List { .. }
+        | RelationCommand::Unlink { .. }
+        | RelationCommand::Neighborhood { .. } => Ok(()),
     }
 }
 
@@ -1,5 +1,5 @@
 #[must_use]
-pub(super) fn read_non_empty_env(name: &str) -> Option<String> {
+pub(crate) fn read_non_empty_env(name: &str) -> Option<String> {
     std::env::var(name)
         .ok()
         .map(|raw| raw.trim().to_string())
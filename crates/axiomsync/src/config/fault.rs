@@ -0,0 +1,152 @@
+use crate::error::{AxiomError, Result};
+
+use super::env::read_non_empty_env;
+
+const ENV_FAULT: &str = "AXIOMSYNC_FAULT";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FaultPoint {
+    Embedding,
+    SqliteLock,
+    FsWrite,
+}
+
+impl FaultPoint {
+    pub(crate) const ALL: [Self; 3] = [Self::Embedding, Self::SqliteLock, Self::FsWrite];
+
+    #[must_use]
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Embedding => "embedding",
+            Self::SqliteLock => "sqlite_lock",
+            Self::FsWrite => "fs_write",
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|point| point.as_str() == raw)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FaultRule {
+    pub(crate) point: FaultPoint,
+    pub(crate) fail_rate: f64,
+    pub(crate) seed: u64,
+}
+
+/// Fault-injection settings; `AXIOMSYNC_FAULT` is read only in test or `fault-injection` builds.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct FaultConfig {
+    pub(crate) rules: Vec<FaultRule>,
+}
+
+impl FaultConfig {
+    pub(super) fn from_env() -> Result<Self> {
+        if !cfg!(any(test, feature = "fault-injection")) {
+            return Ok(Self::default());
+        }
+        read_non_empty_env(ENV_FAULT)
+            .map_or_else(|| Ok(Self::default()), |raw| parse_fault_spec(&raw))
+    }
+
+    #[must_use]
+    pub(crate) fn rule(&self, point: FaultPoint) -> Option<&FaultRule> {
+        self.rules.iter().find(|rule| rule.point == point)
+    }
+}
+
+/// Parses `embedding:fail_rate=0.5,seed=7;fs_write:fail_rate=1`; `seed` defaults to 0.
+pub(crate) fn parse_fault_spec(raw: &str) -> Result<FaultConfig> {
+    let mut rules = Vec::<FaultRule>::new();
+    for entry in raw
+        .split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+    {
+        let (point_raw, params) = entry.split_once(':').unwrap_or((entry, ""));
+        let point = FaultPoint::parse(point_raw.trim()).ok_or_else(|| {
+            AxiomError::Validation(format!(
+                "invalid {ENV_FAULT} point '{}': expected embedding|sqlite_lock|fs_write",
+                point_raw.trim()
+            ))
+        })?;
+        let mut fail_rate = None;
+        let mut seed = 0u64;
+        for param in params
+            .split(',')
+            .map(str::trim)
+            .filter(|param| !param.is_empty())
+        {
+            let (key, value) = param.split_once('=').ok_or_else(|| {
+                AxiomError::Validation(format!("invalid {ENV_FAULT} parameter '{param}'"))
+            })?;
+            match key.trim() {
+                "fail_rate" => {
+                    let rate = value
+                        .trim()
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|rate| (0.0..=1.0).contains(rate))
+                        .ok_or_else(|| {
+                            AxiomError::Validation(format!(
+                                "invalid {ENV_FAULT} fail_rate '{value}': expected 0.0..=1.0"
+                            ))
+                        })?;
+                    fail_rate = Some(rate);
+                }
+                "seed" => {
+                    seed = value.trim().parse::<u64>().map_err(|_| {
+                        AxiomError::Validation(format!("invalid {ENV_FAULT} seed '{value}'"))
+                    })?;
+                }
+                other => {
+                    return Err(AxiomError::Validation(format!(
+                        "unknown {ENV_FAULT} parameter '{other}'"
+                    )));
+                }
+            }
+        }
+        let fail_rate = fail_rate.ok_or_else(|| {
+            AxiomError::Validation(format!(
+                "{ENV_FAULT} point '{}' requires fail_rate",
+                point.as_str()
+            ))
+        })?;
+        rules.retain(|rule| rule.point != point);
+        rules.push(FaultRule {
+            point,
+            fail_rate,
+            seed,
+        });
+    }
+    Ok(FaultConfig { rules })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_fault_spec_reads_points_rates_and_seeds() {
+        let config =
+            parse_fault_spec("embedding:fail_rate=0.5,seed=7; fs_write:fail_rate=1").expect("spec");
+        assert_eq!(
+            config.rule(FaultPoint::Embedding),
+            Some(&FaultRule {
+                point: FaultPoint::Embedding,
+                fail_rate: 0.5,
+                seed: 7,
+            })
+        );
+        assert_eq!(
+            config.rule(FaultPoint::FsWrite).map(|rule| rule.fail_rate),
+            Some(1.0)
+        );
+        assert!(config.rule(FaultPoint::SqliteLock).is_none());
+
+        assert!(parse_fault_spec("network:fail_rate=0.5").is_err());
+        assert!(parse_fault_spec("embedding:fail_rate=1.5").is_err());
+        assert!(parse_fault_spec("embedding:seed=1").is_err());
+    }
+}
@@ -1,9 +1,13 @@
 use crate::uri::Scope;
 
-use super::env::read_non_empty_env;
+use crate::llm_io::parse_env_bool;
+
+use super::env::{read_non_empty_env, read_raw_env};
 
 const ENV_TIER_SYNTHESIS: &str = "AXIOMSYNC_TIER_SYNTHESIS";
 const ENV_INTERNAL_TIERS: &str = "AXIOMSYNC_INTERNAL_TIERS";
+const ENV_VECTOR_PERSISTENCE: &str = "AXIOMSYNC_INDEX_VECTOR_PERSISTENCE";
+const ENV_EMBEDDING_CACHE: &str = "AXIOMSYNC_INDEX_EMBEDDING_CACHE";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum TierSynthesisMode {
@@ -46,6 +50,8 @@ pub(crate) const fn should_persist_scope_tiers(scope: Scope, policy: InternalTie
 pub(crate) struct IndexingConfig {
     pub(crate) tier_synthesis_mode: TierSynthesisMode,
     pub(crate) internal_tier_policy: InternalTierPolicy,
+    pub(crate) vector_persistence: bool,
+    pub(crate) embedding_cache: bool,
 }
 
 impl IndexingConfig {
@@ -58,6 +64,8 @@ impl IndexingConfig {
             internal_tier_policy: resolve_internal_tier_policy(
                 read_non_empty_env(ENV_INTERNAL_TIERS).as_deref(),
             ),
+            vector_persistence: parse_env_bool(read_raw_env(ENV_VECTOR_PERSISTENCE).as_deref()),
+            embedding_cache: parse_env_bool(read_raw_env(ENV_EMBEDDING_CACHE).as_deref()),
         }
     }
 }
@@ -67,6 +75,8 @@ impl Default for IndexingConfig {
         Self {
             tier_synthesis_mode: TierSynthesisMode::Deterministic,
             internal_tier_policy: InternalTierPolicy::Virtual,
+            vector_persistence: false,
+            embedding_cache: false,
         }
     }
 }
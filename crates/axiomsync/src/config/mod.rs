@@ -6,11 +6,22 @@ use crate::error::Result;
 use crate::llm_io::parse_env_bool;
 
 mod env;
+mod fault;
 mod indexing;
 mod memory;
 mod om;
+mod queue;
+mod quota;
+mod redaction;
+mod remote;
 mod search;
+mod session;
 
+#[cfg(feature = "sqlcipher")]
+pub(crate) use env::read_non_empty_env;
+#[cfg(test)]
+pub(crate) use fault::parse_fault_spec;
+pub(crate) use fault::{FaultConfig, FaultPoint};
 pub(crate) use indexing::{
     IndexingConfig, InternalTierPolicy, TierSynthesisMode, should_persist_scope_tiers,
 };
@@ -21,10 +32,18 @@ pub(crate) use om::{
     OmConfig, OmHintReaderMode, OmObserverConfigSnapshot, OmReflectorConfigSnapshot,
     OmRuntimeEnvConfig, OmRuntimeLimitsConfig, OmScopeConfig,
 };
+pub(crate) use queue::QueuePolicyConfig;
+pub(crate) use quota::QuotaConfig;
+pub(crate) use redaction::{QueryLogMode, REDACTED_MARKER, RedactionPolicy, ScopeRedaction};
+pub(crate) use remote::{ENV_REMOTE_SOURCE_HOSTS, RemoteSourceConfig};
 pub(crate) use search::{
-    OmHintBounds, OmHintPolicy, QUERY_PLAN_BACKEND_POLICY_MEMORY_ONLY, RETRIEVAL_BACKEND_MEMORY,
-    RETRIEVAL_BACKEND_POLICY_MEMORY_ONLY, SearchConfig,
+    ENV_SEARCH_MAX_QUERY_CHARS, OmHintBounds, OmHintPolicy, QUERY_PLAN_BACKEND_POLICY_MEMORY_ONLY,
+    QueryOverflowPolicy, RETRIEVAL_BACKEND_MEMORY, RETRIEVAL_BACKEND_POLICY_MEMORY_ONLY,
+    SearchConfig,
 };
+#[cfg(test)]
+pub(crate) use session::SessionAutoCommitPolicy;
+pub(crate) use session::SessionConfig;
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct AppConfig {
@@ -33,6 +52,13 @@ pub(crate) struct AppConfig {
     pub(crate) indexing: IndexingConfig,
     pub(crate) om: OmConfig,
     pub(crate) memory: MemoryConfig,
+    pub(crate) session: SessionConfig,
+    pub(crate) redaction: RedactionPolicy,
+    pub(crate) query_log: QueryLogMode,
+    pub(crate) fault: FaultConfig,
+    pub(crate) quota: QuotaConfig,
+    pub(crate) queue: QueuePolicyConfig,
+    pub(crate) remote: RemoteSourceConfig,
 }
 
 impl AppConfig {
@@ -43,6 +69,13 @@ impl AppConfig {
             indexing: IndexingConfig::from_env(),
             om: OmConfig::from_env(),
             memory: MemoryConfig::from_env(),
+            session: SessionConfig::from_env(),
+            redaction: RedactionPolicy::from_env()?,
+            query_log: QueryLogMode::from_env()?,
+            fault: FaultConfig::from_env()?,
+            quota: QuotaConfig::from_env()?,
+            queue: QueuePolicyConfig::from_env()?,
+            remote: RemoteSourceConfig::from_env()?,
         })
     }
 }
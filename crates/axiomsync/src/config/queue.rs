@@ -0,0 +1,169 @@
+use crate::error::{AxiomError, Result};
+use crate::queue_policy::RetryPolicy;
+
+use super::env::read_non_empty_env;
+
+const ENV_QUEUE_RETRY_BASE_SECS: &str = "AXIOMSYNC_QUEUE_RETRY_BASE_SECS";
+const ENV_QUEUE_RETRY_MULTIPLIER: &str = "AXIOMSYNC_QUEUE_RETRY_MULTIPLIER";
+const ENV_QUEUE_RETRY_MAX_SECS: &str = "AXIOMSYNC_QUEUE_RETRY_MAX_SECS";
+const ENV_QUEUE_RETRY_MAX_ATTEMPTS: &str = "AXIOMSYNC_QUEUE_RETRY_MAX_ATTEMPTS";
+const ENV_QUEUE_HIGH_WATER_MARK: &str = "AXIOMSYNC_QUEUE_HIGH_WATER_MARK";
+
+/// Retry curve overrides and async add backpressure; unset entries use `RetryPolicy::for_event_type`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct QueuePolicyConfig {
+    pub(crate) base_delay_secs: Option<i64>,
+    pub(crate) multiplier: Option<f64>,
+    pub(crate) max_delay_secs: Option<i64>,
+    pub(crate) max_attempts: Option<u32>,
+    pub(crate) high_water_mark: Option<u64>,
+}
+
+impl QueuePolicyConfig {
+    pub(super) fn from_env() -> Result<Self> {
+        let config = Self {
+            base_delay_secs: parse_env(ENV_QUEUE_RETRY_BASE_SECS)?,
+            multiplier: parse_env(ENV_QUEUE_RETRY_MULTIPLIER)?,
+            max_delay_secs: parse_env(ENV_QUEUE_RETRY_MAX_SECS)?,
+            max_attempts: parse_env(ENV_QUEUE_RETRY_MAX_ATTEMPTS)?,
+            high_water_mark: parse_env(ENV_QUEUE_HIGH_WATER_MARK)?,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        if let Some(base) = self.base_delay_secs
+            && base < 1
+        {
+            return Err(AxiomError::Validation(format!(
+                "{ENV_QUEUE_RETRY_BASE_SECS} must be >= 1, got {base}"
+            )));
+        }
+        if let Some(multiplier) = self.multiplier
+            && !(multiplier.is_finite() && multiplier >= 1.0)
+        {
+            return Err(AxiomError::Validation(format!(
+                "{ENV_QUEUE_RETRY_MULTIPLIER} must be >= 1, got {multiplier}"
+            )));
+        }
+        if let (Some(base), Some(max)) = (self.base_delay_secs, self.max_delay_secs)
+            && max < base
+        {
+            return Err(AxiomError::Validation(format!(
+                "{ENV_QUEUE_RETRY_MAX_SECS} ({max}) must be >= {ENV_QUEUE_RETRY_BASE_SECS} ({base})"
+            )));
+        }
+        if self.max_attempts == Some(0) {
+            return Err(AxiomError::Validation(format!(
+                "{ENV_QUEUE_RETRY_MAX_ATTEMPTS} must be >= 1"
+            )));
+        }
+        if self.high_water_mark == Some(0) {
+            return Err(AxiomError::Validation(format!(
+                "{ENV_QUEUE_HIGH_WATER_MARK} must be >= 1"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Layers overrides on the per-kind defaults; a base above the default max raises the max too.
+    #[must_use]
+    pub(crate) fn retry_policy(&self, event_type: &str) -> RetryPolicy {
+        let defaults = RetryPolicy::for_event_type(event_type);
+        let base_delay_secs = self.base_delay_secs.unwrap_or(defaults.base_delay_secs);
+        RetryPolicy {
+            base_delay_secs,
+            multiplier: self.multiplier.unwrap_or(defaults.multiplier),
+            max_delay_secs: self
+                .max_delay_secs
+                .unwrap_or(defaults.max_delay_secs)
+                .max(base_delay_secs),
+            max_attempts: self.max_attempts.unwrap_or(defaults.max_attempts),
+        }
+    }
+}
+
+fn parse_env<T: std::str::FromStr>(name: &str) -> Result<Option<T>> {
+    read_non_empty_env(name)
+        .map(|raw| {
+            raw.parse::<T>()
+                .map_err(|_| AxiomError::Validation(format!("invalid {name} '{raw}'")))
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_policy_overrides_defaults_per_field() {
+        let config = QueuePolicyConfig {
+            base_delay_secs: Some(5),
+            multiplier: None,
+            max_delay_secs: None,
+            max_attempts: Some(10),
+            high_water_mark: None,
+        };
+        let policy = config.retry_policy("semantic_scan");
+        assert_eq!(policy.base_delay_secs, 5);
+        assert!((policy.multiplier - 2.0).abs() < f64::EPSILON);
+        assert_eq!(policy.max_delay_secs, 60);
+        assert_eq!(policy.max_attempts, 10);
+        assert_eq!(
+            QueuePolicyConfig::default().retry_policy("semantic_scan"),
+            RetryPolicy::for_event_type("semantic_scan")
+        );
+
+        let wide_base = QueuePolicyConfig {
+            base_delay_secs: Some(90),
+            ..QueuePolicyConfig::default()
+        };
+        assert_eq!(wide_base.retry_policy("unknown").max_delay_secs, 90);
+    }
+
+    #[test]
+    fn validate_rejects_shrinking_multiplier_and_inverted_bounds() {
+        let invalid = [
+            QueuePolicyConfig {
+                multiplier: Some(0.5),
+                ..QueuePolicyConfig::default()
+            },
+            QueuePolicyConfig {
+                base_delay_secs: Some(30),
+                max_delay_secs: Some(10),
+                ..QueuePolicyConfig::default()
+            },
+            QueuePolicyConfig {
+                base_delay_secs: Some(0),
+                ..QueuePolicyConfig::default()
+            },
+            QueuePolicyConfig {
+                max_attempts: Some(0),
+                ..QueuePolicyConfig::default()
+            },
+            QueuePolicyConfig {
+                high_water_mark: Some(0),
+                ..QueuePolicyConfig::default()
+            },
+        ];
+        for config in invalid {
+            assert!(
+                matches!(config.validate(), Err(AxiomError::Validation(_))),
+                "{config:?}"
+            );
+        }
+        assert!(
+            QueuePolicyConfig {
+                base_delay_secs: Some(2),
+                multiplier: Some(1.0),
+                max_delay_secs: Some(2),
+                max_attempts: Some(1),
+                high_water_mark: Some(1),
+            }
+            .validate()
+            .is_ok()
+        );
+    }
+}
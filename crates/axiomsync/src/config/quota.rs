@@ -0,0 +1,96 @@
+use crate::error::{AxiomError, Result};
+use crate::uri::Scope;
+
+use super::env::read_non_empty_env;
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+/// Scopes that can carry a quota; trash is excluded so deletes never fail.
+const QUOTA_SCOPES: [Scope; 6] = [
+    Scope::Resources,
+    Scope::User,
+    Scope::Agent,
+    Scope::Session,
+    Scope::Temp,
+    Scope::Queue,
+];
+
+/// Per-scope disk limits from `AXIOMSYNC_QUOTA_<SCOPE>_MB`; unset scopes are not tracked.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct QuotaConfig {
+    limits: Vec<(Scope, u64)>,
+}
+
+impl QuotaConfig {
+    pub(super) fn from_env() -> Result<Self> {
+        let mut config = Self::default();
+        for scope in QUOTA_SCOPES {
+            let name = quota_env_name(scope);
+            if let Some(raw) = read_non_empty_env(&name) {
+                config = config.with_limit_bytes(scope, parse_quota_mb(&name, &raw)?);
+            }
+        }
+        Ok(config)
+    }
+
+    #[must_use]
+    pub(crate) fn with_limit_bytes(mut self, scope: Scope, limit_bytes: u64) -> Self {
+        self.limits.retain(|(existing, _)| *existing != scope);
+        self.limits.push((scope, limit_bytes));
+        self
+    }
+
+    #[must_use]
+    pub(crate) fn limit_bytes(&self, scope: Scope) -> Option<u64> {
+        self.limits
+            .iter()
+            .find(|(existing, _)| *existing == scope)
+            .map(|(_, limit)| *limit)
+    }
+
+    #[must_use]
+    pub(crate) fn limited_scopes(&self) -> Vec<Scope> {
+        self.limits.iter().map(|(scope, _)| *scope).collect()
+    }
+
+    #[must_use]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.limits.is_empty()
+    }
+}
+
+fn quota_env_name(scope: Scope) -> String {
+    format!("AXIOMSYNC_QUOTA_{}_MB", scope.as_str().to_ascii_uppercase())
+}
+
+fn parse_quota_mb(name: &str, raw: &str) -> Result<u64> {
+    raw.trim()
+        .parse::<u64>()
+        .ok()
+        .and_then(|mb| mb.checked_mul(BYTES_PER_MB))
+        .ok_or_else(|| {
+            AxiomError::Validation(format!("invalid {name} '{raw}': expected megabytes"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quota_env_names_and_megabyte_parsing() {
+        assert_eq!(quota_env_name(Scope::Session), "AXIOMSYNC_QUOTA_SESSION_MB");
+        assert_eq!(
+            parse_quota_mb("AXIOMSYNC_QUOTA_SESSION_MB", " 200 ").expect("parse"),
+            200 * BYTES_PER_MB
+        );
+        assert!(parse_quota_mb("AXIOMSYNC_QUOTA_SESSION_MB", "2GB").is_err());
+
+        let config = QuotaConfig::default()
+            .with_limit_bytes(Scope::Session, 10)
+            .with_limit_bytes(Scope::Session, 20);
+        assert_eq!(config.limit_bytes(Scope::Session), Some(20));
+        assert_eq!(config.limit_bytes(Scope::Resources), None);
+        assert_eq!(config.limited_scopes(), vec![Scope::Session]);
+    }
+}
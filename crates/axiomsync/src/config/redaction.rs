@@ -0,0 +1,289 @@
+use std::borrow::Cow;
+
+use regex::Regex;
+
+use crate::error::{AxiomError, Result};
+use crate::uri::Scope;
+
+use super::env::read_non_empty_env;
+
+const ENV_REDACTION_SCOPES: &str = "AXIOMSYNC_REDACTION_SCOPES";
+const ENV_REDACTION_PATTERNS: &str = "AXIOMSYNC_REDACTION_PATTERNS";
+const ENV_QUERY_LOG_MODE: &str = "AXIOMSYNC_QUERY_LOG_MODE";
+const DEFAULT_QUERY_LOG_TRUNCATE_CHARS: usize = 32;
+
+pub(crate) const REDACTED_MARKER: &str = "[REDACTED]";
+
+const DEFAULT_SECRET_PATTERNS: &[&str] = &[
+    r"(?i)\b(?:api[_-]?key|secret|password|passwd|access[_-]?token)\s*[:=]\s*\S+",
+    r"\bsk-[A-Za-z0-9_-]{16,}",
+    r"\bAKIA[0-9A-Z]{16}\b",
+    r"\bgh[pousr]_[A-Za-z0-9]{36,}\b",
+    r"-----BEGIN [A-Z ]*PRIVATE KEY-----[\s\S]*?-----END [A-Z ]*PRIVATE KEY-----",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScopeRedaction {
+    Patterns,
+    Full,
+}
+
+/// Redaction for artifacts copied out of the store (traces, fixtures, ovpacks); off when no scope is set.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RedactionPolicy {
+    scopes: Vec<(Scope, ScopeRedaction)>,
+    patterns: Vec<Regex>,
+}
+
+impl RedactionPolicy {
+    pub(super) fn from_env() -> Result<Self> {
+        let Some(raw_scopes) = read_non_empty_env(ENV_REDACTION_SCOPES) else {
+            return Ok(Self::default());
+        };
+        let scopes = parse_redaction_scopes(&raw_scopes)?;
+        let extra_patterns = read_non_empty_env(ENV_REDACTION_PATTERNS)
+            .map(|raw| {
+                raw.lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        Self::new(scopes, &extra_patterns)
+    }
+
+    /// Builds a policy from the default secret patterns plus `extra_patterns`.
+    pub(crate) fn new(
+        scopes: Vec<(Scope, ScopeRedaction)>,
+        extra_patterns: &[String],
+    ) -> Result<Self> {
+        let patterns = DEFAULT_SECRET_PATTERNS
+            .iter()
+            .copied()
+            .chain(extra_patterns.iter().map(String::as_str))
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|err| {
+                    AxiomError::Validation(format!(
+                        "invalid {ENV_REDACTION_PATTERNS} entry '{pattern}': {err}"
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { scopes, patterns })
+    }
+
+    #[must_use]
+    pub(crate) fn is_enabled(&self) -> bool {
+        !self.scopes.is_empty()
+    }
+
+    #[must_use]
+    pub(crate) fn mode_for(&self, scope: Scope) -> Option<ScopeRedaction> {
+        self.scopes
+            .iter()
+            .find(|(candidate, _)| *candidate == scope)
+            .map(|(_, mode)| *mode)
+    }
+
+    /// Redacts per the scope's mode; unconfigured scopes pass through.
+    #[must_use]
+    pub(crate) fn redact_scope_text<'a>(&self, scope: Scope, text: &'a str) -> Cow<'a, str> {
+        match self.mode_for(scope) {
+            Some(ScopeRedaction::Full) => Cow::Borrowed(REDACTED_MARKER),
+            Some(ScopeRedaction::Patterns) => self.redact_patterns(text),
+            None => Cow::Borrowed(text),
+        }
+    }
+
+    /// For text without a clear source scope: `Full` hides everything, otherwise only secrets.
+    #[must_use]
+    pub(crate) fn redact_artifact_text<'a>(
+        &self,
+        target_scope: Option<Scope>,
+        text: &'a str,
+    ) -> Cow<'a, str> {
+        if !self.is_enabled() {
+            return Cow::Borrowed(text);
+        }
+        match target_scope.and_then(|scope| self.mode_for(scope)) {
+            Some(ScopeRedaction::Full) => Cow::Borrowed(REDACTED_MARKER),
+            _ => self.redact_patterns(text),
+        }
+    }
+
+    fn redact_patterns<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut out = Cow::Borrowed(text);
+        for pattern in &self.patterns {
+            if let Cow::Owned(replaced) = pattern.replace_all(&out, REDACTED_MARKER) {
+                out = Cow::Owned(replaced);
+            }
+        }
+        out
+    }
+}
+
+/// How search queries are stored in request logs and traces; requests always use the raw query.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum QueryLogMode {
+    #[default]
+    Raw,
+    Hash,
+    Truncate(usize),
+}
+
+impl QueryLogMode {
+    pub(super) fn from_env() -> Result<Self> {
+        read_non_empty_env(ENV_QUERY_LOG_MODE).map_or(Ok(Self::Raw), |raw| Self::parse(&raw))
+    }
+
+    fn parse(raw: &str) -> Result<Self> {
+        let normalized = raw.trim().to_ascii_lowercase();
+        let (mode, arg) = normalized
+            .split_once(':')
+            .map_or((normalized.as_str(), None), |(mode, arg)| (mode, Some(arg)));
+        match (mode, arg) {
+            ("raw", None) => Ok(Self::Raw),
+            ("hash", None) => Ok(Self::Hash),
+            ("truncate", None) => Ok(Self::Truncate(DEFAULT_QUERY_LOG_TRUNCATE_CHARS)),
+            ("truncate", Some(chars)) => chars
+                .trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|chars| *chars > 0)
+                .map(Self::Truncate)
+                .ok_or_else(|| {
+                    AxiomError::Validation(format!(
+                        "invalid {ENV_QUERY_LOG_MODE} truncate length: {chars}"
+                    ))
+                }),
+            _ => Err(AxiomError::Validation(format!(
+                "invalid {ENV_QUERY_LOG_MODE}: {raw} (expected raw|hash|truncate[:chars])"
+            ))),
+        }
+    }
+
+    #[must_use]
+    pub(crate) const fn is_raw(self) -> bool {
+        matches!(self, Self::Raw)
+    }
+
+    /// `Hash` yields `blake3:<16 hex>`; `Truncate(n)` keeps n chars plus `…`.
+    #[must_use]
+    pub(crate) fn persisted_query(self, query: &str) -> Cow<'_, str> {
+        match self {
+            Self::Raw => Cow::Borrowed(query),
+            Self::Hash => {
+                let hex = blake3::hash(query.as_bytes()).to_hex();
+                Cow::Owned(format!("blake3:{}", &hex[..16]))
+            }
+            Self::Truncate(chars) => match query.char_indices().nth(chars) {
+                Some((cut, _)) => Cow::Owned(format!("{}…", &query[..cut])),
+                None => Cow::Borrowed(query),
+            },
+        }
+    }
+}
+
+fn parse_redaction_scopes(raw: &str) -> Result<Vec<(Scope, ScopeRedaction)>> {
+    let mut out = Vec::new();
+    for entry in raw
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+    {
+        let (scope_raw, mode_raw) = entry.split_once('=').unwrap_or((entry, "patterns"));
+        let scope = scope_raw.trim().parse::<Scope>().map_err(|_| {
+            AxiomError::Validation(format!(
+                "invalid {ENV_REDACTION_SCOPES} scope: {scope_raw} (expected resources|user|agent|session)"
+            ))
+        })?;
+        let mode = match mode_raw.trim().to_ascii_lowercase().as_str() {
+            "patterns" => ScopeRedaction::Patterns,
+            "full" => ScopeRedaction::Full,
+            other => {
+                return Err(AxiomError::Validation(format!(
+                    "invalid {ENV_REDACTION_SCOPES} mode for {scope_raw}: {other} (expected patterns|full)"
+                )));
+            }
+        };
+        out.retain(|(existing, _)| *existing != scope);
+        out.push((scope, mode));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_log_mode_parses_and_rewrites_persisted_queries() {
+        assert_eq!(QueryLogMode::parse("raw").expect("raw"), QueryLogMode::Raw);
+        assert_eq!(
+            QueryLogMode::parse("truncate").expect("truncate"),
+            QueryLogMode::Truncate(DEFAULT_QUERY_LOG_TRUNCATE_CHARS)
+        );
+        assert!(QueryLogMode::parse("truncate:0").is_err());
+        assert!(QueryLogMode::parse("mask").is_err());
+
+        let hash = QueryLogMode::parse("HASH").expect("hash");
+        let fingerprint = hash.persisted_query("patient record 42");
+        assert!(fingerprint.starts_with("blake3:"));
+        assert_eq!(fingerprint.len(), "blake3:".len() + 16);
+        assert_eq!(fingerprint, hash.persisted_query("patient record 42"));
+        assert_eq!(
+            QueryLogMode::parse("truncate:4")
+                .expect("truncate 4")
+                .persisted_query("비밀 질의 원문"),
+            "비밀 질…"
+        );
+        assert_eq!(QueryLogMode::Truncate(8).persisted_query("short"), "short");
+    }
+
+    #[test]
+    fn parse_redaction_scopes_reads_modes_and_rejects_unknown_values() {
+        let scopes = parse_redaction_scopes("user, agent=full ,session=patterns").expect("parse");
+        assert_eq!(
+            scopes,
+            vec![
+                (Scope::User, ScopeRedaction::Patterns),
+                (Scope::Agent, ScopeRedaction::Full),
+                (Scope::Session, ScopeRedaction::Patterns),
+            ]
+        );
+        assert!(parse_redaction_scopes("nowhere").is_err());
+        assert!(parse_redaction_scopes("user=partial").is_err());
+    }
+
+    #[test]
+    fn redaction_policy_masks_secret_patterns_only_for_configured_scopes() {
+        let policy = RedactionPolicy::new(
+            vec![
+                (Scope::User, ScopeRedaction::Patterns),
+                (Scope::Agent, ScopeRedaction::Full),
+            ],
+            &[r"INTERNAL-\d+".to_string()],
+        )
+        .expect("policy");
+        let text = "api_key=abc123 and INTERNAL-42 stay private";
+
+        assert_eq!(
+            policy.redact_scope_text(Scope::User, text),
+            "[REDACTED] and [REDACTED] stay private"
+        );
+        assert_eq!(
+            policy.redact_scope_text(Scope::Agent, text),
+            REDACTED_MARKER
+        );
+        assert_eq!(policy.redact_scope_text(Scope::Resources, text), text);
+        assert_eq!(
+            policy.redact_artifact_text(None, text),
+            "[REDACTED] and [REDACTED] stay private"
+        );
+        assert_eq!(
+            RedactionPolicy::default().redact_artifact_text(None, text),
+            text
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use crate::error::{AxiomError, Result};
+use crate::remote::RemoteFetchLimits;
+
+use super::env::read_non_empty_env;
+
+pub(crate) const ENV_REMOTE_SOURCE_HOSTS: &str = "AXIOMSYNC_REMOTE_SOURCE_HOSTS";
+const ENV_REMOTE_SOURCE_MAX_BYTES: &str = "AXIOMSYNC_REMOTE_SOURCE_MAX_BYTES";
+const ENV_REMOTE_SOURCE_TIMEOUT_SECS: &str = "AXIOMSYNC_REMOTE_SOURCE_TIMEOUT_SECS";
+const DEFAULT_REMOTE_SOURCE_MAX_BYTES: usize = 5 * 1024 * 1024;
+const DEFAULT_REMOTE_SOURCE_TIMEOUT_SECS: u64 = 30;
+
+/// Remote source settings; an empty `allowed_hosts` (the default) disables remote sources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RemoteSourceConfig {
+    pub(crate) allowed_hosts: Vec<String>,
+    pub(crate) max_bytes: usize,
+    pub(crate) timeout_secs: u64,
+}
+
+impl Default for RemoteSourceConfig {
+    fn default() -> Self {
+        Self {
+            allowed_hosts: Vec::new(),
+            max_bytes: DEFAULT_REMOTE_SOURCE_MAX_BYTES,
+            timeout_secs: DEFAULT_REMOTE_SOURCE_TIMEOUT_SECS,
+        }
+    }
+}
+
+impl RemoteSourceConfig {
+    pub(super) fn from_env() -> Result<Self> {
+        Ok(Self {
+            allowed_hosts: parse_allowed_hosts(read_non_empty_env(ENV_REMOTE_SOURCE_HOSTS)),
+            max_bytes: parse_positive(
+                ENV_REMOTE_SOURCE_MAX_BYTES,
+                DEFAULT_REMOTE_SOURCE_MAX_BYTES,
+            )?,
+            timeout_secs: parse_positive(
+                ENV_REMOTE_SOURCE_TIMEOUT_SECS,
+                DEFAULT_REMOTE_SOURCE_TIMEOUT_SECS,
+            )?,
+        })
+    }
+
+    #[must_use]
+    pub(crate) fn is_enabled(&self) -> bool {
+        !self.allowed_hosts.is_empty()
+    }
+
+    /// A request `timeout_secs` overrides the configured timeout.
+    #[must_use]
+    pub(crate) fn limits(&self, timeout_secs: Option<u64>) -> RemoteFetchLimits {
+        RemoteFetchLimits {
+            max_bytes: self.max_bytes,
+            timeout: Duration::from_secs(timeout_secs.unwrap_or(self.timeout_secs).max(1)),
+            allowed_hosts: self.allowed_hosts.clone(),
+        }
+    }
+}
+
+fn parse_allowed_hosts(raw: Option<String>) -> Vec<String> {
+    let mut hosts = raw
+        .unwrap_or_default()
+        .split(',')
+        .map(|host| host.trim().to_ascii_lowercase())
+        .filter(|host| !host.is_empty())
+        .collect::<Vec<_>>();
+    hosts.sort();
+    hosts.dedup();
+    hosts
+}
+
+fn parse_positive<T>(name: &str, default_value: T) -> Result<T>
+where
+    T: std::str::FromStr + PartialOrd + Default,
+{
+    let Some(raw) = read_non_empty_env(name) else {
+        return Ok(default_value);
+    };
+    raw.parse::<T>()
+        .ok()
+        .filter(|value| *value > T::default())
+        .ok_or_else(|| AxiomError::Validation(format!("{name} must be a positive integer: {raw}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_allowed_hosts_normalizes_and_dedups() {
+        assert!(parse_allowed_hosts(None).is_empty());
+        assert_eq!(
+            parse_allowed_hosts(Some(
+                " Docs.Example.com, ,*.rust-lang.org,docs.example.com".into()
+            )),
+            vec![
+                "*.rust-lang.org".to_string(),
+                "docs.example.com".to_string()
+            ]
+        );
+        let config = RemoteSourceConfig::default();
+        assert!(!config.is_enabled());
+        assert_eq!(config.limits(None).timeout, Duration::from_secs(30));
+        assert_eq!(config.limits(Some(0)).timeout, Duration::from_secs(1));
+    }
+}
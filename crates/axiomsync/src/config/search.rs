@@ -1,4 +1,5 @@
 use crate::error::{AxiomError, Result};
+use crate::index::HybridWeights;
 use crate::llm_io::parse_env_bool;
 
 use super::env::{read_env_usize, read_non_empty_env, read_raw_env};
@@ -14,6 +15,13 @@ const ENV_OM_HINT_MAX_LINES: &str = "AXIOMSYNC_OM_HINT_MAX_LINES";
 const ENV_OM_HINT_MAX_CHARS: &str = "AXIOMSYNC_OM_HINT_MAX_CHARS";
 const ENV_OM_HINT_SUGGESTED_MAX_CHARS: &str = "AXIOMSYNC_OM_HINT_SUGGESTED_MAX_CHARS";
 const ENV_SEARCH_TYPED_EDGE_ENRICHMENT: &str = "AXIOMSYNC_SEARCH_TYPED_EDGE_ENRICHMENT";
+const ENV_SEARCH_FRESHNESS_CHECK: &str = "AXIOMSYNC_SEARCH_FRESHNESS_CHECK";
+const ENV_SEARCH_LEXICAL_WEIGHT: &str = "AXIOMSYNC_SEARCH_LEXICAL_WEIGHT";
+const ENV_SEARCH_SEMANTIC_WEIGHT: &str = "AXIOMSYNC_SEARCH_SEMANTIC_WEIGHT";
+const ENV_SEARCH_RESULT_CACHE_CAPACITY: &str = "AXIOMSYNC_SEARCH_RESULT_CACHE_CAPACITY";
+const ENV_SEARCH_CURSOR_TTL_SECS: &str = "AXIOMSYNC_SEARCH_CURSOR_TTL_SECS";
+pub(crate) const ENV_SEARCH_MAX_QUERY_CHARS: &str = "AXIOMSYNC_SEARCH_MAX_QUERY_CHARS";
+const ENV_SEARCH_QUERY_OVERFLOW: &str = "AXIOMSYNC_SEARCH_QUERY_OVERFLOW";
 pub(crate) const RETRIEVAL_BACKEND_MEMORY: &str = "memory";
 pub(crate) const RETRIEVAL_BACKEND_POLICY_MEMORY_ONLY: &str = "memory_only";
 pub(crate) const QUERY_PLAN_BACKEND_POLICY_MEMORY_ONLY: &str = "backend_policy:memory_only";
@@ -26,6 +34,18 @@ const DEFAULT_OM_KEEP_RECENT_WITH_OM: usize = 1;
 const DEFAULT_OM_HINT_MAX_CHARS: usize = 480;
 const DEFAULT_OM_HINT_MAX_LINES: usize = 4;
 const DEFAULT_OM_HINT_SUGGESTED_MAX_CHARS: usize = 160;
+const DEFAULT_SEARCH_RESULT_CACHE_CAPACITY: usize = 256;
+const DEFAULT_SEARCH_CURSOR_TTL_SECS: usize = 900;
+const DEFAULT_SEARCH_MAX_QUERY_CHARS: usize = 8_192;
+
+/// What to do with queries longer than `max_query_chars`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum QueryOverflowPolicy {
+    #[default]
+    Reject,
+    /// Keeps the prefix and adds a `query_truncated:<chars>` plan note.
+    Truncate,
+}
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct SearchConfig {
@@ -33,6 +53,15 @@ pub(crate) struct SearchConfig {
     pub(crate) om_hint_policy: OmHintPolicy,
     pub(crate) om_hint_bounds: OmHintBounds,
     pub(crate) typed_edge_enrichment: bool,
+    pub(crate) freshness_check: bool,
+    pub(crate) hybrid_weights: HybridWeights,
+    /// 0 disables the query result cache.
+    pub(crate) result_cache_capacity: usize,
+    /// 0 disables search cursors.
+    pub(crate) cursor_ttl_secs: usize,
+    /// 0 disables the query length check.
+    pub(crate) max_query_chars: usize,
+    pub(crate) query_overflow: QueryOverflowPolicy,
 }
 
 impl SearchConfig {
@@ -45,6 +74,29 @@ impl SearchConfig {
             typed_edge_enrichment: parse_typed_edge_enrichment(
                 read_raw_env(ENV_SEARCH_TYPED_EDGE_ENRICHMENT).as_deref(),
             ),
+            freshness_check: parse_env_bool(read_raw_env(ENV_SEARCH_FRESHNESS_CHECK).as_deref()),
+            hybrid_weights: parse_hybrid_weights(
+                read_non_empty_env(ENV_SEARCH_LEXICAL_WEIGHT).as_deref(),
+                read_non_empty_env(ENV_SEARCH_SEMANTIC_WEIGHT).as_deref(),
+            )?,
+            result_cache_capacity: read_env_usize(
+                ENV_SEARCH_RESULT_CACHE_CAPACITY,
+                DEFAULT_SEARCH_RESULT_CACHE_CAPACITY,
+                0,
+            ),
+            cursor_ttl_secs: read_env_usize(
+                ENV_SEARCH_CURSOR_TTL_SECS,
+                DEFAULT_SEARCH_CURSOR_TTL_SECS,
+                0,
+            ),
+            max_query_chars: read_env_usize(
+                ENV_SEARCH_MAX_QUERY_CHARS,
+                DEFAULT_SEARCH_MAX_QUERY_CHARS,
+                0,
+            ),
+            query_overflow: parse_query_overflow(
+                read_non_empty_env(ENV_SEARCH_QUERY_OVERFLOW).as_deref(),
+            )?,
         })
     }
 }
@@ -54,6 +106,44 @@ fn parse_typed_edge_enrichment(raw: Option<&str>) -> bool {
     parse_env_bool(raw)
 }
 
+/// A missing side uses the default ratio; both are normalized to sum to 1.
+fn parse_hybrid_weights(lexical: Option<&str>, semantic: Option<&str>) -> Result<HybridWeights> {
+    if lexical.is_none() && semantic.is_none() {
+        return Ok(HybridWeights::default());
+    }
+    let defaults = HybridWeights::default();
+    let parse = |name: &str, raw: Option<&str>, default_value: f32| -> Result<f32> {
+        raw.map_or(Ok(default_value), |raw| {
+            raw.trim()
+                .parse::<f32>()
+                .map_err(|_| AxiomError::Validation(format!("invalid {name}: {raw}")))
+        })
+    };
+    HybridWeights::new(
+        parse(ENV_SEARCH_LEXICAL_WEIGHT, lexical, defaults.lexical())?,
+        parse(ENV_SEARCH_SEMANTIC_WEIGHT, semantic, defaults.semantic())?,
+    )
+    .map_err(|err| match err {
+        AxiomError::Validation(message) => AxiomError::Validation(format!(
+            "invalid {ENV_SEARCH_LEXICAL_WEIGHT}/{ENV_SEARCH_SEMANTIC_WEIGHT}: {message}"
+        )),
+        other => other,
+    })
+}
+
+fn parse_query_overflow(raw: Option<&str>) -> Result<QueryOverflowPolicy> {
+    match raw
+        .map(|value| value.trim().to_ascii_lowercase())
+        .as_deref()
+    {
+        None | Some("reject") => Ok(QueryOverflowPolicy::Reject),
+        Some("truncate") => Ok(QueryOverflowPolicy::Truncate),
+        Some(other) => Err(AxiomError::Validation(format!(
+            "invalid {ENV_SEARCH_QUERY_OVERFLOW}: {other} (expected reject|truncate)"
+        ))),
+    }
+}
+
 fn validate_retrieval_backend(raw: Option<&str>) -> Result<()> {
     let Some(raw) = raw else {
         return Ok(());
@@ -160,7 +250,10 @@ impl OmHintBounds {
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_typed_edge_enrichment, validate_retrieval_backend};
+    use super::{
+        QueryOverflowPolicy, parse_hybrid_weights, parse_query_overflow,
+        parse_typed_edge_enrichment, validate_retrieval_backend,
+    };
 
     #[test]
     fn retrieval_backend_validation_accepts_unset() {
@@ -180,6 +273,19 @@ mod tests {
         assert!(validate_retrieval_backend(Some("")).is_err());
     }
 
+    #[test]
+    fn query_overflow_defaults_to_reject_and_rejects_unknown_values() {
+        assert_eq!(
+            parse_query_overflow(None).expect("unset"),
+            QueryOverflowPolicy::Reject
+        );
+        assert_eq!(
+            parse_query_overflow(Some(" Truncate ")).expect("truncate"),
+            QueryOverflowPolicy::Truncate
+        );
+        assert!(parse_query_overflow(Some("drop")).is_err());
+    }
+
     #[test]
     fn typed_edge_enrichment_defaults_disabled() {
         assert!(!parse_typed_edge_enrichment(None));
@@ -194,4 +300,26 @@ mod tests {
         assert!(parse_typed_edge_enrichment(Some("yes")));
         assert!(parse_typed_edge_enrichment(Some("on")));
     }
+
+    #[test]
+    fn hybrid_weights_default_when_unset_and_normalize_explicit_values() {
+        assert_eq!(
+            parse_hybrid_weights(None, None).expect("defaults"),
+            crate::index::HybridWeights::default()
+        );
+        let weights = parse_hybrid_weights(Some("3"), Some("1")).expect("explicit");
+        assert!((weights.lexical() - 0.75).abs() < 1e-6);
+        assert!((weights.semantic() - 0.25).abs() < 1e-6);
+        let lexical_only = parse_hybrid_weights(Some("1"), Some("0")).expect("lexical only");
+        assert!((lexical_only.lexical() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn hybrid_weights_reject_negative_non_finite_and_zero_sum() {
+        assert!(parse_hybrid_weights(Some("-0.1"), None).is_err());
+        assert!(parse_hybrid_weights(Some("NaN"), None).is_err());
+        assert!(parse_hybrid_weights(None, Some("inf")).is_err());
+        assert!(parse_hybrid_weights(Some("0"), Some("0")).is_err());
+        assert!(parse_hybrid_weights(Some("heavy"), None).is_err());
+    }
 }
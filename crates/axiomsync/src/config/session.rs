@@ -0,0 +1,49 @@
+use super::env::read_env_usize_optional;
+
+const ENV_SESSION_AUTO_COMMIT_MESSAGES: &str = "AXIOMSYNC_SESSION_AUTO_COMMIT_MESSAGES";
+const ENV_SESSION_AUTO_COMMIT_TOKENS: &str = "AXIOMSYNC_SESSION_AUTO_COMMIT_TOKENS";
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct SessionConfig {
+    pub(crate) auto_commit: SessionAutoCommitPolicy,
+}
+
+impl SessionConfig {
+    #[must_use]
+    pub(super) fn from_env() -> Self {
+        Self {
+            auto_commit: SessionAutoCommitPolicy::from_env(),
+        }
+    }
+}
+
+/// `add_message` commits once the active buffer reaches a threshold; disabled when both are unset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct SessionAutoCommitPolicy {
+    pub(crate) max_messages: Option<usize>,
+    pub(crate) max_tokens: Option<usize>,
+}
+
+impl SessionAutoCommitPolicy {
+    #[must_use]
+    fn from_env() -> Self {
+        Self {
+            max_messages: read_env_usize_optional(ENV_SESSION_AUTO_COMMIT_MESSAGES)
+                .filter(|value| *value > 0),
+            max_tokens: read_env_usize_optional(ENV_SESSION_AUTO_COMMIT_TOKENS)
+                .filter(|value| *value > 0),
+        }
+    }
+
+    #[must_use]
+    pub(crate) const fn is_enabled(self) -> bool {
+        self.max_messages.is_some() || self.max_tokens.is_some()
+    }
+
+    #[must_use]
+    pub(crate) fn should_commit(self, message_count: usize, token_count: usize) -> bool {
+        self.max_messages
+            .is_some_and(|limit| message_count >= limit)
+            || self.max_tokens.is_some_and(|limit| token_count >= limit)
+    }
+}
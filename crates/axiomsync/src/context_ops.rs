@@ -5,7 +5,8 @@ use chrono::{DateTime, Utc};
 
 use crate::catalog::sanitize_component;
 use crate::error::{AxiomError, Result};
-use crate::models::{IndexRecord, MetadataFilter};
+use crate::models::{IndexRecord, MetadataFilter, SearchFilter, TimeRange};
+use crate::parse::FRONT_MATTER_TAG_PREFIX;
 use crate::uri::{AxiomUri, Scope};
 
 pub fn default_resource_target(path_or_url: &str) -> Result<AxiomUri> {
@@ -66,20 +67,184 @@ pub fn infer_tags(name: &str, content: &str) -> Vec<String> {
     out
 }
 
+const FILTER_FIELDS: &str = "tags, mime, mtime, fm.<key>";
+const TAG_FILTER_OPERATORS: &str = "all, any, not";
+const MIME_FILTER_OPERATORS: &str = "eq, not";
+const MTIME_FILTER_OPERATORS: &str = "gte, gt, lte, lt";
+const FRONT_MATTER_FILTER_OPERATORS: &str = "eq, not";
+
 pub fn validate_filter(filter: Option<&MetadataFilter>) -> Result<()> {
     let Some(filter) = filter else {
         return Ok(());
     };
+    parse_metadata_filter(filter).map(|_| ())
+}
+
+/// Converts a `MetadataFilter` into a retrieval `SearchFilter`; unknown fields are rejected.
+pub fn parse_metadata_filter(filter: &MetadataFilter) -> Result<SearchFilter> {
+    let mut out = SearchFilter::default();
+    for (key, value) in &filter.fields {
+        match key.as_str() {
+            "tags" => parse_tags_filter(value, &mut out)?,
+            "mime" => parse_mime_filter(value, &mut out)?,
+            "mtime" => out.mtime = Some(parse_mtime_filter(value)?),
+            _ if key.starts_with(FRONT_MATTER_TAG_PREFIX) => {
+                parse_front_matter_filter(key, value, &mut out)?;
+            }
+            _ => {
+                return Err(AxiomError::Validation(format!(
+                    "unknown filter field: {key} (supported: {FILTER_FIELDS})"
+                )));
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn parse_tags_filter(value: &serde_json::Value, out: &mut SearchFilter) -> Result<()> {
+    if value.is_array() {
+        out.tags = filter_string_list("tags", value)?;
+        return Ok(());
+    }
+    let operators = filter_operator_object("tags", value, TAG_FILTER_OPERATORS)?;
+    for (op, operand) in operators {
+        match op.as_str() {
+            "all" => out.tags = filter_string_list("tags.all", operand)?,
+            "any" => out.any_tags = filter_string_list("tags.any", operand)?,
+            "not" => out.exclude_tags = filter_string_list("tags.not", operand)?,
+            _ => return Err(unknown_filter_operator("tags", op, TAG_FILTER_OPERATORS)),
+        }
+    }
+    Ok(())
+}
+
+fn parse_mime_filter(value: &serde_json::Value, out: &mut SearchFilter) -> Result<()> {
+    if let Some(mime) = value.as_str() {
+        out.mime = Some(mime.to_string());
+        return Ok(());
+    }
+    let operators = filter_operator_object("mime", value, MIME_FILTER_OPERATORS)?;
+    for (op, operand) in operators {
+        let target = match op.as_str() {
+            "eq" => &mut out.mime,
+            "not" => &mut out.exclude_mime,
+            _ => return Err(unknown_filter_operator("mime", op, MIME_FILTER_OPERATORS)),
+        };
+        let mime = operand
+            .as_str()
+            .ok_or_else(|| AxiomError::Validation(format!("filter mime.{op} must be a string")))?;
+        *target = Some(mime.to_string());
+    }
+    Ok(())
+}
 
-    let allowed = ["tags", "mime"];
-    for key in filter.fields.keys() {
-        if !allowed.contains(&key.as_str()) {
+/// Front matter scalars are indexed as `fm.<key>:<value>` tags, so filter on those tags.
+fn parse_front_matter_filter(
+    key: &str,
+    value: &serde_json::Value,
+    out: &mut SearchFilter,
+) -> Result<()> {
+    if key.len() == FRONT_MATTER_TAG_PREFIX.len() {
+        return Err(AxiomError::Validation(
+            "filter fm.<key> requires a front matter key".to_string(),
+        ));
+    }
+    if !value.is_object() {
+        out.tags.push(front_matter_filter_tag(key, key, value)?);
+        return Ok(());
+    }
+    let operators = filter_operator_object(key, value, FRONT_MATTER_FILTER_OPERATORS)?;
+    for (op, operand) in operators {
+        let target = match op.as_str() {
+            "eq" => &mut out.tags,
+            "not" => &mut out.exclude_tags,
+            _ => {
+                return Err(unknown_filter_operator(
+                    key,
+                    op,
+                    FRONT_MATTER_FILTER_OPERATORS,
+                ));
+            }
+        };
+        target.push(front_matter_filter_tag(
+            key,
+            &format!("{key}.{op}"),
+            operand,
+        )?);
+    }
+    Ok(())
+}
+
+fn front_matter_filter_tag(key: &str, label: &str, value: &serde_json::Value) -> Result<String> {
+    let text = match value {
+        serde_json::Value::String(text) => text.trim().to_string(),
+        serde_json::Value::Bool(_) | serde_json::Value::Number(_) => value.to_string(),
+        _ => {
             return Err(AxiomError::Validation(format!(
-                "unknown filter field: {key}"
+                "filter {label} must be a string, number, or bool"
             )));
         }
+    };
+    Ok(format!("{key}:{text}"))
+}
+
+fn parse_mtime_filter(value: &serde_json::Value) -> Result<TimeRange> {
+    let operators = filter_operator_object("mtime", value, MTIME_FILTER_OPERATORS)?;
+    let mut range = TimeRange::default();
+    for (op, operand) in operators {
+        let target = match op.as_str() {
+            "gte" => &mut range.gte,
+            "gt" => &mut range.gt,
+            "lte" => &mut range.lte,
+            "lt" => &mut range.lt,
+            _ => return Err(unknown_filter_operator("mtime", op, MTIME_FILTER_OPERATORS)),
+        };
+        let raw = operand.as_str().ok_or_else(|| {
+            AxiomError::Validation(format!("filter mtime.{op} must be an RFC3339 string"))
+        })?;
+        let parsed = DateTime::parse_from_rfc3339(raw).map_err(|err| {
+            AxiomError::Validation(format!("filter mtime.{op} is not RFC3339 ({raw}): {err}"))
+        })?;
+        *target = Some(parsed.with_timezone(&Utc));
     }
-    Ok(())
+    if range.is_unbounded() {
+        return Err(AxiomError::Validation(format!(
+            "filter mtime requires at least one operator (supported: {MTIME_FILTER_OPERATORS})"
+        )));
+    }
+    Ok(range)
+}
+
+fn filter_operator_object<'a>(
+    field: &str,
+    value: &'a serde_json::Value,
+    supported: &str,
+) -> Result<&'a serde_json::Map<String, serde_json::Value>> {
+    value.as_object().ok_or_else(|| {
+        AxiomError::Validation(format!(
+            "filter {field} must be a value or an operator object (supported operators: {supported})"
+        ))
+    })
+}
+
+fn filter_string_list(label: &str, value: &serde_json::Value) -> Result<Vec<String>> {
+    let items = value
+        .as_array()
+        .ok_or_else(|| AxiomError::Validation(format!("filter {label} must be a string array")))?;
+    items
+        .iter()
+        .map(|item| {
+            item.as_str().map(ToString::to_string).ok_or_else(|| {
+                AxiomError::Validation(format!("filter {label} must contain only strings"))
+            })
+        })
+        .collect()
+}
+
+fn unknown_filter_operator(field: &str, op: &str, supported: &str) -> AxiomError {
+    AxiomError::Validation(format!(
+        "unknown filter operator {field}.{op} (supported: {supported})"
+    ))
 }
 
 pub struct RecordInput<'a> {
@@ -131,6 +296,84 @@ mod tests {
         assert!(matches!(err, AxiomError::Validation(_)));
     }
 
+    #[test]
+    fn parse_metadata_filter_supports_negation_and_mtime_range() {
+        let mut fields = HashMap::new();
+        fields.insert(
+            "tags".to_string(),
+            serde_json::json!({"all": ["auth"], "any": ["oauth", "sso"], "not": ["archived"]}),
+        );
+        fields.insert("mime".to_string(), serde_json::json!({"not": "text/plain"}));
+        fields.insert(
+            "mtime".to_string(),
+            serde_json::json!({"gte": "2024-06-01T00:00:00Z"}),
+        );
+        let parsed = parse_metadata_filter(&MetadataFilter { fields }).expect("parse");
+        assert_eq!(parsed.tags, vec!["auth".to_string()]);
+        assert_eq!(parsed.exclude_tags, vec!["archived".to_string()]);
+        assert_eq!(
+            parsed.any_tags,
+            vec!["oauth".to_string(), "sso".to_string()]
+        );
+        assert_eq!(parsed.exclude_mime.as_deref(), Some("text/plain"));
+        let range = parsed.mtime.expect("mtime");
+        assert_eq!(
+            range.gte.map(|value| value.to_rfc3339()).as_deref(),
+            Some("2024-06-01T00:00:00+00:00")
+        );
+        assert!(range.gt.is_none());
+    }
+
+    #[test]
+    fn parse_metadata_filter_maps_front_matter_keys_to_tags() {
+        let mut fields = HashMap::new();
+        fields.insert("fm.status".to_string(), serde_json::json!("draft"));
+        fields.insert("fm.review.round".to_string(), serde_json::json!({"not": 3}));
+        let parsed = parse_metadata_filter(&MetadataFilter { fields }).expect("parse");
+        assert_eq!(parsed.tags, vec!["fm.status:draft".to_string()]);
+        assert_eq!(parsed.exclude_tags, vec!["fm.review.round:3".to_string()]);
+
+        for value in [
+            serde_json::json!(["draft"]),
+            serde_json::json!({"gte": "a"}),
+        ] {
+            let mut fields = HashMap::new();
+            fields.insert("fm.status".to_string(), value);
+            let err = validate_filter(Some(&MetadataFilter { fields })).expect_err("must fail");
+            assert!(matches!(err, AxiomError::Validation(_)));
+        }
+        let mut fields = HashMap::new();
+        fields.insert("fm.".to_string(), serde_json::json!("draft"));
+        assert!(validate_filter(Some(&MetadataFilter { fields })).is_err());
+    }
+
+    #[test]
+    fn validate_filter_rejects_unknown_operator_with_supported_list() {
+        let mut fields = HashMap::new();
+        fields.insert("tags".to_string(), serde_json::json!({"nope": ["x"]}));
+        let err = validate_filter(Some(&MetadataFilter { fields })).expect_err("must fail");
+        let AxiomError::Validation(message) = err else {
+            panic!("expected validation error");
+        };
+        assert!(message.contains("tags.nope"));
+        assert!(message.contains("all, any, not"));
+
+        let mut fields = HashMap::new();
+        fields.insert(
+            "created_at".to_string(),
+            serde_json::json!({"gte": "2024-06-01T00:00:00Z"}),
+        );
+        let err = validate_filter(Some(&MetadataFilter { fields })).expect_err("must fail");
+        assert!(
+            matches!(err, AxiomError::Validation(message) if message.contains("tags, mime, mtime"))
+        );
+
+        let mut fields = HashMap::new();
+        fields.insert("mtime".to_string(), serde_json::json!({"gte": "yesterday"}));
+        let err = validate_filter(Some(&MetadataFilter { fields })).expect_err("must fail");
+        assert!(matches!(err, AxiomError::Validation(_)));
+    }
+
     #[test]
     fn classify_context_maps_memory_and_skill_paths() {
         let memory = AxiomUri::parse("axiom://user/memories/preferences/rust.md").expect("parse");
@@ -1,5 +1,5 @@
 use std::collections::HashSet;
-use std::sync::{Mutex, OnceLock};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError, RwLock};
 
 use reqwest::Url;
 use reqwest::blocking::Client;
@@ -19,13 +19,15 @@ const DEFAULT_MODEL_ENDPOINT: &str = "http://127.0.0.1:11434/api/embeddings";
 const DEFAULT_MODEL_NAME: &str = "nomic-embed-text";
 const MAX_MODEL_INPUT_CHARS: usize = 16 * 1024;
 
+/// Embedder choice and model endpoint, from `AXIOMSYNC_EMBEDDER*` or `AxiomSync::set_embedding_provider`.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
-pub(crate) struct EmbedderRuntimeConfig {
-    pub(crate) kind: Option<String>,
-    pub(crate) model_endpoint: Option<String>,
-    pub(crate) model_name: Option<String>,
-    pub(crate) model_timeout_ms: Option<u64>,
-    pub(crate) strict: bool,
+pub struct EmbedderRuntimeConfig {
+    /// Name accepted by `resolve_embedder_kind`, e.g. `semantic-lite` or `hash`.
+    pub kind: Option<String>,
+    pub model_endpoint: Option<String>,
+    pub model_name: Option<String>,
+    pub model_timeout_ms: Option<u64>,
+    pub strict: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -253,7 +255,13 @@ impl Embedder for SemanticModelHttpEmbedder {
     }
 }
 
-static ACTIVE_EMBEDDER: OnceLock<Box<dyn Embedder>> = OnceLock::new();
+struct ActiveEmbedder {
+    config: EmbedderRuntimeConfig,
+    embedder: Arc<dyn Embedder>,
+}
+
+static ACTIVE_EMBEDDER: OnceLock<RwLock<ActiveEmbedder>> = OnceLock::new();
+/// First config passed to `configure_runtime`; the active one lives in `ACTIVE_EMBEDDER`.
 static EMBEDDER_RUNTIME_CONFIG: OnceLock<EmbedderRuntimeConfig> = OnceLock::new();
 static STRICT_EMBEDDER_ERROR: OnceLock<Mutex<Option<String>>> = OnceLock::new();
 
@@ -298,10 +306,35 @@ pub fn embedding_profile() -> EmbeddingProfile {
     }
 }
 
+/// Replaces the process-wide embedder and returns the previous profile.
+/// An invalid explicit model endpoint is rejected instead of falling back.
+pub(crate) fn switch_runtime(config: EmbedderRuntimeConfig) -> Result<EmbeddingProfile> {
+    let embedder: Arc<dyn Embedder> = match resolve_embedder_kind(config.kind.as_deref()) {
+        EmbedderKind::SemanticModelHttp => Arc::new(
+            SemanticModelHttpEmbedder::from_config(&config).map_err(AxiomError::Validation)?,
+        ),
+        EmbedderKind::SemanticLite | EmbedderKind::Hash => build_embedder(&config),
+    };
+    let mut active = active_embedder_slot()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    let previous = EmbeddingProfile {
+        provider: active.embedder.provider().to_string(),
+        vector_version: active.embedder.vector_version().to_string(),
+        dim: EMBED_DIM,
+    };
+    *active = ActiveEmbedder { config, embedder };
+    drop(active);
+    clear_embedding_strict_error();
+    Ok(previous)
+}
+
 #[must_use]
 pub fn embedding_strict_mode() -> bool {
-    EMBEDDER_RUNTIME_CONFIG
-        .get_or_init(EmbedderRuntimeConfig::default)
+    active_embedder_slot()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .config
         .strict
 }
 
@@ -408,30 +441,42 @@ fn flush_token(out: &mut Vec<String>, current: &mut String) {
     out.push(std::mem::take(current));
 }
 
-fn active_embedder() -> &'static dyn Embedder {
-    ACTIVE_EMBEDDER
-        .get_or_init(|| {
-            let runtime = EMBEDDER_RUNTIME_CONFIG.get_or_init(EmbedderRuntimeConfig::default);
-            let kind = resolve_embedder_kind(runtime.kind.as_deref());
-            match kind {
-                EmbedderKind::SemanticLite => Box::new(SemanticLiteEmbedder),
-                EmbedderKind::Hash => Box::new(HashEmbedder),
-                EmbedderKind::SemanticModelHttp => {
-                    match SemanticModelHttpEmbedder::from_config(runtime) {
-                        Ok(embedder) => Box::new(embedder),
-                        Err(err) => {
-                            if embedding_strict_mode() {
-                                record_strict_embedder_error(&format!(
-                                    "semantic-model-http initialization failed: {err}"
-                                ));
-                            }
-                            Box::new(SemanticLiteEmbedder)
-                        }
-                    }
+fn active_embedder_slot() -> &'static RwLock<ActiveEmbedder> {
+    ACTIVE_EMBEDDER.get_or_init(|| {
+        let config = EMBEDDER_RUNTIME_CONFIG
+            .get_or_init(EmbedderRuntimeConfig::default)
+            .clone();
+        RwLock::new(ActiveEmbedder {
+            embedder: build_embedder(&config),
+            config,
+        })
+    })
+}
+
+fn active_embedder() -> Arc<dyn Embedder> {
+    active_embedder_slot()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .embedder
+        .clone()
+}
+
+fn build_embedder(config: &EmbedderRuntimeConfig) -> Arc<dyn Embedder> {
+    match resolve_embedder_kind(config.kind.as_deref()) {
+        EmbedderKind::SemanticLite => Arc::new(SemanticLiteEmbedder),
+        EmbedderKind::Hash => Arc::new(HashEmbedder),
+        EmbedderKind::SemanticModelHttp => match SemanticModelHttpEmbedder::from_config(config) {
+            Ok(embedder) => Arc::new(embedder),
+            Err(err) => {
+                if config.strict {
+                    record_strict_embedder_error(&format!(
+                        "semantic-model-http initialization failed: {err}"
+                    ));
                 }
+                Arc::new(SemanticLiteEmbedder)
             }
-        })
-        .as_ref()
+        },
+    }
 }
 
 fn record_strict_embedder_error(message: &str) {
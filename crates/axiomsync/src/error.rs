@@ -71,6 +71,14 @@ pub enum AxiomError {
     #[error("conflict: {0}")]
     Conflict(String),
 
+    /// Keeps `current_etag` so callers can offer a merge.
+    #[error("conflict: etag mismatch for {uri}")]
+    EtagMismatch {
+        uri: String,
+        expected_etag: String,
+        current_etag: String,
+    },
+
     #[error("permission denied: {0}")]
     PermissionDenied(String),
 
@@ -93,6 +101,24 @@ pub enum AxiomError {
         message: String,
     },
 
+    #[error("embedding failure: {0}")]
+    Embedding(String),
+
+    /// `current_bytes` is the tracked usage before this write.
+    #[error(
+        "quota exceeded for {scope}: {current_bytes} + {requested_bytes} bytes exceeds limit {limit_bytes}"
+    )]
+    QuotaExceeded {
+        scope: String,
+        current_bytes: u64,
+        requested_bytes: u64,
+        limit_bytes: u64,
+    },
+
+    /// `depth` counts `new` + `processing` outbox events.
+    #[error("queue backpressure: depth {depth} reached high-water mark {high_water_mark}")]
+    QueueBackpressure { depth: u64, high_water_mark: u64 },
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
@@ -108,6 +134,9 @@ pub enum AxiomError {
     #[error(transparent)]
     Http(#[from] reqwest::Error),
 
+    #[error("cancelled: {0}")]
+    Cancelled(String),
+
     #[error("internal error: {0}")]
     Internal(String),
 }
@@ -142,7 +171,7 @@ impl AxiomError {
             Self::InvalidScope(_) => "INVALID_SCOPE",
             Self::PathTraversal(_) => "PATH_TRAVERSAL",
             Self::NotFound(_) => "NOT_FOUND",
-            Self::Conflict(_) => "CONFLICT",
+            Self::Conflict(_) | Self::EtagMismatch { .. } => "CONFLICT",
             Self::PermissionDenied(_) => "PERMISSION_DENIED",
             Self::InvalidArchive(_) => "INVALID_ARCHIVE",
             Self::SecurityViolation(_) => "SECURITY_VIOLATION",
@@ -153,11 +182,15 @@ impl AxiomError {
                 OmInferenceFailureKind::Fatal => "OM_INFERENCE_FATAL",
                 OmInferenceFailureKind::Schema => "OM_INFERENCE_SCHEMA",
             },
+            Self::Embedding(_) => "EMBEDDING_FAILED",
+            Self::QuotaExceeded { .. } => "QUOTA_EXCEEDED",
+            Self::QueueBackpressure { .. } => "QUEUE_BACKPRESSURE",
             Self::Io(_) => "IO_ERROR",
             Self::Json(_) => "JSON_ERROR",
             Self::Sqlite(_) => "SQLITE_ERROR",
             Self::Zip(_) => "ZIP_ERROR",
             Self::Http(_) => "HTTP_ERROR",
+            Self::Cancelled(_) => "CANCELLED",
             Self::Internal(_) => "INTERNAL_ERROR",
         }
     }
@@ -169,7 +202,39 @@ impl AxiomError {
             operation: operation.into(),
             trace_id: Uuid::new_v4().to_string(),
             uri,
-            details: None,
+            details: self.payload_details(),
+        }
+    }
+
+    fn payload_details(&self) -> Option<serde_json::Value> {
+        match self {
+            Self::EtagMismatch {
+                expected_etag,
+                current_etag,
+                ..
+            } => Some(serde_json::json!({
+                "expected_etag": expected_etag,
+                "current_etag": current_etag,
+            })),
+            Self::QuotaExceeded {
+                scope,
+                current_bytes,
+                requested_bytes,
+                limit_bytes,
+            } => Some(serde_json::json!({
+                "scope": scope,
+                "current_bytes": current_bytes,
+                "requested_bytes": requested_bytes,
+                "limit_bytes": limit_bytes,
+            })),
+            Self::QueueBackpressure {
+                depth,
+                high_water_mark,
+            } => Some(serde_json::json!({
+                "depth": depth,
+                "high_water_mark": high_water_mark,
+            })),
+            _ => None,
         }
     }
 }
@@ -3,13 +3,21 @@ use crate::models::{
     ReplayReport,
 };
 
-pub const fn accumulate_replay_report(total: &mut ReplayReport, report: &ReplayReport) {
+pub fn accumulate_replay_report(total: &mut ReplayReport, report: &ReplayReport) {
     total.fetched = total.fetched.saturating_add(report.fetched);
     total.processed = total.processed.saturating_add(report.processed);
     total.done = total.done.saturating_add(report.done);
     total.dead_letter = total.dead_letter.saturating_add(report.dead_letter);
     total.requeued = total.requeued.saturating_add(report.requeued);
     total.skipped = total.skipped.saturating_add(report.skipped);
+    for (reason, count) in &report.dead_letter_reasons {
+        let merged = total.dead_letter_reasons.entry(reason.clone()).or_default();
+        *merged = merged.saturating_add(*count);
+    }
+    for (reason, count) in &report.failed_reasons {
+        let merged = total.failed_reasons.entry(reason.clone()).or_default();
+        *merged = merged.saturating_add(*count);
+    }
 }
 
 pub const fn checkpoint_advanced(baseline: Option<i64>, final_checkpoint: Option<i64>) -> bool {
@@ -139,6 +147,8 @@ pub const fn evidence_status(passed: bool) -> EvidenceStatus {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use super::*;
     use crate::models::{OmQueueStatus, OmReflectionApplyMetrics, QueueCounts, QueueDiagnostics};
 
@@ -151,6 +161,7 @@ mod tests {
                 ..QueueCounts::default()
             },
             checkpoints: Vec::new(),
+            priority_lanes: Vec::new(),
             queue_dead_letter_rate: Vec::new(),
             om_status: OmQueueStatus::default(),
             om_reflection_apply_metrics: OmReflectionApplyMetrics::default(),
@@ -187,6 +198,8 @@ mod tests {
             dead_letter: 1,
             requeued: 2,
             skipped: 0,
+            dead_letter_reasons: BTreeMap::from([("IO_ERROR".to_string(), 1)]),
+            failed_reasons: BTreeMap::new(),
         };
         let input = ReliabilityEvidenceInput {
             replay_totals: &replay_totals,
@@ -221,6 +234,8 @@ mod tests {
             dead_letter: 4,
             requeued: 5,
             skipped: 6,
+            dead_letter_reasons: BTreeMap::from([("EMBEDDING_FAILED".to_string(), 4)]),
+            failed_reasons: BTreeMap::from([("INTERNAL_ERROR".to_string(), 1)]),
         };
         let report = ReplayReport {
             fetched: 7,
@@ -229,6 +244,11 @@ mod tests {
             dead_letter: 10,
             requeued: 11,
             skipped: 12,
+            dead_letter_reasons: BTreeMap::from([
+                ("EMBEDDING_FAILED".to_string(), 6),
+                ("UNHANDLED_EVENT".to_string(), 4),
+            ]),
+            failed_reasons: BTreeMap::from([("INTERNAL_ERROR".to_string(), 2)]),
         };
         accumulate_replay_report(&mut total, &report);
         assert_eq!(total.fetched, 8);
@@ -237,6 +257,17 @@ mod tests {
         assert_eq!(total.dead_letter, 14);
         assert_eq!(total.requeued, 16);
         assert_eq!(total.skipped, 18);
+        assert_eq!(
+            total.dead_letter_reasons,
+            BTreeMap::from([
+                ("EMBEDDING_FAILED".to_string(), 10),
+                ("UNHANDLED_EVENT".to_string(), 4),
+            ])
+        );
+        assert_eq!(
+            total.failed_reasons,
+            BTreeMap::from([("INTERNAL_ERROR".to_string(), 3)])
+        );
     }
 
     #[test]
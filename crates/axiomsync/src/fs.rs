@@ -1,22 +1,54 @@
 use std::fs;
-use std::io::Write;
+use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use globset::{Glob, GlobSetBuilder};
 use walkdir::WalkDir;
 
+use crate::client::FaultInjector;
+use crate::config::FaultPoint;
 use crate::error::{AxiomError, Result};
-use crate::models::{Entry, TreeNode, TreeResult};
+use crate::models::{Entry, ReadChunk, TrashEntry, TreeNode, TreeResult};
+use crate::quota::{ScopeQuota, tree_size_bytes};
 use crate::uri::{AxiomUri, Scope};
 
+const UTF8_MAX_CHAR_LEN: usize = 4;
+const TRASH_MANIFEST_FILE_NAME: &str = ".trash.json";
+
 #[derive(Debug, Clone)]
 pub struct LocalContextFs {
     root: PathBuf,
+    quota: Option<Arc<ScopeQuota>>,
+    fault_injector: Option<Arc<FaultInjector>>,
 }
 
 impl LocalContextFs {
     pub fn new(root: impl Into<PathBuf>) -> Self {
-        Self { root: root.into() }
+        Self {
+            root: root.into(),
+            quota: None,
+            fault_injector: None,
+        }
+    }
+
+    /// Writes, deletes and moves update `quota` usage; writes past the limit are rejected.
+    #[must_use]
+    pub(crate) fn with_quota(mut self, quota: Arc<ScopeQuota>) -> Self {
+        self.quota = Some(quota);
+        self
+    }
+
+    /// File writes check `FaultPoint::FsWrite` inside the quota critical section.
+    #[must_use]
+    pub(crate) fn with_fault_injector(mut self, fault_injector: Arc<FaultInjector>) -> Self {
+        self.fault_injector = Some(fault_injector);
+        self
+    }
+
+    pub(crate) fn quota(&self) -> Option<&ScopeQuota> {
+        self.quota.as_deref()
     }
 
     #[must_use]
@@ -33,6 +65,7 @@ impl LocalContextFs {
             Scope::Session,
             Scope::Temp,
             Scope::Queue,
+            Scope::Trash,
         ] {
             let path = self.root.join(scope.as_str());
             fs::create_dir_all(path)?;
@@ -91,6 +124,11 @@ impl LocalContextFs {
         Self::ensure_writable(uri, system)?;
         let path = self.resolve_uri(uri);
         self.ensure_path_within_root(&path)?;
+        if let Some(quota) = self.quota()
+            && !path.is_dir()
+        {
+            quota.ensure_within_limit(uri.scope())?;
+        }
         fs::create_dir_all(path)?;
         Ok(())
     }
@@ -109,15 +147,72 @@ impl LocalContextFs {
         Ok(fs::read_to_string(path)?)
     }
 
+    /// Reads at most `len` bytes starting at `offset` without loading the whole file.
+    ///
+    /// The returned slice never splits a UTF-8 character: a start inside a character moves
+    /// forward to the next boundary and the end is clamped back, unless that would leave the
+    /// chunk empty, in which case the first character is returned whole.
+    pub fn read_range(&self, uri: &AxiomUri, offset: u64, len: usize) -> Result<ReadChunk> {
+        Self::ensure_range_readable(uri)?;
+        if len == 0 {
+            return Err(AxiomError::Validation(
+                "read range length must be > 0".to_string(),
+            ));
+        }
+        let path = self.resolve_uri(uri);
+        if !path.exists() {
+            return Err(AxiomError::NotFound(uri.to_string()));
+        }
+        if path.is_dir() {
+            return Err(AxiomError::Validation(format!(
+                "cannot read directory: {uri}"
+            )));
+        }
+        self.ensure_path_within_root(&path)?;
+
+        let mut file = fs::File::open(&path)?;
+        let total_size = file.metadata()?.len();
+        if offset > total_size {
+            return Err(AxiomError::Validation(format!(
+                "read offset {offset} exceeds file size {total_size}: {uri}"
+            )));
+        }
+        file.seek(SeekFrom::Start(offset))?;
+        let window = len.saturating_add(UTF8_MAX_CHAR_LEN - 1);
+        let mut buf = Vec::new();
+        file.take(u64::try_from(window).unwrap_or(u64::MAX))
+            .read_to_end(&mut buf)?;
+
+        let (start, end) = utf8_chunk_bounds(&buf, len);
+        let content = String::from_utf8(buf[start..end].to_vec()).map_err(|_| {
+            AxiomError::Validation(format!("file is not valid UTF-8 within range: {uri}"))
+        })?;
+        let chunk_offset = offset.saturating_add(start as u64);
+        Ok(ReadChunk {
+            uri: uri.to_string(),
+            offset: chunk_offset,
+            content,
+            total_size,
+            eof: offset.saturating_add(end as u64) >= total_size,
+        })
+    }
+
     pub fn write(&self, uri: &AxiomUri, content: &str, system: bool) -> Result<()> {
         Self::ensure_writable(uri, system)?;
         let path = self.resolve_uri(uri);
         self.ensure_path_within_root(&path)?;
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        fs::write(path, content)?;
-        Ok(())
+        self.write_with_quota(
+            uri,
+            &path,
+            |_| content.len() as u64,
+            || {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, content)?;
+                Ok(())
+            },
+        )
     }
 
     pub fn write_atomic(&self, uri: &AxiomUri, content: &str, system: bool) -> Result<()> {
@@ -127,63 +222,83 @@ impl LocalContextFs {
         let parent = path
             .parent()
             .ok_or_else(|| AxiomError::Validation(format!("target has no parent: {uri}")))?;
-        fs::create_dir_all(parent)?;
-
-        let file_name = path
-            .file_name()
-            .and_then(|x| x.to_str())
-            .ok_or_else(|| AxiomError::Validation(format!("invalid target filename: {uri}")))?;
-        let tmp_name = format!(
-            ".{file_name}.axiomsync.tmp.{}",
-            uuid::Uuid::new_v4().simple()
-        );
-        let tmp_path = parent.join(tmp_name);
-        self.ensure_path_within_root(&tmp_path)?;
-
-        {
-            let mut tmp = fs::OpenOptions::new()
-                .create_new(true)
-                .write(true)
-                .open(&tmp_path)?;
-            tmp.write_all(content.as_bytes())?;
-            tmp.sync_all()?;
-        }
+        self.write_with_quota(
+            uri,
+            &path,
+            |_| content.len() as u64,
+            || {
+                fs::create_dir_all(parent)?;
+
+                let file_name = path.file_name().and_then(|x| x.to_str()).ok_or_else(|| {
+                    AxiomError::Validation(format!("invalid target filename: {uri}"))
+                })?;
+                let tmp_name = format!(
+                    ".{file_name}.axiomsync.tmp.{}",
+                    uuid::Uuid::new_v4().simple()
+                );
+                let tmp_path = parent.join(tmp_name);
+                self.ensure_path_within_root(&tmp_path)?;
+
+                {
+                    let mut tmp = fs::OpenOptions::new()
+                        .create_new(true)
+                        .write(true)
+                        .open(&tmp_path)?;
+                    tmp.write_all(content.as_bytes())?;
+                    tmp.sync_all()?;
+                }
 
-        if let Err(err) = fs::rename(&tmp_path, &path) {
-            let _ = fs::remove_file(&tmp_path);
-            return Err(AxiomError::from(err));
-        }
+                if let Err(err) = fs::rename(&tmp_path, &path) {
+                    let _ = fs::remove_file(&tmp_path);
+                    return Err(AxiomError::from(err));
+                }
 
-        if let Ok(dir) = fs::File::open(parent) {
-            let _ = dir.sync_all();
-        }
-        Ok(())
+                if let Ok(dir) = fs::File::open(parent) {
+                    let _ = dir.sync_all();
+                }
+                Ok(())
+            },
+        )
     }
 
     pub fn append(&self, uri: &AxiomUri, content: &str, system: bool) -> Result<()> {
         Self::ensure_writable(uri, system)?;
         let path = self.resolve_uri(uri);
         self.ensure_path_within_root(&path)?;
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        let mut file = fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(path)?;
-        file.write_all(content.as_bytes())?;
-        Ok(())
+        self.write_with_quota(
+            uri,
+            &path,
+            |previous| previous.saturating_add(content.len() as u64),
+            || {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                let mut file = fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&path)?;
+                file.write_all(content.as_bytes())?;
+                Ok(())
+            },
+        )
     }
 
     pub fn write_bytes(&self, uri: &AxiomUri, bytes: &[u8], system: bool) -> Result<()> {
         Self::ensure_writable(uri, system)?;
         let path = self.resolve_uri(uri);
         self.ensure_path_within_root(&path)?;
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        fs::write(path, bytes)?;
-        Ok(())
+        self.write_with_quota(
+            uri,
+            &path,
+            |_| bytes.len() as u64,
+            || {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, bytes)?;
+                Ok(())
+            },
+        )
     }
 
     pub fn read_bytes(&self, uri: &AxiomUri) -> Result<Vec<u8>> {
@@ -286,6 +401,7 @@ impl LocalContextFs {
             return Ok(());
         }
         self.ensure_path_within_root(&path)?;
+        let released_bytes = self.quota.as_ref().map(|_| tree_size_bytes(&path));
         if path.is_dir() {
             if recursive {
                 fs::remove_dir_all(path)?;
@@ -295,7 +411,171 @@ impl LocalContextFs {
         } else {
             fs::remove_file(path)?;
         }
-        Ok(())
+        self.release_quota(uri.scope(), released_bytes)
+    }
+
+    /// Moves `uri` to `axiom://trash/<timestamp>/<scope>/<path>`; returns `None` if it is missing.
+    pub fn move_to_trash(&self, uri: &AxiomUri, recursive: bool) -> Result<Option<TrashEntry>> {
+        Self::ensure_writable(uri, false)?;
+        if uri.scope().is_internal() {
+            return Err(AxiomError::PermissionDenied(format!(
+                "trash is not available for scope: {}",
+                uri.scope()
+            )));
+        }
+        if uri.is_root() {
+            return Err(AxiomError::PermissionDenied(format!(
+                "scope root cannot be moved to trash: {uri}"
+            )));
+        }
+        let path = self.resolve_uri(uri);
+        if !path.exists() {
+            return Ok(None);
+        }
+        self.ensure_path_within_root(&path)?;
+        let is_dir = path.is_dir();
+        if is_dir && !recursive && fs::read_dir(&path)?.next().is_some() {
+            return Err(AxiomError::Validation(format!(
+                "directory is not empty: {uri}"
+            )));
+        }
+
+        // Back-to-back moves (e.g. `rm_glob`) can land on the same clock tick.
+        let mut deleted_at = Utc::now();
+        let (bucket_uri, bucket_path) = loop {
+            let bucket_uri = AxiomUri::root(Scope::Trash)
+                .join(&deleted_at.format("%Y%m%dT%H%M%S%9fZ").to_string())?;
+            let bucket_path = self.resolve_uri(&bucket_uri);
+            if !bucket_path.exists() {
+                break (bucket_uri, bucket_path);
+            }
+            deleted_at += chrono::Duration::nanoseconds(1);
+        };
+        let mut trash_uri = bucket_uri.join(uri.scope().as_str())?;
+        for segment in uri.segments() {
+            trash_uri = trash_uri.join(segment)?;
+        }
+        let trash_path = self.resolve_uri(&trash_uri);
+        if let Some(parent) = trash_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let released_bytes = self.quota.as_ref().map(|_| tree_size_bytes(&path));
+
+        let entry = TrashEntry {
+            trash_uri: trash_uri.to_string(),
+            original_uri: uri.to_string(),
+            deleted_at,
+            is_dir,
+        };
+        // Manifest first, so a failed rename only leaves a bucket to clean up.
+        fs::write(
+            bucket_path.join(TRASH_MANIFEST_FILE_NAME),
+            serde_json::to_string_pretty(&entry)?,
+        )?;
+        if let Err(err) = fs::rename(&path, &trash_path) {
+            let _ = fs::remove_dir_all(&bucket_path);
+            return Err(err.into());
+        }
+        self.release_quota(uri.scope(), released_bytes)?;
+        Ok(Some(entry))
+    }
+
+    /// Newest first; buckets without a readable manifest are skipped.
+    pub fn list_trash(&self) -> Result<Vec<TrashEntry>> {
+        let trash_path = self.resolve_uri(&AxiomUri::root(Scope::Trash));
+        if !trash_path.is_dir() {
+            return Ok(Vec::new());
+        }
+        let mut entries = Vec::new();
+        for bucket in fs::read_dir(trash_path)? {
+            let manifest_path = bucket?.path().join(TRASH_MANIFEST_FILE_NAME);
+            let Ok(raw) = fs::read_to_string(manifest_path) else {
+                continue;
+            };
+            if let Ok(entry) = serde_json::from_str::<TrashEntry>(&raw) {
+                entries.push(entry);
+            }
+        }
+        entries.sort_by(|a, b| {
+            b.deleted_at
+                .cmp(&a.deleted_at)
+                .then_with(|| a.trash_uri.cmp(&b.trash_uri))
+        });
+        Ok(entries)
+    }
+
+    /// Accepts a bucket uri or the item uri inside it.
+    pub fn restore_from_trash(&self, trash_uri: &AxiomUri) -> Result<TrashEntry> {
+        let Some(bucket) = trash_uri
+            .segments()
+            .first()
+            .filter(|_| trash_uri.scope() == Scope::Trash)
+        else {
+            return Err(AxiomError::Validation(format!(
+                "not a trash entry uri: {trash_uri}"
+            )));
+        };
+        let bucket_uri = AxiomUri::root(Scope::Trash).join(bucket)?;
+        let bucket_path = self.resolve_uri(&bucket_uri);
+        let manifest_path = bucket_path.join(TRASH_MANIFEST_FILE_NAME);
+        if !manifest_path.is_file() {
+            return Err(AxiomError::NotFound(format!("trash entry: {trash_uri}")));
+        }
+        let entry = serde_json::from_str::<TrashEntry>(&fs::read_to_string(manifest_path)?)?;
+        if trash_uri.segments().len() > 1 && trash_uri.to_string() != entry.trash_uri {
+            return Err(AxiomError::Validation(format!(
+                "trash uri does not match entry {}: {trash_uri}",
+                entry.trash_uri
+            )));
+        }
+
+        let original = AxiomUri::parse(&entry.original_uri)?;
+        let item_path = self.resolve_uri(&AxiomUri::parse(&entry.trash_uri)?);
+        let original_path = self.resolve_uri(&original);
+        self.ensure_path_within_root(&item_path)?;
+        self.ensure_path_within_root(&original_path)?;
+        if !item_path.exists() {
+            return Err(AxiomError::NotFound(format!(
+                "trash entry content: {}",
+                entry.trash_uri
+            )));
+        }
+        if original_path.exists() {
+            return Err(AxiomError::Conflict(format!(
+                "restore target already exists: {original}"
+            )));
+        }
+        if let Some(parent) = original_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        match self.quota() {
+            Some(quota) => quota.reserve_with(
+                original.scope(),
+                || (0, tree_size_bytes(&item_path)),
+                || Ok(fs::rename(&item_path, &original_path)?),
+            )?,
+            None => fs::rename(&item_path, &original_path)?,
+        }
+        fs::remove_dir_all(bucket_path)?;
+        Ok(entry)
+    }
+
+    pub fn purge_trash(&self, deleted_before: DateTime<Utc>) -> Result<Vec<TrashEntry>> {
+        let mut purged = Vec::new();
+        for entry in self.list_trash()? {
+            if entry.deleted_at > deleted_before {
+                continue;
+            }
+            let trash_uri = AxiomUri::parse(&entry.trash_uri)?;
+            let Some(bucket) = trash_uri.segments().first() else {
+                continue;
+            };
+            let bucket_path = self.resolve_uri(&AxiomUri::root(Scope::Trash).join(bucket)?);
+            self.ensure_path_within_root(&bucket_path)?;
+            fs::remove_dir_all(bucket_path)?;
+            purged.push(entry);
+        }
+        Ok(purged)
     }
 
     pub fn mv(&self, from: &AxiomUri, to: &AxiomUri, system: bool) -> Result<()> {
@@ -305,11 +585,31 @@ impl LocalContextFs {
         let to_path = self.resolve_uri(to);
         self.ensure_path_within_root(&from_path)?;
         self.ensure_path_within_root(&to_path)?;
+        if !from_path.exists() {
+            return Err(AxiomError::NotFound(from.to_string()));
+        }
+        if to_path.exists() {
+            return Err(AxiomError::Conflict(format!(
+                "move destination already exists: {to}"
+            )));
+        }
         if let Some(parent) = to_path.parent() {
             fs::create_dir_all(parent)?;
         }
-        fs::rename(from_path, to_path)?;
-        Ok(())
+        let moved_bytes = self
+            .quota
+            .as_ref()
+            .filter(|_| from.scope() != to.scope())
+            .map(|_| tree_size_bytes(&from_path));
+        match (self.quota(), moved_bytes) {
+            (Some(quota), Some(bytes)) => quota.reserve_with(
+                to.scope(),
+                || (0, bytes),
+                || Ok(fs::rename(&from_path, &to_path)?),
+            )?,
+            _ => fs::rename(&from_path, &to_path)?,
+        }
+        self.release_quota(from.scope(), moved_bytes)
     }
 
     pub fn tree(&self, uri: &AxiomUri) -> Result<TreeResult> {
@@ -346,11 +646,56 @@ impl LocalContextFs {
         Ok(node)
     }
 
+    /// Sizes `path` and runs `write` inside one quota critical section.
+    fn write_with_quota(
+        &self,
+        uri: &AxiomUri,
+        path: &Path,
+        new_len: impl FnOnce(u64) -> u64,
+        write: impl FnOnce() -> Result<()>,
+    ) -> Result<()> {
+        let write = || {
+            if let Some(fault_injector) = &self.fault_injector {
+                fault_injector.check(FaultPoint::FsWrite)?;
+            }
+            write()
+        };
+        match self.quota() {
+            Some(quota) => quota.reserve_with(
+                uri.scope(),
+                || {
+                    let previous = file_len(path);
+                    (previous, new_len(previous))
+                },
+                write,
+            ),
+            None => write(),
+        }
+    }
+
+    fn release_quota(&self, scope: Scope, released_bytes: Option<u64>) -> Result<()> {
+        match (self.quota(), released_bytes) {
+            (Some(quota), Some(bytes)) => quota.release(scope, bytes),
+            _ => Ok(()),
+        }
+    }
+
     fn ensure_writable(uri: &AxiomUri, system: bool) -> Result<()> {
-        if !system && matches!(uri.scope(), Scope::Queue) {
-            return Err(AxiomError::PermissionDenied(
-                "queue scope is read-only for non-system operations".to_string(),
-            ));
+        if !system && matches!(uri.scope(), Scope::Queue | Scope::Trash) {
+            return Err(AxiomError::PermissionDenied(format!(
+                "{} scope is read-only for non-system operations",
+                uri.scope()
+            )));
+        }
+        Ok(())
+    }
+
+    fn ensure_range_readable(uri: &AxiomUri) -> Result<()> {
+        if uri.scope().is_internal() {
+            return Err(AxiomError::PermissionDenied(format!(
+                "range reads are not allowed for scope: {}",
+                uri.scope()
+            )));
         }
         Ok(())
     }
@@ -396,6 +741,36 @@ impl LocalContextFs {
     }
 }
 
+fn file_len(path: &Path) -> u64 {
+    fs::metadata(path)
+        .ok()
+        .filter(std::fs::Metadata::is_file)
+        .map_or(0, |meta| meta.len())
+}
+
+const fn is_utf8_continuation(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+fn utf8_chunk_bounds(buf: &[u8], len: usize) -> (usize, usize) {
+    let start = buf
+        .iter()
+        .take(UTF8_MAX_CHAR_LEN - 1)
+        .position(|byte| !is_utf8_continuation(*byte))
+        .unwrap_or_else(|| buf.len().min(UTF8_MAX_CHAR_LEN - 1));
+    let mut end = start.saturating_add(len).min(buf.len());
+    while end > start && end < buf.len() && is_utf8_continuation(buf[end]) {
+        end -= 1;
+    }
+    if end == start && start < buf.len() {
+        end = start + 1;
+        while end < buf.len() && is_utf8_continuation(buf[end]) {
+            end += 1;
+        }
+    }
+    (start, end)
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -421,6 +796,101 @@ mod tests {
         assert!(matches!(err, AxiomError::PermissionDenied(_)));
     }
 
+    #[test]
+    fn scope_quota_rejects_the_first_write_past_the_limit() {
+        let temp = tempdir().expect("tempdir");
+        let state =
+            crate::state::SqliteStateStore::open(temp.path().join("state.db")).expect("state");
+        let quota = ScopeQuota::new(
+            crate::config::QuotaConfig::default().with_limit_bytes(Scope::Session, 10),
+            state,
+            temp.path().join("root"),
+        );
+        let fs = LocalContextFs::new(temp.path().join("root")).with_quota(Arc::new(quota));
+        fs.initialize().expect("init failed");
+
+        let first = AxiomUri::parse("axiom://session/s1/a.txt").expect("parse");
+        let second = AxiomUri::parse("axiom://session/s1/b.txt").expect("parse");
+        fs.write(&first, "123456", true).expect("6 bytes");
+        fs.write(&second, "7890", true).expect("exactly at limit");
+
+        let err = fs.append(&second, "x", true).expect_err("one byte over");
+        assert!(matches!(
+            err,
+            AxiomError::QuotaExceeded {
+                current_bytes: 10,
+                requested_bytes: 1,
+                limit_bytes: 10,
+                ..
+            }
+        ));
+        assert_eq!(err.code(), "QUOTA_EXCEEDED");
+        assert_eq!(fs.read(&second).expect("unchanged"), "7890");
+
+        fs.write(&first, "12", true).expect("shrinking overwrite");
+        fs.write_atomic(&second, "12345678", true)
+            .expect("freed bytes are reusable");
+        fs.rm(&first, false, true).expect("rm");
+        fs.write(&first, "ab", true).expect("rm releases usage");
+
+        let resources = AxiomUri::parse("axiom://resources/big.txt").expect("parse");
+        fs.write(&resources, &"x".repeat(64), false)
+            .expect("unlimited scope");
+        let status = fs.quota().expect("quota").status().expect("status");
+        assert_eq!(status.len(), 1);
+        assert_eq!(status[0].scope, "session");
+        assert_eq!(status[0].usage_bytes, 10);
+        assert_eq!(status[0].limit_bytes, 10);
+    }
+
+    #[test]
+    fn scope_quota_usage_is_unchanged_when_fs_write_fails() {
+        let temp = tempdir().expect("tempdir");
+        let state =
+            crate::state::SqliteStateStore::open(temp.path().join("state.db")).expect("state");
+        let quota = Arc::new(ScopeQuota::new(
+            crate::config::QuotaConfig::default().with_limit_bytes(Scope::Session, 100),
+            state,
+            temp.path().join("root"),
+        ));
+        let fs = LocalContextFs::new(temp.path().join("root")).with_quota(Arc::clone(&quota));
+        fs.initialize().expect("init failed");
+        let usage = || quota.status().expect("status")[0].usage_bytes;
+
+        let existing = AxiomUri::parse("axiom://session/s1/existing.txt").expect("parse");
+        fs.write(&existing, "1234", true).expect("existing");
+        assert_eq!(usage(), 4);
+
+        let faulty = fs.clone().with_fault_injector(Arc::new(FaultInjector::new(
+            crate::config::parse_fault_spec("fs_write:fail_rate=1").expect("fault spec"),
+        )));
+        let target = AxiomUri::parse("axiom://session/s1/a.txt").expect("parse");
+        faulty.write(&target, "abcdef", true).expect_err("write");
+        faulty
+            .write_atomic(&target, "abcdef", true)
+            .expect_err("write_atomic");
+        faulty
+            .append(&existing, "abcdef", true)
+            .expect_err("append");
+        faulty
+            .write_bytes(&target, b"abcdef", true)
+            .expect_err("write_bytes");
+        assert_eq!(usage(), 4);
+        assert!(!fs.exists(&target));
+        assert_eq!(fs.read(&existing).expect("unchanged"), "1234");
+
+        // A regular file where the destination parent should be makes the rename fail.
+        let source = AxiomUri::parse("axiom://resources/moved.txt").expect("parse");
+        fs.write(&source, "abcdef", false).expect("source");
+        let blocked = AxiomUri::parse("axiom://session/s1/existing.txt/moved.txt").expect("parse");
+        fs.mv(&source, &blocked, true).expect_err("mv");
+        assert_eq!(usage(), 4);
+        assert!(fs.exists(&source));
+
+        fs.append(&existing, "56", true).expect("append");
+        assert_eq!(usage(), 6);
+    }
+
     #[test]
     fn append_supports_incremental_log_writes() {
         let temp = tempdir().expect("tempdir");
@@ -435,6 +905,77 @@ mod tests {
         assert!(raw.contains("{\"b\":2}"));
     }
 
+    #[test]
+    fn read_range_returns_slices_with_size_and_eof() {
+        let temp = tempdir().expect("tempdir");
+        let fs = LocalContextFs::new(temp.path());
+        fs.initialize().expect("init failed");
+
+        let uri = AxiomUri::parse("axiom://resources/docs/range.txt").expect("parse");
+        fs.write(&uri, "abcdefghij", true).expect("write");
+
+        let head = fs.read_range(&uri, 0, 4).expect("head");
+        assert_eq!(head.content, "abcd");
+        assert_eq!(head.offset, 0);
+        assert_eq!(head.total_size, 10);
+        assert!(!head.eof);
+
+        let tail = fs.read_range(&uri, 8, 16).expect("tail");
+        assert_eq!(tail.content, "ij");
+        assert!(tail.eof);
+
+        let err = fs.read_range(&uri, 11, 4).expect_err("offset past end");
+        assert!(matches!(err, AxiomError::Validation(_)));
+    }
+
+    #[test]
+    fn read_range_clamps_to_utf8_char_boundaries() {
+        let temp = tempdir().expect("tempdir");
+        let fs = LocalContextFs::new(temp.path());
+        fs.initialize().expect("init failed");
+
+        let uri = AxiomUri::parse("axiom://resources/docs/hangul.txt").expect("parse");
+        fs.write(&uri, "a가나다", true).expect("write");
+
+        let clamped_end = fs.read_range(&uri, 0, 3).expect("clamped end");
+        assert_eq!(clamped_end.content, "a");
+
+        let shifted_start = fs.read_range(&uri, 2, 6).expect("shifted start");
+        assert_eq!(shifted_start.offset, 4);
+        assert_eq!(shifted_start.content, "나다");
+        assert!(shifted_start.eof);
+
+        let widened = fs.read_range(&uri, 1, 1).expect("widened");
+        assert_eq!(widened.content, "가");
+    }
+
+    #[test]
+    fn read_range_empty_file_returns_eof_chunk() {
+        let temp = tempdir().expect("tempdir");
+        let fs = LocalContextFs::new(temp.path());
+        fs.initialize().expect("init failed");
+
+        let uri = AxiomUri::parse("axiom://resources/docs/empty.txt").expect("parse");
+        fs.write(&uri, "", true).expect("write");
+
+        let chunk = fs.read_range(&uri, 0, 64).expect("empty read");
+        assert!(chunk.content.is_empty());
+        assert_eq!(chunk.total_size, 0);
+        assert!(chunk.eof);
+    }
+
+    #[test]
+    fn read_range_rejects_internal_scopes() {
+        let temp = tempdir().expect("tempdir");
+        let fs = LocalContextFs::new(temp.path());
+        fs.initialize().expect("init failed");
+
+        let uri = AxiomUri::parse("axiom://queue/logs/requests.jsonl").expect("parse");
+        fs.append(&uri, "{}\n", true).expect("append");
+        let err = fs.read_range(&uri, 0, 8).expect_err("queue scope");
+        assert!(matches!(err, AxiomError::PermissionDenied(_)));
+    }
+
     #[test]
     fn write_atomic_overwrites_existing_file() {
         let temp = tempdir().expect("tempdir");
@@ -1,7 +1,8 @@
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
 use crate::embedding::{embed_text, tokenize_features};
+use crate::error::{AxiomError, Result};
 use crate::models::{IndexRecord, SearchFilter};
 use crate::uri::{AxiomUri, Scope};
 use ancestry::{
@@ -33,6 +34,58 @@ const W_DENSE: f32 = 0.33;
 const W_SPARSE: f32 = 0.20;
 const W_RECENCY: f32 = 0.03;
 const W_PATH: f32 = 0.02;
+const W_HYBRID: f32 = W_DENSE + W_SPARSE;
+
+/// Index changes kept for query cache revalidation.
+const MAX_TRACKED_CHANGES: usize = 4096;
+
+/// Lexical (sparse) vs semantic (dense) mix, normalized to 1 over the `W_DENSE + W_SPARSE` share.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HybridWeights {
+    lexical: f32,
+    semantic: f32,
+}
+
+impl HybridWeights {
+    pub fn new(lexical: f32, semantic: f32) -> Result<Self> {
+        for (name, value) in [("lexical", lexical), ("semantic", semantic)] {
+            if !value.is_finite() || value < 0.0 {
+                return Err(AxiomError::Validation(format!(
+                    "{name} weight must be finite and >= 0, got {value}"
+                )));
+            }
+        }
+        let total = lexical + semantic;
+        if total <= 0.0 {
+            return Err(AxiomError::Validation(
+                "lexical and semantic weights must not both be 0".to_string(),
+            ));
+        }
+        Ok(Self {
+            lexical: lexical / total,
+            semantic: semantic / total,
+        })
+    }
+
+    #[must_use]
+    pub const fn lexical(&self) -> f32 {
+        self.lexical
+    }
+
+    #[must_use]
+    pub const fn semantic(&self) -> f32 {
+        self.semantic
+    }
+}
+
+impl Default for HybridWeights {
+    fn default() -> Self {
+        Self {
+            lexical: W_SPARSE / W_HYBRID,
+            semantic: W_DENSE / W_HYBRID,
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct ScoredRecord {
@@ -73,6 +126,11 @@ pub struct InMemoryIndex {
     exact_keys: HashMap<Arc<str>, ExactRecordKeys>,
     children_by_parent: HashMap<Arc<str>, BTreeMap<Arc<str>, ChildIndexEntry>>,
     total_doc_length: usize,
+    embedded_vector_count: usize,
+    /// Bumped on every record change; never reset by `clear`.
+    version: u64,
+    /// Recent `(version, uri)` changes; `None` marks a `clear`.
+    changes: VecDeque<(u64, Option<Arc<str>>)>,
 }
 
 #[derive(Debug)]
@@ -119,6 +177,11 @@ impl InMemoryIndex {
     }
 
     pub fn upsert(&mut self, record: IndexRecord) {
+        self.upsert_with_vector(record, None);
+    }
+
+    /// Upserts a record, reusing `vector` instead of re-embedding when one is supplied.
+    pub fn upsert_with_vector(&mut self, record: IndexRecord, vector: Option<Vec<f32>>) {
         let key: Arc<str> = Arc::from(record.uri.as_str());
         let has_existing = self.records.contains_key(key.as_ref());
         let previous_parent_uri = if has_existing {
@@ -137,7 +200,10 @@ impl InMemoryIndex {
             depth: record.depth,
         };
         let parent_uri = record.parent_uri.clone();
-        let payload = build_index_document_payload(&record);
+        if vector.is_none() {
+            self.embedded_vector_count = self.embedded_vector_count.saturating_add(1);
+        }
+        let payload = build_index_document_payload(&record, vector);
         for token in payload.term_freq.keys() {
             *self.doc_freqs.entry(token.clone()).or_insert(0) += 1;
         }
@@ -150,7 +216,8 @@ impl InMemoryIndex {
         self.exact_keys.insert(key.clone(), payload.exact_keys);
         self.vectors.insert(key.clone(), payload.vector);
         self.records.insert(key.clone(), record);
-        self.upsert_child_index_entry(parent_uri.as_deref(), key, child_entry);
+        self.upsert_child_index_entry(parent_uri.as_deref(), key.clone(), child_entry);
+        self.record_change(Some(key));
     }
 
     pub fn remove(&mut self, uri: &str) {
@@ -163,6 +230,7 @@ impl InMemoryIndex {
         self.vectors.remove(uri);
         self.remove_lexical_stats(uri);
         self.exact_keys.remove(uri);
+        self.record_change(Some(Arc::from(uri)));
     }
 
     pub fn clear(&mut self) {
@@ -177,6 +245,38 @@ impl InMemoryIndex {
         self.exact_keys.clear();
         self.children_by_parent.clear();
         self.total_doc_length = 0;
+        self.record_change(None);
+    }
+
+    /// Corpus version the query cache validates entries against.
+    #[must_use]
+    pub const fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// URIs changed after `version`; `None` if a `clear` happened or the log no longer reaches back.
+    #[must_use]
+    pub fn touched_since(&self, version: u64) -> Option<Vec<&str>> {
+        if version == self.version {
+            return Some(Vec::new());
+        }
+        let oldest = self.changes.front()?.0;
+        if version.wrapping_add(1) < oldest || version > self.version {
+            return None;
+        }
+        self.changes
+            .iter()
+            .filter(|(changed_at, _)| *changed_at > version)
+            .map(|(_, uri)| uri.as_deref())
+            .collect()
+    }
+
+    fn record_change(&mut self, uri: Option<Arc<str>>) {
+        self.version = self.version.wrapping_add(1);
+        if self.changes.len() == MAX_TRACKED_CHANGES {
+            self.changes.pop_front();
+        }
+        self.changes.push_back((self.version, uri));
     }
 
     #[must_use]
@@ -184,6 +284,17 @@ impl InMemoryIndex {
         self.records.get(uri)
     }
 
+    #[must_use]
+    pub fn vector(&self, uri: &str) -> Option<&[f32]> {
+        self.vectors.get(uri).map(Vec::as_slice)
+    }
+
+    /// Number of upserts that computed a fresh embedding instead of reusing a supplied vector.
+    #[must_use]
+    pub const fn embedded_vector_count(&self) -> usize {
+        self.embedded_vector_count
+    }
+
     #[must_use]
     pub fn all_records(&self) -> Vec<IndexRecord> {
         let mut out: Vec<_> = self.records.values().cloned().collect();
@@ -296,11 +407,37 @@ fn apply_weighted_token_features(
     }
 }
 
-fn build_index_document_payload(record: &IndexRecord) -> IndexDocumentPayload {
+/// Hash of the text a record embeds, used to decide whether a persisted vector is still valid.
+///
+/// Tags are normalized the way the state store persists them so a restored record hashes the
+/// same as the one originally indexed.
+#[must_use]
+pub fn embedding_text_hash(record: &IndexRecord) -> String {
+    let mut tags = record
+        .tags
+        .iter()
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect::<Vec<_>>();
+    tags.sort();
+    tags.dedup();
+    let mut hasher = blake3::Hasher::new();
+    for part in [&record.name, &record.abstract_text, &record.content] {
+        hasher.update(part.as_bytes());
+        hasher.update(&[0]);
+    }
+    hasher.update(tags.join(" ").as_bytes());
+    hasher.finalize().to_hex().to_string()
+}
+
+fn build_index_document_payload(
+    record: &IndexRecord,
+    vector: Option<Vec<f32>>,
+) -> IndexDocumentPayload {
     let exact_keys = ExactRecordKeys::from_record(record);
     let text = build_upsert_text(record);
     let text_lower = text.to_lowercase();
-    let vector = embed_text(&text);
+    let vector = vector.unwrap_or_else(|| embed_text(&text));
     let mut term_freq = HashMap::new();
     apply_weighted_token_features(&mut term_freq, tokenize_features(&text), 1, 1);
     apply_weighted_token_features(&mut term_freq, tokenize_features(&record.name), 2, 3);
@@ -1128,6 +1265,10 @@ mod tests {
         let filter = SearchFilter {
             tags: vec!["auth".to_string()],
             mime: None,
+            exclude_tags: Vec::new(),
+            any_tags: Vec::new(),
+            exclude_mime: None,
+            mtime: None,
         };
         let result = index.search("docs", None, 20, None, Some(&filter));
         assert!(
@@ -1206,6 +1347,10 @@ mod tests {
         let filter = SearchFilter {
             tags: vec!["auth".to_string()],
             mime: None,
+            exclude_tags: Vec::new(),
+            any_tags: Vec::new(),
+            exclude_mime: None,
+            mtime: None,
         };
         let result = index.search("docs", None, 20, None, Some(&filter));
         assert!(
@@ -1276,6 +1421,10 @@ mod tests {
         let filter = SearchFilter {
             tags: vec!["auth".to_string()],
             mime: None,
+            exclude_tags: Vec::new(),
+            any_tags: Vec::new(),
+            exclude_mime: None,
+            mtime: None,
         };
         let docs = index.get("axiom://resources/docs").expect("docs record");
         let other = index.get("axiom://resources/other").expect("other record");
@@ -1316,6 +1465,10 @@ mod tests {
         let filter = SearchFilter {
             tags: vec![],
             mime: Some("text/markdown".to_string()),
+            exclude_tags: Vec::new(),
+            any_tags: Vec::new(),
+            exclude_mime: None,
+            mtime: None,
         };
         let result = index.search("schema guide", None, 20, None, Some(&filter));
         assert!(result.iter().any(|x| x.uri.ends_with("guide.md")));
@@ -1,10 +1,14 @@
 use crate::mime::infer_mime;
-use crate::models::{IndexRecord, SearchFilter};
+use crate::models::{IndexRecord, SearchFilter, TimeRange};
 
 #[derive(Debug)]
 pub(super) struct NormalizedFilter {
     pub(super) tags: Vec<String>,
     pub(super) mime: Option<String>,
+    pub(super) exclude_tags: Vec<String>,
+    pub(super) any_tags: Vec<String>,
+    pub(super) exclude_mime: Option<String>,
+    pub(super) mtime: Option<TimeRange>,
 }
 
 pub(super) fn record_matches_filter(
@@ -25,21 +29,41 @@ pub(super) fn record_matches_filter(
 
 pub(super) fn normalize_filter(filter: Option<&SearchFilter>) -> Option<NormalizedFilter> {
     let filter = filter?;
-    let tags = filter
-        .tags
-        .iter()
-        .map(|tag| tag.trim().to_lowercase())
-        .filter(|tag| !tag.is_empty())
-        .collect::<Vec<_>>();
-    let mime = filter
-        .mime
-        .as_ref()
-        .map(|mime| mime.trim().to_lowercase())
-        .filter(|mime| !mime.is_empty());
-    if tags.is_empty() && mime.is_none() {
+    let tags = normalize_filter_tags(&filter.tags);
+    let mime = normalize_filter_mime(filter.mime.as_deref());
+    let exclude_tags = normalize_filter_tags(&filter.exclude_tags);
+    let any_tags = normalize_filter_tags(&filter.any_tags);
+    let exclude_mime = normalize_filter_mime(filter.exclude_mime.as_deref());
+    let mtime = filter.mtime.filter(|range| !range.is_unbounded());
+    if tags.is_empty()
+        && mime.is_none()
+        && exclude_tags.is_empty()
+        && any_tags.is_empty()
+        && exclude_mime.is_none()
+        && mtime.is_none()
+    {
         return None;
     }
-    Some(NormalizedFilter { tags, mime })
+    Some(NormalizedFilter {
+        tags,
+        mime,
+        exclude_tags,
+        any_tags,
+        exclude_mime,
+        mtime,
+    })
+}
+
+fn normalize_filter_tags(tags: &[String]) -> Vec<String> {
+    tags.iter()
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+fn normalize_filter_mime(mime: Option<&str>) -> Option<String> {
+    mime.map(|mime| mime.trim().to_lowercase())
+        .filter(|mime| !mime.is_empty())
 }
 
 pub(super) fn leaf_matches_filter(record: &IndexRecord, filter: &NormalizedFilter) -> bool {
@@ -54,6 +78,26 @@ pub(super) fn leaf_matches_filter(record: &IndexRecord, filter: &NormalizedFilte
         return false;
     }
 
+    if !filter.any_tags.is_empty()
+        && !filter.any_tags.iter().any(|wanted| {
+            record
+                .tags
+                .iter()
+                .any(|tag| tag.eq_ignore_ascii_case(wanted))
+        })
+    {
+        return false;
+    }
+
+    if filter.exclude_tags.iter().any(|excluded| {
+        record
+            .tags
+            .iter()
+            .any(|tag| tag.eq_ignore_ascii_case(excluded))
+    }) {
+        return false;
+    }
+
     if let Some(required_mime) = &filter.mime {
         let Some(record_mime) = infer_mime(record) else {
             return false;
@@ -63,5 +107,17 @@ pub(super) fn leaf_matches_filter(record: &IndexRecord, filter: &NormalizedFilte
         }
     }
 
+    if let Some(excluded_mime) = &filter.exclude_mime
+        && infer_mime(record).is_some_and(|mime| mime.eq_ignore_ascii_case(excluded_mime))
+    {
+        return false;
+    }
+
+    if let Some(range) = &filter.mtime
+        && !range.contains(&record.updated_at)
+    {
+        return false;
+    }
+
     true
 }
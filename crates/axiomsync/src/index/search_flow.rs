@@ -9,7 +9,7 @@ use super::rank::{
     LexicalCorpusView, LexicalDocView, cosine, exact_confidence_bonus, lexical_score, path_score,
     recency_score, score_ordering, uri_path_prefix_match,
 };
-use super::{InMemoryIndex, ScoredRecord};
+use super::{HybridWeights, InMemoryIndex, ScoredRecord};
 
 impl InMemoryIndex {
     pub fn search(
@@ -20,6 +20,27 @@ impl InMemoryIndex {
         score_threshold: Option<f32>,
         filter: Option<&SearchFilter>,
     ) -> Vec<ScoredRecord> {
+        self.search_with_weights(
+            query,
+            target_uri,
+            limit,
+            score_threshold,
+            filter,
+            HybridWeights::default(),
+        )
+    }
+
+    pub fn search_with_weights(
+        &self,
+        query: &str,
+        target_uri: Option<&AxiomUri>,
+        limit: usize,
+        score_threshold: Option<f32>,
+        filter: Option<&SearchFilter>,
+        weights: HybridWeights,
+    ) -> Vec<ScoredRecord> {
+        let w_sparse = super::W_HYBRID * weights.lexical();
+        let w_dense = super::W_HYBRID * weights.semantic();
         let exact_query = ExactQueryKeys::from_query(query);
         let q_embed = embed_text(query);
         let q_tokens = tokenize_set(query);
@@ -86,8 +107,7 @@ impl InMemoryIndex {
                     path,
                     super::W_RECENCY.mul_add(
                         recency,
-                        super::W_SPARSE
-                            .mul_add(sparse, super::W_DENSE.mul_add(dense, exact_component)),
+                        w_sparse.mul_add(sparse, w_dense.mul_add(dense, exact_component)),
                     ),
                 );
             if let Some(threshold) = score_threshold
@@ -120,14 +140,16 @@ impl InMemoryIndex {
         target_uri: Option<&AxiomUri>,
         limit: usize,
         filter: Option<&SearchFilter>,
+        weights: HybridWeights,
     ) -> Vec<ScoredRecord> {
         let mut out = self
-            .search(
+            .search_with_weights(
                 query,
                 target_uri,
                 limit.saturating_mul(4).max(20),
                 None,
                 filter,
+                weights,
             )
             .into_iter()
             .filter(|score| !score.is_leaf)
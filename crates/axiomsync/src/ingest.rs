@@ -10,6 +10,7 @@ use crate::error::{AxiomError, Result};
 use crate::fs::LocalContextFs;
 use crate::models::AddResourceIngestOptions;
 use crate::parse::ParserRegistry;
+use crate::quota::tree_size_bytes;
 use crate::uri::{AxiomUri, Scope};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +32,8 @@ pub struct IngestFileInfo {
     pub title: Option<String>,
     pub preview: String,
     pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -178,6 +181,23 @@ impl IngestSession {
         Ok(manifest)
     }
 
+    /// Removes one staged file; `relative_path` uses the manifest path form.
+    pub fn remove_staged_file(&mut self, relative_path: &str) -> Result<()> {
+        let relative = Path::new(relative_path);
+        if relative.as_os_str().is_empty()
+            || !relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(AxiomError::PathTraversal(relative_path.to_string()));
+        }
+        let path = self.fs.resolve_uri(&self.staged_uri).join(relative);
+        if path.is_file() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
     pub fn finalize_to(&mut self, target_uri: &AxiomUri, mode: IngestFinalizeMode) -> Result<()> {
         let staged_path = self.fs.resolve_uri(&self.staged_uri);
         let target_path = self.fs.resolve_uri(target_uri);
@@ -186,26 +206,37 @@ impl IngestSession {
             fs::create_dir_all(parent)?;
         }
 
-        match mode {
-            IngestFinalizeMode::ReplaceTarget => {
-                remove_path_if_exists(&target_path)?;
-                fs::rename(&staged_path, &target_path)?;
-            }
-            IngestFinalizeMode::MergeIntoTarget => {
-                if !target_path.exists() {
+        let publish = || -> Result<()> {
+            match mode {
+                IngestFinalizeMode::ReplaceTarget => {
+                    remove_path_if_exists(&target_path)?;
                     fs::rename(&staged_path, &target_path)?;
-                } else {
-                    let staged_metadata = fs::symlink_metadata(&staged_path)?;
-                    let target_metadata = fs::symlink_metadata(&target_path)?;
-                    if staged_metadata.is_dir() && target_metadata.is_dir() {
-                        merge_directory_contents(&staged_path, &target_path)?;
-                        fs::remove_dir_all(&staged_path)?;
-                    } else {
-                        remove_path_if_exists(&target_path)?;
+                }
+                IngestFinalizeMode::MergeIntoTarget => {
+                    if !target_path.exists() {
                         fs::rename(&staged_path, &target_path)?;
+                    } else {
+                        let staged_metadata = fs::symlink_metadata(&staged_path)?;
+                        let target_metadata = fs::symlink_metadata(&target_path)?;
+                        if staged_metadata.is_dir() && target_metadata.is_dir() {
+                            merge_directory_contents(&staged_path, &target_path)?;
+                            fs::remove_dir_all(&staged_path)?;
+                        } else {
+                            remove_path_if_exists(&target_path)?;
+                            fs::rename(&staged_path, &target_path)?;
+                        }
                     }
                 }
             }
+            Ok(())
+        };
+        match self.fs.quota() {
+            Some(quota) => quota.reserve_with(
+                target_uri.scope(),
+                || finalize_size_bytes(&staged_path, &target_path, mode),
+                publish,
+            )?,
+            None => publish()?,
         }
         self.finalized = true;
 
@@ -260,6 +291,7 @@ fn scan_manifest_files(parser: &ParserRegistry, staged_path: &Path) -> Result<Ve
             title: parsed.title,
             preview: parsed.text_preview,
             tags: parsed.tags,
+            warnings: parsed.warnings,
         });
     }
 
@@ -294,6 +326,38 @@ fn copy_dir_contents(src: &Path, dst: &Path) -> Result<()> {
     Ok(())
 }
 
+/// `(replaced bytes, published bytes)` for a finalize, mirroring `finalize_to`.
+fn finalize_size_bytes(staged: &Path, target: &Path, mode: IngestFinalizeMode) -> (u64, u64) {
+    let staged_bytes = tree_size_bytes(staged);
+    let merges_into_dir = mode == IngestFinalizeMode::MergeIntoTarget
+        && staged.is_dir()
+        && fs::symlink_metadata(target).is_ok_and(|meta| meta.is_dir());
+    if merges_into_dir {
+        (merged_over_bytes(staged, target), staged_bytes)
+    } else {
+        (tree_size_bytes(target), staged_bytes)
+    }
+}
+
+/// Bytes under `target` that `merge_directory_contents` would replace.
+fn merged_over_bytes(source: &Path, target: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(source) else {
+        return 0;
+    };
+    entries
+        .filter_map(std::result::Result::ok)
+        .map(|entry| {
+            let target_path = target.join(entry.file_name());
+            let target_is_dir = fs::symlink_metadata(&target_path).is_ok_and(|meta| meta.is_dir());
+            if entry.file_type().is_ok_and(|kind| kind.is_dir()) && target_is_dir {
+                merged_over_bytes(&entry.path(), &target_path)
+            } else {
+                tree_size_bytes(&target_path)
+            }
+        })
+        .sum()
+}
+
 fn merge_directory_contents(source: &Path, target: &Path) -> Result<()> {
     fs::create_dir_all(target)?;
 
@@ -333,14 +397,14 @@ fn remove_path_if_exists(path: &Path) -> Result<()> {
 }
 
 #[derive(Debug)]
-struct IngestPathFilter {
+pub(crate) struct IngestPathFilter {
     markdown_only: bool,
     include_hidden: bool,
     exclude: GlobSet,
 }
 
 impl IngestPathFilter {
-    fn new(options: &AddResourceIngestOptions) -> Result<Self> {
+    pub(crate) fn new(options: &AddResourceIngestOptions) -> Result<Self> {
         let mut builder = GlobSetBuilder::new();
         for pattern in &options.exclude_globs {
             let trimmed = pattern.trim();
@@ -364,7 +428,7 @@ impl IngestPathFilter {
         })
     }
 
-    fn allows_directory(&self, relative: &Path) -> bool {
+    pub(crate) fn allows_directory(&self, relative: &Path) -> bool {
         if relative.as_os_str().is_empty() {
             return true;
         }
@@ -374,7 +438,7 @@ impl IngestPathFilter {
         !self.exclude.is_match(relative_to_unix_path(relative))
     }
 
-    fn allows_file(&self, relative: &Path) -> bool {
+    pub(crate) fn allows_file(&self, relative: &Path) -> bool {
         if !self.include_hidden && path_has_hidden_component(relative) {
             return false;
         }
@@ -449,7 +513,7 @@ fn path_has_hidden_component(path: &Path) -> bool {
     })
 }
 
-fn relative_to_unix_path(relative: &Path) -> String {
+pub(crate) fn relative_to_unix_path(relative: &Path) -> String {
     relative
         .components()
         .filter_map(|component| match component {
@@ -5,6 +5,8 @@
     reason = "crate-wide fallible API uses one explicit error type; per-item boilerplate would duplicate contract"
 )]
 
+#[cfg(feature = "async")]
+pub mod async_client;
 pub(crate) mod catalog;
 pub mod client;
 pub(crate) mod config;
@@ -29,8 +31,10 @@ pub mod pack;
 pub mod parse;
 pub(crate) mod quality;
 pub(crate) mod queue_policy;
+pub(crate) mod quota;
 pub(crate) mod relation_documents;
 pub(crate) mod release_gate;
+pub mod remote;
 pub mod retrieval;
 pub(crate) mod security_audit;
 pub mod session;
@@ -39,9 +43,12 @@ pub(crate) mod text;
 pub(crate) mod tier_documents;
 pub mod uri;
 
-pub use client::AxiomSync;
+#[cfg(feature = "async")]
+pub use async_client::AxiomSyncAsync;
+pub use client::{AxiomSync, AxiomSyncWorkspace};
 pub use error::{AxiomError, Result};
 pub(crate) use om::engine::*;
 pub(crate) use om::engine::{addon, inference, model, xml};
+pub use quality::{export_search_result_markdown, format_citations};
 pub use session::Session;
 pub use uri::{AxiomUri, Scope};
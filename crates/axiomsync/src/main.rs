@@ -8,5 +8,10 @@ use crate::cli::Cli;
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
-    commands::run_from_root(&cli.root, cli.command)
+    match cli.workspace_config.as_deref() {
+        Some(config) => {
+            commands::run_from_workspace(config, cli.workspace_root.as_deref(), cli.command)
+        }
+        None => commands::run_from_root(&cli.root, cli.command),
+    }
 }
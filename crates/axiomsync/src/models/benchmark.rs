@@ -18,6 +18,12 @@ pub struct BenchmarkRunOptions {
     #[serde(default)]
     pub trace_expectations: bool,
     pub fixture_name: Option<String>,
+    /// When set, runs only if the current corpus snapshot id matches.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corpus_snapshot_id: Option<String>,
+    /// Concurrency levels to rerun the case set at, filling `concurrency_profiles`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<Vec<usize>>,
 }
 
 impl Default for BenchmarkRunOptions {
@@ -30,6 +36,8 @@ impl Default for BenchmarkRunOptions {
             include_stress: true,
             trace_expectations: false,
             fixture_name: None,
+            corpus_snapshot_id: None,
+            concurrency: None,
         }
     }
 }
@@ -101,10 +109,16 @@ pub struct BenchmarkGateOptions {
     pub min_stress_top1_accuracy: Option<f32>,
     pub max_p95_regression_pct: Option<f32>,
     pub max_top1_regression_pct: Option<f32>,
+    /// Maximum MRR drop versus the previous run, in percent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_mrr_regression_pct: Option<f32>,
     pub window_size: usize,
     pub required_passes: usize,
     pub record: bool,
     pub write_release_check: bool,
+    /// `(concurrency, max_p95_ms)`; fails when that level's p95 exceeds the limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_p95_ms_at_concurrency: Option<(usize, u128)>,
 }
 
 impl Default for BenchmarkGateOptions {
@@ -116,10 +130,12 @@ impl Default for BenchmarkGateOptions {
             min_stress_top1_accuracy: None,
             max_p95_regression_pct: None,
             max_top1_regression_pct: None,
+            max_mrr_regression_pct: None,
             window_size: 1,
             required_passes: 1,
             record: false,
             write_release_check: false,
+            max_p95_ms_at_concurrency: None,
         }
     }
 }
@@ -347,6 +363,9 @@ pub struct BenchmarkQualityMetrics {
     pub top1_accuracy: f32,
     pub ndcg_at_10: f32,
     pub recall_at_10: f32,
+    /// Mean `1 / expected_rank` over graded cases; a missing result counts as 0.
+    #[serde(default)]
+    pub mrr: f32,
     pub error_rate: f32,
 }
 
@@ -378,6 +397,16 @@ pub struct BenchmarkArtifacts {
     pub case_set_uri: String,
 }
 
+/// One parallel run at a concurrency level; `queries_per_second` uses wall-clock time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkConcurrencyProfile {
+    pub concurrency: usize,
+    pub executed_queries: usize,
+    pub wall_ms: u128,
+    pub queries_per_second: f32,
+    pub latency: BenchmarkLatencySummary,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BenchmarkReport {
     pub run_id: String,
@@ -391,6 +420,8 @@ pub struct BenchmarkReport {
     pub acceptance: BenchmarkAcceptanceResult,
     pub artifacts: BenchmarkArtifacts,
     pub results: Vec<BenchmarkCaseResult>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub concurrency_profiles: Vec<BenchmarkConcurrencyProfile>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -399,6 +430,8 @@ pub struct BenchmarkSummary {
     pub created_at: String,
     pub executed_cases: usize,
     pub top1_accuracy: f32,
+    #[serde(default)]
+    pub mrr: f32,
     pub p95_latency_ms: u128,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub p95_latency_us: Option<u128>,
@@ -429,6 +462,8 @@ pub struct BenchmarkTrendReport {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delta_p95_latency_us: Option<i128>,
     pub delta_top1_accuracy: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delta_mrr: Option<f32>,
     pub status: String,
 }
 
@@ -444,6 +479,8 @@ pub struct BenchmarkGateRunResult {
     pub stress_top1_accuracy: Option<f32>,
     pub regression_pct: Option<f32>,
     pub top1_regression_pct: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mrr_regression_pct: Option<f32>,
     pub reasons: Vec<String>,
 }
 
@@ -455,6 +492,10 @@ pub struct BenchmarkGateThresholds {
     pub min_stress_top1_accuracy: Option<f32>,
     pub max_p95_regression_pct: Option<f32>,
     pub max_top1_regression_pct: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_mrr_regression_pct: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_p95_ms_at_concurrency: Option<(usize, u128)>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -471,6 +512,9 @@ pub struct BenchmarkGateSnapshot {
     pub top1_regression_pct: Option<f32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stress_top1_accuracy: Option<f32>,
+    /// `"<previous>!=<latest>"` when the corpus changed; regressions are then not compared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corpus_snapshot_mismatch: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
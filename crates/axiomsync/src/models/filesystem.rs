@@ -1,5 +1,8 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::defaults::default_true;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Entry {
     pub uri: String,
@@ -8,6 +11,66 @@ pub struct Entry {
     pub size: u64,
 }
 
+/// `stat` result; directory `size` sums its files and `child_count` counts direct children.
+/// `content_hash`/`mime` are file-only; tier flags are directory-only.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntryStat {
+    pub uri: String,
+    pub name: String,
+    pub is_dir: bool,
+    pub size: u64,
+    pub mtime: Option<DateTime<Utc>>,
+    pub mime: Option<String>,
+    pub content_hash: Option<String>,
+    pub index_status: EntryIndexStatus,
+    pub has_abstract: bool,
+    pub has_overview: bool,
+    pub relation_count: usize,
+    /// False for read-only internal scopes (`temp`/`queue`/`trash`).
+    pub mutable: bool,
+    pub child_count: Option<usize>,
+}
+
+/// `indexed`: index matches the file; `pending`: file changed since indexing; `missing`: never indexed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryIndexStatus {
+    Indexed,
+    Pending,
+    Missing,
+}
+
+/// `ls_page` result: entries `[offset, offset + limit)` of the uri-sorted listing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryPage {
+    pub entries: Vec<Entry>,
+    pub offset: usize,
+    pub limit: usize,
+    pub total: usize,
+    pub has_more: bool,
+}
+
+/// `rm_glob` result; `uris` are the deleted (or, on dry run, matching) files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RmGlobReport {
+    pub pattern: String,
+    pub base_uri: String,
+    pub dry_run: bool,
+    pub permanent: bool,
+    pub uris: Vec<String>,
+    pub removed: usize,
+    pub index_state_removed: usize,
+}
+
+/// An item moved to `axiom://trash`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub trash_uri: String,
+    pub original_uri: String,
+    pub deleted_at: DateTime<Utc>,
+    pub is_dir: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GlobResult {
     pub matches: Vec<String>,
@@ -40,6 +103,43 @@ pub struct AddResourceResult {
     pub wait_mode: Option<AddResourceWaitMode>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wait_contract: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dedup: Option<AddResourceDedupReport>,
+    /// Duplicate files skipped without any record.
+    #[serde(default)]
+    pub duplicates_skipped: usize,
+    /// Duplicate files skipped but linked to the original via `duplicate-of`.
+    #[serde(default)]
+    pub duplicates_linked: usize,
+}
+
+/// Staged files skipped by `dedup` because their content is already indexed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AddResourceDedupReport {
+    pub kept_files: usize,
+    pub skipped: Vec<AddResourceDuplicate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddResourceDuplicate {
+    pub relative_path: String,
+    pub content_hash: String,
+    /// Existing document with the same content, or the earlier staged file's target URI.
+    pub existing_uri: String,
+    /// Relation id written by `DedupAction::Link`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relation_id: Option<String>,
+}
+
+/// What to record for files dropped by `dedup_by_content`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DedupAction {
+    /// Drop from staging and record nothing.
+    #[default]
+    Skip,
+    /// Drop from staging and link the would-be URI to the original with `duplicate-of`.
+    Link,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,6 +154,9 @@ pub struct AddResourceRequest {
     pub wait_mode: AddResourceWaitMode,
     #[serde(default)]
     pub ingest_options: AddResourceIngestOptions,
+    /// Tags for every document in this add; kept across reindexing and matched by `tags` filters.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 impl AddResourceRequest {
@@ -66,6 +169,47 @@ impl AddResourceRequest {
             timeout_secs: None,
             wait_mode: AddResourceWaitMode::default(),
             ingest_options: AddResourceIngestOptions::default(),
+            tags: Vec::new(),
+        }
+    }
+}
+
+/// Selects what an ovpack export includes; globs match `/`-separated paths relative to the export root.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExportOvpackOptions {
+    /// Drops matching files and whole matching directories.
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    /// When non-empty, only matching files are packed.
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    /// Drops generated `.abstract.md`/`.overview.md` tier files.
+    #[serde(default)]
+    pub skip_tier_files: bool,
+}
+
+/// ovpack import behavior; defaults to no overwrite, indexing and manifest verification.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImportOvpackOptions {
+    #[serde(default)]
+    pub force: bool,
+    #[serde(default = "default_true")]
+    pub vectorize: bool,
+    /// When false, skips size/hash checks against the bundled manifest.
+    #[serde(default = "default_true")]
+    pub verify: bool,
+    /// Imports the pack root as `parent/as_name` instead of its original name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_name: Option<String>,
+}
+
+impl Default for ImportOvpackOptions {
+    fn default() -> Self {
+        Self {
+            force: false,
+            vectorize: true,
+            verify: true,
+            as_name: None,
         }
     }
 }
@@ -78,6 +222,11 @@ pub struct AddResourceIngestOptions {
     pub include_hidden: bool,
     #[serde(default)]
     pub exclude_globs: Vec<String>,
+    /// Applies `dedup_action` to staged files whose blake3 hash is already indexed; not used by `watch`.
+    #[serde(default)]
+    pub dedup_by_content: bool,
+    #[serde(default)]
+    pub dedup_action: DedupAction,
 }
 
 const fn default_include_hidden() -> bool {
@@ -90,6 +239,8 @@ impl Default for AddResourceIngestOptions {
             markdown_only: false,
             include_hidden: true,
             exclude_globs: Vec::new(),
+            dedup_by_content: false,
+            dedup_action: DedupAction::Skip,
         }
     }
 }
@@ -105,10 +256,22 @@ impl AddResourceIngestOptions {
                 ".obsidian/**".to_string(),
                 "**/*.json".to_string(),
             ],
+            dedup_by_content: false,
+            dedup_action: DedupAction::Skip,
         }
     }
 }
 
+/// Byte-range slice of a file, clamped to UTF-8 character boundaries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadChunk {
+    pub uri: String,
+    pub offset: u64,
+    pub content: String,
+    pub total_size: u64,
+    pub eof: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarkdownDocument {
     pub uri: String,
@@ -128,6 +291,76 @@ pub struct MarkdownSaveResult {
     pub save_ms: u128,
     pub reindex_ms: u128,
 }
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchOptions {
+    #[serde(default = "default_watch_interval_ms")]
+    pub interval_ms: u64,
+    #[serde(default)]
+    pub ingest_options: AddResourceIngestOptions,
+    /// Replays the outbox after cycles that enqueued changes.
+    #[serde(default = "default_watch_process_queue")]
+    pub process_queue: bool,
+    /// `None` scans until `WatchHandle::stop()`.
+    #[serde(default)]
+    pub max_cycles: Option<u64>,
+}
+
+const fn default_watch_interval_ms() -> u64 {
+    5_000
+}
+
+const fn default_watch_process_queue() -> bool {
+    true
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            interval_ms: default_watch_interval_ms(),
+            ingest_options: AddResourceIngestOptions::default(),
+            process_queue: default_watch_process_queue(),
+            max_cycles: None,
+        }
+    }
+}
+
+/// Cumulative stats since the watch started; `deleted` is included in `enqueued`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchStats {
+    pub target_uri: String,
+    pub cycles: u64,
+    pub scanned: u64,
+    pub enqueued: u64,
+    pub skipped: u64,
+    pub deleted: u64,
+    pub errors: u64,
+    #[serde(default)]
+    pub last_error: Option<String>,
+}
+
+/// Replaces 1-based `start_line..=end_line` with `replacement`.
+/// `end_line == start_line - 1` inserts before `start_line`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarkdownPatchOp {
+    pub start_line: usize,
+    pub end_line: usize,
+    #[serde(default)]
+    pub replacement: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownPatchResult {
+    pub uri: String,
+    pub etag: String,
+    pub updated_at: String,
+    pub reindexed_root: String,
+    pub applied_ops: usize,
+    /// Sum over ops of `max(removed lines, inserted lines)`.
+    pub changed_lines: usize,
+    pub save_ms: u128,
+    pub reindex_ms: u128,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TreeNode {
     pub uri: String,
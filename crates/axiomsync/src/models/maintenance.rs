@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+/// `compact` retention; a zero day limit treats everything in that category as old.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CompactOptions {
+    /// Reports reclaimable space without deleting anything.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Trace files and `trace_index` rows older than this are removed.
+    pub trace_retention_days: u32,
+    /// Request log lines to keep.
+    pub request_log_max_rows: usize,
+    /// Benchmark/eval reports older than this are removed.
+    pub report_retention_days: u32,
+    /// Recent runs kept per report directory regardless of age.
+    pub reports_keep: usize,
+}
+
+impl Default for CompactOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            trace_retention_days: 30,
+            request_log_max_rows: 10_000,
+            report_retention_days: 30,
+            reports_keep: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CompactCategoryReport {
+    /// `traces` | `request_logs` | `reports` | `sqlite`
+    pub category: String,
+    /// Files, rows or lines removed (or removable on dry run); always 0 for `sqlite`.
+    pub removed: usize,
+    pub reclaimed_bytes: u64,
+}
+
+/// `compact_state` result; sizes include the DB file and its `-wal` file.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StateCompactReport {
+    pub before_bytes: u64,
+    pub after_bytes: u64,
+    pub reclaimed_bytes: u64,
+}
+
+/// `backup_state` result; `path` is the written backup file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StateBackupReport {
+    pub path: String,
+    pub size_bytes: u64,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CompactReport {
+    pub run_id: String,
+    pub dry_run: bool,
+    pub options: CompactOptions,
+    pub categories: Vec<CompactCategoryReport>,
+    pub reclaimed_bytes_total: u64,
+    pub report_uri: String,
+    pub created_at: String,
+}
@@ -2,6 +2,7 @@ mod benchmark;
 mod defaults;
 mod eval;
 mod filesystem;
+mod maintenance;
 mod queue;
 mod reconcile;
 mod release;
@@ -13,10 +14,10 @@ pub use benchmark::{
     BenchmarkAcceptanceCheck, BenchmarkAcceptanceMeasured, BenchmarkAcceptanceResult,
     BenchmarkAcceptanceThresholds, BenchmarkAmortizedQualitySummary, BenchmarkAmortizedReport,
     BenchmarkAmortizedRunSummary, BenchmarkAmortizedSelection, BenchmarkAmortizedTiming,
-    BenchmarkArtifacts, BenchmarkCaseResult, BenchmarkCorpusMetadata, BenchmarkEnvironmentMetadata,
-    BenchmarkFixtureDocument, BenchmarkFixtureSummary, BenchmarkGateArtifacts,
-    BenchmarkGateExecution, BenchmarkGateOptions, BenchmarkGateQuorum, BenchmarkGateResult,
-    BenchmarkGateRunResult, BenchmarkGateSnapshot, BenchmarkGateThresholds,
+    BenchmarkArtifacts, BenchmarkCaseResult, BenchmarkConcurrencyProfile, BenchmarkCorpusMetadata,
+    BenchmarkEnvironmentMetadata, BenchmarkFixtureDocument, BenchmarkFixtureSummary,
+    BenchmarkGateArtifacts, BenchmarkGateExecution, BenchmarkGateOptions, BenchmarkGateQuorum,
+    BenchmarkGateResult, BenchmarkGateRunResult, BenchmarkGateSnapshot, BenchmarkGateThresholds,
     BenchmarkLatencyProfile, BenchmarkLatencySummary, BenchmarkQualityMetrics,
     BenchmarkQuerySetMetadata, BenchmarkReport, BenchmarkRunOptions, BenchmarkRunSelection,
     BenchmarkSummary, BenchmarkTrendReport, ReleaseGateBenchmarkGatePlan,
@@ -29,15 +30,23 @@ pub use eval::{
     EvalRunOptions, EvalRunSelection,
 };
 pub use filesystem::{
-    AddResourceIngestOptions, AddResourceRequest, AddResourceResult, AddResourceWaitMode, Entry,
-    GlobResult, MarkdownDocument, MarkdownSaveResult, TreeNode, TreeResult,
+    AddResourceDedupReport, AddResourceDuplicate, AddResourceIngestOptions, AddResourceRequest,
+    AddResourceResult, AddResourceWaitMode, DedupAction, Entry, EntryIndexStatus, EntryPage,
+    EntryStat, ExportOvpackOptions, GlobResult, ImportOvpackOptions, MarkdownDocument,
+    MarkdownPatchOp, MarkdownPatchResult, MarkdownSaveResult, ReadChunk, RmGlobReport, TrashEntry,
+    TreeNode, TreeResult, WatchOptions, WatchStats,
+};
+pub use maintenance::{
+    CompactCategoryReport, CompactOptions, CompactReport, StateBackupReport, StateCompactReport,
 };
 pub use queue::{
     OmQueueStatus, OmReflectionApplyMetrics, OmV2MigrationReport, OutboxEvent, QueueCheckpoint,
     QueueCounts, QueueDeadLetterRate, QueueDiagnostics, QueueEventStatus, QueueLaneStatus,
-    QueueOverview, QueueStatus, ReplayReport,
+    QueueOverview, QueuePriority, QueuePriorityLaneCounts, QueueStatus, ReplayReport,
+};
+pub use reconcile::{
+    ReconcileDuplicateGroup, ReconcileOptions, ReconcileReport, ReconcileRunStatus,
 };
-pub use reconcile::{ReconcileOptions, ReconcileReport, ReconcileRunStatus};
 pub use release::{
     BenchmarkGateDetails, BlockerRollupGateDetails, BuildQualityGateDetails, CommandProbeResult,
     ContractIntegrityGateDetails, DependencyAuditStatus, DependencyAuditSummary,
@@ -54,18 +63,23 @@ pub use release::{
     SessionMemoryGateDetails,
 };
 pub use search::{
-    BackendStatus, ContextHit, EmbeddingBackendStatus, FindResult, HitBuckets, IndexRecord,
-    MetadataFilter, QueryPlan, RelationLink, RelationSummary, RetrievalStep, RetrievalTrace,
-    RuntimeHint, RuntimeHintKind, ScoreComponents, SearchBudget, SearchFilter, SearchOptions,
-    SearchRequest, TracePoint, TraceStats, TypedQueryPlan, classify_hit_buckets,
+    BackendStatus, BatchSearchItem, ContextHit, EffectiveSearchOptions, EmbeddingBackendStatus,
+    EmbeddingCacheStatus, FindResult, HitBuckets, HitFreshness, IndexRecord, MetadataFilter,
+    NoResultsCause, NoResultsDiagnosis, QueryPlan, QueryResultCacheStatus, RelationGraph,
+    RelationGraphEdge, RelationGraphNode, RelationLink, RelationSummary, RetrievalStep,
+    RetrievalTrace, RuntimeHint, RuntimeHintKind, ScopeQuotaStatus, ScoreComponents, SearchBudget,
+    SearchExplanation, SearchFilter, SearchOptions, SearchProfile, SearchRequest, SearchScopeSet,
+    TimeRange, TracePoint, TraceStats, TypedQueryPlan, WorkspaceSearchItem, classify_hit_buckets,
 };
 pub use session::{
-    CommitMode, CommitResult, CommitStats, ContextUsage, MemoryCandidate, MemoryCategory,
-    MemoryPromotionFact, MemoryPromotionRequest, MemoryPromotionResult, Message,
-    PromotionApplyMode, SearchContext, SessionInfo, SessionMeta,
+    AddMessageOutcome, AssembledContext, AssembledContextTokens, CommitMode, CommitResult,
+    CommitStats, ContextUsage, MemoryCandidate, MemoryCategory, MemoryPromotionFact,
+    MemoryPromotionRequest, MemoryPromotionResult, Message, PromotionApplyMode,
+    SESSION_BUNDLE_VERSION, SearchContext, SessionBundle, SessionBundleArchive, SessionBundleOm,
+    SessionBundleOmThread, SessionInfo, SessionMeta, SessionView,
 };
 pub use trace::{
-    RequestLogEntry, TraceIndexEntry, TraceMetricsReport, TraceMetricsSample,
-    TraceMetricsSnapshotDocument, TraceMetricsSnapshotSummary, TraceMetricsTrendReport,
-    TraceRequestTypeMetrics,
+    RequestLogEntry, TraceDiff, TraceDiffHit, TraceIndexEntry, TraceMetricsReport,
+    TraceMetricsSample, TraceMetricsSnapshotDocument, TraceMetricsSnapshotSummary,
+    TraceMetricsTrendReport, TraceRankChange, TraceRequestTypeMetrics,
 };
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -41,6 +42,64 @@ impl FromStr for QueueEventStatus {
     }
 }
 
+/// Outbox priority; replay takes higher lanes first, FIFO by id within a lane.
+/// Stored as `Low=-1`, `Normal=0`, `High=1`; existing and default events are `Normal`.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord, Default,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum QueuePriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl QueuePriority {
+    pub const fn as_i64(self) -> i64 {
+        match self {
+            Self::Low => -1,
+            Self::Normal => 0,
+            Self::High => 1,
+        }
+    }
+
+    /// Out-of-range values clamp to the nearest priority.
+    pub const fn from_i64(raw: i64) -> Self {
+        if raw < 0 {
+            Self::Low
+        } else if raw == 0 {
+            Self::Normal
+        } else {
+            Self::High
+        }
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Normal => "normal",
+            Self::High => "high",
+        }
+    }
+}
+
+impl std::fmt::Display for QueuePriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// New, processing and dead-letter counts for one priority lane.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct QueuePriorityLaneCounts {
+    pub priority: QueuePriority,
+    pub new_total: u64,
+    pub new_due: u64,
+    pub processing: u64,
+    pub dead_letter: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct QueueLaneStatus {
     pub new_total: u64,
@@ -79,6 +138,9 @@ pub struct QueueCheckpoint {
 pub struct QueueDiagnostics {
     pub counts: QueueCounts,
     pub checkpoints: Vec<QueueCheckpoint>,
+    /// Non-empty lanes only, highest priority first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub priority_lanes: Vec<QueuePriorityLaneCounts>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub queue_dead_letter_rate: Vec<QueueDeadLetterRate>,
     #[serde(default)]
@@ -140,6 +202,8 @@ pub struct OutboxEvent {
     pub status: QueueEventStatus,
     pub attempt_count: u32,
     pub next_attempt_at: Option<String>,
+    #[serde(default)]
+    pub priority: QueuePriority,
 }
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ReplayReport {
@@ -149,6 +213,12 @@ pub struct ReplayReport {
     pub dead_letter: usize,
     pub requeued: usize,
     pub skipped: usize,
+    /// Dead-lettered events by error code; unsupported event types count as `UNHANDLED_EVENT`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dead_letter_reasons: BTreeMap<String, usize>,
+    /// Events whose replay failed on a state error, by error code. They are left for the next replay.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub failed_reasons: BTreeMap<String, usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
+use crate::models::ScopeQuotaStatus;
 use crate::uri::Scope;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -43,6 +44,13 @@ impl FromStr for ReconcileRunStatus {
     }
 }
 
+/// `index_state` entries that normalize to one URI; `uris` are stored verbatim.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReconcileDuplicateGroup {
+    pub canonical_uri: String,
+    pub uris: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReconcileReport {
     pub run_id: String,
@@ -50,9 +58,33 @@ pub struct ReconcileReport {
     pub invalid_uri_entries: usize,
     pub missing_uri_entries: usize,
     pub missing_files_pruned: usize,
+    #[serde(default)]
+    pub duplicate_uri_entries: usize,
+    #[serde(default)]
+    pub duplicates_merged: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub duplicate_uri_groups: Vec<ReconcileDuplicateGroup>,
     pub reindexed_scopes: usize,
     pub dry_run: bool,
     pub drift_uris_sample: Vec<String>,
+    /// Usage recomputed from disk for quota scopes; not persisted on dry runs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub quota_usage: Vec<ScopeQuotaStatus>,
+    /// Index/search documents removed by `check_vectors` for URIs missing on disk.
+    #[serde(default)]
+    pub orphan_vectors_removed: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orphan_vector_samples: Vec<String>,
+    /// Missing relation targets removed by `check_relations`.
+    #[serde(default)]
+    pub dangling_relations_pruned: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dangling_relation_samples: Vec<String>,
+    /// Directories in tiered scopes whose tier files were missing or empty and were rebuilt.
+    #[serde(default)]
+    pub missing_tiers_regenerated: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_tier_samples: Vec<String>,
     pub status: ReconcileRunStatus,
 }
 
@@ -61,6 +93,15 @@ pub struct ReconcileOptions {
     pub dry_run: bool,
     pub scopes: Option<Vec<Scope>>,
     pub max_drift_sample: usize,
+    /// Deletes non-canonical duplicates unless this is a dry run.
+    #[serde(default)]
+    pub fix_duplicates: bool,
+    /// Removes index and search documents whose URIs are missing on disk.
+    #[serde(default)]
+    pub check_vectors: bool,
+    /// Finds missing relation targets and removes them unless this is a dry run.
+    #[serde(default)]
+    pub check_relations: bool,
 }
 
 impl Default for ReconcileOptions {
@@ -69,6 +110,9 @@ impl Default for ReconcileOptions {
             dry_run: false,
             scopes: None,
             max_drift_sample: 50,
+            fix_duplicates: false,
+            check_vectors: false,
+            check_relations: false,
         }
     }
 }
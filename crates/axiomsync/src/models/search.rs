@@ -3,7 +3,8 @@ use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::uri::AxiomUri;
+use crate::error::AxiomError;
+use crate::uri::{AxiomUri, Scope};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RelationSummary {
@@ -25,6 +26,39 @@ pub struct RelationLink {
     pub reason: String,
 }
 
+/// Neighborhood node; `relation_path` lists relation ids from the root.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RelationGraphNode {
+    pub uri: String,
+    pub hops: usize,
+    #[serde(default)]
+    pub relation_path: Vec<String>,
+    /// Linked uri that no longer exists; not expanded.
+    #[serde(default)]
+    pub dangling: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RelationGraphEdge {
+    pub from: String,
+    pub to: String,
+    pub relation_id: String,
+    pub owner_uri: String,
+    pub reason: String,
+}
+
+/// Breadth-first relation graph around a root uri.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RelationGraph {
+    pub root_uri: String,
+    pub max_hops: usize,
+    pub limit: usize,
+    pub nodes: Vec<RelationGraphNode>,
+    pub edges: Vec<RelationGraphEdge>,
+    /// True when `limit` left nodes unvisited.
+    pub truncated: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContextHit {
     pub uri: String,
@@ -39,6 +73,24 @@ pub struct ContextHit {
     pub matched_heading: Option<String>,
     #[serde(default)]
     pub score_components: ScoreComponents,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relation_neighborhood: Option<RelationGraph>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub freshness: Option<HitFreshness>,
+}
+
+/// Index vs disk mtime for a file hit; filled when `AXIOMSYNC_SEARCH_FRESHNESS_CHECK` is on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HitFreshness {
+    /// File mtime recorded at index time.
+    pub indexed_mtime: DateTime<Utc>,
+    /// Current file mtime; absent if the file is gone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_mtime: Option<DateTime<Utc>>,
+    /// How much newer the file is than the index, in ms; 0 when fresh.
+    pub index_age_ms: u64,
+    /// True when the file changed or vanished since indexing.
+    pub possibly_stale: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -66,6 +118,55 @@ pub struct FindResult {
     pub trace: Option<RetrievalTrace>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trace_uri: Option<String>,
+    /// Pass as `SearchRequest.offset` for the next page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<usize>,
+    /// Pass as `SearchRequest.cursor` to fetch the next page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// Set only when `query_results` is empty.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_results_diagnosis: Option<NoResultsDiagnosis>,
+}
+
+/// Detectable reason a search returned nothing.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NoResultsCause {
+    /// Nothing is indexed under the target or planned scopes.
+    ScopeEmpty,
+    /// `exclude_scopes` removed every candidate.
+    ExcludedScopesEliminatedAll,
+    /// `filter` removed every candidate.
+    FilterEliminatedAll,
+    /// Documents match without `score_threshold`/`min_match_tokens`.
+    ThresholdTooHigh,
+    /// The time or node budget ran out first.
+    BudgetExhausted,
+    /// Candidates existed but none matched the query.
+    NoSemanticMatch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NoResultsDiagnosis {
+    pub causes: Vec<NoResultsCause>,
+    /// Index entries under the target or scopes.
+    pub scoped_nodes: usize,
+    /// Of those, entries passing `exclude_scopes` and `filter`.
+    pub candidate_nodes: usize,
+}
+
+/// Per-request batch result, at the same position as its input.
+#[derive(Debug)]
+pub struct BatchSearchItem {
+    pub result: Result<FindResult, AxiomError>,
+}
+
+/// Per-root workspace search result, sorted by root name.
+#[derive(Debug)]
+pub struct WorkspaceSearchItem {
+    pub root: String,
+    pub result: Result<FindResult, AxiomError>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
@@ -143,6 +244,42 @@ pub struct SearchFilter {
     pub tags: Vec<String>,
     #[serde(default)]
     pub mime: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub any_tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude_mime: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mtime: Option<TimeRange>,
+}
+
+/// `updated_at` range; only the given bounds are checked.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct TimeRange {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<DateTime<Utc>>,
+}
+
+impl TimeRange {
+    #[must_use]
+    pub const fn is_unbounded(&self) -> bool {
+        self.gte.is_none() && self.gt.is_none() && self.lte.is_none() && self.lt.is_none()
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: &DateTime<Utc>) -> bool {
+        self.gte.is_none_or(|bound| *value >= bound)
+            && self.gt.is_none_or(|bound| *value > bound)
+            && self.lte.is_none_or(|bound| *value <= bound)
+            && self.lt.is_none_or(|bound| *value < bound)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -204,6 +341,9 @@ pub struct IndexRecord {
 pub struct SearchOptions {
     pub query: String,
     pub target_uri: Option<AxiomUri>,
+    /// Set only for two or more targets; `target_uri` is then empty and results are merged per uri.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub target_uris: Vec<AxiomUri>,
     pub session: Option<String>,
     #[serde(default)]
     pub session_hints: Vec<String>,
@@ -214,6 +354,13 @@ pub struct SearchOptions {
     pub min_match_tokens: Option<usize>,
     pub filter: Option<SearchFilter>,
     pub request_type: String,
+    #[serde(default)]
+    pub exclude_scopes: Vec<Scope>,
+    #[serde(default)]
+    pub scope_set: SearchScopeSet,
+    /// Hits to skip in the final ranked list.
+    #[serde(default)]
+    pub offset: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -232,6 +379,38 @@ pub struct QueryPlan {
     pub typed_queries: Vec<TypedQueryPlan>,
     #[serde(default)]
     pub notes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_options: Option<EffectiveSearchOptions>,
+}
+
+/// `explain_search` result: the plan search would use, without running retrieval.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchExplanation {
+    pub query_plan: QueryPlan,
+    /// Session hints for retrieval, merged runtime, recent, then OM.
+    #[serde(default)]
+    pub session_hints: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<SearchBudget>,
+    /// Index entries passing target, filter and exclude scopes, before budget.
+    pub estimated_candidate_nodes: usize,
+}
+
+/// Options retrieval actually used after clamping and defaults; query text lives in the trace.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct EffectiveSearchOptions {
+    pub request_type: String,
+    pub target_uri: Option<String>,
+    pub limit: usize,
+    pub score_threshold: Option<f32>,
+    pub min_match_tokens: Option<usize>,
+    pub budget_ms: Option<u64>,
+    pub budget_nodes: usize,
+    pub budget_depth: usize,
+    pub session_hints: usize,
+    pub filter: bool,
+    #[serde(default)]
+    pub exclude_scopes: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -242,16 +421,77 @@ pub struct TypedQueryPlan {
     pub priority: u8,
 }
 
+/// Search metadata filter.
+/// - `tags`: string array (all) or `{"all": [...], "any": [...], "not": [...]}`
+/// - `mime`: string or `{"eq": "...", "not": "..."}`
+/// - `mtime`: `{"gte" | "gt" | "lte" | "lt": RFC3339}` against `updated_at`
+/// - `fm.<key>`: front matter scalar or `{"eq": ..., "not": ...}`; nested keys use dots
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetadataFilter {
     pub fields: HashMap<String, serde_json::Value>,
 }
 
+/// Scopes the planner may use without a target; a target restricts search to its own scope.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchScopeSet {
+    /// `resources`/`user`/`agent`/`session`; no `temp`/`queue`/`trash` artifacts.
+    #[default]
+    AllContent,
+    /// Adds internal scopes to the primary query.
+    AllIncludingInternal,
+    Only(Vec<Scope>),
+}
+
+impl SearchScopeSet {
+    #[must_use]
+    pub fn scopes(&self) -> Vec<Scope> {
+        match self {
+            Self::AllContent => vec![Scope::Resources, Scope::User, Scope::Agent, Scope::Session],
+            Self::AllIncludingInternal => vec![
+                Scope::Resources,
+                Scope::User,
+                Scope::Agent,
+                Scope::Session,
+                Scope::Temp,
+                Scope::Queue,
+                Scope::Trash,
+            ],
+            Self::Only(scopes) => scopes.clone(),
+        }
+    }
+
+    #[must_use]
+    pub const fn is_all_content(&self) -> bool {
+        matches!(self, Self::AllContent)
+    }
+
+    /// Plan note name, e.g. `all_content` or `only:resources,user`.
+    #[must_use]
+    pub fn label(&self) -> String {
+        match self {
+            Self::AllContent => "all_content".to_string(),
+            Self::AllIncludingInternal => "all_including_internal".to_string(),
+            Self::Only(scopes) => format!(
+                "only:{}",
+                scopes
+                    .iter()
+                    .map(Scope::as_str)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchRequest {
     pub query: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub target_uri: Option<String>,
+    /// Extra subtrees searched with `target_uri`; results merge by best score per uri.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub target_uris: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub session: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -266,6 +506,41 @@ pub struct SearchRequest {
     pub budget: Option<SearchBudget>,
     #[serde(default)]
     pub runtime_hints: Vec<RuntimeHint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_scopes: Vec<Scope>,
+    /// Scopes to search without a target; defaults to `AllContent`.
+    #[serde(default, skip_serializing_if = "SearchScopeSet::is_all_content")]
+    pub scope_set: SearchScopeSet,
+    /// Attaches a `relation_neighborhood` of up to this many hops to each hit (max 5).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relation_hops: Option<usize>,
+    /// Re-extracts each hit's `snippet` around the best query overlap, wrapping matches in this delimiter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet_highlight: Option<String>,
+    /// Hits to skip; pass the previous `next_offset` for the next page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    /// `next_cursor` from a previous result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Issues a `next_cursor` for a full first page; without it only `next_offset` is returned.
+    #[serde(default)]
+    pub paginate: bool,
+}
+
+/// Named search defaults used via `search --profile <name>`.
+/// Explicit request values win; filters merge per field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchProfile {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<MetadataFilter>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<SearchBudget>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -292,12 +567,45 @@ pub struct EmbeddingBackendStatus {
     pub dim: usize,
 }
 
+/// Content-hash embedding cache stats; `hits`/`misses` are per process.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct EmbeddingCacheStatus {
+    pub enabled: bool,
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Query result cache stats; `hits`/`misses` are per process.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct QueryResultCacheStatus {
+    pub enabled: bool,
+    pub capacity: usize,
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Tracked usage and limit for a quota scope.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScopeQuotaStatus {
+    pub scope: String,
+    pub usage_bytes: u64,
+    pub limit_bytes: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendStatus {
     pub local_records: usize,
     pub retrieval_backend: String,
     pub retrieval_backend_policy: String,
     pub embedding: EmbeddingBackendStatus,
+    #[serde(default)]
+    pub embedding_cache: EmbeddingCacheStatus,
+    #[serde(default)]
+    pub query_cache: QueryResultCacheStatus,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub quotas: Vec<ScopeQuotaStatus>,
 }
 
 #[cfg(test)]
@@ -327,6 +635,7 @@ mod tests {
                 },
             ],
             notes: vec!["backend:memory".to_string(), "budget_nodes:10".to_string()],
+            effective_options: None,
         };
 
         let encoded = serde_json::to_value(&plan).expect("serialize query plan");
@@ -377,6 +686,8 @@ mod tests {
             snippet: None,
             matched_heading: None,
             score_components: ScoreComponents::default(),
+            relation_neighborhood: None,
+            freshness: None,
         }
     }
 
@@ -411,6 +722,9 @@ mod tests {
             skills: Vec::new(),
             trace: None,
             trace_uri: None,
+            next_offset: None,
+            next_cursor: None,
+            no_results_diagnosis: None,
         };
         result.sync_compat_views();
         let memories = result
@@ -450,6 +764,9 @@ mod tests {
             skills: Vec::new(),
             trace: None,
             trace_uri: None,
+            next_offset: None,
+            next_cursor: None,
+            no_results_diagnosis: None,
         };
 
         result.sync_compat_views();
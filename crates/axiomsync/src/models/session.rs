@@ -1,12 +1,22 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::om::{OmObservationChunk, OmRecord};
+
+pub const SESSION_BUNDLE_VERSION: u32 = 1;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Message {
     pub id: String,
     pub role: String,
     pub text: String,
     pub created_at: DateTime<Utc>,
+    /// Structured turn data such as tool calls; absent in older JSONL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// `axiom://` URIs the turn referenced; passed to memory extraction and the OM observer.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,14 +41,102 @@ pub struct CommitResult {
     pub memories_extracted: usize,
     pub active_count_updated: usize,
     pub archived: bool,
+    #[serde(default)]
+    pub messages_archived: usize,
+    #[serde(default)]
+    pub messages_retained: usize,
     pub stats: CommitStats,
 }
 
+/// `add_message` result, including the commit it triggered, if any.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMessageOutcome {
+    pub message: Message,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_commit: Option<CommitResult>,
+}
+
+/// Portable JSON snapshot of a session: messages, archives, meta, relations and OM state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionBundle {
+    pub version: u32,
+    pub session_id: String,
+    pub exported_at: DateTime<Utc>,
+    pub meta: SessionMeta,
+    #[serde(default)]
+    pub messages: Vec<Message>,
+    #[serde(default)]
+    pub archives: Vec<SessionBundleArchive>,
+    #[serde(default)]
+    pub relations: serde_json::Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub om: Option<SessionBundleOm>,
+}
+
+/// `session show` output: active and archived messages with metadata and attachments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionView {
+    pub session_id: String,
+    pub meta: SessionMeta,
+    pub messages: Vec<Message>,
+    #[serde(default)]
+    pub archives: Vec<SessionBundleArchive>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionBundleArchive {
+    pub number: u32,
+    pub messages: Vec<Message>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionBundleOm {
+    pub record: OmRecord,
+    #[serde(default)]
+    pub observation_chunks: Vec<OmObservationChunk>,
+    #[serde(default)]
+    pub threads: Vec<SessionBundleOmThread>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionBundleOmThread {
+    pub thread_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_observed_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_task: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_response: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchContext {
     pub session_id: String,
     pub recent_messages: Vec<Message>,
 }
+
+/// Session context for prompt assembly: hints first, then newest messages within budget.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssembledContext {
+    pub session_id: String,
+    pub query: String,
+    pub budget_tokens: u32,
+    /// Messages that fit the budget, in chronological order.
+    pub messages: Vec<Message>,
+    /// Hints in the same order search applies them.
+    pub hints: Vec<String>,
+    pub om_hint_applied: bool,
+    pub tokens: AssembledContextTokens,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AssembledContextTokens {
+    pub hint_tokens: u32,
+    pub message_tokens: u32,
+    pub total_tokens: u32,
+    pub dropped_hints: usize,
+    pub dropped_messages: usize,
+}
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ContextUsage {
     pub contexts_used: usize,
@@ -52,6 +150,9 @@ pub struct SessionMeta {
     pub updated_at: DateTime<Utc>,
     #[serde(default)]
     pub context_usage: ContextUsage,
+    /// Last message id moved to the archive; active messages up to it are not archived again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_archived_message_id: Option<String>,
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MemoryCandidate {
@@ -61,7 +162,7 @@ pub struct MemoryCandidate {
     pub source_message_id: String,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum MemoryCategory {
     Profile,
@@ -73,6 +174,15 @@ pub enum MemoryCategory {
 }
 
 impl MemoryCategory {
+    pub const ALL: [Self; 6] = [
+        Self::Profile,
+        Self::Preferences,
+        Self::Entities,
+        Self::Events,
+        Self::Cases,
+        Self::Patterns,
+    ];
+
     #[must_use]
     pub const fn as_str(self) -> &'static str {
         match self {
@@ -98,6 +208,10 @@ pub enum PromotionApplyMode {
 pub enum CommitMode {
     ArchiveAndExtract,
     ArchiveOnly,
+    /// Keeps the last `keep_last` messages active and archives the rest.
+    ExtractKeepRecent {
+        keep_last: usize,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -10,6 +10,42 @@ pub struct TraceIndexEntry {
     pub created_at: String,
 }
 
+/// `final_topk` comparison of two persisted traces; ranks are 1-based.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceDiff {
+    pub trace_id_a: String,
+    pub trace_id_b: String,
+    pub query_a: String,
+    pub query_b: String,
+    /// Hits only in b.
+    pub added: Vec<TraceDiffHit>,
+    /// Hits only in a.
+    pub removed: Vec<TraceDiffHit>,
+    /// Hits in both whose rank or score changed, in b's order.
+    pub changed: Vec<TraceRankChange>,
+    pub unchanged: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceDiffHit {
+    pub uri: String,
+    pub rank: usize,
+    pub score: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceRankChange {
+    pub uri: String,
+    pub rank_a: usize,
+    pub rank_b: usize,
+    /// `rank_a - rank_b`; positive means it moved up in b.
+    pub rank_delta: i64,
+    pub score_a: f32,
+    pub score_b: f32,
+    /// `score_b - score_a`.
+    pub score_delta: f32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RequestLogEntry {
     pub request_id: String,
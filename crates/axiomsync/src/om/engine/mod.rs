@@ -76,10 +76,11 @@ pub use transform::{
     filter_observer_candidates_by_last_observed_at, infer_deterministic_continuation,
     infer_deterministic_observer_response, materialize_search_visible_snapshot,
     merge_activated_observations, merge_buffered_reflection, normalize_observation_buffer_boundary,
-    plan_buffered_reflection_slice, reflector_compression_guidance, render_search_hint,
-    resolve_canonical_thread_ref, resolve_continuation_update, select_activation_boundary,
-    select_observed_message_candidates, select_observer_message_candidates,
-    select_reflection_action, should_skip_observer_continuation_hints, should_trigger_observer,
-    should_trigger_reflector, split_pending_and_other_conversation_candidates,
-    synthesize_observer_observations, validate_reflection_compression,
+    observer_candidate_text, plan_buffered_reflection_slice, reflector_compression_guidance,
+    render_search_hint, resolve_canonical_thread_ref, resolve_continuation_update,
+    select_activation_boundary, select_observed_message_candidates,
+    select_observer_message_candidates, select_reflection_action,
+    should_skip_observer_continuation_hints, should_trigger_observer, should_trigger_reflector,
+    split_pending_and_other_conversation_candidates, synthesize_observer_observations,
+    validate_reflection_compression,
 };
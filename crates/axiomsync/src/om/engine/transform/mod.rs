@@ -14,7 +14,7 @@ pub use observer::{
     build_other_conversation_blocks, combine_observations_for_buffering, compute_pending_tokens,
     decide_observer_write_action, evaluate_async_observation_interval,
     filter_observer_candidates_by_last_observed_at, infer_deterministic_continuation,
-    infer_deterministic_observer_response, resolve_continuation_update,
+    infer_deterministic_observer_response, observer_candidate_text, resolve_continuation_update,
     select_observed_message_candidates, select_observer_message_candidates,
     should_skip_observer_continuation_hints, should_trigger_observer,
     split_pending_and_other_conversation_candidates, synthesize_observer_observations,
@@ -4,6 +4,15 @@ use chrono::{DateTime, Utc};
 
 use super::super::types::OmObserverMessageCandidate;
 
+/// Appends referenced URIs to candidate text for the observer prompt.
+#[must_use]
+pub fn observer_candidate_text(text: &str, attachments: &[String]) -> String {
+    if attachments.is_empty() {
+        return text.to_string();
+    }
+    format!("{text}\n[attachments: {}]", attachments.join(", "))
+}
+
 pub fn select_observer_message_candidates(
     candidates: &[OmObserverMessageCandidate],
     observed_message_ids: &HashSet<String>,
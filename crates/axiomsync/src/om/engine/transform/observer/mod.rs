@@ -5,8 +5,9 @@ mod decision;
 mod synthesis;
 
 pub use candidates::{
-    filter_observer_candidates_by_last_observed_at, select_observed_message_candidates,
-    select_observer_message_candidates, split_pending_and_other_conversation_candidates,
+    filter_observer_candidates_by_last_observed_at, observer_candidate_text,
+    select_observed_message_candidates, select_observer_message_candidates,
+    split_pending_and_other_conversation_candidates,
 };
 pub use context::{build_other_conversation_blocks, combine_observations_for_buffering};
 pub use continuation::resolve_continuation_update;
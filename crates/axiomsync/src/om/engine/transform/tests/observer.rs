@@ -903,3 +903,18 @@ fn evaluate_async_observation_interval_reports_explicit_state() {
     assert!(!disabled.debounce_passed);
     assert!(!disabled.should_trigger);
 }
+
+#[test]
+fn observer_candidate_text_appends_attachment_uris() {
+    assert_eq!(observer_candidate_text("plain", &[]), "plain");
+    assert_eq!(
+        observer_candidate_text(
+            "read docs",
+            &[
+                "axiom://resources/a.md".to_string(),
+                "axiom://resources/b.md".to_string(),
+            ],
+        ),
+        "read docs\n[attachments: axiom://resources/a.md, axiom://resources/b.md]"
+    );
+}
@@ -38,7 +38,7 @@ pub use engine::{
     format_multi_thread_observer_messages_for_prompt, format_observer_messages_for_prompt,
     infer_deterministic_observer_response, materialize_search_visible_snapshot,
     merge_activated_observations, merge_buffered_reflection, normalize_observation_buffer_boundary,
-    parse_memory_section_xml, parse_memory_section_xml_accuracy_first,
+    observer_candidate_text, parse_memory_section_xml, parse_memory_section_xml_accuracy_first,
     parse_multi_thread_observer_output, parse_multi_thread_observer_output_accuracy_first,
     plan_buffered_reflection_slice, plan_process_input_step, plan_process_output_result,
     reflection_command_from_action, reflector_compression_guidance, resolve_continuation_update,
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 pub const OM_OUTBOX_SCHEMA_VERSION_V1: u8 = 1;
+pub const OM_STATE_EXPORT_VERSION_V1: u32 = 1;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -107,6 +108,46 @@ pub struct OmHintReadStateV1 {
     pub reflector_trigger_count_total: u32,
 }
 
+/// Session OM trigger counters and active observation size, read without searching.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct OmMetricsV1 {
+    pub scope_key: String,
+    pub observer_trigger_count_total: u32,
+    pub reflector_trigger_count_total: u32,
+    pub observation_tokens_active: u32,
+    pub last_activated_message_ids: Vec<String>,
+}
+
+/// OM record summary for `session om show`, to inspect before `om_reset`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OmStateView {
+    pub scope_key: String,
+    pub generation_count: u32,
+    pub active_observations: String,
+    pub observation_token_count: u32,
+    pub pending_message_tokens: u32,
+    #[serde(default)]
+    pub buffered_chunks: Vec<crate::om::OmObservationChunk>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffered_reflection: Option<String>,
+    pub observer_trigger_count_total: u32,
+    pub reflector_trigger_count_total: u32,
+    #[serde(default)]
+    pub last_activated_message_ids: Vec<String>,
+    pub updated_at: String,
+}
+
+/// `force_om_reflection` result; `StaleGeneration` means the record changed and nothing was applied.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OmForcedReflectionV1 {
+    pub scope_key: String,
+    pub event_id: i64,
+    pub outcome: crate::state::OmReflectionApplyOutcome,
+    pub generation_count: u32,
+    pub reflector_trigger_count_total: u32,
+    pub apply_metrics: crate::models::OmReflectionApplyMetrics,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct OmScopeBindingInputV1 {
     pub scope: OmScopeV1,
@@ -185,3 +226,59 @@ impl OmReplayModeV1 {
         }
     }
 }
+
+/// Portable OM state for one session: the record plus side-table rows for its scope key.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OmStateExportV1 {
+    pub version: u32,
+    pub session_id: String,
+    pub scope_key: String,
+    pub record: crate::om::OmRecord,
+    #[serde(default)]
+    pub scope_sessions: Vec<String>,
+    #[serde(default)]
+    pub observation_chunks: Vec<crate::om::OmObservationChunk>,
+    #[serde(default)]
+    pub entries: Vec<OmStateEntryV1>,
+    #[serde(default)]
+    pub thread_states: Vec<OmStateThreadV1>,
+    #[serde(default)]
+    pub continuation_states: Vec<OmStateContinuationV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OmStateEntryV1 {
+    pub entry_id: String,
+    pub canonical_thread_id: String,
+    pub priority: String,
+    pub text: String,
+    #[serde(default)]
+    pub source_message_ids: Vec<String>,
+    pub origin_kind: String,
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub superseded_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OmStateThreadV1 {
+    pub thread_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_observed_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_task: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_response: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OmStateContinuationV1 {
+    pub canonical_thread_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_task: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_response: Option<String>,
+    pub confidence: f64,
+    pub source_kind: String,
+    pub updated_at: String,
+}
@@ -0,0 +1,212 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::model::OntologySchemaV1;
+
+/// Differences between two schemas; definitions are paired by `id`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct OntologySchemaDiff {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub object_types: OntologyDefinitionDiff,
+    pub link_types: OntologyDefinitionDiff,
+    pub action_types: OntologyDefinitionDiff,
+    pub invariants: OntologyDefinitionDiff,
+}
+
+impl OntologySchemaDiff {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.from_version == self.to_version
+            && self.object_types.is_empty()
+            && self.link_types.is_empty()
+            && self.action_types.is_empty()
+            && self.invariants.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct OntologyDefinitionDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub modified: Vec<OntologyDefinitionChange>,
+}
+
+impl OntologyDefinitionDiff {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// A definition present on both sides with different content, and its changed field names.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OntologyDefinitionChange {
+    pub id: String,
+    pub changed_fields: Vec<String>,
+}
+
+/// Types and invariants added, removed or changed from `old` to `new`.
+/// Ids and field lists are sorted so equal inputs yield equal JSON.
+#[must_use]
+pub fn diff_schemas(old: &OntologySchemaV1, new: &OntologySchemaV1) -> OntologySchemaDiff {
+    OntologySchemaDiff {
+        from_version: old.version,
+        to_version: new.version,
+        object_types: diff_definitions(&old.object_types, &new.object_types, |def| &def.id),
+        link_types: diff_definitions(&old.link_types, &new.link_types, |def| &def.id),
+        action_types: diff_definitions(&old.action_types, &new.action_types, |def| &def.id),
+        invariants: diff_definitions(&old.invariants, &new.invariants, |def| &def.id),
+    }
+}
+
+fn diff_definitions<T: Serialize>(
+    old: &[T],
+    new: &[T],
+    id_of: impl Fn(&T) -> &String,
+) -> OntologyDefinitionDiff {
+    let old_by_id = index_definitions(old, &id_of);
+    let new_by_id = index_definitions(new, &id_of);
+    let mut diff = OntologyDefinitionDiff::default();
+    for (id, old_fields) in &old_by_id {
+        match new_by_id.get(id) {
+            None => diff.removed.push((*id).clone()),
+            Some(new_fields) => {
+                let changed_fields = changed_fields(old_fields, new_fields);
+                if !changed_fields.is_empty() {
+                    diff.modified.push(OntologyDefinitionChange {
+                        id: (*id).clone(),
+                        changed_fields,
+                    });
+                }
+            }
+        }
+    }
+    diff.added = new_by_id
+        .keys()
+        .filter(|id| !old_by_id.contains_key(*id))
+        .map(|id| (*id).clone())
+        .collect();
+    diff
+}
+
+/// First definition wins on repeated ids; `compile_schema` rejects duplicates separately.
+fn index_definitions<'a, T: Serialize>(
+    definitions: &'a [T],
+    id_of: &impl Fn(&T) -> &String,
+) -> BTreeMap<&'a String, serde_json::Map<String, serde_json::Value>> {
+    let mut out = BTreeMap::new();
+    for definition in definitions {
+        out.entry(id_of(definition))
+            .or_insert_with(|| match serde_json::to_value(definition) {
+                Ok(serde_json::Value::Object(fields)) => fields,
+                _ => serde_json::Map::new(),
+            });
+    }
+    out
+}
+
+fn changed_fields(
+    old: &serde_json::Map<String, serde_json::Value>,
+    new: &serde_json::Map<String, serde_json::Value>,
+) -> Vec<String> {
+    let mut fields = old
+        .keys()
+        .chain(new.keys())
+        .filter(|field| old.get(*field) != new.get(*field))
+        .cloned()
+        .collect::<Vec<_>>();
+    fields.sort();
+    fields.dedup();
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::{InvariantDef, LinkTypeDef, ObjectTypeDef};
+
+    fn base_schema() -> OntologySchemaV1 {
+        OntologySchemaV1 {
+            version: 1,
+            object_types: vec![
+                ObjectTypeDef {
+                    id: "resource_doc".to_string(),
+                    uri_prefixes: vec!["axiom://resources/docs".to_string()],
+                    ..ObjectTypeDef::default()
+                },
+                ObjectTypeDef {
+                    id: "legacy_note".to_string(),
+                    uri_prefixes: vec!["axiom://resources/notes".to_string()],
+                    ..ObjectTypeDef::default()
+                },
+            ],
+            link_types: vec![LinkTypeDef {
+                id: "depends_on".to_string(),
+                from_types: vec!["resource_doc".to_string()],
+                to_types: vec!["resource_doc".to_string()],
+                ..LinkTypeDef::default()
+            }],
+            action_types: Vec::new(),
+            invariants: vec![InvariantDef {
+                id: "inv_docs".to_string(),
+                rule: "object_type_declared:resource_doc".to_string(),
+                severity: "error".to_string(),
+                message: "docs type must exist".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn diff_schemas_is_empty_for_identical_documents() {
+        let schema = base_schema();
+        assert!(diff_schemas(&schema, &schema).is_empty());
+    }
+
+    #[test]
+    fn diff_schemas_reports_added_and_removed_definitions() {
+        let old = base_schema();
+        let mut new = base_schema();
+        new.object_types.retain(|def| def.id != "legacy_note");
+        new.link_types.push(LinkTypeDef {
+            id: "cites".to_string(),
+            ..LinkTypeDef::default()
+        });
+
+        let diff = diff_schemas(&old, &new);
+        assert_eq!(diff.object_types.removed, vec!["legacy_note".to_string()]);
+        assert!(diff.object_types.added.is_empty());
+        assert_eq!(diff.link_types.added, vec!["cites".to_string()]);
+        assert!(diff.link_types.removed.is_empty());
+        assert!(diff.action_types.is_empty());
+        assert!(diff.invariants.is_empty());
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn diff_schemas_lists_changed_fields_of_modified_definitions() {
+        let old = base_schema();
+        let mut new = base_schema();
+        new.version = 2;
+        let link = &mut new.link_types[0];
+        link.max_arity = 4;
+        link.symmetric = true;
+        new.invariants[0].severity = "warn".to_string();
+
+        let diff = diff_schemas(&old, &new);
+        assert_eq!((diff.from_version, diff.to_version), (1, 2));
+        assert_eq!(
+            diff.link_types.modified,
+            vec![OntologyDefinitionChange {
+                id: "depends_on".to_string(),
+                changed_fields: vec!["max_arity".to_string(), "symmetric".to_string()],
+            }]
+        );
+        assert_eq!(
+            diff.invariants.modified[0].changed_fields,
+            vec!["severity".to_string()]
+        );
+        assert!(diff.object_types.is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+use serde_json::json;
+
+use crate::error::{AxiomError, Result};
+
+use super::model::{ActionTypeDef, OntologyJsonValueKind};
+use super::validate::{ActionInputContract, CompiledOntologySchema, parse_action_input_contract};
+
+pub const JSON_SCHEMA_DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Exports an action type's input contract as JSON Schema (draft 2020-12).
+/// Extra undeclared fields are allowed, matching `validate_action_request`.
+pub fn export_json_schema(
+    schema: &CompiledOntologySchema,
+    action_id: &str,
+) -> Result<serde_json::Value> {
+    let action_id = action_id.trim();
+    let action = schema.action_type(action_id).ok_or_else(|| {
+        AxiomError::OntologyViolation(format!(
+            "ontology action type is not declared: action_id='{action_id}'"
+        ))
+    })?;
+    let contract =
+        parse_action_input_contract(action.input_contract.as_str()).map_err(|detail| {
+            AxiomError::OntologyViolation(format!(
+                "ontology action input contract is unsupported: {detail}"
+            ))
+        })?;
+
+    let mut document = json!({
+        "$schema": JSON_SCHEMA_DRAFT_2020_12,
+        "title": format!("{} input", action.id),
+    });
+    if let ActionInputContract::Strict(kind) = contract {
+        document["type"] = json!(json_schema_type(kind));
+    }
+    if !action.input_fields.is_empty() {
+        document["properties"] = input_field_properties(action);
+        document["required"] = json!(
+            action
+                .input_fields
+                .iter()
+                .filter(|field| field.required)
+                .map(|field| field.name.trim())
+                .collect::<Vec<_>>()
+        );
+    }
+    Ok(document)
+}
+
+fn input_field_properties(action: &ActionTypeDef) -> serde_json::Value {
+    let mut properties = serde_json::Map::new();
+    for field in &action.input_fields {
+        let mut property = json!({ "type": json_schema_type(field.kind) });
+        if !field.enum_values.is_empty() {
+            property["enum"] = json!(field.enum_values);
+        }
+        properties.insert(field.name.trim().to_string(), property);
+    }
+    serde_json::Value::Object(properties)
+}
+
+const fn json_schema_type(kind: OntologyJsonValueKind) -> &'static str {
+    match kind {
+        OntologyJsonValueKind::Null => "null",
+        OntologyJsonValueKind::Boolean => "boolean",
+        OntologyJsonValueKind::Number => "number",
+        OntologyJsonValueKind::String => "string",
+        OntologyJsonValueKind::Array => "array",
+        OntologyJsonValueKind::Object => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::{compile_schema, parse_schema_v1};
+
+    fn compiled_schema() -> CompiledOntologySchema {
+        let raw = r#"{
+            "version": 1,
+            "object_types": [],
+            "link_types": [],
+            "action_types": [
+                {
+                    "id":"sync_doc",
+                    "input_contract":"json-object",
+                    "input_fields":[
+                        {"name":"uri","kind":"string","required":true},
+                        {"name":"limit","kind":"number"},
+                        {"name":"mode","kind":"string","required":true,"enum":["full","delta"]}
+                    ],
+                    "queue_event_type":"semantic_scan"
+                },
+                {
+                    "id":"free_form",
+                    "input_contract":"json-any",
+                    "queue_event_type":"semantic_scan"
+                }
+            ],
+            "invariants": []
+        }"#;
+        compile_schema(parse_schema_v1(raw).expect("parse")).expect("compile")
+    }
+
+    #[test]
+    fn export_json_schema_maps_fields_required_and_enum() {
+        let document = export_json_schema(&compiled_schema(), "sync_doc").expect("export");
+        assert_eq!(document["$schema"], JSON_SCHEMA_DRAFT_2020_12);
+        assert_eq!(document["type"], "object");
+        assert_eq!(document["properties"]["limit"]["type"], "number");
+        assert_eq!(
+            document["properties"]["mode"]["enum"],
+            json!(["full", "delta"])
+        );
+        assert_eq!(document["required"], json!(["uri", "mode"]));
+
+        let validator = jsonschema::validator_for(&document).expect("valid json schema");
+        assert!(validator.is_valid(&json!({
+            "uri": "axiom://resources/docs/a.md",
+            "mode": "delta",
+            "limit": 3
+        })));
+        assert!(!validator.is_valid(&json!({ "mode": "delta" })));
+        assert!(!validator.is_valid(&json!({
+            "uri": "axiom://resources/docs/a.md",
+            "mode": "partial"
+        })));
+    }
+
+    #[test]
+    fn export_json_schema_leaves_json_any_unconstrained_and_rejects_unknown_action() {
+        let document = export_json_schema(&compiled_schema(), "free_form").expect("export");
+        assert!(document.get("type").is_none());
+        let validator = jsonschema::validator_for(&document).expect("valid json schema");
+        assert!(validator.is_valid(&json!("anything")));
+
+        let err = export_json_schema(&compiled_schema(), "missing").expect_err("unknown action");
+        assert!(matches!(err, AxiomError::OntologyViolation(_)));
+    }
+}
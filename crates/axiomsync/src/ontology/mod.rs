@@ -1,11 +1,18 @@
+mod diff;
+mod json_schema;
 mod model;
 mod parse;
 mod pressure;
 mod validate;
 
+pub use diff::{
+    OntologyDefinitionChange, OntologyDefinitionDiff, OntologySchemaDiff, diff_schemas,
+};
+pub use json_schema::{JSON_SCHEMA_DRAFT_2020_12, export_json_schema};
 pub use model::{
-    ActionTypeDef, DEFAULT_ONTOLOGY_SCHEMA_V1_JSON, InvariantDef, LinkTypeDef,
-    ONTOLOGY_SCHEMA_URI_V1, ObjectTypeDef, OntologyActionRequestV1, OntologyActionValidationReport,
+    ActionInputFieldDef, ActionTypeDef, DEFAULT_ONTOLOGY_SCHEMA_V1_JSON, InvariantDef, LinkTypeDef,
+    ONTOLOGY_SCHEMA_URI_V1, ObjectTypeDef, OntologyActionRequestV1,
+    OntologyActionValidationOptions, OntologyActionValidationReport, OntologyInputCoercion,
     OntologyInvariantCheckItem, OntologyInvariantCheckReport, OntologyInvariantCheckStatus,
     OntologyInvariantFailureKind, OntologyJsonValueKind, OntologySchemaV1,
 };
@@ -17,4 +24,6 @@ pub use pressure::{
     validate_v2_pressure_trend_policy,
 };
 pub use validate::{CompiledOntologySchema, compile_schema, validate_relation_link};
-pub use validate::{evaluate_invariants, validate_action_request};
+pub use validate::{
+    evaluate_invariants, validate_action_request, validate_action_request_with_options,
+};
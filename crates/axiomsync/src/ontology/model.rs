@@ -71,11 +71,26 @@ impl Default for LinkTypeDef {
 pub struct ActionTypeDef {
     pub id: String,
     pub input_contract: String,
+    /// Fields of a `json-object` input; empty checks only that it is an object.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub input_fields: Vec<ActionInputFieldDef>,
     #[serde(default)]
     pub effects: Vec<String>,
     pub queue_event_type: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ActionInputFieldDef {
+    pub name: String,
+    pub kind: OntologyJsonValueKind,
+    #[serde(default)]
+    pub required: bool,
+    /// When non-empty, the value must be one of these.
+    #[serde(default, rename = "enum", skip_serializing_if = "Vec::is_empty")]
+    pub enum_values: Vec<serde_json::Value>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(deny_unknown_fields)]
 pub struct InvariantDef {
@@ -111,6 +126,26 @@ pub struct OntologyActionValidationReport {
     pub queue_event_type: String,
     pub input_contract: String,
     pub input_kind: OntologyJsonValueKind,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub coercions: Vec<OntologyInputCoercion>,
+    /// Input used for validation when any coercion applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coerced_input: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OntologyActionValidationOptions {
+    /// Converts strings like `"true"`/`"42"` where boolean/number is declared before validating.
+    pub coerce: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OntologyInputCoercion {
+    /// Coerced `input_fields` name; absent when the whole input was coerced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    pub from: String,
+    pub to: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -4,8 +4,9 @@ use crate::error::{AxiomError, Result};
 use crate::uri::AxiomUri;
 
 use super::model::{
-    ActionTypeDef, InvariantDef, LinkTypeDef, ObjectTypeDef, OntologyActionRequestV1,
-    OntologyActionValidationReport, OntologyInvariantCheckItem, OntologyInvariantCheckReport,
+    ActionInputFieldDef, ActionTypeDef, InvariantDef, LinkTypeDef, ObjectTypeDef,
+    OntologyActionRequestV1, OntologyActionValidationOptions, OntologyActionValidationReport,
+    OntologyInputCoercion, OntologyInvariantCheckItem, OntologyInvariantCheckReport,
     OntologyInvariantCheckStatus, OntologyInvariantFailureKind, OntologyJsonValueKind,
     OntologySchemaV1,
 };
@@ -127,7 +128,7 @@ pub fn compile_schema(schema: OntologySchemaV1) -> Result<CompiledOntologySchema
             });
         }
     }
-    uri_prefix_rules.sort_by(|a, b| b.prefix.len().cmp(&a.prefix.len()));
+    uri_prefix_rules.sort_by_key(|rule| std::cmp::Reverse(rule.prefix.len()));
 
     Ok(CompiledOntologySchema {
         object_types: object_types_by_id,
@@ -200,6 +201,20 @@ pub fn validate_relation_link(
 pub fn validate_action_request(
     schema: &CompiledOntologySchema,
     request: &OntologyActionRequestV1,
+) -> Result<OntologyActionValidationReport> {
+    validate_action_request_with_options(
+        schema,
+        request,
+        OntologyActionValidationOptions::default(),
+    )
+}
+
+/// Same as `validate_action_request` unless `options.coerce`, which first converts string
+/// booleans/numbers per declared kinds and reports each conversion.
+pub fn validate_action_request_with_options(
+    schema: &CompiledOntologySchema,
+    request: &OntologyActionRequestV1,
+    options: OntologyActionValidationOptions,
 ) -> Result<OntologyActionValidationReport> {
     let action_id = request.action_id.trim();
     if action_id.is_empty() {
@@ -226,15 +241,93 @@ pub fn validate_action_request(
         )));
     }
 
-    validate_action_input_contract(action.input_contract.as_str(), &request.input)?;
+    let (coerced_input, coercions) = if options.coerce {
+        coerce_action_input(action, &request.input)
+    } else {
+        (None, Vec::new())
+    };
+    let input = coerced_input.as_ref().unwrap_or(&request.input);
+    validate_action_input_contract(action.input_contract.as_str(), input)?;
+    validate_action_input_fields(&action.input_fields, input)?;
     Ok(OntologyActionValidationReport {
         action_id: action.id.clone(),
         queue_event_type: action.queue_event_type.clone(),
         input_contract: action.input_contract.clone(),
-        input_kind: json_value_kind(&request.input),
+        input_kind: json_value_kind(input),
+        coercions,
+        coerced_input,
     })
 }
 
+/// `None` when nothing was coerced, so the original input is used.
+fn coerce_action_input(
+    action: &ActionTypeDef,
+    input: &serde_json::Value,
+) -> (Option<serde_json::Value>, Vec<OntologyInputCoercion>) {
+    let mut coerced = input.clone();
+    let mut coercions = Vec::new();
+    if let Ok(ActionInputContract::Strict(kind)) =
+        parse_action_input_contract(action.input_contract.as_str())
+        && let Some(value) = coerce_string_value(&coerced, kind)
+    {
+        coercions.push(OntologyInputCoercion {
+            field: None,
+            from: coerced.as_str().unwrap_or_default().to_string(),
+            to: value.clone(),
+        });
+        coerced = value;
+    }
+    if let Some(object) = coerced.as_object_mut() {
+        for field in &action.input_fields {
+            let name = field.name.trim();
+            let Some(slot) = object.get_mut(name) else {
+                continue;
+            };
+            if let Some(value) = coerce_string_value(slot, field.kind) {
+                coercions.push(OntologyInputCoercion {
+                    field: Some(name.to_string()),
+                    from: slot.as_str().unwrap_or_default().to_string(),
+                    to: value.clone(),
+                });
+                *slot = value;
+            }
+        }
+    }
+    if coercions.is_empty() {
+        (None, coercions)
+    } else {
+        (Some(coerced), coercions)
+    }
+}
+
+/// Reads a string as boolean or number (integer first, then finite float); other kinds are untouched.
+fn coerce_string_value(
+    value: &serde_json::Value,
+    kind: OntologyJsonValueKind,
+) -> Option<serde_json::Value> {
+    let raw = value.as_str()?.trim();
+    match kind {
+        OntologyJsonValueKind::Boolean => match raw {
+            "true" => Some(serde_json::Value::Bool(true)),
+            "false" => Some(serde_json::Value::Bool(false)),
+            _ => None,
+        },
+        OntologyJsonValueKind::Number => {
+            if let Ok(number) = raw.parse::<i64>() {
+                return Some(serde_json::Value::from(number));
+            }
+            if let Ok(number) = raw.parse::<u64>() {
+                return Some(serde_json::Value::from(number));
+            }
+            raw.parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(serde_json::Value::Number)
+        }
+        _ => None,
+    }
+}
+
 pub fn evaluate_invariants(schema: &CompiledOntologySchema) -> OntologyInvariantCheckReport {
     let mut items = Vec::<OntologyInvariantCheckItem>::with_capacity(schema.invariants().len());
     let mut passed = 0_usize;
@@ -380,11 +473,13 @@ fn compile_action_defs(action_types: &[ActionTypeDef]) -> Result<HashMap<String,
                 "ontology action type '{id}' input_contract must not be empty"
             )));
         }
-        if let Err(detail) = parse_action_input_contract(action.input_contract.as_str()) {
-            return Err(AxiomError::OntologyViolation(format!(
-                "ontology action type '{id}' input_contract is unsupported: {detail}"
-            )));
-        }
+        let contract =
+            parse_action_input_contract(action.input_contract.as_str()).map_err(|detail| {
+                AxiomError::OntologyViolation(format!(
+                    "ontology action type '{id}' input_contract is unsupported: {detail}"
+                ))
+            })?;
+        compile_action_input_fields(id, &contract, &action.input_fields)?;
         if action.queue_event_type.trim().is_empty() {
             return Err(AxiomError::OntologyViolation(format!(
                 "ontology action type '{id}' queue_event_type must not be empty"
@@ -395,6 +490,49 @@ fn compile_action_defs(action_types: &[ActionTypeDef]) -> Result<HashMap<String,
     Ok(out)
 }
 
+fn compile_action_input_fields(
+    id: &str,
+    contract: &ActionInputContract,
+    fields: &[ActionInputFieldDef],
+) -> Result<()> {
+    if fields.is_empty() {
+        return Ok(());
+    }
+    if !matches!(
+        contract,
+        ActionInputContract::Strict(OntologyJsonValueKind::Object)
+    ) {
+        return Err(AxiomError::OntologyViolation(format!(
+            "ontology action type '{id}' input_fields requires input_contract json-object"
+        )));
+    }
+    let mut names = HashSet::<&str>::new();
+    for field in fields {
+        let name = field.name.trim();
+        if name.is_empty() {
+            return Err(AxiomError::OntologyViolation(format!(
+                "ontology action type '{id}' input field name must not be empty"
+            )));
+        }
+        if !names.insert(name) {
+            return Err(AxiomError::OntologyViolation(format!(
+                "ontology action type '{id}' has duplicate input field: {name}"
+            )));
+        }
+        if let Some(value) = field
+            .enum_values
+            .iter()
+            .find(|value| json_value_kind(value) != field.kind)
+        {
+            return Err(AxiomError::OntologyViolation(format!(
+                "ontology action type '{id}' input field '{name}' enum value {value} is not {}",
+                json_value_kind_label(field.kind)
+            )));
+        }
+    }
+    Ok(())
+}
+
 fn compile_invariant_defs(invariants: &[InvariantDef]) -> Result<Vec<InvariantDef>> {
     let mut ids = HashSet::<String>::new();
     let mut out = Vec::<InvariantDef>::with_capacity(invariants.len());
@@ -450,12 +588,48 @@ fn validate_action_input_contract(contract: &str, input: &serde_json::Value) ->
     Ok(())
 }
 
-enum ActionInputContract {
+fn validate_action_input_fields(
+    fields: &[ActionInputFieldDef],
+    input: &serde_json::Value,
+) -> Result<()> {
+    let Some(object) = input.as_object() else {
+        return Ok(());
+    };
+    for field in fields {
+        let name = field.name.trim();
+        let Some(value) = object.get(name) else {
+            if field.required {
+                return Err(AxiomError::OntologyViolation(format!(
+                    "ontology action input is missing required field '{name}'"
+                )));
+            }
+            continue;
+        };
+        let actual_kind = json_value_kind(value);
+        if actual_kind != field.kind {
+            return Err(AxiomError::OntologyViolation(format!(
+                "ontology action input field '{name}' mismatch: expected='{}' actual='{}'",
+                json_value_kind_label(field.kind),
+                json_value_kind_label(actual_kind)
+            )));
+        }
+        if !field.enum_values.is_empty() && !field.enum_values.contains(value) {
+            return Err(AxiomError::OntologyViolation(format!(
+                "ontology action input field '{name}' value {value} is not in enum"
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub(super) enum ActionInputContract {
     Any,
     Strict(OntologyJsonValueKind),
 }
 
-fn parse_action_input_contract(contract: &str) -> std::result::Result<ActionInputContract, String> {
+pub(super) fn parse_action_input_contract(
+    contract: &str,
+) -> std::result::Result<ActionInputContract, String> {
     if contract.eq_ignore_ascii_case("json-any") {
         return Ok(ActionInputContract::Any);
     }
@@ -909,4 +1083,163 @@ mod tests {
         assert_eq!(report.input_contract, "json-any");
         assert_eq!(report.input_kind, OntologyJsonValueKind::String);
     }
+
+    #[test]
+    fn validate_action_request_enforces_declared_input_fields() {
+        let raw = r#"{
+            "version": 1,
+            "object_types": [],
+            "link_types": [],
+            "action_types": [{
+                "id":"sync_doc",
+                "input_contract":"json-object",
+                "input_fields":[
+                    {"name":"uri","kind":"string","required":true},
+                    {"name":"mode","kind":"string","enum":["full","delta"]}
+                ],
+                "queue_event_type":"semantic_scan"
+            }],
+            "invariants": []
+        }"#;
+        let schema = compile_schema(parse_schema_v1(raw).expect("parse")).expect("compile");
+        let request = |input: serde_json::Value| OntologyActionRequestV1 {
+            action_id: "sync_doc".to_string(),
+            queue_event_type: "semantic_scan".to_string(),
+            input,
+        };
+
+        validate_action_request(
+            &schema,
+            &request(serde_json::json!({"uri":"axiom://resources/docs/a.md","extra":1})),
+        )
+        .expect("optional field may be absent and extra fields pass");
+        for input in [
+            serde_json::json!({"mode":"full"}),
+            serde_json::json!({"uri":7}),
+            serde_json::json!({"uri":"axiom://resources/docs/a.md","mode":"partial"}),
+        ] {
+            let err = validate_action_request(&schema, &request(input)).expect_err("must fail");
+            assert!(matches!(err, AxiomError::OntologyViolation(_)));
+        }
+    }
+
+    #[test]
+    fn compile_schema_rejects_input_fields_outside_json_object_contract() {
+        for (contract, fields) in [
+            ("json-any", r#"[{"name":"uri","kind":"string"}]"#),
+            (
+                "json-object",
+                r#"[{"name":"uri","kind":"string"},{"name":"uri","kind":"number"}]"#,
+            ),
+            (
+                "json-object",
+                r#"[{"name":"mode","kind":"string","enum":[1]}]"#,
+            ),
+        ] {
+            let raw = format!(
+                r#"{{
+                    "version": 1,
+                    "object_types": [],
+                    "link_types": [],
+                    "action_types": [{{
+                        "id":"sync_doc",
+                        "input_contract":"{contract}",
+                        "input_fields":{fields},
+                        "queue_event_type":"semantic_scan"
+                    }}],
+                    "invariants": []
+                }}"#
+            );
+            let parsed = parse_schema_v1(&raw).expect("parse");
+            let err = compile_schema(parsed).expect_err("compile must fail");
+            assert!(matches!(err, AxiomError::OntologyViolation(_)));
+        }
+    }
+
+    fn coercion_schema() -> CompiledOntologySchema {
+        let raw = r#"{
+            "version": 1,
+            "object_types": [],
+            "link_types": [],
+            "action_types": [{
+                "id":"reindex",
+                "input_contract":"json-object",
+                "input_fields":[
+                    {"name":"limit","kind":"number","required":true},
+                    {"name":"force","kind":"boolean"}
+                ],
+                "queue_event_type":"semantic_scan"
+            }],
+            "invariants": []
+        }"#;
+        compile_schema(parse_schema_v1(raw).expect("parse")).expect("compile")
+    }
+
+    fn coercion_request(input: serde_json::Value) -> OntologyActionRequestV1 {
+        OntologyActionRequestV1 {
+            action_id: "reindex".to_string(),
+            queue_event_type: "semantic_scan".to_string(),
+            input,
+        }
+    }
+
+    #[test]
+    fn validate_action_request_strict_mode_rejects_stringified_number() {
+        let schema = coercion_schema();
+        let request = coercion_request(serde_json::json!({"limit":"42"}));
+        let err = validate_action_request(&schema, &request).expect_err("strict must fail");
+        assert!(matches!(err, AxiomError::OntologyViolation(_)));
+        let err = validate_action_request_with_options(
+            &schema,
+            &request,
+            OntologyActionValidationOptions::default(),
+        )
+        .expect_err("default options stay strict");
+        assert!(matches!(err, AxiomError::OntologyViolation(_)));
+
+        let report =
+            validate_action_request(&schema, &coercion_request(serde_json::json!({"limit":42})))
+                .expect("native number passes");
+        assert!(report.coercions.is_empty());
+        assert!(report.coerced_input.is_none());
+    }
+
+    #[test]
+    fn validate_action_request_coerce_mode_converts_and_reports_fields() {
+        let schema = coercion_schema();
+        let options = OntologyActionValidationOptions { coerce: true };
+        let report = validate_action_request_with_options(
+            &schema,
+            &coercion_request(serde_json::json!({"limit":"42","force":"true"})),
+            options,
+        )
+        .expect("coerced input passes");
+        assert_eq!(
+            report.coerced_input,
+            Some(serde_json::json!({"limit":42,"force":true}))
+        );
+        assert_eq!(
+            report.coercions,
+            vec![
+                OntologyInputCoercion {
+                    field: Some("limit".to_string()),
+                    from: "42".to_string(),
+                    to: serde_json::json!(42),
+                },
+                OntologyInputCoercion {
+                    field: Some("force".to_string()),
+                    from: "true".to_string(),
+                    to: serde_json::json!(true),
+                },
+            ]
+        );
+
+        let err = validate_action_request_with_options(
+            &schema,
+            &coercion_request(serde_json::json!({"limit":"forty-two"})),
+            options,
+        )
+        .expect_err("non-numeric string still fails");
+        assert!(matches!(err, AxiomError::OntologyViolation(_)));
+    }
 }
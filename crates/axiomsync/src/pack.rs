@@ -2,19 +2,87 @@ use std::fs;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 
+use std::collections::HashMap;
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
 use walkdir::WalkDir;
 use zip::write::SimpleFileOptions;
 use zip::{CompressionMethod, ZipArchive, ZipWriter};
 
+use crate::config::{REDACTED_MARKER, RedactionPolicy, ScopeRedaction};
 use crate::error::{AxiomError, Result};
 use crate::fs::LocalContextFs;
-use crate::uri::AxiomUri;
+use crate::mime::infer_mime_from_name;
+use crate::models::ExportOvpackOptions;
+use crate::uri::{AxiomUri, Scope};
+
+/// Manifest file stored in each pack root; never written to disk on import.
+pub const OVPACK_MANIFEST_FILE_NAME: &str = ".ovpack-manifest.json";
+/// `SessionBundle` JSON bundled into session archives; removed after import.
+pub(crate) const OVPACK_SESSION_BUNDLE_FILE_NAME: &str = ".session-bundle.json";
+const OVPACK_MANIFEST_VERSION: u32 = 1;
+const TIER_FILE_NAMES: [&str; 2] = [".abstract.md", ".overview.md"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OvpackManifest {
+    pub version: u32,
+    pub files: Vec<OvpackManifestEntry>,
+}
+
+/// One packed file; `path` is relative to the pack root.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OvpackManifestEntry {
+    pub path: String,
+    pub size: u64,
+    pub blake3: String,
+    pub mime: String,
+}
 
 pub fn export_ovpack(
     fs: &LocalContextFs,
     source: &AxiomUri,
     destination: &Path,
 ) -> Result<PathBuf> {
+    export_ovpack_with_options(fs, source, destination, &ExportOvpackOptions::default())
+}
+
+pub fn export_ovpack_with_options(
+    fs: &LocalContextFs,
+    source: &AxiomUri,
+    destination: &Path,
+    options: &ExportOvpackOptions,
+) -> Result<PathBuf> {
+    export_ovpack_redacted(
+        fs,
+        source,
+        destination,
+        options,
+        &RedactionPolicy::default(),
+    )
+}
+
+pub(crate) fn export_ovpack_redacted(
+    fs: &LocalContextFs,
+    source: &AxiomUri,
+    destination: &Path,
+    options: &ExportOvpackOptions,
+    redaction: &RedactionPolicy,
+) -> Result<PathBuf> {
+    export_ovpack_with_sidecar(fs, source, destination, options, redaction, None)
+}
+
+/// Adds an optional `(file name, bytes)` sidecar at the pack root.
+pub(crate) fn export_ovpack_with_sidecar(
+    fs: &LocalContextFs,
+    source: &AxiomUri,
+    destination: &Path,
+    options: &ExportOvpackOptions,
+    redaction: &RedactionPolicy,
+    sidecar: Option<(&str, &[u8])>,
+) -> Result<PathBuf> {
+    let exclude = build_glob_set(&options.exclude_globs, "exclude")?;
+    let include = build_glob_set(&options.include_globs, "include")?;
     let source_path = fs.resolve_uri(source);
     if !source_path.exists() {
         return Err(AxiomError::NotFound(source.to_string()));
@@ -43,16 +111,32 @@ pub fn export_ovpack(
 
     let file = fs::File::create(&out_path)?;
     let mut zip = ZipWriter::new(file);
-    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let zip_options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
 
     let base_name = source
         .last_segment()
         .map_or_else(|| source.scope().as_str().to_string(), ToString::to_string);
     let transformed_root = transform_component(&base_name);
 
-    zip.add_directory(format!("{transformed_root}/"), options)?;
+    zip.add_directory(format!("{transformed_root}/"), zip_options)?;
 
-    for entry in WalkDir::new(&source_path).follow_links(false) {
+    let mut manifest = OvpackManifest {
+        version: OVPACK_MANIFEST_VERSION,
+        files: Vec::new(),
+    };
+    let walker = WalkDir::new(&source_path)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.path() == source_path
+                || !entry.file_type().is_dir()
+                || entry
+                    .path()
+                    .strip_prefix(&source_path)
+                    .map_or(true, |rel| !glob_set_matches(exclude.as_ref(), rel))
+        });
+    for entry in walker {
         let entry = entry.map_err(|e| AxiomError::Validation(e.to_string()))?;
         if entry.path() == source_path {
             continue;
@@ -73,24 +157,145 @@ pub fn export_ovpack(
 
         let zip_path = format!("{transformed_root}/{transformed_rel}");
         if entry.file_type().is_dir() {
-            zip.add_directory(format!("{zip_path}/"), options)?;
-        } else {
-            zip.start_file(zip_path, options)?;
-            let bytes = fs::read(entry.path())?;
-            zip.write_all(&bytes)?;
+            // With an include glob, needed directories are created from file paths on import.
+            if include.is_none() {
+                zip.add_directory(format!("{zip_path}/"), zip_options)?;
+            }
+            continue;
+        }
+        if !export_includes_file(rel, options, exclude.as_ref(), include.as_ref()) {
+            continue;
         }
+        zip.start_file(zip_path, zip_options)?;
+        let bytes = redact_export_bytes(redaction, source.scope(), fs::read(entry.path())?);
+        zip.write_all(&bytes)?;
+        manifest
+            .files
+            .push(manifest_entry(&relative_unix_path(rel), &bytes));
+    }
+
+    if let Some((name, bytes)) = sidecar {
+        zip.start_file(
+            format!("{transformed_root}/{}", transform_component(name)),
+            zip_options,
+        )?;
+        zip.write_all(bytes)?;
+        manifest.files.push(manifest_entry(name, bytes));
     }
 
+    zip.start_file(
+        format!(
+            "{transformed_root}/{}",
+            transform_component(OVPACK_MANIFEST_FILE_NAME)
+        ),
+        zip_options,
+    )?;
+    zip.write_all(&serde_json::to_vec_pretty(&manifest)?)?;
+
     zip.finish()?;
     Ok(out_path)
 }
 
+fn export_includes_file(
+    rel: &Path,
+    options: &ExportOvpackOptions,
+    exclude: Option<&GlobSet>,
+    include: Option<&GlobSet>,
+) -> bool {
+    let is_root_manifest = rel == Path::new(OVPACK_MANIFEST_FILE_NAME);
+    let is_tier_file = rel
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| TIER_FILE_NAMES.contains(&name));
+    if is_root_manifest || (options.skip_tier_files && is_tier_file) {
+        return false;
+    }
+    if glob_set_matches(exclude, rel) {
+        return false;
+    }
+    include.is_none_or(|include| include.is_match(relative_unix_path(rel)))
+}
+
+fn build_glob_set(patterns: &[String], label: &str) -> Result<Option<GlobSet>> {
+    let mut builder = GlobSetBuilder::new();
+    let mut count = 0usize;
+    for pattern in patterns {
+        let trimmed = pattern.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let glob = Glob::new(trimmed).map_err(|err| {
+            AxiomError::Validation(format!("invalid ovpack {label} glob '{trimmed}': {err}"))
+        })?;
+        builder.add(glob);
+        count += 1;
+    }
+    if count == 0 {
+        return Ok(None);
+    }
+    builder
+        .build()
+        .map(Some)
+        .map_err(|err| AxiomError::Validation(format!("invalid ovpack {label} globs: {err}")))
+}
+
+fn glob_set_matches(set: Option<&GlobSet>, rel: &Path) -> bool {
+    set.is_some_and(|set| set.is_match(relative_unix_path(rel)))
+}
+
+fn relative_unix_path(rel: &Path) -> String {
+    rel.components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn manifest_entry(path: &str, bytes: &[u8]) -> OvpackManifestEntry {
+    OvpackManifestEntry {
+        path: path.to_string(),
+        size: bytes.len() as u64,
+        blake3: blake3::hash(bytes).to_hex().to_string(),
+        mime: infer_mime_from_name(path)
+            .unwrap_or("application/octet-stream")
+            .to_string(),
+    }
+}
+
+fn redact_export_bytes(redaction: &RedactionPolicy, scope: Scope, bytes: Vec<u8>) -> Vec<u8> {
+    match redaction.mode_for(scope) {
+        None => bytes,
+        Some(ScopeRedaction::Full) => REDACTED_MARKER.as_bytes().to_vec(),
+        Some(ScopeRedaction::Patterns) => match String::from_utf8(bytes) {
+            Ok(text) => redaction
+                .redact_scope_text(scope, &text)
+                .into_owned()
+                .into_bytes(),
+            Err(err) => err.into_bytes(),
+        },
+    }
+}
+
 pub fn import_ovpack(
     fs: &LocalContextFs,
     file_path: &Path,
     parent: &AxiomUri,
     force: bool,
 ) -> Result<AxiomUri> {
+    import_ovpack_with_verify(fs, file_path, parent, force, true, None)
+}
+
+/// With `verify` and a manifest, checks every file before writing and fails with
+/// `AxiomError::Validation` listing mismatches; legacy packs without a manifest skip the check.
+/// `as_name` imports the root as `parent/as_name`.
+pub fn import_ovpack_with_verify(
+    fs: &LocalContextFs,
+    file_path: &Path,
+    parent: &AxiomUri,
+    force: bool,
+    verify: bool,
+    as_name: Option<&str>,
+) -> Result<AxiomUri> {
+    let as_name = as_name.map(validate_import_root_name).transpose()?;
     if !file_path.exists() {
         return Err(AxiomError::NotFound(file_path.display().to_string()));
     }
@@ -110,7 +315,15 @@ pub fn import_ovpack(
             .to_string()
     };
 
-    let base_name = reverse_component(&root_component);
+    let manifest_zip_path = format!(
+        "{root_component}/{}",
+        transform_component(OVPACK_MANIFEST_FILE_NAME)
+    );
+    if verify {
+        verify_ovpack_manifest(&mut archive, &root_component, &manifest_zip_path)?;
+    }
+
+    let base_name = as_name.map_or_else(|| reverse_component(&root_component), str::to_string);
     let target_root = parent.join(&base_name)?;
 
     if fs.exists(&target_root) {
@@ -128,6 +341,9 @@ pub fn import_ovpack(
     for i in 0..archive.len() {
         let mut entry = archive.by_index(i)?;
         let name = entry.name().to_string();
+        if name == manifest_zip_path {
+            continue;
+        }
 
         if name.contains('\\') {
             return Err(AxiomError::SecurityViolation(format!(
@@ -204,6 +420,121 @@ pub fn import_ovpack(
     Ok(target_root)
 }
 
+/// Reads a root-level pack file without extracting; `None` if absent.
+pub(crate) fn read_ovpack_root_file(file_path: &Path, file_name: &str) -> Result<Option<Vec<u8>>> {
+    if !file_path.exists() {
+        return Err(AxiomError::NotFound(file_path.display().to_string()));
+    }
+    let mut archive = ZipArchive::new(fs::File::open(file_path)?)?;
+    if archive.is_empty() {
+        return Err(AxiomError::InvalidArchive("empty archive".to_string()));
+    }
+    let root_component = {
+        let first = archive.by_index(0)?.name().to_string();
+        first
+            .split('/')
+            .find(|s| !s.is_empty())
+            .ok_or_else(|| AxiomError::InvalidArchive("archive has invalid root".to_string()))?
+            .to_string()
+    };
+    let entry_name = format!("{root_component}/{}", transform_component(file_name));
+    match archive.by_name(&entry_name) {
+        Ok(mut entry) => {
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes)?;
+            Ok(Some(bytes))
+        }
+        Err(zip::result::ZipError::FileNotFound) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn verify_ovpack_manifest(
+    archive: &mut ZipArchive<fs::File>,
+    root_component: &str,
+    manifest_zip_path: &str,
+) -> Result<()> {
+    let manifest: OvpackManifest = match archive.by_name(manifest_zip_path) {
+        Ok(mut entry) => {
+            let mut raw = Vec::new();
+            entry.read_to_end(&mut raw)?;
+            serde_json::from_slice(&raw).map_err(|err| {
+                AxiomError::InvalidArchive(format!("invalid ovpack manifest: {err}"))
+            })?
+        }
+        Err(zip::result::ZipError::FileNotFound) => return Ok(()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut expected = manifest
+        .files
+        .into_iter()
+        .map(|entry| (entry.path.clone(), entry))
+        .collect::<HashMap<_, _>>();
+
+    let mut mismatches = Vec::new();
+    let root_prefix = format!("{root_component}/");
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let name = entry.name().to_string();
+        if entry.is_dir() || name.ends_with('/') || name == manifest_zip_path {
+            continue;
+        }
+        let rel = name
+            .strip_prefix(&root_prefix)
+            .unwrap_or(&name)
+            .split('/')
+            .filter(|part| !part.is_empty())
+            .map(reverse_component)
+            .collect::<Vec<_>>()
+            .join("/");
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes)?;
+        let actual = manifest_entry(&rel, &bytes);
+        match expected.remove(&rel) {
+            None => mismatches.push(format!("{rel} (not in manifest)")),
+            Some(listed) if listed.size != actual.size => mismatches.push(format!(
+                "{rel} (size {} != manifest {})",
+                actual.size, listed.size
+            )),
+            Some(listed) if listed.blake3 != actual.blake3 => {
+                mismatches.push(format!("{rel} (blake3 mismatch)"));
+            }
+            Some(_) => {}
+        }
+    }
+    let mut missing = expected.into_keys().collect::<Vec<_>>();
+    missing.sort();
+    mismatches.extend(
+        missing
+            .into_iter()
+            .map(|path| format!("{path} (missing from archive)")),
+    );
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(AxiomError::Validation(format!(
+            "ovpack manifest verification failed: {}",
+            mismatches.join(", ")
+        )))
+    }
+}
+
+fn validate_import_root_name(raw: &str) -> Result<&str> {
+    let name = raw.trim();
+    if name.is_empty()
+        || name == "."
+        || name == ".."
+        || name.contains(['/', '\\'])
+        || name.chars().any(char::is_control)
+    {
+        return Err(AxiomError::Validation(format!(
+            "invalid ovpack import name: '{raw}'"
+        )));
+    }
+    Ok(name)
+}
+
 fn looks_like_windows_abs(path: &str) -> bool {
     let chars = path.chars().collect::<Vec<_>>();
     chars.len() >= 2 && chars[1] == ':'
@@ -224,7 +555,7 @@ fn reverse_component(component: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use std::io::Write;
+    use std::io::{Read, Write};
 
     use tempfile::tempdir;
 
@@ -257,6 +588,176 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ovpack_import_as_name_overrides_root_name() {
+        let temp = tempdir().expect("tempdir");
+        let fsys = LocalContextFs::new(temp.path());
+        fsys.initialize().expect("init failed");
+
+        let src = AxiomUri::root(Scope::Resources).join("demo").expect("join");
+        fsys.create_dir_all(&src, true).expect("mkdir");
+        fs::write(fsys.resolve_uri(&src).join("note.txt"), "world").expect("write");
+        let pack_path = export_ovpack(&fsys, &src, &temp.path().join("demo")).expect("export");
+
+        let parent = AxiomUri::root(Scope::User);
+        let imported =
+            import_ovpack_with_verify(&fsys, &pack_path, &parent, false, true, Some(" renamed "))
+                .expect("import");
+        assert_eq!(imported.to_string(), "axiom://user/renamed");
+        assert_eq!(
+            fs::read_to_string(fsys.resolve_uri(&imported).join("note.txt")).expect("read"),
+            "world"
+        );
+        assert!(!fsys.exists(&parent.join("demo").expect("join")));
+
+        for invalid in ["", "  ", "..", "a/b"] {
+            let err =
+                import_ovpack_with_verify(&fsys, &pack_path, &parent, true, true, Some(invalid))
+                    .expect_err("invalid name");
+            assert!(
+                matches!(err, AxiomError::Validation(_)),
+                "{invalid}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn ovpack_export_options_filter_entries_and_embed_manifest() {
+        let temp = tempdir().expect("tempdir");
+        let fsys = LocalContextFs::new(temp.path());
+        fsys.initialize().expect("init failed");
+
+        let src = AxiomUri::root(Scope::Resources).join("demo").expect("join");
+        fsys.create_dir_all(&src, true).expect("mkdir");
+        let src_path = fsys.resolve_uri(&src);
+        fs::create_dir_all(src_path.join("assets")).expect("mkdir assets");
+        fs::write(src_path.join(".abstract.md"), "tier").expect("write tier");
+        fs::write(src_path.join("note.md"), "keep me").expect("write note");
+        fs::write(src_path.join("draft.tmp"), "scratch").expect("write tmp");
+        fs::write(src_path.join("assets").join("big.bin"), [0u8; 64]).expect("write bin");
+
+        let pack_path = export_ovpack_with_options(
+            &fsys,
+            &src,
+            &temp.path().join("demo"),
+            &ExportOvpackOptions {
+                exclude_globs: vec!["assets".to_string(), "*.tmp".to_string()],
+                include_globs: Vec::new(),
+                skip_tier_files: true,
+            },
+        )
+        .expect("export");
+
+        let mut archive = ZipArchive::new(fs::File::open(&pack_path).expect("open")).expect("zip");
+        let mut raw = String::new();
+        archive
+            .by_name("demo/_._ovpack-manifest.json")
+            .expect("manifest entry")
+            .read_to_string(&mut raw)
+            .expect("read manifest");
+        let manifest: OvpackManifest = serde_json::from_str(&raw).expect("parse manifest");
+        assert_eq!(manifest.version, OVPACK_MANIFEST_VERSION);
+        assert_eq!(manifest.files.len(), 1);
+        let entry = &manifest.files[0];
+        assert_eq!(entry.path, "note.md");
+        assert_eq!(entry.size, 7);
+        assert_eq!(entry.mime, "text/markdown");
+        assert_eq!(entry.blake3, blake3::hash(b"keep me").to_hex().to_string());
+
+        let imported =
+            import_ovpack(&fsys, &pack_path, &AxiomUri::root(Scope::User), true).expect("import");
+        let imported_path = fsys.resolve_uri(&imported);
+        assert!(imported_path.join("note.md").exists());
+        assert!(!imported_path.join(".abstract.md").exists());
+        assert!(!imported_path.join("draft.tmp").exists());
+        assert!(!imported_path.join("assets").exists());
+        assert!(!imported_path.join(OVPACK_MANIFEST_FILE_NAME).exists());
+
+        let include_path = export_ovpack_with_options(
+            &fsys,
+            &src,
+            &temp.path().join("only-bin"),
+            &ExportOvpackOptions {
+                exclude_globs: Vec::new(),
+                include_globs: vec!["**/*.bin".to_string()],
+                skip_tier_files: false,
+            },
+        )
+        .expect("export include");
+        let archive = ZipArchive::new(fs::File::open(&include_path).expect("open")).expect("zip");
+        let mut names = archive
+            .file_names()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "demo/".to_string(),
+                "demo/_._ovpack-manifest.json".to_string(),
+                "demo/assets/big.bin".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn ovpack_import_rejects_tampered_files_before_writing_unless_unverified() {
+        let temp = tempdir().expect("tempdir");
+        let fsys = LocalContextFs::new(temp.path());
+        fsys.initialize().expect("init failed");
+
+        let src = AxiomUri::root(Scope::Resources).join("demo").expect("join");
+        fsys.create_dir_all(&src, true).expect("mkdir");
+        fs::write(fsys.resolve_uri(&src).join("a.txt"), "alpha").expect("write a");
+        fs::write(fsys.resolve_uri(&src).join("b.txt"), "beta").expect("write b");
+        let pack_path = export_ovpack(&fsys, &src, &temp.path().join("demo")).expect("export");
+
+        // Repack with a.txt changed and b.txt dropped.
+        let tampered = temp.path().join("tampered.ovpack");
+        let mut source = ZipArchive::new(fs::File::open(&pack_path).expect("open")).expect("zip");
+        let mut writer = ZipWriter::new(fs::File::create(&tampered).expect("create"));
+        let options = SimpleFileOptions::default();
+        for i in 0..source.len() {
+            let mut entry = source.by_index(i).expect("entry");
+            let name = entry.name().to_string();
+            if entry.is_dir() {
+                writer.add_directory(name, options).expect("dir");
+                continue;
+            }
+            if name == "demo/b.txt" {
+                continue;
+            }
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes).expect("read");
+            if name == "demo/a.txt" {
+                bytes = b"ALPHA".to_vec();
+            }
+            writer.start_file(name, options).expect("start");
+            writer.write_all(&bytes).expect("write");
+        }
+        writer.finish().expect("finish");
+
+        let parent = AxiomUri::root(Scope::User);
+        let err = import_ovpack(&fsys, &tampered, &parent, false).expect_err("must fail");
+        let AxiomError::Validation(message) = err else {
+            panic!("expected validation error, got {err:?}");
+        };
+        assert!(message.contains("a.txt (blake3 mismatch)"), "{message}");
+        assert!(
+            message.contains("b.txt (missing from archive)"),
+            "{message}"
+        );
+        let target = parent.join("demo").expect("target");
+        assert!(!fsys.exists(&target), "nothing may be written on mismatch");
+
+        let imported = import_ovpack_with_verify(&fsys, &tampered, &parent, false, false, None)
+            .expect("no verify");
+        assert_eq!(
+            fs::read_to_string(fsys.resolve_uri(&imported).join("a.txt")).expect("read"),
+            "ALPHA"
+        );
+    }
+
     #[test]
     fn ovpack_rejects_zip_slip() {
         let temp = tempdir().expect("tempdir");
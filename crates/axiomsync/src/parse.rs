@@ -13,8 +13,14 @@ pub struct ParsedDocument {
     pub normalized_text: Option<String>,
     pub line_count: usize,
     pub tags: Vec<String>,
+    /// Non-fatal parse problems to report, such as broken front matter.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
+/// Tag prefix for front matter scalars, e.g. `fm.status:draft`.
+pub const FRONT_MATTER_TAG_PREFIX: &str = "fm.";
+
 #[derive(Debug, Clone, Default)]
 pub struct ParserRegistry;
 
@@ -52,18 +58,24 @@ impl ParserRegistry {
 fn parse_markdown(bytes: &[u8]) -> ParsedDocument {
     let text = String::from_utf8_lossy(bytes);
     let normalized = normalize_markdown_for_indexing(&text);
-    let mut title = None;
-    for line in normalized.lines() {
-        let trimmed = line.trim();
-        if let Some(rest) = trimmed.strip_prefix("# ") {
-            title = Some(rest.trim().to_string());
-            break;
-        }
-        if !trimmed.is_empty() && title.is_none() && !is_markdown_rule_line(trimmed) {
-            title = Some(trimmed.to_string());
+    let mut tags = vec!["markdown".to_string()];
+    let mut warnings = Vec::new();
+    let mut front_matter_title = None;
+    let body = match split_front_matter(&normalized) {
+        Some((yaml, body)) => {
+            match parse_front_matter(yaml) {
+                Ok(front_matter) => {
+                    front_matter_title = front_matter.title;
+                    tags.extend(front_matter.tags);
+                }
+                Err(err) => warnings.push(format!("front_matter_invalid: {err}")),
+            }
+            body
         }
-    }
+        None => normalized.as_str(),
+    };
 
+    let title = front_matter_title.or_else(|| markdown_title(body));
     let preview = normalized.chars().take(240).collect::<String>();
     ParsedDocument {
         parser: "markdown".to_string(),
@@ -72,7 +84,110 @@ fn parse_markdown(bytes: &[u8]) -> ParsedDocument {
         text_preview: preview,
         normalized_text: Some(normalized.clone()),
         line_count: normalized.lines().count(),
-        tags: vec!["markdown".to_string()],
+        tags,
+        warnings,
+    }
+}
+
+fn markdown_title(body: &str) -> Option<String> {
+    let mut title = None;
+    for line in body.lines() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix("# ") {
+            return Some(rest.trim().to_string());
+        }
+        if !trimmed.is_empty() && title.is_none() && !is_markdown_rule_line(trimmed) {
+            title = Some(trimmed.to_string());
+        }
+    }
+    title
+}
+
+#[derive(Debug, Default)]
+struct FrontMatter {
+    title: Option<String>,
+    tags: Vec<String>,
+}
+
+/// Front matter only when the first line is `---` and a closing `---` or `...` follows.
+fn split_front_matter(text: &str) -> Option<(&str, &str)> {
+    let rest = text
+        .strip_prefix("---\r\n")
+        .or_else(|| text.strip_prefix("---\n"))?;
+    let mut offset = 0usize;
+    for line in rest.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed == "---" || trimmed == "..." {
+            return Some((&rest[..offset], &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Maps `title` and `tags` directly and flattens other scalars into `fm.<key>:<value>` tags,
+/// one per list element.
+fn parse_front_matter(yaml: &str) -> std::result::Result<FrontMatter, String> {
+    if yaml.trim().is_empty() {
+        return Ok(FrontMatter::default());
+    }
+    let value =
+        serde_norway::from_str::<serde_norway::Value>(yaml).map_err(|err| err.to_string())?;
+    let value = serde_json::to_value(value).map_err(|err| err.to_string())?;
+    let serde_json::Value::Object(map) = value else {
+        return Err("front matter must be a mapping".to_string());
+    };
+
+    let mut front_matter = FrontMatter::default();
+    for (key, child) in &map {
+        match key.as_str() {
+            "title" => {
+                front_matter.title = child
+                    .as_str()
+                    .map(normalize_scalar_text)
+                    .filter(|title| !title.is_empty());
+            }
+            "tags" => front_matter.tags.extend(front_matter_tag_values(child)),
+            _ => {}
+        }
+        if key != "tags" {
+            flatten_front_matter_value(child, key, &mut front_matter.tags);
+        }
+    }
+    Ok(front_matter)
+}
+
+fn front_matter_tag_values(value: &serde_json::Value) -> Vec<String> {
+    let raw = match value {
+        serde_json::Value::Array(items) => items.iter().map(json_scalar_text).collect::<Vec<_>>(),
+        serde_json::Value::String(text) => text.split(',').map(ToString::to_string).collect(),
+        _ => Vec::new(),
+    };
+    raw.into_iter()
+        .map(|tag| tag.trim().trim_start_matches('#').to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+fn flatten_front_matter_value(value: &serde_json::Value, key: &str, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (child_key, child) in map {
+                flatten_front_matter_value(child, &format!("{key}.{child_key}"), out);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                flatten_front_matter_value(item, key, out);
+            }
+        }
+        serde_json::Value::Null => {}
+        _ => {
+            let text = json_scalar_text(value);
+            if !text.is_empty() {
+                out.push(format!("{FRONT_MATTER_TAG_PREFIX}{key}:{text}"));
+            }
+        }
     }
 }
 
@@ -92,6 +207,7 @@ fn parse_plain_text(text: &str) -> ParsedDocument {
         normalized_text: Some(text.to_string()),
         line_count: text.lines().count(),
         tags: vec!["text".to_string()],
+        warnings: Vec::new(),
     }
 }
 
@@ -257,6 +373,7 @@ fn structured_document(
         normalized_text: Some(normalized),
         line_count: lines.len(),
         tags,
+        warnings: Vec::new(),
     }
 }
 
@@ -513,6 +630,7 @@ fn parse_binary(bytes: &[u8]) -> ParsedDocument {
         normalized_text: None,
         line_count: 0,
         tags: vec!["binary".to_string()],
+        warnings: Vec::new(),
     }
 }
 
@@ -588,6 +706,73 @@ mod tests {
         assert!(normalized.contains("# Real Title"));
     }
 
+    #[test]
+    fn markdown_parser_extracts_front_matter_title_tags_and_scalars() {
+        let registry = ParserRegistry::new();
+        let parsed = registry.parse_file(
+            Path::new("note.md"),
+            b"---\ntitle: Rotation Plan\ntags: [Auth, ops]\naliases:\n  - rotate\n  - keys\nstatus: draft\nreview:\n  owner: kim\n  round: 2\n---\n# Heading\n\nBody",
+        );
+
+        assert_eq!(parsed.title.as_deref(), Some("Rotation Plan"));
+        assert!(parsed.warnings.is_empty());
+        for tag in [
+            "markdown",
+            "auth",
+            "ops",
+            "fm.title:Rotation Plan",
+            "fm.aliases:rotate",
+            "fm.aliases:keys",
+            "fm.status:draft",
+            "fm.review.owner:kim",
+            "fm.review.round:2",
+        ] {
+            assert!(
+                parsed.tags.contains(&tag.to_string()),
+                "{tag}: {:?}",
+                parsed.tags
+            );
+        }
+        assert!(!parsed.tags.iter().any(|tag| tag.starts_with("fm.tags")));
+    }
+
+    #[test]
+    fn markdown_parser_only_reads_leading_front_matter_block() {
+        let registry = ParserRegistry::new();
+        let parsed = registry.parse_file(
+            Path::new("note.md"),
+            b"# Intro\n\n---\nstatus: draft\n---\nmore body",
+        );
+        assert_eq!(parsed.title.as_deref(), Some("Intro"));
+        assert!(!parsed.tags.iter().any(|tag| tag.starts_with("fm.")));
+
+        let parsed = registry.parse_file(
+            Path::new("note.md"),
+            b"---\nstatus: final\n---\nSection one\n\n---\n\nstatus: ignored\n",
+        );
+        assert_eq!(parsed.title.as_deref(), Some("Section one"));
+        assert!(parsed.tags.contains(&"fm.status:final".to_string()));
+        assert!(!parsed.tags.contains(&"fm.status:ignored".to_string()));
+    }
+
+    #[test]
+    fn markdown_parser_warns_on_malformed_front_matter_and_keeps_body() {
+        let registry = ParserRegistry::new();
+        let parsed = registry.parse_file(
+            Path::new("note.md"),
+            b"---\ntitle: [unclosed\n---\n# Body Title\n\nbody_token",
+        );
+        assert_eq!(parsed.title.as_deref(), Some("Body Title"));
+        assert_eq!(parsed.warnings.len(), 1);
+        assert!(parsed.warnings[0].starts_with("front_matter_invalid:"));
+        assert!(
+            parsed
+                .normalized_text
+                .expect("normalized")
+                .contains("body_token")
+        );
+    }
+
     #[test]
     fn markdown_parser_keeps_leading_metadata_lines() {
         let registry = ParserRegistry::new();
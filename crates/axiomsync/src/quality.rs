@@ -7,7 +7,8 @@ use crate::host_tools::{HostCommandResult, HostCommandSpec, run_host_command};
 use crate::models::{
     BenchmarkAcceptanceCheck, BenchmarkAcceptanceMeasured, BenchmarkAcceptanceResult,
     BenchmarkAcceptanceThresholds, BenchmarkQuerySetMetadata, BenchmarkReport, BenchmarkSummary,
-    EvalLoopReport, EvalQueryCase, TraceMetricsSnapshotDocument, TraceMetricsSnapshotSummary,
+    ContextHit, EvalLoopReport, EvalQueryCase, FindResult, TraceMetricsSnapshotDocument,
+    TraceMetricsSnapshotSummary,
 };
 use crate::uri::AxiomUri;
 
@@ -412,6 +413,7 @@ pub fn format_benchmark_report_markdown(report: &BenchmarkReport) -> String {
     write_benchmark_corpus(&mut out, report);
     write_benchmark_query_set(&mut out, report);
     write_benchmark_acceptance(&mut out, report);
+    write_benchmark_concurrency_profiles(&mut out, report);
     write_benchmark_slowest_cases(&mut out, report);
     out
 }
@@ -459,6 +461,7 @@ fn write_benchmark_header(out: &mut String, report: &BenchmarkReport) {
         out,
         format_args!("- recall@10: `{:.4}`\n", report.quality.recall_at_10),
     );
+    write_line(out, format_args!("- mrr: `{:.4}`\n", report.quality.mrr));
     write_line(
         out,
         format_args!(
@@ -672,10 +675,31 @@ fn write_benchmark_acceptance(out: &mut String, report: &BenchmarkReport) {
     }
 }
 
+fn write_benchmark_concurrency_profiles(out: &mut String, report: &BenchmarkReport) {
+    if report.concurrency_profiles.is_empty() {
+        return;
+    }
+    write_section_header(out, "Concurrency Profiles");
+    for profile in &report.concurrency_profiles {
+        write_line(
+            out,
+            format_args!(
+                "- concurrency=`{}` queries=`{}` qps=`{:.2}` latency_ms: p50=`{}`, p95=`{}`, p99=`{}`\n",
+                profile.concurrency,
+                profile.executed_queries,
+                profile.queries_per_second,
+                profile.latency.p50_ms,
+                profile.latency.p95_ms,
+                profile.latency.p99_ms
+            ),
+        );
+    }
+}
+
 fn write_benchmark_slowest_cases(out: &mut String, report: &BenchmarkReport) {
     write_section_header(out, "Slowest Cases");
     let mut results = report.results.clone();
-    results.sort_by(|a, b| b.latency_ms.cmp(&a.latency_ms));
+    results.sort_by_key(|case| std::cmp::Reverse(case.latency_ms));
     if results.is_empty() {
         out.push_str("- none\n");
         return;
@@ -705,12 +729,131 @@ fn write_benchmark_slowest_cases(out: &mut String, report: &BenchmarkReport) {
     }
 }
 
+/// Renders a search result as a shareable Markdown report; the query comes from the trace.
+pub fn export_search_result_markdown(result: &FindResult) -> String {
+    let mut out = String::new();
+    out.push_str("# Search Report\n\n");
+    let query = result.trace.as_ref().map_or_else(
+        || {
+            result
+                .query_plan
+                .typed_queries
+                .first()
+                .map_or("-", |typed| typed.query.as_str())
+        },
+        |trace| trace.query.as_str(),
+    );
+    write_line(&mut out, format_args!("- query: `{query}`\n"));
+    if let Some(target_uri) = result
+        .trace
+        .as_ref()
+        .and_then(|trace| trace.target_uri.as_deref())
+    {
+        write_line(&mut out, format_args!("- target_uri: `{target_uri}`\n"));
+    }
+    if !result.query_plan.scopes.is_empty() {
+        write_line(
+            &mut out,
+            format_args!("- scopes: `{}`\n", result.query_plan.scopes.join(", ")),
+        );
+    }
+    write_line(
+        &mut out,
+        format_args!("- hits: `{}`\n", result.query_results.len()),
+    );
+    if let Some(trace_uri) = result.trace_uri.as_deref() {
+        write_line(&mut out, format_args!("- trace_uri: `{trace_uri}`\n"));
+    }
+
+    write_section_header(&mut out, "Hits");
+    if result.query_results.is_empty() {
+        out.push_str("- none\n");
+        return out;
+    }
+    for (rank, hit) in result.query_results.iter().enumerate() {
+        write_search_hit(&mut out, rank + 1, hit);
+    }
+    out
+}
+
+/// Numbered citation block, one `[n] <uri> — <first abstract line>` per hit; URI only if the abstract is empty.
+pub fn format_citations(result: &FindResult) -> String {
+    let mut out = String::new();
+    for (rank, hit) in result.query_results.iter().enumerate() {
+        let rank = rank + 1;
+        match hit
+            .abstract_text
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+        {
+            Some(summary) => {
+                write_line(&mut out, format_args!("[{rank}] {} — {summary}\n", hit.uri))
+            }
+            None => write_line(&mut out, format_args!("[{rank}] {}\n", hit.uri)),
+        }
+    }
+    out
+}
+
+fn write_search_hit(out: &mut String, rank: usize, hit: &ContextHit) {
+    write_line(out, format_args!("### {rank}. `{}`\n\n", hit.uri));
+    write_line(
+        out,
+        format_args!(
+            "- score: `{:.4}` (exact `{:.4}`, dense `{:.4}`, sparse `{:.4}`, path `{:.4}`, recency `{:.4}`)\n",
+            hit.score,
+            hit.score_components.exact,
+            hit.score_components.dense,
+            hit.score_components.sparse,
+            hit.score_components.path,
+            hit.score_components.recency
+        ),
+    );
+    write_line(
+        out,
+        format_args!("- context_type: `{}`\n", hit.context_type),
+    );
+    if let Some(heading) = hit.matched_heading.as_deref() {
+        write_line(out, format_args!("- matched_heading: `{heading}`\n"));
+    }
+    let snippet = hit.snippet.as_deref().unwrap_or(hit.abstract_text.as_str());
+    let snippet = snippet.trim();
+    if !snippet.is_empty() {
+        out.push('\n');
+        for line in snippet.lines() {
+            write_line(out, format_args!("> {line}\n"));
+        }
+        out.push('\n');
+    }
+    if !hit.relations.is_empty() {
+        out.push_str("- relations:\n");
+        for relation in &hit.relations {
+            write_line(
+                out,
+                format_args!(
+                    "  - `{}` ({}{})\n",
+                    relation.uri,
+                    relation.relation_type.as_deref().unwrap_or("related"),
+                    if relation.reason.is_empty() {
+                        String::new()
+                    } else {
+                        format!(": {}", relation.reason)
+                    }
+                ),
+            );
+        }
+    }
+    out.push('\n');
+}
+
 pub fn to_benchmark_summary(report: BenchmarkReport) -> BenchmarkSummary {
     BenchmarkSummary {
         run_id: report.run_id,
         created_at: report.created_at,
         executed_cases: report.quality.executed_cases,
         top1_accuracy: report.quality.top1_accuracy,
+        mrr: report.quality.mrr,
         p95_latency_ms: report.latency.find.p95_ms,
         p95_latency_us: report.latency.find.p95_us,
         report_uri: report.artifacts.report_uri,
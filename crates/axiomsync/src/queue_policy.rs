@@ -1,45 +1,87 @@
 use crate::error::{AxiomError, OmInferenceFailureKind};
 use crate::uri::Scope;
 
+/// Retry curve and dead-letter cutoff for outbox events. The delay after failure `attempt` is
+/// `base_delay_secs * multiplier^(attempt-1)` plus deterministic jitter, capped at `max_delay_secs`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub base_delay_secs: i64,
+    pub multiplier: f64,
+    pub max_delay_secs: i64,
+    /// Dead-letters the event after this many failed attempts.
+    pub max_attempts: u32,
+}
+
+impl RetryPolicy {
+    /// Per-kind defaults when unconfigured.
+    #[must_use]
+    pub fn for_event_type(event_type: &str) -> Self {
+        let (max_delay_secs, max_attempts) = match event_type {
+            "semantic_scan" => (60, 5),
+            "om_reflect_requested"
+            | "om_reflect_buffer_requested"
+            | "om_observe_buffer_requested" => (120, 6),
+            _ => (30, 3),
+        };
+        Self {
+            base_delay_secs: 1,
+            multiplier: 2.0,
+            max_delay_secs,
+            max_attempts,
+        }
+    }
+
+    #[must_use]
+    pub const fn should_retry(&self, attempt: u32) -> bool {
+        attempt < self.max_attempts
+    }
+
+    /// OM inference errors retry only when transient.
+    #[must_use]
+    pub fn should_retry_error(&self, event_type: &str, attempt: u32, err: &AxiomError) -> bool {
+        if matches!(
+            event_type,
+            "om_reflect_requested" | "om_reflect_buffer_requested" | "om_observe_buffer_requested"
+        ) && let AxiomError::OmInference { kind, .. } = err
+        {
+            return matches!(kind, OmInferenceFailureKind::Transient) && self.should_retry(attempt);
+        }
+        self.should_retry(attempt)
+    }
+
+    #[must_use]
+    pub fn backoff_seconds(&self, event_type: &str, attempt: u32, event_id: i64) -> i64 {
+        let max = self.max_delay_secs.max(self.base_delay_secs);
+        let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+        let scaled = self.base_delay_secs as f64 * self.multiplier.powi(exponent);
+        let baseline = if scaled.is_finite() && scaled < max as f64 {
+            scaled.round() as i64
+        } else {
+            max
+        };
+        let jitter_bound = (baseline / 4).max(1);
+        let jitter_seed = format!("{event_type}:{attempt}:{event_id}");
+        let hash = blake3::hash(jitter_seed.as_bytes());
+        let bytes = hash.as_bytes();
+        let rand = i64::from(u16::from_be_bytes([bytes[0], bytes[1]]));
+        let jitter = rand % (jitter_bound + 1);
+        (baseline + jitter).min(max)
+    }
+}
+
+#[cfg(test)]
 pub fn should_retry_event(event_type: &str, attempt: u32) -> bool {
-    let max_attempts = match event_type {
-        "semantic_scan" => 5,
-        "om_reflect_requested" | "om_reflect_buffer_requested" | "om_observe_buffer_requested" => 6,
-        _ => 3,
-    };
-    attempt < max_attempts
+    RetryPolicy::for_event_type(event_type).should_retry(attempt)
 }
 
+#[cfg(test)]
 pub fn should_retry_event_error(event_type: &str, attempt: u32, err: &AxiomError) -> bool {
-    if matches!(
-        event_type,
-        "om_reflect_requested" | "om_reflect_buffer_requested" | "om_observe_buffer_requested"
-    ) && let AxiomError::OmInference { kind, .. } = err
-    {
-        return matches!(kind, OmInferenceFailureKind::Transient)
-            && should_retry_event(event_type, attempt);
-    }
-    should_retry_event(event_type, attempt)
+    RetryPolicy::for_event_type(event_type).should_retry_error(event_type, attempt, err)
 }
 
+#[cfg(test)]
 pub fn retry_backoff_seconds(event_type: &str, attempt: u32, event_id: i64) -> i64 {
-    let capped_exp = attempt.saturating_sub(1).min(6);
-    let base = 1_i64 << capped_exp;
-    let max = match event_type {
-        "semantic_scan" => 60,
-        "om_reflect_requested" | "om_reflect_buffer_requested" | "om_observe_buffer_requested" => {
-            120
-        }
-        _ => 30,
-    };
-    let baseline = base.min(max);
-    let jitter_bound = (baseline / 4).max(1);
-    let jitter_seed = format!("{event_type}:{attempt}:{event_id}");
-    let hash = blake3::hash(jitter_seed.as_bytes());
-    let bytes = hash.as_bytes();
-    let rand = i64::from(u16::from_be_bytes([bytes[0], bytes[1]]));
-    let jitter = rand % (jitter_bound + 1);
-    (baseline + jitter).min(max)
+    RetryPolicy::for_event_type(event_type).backoff_seconds(event_type, attempt, event_id)
 }
 
 pub fn default_scope_set() -> Vec<Scope> {
@@ -118,6 +160,34 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn retry_policy_backoff_follows_configured_curve_and_cap() {
+        let policy = RetryPolicy {
+            base_delay_secs: 4,
+            multiplier: 3.0,
+            max_delay_secs: 200,
+            max_attempts: 6,
+        };
+        let baselines = [4_i64, 12, 36, 108, 200, 200];
+        for (attempt, baseline) in (1_u32..).zip(baselines) {
+            let delay = policy.backoff_seconds("semantic_scan", attempt, 7);
+            assert!(
+                (baseline..=(baseline + baseline / 4).min(200)).contains(&delay),
+                "attempt {attempt}: {delay} not within jitter of {baseline}"
+            );
+        }
+        assert_eq!(policy.backoff_seconds("semantic_scan", 40, 7), 200);
+        assert_eq!(policy.backoff_seconds("semantic_scan", u32::MAX, 7), 200);
+        assert!(policy.should_retry(5));
+        assert!(!policy.should_retry(6));
+
+        let flat = RetryPolicy {
+            multiplier: 1.0,
+            ..policy
+        };
+        assert!((4..=5).contains(&flat.backoff_seconds("semantic_scan", 5, 7)));
+    }
+
     #[test]
     fn default_scope_set_contains_all_expected_scopes() {
         let scopes = default_scope_set();
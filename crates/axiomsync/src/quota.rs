@@ -0,0 +1,163 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use walkdir::WalkDir;
+
+use crate::config::QuotaConfig;
+use crate::error::{AxiomError, Result};
+use crate::models::ScopeQuotaStatus;
+use crate::state::SqliteStateStore;
+use crate::uri::Scope;
+
+/// Tracks per-scope usage in `SqliteStateStore` and rejects writes past the limit.
+pub(crate) struct ScopeQuota {
+    config: QuotaConfig,
+    state: SqliteStateStore,
+    root: PathBuf,
+    guard: Mutex<()>,
+}
+
+impl std::fmt::Debug for ScopeQuota {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScopeQuota")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ScopeQuota {
+    pub(crate) fn new(config: QuotaConfig, state: SqliteStateStore, root: PathBuf) -> Self {
+        Self {
+            config,
+            state,
+            root,
+            guard: Mutex::new(()),
+        }
+    }
+
+    /// Runs `write` under the quota lock and records the size change only if it succeeds.
+    /// Shrinking writes are allowed even when the scope is already over its limit.
+    pub(crate) fn reserve_with<T>(
+        &self,
+        scope: Scope,
+        sizes: impl FnOnce() -> (u64, u64),
+        write: impl FnOnce() -> Result<T>,
+    ) -> Result<T> {
+        let Some(limit_bytes) = self.config.limit_bytes(scope) else {
+            return write();
+        };
+        let _guard = self.lock()?;
+        let current_bytes = self.usage_locked(scope)?;
+        let (previous_bytes, new_bytes) = sizes();
+        let requested_bytes = new_bytes.saturating_sub(previous_bytes);
+        if requested_bytes > 0 && current_bytes.saturating_add(requested_bytes) > limit_bytes {
+            return Err(AxiomError::QuotaExceeded {
+                scope: scope.as_str().to_string(),
+                current_bytes,
+                requested_bytes,
+                limit_bytes,
+            });
+        }
+        let output = write()?;
+        let next = current_bytes
+            .saturating_add(new_bytes)
+            .saturating_sub(previous_bytes);
+        self.state.set_scope_usage_bytes(scope.as_str(), next)?;
+        Ok(output)
+    }
+
+    /// Directory creation is rejected only when usage is already over the limit.
+    pub(crate) fn ensure_within_limit(&self, scope: Scope) -> Result<()> {
+        let Some(limit_bytes) = self.config.limit_bytes(scope) else {
+            return Ok(());
+        };
+        let _guard = self.lock()?;
+        let current_bytes = self.usage_locked(scope)?;
+        if current_bytes > limit_bytes {
+            return Err(AxiomError::QuotaExceeded {
+                scope: scope.as_str().to_string(),
+                current_bytes,
+                requested_bytes: 0,
+                limit_bytes,
+            });
+        }
+        Ok(())
+    }
+
+    pub(crate) fn release(&self, scope: Scope, bytes: u64) -> Result<()> {
+        self.reserve_with(scope, || (bytes, 0), || Ok(()))
+    }
+
+    /// Rescans disk usage; `persist = false` leaves the stored usage unchanged.
+    pub(crate) fn recompute(
+        &self,
+        scopes: &[Scope],
+        persist: bool,
+    ) -> Result<Vec<ScopeQuotaStatus>> {
+        let _guard = self.lock()?;
+        let mut out = Vec::new();
+        for scope in self.config.limited_scopes() {
+            if !scopes.contains(&scope) {
+                continue;
+            }
+            let usage_bytes = tree_size_bytes(&self.root.join(scope.as_str()));
+            if persist {
+                self.state
+                    .set_scope_usage_bytes(scope.as_str(), usage_bytes)?;
+            }
+            out.push(self.status_entry(scope, usage_bytes));
+        }
+        Ok(out)
+    }
+
+    pub(crate) fn status(&self) -> Result<Vec<ScopeQuotaStatus>> {
+        let _guard = self.lock()?;
+        self.config
+            .limited_scopes()
+            .into_iter()
+            .map(|scope| Ok(self.status_entry(scope, self.usage_locked(scope)?)))
+            .collect()
+    }
+
+    fn status_entry(&self, scope: Scope, usage_bytes: u64) -> ScopeQuotaStatus {
+        ScopeQuotaStatus {
+            scope: scope.as_str().to_string(),
+            usage_bytes,
+            limit_bytes: self.config.limit_bytes(scope).unwrap_or(u64::MAX),
+        }
+    }
+
+    fn usage_locked(&self, scope: Scope) -> Result<u64> {
+        if let Some(bytes) = self.state.get_scope_usage_bytes(scope.as_str())? {
+            return Ok(bytes);
+        }
+        let bytes = tree_size_bytes(&self.root.join(scope.as_str()));
+        self.state.set_scope_usage_bytes(scope.as_str(), bytes)?;
+        Ok(bytes)
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, ()>> {
+        self.guard
+            .lock()
+            .map_err(|_| AxiomError::mutex_poisoned("scope quota"))
+    }
+}
+
+/// File size, or the sum of file sizes under a directory; 0 if missing.
+pub(crate) fn tree_size_bytes(path: &Path) -> u64 {
+    let Ok(meta) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !meta.is_dir() {
+        return meta.len();
+    }
+    WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(std::result::Result::ok)
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(std::fs::Metadata::is_file)
+        .map(|meta| meta.len())
+        .sum()
+}
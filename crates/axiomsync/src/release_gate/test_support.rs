@@ -52,6 +52,8 @@ pub(super) fn benchmark_gate_result(
             min_stress_top1_accuracy: None,
             max_p95_regression_pct: Some(0.1),
             max_top1_regression_pct: Some(2.0),
+            max_mrr_regression_pct: None,
+            max_p95_ms_at_concurrency: None,
         },
         quorum: BenchmarkGateQuorum {
             window_size: 3,
@@ -63,6 +65,7 @@ pub(super) fn benchmark_gate_result(
                 created_at: "2026-01-01T00:00:00Z".to_string(),
                 executed_cases: 10,
                 top1_accuracy: 0.9,
+                mrr: 0.0,
                 p95_latency_ms: 700,
                 p95_latency_us: Some(699_420),
                 report_uri: "axiom://queue/benchmarks/reports/run.json".to_string(),
@@ -71,6 +74,7 @@ pub(super) fn benchmark_gate_result(
             regression_pct: None,
             top1_regression_pct: None,
             stress_top1_accuracy: None,
+            corpus_snapshot_mismatch: None,
         },
         execution: BenchmarkGateExecution {
             evaluated_runs: 1,
@@ -84,6 +88,7 @@ pub(super) fn benchmark_gate_result(
                 stress_top1_accuracy: None,
                 regression_pct: None,
                 top1_regression_pct: None,
+                mrr_regression_pct: None,
                 reasons: vec!["ok".to_string()],
             }],
             reasons: vec!["ok".to_string()],
@@ -0,0 +1,143 @@
+use std::io::Read;
+use std::time::Duration;
+
+use reqwest::Url;
+use reqwest::blocking::Client;
+use reqwest::redirect::Policy;
+
+use crate::error::{AxiomError, Result};
+
+/// Limits applied when fetching an `http(s)://` source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteFetchLimits {
+    pub max_bytes: usize,
+    pub timeout: Duration,
+    /// `*` allows any host; `*.example.com` allows subdomains only.
+    pub allowed_hosts: Vec<String>,
+}
+
+impl RemoteFetchLimits {
+    #[must_use]
+    pub fn allows(&self, url: &Url) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        let host = host.to_ascii_lowercase();
+        self.allowed_hosts.iter().any(|allowed| {
+            if allowed == "*" {
+                return true;
+            }
+            if let Some(suffix) = allowed.strip_prefix("*.") {
+                return host
+                    .strip_suffix(suffix)
+                    .is_some_and(|prefix| prefix.ends_with('.'));
+            }
+            *allowed == host
+        })
+    }
+}
+
+/// Fetches a remote source as text; implementations must re-check redirects against `limits`.
+pub trait RemoteFetcher: Send + Sync {
+    fn fetch(&self, url: &Url, limits: &RemoteFetchLimits) -> Result<String>;
+}
+
+/// Default blocking `reqwest` fetcher; does not follow redirects off the allowlist.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HttpRemoteFetcher;
+
+impl RemoteFetcher for HttpRemoteFetcher {
+    fn fetch(&self, url: &Url, limits: &RemoteFetchLimits) -> Result<String> {
+        let redirect_limits = limits.clone();
+        let client = Client::builder()
+            .timeout(limits.timeout)
+            .redirect(Policy::custom(move |attempt| {
+                if attempt.previous().len() >= 10 || !redirect_limits.allows(attempt.url()) {
+                    attempt.stop()
+                } else {
+                    attempt.follow()
+                }
+            }))
+            .build()?;
+        let resp = client.get(url.clone()).send()?;
+        if !resp.status().is_success() {
+            return Err(AxiomError::Internal(format!(
+                "failed to fetch {url}: status {}",
+                resp.status()
+            )));
+        }
+        if let Some(bytes) = resp.content_length()
+            && bytes > limits.max_bytes as u64
+        {
+            return Err(AxiomError::Validation(format!(
+                "remote resource too large: {bytes} bytes (limit {})",
+                limits.max_bytes
+            )));
+        }
+        read_remote_text_limited(resp, limits.max_bytes)
+    }
+}
+
+pub(crate) fn read_remote_text_limited<R: Read>(mut reader: R, max_bytes: usize) -> Result<String> {
+    let mut body = Vec::new();
+    let mut limited = (&mut reader).take((max_bytes as u64) + 1);
+    limited.read_to_end(&mut body)?;
+    if body.len() > max_bytes {
+        return Err(AxiomError::Validation(format!(
+            "remote resource too large after download: {} bytes (limit {max_bytes})",
+            body.len()
+        )));
+    }
+    String::from_utf8(body).map_err(|err| {
+        AxiomError::Validation(format!("remote resource is not valid utf-8 text: {err}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    const LIMIT: usize = 5 * 1024 * 1024;
+
+    #[test]
+    fn read_remote_text_limited_rejects_payload_over_limit() {
+        let data = vec![b'a'; LIMIT + 1];
+        let err = read_remote_text_limited(Cursor::new(data), LIMIT)
+            .expect_err("must reject oversized payload");
+        assert!(matches!(err, AxiomError::Validation(_)));
+    }
+
+    #[test]
+    fn read_remote_text_limited_accepts_payload_within_limit() {
+        let data = b"hello remote".to_vec();
+        let text = read_remote_text_limited(Cursor::new(data.clone()), LIMIT).expect("ok");
+        assert_eq!(text, String::from_utf8(data).expect("utf8"));
+    }
+
+    #[test]
+    fn limits_allow_exact_and_wildcard_subdomain_hosts_only() {
+        let limits = RemoteFetchLimits {
+            max_bytes: LIMIT,
+            timeout: Duration::from_secs(1),
+            allowed_hosts: vec![
+                "docs.example.com".to_string(),
+                "*.rust-lang.org".to_string(),
+            ],
+        };
+        let allows = |raw: &str| limits.allows(&Url::parse(raw).expect("url"));
+        assert!(allows("https://docs.example.com/guide"));
+        assert!(allows("https://DOCS.example.com/guide"));
+        assert!(allows("https://doc.rust-lang.org/std"));
+        assert!(!allows("https://rust-lang.org/"));
+        assert!(!allows("https://evilrust-lang.org/"));
+        assert!(!allows("https://example.com/"));
+
+        let open = RemoteFetchLimits {
+            allowed_hosts: vec!["*".to_string()],
+            ..limits
+        };
+        assert!(open.allows(&Url::parse("http://anything.test/").expect("url")));
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use crate::models::SearchBudget;
 
 use super::config::DrrConfig;
@@ -7,6 +9,29 @@ pub(super) struct ResolvedBudget {
     pub time_ms: Option<u64>,
     pub nodes: usize,
     pub depth: usize,
+    pub deadline: Deadline,
+}
+
+/// Absolute `max_ms` deadline, also checked inside expansion so one large directory cannot overrun it.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct Deadline(Option<Instant>);
+
+impl Deadline {
+    pub(super) fn is_exhausted(self) -> bool {
+        self.0.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+impl ResolvedBudget {
+    pub(super) fn starting_at(self, start: Instant) -> Self {
+        Self {
+            deadline: Deadline(
+                self.time_ms
+                    .map(|max_ms| start + Duration::from_millis(max_ms)),
+            ),
+            ..self
+        }
+    }
 }
 
 pub(super) fn resolve_budget(config: &DrrConfig, budget: Option<&SearchBudget>) -> ResolvedBudget {
@@ -23,5 +48,6 @@ pub(super) fn resolve_budget(config: &DrrConfig, budget: Option<&SearchBudget>)
         time_ms,
         nodes,
         depth,
+        deadline: Deadline::default(),
     }
 }
@@ -1,3 +1,5 @@
+use crate::index::HybridWeights;
+
 #[derive(Debug, Clone)]
 pub struct DrrConfig {
     pub alpha: f32,
@@ -5,6 +7,7 @@ pub struct DrrConfig {
     pub max_convergence_rounds: u32,
     pub max_depth: usize,
     pub max_nodes: usize,
+    pub hybrid_weights: HybridWeights,
 }
 
 impl Default for DrrConfig {
@@ -15,6 +18,7 @@ impl Default for DrrConfig {
             max_convergence_rounds: 3,
             max_depth: 5,
             max_nodes: 256,
+            hybrid_weights: HybridWeights::default(),
         }
     }
 }
@@ -1,18 +1,22 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::Instant;
 
 use uuid::Uuid;
 
 use crate::index::InMemoryIndex;
 use crate::models::{
-    ContextHit, FindResult, QueryPlan, RetrievalStep, RetrievalTrace, SearchOptions, TracePoint,
-    TraceStats, classify_hit_buckets,
+    ContextHit, EffectiveSearchOptions, FindResult, NoResultsCause, NoResultsDiagnosis, QueryPlan,
+    RetrievalStep, RetrievalTrace, SearchOptions, TracePoint, TraceStats, classify_hit_buckets,
 };
+use crate::uri::{AxiomUri, Scope};
 
 use super::budget::{ResolvedBudget, resolve_budget};
+use super::cancel::CancellationToken;
 use super::config::DrrConfig;
 use super::expansion::run_single_query;
-use super::planner::{PlannedQuery, collect_scope_names, is_om_hint, plan_queries};
+use super::planner::{
+    PlannedQuery, collect_scope_names, is_om_hint, plan_queries, uri_in_excluded_scopes,
+};
 use super::scoring::{
     fanout_priority_weight, merge_hits, merge_trace_points, scale_hit_scores,
     scale_trace_point_scores, sort_hits_by_score_desc_uri_asc, sorted_trace_points,
@@ -25,6 +29,10 @@ pub struct DrrEngine {
 }
 
 const FANOUT_PRIORITY_WEIGHT_NOTE: &str = "fanout_weight:p1=1.00,p2=0.82,p3=0.64,p4+=0.46";
+pub(super) const BUDGET_MS_STOP_REASON: &str = "budget_ms";
+const BUDGET_NODES_STOP_REASON: &str = "budget_nodes";
+/// Plan note marking results cut short by the `max_ms` deadline.
+pub(crate) const BUDGET_EXHAUSTED_NOTE: &str = "budget_exhausted";
 
 impl DrrEngine {
     #[must_use]
@@ -33,10 +41,36 @@ impl DrrEngine {
     }
 
     pub fn run(&self, index: &InMemoryIndex, options: &SearchOptions) -> FindResult {
+        self.run_cancellable(index, options, None)
+    }
+
+    /// Returns the query plan `run` would use and an estimate of candidate nodes.
+    #[must_use]
+    pub fn explain(&self, index: &InMemoryIndex, options: &SearchOptions) -> (QueryPlan, usize) {
+        let planned_queries = plan_queries(options);
+        let request_budget = resolve_budget(&self.config, options.budget.as_ref());
+        let plan = build_query_plan(
+            options,
+            &planned_queries,
+            request_budget,
+            options.limit.max(1),
+        );
+        let candidates = count_candidate_nodes(index, options, &planned_queries).candidates;
+        (plan, candidates)
+    }
+
+    /// Checks `cancel` between planned queries and `max_ms` inside expansion.
+    pub fn run_cancellable(
+        &self,
+        index: &InMemoryIndex,
+        options: &SearchOptions,
+        cancel: Option<&CancellationToken>,
+    ) -> FindResult {
         let start = Instant::now();
         let trace_id = Uuid::new_v4().to_string();
         let planned_queries = plan_queries(options);
-        let request_budget = resolve_budget(&self.config, options.budget.as_ref());
+        let request_budget =
+            resolve_budget(&self.config, options.budget.as_ref()).starting_at(start);
         let fanout = execute_planned_queries(
             &self.config,
             index,
@@ -44,10 +78,12 @@ impl DrrEngine {
             &planned_queries,
             request_budget,
             start,
+            cancel,
         );
 
         let limit = options.limit.max(1);
         let mut hits: Vec<_> = fanout.merged_hits.into_values().collect();
+        hits.retain(|hit| !uri_in_excluded_scopes(&hit.uri, &options.exclude_scopes));
         sort_hits_by_score_desc_uri_asc(&mut hits);
         hits.truncate(limit);
 
@@ -60,6 +96,13 @@ impl DrrEngine {
             .collect::<Vec<_>>();
 
         let start_points = sorted_trace_points(fanout.merged_start_points);
+        let budget_exhausted = fanout
+            .stop_reasons
+            .iter()
+            .any(|reason| reason == BUDGET_MS_STOP_REASON);
+        let no_results_diagnosis = hits.is_empty().then(|| {
+            self.diagnose_no_results(index, options, &planned_queries, &fanout.stop_reasons)
+        });
         let stop_reason = build_stop_reason(&fanout.stop_reasons);
 
         let trace = RetrievalTrace {
@@ -82,7 +125,6 @@ impl DrrEngine {
         };
 
         let hit_buckets = classify_hit_buckets(&hits);
-        let notes = build_query_notes(options, request_budget, planned_queries.len());
         let memories = hit_buckets
             .memories
             .iter()
@@ -99,13 +141,13 @@ impl DrrEngine {
             .filter_map(|&index| hits.get(index).cloned())
             .collect::<Vec<_>>();
 
+        let mut query_plan = build_query_plan(options, &planned_queries, request_budget, limit);
+        if budget_exhausted {
+            query_plan.notes.push(BUDGET_EXHAUSTED_NOTE.to_string());
+        }
+
         FindResult {
-            query_plan: QueryPlan {
-                scopes: collect_scope_names(&planned_queries),
-                keywords: tokenize_keywords(&options.query),
-                typed_queries: typed_query_plans(&planned_queries),
-                notes,
-            },
+            query_plan,
             query_results: hits,
             hit_buckets,
             memories,
@@ -113,6 +155,56 @@ impl DrrEngine {
             skills,
             trace: Some(trace),
             trace_uri: None,
+            next_offset: None,
+            next_cursor: None,
+            no_results_diagnosis,
+        }
+    }
+
+    /// Guesses where candidates were lost: the first stage that empties them, else budget
+    /// or cutoff; the cutoff check reruns retrieval without thresholds.
+    fn diagnose_no_results(
+        &self,
+        index: &InMemoryIndex,
+        options: &SearchOptions,
+        planned_queries: &[PlannedQuery],
+        stop_reasons: &[String],
+    ) -> NoResultsDiagnosis {
+        let counts = count_candidate_nodes(index, options, planned_queries);
+        let mut causes = Vec::new();
+        if counts.scoped == 0 {
+            causes.push(NoResultsCause::ScopeEmpty);
+        } else if counts.after_exclude == 0 {
+            causes.push(NoResultsCause::ExcludedScopesEliminatedAll);
+        } else if counts.candidates == 0 {
+            causes.push(NoResultsCause::FilterEliminatedAll);
+        } else {
+            if stop_reasons
+                .iter()
+                .any(|reason| reason == BUDGET_MS_STOP_REASON || reason == BUDGET_NODES_STOP_REASON)
+            {
+                causes.push(NoResultsCause::BudgetExhausted);
+            }
+            let has_cutoffs = options.score_threshold.is_some()
+                || options.min_match_tokens.is_some_and(|value| value > 1);
+            if has_cutoffs {
+                let uncut = SearchOptions {
+                    score_threshold: None,
+                    min_match_tokens: None,
+                    ..options.clone()
+                };
+                if !self.run(index, &uncut).query_results.is_empty() {
+                    causes.push(NoResultsCause::ThresholdTooHigh);
+                }
+            }
+            if causes.is_empty() {
+                causes.push(NoResultsCause::NoSemanticMatch);
+            }
+        }
+        NoResultsDiagnosis {
+            causes,
+            scoped_nodes: counts.scoped,
+            candidate_nodes: counts.candidates,
         }
     }
 }
@@ -134,25 +226,32 @@ fn execute_planned_queries(
     planned_queries: &[PlannedQuery],
     request_budget: ResolvedBudget,
     start: Instant,
+    cancel: Option<&CancellationToken>,
 ) -> FanoutState {
     let mut state = FanoutState::default();
     let mut round_offset = 0u32;
     let mut remaining_nodes = request_budget.nodes;
 
     for planned in planned_queries {
+        if cancel.is_some_and(CancellationToken::is_cancelled) {
+            state.stop_reasons.push("cancelled".to_string());
+            break;
+        }
         if remaining_nodes == 0 {
-            state.stop_reasons.push("budget_nodes".to_string());
+            state
+                .stop_reasons
+                .push(BUDGET_NODES_STOP_REASON.to_string());
             break;
         }
 
+        if request_budget.deadline.is_exhausted() {
+            state.stop_reasons.push(BUDGET_MS_STOP_REASON.to_string());
+            break;
+        }
         let remaining_ms = request_budget.time_ms.map(|max_ms| {
             let elapsed = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
             max_ms.saturating_sub(elapsed)
         });
-        if remaining_ms == Some(0) {
-            state.stop_reasons.push("budget_ms".to_string());
-            break;
-        }
 
         let mut single = run_single_query(
             config,
@@ -163,6 +262,7 @@ fn execute_planned_queries(
                 time_ms: remaining_ms,
                 nodes: remaining_nodes,
                 depth: request_budget.depth,
+                deadline: request_budget.deadline,
             },
         );
         let weight = fanout_priority_weight(planned.priority);
@@ -193,6 +293,69 @@ fn execute_planned_queries(
     state
 }
 
+fn build_query_plan(
+    options: &SearchOptions,
+    planned_queries: &[PlannedQuery],
+    request_budget: ResolvedBudget,
+    limit: usize,
+) -> QueryPlan {
+    QueryPlan {
+        scopes: collect_scope_names(planned_queries),
+        keywords: tokenize_keywords(&options.query),
+        typed_queries: typed_query_plans(planned_queries),
+        notes: build_query_notes(options, request_budget, planned_queries.len()),
+        effective_options: Some(build_effective_options(options, request_budget, limit)),
+    }
+}
+
+/// Index entries under the target, then after exclude scopes and after filter.
+struct CandidateCounts {
+    scoped: usize,
+    after_exclude: usize,
+    candidates: usize,
+}
+
+fn count_candidate_nodes(
+    index: &InMemoryIndex,
+    options: &SearchOptions,
+    planned_queries: &[PlannedQuery],
+) -> CandidateCounts {
+    let prefixes = options.target_uri.as_ref().map_or_else(
+        || {
+            let mut scopes = planned_queries
+                .iter()
+                .flat_map(|planned| planned.scopes.iter().copied())
+                .collect::<Vec<_>>();
+            scopes.sort_by_key(Scope::as_str);
+            scopes.dedup();
+            scopes.into_iter().map(AxiomUri::root).collect::<Vec<_>>()
+        },
+        |target| vec![target.clone()],
+    );
+    let scoped = prefixes
+        .iter()
+        .flat_map(|prefix| index.uris_with_prefix(prefix))
+        .collect::<HashSet<_>>();
+    let after_exclude = scoped
+        .iter()
+        .filter(|uri| !uri_in_excluded_scopes(uri, &options.exclude_scopes))
+        .collect::<Vec<_>>();
+    let projection = index.filter_projection_uris(options.filter.as_ref());
+    let candidates = after_exclude
+        .iter()
+        .filter(|uri| {
+            projection
+                .as_ref()
+                .is_none_or(|allowed| allowed.contains(uri.as_str()))
+        })
+        .count();
+    CandidateCounts {
+        scoped: scoped.len(),
+        after_exclude: after_exclude.len(),
+        candidates,
+    }
+}
+
 fn build_stop_reason(stop_reasons: &[String]) -> String {
     if stop_reasons.len() <= 1 {
         stop_reasons
@@ -204,6 +367,30 @@ fn build_stop_reason(stop_reasons: &[String]) -> String {
     }
 }
 
+fn build_effective_options(
+    options: &SearchOptions,
+    request_budget: ResolvedBudget,
+    limit: usize,
+) -> EffectiveSearchOptions {
+    EffectiveSearchOptions {
+        request_type: options.request_type.clone(),
+        target_uri: options.target_uri.as_ref().map(ToString::to_string),
+        limit,
+        score_threshold: options.score_threshold,
+        min_match_tokens: options.min_match_tokens.filter(|value| *value > 1),
+        budget_ms: request_budget.time_ms,
+        budget_nodes: request_budget.nodes,
+        budget_depth: request_budget.depth,
+        session_hints: options.session_hints.len(),
+        filter: options.filter.is_some(),
+        exclude_scopes: options
+            .exclude_scopes
+            .iter()
+            .map(|scope| scope.as_str().to_string())
+            .collect(),
+    }
+}
+
 fn build_query_notes(
     options: &SearchOptions,
     request_budget: ResolvedBudget,
@@ -228,6 +415,17 @@ fn build_query_notes(
     if options.filter.is_some() {
         notes.push("filter".to_string());
     }
+    if !options.exclude_scopes.is_empty() {
+        let names = options
+            .exclude_scopes
+            .iter()
+            .map(Scope::as_str)
+            .collect::<Vec<_>>();
+        notes.push(format!("exclude_scopes:{}", names.join(",")));
+    }
+    if options.target_uri.is_none() && !options.scope_set.is_all_content() {
+        notes.push(format!("scope_set:{}", options.scope_set.label()));
+    }
     if let Some(max_ms) = request_budget.time_ms {
         notes.push(format!("budget_ms:{max_ms}"));
     }
@@ -5,7 +5,7 @@ use std::time::Instant;
 
 use uuid::Uuid;
 
-use crate::index::{InMemoryIndex, ScoredRecord};
+use crate::index::{HybridWeights, InMemoryIndex, ScoredRecord};
 use crate::models::{
     ContextHit, RetrievalStep, RetrievalTrace, SearchOptions, TracePoint, TraceStats,
 };
@@ -13,11 +13,14 @@ use crate::uri::AxiomUri;
 
 use super::budget::ResolvedBudget;
 use super::config::DrrConfig;
+use super::engine::BUDGET_MS_STOP_REASON;
 use super::planner::{PlannedQuery, uri_in_scopes};
 use super::scoring::{make_hit, sort_hits_by_score_desc_uri_asc};
 
 const GLOBAL_RANK_FLOOR_DEFAULT: usize = 128;
 const GLOBAL_RANK_FLOOR_IDENTIFIER_QUERY: usize = 256;
+// Read the clock only every this many children when expanding large directories.
+const DEADLINE_CHECK_INTERVAL: usize = 64;
 
 #[derive(Debug, Clone)]
 pub(super) struct SingleRunResult {
@@ -116,7 +119,6 @@ struct ExpansionLoopInput<'a> {
     score_map: &'a HashMap<Arc<str>, f32>,
     score_details: &'a HashMap<Arc<str>, ScoredRecord>,
     frontier: BinaryHeap<Node>,
-    run_start: Instant,
 }
 
 struct IdentifierFastPathInput<'a> {
@@ -129,6 +131,7 @@ struct IdentifierFastPathInput<'a> {
     target_prefix: Option<String>,
     limit: usize,
     run_start: Instant,
+    weights: HybridWeights,
 }
 
 struct QueryFrontierInput<'a> {
@@ -167,6 +170,7 @@ pub(super) fn run_single_query(
         target_prefix: target_prefix.clone(),
         limit,
         run_start,
+        weights: config.hybrid_weights,
     }) {
         return result;
     }
@@ -202,7 +206,6 @@ pub(super) fn run_single_query(
         score_map: &score_map,
         score_details: &score_details,
         frontier,
-        run_start,
     });
     let trace_id = Uuid::new_v4().to_string();
 
@@ -241,6 +244,7 @@ fn run_identifier_query_fast_path(input: IdentifierFastPathInput<'_>) -> Option<
         target_prefix,
         limit,
         run_start,
+        weights,
     } = input;
     // max_ms/max_nodes are enforced by the bounded traversal loop. Fast path
     // bypasses that loop, so opt out when either bound is explicitly set.
@@ -254,12 +258,13 @@ fn run_identifier_query_fast_path(input: IdentifierFastPathInput<'_>) -> Option<
     }
 
     let target = options.target_uri.clone();
-    let mut ranked = index.search(
+    let mut ranked = index.search_with_weights(
         query,
         target.as_ref(),
         limit.max(global_rank_floor(query)),
         options.score_threshold,
         options.filter.as_ref(),
+        weights,
     );
     let target_str = target.as_ref().map(ToString::to_string);
     ranked.retain(|item| {
@@ -334,7 +339,6 @@ fn execute_expansion_loop(input: ExpansionLoopInput<'_>) -> ExpansionLoopState {
         score_map,
         score_details,
         mut frontier,
-        run_start,
     } = input;
     let mut steps = Vec::with_capacity(budget.nodes.min(1024));
     let mut visited = HashSet::with_capacity(budget.nodes.min(1024));
@@ -347,10 +351,8 @@ fn execute_expansion_loop(input: ExpansionLoopInput<'_>) -> ExpansionLoopState {
     let target_str = target.map(ToString::to_string);
 
     while let Some(node) = frontier.pop() {
-        if let Some(max_ms) = budget.time_ms
-            && run_start.elapsed().as_millis() >= u128::from(max_ms)
-        {
-            stop_reason = "budget_ms".to_string();
+        if budget.deadline.is_exhausted() {
+            stop_reason = BUDGET_MS_STOP_REASON.to_string();
             break;
         }
         if explored >= budget.nodes {
@@ -371,8 +373,15 @@ fn execute_expansion_loop(input: ExpansionLoopInput<'_>) -> ExpansionLoopState {
         let children = index.children_of(&node.uri);
         let children_examined = children.len();
         let mut children_selected = 0usize;
+        let mut deadline_hit = false;
 
-        for child in children {
+        for (position, child) in children.into_iter().enumerate() {
+            if position % DEADLINE_CHECK_INTERVAL == DEADLINE_CHECK_INTERVAL - 1
+                && budget.deadline.is_exhausted()
+            {
+                deadline_hit = true;
+                break;
+            }
             if !uri_matches_query_bounds_optimized(
                 &child.uri,
                 planned,
@@ -415,6 +424,10 @@ fn execute_expansion_loop(input: ExpansionLoopInput<'_>) -> ExpansionLoopState {
             children_selected,
             queue_size_after: frontier.len(),
         });
+        if deadline_hit {
+            stop_reason = BUDGET_MS_STOP_REASON.to_string();
+            break;
+        }
 
         if round.is_multiple_of(8)
             && update_convergence_state(
@@ -540,8 +553,13 @@ fn initialize_query_frontier(input: QueryFrontierInput<'_>) -> QueryInitializati
             .filter(|record| uri_matches_filter_projection(&record.uri, filter_projection.as_ref()))
             .collect::<Vec<_>>()
     };
-    let mut global_dirs =
-        index.search_directories(query, target.as_ref(), config.global_topk, filter);
+    let mut global_dirs = index.search_directories(
+        query,
+        target.as_ref(),
+        config.global_topk,
+        filter,
+        config.hybrid_weights,
+    );
     global_dirs.retain(|x| {
         uri_matches_query_bounds_optimized(
             &x.uri,
@@ -551,12 +569,13 @@ fn initialize_query_frontier(input: QueryFrontierInput<'_>) -> QueryInitializati
         ) && x.depth <= budget.depth
     });
 
-    let mut global_rank = index.search(
+    let mut global_rank = index.search_with_weights(
         query,
         target.as_ref(),
         limit.max(global_rank_floor(query)),
         options.score_threshold,
         filter,
+        config.hybrid_weights,
     );
     global_rank.retain(|x| {
         uri_matches_query_bounds_optimized(
@@ -764,6 +783,8 @@ mod tests {
             snippet: None,
             matched_heading: None,
             score_components: crate::models::ScoreComponents::default(),
+            relation_neighborhood: None,
+            freshness: None,
         }
     }
 
@@ -1,12 +1,17 @@
 mod budget;
+mod cancel;
 mod config;
 mod engine;
 mod expansion;
 mod planner;
+mod reranker;
 mod scoring;
 
+pub use cancel::CancellationToken;
 pub use config::DrrConfig;
+pub(crate) use engine::BUDGET_EXHAUSTED_NOTE;
 pub use engine::DrrEngine;
+pub use reranker::{NoopReranker, Reranker};
 
 #[cfg(test)]
 mod tests;
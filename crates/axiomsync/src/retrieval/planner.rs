@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::models::SearchOptions;
+use crate::models::{SearchOptions, SearchScopeSet};
 use crate::uri::{AxiomUri, Scope};
 
 #[derive(Debug, Clone)]
@@ -40,7 +40,14 @@ pub(super) fn plan_queries(options: &SearchOptions) -> Vec<PlannedQuery> {
     )];
 
     if !options.request_type.starts_with("search") {
-        return dedup_and_limit_queries(planned, 1);
+        return dedup_and_limit_queries(
+            exclude_planned_scopes(
+                restrict_to_scope_set(planned, options),
+                options,
+                &options.exclude_scopes,
+            ),
+            1,
+        );
     }
 
     if !options.session_hints.is_empty() {
@@ -107,7 +114,81 @@ pub(super) fn plan_queries(options: &SearchOptions) -> Vec<PlannedQuery> {
         }
     }
 
-    dedup_and_limit_queries(planned, 5)
+    dedup_and_limit_queries(
+        exclude_planned_scopes(
+            restrict_to_scope_set(planned, options),
+            options,
+            &options.exclude_scopes,
+        ),
+        5,
+    )
+}
+
+/// Narrows untargeted planned queries to `scope_set`, adding internal scopes for `AllIncludingInternal`.
+/// Falls back to one primary query over all allowed scopes.
+fn restrict_to_scope_set(planned: Vec<PlannedQuery>, options: &SearchOptions) -> Vec<PlannedQuery> {
+    if options.target_uri.is_some() {
+        return planned;
+    }
+    let allowed = options.scope_set.scopes();
+    let include_internal = options.scope_set == SearchScopeSet::AllIncludingInternal;
+    let mut out = planned
+        .into_iter()
+        .filter_map(|mut item| {
+            if include_internal && item.kind == "primary" {
+                item.scopes
+                    .extend(allowed.iter().copied().filter(Scope::is_internal));
+                item.scopes = normalize_scopes(item.scopes);
+            }
+            item.scopes.retain(|scope| allowed.contains(scope));
+            (!item.scopes.is_empty()).then_some(item)
+        })
+        .collect::<Vec<_>>();
+    if out.is_empty() && !allowed.is_empty() {
+        out.push(PlannedQuery::new(
+            "primary",
+            options.query.clone(),
+            allowed,
+            1,
+        ));
+    }
+    out
+}
+
+/// Removes excluded scopes from every planned query; queries left without any scope are dropped
+/// because an empty scope list would widen them to the whole tree.
+fn exclude_planned_scopes(
+    planned: Vec<PlannedQuery>,
+    options: &SearchOptions,
+    excluded: &[Scope],
+) -> Vec<PlannedQuery> {
+    if excluded.is_empty() {
+        return planned;
+    }
+    let mut out = planned
+        .into_iter()
+        .filter_map(|mut item| {
+            item.scopes.retain(|scope| !excluded.contains(scope));
+            (!item.scopes.is_empty()).then_some(item)
+        })
+        .collect::<Vec<_>>();
+    if out.is_empty() {
+        let remaining = options
+            .scope_set
+            .scopes()
+            .into_iter()
+            .filter(|scope| !excluded.contains(scope))
+            .collect::<Vec<_>>();
+        if !remaining.is_empty() {
+            out.push(PlannedQuery::new(
+                "primary",
+                options.query.clone(),
+                remaining,
+                1,
+            ));
+        }
+    }
+    out
 }
 
 fn query_intent(query: &str) -> QueryIntent {
@@ -232,6 +313,10 @@ pub(super) fn collect_scope_names(planned_queries: &[PlannedQuery]) -> Vec<Strin
         .collect()
 }
 
+pub(super) fn uri_in_excluded_scopes(uri: &str, excluded: &[Scope]) -> bool {
+    !excluded.is_empty() && uri_in_scopes(uri, excluded)
+}
+
 pub(super) fn uri_in_scopes(uri: &str, scopes: &[Scope]) -> bool {
     if scopes.is_empty() {
         return true;
@@ -256,7 +341,7 @@ mod tests {
         PlannedQuery, collect_scope_names, dedup_and_limit_queries, is_om_hint, merge_non_om_hints,
         normalize_scopes, plan_queries, query_intent,
     };
-    use crate::models::SearchOptions;
+    use crate::models::{SearchOptions, SearchScopeSet};
     use crate::uri::Scope;
 
     #[test]
@@ -353,6 +438,7 @@ mod tests {
         let options = SearchOptions {
             query: "oauth".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: Some("s-1".to_string()),
             session_hints: Vec::new(),
             budget: None,
@@ -361,6 +447,9 @@ mod tests {
             min_match_tokens: None,
             filter: None,
             request_type: "search".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         };
         let planned = plan_queries(&options);
         assert!(planned.iter().any(|item| {
@@ -370,11 +459,37 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn excluded_session_scope_drops_session_focus_query() {
+        let options = SearchOptions {
+            query: "oauth".to_string(),
+            target_uri: None,
+            target_uris: Vec::new(),
+            session: Some("s-1".to_string()),
+            session_hints: Vec::new(),
+            budget: None,
+            limit: 5,
+            score_threshold: None,
+            min_match_tokens: None,
+            filter: None,
+            request_type: "search".to_string(),
+            exclude_scopes: vec![Scope::Session],
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
+        };
+        let planned = plan_queries(&options);
+        assert!(!planned.is_empty());
+        assert!(planned.iter().all(|item| item.kind != "session_focus"
+            && !item.scopes.is_empty()
+            && !item.scopes.contains(&Scope::Session)));
+    }
+
     #[test]
     fn recent_chat_query_prefers_session_scope() {
         let options = SearchOptions {
             query: "recent chat summary".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: Some("s-2".to_string()),
             session_hints: Vec::new(),
             budget: None,
@@ -383,6 +498,9 @@ mod tests {
             min_match_tokens: None,
             filter: None,
             request_type: "search".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         };
         let planned = plan_queries(&options);
         assert!(planned.iter().any(|item| {
@@ -397,6 +515,7 @@ mod tests {
         let options = SearchOptions {
             query: "session skill onboarding".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: Some("s-3".to_string()),
             session_hints: Vec::new(),
             budget: None,
@@ -405,6 +524,9 @@ mod tests {
             min_match_tokens: None,
             filter: None,
             request_type: "search".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         };
         let planned = plan_queries(&options);
         assert!(planned.iter().any(|item| {
@@ -0,0 +1,29 @@
+use crate::models::{ContextHit, SearchOptions};
+
+/// Reorders retrieval hits; replaceable via `AxiomSync::with_reranker`.
+/// Must not add hits or change uris, since the list is truncated and rebucketed.
+pub trait Reranker: Send + Sync {
+    /// Name used in the `reranker:<name>` plan note, the query cache key and benchmark reports.
+    fn name(&self) -> &str;
+
+    fn rerank(&self, query: &SearchOptions, hits: Vec<ContextHit>) -> Vec<ContextHit>;
+
+    /// Extra query plan notes recorded with the rerank.
+    fn plan_notes(&self, _query: &SearchOptions) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Keeps the order; the default when `AXIOMSYNC_RERANKER` is unset or `off`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopReranker;
+
+impl Reranker for NoopReranker {
+    fn name(&self) -> &str {
+        "off"
+    }
+
+    fn rerank(&self, _query: &SearchOptions, hits: Vec<ContextHit>) -> Vec<ContextHit> {
+        hits
+    }
+}
@@ -26,6 +26,8 @@ pub(super) fn make_hit(
         snippet,
         matched_heading,
         score_components: score_components_from_scored(components),
+        relation_neighborhood: None,
+        freshness: None,
     }
 }
 
@@ -230,6 +232,8 @@ mod tests {
             snippet: None,
             matched_heading: None,
             score_components: crate::models::ScoreComponents::default(),
+            relation_neighborhood: None,
+            freshness: None,
         }
     }
 
@@ -1,9 +1,11 @@
-use chrono::Utc;
+use chrono::{Duration, Utc};
 
-use crate::index::InMemoryIndex;
-use crate::models::{IndexRecord, SearchBudget, SearchFilter, SearchOptions};
-use crate::retrieval::{DrrConfig, DrrEngine};
-use crate::uri::AxiomUri;
+use crate::index::{HybridWeights, InMemoryIndex};
+use crate::models::{
+    IndexRecord, SearchBudget, SearchFilter, SearchOptions, SearchScopeSet, TimeRange,
+};
+use crate::retrieval::{CancellationToken, DrrConfig, DrrEngine};
+use crate::uri::{AxiomUri, Scope};
 
 #[test]
 fn drr_returns_trace_and_hits() {
@@ -57,6 +59,7 @@ fn drr_returns_trace_and_hits() {
         &SearchOptions {
             query: "oauth".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: None,
@@ -65,6 +68,9 @@ fn drr_returns_trace_and_hits() {
             min_match_tokens: None,
             filter: None,
             request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -72,6 +78,49 @@ fn drr_returns_trace_and_hits() {
     assert!(result.trace.is_some());
 }
 
+#[test]
+fn drr_cancelled_token_skips_planned_queries() {
+    let mut index = InMemoryIndex::new();
+    index.upsert(IndexRecord {
+        id: "auth".to_string(),
+        uri: "axiom://resources/auth.md".to_string(),
+        parent_uri: Some("axiom://resources".to_string()),
+        is_leaf: true,
+        context_type: "resource".to_string(),
+        name: "auth.md".to_string(),
+        abstract_text: "oauth flow".to_string(),
+        content: "oauth authorization code".to_string(),
+        tags: vec![],
+        updated_at: Utc::now(),
+        depth: 1,
+    });
+    let options = SearchOptions {
+        query: "oauth".to_string(),
+        target_uri: None,
+        target_uris: Vec::new(),
+        session: None,
+        session_hints: Vec::new(),
+        budget: None,
+        limit: 5,
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        request_type: "search".to_string(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        offset: 0,
+    };
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+
+    let engine = DrrEngine::new(DrrConfig::default());
+    let result = engine.run_cancellable(&index, &options, Some(&cancel));
+    let trace = result.trace.expect("trace");
+    assert_eq!(trace.stop_reason, "cancelled");
+    assert_eq!(trace.metrics.explored_nodes, 0);
+    assert!(result.query_results.is_empty());
+}
+
 #[test]
 fn identifier_query_fast_path_prefers_filename_typo_match() {
     let mut index = InMemoryIndex::new();
@@ -124,6 +173,7 @@ fn identifier_query_fast_path_prefers_filename_typo_match() {
         &SearchOptions {
             query: "swfittesting".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: None,
@@ -132,6 +182,9 @@ fn identifier_query_fast_path_prefers_filename_typo_match() {
             min_match_tokens: None,
             filter: None,
             request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -196,6 +249,7 @@ fn identifier_query_fast_path_is_disabled_when_budget_nodes_is_explicit() {
         &SearchOptions {
             query: "oauthflow".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: Some(SearchBudget {
@@ -208,6 +262,9 @@ fn identifier_query_fast_path_is_disabled_when_budget_nodes_is_explicit() {
             min_match_tokens: None,
             filter: None,
             request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -240,6 +297,7 @@ fn identifier_query_fast_path_is_disabled_when_budget_ms_is_explicit() {
         &SearchOptions {
             query: "swfittesting".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: Some(SearchBudget {
@@ -252,6 +310,9 @@ fn identifier_query_fast_path_is_disabled_when_budget_ms_is_explicit() {
             min_match_tokens: None,
             filter: None,
             request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -337,6 +398,7 @@ fn drr_small_limit_preserves_global_exact_candidate() {
         &SearchOptions {
             query: "Actix-Web 개발 가이드라인 (Production-Grade)".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: Some(SearchBudget {
@@ -349,6 +411,9 @@ fn drr_small_limit_preserves_global_exact_candidate() {
             min_match_tokens: None,
             filter: None,
             request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -402,6 +467,7 @@ fn search_query_plan_includes_typed_queries() {
         &SearchOptions {
             query: "oauth".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: Some("s-1".to_string()),
             session_hints: vec!["use refresh token".to_string()],
             budget: None,
@@ -410,6 +476,9 @@ fn search_query_plan_includes_typed_queries() {
             min_match_tokens: None,
             filter: None,
             request_type: "search".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -459,6 +528,7 @@ fn search_query_plan_includes_session_om_query_and_visibility_note() {
         &SearchOptions {
             query: "oauth".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: Some("s-om".to_string()),
             session_hints: vec![
                 "recent user hint".to_string(),
@@ -470,6 +540,9 @@ fn search_query_plan_includes_session_om_query_and_visibility_note() {
             min_match_tokens: None,
             filter: None,
             request_type: "search".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -519,6 +592,7 @@ fn search_query_plan_normalizes_mixed_case_om_hint_prefix() {
         &SearchOptions {
             query: "oauth".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: Some("s-om-mixed".to_string()),
             session_hints: vec!["Om: compact long-term memory".to_string()],
             budget: None,
@@ -527,6 +601,9 @@ fn search_query_plan_normalizes_mixed_case_om_hint_prefix() {
             min_match_tokens: None,
             filter: None,
             request_type: "search".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -603,6 +680,7 @@ fn drr_applies_filter_in_child_and_fallback_paths() {
         &SearchOptions {
             query: "something-unseen".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: None,
@@ -612,8 +690,15 @@ fn drr_applies_filter_in_child_and_fallback_paths() {
             filter: Some(SearchFilter {
                 tags: vec!["auth".to_string()],
                 mime: None,
+                exclude_tags: Vec::new(),
+                any_tags: Vec::new(),
+                exclude_mime: None,
+                mtime: None,
             }),
             request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -632,6 +717,193 @@ fn drr_applies_filter_in_child_and_fallback_paths() {
     );
 }
 
+#[test]
+fn drr_applies_tag_negation_and_mtime_range_filters() {
+    let mut index = InMemoryIndex::new();
+    let now = Utc::now();
+    let leaf = |id: &str, tags: &[&str], updated_at| IndexRecord {
+        id: id.to_string(),
+        uri: format!("axiom://resources/docs/{id}.md"),
+        parent_uri: Some("axiom://resources/docs".to_string()),
+        is_leaf: true,
+        context_type: "resource".to_string(),
+        name: format!("{id}.md"),
+        abstract_text: format!("oauth {id}"),
+        content: "oauth refresh flow".to_string(),
+        tags: tags.iter().map(ToString::to_string).collect(),
+        updated_at,
+        depth: 2,
+    };
+    index.upsert(IndexRecord {
+        id: "root".to_string(),
+        uri: "axiom://resources".to_string(),
+        parent_uri: None,
+        is_leaf: false,
+        context_type: "resource".to_string(),
+        name: "resources".to_string(),
+        abstract_text: "resource root".to_string(),
+        content: String::new(),
+        tags: vec![],
+        updated_at: now,
+        depth: 0,
+    });
+    index.upsert(IndexRecord {
+        id: "docs".to_string(),
+        uri: "axiom://resources/docs".to_string(),
+        parent_uri: Some("axiom://resources".to_string()),
+        is_leaf: false,
+        context_type: "resource".to_string(),
+        name: "docs".to_string(),
+        abstract_text: "oauth docs".to_string(),
+        content: String::new(),
+        tags: vec![],
+        updated_at: now,
+        depth: 1,
+    });
+    index.upsert(leaf("fresh", &["auth"], now));
+    index.upsert(leaf("archived", &["auth", "Archived"], now));
+    index.upsert(leaf("stale", &["auth"], now - Duration::days(30)));
+
+    let engine = DrrEngine::new(DrrConfig::default());
+    let result = engine.run(
+        &index,
+        &SearchOptions {
+            query: "oauth refresh".to_string(),
+            target_uri: None,
+            target_uris: Vec::new(),
+            session: None,
+            session_hints: Vec::new(),
+            budget: None,
+            limit: 10,
+            score_threshold: None,
+            min_match_tokens: None,
+            filter: Some(SearchFilter {
+                exclude_tags: vec!["archived".to_string()],
+                mtime: Some(TimeRange {
+                    gte: Some(now - Duration::days(1)),
+                    ..TimeRange::default()
+                }),
+                ..SearchFilter::default()
+            }),
+            request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
+        },
+    );
+
+    let uris = result
+        .query_results
+        .iter()
+        .map(|hit| hit.uri.as_str())
+        .collect::<Vec<_>>();
+    assert!(uris.contains(&"axiom://resources/docs/fresh.md"));
+    assert!(!uris.contains(&"axiom://resources/docs/archived.md"));
+    assert!(!uris.contains(&"axiom://resources/docs/stale.md"));
+}
+
+#[test]
+fn drr_applies_tag_any_and_inclusive_exclusive_mtime_boundaries() {
+    let mut index = InMemoryIndex::new();
+    let boundary = Utc::now() - Duration::days(7);
+    let leaf = |id: &str, tags: &[&str], updated_at| IndexRecord {
+        id: id.to_string(),
+        uri: format!("axiom://resources/docs/{id}.md"),
+        parent_uri: Some("axiom://resources/docs".to_string()),
+        is_leaf: true,
+        context_type: "resource".to_string(),
+        name: format!("{id}.md"),
+        abstract_text: format!("oauth {id}"),
+        content: "oauth refresh flow".to_string(),
+        tags: tags.iter().map(ToString::to_string).collect(),
+        updated_at,
+        depth: 2,
+    };
+    for (id, depth, parent) in [
+        ("root", 0, None),
+        ("docs", 1, Some("axiom://resources".to_string())),
+    ] {
+        index.upsert(IndexRecord {
+            id: id.to_string(),
+            uri: if depth == 0 {
+                "axiom://resources".to_string()
+            } else {
+                "axiom://resources/docs".to_string()
+            },
+            parent_uri: parent,
+            is_leaf: false,
+            context_type: "resource".to_string(),
+            name: id.to_string(),
+            abstract_text: "oauth docs".to_string(),
+            content: String::new(),
+            tags: vec![],
+            updated_at: boundary,
+            depth,
+        });
+    }
+    index.upsert(leaf("on-boundary", &["auth"], boundary));
+    index.upsert(leaf("after", &["Billing"], boundary + Duration::hours(1)));
+    index.upsert(leaf("before", &["auth"], boundary - Duration::hours(1)));
+    index.upsert(leaf("untagged", &["misc"], boundary + Duration::hours(1)));
+
+    let engine = DrrEngine::new(DrrConfig::default());
+    let run = |range: TimeRange| {
+        let result = engine.run(
+            &index,
+            &SearchOptions {
+                query: "oauth refresh".to_string(),
+                target_uri: None,
+                target_uris: Vec::new(),
+                session: None,
+                session_hints: Vec::new(),
+                budget: None,
+                limit: 10,
+                score_threshold: None,
+                min_match_tokens: None,
+                filter: Some(SearchFilter {
+                    any_tags: vec!["auth".to_string(), "billing".to_string()],
+                    mtime: Some(range),
+                    ..SearchFilter::default()
+                }),
+                request_type: "find".to_string(),
+                exclude_scopes: Vec::new(),
+                scope_set: SearchScopeSet::AllContent,
+                offset: 0,
+            },
+        );
+        let mut uris = result
+            .query_results
+            .iter()
+            .filter(|hit| hit.uri.ends_with(".md"))
+            .map(|hit| {
+                hit.uri
+                    .trim_start_matches("axiom://resources/docs/")
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        uris.sort();
+        uris
+    };
+
+    let inclusive = run(TimeRange {
+        gte: Some(boundary),
+        ..TimeRange::default()
+    });
+    assert_eq!(inclusive, vec!["after.md", "on-boundary.md"]);
+
+    let exclusive = run(TimeRange {
+        gt: Some(boundary),
+        ..TimeRange::default()
+    });
+    assert_eq!(exclusive, vec!["after.md"]);
+
+    let upper = run(TimeRange {
+        lt: Some(boundary),
+        ..TimeRange::default()
+    });
+    assert_eq!(upper, vec!["before.md"]);
+}
+
 #[test]
 fn drr_enforces_min_match_tokens_for_selected_hits() {
     let mut index = InMemoryIndex::new();
@@ -695,6 +967,7 @@ fn drr_enforces_min_match_tokens_for_selected_hits() {
         &SearchOptions {
             query: "oauth authorization".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: None,
@@ -703,6 +976,9 @@ fn drr_enforces_min_match_tokens_for_selected_hits() {
             min_match_tokens: Some(2),
             filter: None,
             request_type: "search".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -770,6 +1046,7 @@ fn drr_enforces_score_threshold_for_expansion_selected_hits() {
         &SearchOptions {
             query: "query that does not match".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: None,
@@ -778,6 +1055,9 @@ fn drr_enforces_score_threshold_for_expansion_selected_hits() {
             min_match_tokens: None,
             filter: None,
             request_type: "search".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -861,6 +1141,7 @@ fn drr_respects_target_uri_boundary_during_expansion_and_fallback() {
             target_uri: Some(
                 AxiomUri::parse("axiom://resources/mv-src").expect("target uri parse"),
             ),
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: None,
@@ -869,6 +1150,9 @@ fn drr_respects_target_uri_boundary_during_expansion_and_fallback() {
             min_match_tokens: None,
             filter: None,
             request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -930,6 +1214,7 @@ fn drr_respects_max_nodes_budget() {
         &SearchOptions {
             query: "oauth".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: Some(SearchBudget {
@@ -942,6 +1227,9 @@ fn drr_respects_max_nodes_budget() {
             min_match_tokens: None,
             filter: None,
             request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -999,6 +1287,7 @@ fn drr_respects_max_depth_budget_including_fallback() {
         &SearchOptions {
             query: "unknown-query".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: Some(SearchBudget {
@@ -1011,6 +1300,9 @@ fn drr_respects_max_depth_budget_including_fallback() {
             min_match_tokens: None,
             filter: None,
             request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -1045,6 +1337,7 @@ fn drr_respects_max_ms_budget() {
         &SearchOptions {
             query: "oauth".to_string(),
             target_uri: None,
+            target_uris: Vec::new(),
             session: None,
             session_hints: Vec::new(),
             budget: Some(SearchBudget {
@@ -1057,6 +1350,9 @@ fn drr_respects_max_ms_budget() {
             min_match_tokens: None,
             filter: None,
             request_type: "find".to_string(),
+            exclude_scopes: Vec::new(),
+            scope_set: SearchScopeSet::AllContent,
+            offset: 0,
         },
     );
 
@@ -1064,3 +1360,277 @@ fn drr_respects_max_ms_budget() {
     assert!(trace.stop_reason.contains("budget_ms"));
     assert_eq!(trace.metrics.explored_nodes, 0);
 }
+
+#[test]
+fn drr_max_ms_deadline_returns_partial_result_with_budget_exhausted_note() {
+    let mut index = InMemoryIndex::new();
+    let dir_record = |uri: String, parent: Option<String>, depth: usize| IndexRecord {
+        id: uri.clone(),
+        name: uri.rsplit('/').next().unwrap_or_default().to_string(),
+        uri,
+        parent_uri: parent,
+        is_leaf: false,
+        context_type: "resource".to_string(),
+        abstract_text: "oauth notes directory".to_string(),
+        content: String::new(),
+        tags: vec![],
+        updated_at: Utc::now(),
+        depth,
+    };
+    index.upsert(dir_record("axiom://resources".to_string(), None, 0));
+    for dir in 0..40 {
+        let dir_uri = format!("axiom://resources/dir-{dir:02}");
+        index.upsert(dir_record(
+            dir_uri.clone(),
+            Some("axiom://resources".to_string()),
+            1,
+        ));
+        for file in 0..100 {
+            let uri = format!("{dir_uri}/note-{file:03}.md");
+            index.upsert(IndexRecord {
+                id: uri.clone(),
+                name: format!("note-{file:03}.md"),
+                uri,
+                parent_uri: Some(dir_uri.clone()),
+                is_leaf: true,
+                context_type: "resource".to_string(),
+                abstract_text: "oauth token refresh".to_string(),
+                content: format!("oauth token refresh flow {dir} {file}"),
+                tags: vec![],
+                updated_at: Utc::now(),
+                depth: 2,
+            });
+        }
+    }
+    let options = |budget: Option<SearchBudget>| SearchOptions {
+        query: "oauth token refresh".to_string(),
+        target_uri: None,
+        target_uris: Vec::new(),
+        session: None,
+        session_hints: Vec::new(),
+        budget,
+        limit: 5,
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        request_type: "find".to_string(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        offset: 0,
+    };
+    let engine = DrrEngine::new(DrrConfig::default());
+
+    let unbounded = engine.run(&index, &options(None));
+    assert!(
+        !unbounded
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "budget_exhausted")
+    );
+
+    let started = std::time::Instant::now();
+    let result = engine.run(
+        &index,
+        &options(Some(SearchBudget {
+            max_ms: Some(1),
+            max_nodes: None,
+            max_depth: None,
+        })),
+    );
+    let elapsed = started.elapsed();
+
+    assert!(
+        result
+            .query_plan
+            .notes
+            .iter()
+            .any(|note| note == "budget_exhausted")
+    );
+    let trace = result.trace.expect("trace");
+    assert!(trace.stop_reason.contains("budget_ms"));
+    assert!(trace.metrics.explored_nodes < 41);
+    assert!(result.query_results.len() <= 5);
+    assert_eq!(
+        result.memories.len() + result.resources.len() + result.skills.len(),
+        result.query_results.len()
+    );
+    assert!(
+        elapsed < std::time::Duration::from_secs(5),
+        "deadline-bounded run took {elapsed:?}"
+    );
+}
+
+#[test]
+fn drr_hybrid_weights_flip_lexical_and_semantic_hit_order() {
+    let mut index = InMemoryIndex::new();
+    let now = Utc::now();
+    index.upsert(IndexRecord {
+        id: "root".to_string(),
+        uri: "axiom://resources".to_string(),
+        parent_uri: None,
+        is_leaf: false,
+        context_type: "resource".to_string(),
+        name: "resources".to_string(),
+        abstract_text: String::new(),
+        content: String::new(),
+        tags: vec![],
+        updated_at: now,
+        depth: 0,
+    });
+    // The first doc has the exact token buried in noise; the second shares no tokens
+    // but is closer in embedding space.
+    for (name, content) in [
+        (
+            "lexical.md",
+            "authentication tokens are listed here among many unrelated words like disk iops \
+             latency storage network bandwidth cache queue worker pool shard replica",
+        ),
+        ("semantic.md", "authenticate token"),
+    ] {
+        index.upsert(IndexRecord {
+            id: name.to_string(),
+            uri: format!("axiom://resources/{name}"),
+            parent_uri: Some("axiom://resources".to_string()),
+            is_leaf: true,
+            context_type: "resource".to_string(),
+            name: name.to_string(),
+            abstract_text: String::new(),
+            content: content.to_string(),
+            tags: vec![],
+            updated_at: now,
+            depth: 1,
+        });
+    }
+    let options = SearchOptions {
+        query: "authentication tokens".to_string(),
+        target_uri: Some(AxiomUri::parse("axiom://resources").expect("target")),
+        target_uris: Vec::new(),
+        session: None,
+        session_hints: Vec::new(),
+        budget: None,
+        limit: 5,
+        score_threshold: None,
+        min_match_tokens: None,
+        filter: None,
+        request_type: "find".to_string(),
+        exclude_scopes: Vec::new(),
+        scope_set: SearchScopeSet::AllContent,
+        offset: 0,
+    };
+    let leaf_order = |lexical: f32, semantic: f32| {
+        let engine = DrrEngine::new(DrrConfig {
+            hybrid_weights: HybridWeights::new(lexical, semantic).expect("weights"),
+            ..DrrConfig::default()
+        });
+        engine
+            .run(&index, &options)
+            .query_results
+            .into_iter()
+            .map(|hit| hit.uri)
+            .filter(|uri| uri.ends_with(".md"))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        leaf_order(0.9, 0.1),
+        vec![
+            "axiom://resources/lexical.md".to_string(),
+            "axiom://resources/semantic.md".to_string(),
+        ]
+    );
+    assert_eq!(
+        leaf_order(0.1, 0.9),
+        vec![
+            "axiom://resources/semantic.md".to_string(),
+            "axiom://resources/lexical.md".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn drr_scope_set_all_content_never_returns_queue_hits() {
+    let mut index = InMemoryIndex::new();
+    for (id, uri, parent_uri, context_type) in [
+        (
+            "doc",
+            "axiom://resources/docs/outbox.md",
+            "axiom://resources/docs",
+            "resource",
+        ),
+        (
+            "queue",
+            "axiom://queue/outbox/event-1.json",
+            "axiom://queue/outbox",
+            "queue",
+        ),
+    ] {
+        index.upsert(IndexRecord {
+            id: id.to_string(),
+            uri: uri.to_string(),
+            parent_uri: Some(parent_uri.to_string()),
+            is_leaf: true,
+            context_type: context_type.to_string(),
+            name: uri.rsplit('/').next().unwrap_or_default().to_string(),
+            abstract_text: "outbox replay event".to_string(),
+            content: "outbox replay event payload".to_string(),
+            tags: vec![],
+            updated_at: Utc::now(),
+            depth: 3,
+        });
+    }
+
+    let engine = DrrEngine::new(DrrConfig::default());
+    let run = |scope_set: SearchScopeSet| {
+        engine.run(
+            &index,
+            &SearchOptions {
+                query: "outbox replay event".to_string(),
+                target_uri: None,
+                target_uris: Vec::new(),
+                session: None,
+                session_hints: Vec::new(),
+                budget: None,
+                limit: 10,
+                score_threshold: None,
+                min_match_tokens: None,
+                filter: None,
+                request_type: "find".to_string(),
+                exclude_scopes: Vec::new(),
+                scope_set,
+                offset: 0,
+            },
+        )
+    };
+
+    let content = run(SearchScopeSet::AllContent);
+    assert!(
+        content
+            .query_results
+            .iter()
+            .any(|hit| hit.uri == "axiom://resources/docs/outbox.md")
+    );
+    assert!(
+        content
+            .query_results
+            .iter()
+            .all(|hit| !hit.uri.starts_with("axiom://queue/"))
+    );
+
+    let internal = run(SearchScopeSet::AllIncludingInternal);
+    assert!(
+        internal
+            .query_results
+            .iter()
+            .any(|hit| hit.uri == "axiom://queue/outbox/event-1.json")
+    );
+
+    let queue_only = run(SearchScopeSet::Only(vec![Scope::Queue]));
+    assert!(!queue_only.query_results.is_empty());
+    assert!(
+        queue_only
+            .query_results
+            .iter()
+            .all(|hit| hit.uri.starts_with("axiom://queue/"))
+    );
+}
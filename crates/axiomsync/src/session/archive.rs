@@ -1,9 +1,11 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::error::Result;
+use crate::error::{AxiomError, Result};
 use crate::jsonl::{jsonl_all_lines_invalid, parse_jsonl_tolerant};
 use crate::models::Message;
+use crate::tier_documents::{read_abstract, write_tiers};
 
 use super::Session;
 
@@ -25,6 +27,64 @@ pub(super) fn summarize_messages(messages: &[Message]) -> String {
     )
 }
 
+impl Session {
+    /// Joins archive summaries for `from..=to` in order; fails if any archive is missing.
+    pub fn summarize_archives(&self, from: u32, to: u32) -> Result<String> {
+        self.summarize_archives_with_persist(from, to, false)
+    }
+
+    /// With `persist`, overwrites the session root tier so the next context read sees it.
+    pub fn summarize_archives_with_persist(
+        &self,
+        from: u32,
+        to: u32,
+        persist: bool,
+    ) -> Result<String> {
+        if from == 0 || from > to {
+            return Err(AxiomError::Validation(format!(
+                "invalid archive range: {from}..={to}"
+            )));
+        }
+        let archives = list_archive_paths(self)?
+            .into_iter()
+            .filter(|(archive_num, _)| (from..=to).contains(archive_num))
+            .collect::<BTreeMap<_, _>>();
+        if let Some(missing) = (from..=to).find(|archive_num| !archives.contains_key(archive_num)) {
+            return Err(AxiomError::NotFound(format!(
+                "session {} archive {missing}",
+                self.session_id
+            )));
+        }
+
+        let session_uri = self.session_uri()?;
+        let mut lines = Vec::with_capacity(archives.len());
+        for (archive_num, archive_path) in &archives {
+            let archive_uri = session_uri.join(&format!("history/archive_{archive_num:03}"))?;
+            // Older archives without tiers are summarized from their messages.
+            let summary = match read_abstract(&self.fs, &archive_uri) {
+                Ok(summary) => summary,
+                Err(AxiomError::NotFound(_)) => {
+                    summarize_messages(&read_messages_jsonl(&archive_path.join("messages.jsonl"))?)
+                }
+                Err(err) => return Err(err),
+            };
+            lines.push(format!("- Archive {archive_num}: {}", summary.trim()));
+        }
+        let combined = format!("Archives {from}-{to} summary:\n{}", lines.join("\n"));
+
+        if persist {
+            write_tiers(
+                &self.fs,
+                &session_uri,
+                &format!("Session {} archives {from}-{to}", self.session_id),
+                &format!("# Session Overview\n\n{combined}"),
+                true,
+            )?;
+        }
+        Ok(combined)
+    }
+}
+
 #[derive(Debug)]
 struct ArchiveMatch {
     archive_num: u32,
@@ -142,7 +202,7 @@ pub(super) fn next_archive_number(session: &Session) -> Result<u32> {
     Ok(max_num + 1)
 }
 
-fn list_archive_paths(session: &Session) -> Result<Vec<(u32, PathBuf)>> {
+pub(super) fn list_archive_paths(session: &Session) -> Result<Vec<(u32, PathBuf)>> {
     let history_uri = session.session_uri()?.join("history")?;
     let history_path = session.fs.resolve_uri(&history_uri);
     if !history_path.exists() {
@@ -162,11 +222,11 @@ fn list_archive_paths(session: &Session) -> Result<Vec<(u32, PathBuf)>> {
             out.push((value, entry.path()));
         }
     }
-    out.sort_by(|a, b| b.0.cmp(&a.0));
+    out.sort_by_key(|entry| std::cmp::Reverse(entry.0));
     Ok(out)
 }
 
-fn read_messages_jsonl(path: &Path) -> Result<Vec<Message>> {
+pub(super) fn read_messages_jsonl(path: &Path) -> Result<Vec<Message>> {
     if !path.exists() {
         return Ok(Vec::new());
     }
@@ -0,0 +1,252 @@
+use std::fs;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::error::{AxiomError, Result};
+use crate::models::{
+    Message, SESSION_BUNDLE_VERSION, SessionBundle, SessionBundleArchive, SessionBundleOm,
+    SessionBundleOmThread, SessionView,
+};
+use crate::om::{OmScope, build_scope_key};
+use crate::tier_documents::write_tiers;
+
+use super::Session;
+use super::archive::{list_archive_paths, read_messages_jsonl, summarize_messages};
+
+impl Session {
+    /// Exports the session tree and its session-scope OM state as one bundle.
+    pub fn export_bundle(&self) -> Result<SessionBundle> {
+        let session_uri = self.session_uri()?;
+        if !self.fs.exists(&session_uri) {
+            return Err(AxiomError::NotFound(session_uri.to_string()));
+        }
+
+        let archives = self.read_archives()?;
+        let relations_path = self.relations_path()?;
+        let relations = if relations_path.exists() {
+            serde_json::from_str(&fs::read_to_string(relations_path)?)?
+        } else {
+            serde_json::Value::Array(Vec::new())
+        };
+
+        Ok(SessionBundle {
+            version: SESSION_BUNDLE_VERSION,
+            session_id: self.session_id.clone(),
+            exported_at: Utc::now(),
+            meta: self.read_meta()?,
+            messages: self.read_messages()?,
+            archives,
+            relations,
+            om: self.export_om_bundle()?,
+        })
+    }
+
+    /// Reads session meta and messages; metadata and attachments are returned as stored.
+    pub fn show(&self) -> Result<SessionView> {
+        let session_uri = self.session_uri()?;
+        if !self.fs.exists(&session_uri) {
+            return Err(AxiomError::NotFound(session_uri.to_string()));
+        }
+        Ok(SessionView {
+            session_id: self.session_id.clone(),
+            meta: self.read_meta()?,
+            messages: self.read_messages()?,
+            archives: self.read_archives()?,
+        })
+    }
+
+    fn read_archives(&self) -> Result<Vec<SessionBundleArchive>> {
+        let mut archives = list_archive_paths(self)?
+            .into_iter()
+            .map(|(number, path)| {
+                Ok(SessionBundleArchive {
+                    number,
+                    messages: read_messages_jsonl(&path.join("messages.jsonl"))?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        archives.sort_by_key(|archive| archive.number);
+        Ok(archives)
+    }
+
+    fn export_om_bundle(&self) -> Result<Option<SessionBundleOm>> {
+        let scope_key = session_scope_key(&self.session_id)?;
+        let Some(record) = self.state.get_om_record_by_scope_key(&scope_key)? else {
+            return Ok(None);
+        };
+        let observation_chunks = self.state.list_om_observation_chunks(&record.id)?;
+        let threads = self
+            .state
+            .list_om_thread_states(&scope_key)?
+            .into_iter()
+            .map(|thread| SessionBundleOmThread {
+                thread_id: thread.thread_id,
+                last_observed_at: thread.last_observed_at,
+                current_task: thread.current_task,
+                suggested_response: thread.suggested_response,
+            })
+            .collect();
+        Ok(Some(SessionBundleOm {
+            record,
+            observation_chunks,
+            threads,
+        }))
+    }
+
+    /// Writes the bundle under this session id; cleanup and indexing are left to the caller.
+    pub(crate) fn restore_bundle(&self, bundle: &SessionBundle) -> Result<()> {
+        let session_uri = self.session_uri()?;
+        self.fs.create_dir_all(&session_uri, true)?;
+
+        fs::write(self.messages_path()?, messages_to_jsonl(&bundle.messages)?)?;
+
+        let mut meta = bundle.meta.clone();
+        meta.session_id.clone_from(&self.session_id);
+        fs::write(self.meta_path()?, serde_json::to_string_pretty(&meta)?)?;
+
+        let source_prefix = format!("axiom://session/{}", bundle.session_id);
+        let relations = match &bundle.relations {
+            serde_json::Value::Null => serde_json::Value::Array(Vec::new()),
+            value => rewrite_session_uris(value.clone(), &source_prefix, &session_uri.to_string()),
+        };
+        fs::write(
+            self.relations_path()?,
+            serde_json::to_string_pretty(&relations)?,
+        )?;
+
+        for archive in &bundle.archives {
+            let archive_uri =
+                session_uri.join(&format!("history/archive_{:03}", archive.number))?;
+            self.fs.create_dir_all(&archive_uri, true)?;
+            self.fs.write(
+                &archive_uri.join("messages.jsonl")?,
+                &messages_to_jsonl(&archive.messages)?,
+                true,
+            )?;
+            let summary = summarize_messages(&archive.messages);
+            write_tiers(
+                &self.fs,
+                &archive_uri,
+                &summary,
+                &format!("# Archive {}\n\n{summary}", archive.number),
+                true,
+            )?;
+        }
+
+        if let Some(latest) = bundle.archives.iter().map(|archive| archive.number).max() {
+            write_tiers(
+                &self.fs,
+                &session_uri,
+                &format!("Session {} latest commit", self.session_id),
+                &format!("# Session Overview\n\nLatest archive: {latest}"),
+                true,
+            )?;
+        }
+        self.load()?;
+
+        if let Some(om) = &bundle.om {
+            self.restore_om_bundle(om, &bundle.session_id)?;
+        }
+        Ok(())
+    }
+
+    fn restore_om_bundle(&self, om: &SessionBundleOm, source_session_id: &str) -> Result<()> {
+        let scope_key = session_scope_key(&self.session_id)?;
+        let rename_thread = |thread_id: &str| {
+            if thread_id == source_session_id {
+                self.session_id.clone()
+            } else {
+                thread_id.to_string()
+            }
+        };
+
+        let mut record = om.record.clone();
+        record.id = Uuid::new_v4().to_string();
+        record.scope = OmScope::Session;
+        record.scope_key.clone_from(&scope_key);
+        record.session_id = Some(self.session_id.clone());
+        record.thread_id = record.thread_id.as_deref().map(rename_thread);
+        record.last_applied_outbox_event_id = None;
+        self.state.upsert_om_record(&record)?;
+
+        // On a scope_key conflict the existing row id wins, so re-read the stored record.
+        let stored = self
+            .state
+            .get_om_record_by_scope_key(&scope_key)?
+            .ok_or_else(|| {
+                AxiomError::Internal(format!("om record missing after import: {scope_key}"))
+            })?;
+        self.state
+            .clear_om_observation_chunks_through_seq(&stored.id, u32::MAX)?;
+        for chunk in &om.observation_chunks {
+            let mut chunk = chunk.clone();
+            chunk.id = Uuid::new_v4().to_string();
+            chunk.record_id.clone_from(&stored.id);
+            self.state.append_om_observation_chunk(&chunk)?;
+        }
+        for thread in &om.threads {
+            self.state.upsert_om_thread_state(
+                &scope_key,
+                &rename_thread(&thread.thread_id),
+                thread.last_observed_at,
+                thread.current_task.as_deref(),
+                thread.suggested_response.as_deref(),
+            )?;
+        }
+        self.state
+            .upsert_om_scope_session(&scope_key, &self.session_id)?;
+
+        self.index
+            .write()
+            .map_err(|_| AxiomError::lock_poisoned("index"))?
+            .upsert_om_record(stored);
+        Ok(())
+    }
+}
+
+fn session_scope_key(session_id: &str) -> Result<String> {
+    build_scope_key(OmScope::Session, Some(session_id), None, None)
+        .map_err(|err| AxiomError::Validation(err.to_string()))
+}
+
+fn messages_to_jsonl(messages: &[Message]) -> Result<String> {
+    let mut out = String::new();
+    for message in messages {
+        out.push_str(&serde_json::to_string(message)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn rewrite_session_uris(
+    value: serde_json::Value,
+    source_prefix: &str,
+    target_prefix: &str,
+) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(text) => {
+            let rewritten = text.strip_prefix(source_prefix).and_then(|rest| {
+                (rest.is_empty() || rest.starts_with('/')).then(|| format!("{target_prefix}{rest}"))
+            });
+            serde_json::Value::String(rewritten.unwrap_or(text))
+        }
+        serde_json::Value::Array(items) => serde_json::Value::Array(
+            items
+                .into_iter()
+                .map(|item| rewrite_session_uris(item, source_prefix, target_prefix))
+                .collect(),
+        ),
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .map(|(key, item)| {
+                    (
+                        key,
+                        rewrite_session_uris(item, source_prefix, target_prefix),
+                    )
+                })
+                .collect(),
+        ),
+        other => other,
+    }
+}
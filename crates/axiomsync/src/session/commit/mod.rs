@@ -1,11 +1,12 @@
+use std::collections::HashMap;
 use std::fs;
 
 use chrono::Utc;
 
 use crate::error::Result;
 use crate::models::{
-    CommitMode, CommitResult, CommitStats, MemoryPromotionFact, MemoryPromotionRequest,
-    MemoryPromotionResult,
+    CommitMode, CommitResult, CommitStats, MemoryCategory, MemoryPromotionFact,
+    MemoryPromotionRequest, MemoryPromotionResult, Message,
 };
 use crate::tier_documents::write_tiers;
 use crate::uri::AxiomUri;
@@ -19,6 +20,7 @@ use self::fallbacks::{
     record_memory_dedup_fallback as record_memory_dedup_fallback_event,
     record_memory_extractor_fallback as record_memory_extractor_fallback_event,
 };
+use self::read_path::count_session_memories_by_category;
 use self::types::ResolvedMemoryCandidate;
 use self::write_path::{
     persist_memory as persist_memory_write_path,
@@ -47,20 +49,39 @@ impl Session {
         self.commit_with_mode(CommitMode::ArchiveAndExtract)
     }
 
+    /// Promoted memory counts per category for this session; empty categories are omitted.
+    pub fn memory_category_counts(&self) -> Result<HashMap<MemoryCategory, usize>> {
+        count_session_memories_by_category(self)
+    }
+
     pub fn commit_with_mode(&self, mode: CommitMode) -> Result<CommitResult> {
-        let active_messages = self.read_messages()?;
-        let total_turns = active_messages.len();
         let meta = self.read_meta()?;
-
-        if total_turns == 0 {
+        let (active_messages, stale_prefix) = skip_already_archived(
+            self.read_messages()?,
+            meta.last_archived_message_id.as_deref(),
+        );
+        let total_turns = active_messages.len();
+        let keep_last = match mode {
+            CommitMode::ExtractKeepRecent { keep_last } => keep_last,
+            CommitMode::ArchiveAndExtract | CommitMode::ArchiveOnly => 0,
+        };
+        let (archived_messages, retained_messages) =
+            active_messages.split_at(total_turns.saturating_sub(keep_last));
+
+        if archived_messages.is_empty() {
+            if stale_prefix {
+                fs::write(self.messages_path()?, messages_to_jsonl(retained_messages)?)?;
+            }
             return Ok(CommitResult {
                 session_id: self.session_id.clone(),
                 status: "committed".to_string(),
                 memories_extracted: 0,
                 active_count_updated: 0,
                 archived: false,
+                messages_archived: 0,
+                messages_retained: retained_messages.len(),
                 stats: CommitStats {
-                    total_turns: 0,
+                    total_turns,
                     contexts_used: meta.context_usage.contexts_used,
                     skills_used: meta.context_usage.skills_used,
                     memories_extracted: 0,
@@ -76,11 +97,20 @@ impl Session {
 
         let archive_messages_uri = archive_uri.join("messages.jsonl")?;
         let messages_path = self.messages_path()?;
-        let raw_messages = fs::read_to_string(&messages_path)?;
-        self.fs.write(&archive_messages_uri, &raw_messages, true)?;
-        fs::write(messages_path, "")?;
+        let archived_raw = if retained_messages.is_empty() && !stale_prefix {
+            fs::read_to_string(&messages_path)?
+        } else {
+            messages_to_jsonl(archived_messages)?
+        };
+        self.fs.write(&archive_messages_uri, &archived_raw, true)?;
+        // Recorded right after the archive write so an interrupted commit does not re-archive.
+        let last_archived_message_id = archived_messages.last().map(|message| message.id.clone());
+        self.touch_meta(|meta| {
+            meta.last_archived_message_id = last_archived_message_id;
+        })?;
+        fs::write(messages_path, messages_to_jsonl(retained_messages)?)?;
 
-        let session_summary = summarize_messages(&active_messages);
+        let session_summary = summarize_messages(archived_messages);
         write_tiers(
             &self.fs,
             &archive_uri,
@@ -100,9 +130,9 @@ impl Session {
 
         let mut candidates_len = 0usize;
         let mut persisted_uris = Vec::new();
-        if matches!(mode, CommitMode::ArchiveAndExtract) {
+        if !matches!(mode, CommitMode::ArchiveOnly) {
             let extracted =
-                extract_memories_for_commit(&active_messages, &self.config.memory.extractor)?;
+                extract_memories_for_commit(archived_messages, &self.config.memory.extractor)?;
             if let Some(error) = extracted.llm_error.as_deref() {
                 record_memory_extractor_fallback_event(self, &extracted.mode_requested, error);
             }
@@ -126,6 +156,8 @@ impl Session {
             memories_extracted: candidates_len,
             active_count_updated: persisted_uris.len(),
             archived: true,
+            messages_archived: archived_messages.len(),
+            messages_retained: retained_messages.len(),
             stats: CommitStats {
                 total_turns,
                 contexts_used: meta.context_usage.contexts_used,
@@ -181,3 +213,25 @@ impl Session {
         reindex_memory_uris_write_path(self, uris)
     }
 }
+
+/// Skips active messages up to `last_archived_message_id`; the flag reports whether any were skipped.
+fn skip_already_archived(
+    messages: Vec<Message>,
+    last_archived_message_id: Option<&str>,
+) -> (Vec<Message>, bool) {
+    let Some(position) = last_archived_message_id
+        .and_then(|id| messages.iter().position(|message| message.id == id))
+    else {
+        return (messages, false);
+    };
+    (messages.into_iter().skip(position + 1).collect(), true)
+}
+
+fn messages_to_jsonl(messages: &[Message]) -> Result<String> {
+    let mut out = String::new();
+    for message in messages {
+        out.push_str(&serde_json::to_string(message)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
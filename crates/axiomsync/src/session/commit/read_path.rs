@@ -1,5 +1,8 @@
+use std::collections::HashMap;
+
 use crate::embedding::embed_text;
 use crate::error::Result;
+use crate::models::MemoryCategory;
 use crate::uri::AxiomUri;
 
 use super::Session;
@@ -73,6 +76,32 @@ pub(super) fn list_existing_memory_facts(
     Ok(out)
 }
 
+/// Counts promoted memories sourced from this session, by category.
+pub(super) fn count_session_memories_by_category(
+    session: &Session,
+) -> Result<HashMap<MemoryCategory, usize>> {
+    let mut counts = HashMap::<MemoryCategory, usize>::new();
+    for category in MemoryCategory::ALL {
+        let mut count = 0usize;
+        for uri in list_memory_document_uris(session, category.as_str())? {
+            let content = session.fs.read(&uri)?;
+            count += parse_memory_entries(&content)
+                .iter()
+                .filter(|entry| {
+                    entry
+                        .sources
+                        .iter()
+                        .any(|source| source.session_id == session.session_id)
+                })
+                .count();
+        }
+        if count > 0 {
+            counts.insert(category, count);
+        }
+    }
+    Ok(counts)
+}
+
 fn list_memory_document_uris(session: &Session, category: &str) -> Result<Vec<AxiomUri>> {
     let (scope, base_path, single_file) = memory_category_path(category)?;
     let base_uri = AxiomUri::root(scope).join(base_path)?;
@@ -5,9 +5,11 @@ use chrono::Utc;
 use uuid::Uuid;
 
 use crate::error::Result;
-use crate::models::{ContextUsage, Message, SessionMeta};
+use crate::llm_io::estimate_text_tokens;
+use crate::models::{AddMessageOutcome, CommitResult, ContextUsage, Message, SessionMeta};
 use crate::om::plan_process_output_result;
 use crate::tier_documents::{abstract_path, overview_path, write_tiers};
+use crate::uri::AxiomUri;
 
 use super::Session;
 
@@ -29,6 +31,7 @@ impl Session {
                 created_at: now,
                 updated_at: now,
                 context_usage: ContextUsage::default(),
+                last_archived_message_id: None,
             };
             fs::write(meta_path, serde_json::to_string_pretty(&meta)?)?;
         }
@@ -54,15 +57,65 @@ impl Session {
     }
 
     pub fn add_message(&self, role: &str, text: impl Into<String>) -> Result<Message> {
+        Ok(self.add_message_with_auto_commit(role, text)?.message)
+    }
+
+    /// JSON `null` metadata is not stored; `attachments` must be axiom URIs and are deduped in order.
+    pub fn add_message_with_metadata(
+        &self,
+        role: &str,
+        text: impl Into<String>,
+        metadata: Option<serde_json::Value>,
+        attachments: Vec<String>,
+    ) -> Result<Message> {
+        let mut normalized = Vec::<String>::with_capacity(attachments.len());
+        for raw in attachments {
+            let uri = AxiomUri::parse(raw.trim())?.to_string();
+            if !normalized.contains(&uri) {
+                normalized.push(uri);
+            }
+        }
         let message = Message {
-            id: Uuid::new_v4().to_string(),
-            role: role.to_string(),
-            text: text.into(),
-            created_at: Utc::now(),
+            metadata: metadata.filter(|value| !value.is_null()),
+            attachments: normalized,
+            ..new_message(role, text.into())
         };
+        Ok(self.append_message(message)?.message)
+    }
+
+    /// Adds a message and commits when the auto-commit threshold is reached.
+    pub fn add_message_with_auto_commit(
+        &self,
+        role: &str,
+        text: impl Into<String>,
+    ) -> Result<AddMessageOutcome> {
+        self.append_message(new_message(role, text.into()))
+    }
+
+    fn append_message(&self, message: Message) -> Result<AddMessageOutcome> {
         let _ = self.persist_output_stage_messages(std::slice::from_ref(&message), false)?;
+        let auto_commit = self.maybe_auto_commit()?;
 
-        Ok(message)
+        Ok(AddMessageOutcome {
+            message,
+            auto_commit,
+        })
+    }
+
+    fn maybe_auto_commit(&self) -> Result<Option<CommitResult>> {
+        let policy = self.config.session.auto_commit;
+        if !policy.is_enabled() {
+            return Ok(None);
+        }
+        let active_messages = self.read_messages()?;
+        let token_count = active_messages
+            .iter()
+            .map(|message| estimate_text_tokens(&message.text) as usize)
+            .sum::<usize>();
+        if !policy.should_commit(active_messages.len(), token_count) {
+            return Ok(None);
+        }
+        self.commit().map(Some)
     }
 
     pub(super) fn persist_output_stage_messages(
@@ -126,3 +179,14 @@ impl Session {
         Ok(())
     }
 }
+
+fn new_message(role: &str, text: String) -> Message {
+    Message {
+        id: Uuid::new_v4().to_string(),
+        role: role.to_string(),
+        text,
+        created_at: Utc::now(),
+        metadata: None,
+        attachments: Vec::new(),
+    }
+}
@@ -196,11 +196,18 @@ fn llm_memories(
     let message_payload = selected_messages
         .iter()
         .map(|message| {
-            serde_json::json!({
+            let mut item = serde_json::json!({
                 "id": message.id,
                 "role": message.role,
                 "text": message.text,
-            })
+            });
+            if let Some(metadata) = message.metadata.as_ref() {
+                item["metadata"] = metadata.clone();
+            }
+            if !message.attachments.is_empty() {
+                item["attachments"] = serde_json::json!(message.attachments);
+            }
+            item
         })
         .collect::<Vec<_>>();
 
@@ -208,7 +215,7 @@ fn llm_memories(
 Return JSON only. Use categories: profile, preferences, entities, events, cases, patterns. \
 Output schema: {\"memories\":[{\"category\":\"...\",\"text\":\"...\",\"source_message_ids\":[\"...\"],\"confidence\":0.0}]}";
     let user_prompt = format!(
-        "Conversation messages as JSON:\n{}\n\nRules:\n- keep memory text concise and factual\n- no duplicate memories\n- source_message_ids must reference provided ids",
+        "Conversation messages as JSON:\n{}\n\nRules:\n- keep memory text concise and factual\n- no duplicate memories\n- source_message_ids must reference provided ids\n- attachments are axiom URIs the turn referenced; mention them when a memory depends on them",
         serde_json::to_string(&message_payload)?
     );
 
@@ -468,10 +475,8 @@ fn select_messages_for_llm(
         .rev()
         .take(max_messages)
         .map(|message| Message {
-            id: message.id.clone(),
-            role: message.role.clone(),
             text: message.text.chars().take(max_chars).collect::<String>(),
-            created_at: message.created_at,
+            ..message.clone()
         })
         .collect::<Vec<_>>()
         .into_iter()
@@ -491,6 +496,8 @@ mod tests {
             role: "user".to_string(),
             text: text.to_string(),
             created_at: Utc::now(),
+            metadata: None,
+            attachments: Vec::new(),
         }
     }
 
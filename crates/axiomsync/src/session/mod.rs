@@ -8,6 +8,7 @@ use crate::om::OmScope;
 use crate::state::SqliteStateStore;
 
 mod archive;
+mod bundle;
 mod commit;
 mod context;
 mod indexing;
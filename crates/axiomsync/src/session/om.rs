@@ -17,11 +17,11 @@ use crate::om::{
     build_multi_thread_observer_user_prompt, build_observer_prompt_contract_v2,
     build_observer_system_prompt, build_observer_user_prompt, build_other_conversation_blocks,
     combine_observations_for_buffering, filter_observer_candidates_by_last_observed_at,
-    format_observer_messages_for_prompt, om_observer_error, om_status_kind,
-    parse_memory_section_xml_accuracy_first, parse_multi_thread_observer_output_accuracy_first,
-    resolve_canonical_thread_id, resolve_observer_model_enabled,
-    select_observed_message_candidates, select_observer_message_candidates,
-    split_pending_and_other_conversation_candidates,
+    format_observer_messages_for_prompt, observer_candidate_text, om_observer_error,
+    om_status_kind, parse_memory_section_xml_accuracy_first,
+    parse_multi_thread_observer_output_accuracy_first, resolve_canonical_thread_id,
+    resolve_observer_model_enabled, select_observed_message_candidates,
+    select_observer_message_candidates, split_pending_and_other_conversation_candidates,
 };
 use crate::om_bridge::{
     OmObserveBufferRequestedV1, OmReflectBufferRequestedV1, OmReflectRequestedV1,
@@ -332,9 +332,9 @@ impl Session {
             .read_messages()?
             .into_iter()
             .map(|message| OmObserverMessageCandidate {
+                text: observer_candidate_text(&message.text, &message.attachments),
                 id: message.id,
                 role: message.role,
-                text: message.text,
                 created_at: message.created_at,
                 source_thread_id: Some(self.session_id.clone()),
                 source_session_id: Some(self.session_id.clone()),
@@ -362,9 +362,9 @@ impl Session {
                 if let Ok(peer_messages) = peer.read_messages() {
                     candidates.extend(peer_messages.into_iter().map(|message| {
                         OmObserverMessageCandidate {
+                            text: observer_candidate_text(&message.text, &message.attachments),
                             id: message.id,
                             role: message.role,
-                            text: message.text,
                             created_at: message.created_at,
                             source_thread_id: Some(peer_session_id.clone()),
                             source_session_id: Some(peer_session_id.clone()),
@@ -5,6 +5,7 @@ use chrono::{Duration, Utc};
 use rusqlite::{Connection, params};
 use tempfile::tempdir;
 
+use crate::config::{AppConfig, SessionAutoCommitPolicy};
 use crate::fs::LocalContextFs;
 use crate::index::InMemoryIndex;
 use crate::models::{
@@ -29,6 +30,8 @@ fn fixture_categories(role: &str, text: &str) -> HashSet<String> {
         role: role.to_string(),
         text: text.to_string(),
         created_at: Utc::now(),
+        metadata: None,
+        attachments: Vec::new(),
     }])
     .into_iter()
     .map(|candidate| candidate.category)
@@ -97,6 +100,312 @@ fn commit_mode_archive_only_skips_auto_extraction() {
     );
 }
 
+#[test]
+fn commit_keep_recent_archives_only_older_messages_and_preserves_om_activation() {
+    let temp = tempdir().expect("tempdir");
+    let fs = LocalContextFs::new(temp.path());
+    fs.initialize().expect("init failed");
+    let state = SqliteStateStore::open(temp.path().join("state.db")).expect("state open failed");
+    let index = Arc::new(RwLock::new(InMemoryIndex::new()));
+
+    let session = Session::new("s-keep-recent", fs.clone(), state, index);
+    session.load().expect("load failed");
+    let ids = (0..5)
+        .map(|idx| {
+            session
+                .add_message("user", format!("rolling message {idx}"))
+                .expect("append failed")
+                .id
+        })
+        .collect::<Vec<_>>();
+
+    let scope_key =
+        build_scope_key(OmScope::Session, Some("s-keep-recent"), None, None).expect("key");
+    let mut om_record = session
+        .state
+        .get_om_record_by_scope_key(&scope_key)
+        .expect("om lookup")
+        .unwrap_or_else(|| {
+            let now = Utc::now();
+            OmRecord {
+                id: "om-keep-recent".to_string(),
+                scope: OmScope::Session,
+                scope_key: scope_key.clone(),
+                session_id: Some("s-keep-recent".to_string()),
+                thread_id: None,
+                resource_id: None,
+                generation_count: 1,
+                last_applied_outbox_event_id: None,
+                origin_type: OmOriginType::Initial,
+                active_observations: "rolling observation".to_string(),
+                observation_token_count: 0,
+                pending_message_tokens: 0,
+                last_observed_at: Some(now),
+                current_task: None,
+                suggested_response: None,
+                last_activated_message_ids: Vec::new(),
+                observer_trigger_count_total: 0,
+                reflector_trigger_count_total: 0,
+                is_observing: false,
+                is_reflecting: false,
+                is_buffering_observation: false,
+                is_buffering_reflection: false,
+                last_buffered_at_tokens: 0,
+                last_buffered_at_time: None,
+                buffered_reflection: None,
+                buffered_reflection_tokens: None,
+                buffered_reflection_input_tokens: None,
+                created_at: now,
+                updated_at: now,
+            }
+        });
+    om_record.last_activated_message_ids = ids[3..].to_vec();
+    session
+        .state
+        .upsert_om_record(&om_record)
+        .expect("seed om record");
+
+    let result = session
+        .commit_with_mode(CommitMode::ExtractKeepRecent { keep_last: 2 })
+        .expect("keep-recent commit");
+    assert!(result.archived);
+    assert_eq!(result.messages_archived, 3);
+    assert_eq!(result.messages_retained, 2);
+    assert_eq!(result.stats.total_turns, 5);
+
+    let retained = session.read_messages().expect("read active");
+    assert_eq!(
+        retained.iter().map(|m| m.id.as_str()).collect::<Vec<_>>(),
+        vec![ids[3].as_str(), ids[4].as_str()]
+    );
+    assert_eq!(
+        session.read_meta().expect("meta").last_archived_message_id,
+        Some(ids[2].clone())
+    );
+    let archive_uri =
+        AxiomUri::parse("axiom://session/s-keep-recent/history/archive_001/messages.jsonl")
+            .expect("archive uri");
+    let archived_raw = fs.read(&archive_uri).expect("read archive");
+    assert_eq!(archived_raw.lines().count(), 3);
+    assert!(!archived_raw.contains(&ids[3]));
+    let om_after = session
+        .state
+        .get_om_record_by_scope_key(&scope_key)
+        .expect("om lookup")
+        .expect("om record");
+    assert_eq!(om_after.last_activated_message_ids, ids[3..].to_vec());
+
+    // Messages left active by an interrupted commit must not be archived twice.
+    let messages_path = session.messages_path().expect("messages path");
+    let stale_line = archived_raw.lines().last().expect("last archived line");
+    let active_raw = std::fs::read_to_string(&messages_path).expect("read active raw");
+    std::fs::write(&messages_path, format!("{stale_line}\n{active_raw}")).expect("write stale");
+
+    let noop = session
+        .commit_with_mode(CommitMode::ExtractKeepRecent { keep_last: 2 })
+        .expect("noop commit");
+    assert!(!noop.archived);
+    assert_eq!(noop.messages_archived, 0);
+    assert_eq!(noop.messages_retained, 2);
+    assert_eq!(session.read_messages().expect("read active").len(), 2);
+    let next_archive =
+        AxiomUri::parse("axiom://session/s-keep-recent/history/archive_002").expect("archive uri");
+    assert!(!fs.exists(&next_archive));
+}
+
+#[test]
+fn add_message_with_metadata_persists_attachments_and_reads_legacy_lines() {
+    let temp = tempdir().expect("tempdir");
+    let fs = LocalContextFs::new(temp.path());
+    fs.initialize().expect("init failed");
+    let state = SqliteStateStore::open(temp.path().join("state.db")).expect("state open failed");
+    let index = Arc::new(RwLock::new(InMemoryIndex::new()));
+
+    let session = Session::new("s-message-metadata", fs, state, index);
+    session.load().expect("load failed");
+    let legacy = serde_json::json!({
+        "id": "legacy-1",
+        "role": "user",
+        "text": "legacy line",
+        "created_at": "2026-01-01T00:00:00Z",
+    });
+    std::fs::write(
+        session.messages_path().expect("messages path"),
+        format!("{legacy}\n"),
+    )
+    .expect("write legacy line");
+
+    let added = session
+        .add_message_with_metadata(
+            "assistant",
+            "checked the auth doc",
+            Some(serde_json::json!({"tool": "read", "call_id": "c1"})),
+            vec![
+                "axiom://resources/docs/auth.md".to_string(),
+                " axiom://resources/docs/auth.md ".to_string(),
+            ],
+        )
+        .expect("add with metadata");
+    assert_eq!(added.attachments, vec!["axiom://resources/docs/auth.md"]);
+
+    let messages = session.read_messages().expect("read messages");
+    assert_eq!(messages.len(), 2);
+    assert!(messages[0].metadata.is_none());
+    assert!(messages[0].attachments.is_empty());
+    assert_eq!(
+        messages[1].metadata,
+        Some(serde_json::json!({"tool": "read", "call_id": "c1"}))
+    );
+    assert_eq!(messages[1].attachments, added.attachments);
+
+    let err = session
+        .add_message_with_metadata("user", "bad", None, vec!["not a uri".to_string()])
+        .expect_err("invalid attachment");
+    assert!(matches!(err, AxiomError::InvalidUri(_)));
+}
+
+#[test]
+fn message_metadata_round_trips_through_commit_without_changing_extraction() {
+    let temp = tempdir().expect("tempdir");
+    let fs = LocalContextFs::new(temp.path());
+    fs.initialize().expect("init failed");
+    let state = SqliteStateStore::open(temp.path().join("state.db")).expect("state open failed");
+    let index = Arc::new(RwLock::new(InMemoryIndex::new()));
+
+    let metadata = serde_json::json!({
+        "source_tool": "editor",
+        "confidence": 0.7,
+        "unknown": {"nested": [1, 2]},
+    });
+    let mut results = Vec::new();
+    for (session_id, metadata) in [
+        ("s-meta-plain", None),
+        ("s-meta-tagged", Some(metadata.clone())),
+    ] {
+        let session = Session::new(session_id, fs.clone(), state.clone(), index.clone());
+        session.load().expect("load failed");
+        session
+            .add_message_with_metadata("user", "I prefer concise Rust code.", metadata, Vec::new())
+            .expect("append failed");
+        results.push((session.commit().expect("commit failed"), session));
+    }
+
+    let (plain, _) = &results[0];
+    let (tagged, tagged_session) = &results[1];
+    assert!(plain.memories_extracted >= 1);
+    assert_eq!(tagged.memories_extracted, plain.memories_extracted);
+
+    let view = tagged_session.show().expect("show");
+    assert!(view.messages.is_empty());
+    assert_eq!(view.archives.len(), 1);
+    assert_eq!(view.archives[0].messages[0].metadata, Some(metadata));
+}
+
+#[test]
+fn summarize_archives_combines_each_archive_in_range_and_persists_root_tier() {
+    let temp = tempdir().expect("tempdir");
+    let fs = LocalContextFs::new(temp.path());
+    fs.initialize().expect("init failed");
+    let state = SqliteStateStore::open(temp.path().join("state.db")).expect("state open failed");
+    let index = Arc::new(RwLock::new(InMemoryIndex::new()));
+
+    let session = Session::new("s-archive-summary", fs.clone(), state, index);
+    session.load().expect("load failed");
+    for topic in ["alpha rollout", "beta migration", "gamma cleanup"] {
+        session
+            .add_message("user", format!("Plan the {topic} today"))
+            .expect("append user");
+        session
+            .add_message("assistant", format!("Drafted {topic} checklist"))
+            .expect("append assistant");
+        session
+            .commit_with_mode(CommitMode::ArchiveOnly)
+            .expect("commit");
+    }
+
+    let combined = session.summarize_archives(1, 2).expect("summarize");
+    assert!(combined.contains("alpha rollout"));
+    assert!(combined.contains("beta migration"));
+    assert!(!combined.contains("gamma cleanup"));
+
+    let err = session
+        .summarize_archives(2, 4)
+        .expect_err("missing archive");
+    assert!(matches!(err, AxiomError::NotFound(_)));
+    let err = session
+        .summarize_archives(3, 1)
+        .expect_err("reversed range");
+    assert!(matches!(err, AxiomError::Validation(_)));
+
+    session
+        .summarize_archives_with_persist(1, 3, true)
+        .expect("persist");
+    let session_uri = AxiomUri::parse("axiom://session/s-archive-summary").expect("uri");
+    let overview = crate::tier_documents::read_overview(&fs, &session_uri).expect("overview");
+    assert!(overview.contains("gamma cleanup"));
+    assert!(overview.contains("alpha rollout"));
+}
+
+#[test]
+fn add_message_auto_commits_once_when_message_threshold_is_crossed() {
+    let temp = tempdir().expect("tempdir");
+    let fs = LocalContextFs::new(temp.path());
+    fs.initialize().expect("init failed");
+    let state = SqliteStateStore::open(temp.path().join("state.db")).expect("state open failed");
+    let index = Arc::new(RwLock::new(InMemoryIndex::new()));
+    let mut config = AppConfig::default();
+    config.session.auto_commit = SessionAutoCommitPolicy {
+        max_messages: Some(3),
+        max_tokens: None,
+    };
+
+    let session =
+        Session::new("s-auto-commit", fs.clone(), state, index).with_config(Arc::new(config));
+    session.load().expect("load failed");
+
+    let mut auto_commits = Vec::new();
+    for idx in 0..4 {
+        let outcome = session
+            .add_message_with_auto_commit("user", format!("auto commit message {idx}"))
+            .expect("append failed");
+        if let Some(commit) = outcome.auto_commit {
+            auto_commits.push((idx, commit));
+        }
+    }
+
+    assert_eq!(auto_commits.len(), 1);
+    let (idx, commit) = &auto_commits[0];
+    assert_eq!(*idx, 2);
+    assert!(commit.archived);
+    assert_eq!(commit.stats.total_turns, 3);
+
+    let archive_uri =
+        AxiomUri::parse("axiom://session/s-auto-commit/history/archive_001").expect("archive uri");
+    assert!(fs.exists(&archive_uri));
+    let remaining = session.read_messages().expect("read active");
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].text, "auto commit message 3");
+}
+
+#[test]
+fn add_message_does_not_auto_commit_by_default() {
+    let temp = tempdir().expect("tempdir");
+    let fs = LocalContextFs::new(temp.path());
+    fs.initialize().expect("init failed");
+    let state = SqliteStateStore::open(temp.path().join("state.db")).expect("state open failed");
+    let index = Arc::new(RwLock::new(InMemoryIndex::new()));
+
+    let session = Session::new("s-no-auto-commit", fs, state, index);
+    session.load().expect("load failed");
+    for idx in 0..8 {
+        let outcome = session
+            .add_message_with_auto_commit("user", format!("message {idx}"))
+            .expect("append failed");
+        assert!(outcome.auto_commit.is_none());
+    }
+    assert_eq!(session.read_messages().expect("read active").len(), 8);
+}
+
 fn promotion_request(
     session_id: &str,
     checkpoint_id: &str,
@@ -1043,6 +1352,8 @@ fn output_stage_contract_skips_save_when_read_only() {
         role: "user".to_string(),
         text: "readonly should skip save".to_string(),
         created_at: Utc::now(),
+        metadata: None,
+        attachments: Vec::new(),
     };
     let saved = session
         .persist_output_stage_messages(std::slice::from_ref(&message), true)
@@ -1083,6 +1394,8 @@ fn output_stage_contract_saves_when_writable_and_non_empty() {
         role: "assistant".to_string(),
         text: "writable save".to_string(),
         created_at: Utc::now(),
+        metadata: None,
+        attachments: Vec::new(),
     };
     let saved = session
         .persist_output_stage_messages(std::slice::from_ref(&message), false)
@@ -1472,6 +1785,62 @@ fn commit_extracts_six_categories_and_reindexes_immediately() {
     );
 }
 
+#[test]
+fn memory_category_counts_sum_to_memories_extracted_after_commit() {
+    let temp = tempdir().expect("tempdir");
+    let fs = LocalContextFs::new(temp.path());
+    fs.initialize().expect("init failed");
+    let state = SqliteStateStore::open(temp.path().join("state.db")).expect("state open failed");
+    let index = Arc::new(RwLock::new(InMemoryIndex::new()));
+
+    let session = Session::new(
+        "s-category-counts",
+        fs.clone(),
+        state.clone(),
+        index.clone(),
+    );
+    session.load().expect("load failed");
+    assert!(
+        session
+            .memory_category_counts()
+            .expect("counts before commit")
+            .is_empty()
+    );
+    for (role, text) in [
+        ("user", "My name is Axient"),
+        ("user", "I prefer concise Rust code"),
+        ("user", "This project repository is AxiomSync"),
+        ("assistant", "Today we deployed release v1.2"),
+        (
+            "assistant",
+            "Root cause identified and fixed with workaround",
+        ),
+        ("assistant", "Always run this checklist before release"),
+    ] {
+        session.add_message(role, text).expect("append");
+    }
+
+    let result = session.commit().expect("commit failed");
+    let counts = session.memory_category_counts().expect("counts");
+    assert_eq!(counts.values().sum::<usize>(), result.memories_extracted);
+    for category in MemoryCategory::ALL {
+        assert!(
+            counts.get(&category).copied().unwrap_or(0) >= 1,
+            "missing category {}",
+            category.as_str()
+        );
+    }
+
+    let other = Session::new("s-category-other", fs, state, index);
+    other.load().expect("load other");
+    assert!(
+        other
+            .memory_category_counts()
+            .expect("other counts")
+            .is_empty()
+    );
+}
+
 #[test]
 fn commit_merges_same_memory_with_provenance_across_sessions() {
     let temp = tempdir().expect("tempdir");
@@ -1516,12 +1885,16 @@ fn extract_memories_uses_stable_key_for_same_text() {
             role: "user".to_string(),
             text: "I prefer concise Rust code".to_string(),
             created_at: Utc::now(),
+            metadata: None,
+            attachments: Vec::new(),
         },
         Message {
             id: "msg-2".to_string(),
             role: "user".to_string(),
             text: "I prefer concise Rust code".to_string(),
             created_at: Utc::now(),
+            metadata: None,
+            attachments: Vec::new(),
         },
     ];
 
@@ -23,6 +23,9 @@ const MIGRATION_SCHEMA_SQL: &str = r"
         status TEXT NOT NULL
     );
 
+    CREATE INDEX IF NOT EXISTS idx_index_state_content_hash
+    ON index_state(content_hash);
+
     CREATE TABLE IF NOT EXISTS outbox (
         id INTEGER PRIMARY KEY AUTOINCREMENT,
         event_type TEXT NOT NULL,
@@ -32,7 +35,8 @@ const MIGRATION_SCHEMA_SQL: &str = r"
         attempt_count INTEGER NOT NULL DEFAULT 0,
         status TEXT NOT NULL CHECK(status IN ('new', 'processing', 'done', 'dead_letter')),
         next_attempt_at TEXT NOT NULL,
-        lane TEXT NOT NULL
+        lane TEXT NOT NULL,
+        priority INTEGER NOT NULL DEFAULT 0
     );
 
     CREATE TABLE IF NOT EXISTS queue_checkpoint (
@@ -89,6 +93,41 @@ const MIGRATION_SCHEMA_SQL: &str = r"
         FOREIGN KEY (doc_id) REFERENCES search_docs(id) ON DELETE CASCADE
     );
 
+    CREATE TABLE IF NOT EXISTS search_doc_vectors (
+        uri TEXT PRIMARY KEY,
+        vector_version TEXT NOT NULL,
+        text_hash TEXT NOT NULL,
+        vector BLOB NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS search_profiles (
+        name TEXT PRIMARY KEY,
+        profile_json TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS search_cursors (
+        snapshot_id TEXT PRIMARY KEY,
+        fingerprint TEXT NOT NULL,
+        snapshot_json TEXT NOT NULL,
+        expires_at INTEGER NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS resource_tags (
+        root_uri TEXT PRIMARY KEY,
+        tags_json TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS document_tags (
+        uri TEXT PRIMARY KEY,
+        added_json TEXT NOT NULL,
+        removed_json TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+
     CREATE VIRTUAL TABLE IF NOT EXISTS search_docs_fts
     USING fts5(
         uri UNINDEXED,
@@ -258,6 +297,8 @@ const MIGRATION_SCHEMA_SQL: &str = r"
     CREATE INDEX IF NOT EXISTS idx_search_docs_restore_order ON search_docs(depth ASC, uri ASC);
     CREATE INDEX IF NOT EXISTS idx_search_docs_mime ON search_docs(mime);
     CREATE INDEX IF NOT EXISTS idx_search_doc_tags_tag ON search_doc_tags(tag);
+    CREATE INDEX IF NOT EXISTS idx_search_doc_vectors_text_hash
+    ON search_doc_vectors(text_hash, vector_version);
     CREATE INDEX IF NOT EXISTS idx_om_records_updated_at ON om_records(updated_at);
     CREATE INDEX IF NOT EXISTS idx_om_records_scope_session ON om_records(scope, session_id);
     CREATE INDEX IF NOT EXISTS idx_om_chunks_record_created_at ON om_observation_chunks(record_id, created_at);
@@ -347,6 +388,7 @@ impl SqliteStateStore {
                 "unsupported reconcile_runs schema: status is missing; reset workspace state database",
             )],
         )?;
+        ensure_outbox_priority_column(&conn)?;
         normalize_status_column(&conn, "outbox", "status")?;
         validate_status_domain(
             &conn,
@@ -367,6 +409,10 @@ impl SqliteStateStore {
             "CREATE INDEX IF NOT EXISTS idx_outbox_status_next_attempt_id ON outbox(status, next_attempt_at, id)",
             [],
         )?;
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_outbox_status_priority_id ON outbox(status, priority DESC, id)",
+            [],
+        )?;
         ensure_search_docs_fts_bootstrapped(&conn, needs_fts_rebuild)?;
         drop(conn);
         Ok(())
@@ -722,6 +768,17 @@ fn has_column(conn: &Connection, table: &str, column: &str) -> Result<bool> {
     Ok(false)
 }
 
+/// Adds the column to older outboxes; existing events get priority 0.
+fn ensure_outbox_priority_column(conn: &Connection) -> Result<()> {
+    if !has_column(conn, "outbox", "priority")? {
+        conn.execute(
+            "ALTER TABLE outbox ADD COLUMN priority INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
 fn ensure_required_column(
     conn: &Connection,
     table: &str,
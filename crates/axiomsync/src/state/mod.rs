@@ -6,9 +6,10 @@ use std::time::Duration;
 
 use chrono::Utc;
 use rusqlite::{Connection, OptionalExtension, params};
+use serde::{Deserialize, Serialize};
 
 use crate::error::{AxiomError, Result};
-use crate::models::TraceIndexEntry;
+use crate::models::{StateCompactReport, TraceIndexEntry};
 
 mod migration;
 mod om;
@@ -19,13 +20,18 @@ mod search;
 
 pub(crate) use om::{OmActiveEntry, OmContinuationHints};
 pub(crate) use promotion_checkpoint::PromotionCheckpointPhase;
+pub(crate) use search::{DocumentTagOverrides, PersistedSearchVector, SearchCursorRecord};
+
+#[cfg(feature = "sqlcipher")]
+const ENV_DB_KEY: &str = "AXIOMSYNC_DB_KEY";
 
 #[derive(Clone)]
 pub struct SqliteStateStore {
     conn: Arc<Mutex<Connection>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum OmReflectionApplyOutcome {
     Applied,
     StaleGeneration,
@@ -72,14 +78,44 @@ impl SqliteStateStore {
         Ok(value)
     }
 
+    /// With the `sqlcipher` feature, a set `AXIOMSYNC_DB_KEY` opens the database encrypted.
     pub fn open(path: impl AsRef<Path>) -> Result<Self> {
-        let path = path.as_ref();
+        #[cfg(feature = "sqlcipher")]
+        if let Some(key) = db_key_from_env() {
+            return Self::open_with_key(path, &key);
+        }
+        Self::open_connection(path.as_ref(), |_| Ok(()))
+    }
+
+    /// Opens with SQLCipher `PRAGMA key`; a wrong key or plaintext file is `PermissionDenied`.
+    #[cfg(feature = "sqlcipher")]
+    pub fn open_with_key(path: impl AsRef<Path>, key: &str) -> Result<Self> {
+        Self::open_connection(path.as_ref(), |conn| {
+            conn.pragma_update(None, "key", key)?;
+            conn.query_row("SELECT count(*) FROM sqlite_master", [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .map_err(|_| {
+                AxiomError::PermissionDenied(
+                    "state database key does not match or the database is not encrypted"
+                        .to_string(),
+                )
+            })?;
+            Ok(())
+        })
+    }
+
+    fn open_connection(
+        path: &Path,
+        unlock: impl FnOnce(&Connection) -> Result<()>,
+    ) -> Result<Self> {
         if let Some(parent) = path.parent()
             && !parent.as_os_str().is_empty()
         {
             std::fs::create_dir_all(parent)?;
         }
         let conn = Connection::open(path)?;
+        unlock(&conn)?;
         conn.busy_timeout(Duration::from_millis(5_000))?;
         let store = Self {
             conn: Arc::new(Mutex::new(conn)),
@@ -119,6 +155,17 @@ impl SqliteStateStore {
         })
     }
 
+    /// Tracked quota usage for a scope; `None` if never recorded.
+    pub fn get_scope_usage_bytes(&self, scope: &str) -> Result<Option<u64>> {
+        Ok(self
+            .get_system_value(&scope_usage_key(scope))?
+            .and_then(|raw| raw.parse::<u64>().ok()))
+    }
+
+    pub fn set_scope_usage_bytes(&self, scope: &str, bytes: u64) -> Result<()> {
+        self.set_system_value(&scope_usage_key(scope), &bytes.to_string())
+    }
+
     pub fn upsert_index_state(
         &self,
         uri: &str,
@@ -170,6 +217,20 @@ impl SqliteStateStore {
         })
     }
 
+    pub fn list_index_state_uris_by_hash(&self, content_hash: &str) -> Result<Vec<String>> {
+        self.with_conn(|conn| {
+            let mut stmt = conn
+                .prepare("SELECT uri FROM index_state WHERE content_hash = ?1 ORDER BY uri ASC")?;
+            let rows = stmt.query_map(params![content_hash], |row| row.get::<_, String>(0))?;
+
+            let mut out = Vec::new();
+            for row in rows {
+                out.push(row?);
+            }
+            Ok(out)
+        })
+    }
+
     pub fn list_index_state_uris(&self) -> Result<Vec<String>> {
         self.with_conn(|conn| {
             let mut stmt = conn.prepare("SELECT uri FROM index_state ORDER BY uri ASC")?;
@@ -304,6 +365,104 @@ impl SqliteStateStore {
             Ok(out)
         })
     }
+
+    /// Trace index rows created before `cutoff_rfc3339`, oldest first.
+    pub fn list_trace_index_before(&self, cutoff_rfc3339: &str) -> Result<Vec<TraceIndexEntry>> {
+        self.with_conn(|conn| {
+            let mut stmt = conn.prepare(
+                r"
+                SELECT trace_id, uri, request_type, query, target_uri, created_at
+                FROM trace_index
+                WHERE created_at < ?1
+                ORDER BY created_at ASC, trace_id ASC
+                ",
+            )?;
+            let rows = stmt.query_map(params![cutoff_rfc3339], |row| {
+                Ok(TraceIndexEntry {
+                    trace_id: row.get(0)?,
+                    uri: row.get(1)?,
+                    request_type: row.get(2)?,
+                    query: row.get(3)?,
+                    target_uri: row.get(4)?,
+                    created_at: row.get(5)?,
+                })
+            })?;
+
+            let mut out = Vec::new();
+            for row in rows {
+                out.push(row?);
+            }
+            Ok(out)
+        })
+    }
+
+    pub fn remove_trace_index(&self, trace_ids: &[String]) -> Result<usize> {
+        self.with_tx(|tx| {
+            let mut removed = 0usize;
+            for trace_id in trace_ids {
+                removed += tx.execute(
+                    "DELETE FROM trace_index WHERE trace_id = ?1",
+                    params![trace_id],
+                )?;
+            }
+            Ok(removed)
+        })
+    }
+
+    /// `(total bytes, free page bytes)`; free pages are what `VACUUM` reclaims.
+    pub fn database_size_bytes(&self) -> Result<(u64, u64)> {
+        self.with_conn(|conn| {
+            let page_size = conn.query_row("PRAGMA page_size", [], |row| row.get::<_, i64>(0))?;
+            let page_count = conn.query_row("PRAGMA page_count", [], |row| row.get::<_, i64>(0))?;
+            let freelist_count =
+                conn.query_row("PRAGMA freelist_count", [], |row| row.get::<_, i64>(0))?;
+            let bytes = |pages: i64| u64::try_from(pages.saturating_mul(page_size)).unwrap_or(0);
+            Ok((bytes(page_count), bytes(freelist_count)))
+        })
+    }
+
+    /// `VACUUM` then `wal_checkpoint(TRUNCATE)`; sizes include the `-wal` file.
+    /// Sent on the raw connection because `VACUUM` cannot run inside a transaction.
+    pub fn compact(&self) -> Result<StateCompactReport> {
+        self.with_conn(|conn| {
+            let before_bytes = sqlite_file_bytes(conn);
+            conn.execute_batch("VACUUM")?;
+            conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+            let after_bytes = sqlite_file_bytes(conn);
+            Ok(StateCompactReport {
+                before_bytes,
+                after_bytes,
+                reclaimed_bytes: before_bytes.saturating_sub(after_bytes),
+            })
+        })
+    }
+
+    /// Writes a consistent copy to `path` with the online backup API, overwriting it.
+    /// Other writes wait while the copy holds the connection.
+    pub fn backup_to(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        self.with_conn(|conn| {
+            conn.backup(rusqlite::DatabaseName::Main, path, None)?;
+            Ok(())
+        })?;
+        #[cfg(unix)]
+        harden_sqlite_permissions(path)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlcipher")]
+fn db_key_from_env() -> Option<String> {
+    crate::config::read_non_empty_env(ENV_DB_KEY)
+}
+
+fn scope_usage_key(scope: &str) -> String {
+    format!("quota_usage_bytes:{scope}")
 }
 
 fn escape_sql_like_pattern(raw: &str) -> String {
@@ -312,6 +471,17 @@ fn escape_sql_like_pattern(raw: &str) -> String {
         .replace('_', "\\_")
 }
 
+fn sqlite_file_bytes(conn: &Connection) -> u64 {
+    let Some(path) = conn.path().filter(|path| !path.is_empty()) else {
+        return 0;
+    };
+    [String::new(), "-wal".to_string()]
+        .iter()
+        .filter_map(|suffix| std::fs::metadata(format!("{path}{suffix}")).ok())
+        .map(|meta| meta.len())
+        .sum()
+}
+
 fn usize_to_i64_saturating(value: usize) -> i64 {
     i64::try_from(value).unwrap_or(i64::MAX)
 }
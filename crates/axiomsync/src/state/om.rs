@@ -667,6 +667,92 @@ impl SqliteStateStore {
         })
     }
 
+    /// Counts a manual reflection in `reflector_trigger_count_total`; false if the generation moved.
+    pub fn increment_om_reflector_trigger_with_cas(
+        &self,
+        scope_key: &str,
+        expected_generation: u32,
+    ) -> Result<bool> {
+        self.with_conn(|conn| {
+            let affected = conn.execute(
+                r"
+                UPDATE om_records
+                SET reflector_trigger_count_total = reflector_trigger_count_total + 1,
+                    updated_at = ?3
+                WHERE scope_key = ?1
+                  AND generation_count = ?2
+                ",
+                params![
+                    scope_key,
+                    i64::from(expected_generation),
+                    Utc::now().to_rfc3339(),
+                ],
+            )?;
+            Ok(affected > 0)
+        })
+    }
+
+    /// Clears the observation, its entries, the reflection buffer and counters, and bumps
+    /// `generation_count` so in-flight reflections hit `StaleGeneration`.
+    /// Buffered chunks are cleared unless `keep_buffered`; false if there is no record.
+    pub fn reset_om_record(&self, scope_key: &str, keep_buffered: bool) -> Result<bool> {
+        self.with_tx(|tx| {
+            let record_id = tx
+                .query_row(
+                    "SELECT id FROM om_records WHERE scope_key = ?1",
+                    params![scope_key],
+                    |row| row.get::<_, String>(0),
+                )
+                .optional()?;
+            let Some(record_id) = record_id else {
+                return Ok(false);
+            };
+
+            tx.execute(
+                r"
+                UPDATE om_records
+                SET generation_count = generation_count + 1,
+                    active_observations = '',
+                    observation_token_count = 0,
+                    last_activated_message_ids_json = '[]',
+                    observer_trigger_count_total = 0,
+                    reflector_trigger_count_total = 0,
+                    is_observing = 0,
+                    is_reflecting = 0,
+                    is_buffering_reflection = 0,
+                    buffered_reflection = NULL,
+                    buffered_reflection_tokens = NULL,
+                    buffered_reflection_input_tokens = NULL,
+                    reflected_observation_line_count = NULL,
+                    updated_at = ?2
+                WHERE id = ?1
+                ",
+                params![record_id, Utc::now().to_rfc3339()],
+            )?;
+            tx.execute(
+                "DELETE FROM om_entries WHERE scope_key = ?1",
+                params![scope_key],
+            )?;
+            if !keep_buffered {
+                tx.execute(
+                    "DELETE FROM om_observation_chunks WHERE record_id = ?1",
+                    params![record_id],
+                )?;
+                tx.execute(
+                    r"
+                    UPDATE om_records
+                    SET is_buffering_observation = 0,
+                        last_buffered_at_tokens = 0,
+                        last_buffered_at_time = NULL
+                    WHERE id = ?1
+                    ",
+                    params![record_id],
+                )?;
+            }
+            Ok(true)
+        })
+    }
+
     pub fn apply_om_reflection_with_cas(
         &self,
         scope_key: &str,
@@ -6,6 +6,7 @@ use crate::om::{
     ContinuationPolicyV2, OmContinuationCandidateV2, OmContinuationStateV2,
     resolve_continuation_update,
 };
+use crate::om_bridge::{OmStateContinuationV1, OmStateEntryV1};
 
 use super::{
     OmActiveEntry, OmContinuationHints, OmContinuationState, OmThreadState, SqliteStateStore,
@@ -355,6 +356,108 @@ impl SqliteStateStore {
             Ok(out)
         })
     }
+
+    /// All `om_entries` rows for a scope, including superseded ones.
+    pub(crate) fn list_om_entries(&self, scope_key: &str) -> Result<Vec<OmStateEntryV1>> {
+        self.with_conn(|conn| {
+            let mut stmt = conn.prepare(
+                r"
+                SELECT entry_id, canonical_thread_id, priority, text, source_message_ids_json,
+                       origin_kind, created_at, superseded_by
+                FROM om_entries
+                WHERE scope_key = ?1
+                ORDER BY created_at ASC, entry_id ASC
+                ",
+            )?;
+            let rows = stmt.query_map(params![scope_key], |row| {
+                let source_message_ids_raw = row.get::<_, String>(4)?;
+                Ok(OmStateEntryV1 {
+                    entry_id: row.get(0)?,
+                    canonical_thread_id: row.get(1)?,
+                    priority: row.get(2)?,
+                    text: row.get(3)?,
+                    source_message_ids: super::parse_string_vec_json(4, &source_message_ids_raw)?,
+                    origin_kind: row.get(5)?,
+                    created_at: row.get(6)?,
+                    superseded_by: row.get(7)?,
+                })
+            })?;
+            let mut out = Vec::<OmStateEntryV1>::new();
+            for row in rows {
+                out.push(row?);
+            }
+            Ok(out)
+        })
+    }
+
+    pub(crate) fn upsert_om_entry(&self, scope_key: &str, entry: &OmStateEntryV1) -> Result<()> {
+        let source_message_ids_json = serde_json::to_string(&entry.source_message_ids)?;
+        self.with_conn(|conn| {
+            conn.execute(
+                r"
+                INSERT INTO om_entries(
+                    entry_id, scope_key, canonical_thread_id, priority, text,
+                    source_message_ids_json, origin_kind, created_at, superseded_by
+                )
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                ON CONFLICT(entry_id) DO UPDATE SET
+                    scope_key = excluded.scope_key,
+                    canonical_thread_id = excluded.canonical_thread_id,
+                    priority = excluded.priority,
+                    text = excluded.text,
+                    source_message_ids_json = excluded.source_message_ids_json,
+                    origin_kind = excluded.origin_kind,
+                    created_at = excluded.created_at,
+                    superseded_by = excluded.superseded_by
+                ",
+                params![
+                    entry.entry_id,
+                    scope_key,
+                    entry.canonical_thread_id,
+                    entry.priority,
+                    entry.text,
+                    source_message_ids_json,
+                    entry.origin_kind,
+                    entry.created_at,
+                    entry.superseded_by,
+                ],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Like `list_om_continuation_states` but with confidence, source and updated_at.
+    pub(crate) fn list_om_continuation_rows(
+        &self,
+        scope_key: &str,
+    ) -> Result<Vec<OmStateContinuationV1>> {
+        self.with_conn(|conn| {
+            let mut stmt = conn.prepare(
+                r"
+                SELECT canonical_thread_id, current_task, suggested_response,
+                       confidence, source_kind, updated_at
+                FROM om_continuation_state
+                WHERE scope_key = ?1
+                ORDER BY canonical_thread_id ASC
+                ",
+            )?;
+            let rows = stmt.query_map(params![scope_key], |row| {
+                Ok(OmStateContinuationV1 {
+                    canonical_thread_id: row.get(0)?,
+                    current_task: row.get(1)?,
+                    suggested_response: row.get(2)?,
+                    confidence: row.get(3)?,
+                    source_kind: row.get(4)?,
+                    updated_at: row.get(5)?,
+                })
+            })?;
+            let mut out = Vec::<OmStateContinuationV1>::new();
+            for row in rows {
+                out.push(row?);
+            }
+            Ok(out)
+        })
+    }
 }
 
 fn normalize_optional_text(raw: Option<&str>) -> Option<String> {
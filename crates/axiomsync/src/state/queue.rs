@@ -6,7 +6,7 @@ use std::str::FromStr;
 use crate::error::Result;
 use crate::models::{
     OutboxEvent, QueueCheckpoint, QueueCounts, QueueDeadLetterRate, QueueEventStatus,
-    QueueLaneStatus, QueueStatus, ReconcileRunStatus,
+    QueueLaneStatus, QueuePriority, QueuePriorityLaneCounts, QueueStatus, ReconcileRunStatus,
 };
 
 use super::SqliteStateStore;
@@ -19,7 +19,25 @@ impl SqliteStateStore {
         uri: &str,
         payload_json: impl serde::Serialize,
     ) -> Result<i64> {
-        self.enqueue_with_status(event_type, uri, payload_json, QueueEventStatus::New, 0)
+        self.enqueue_with_priority(event_type, uri, payload_json, QueuePriority::Normal)
+    }
+
+    /// Higher `priority` events replay before earlier lower-priority ones.
+    pub fn enqueue_with_priority(
+        &self,
+        event_type: &str,
+        uri: &str,
+        payload_json: impl serde::Serialize,
+        priority: QueuePriority,
+    ) -> Result<i64> {
+        self.enqueue_with_status(
+            event_type,
+            uri,
+            payload_json,
+            QueueEventStatus::New,
+            0,
+            priority,
+        )
     }
 
     pub fn enqueue_dead_letter(
@@ -34,6 +52,7 @@ impl SqliteStateStore {
             payload_json,
             QueueEventStatus::DeadLetter,
             1,
+            QueuePriority::Normal,
         )
     }
 
@@ -44,6 +63,7 @@ impl SqliteStateStore {
         payload_json: impl serde::Serialize,
         status: QueueEventStatus,
         attempt_count: u32,
+        priority: QueuePriority,
     ) -> Result<i64> {
         let now = Utc::now().to_rfc3339();
         let lane = lane_for_event_type(event_type);
@@ -52,8 +72,8 @@ impl SqliteStateStore {
         self.with_conn(|conn| {
             conn.execute(
                 r"
-                INSERT INTO outbox(event_type, uri, payload_json, created_at, status, attempt_count, next_attempt_at, lane)
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?4, ?7)
+                INSERT INTO outbox(event_type, uri, payload_json, created_at, status, attempt_count, next_attempt_at, lane, priority)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?4, ?7, ?8)
                 ",
                 params![
                     event_type,
@@ -62,7 +82,8 @@ impl SqliteStateStore {
                     now,
                     status.as_str(),
                     i64::from(attempt_count),
-                    lane
+                    lane,
+                    priority.as_i64()
                 ],
             )?;
             Ok(conn.last_insert_rowid())
@@ -80,11 +101,11 @@ impl SqliteStateStore {
         self.with_conn(|conn| {
             let mut stmt = conn.prepare(
                 r"
-                SELECT id, event_type, uri, payload_json, status, attempt_count, next_attempt_at
+                SELECT id, event_type, uri, payload_json, status, attempt_count, next_attempt_at, priority
                 FROM outbox
                 WHERE status = ?1
                   AND (?4 = 1 OR next_attempt_at <= ?3)
-                ORDER BY id ASC
+                ORDER BY priority DESC, id ASC
                 LIMIT ?2
                 ",
             )?;
@@ -106,7 +127,7 @@ impl SqliteStateStore {
         self.with_conn(|conn| {
             let mut stmt = conn.prepare(
                 r"
-                SELECT id, event_type, uri, payload_json, status, attempt_count, next_attempt_at
+                SELECT id, event_type, uri, payload_json, status, attempt_count, next_attempt_at, priority
                 FROM outbox
                 WHERE id = ?1
                 ",
@@ -119,6 +140,58 @@ impl SqliteStateStore {
         })
     }
 
+    /// Resets retries and makes the event ready now; processing events are untouched.
+    pub fn reset_outbox_event(&self, id: i64) -> Result<bool> {
+        let now = Utc::now().to_rfc3339();
+        self.with_conn(|conn| {
+            let affected = conn.execute(
+                r"
+                UPDATE outbox
+                SET status = ?1, attempt_count = 0, next_attempt_at = ?2
+                WHERE id = ?3 AND status != ?4
+                ",
+                params![
+                    QueueEventStatus::New.as_str(),
+                    now,
+                    id,
+                    QueueEventStatus::Processing.as_str()
+                ],
+            )?;
+            Ok(affected == 1)
+        })
+    }
+
+    /// Resets a dead-letter event to new in a single guarded UPDATE.
+    pub fn requeue_dead_letter_event(&self, id: i64) -> Result<bool> {
+        let now = Utc::now().to_rfc3339();
+        self.with_conn(|conn| {
+            let affected = conn.execute(
+                r"
+                UPDATE outbox
+                SET status = ?1, attempt_count = 0, next_attempt_at = ?2
+                WHERE id = ?3 AND status = ?4
+                ",
+                params![
+                    QueueEventStatus::New.as_str(),
+                    now,
+                    id,
+                    QueueEventStatus::DeadLetter.as_str()
+                ],
+            )?;
+            Ok(affected == 1)
+        })
+    }
+
+    pub fn delete_outbox_event(&self, id: i64) -> Result<bool> {
+        self.with_conn(|conn| {
+            let affected = conn.execute(
+                "DELETE FROM outbox WHERE id = ?1 AND status != ?2",
+                params![id, QueueEventStatus::Processing.as_str()],
+            )?;
+            Ok(affected == 1)
+        })
+    }
+
     #[cfg(test)]
     pub(crate) fn update_outbox_payload_json(
         &self,
@@ -169,6 +242,28 @@ impl SqliteStateStore {
         })
     }
 
+    // Claims act as leases: the start time goes into `next_attempt_at` and expired
+    // leases are reset by `recover_timed_out_processing_events`.
+    pub fn claim_outbox_event(&self, id: i64, from_status: QueueEventStatus) -> Result<bool> {
+        let now = Utc::now().to_rfc3339();
+        self.with_conn(|conn| {
+            let affected = conn.execute(
+                r"
+                UPDATE outbox
+                SET status = ?1, attempt_count = attempt_count + 1, next_attempt_at = ?2
+                WHERE id = ?3 AND status = ?4
+                ",
+                params![
+                    QueueEventStatus::Processing.as_str(),
+                    now,
+                    id,
+                    from_status.as_str()
+                ],
+            )?;
+            Ok(affected == 1)
+        })
+    }
+
     pub fn recover_timed_out_processing_events(&self, timeout_seconds: i64) -> Result<u64> {
         let stale_before = (Utc::now() - Duration::seconds(timeout_seconds.max(0))).to_rfc3339();
         self.with_conn(|conn| {
@@ -397,6 +492,56 @@ impl SqliteStateStore {
         Ok(counts)
     }
 
+    /// Counts per non-empty priority lane, highest first.
+    pub fn queue_priority_lane_counts(&self) -> Result<Vec<QueuePriorityLaneCounts>> {
+        let now = Utc::now().to_rfc3339();
+        self.with_conn(|conn| {
+            let mut stmt = conn.prepare(
+                r"
+                SELECT
+                    CASE WHEN priority < 0 THEN -1 WHEN priority > 0 THEN 1 ELSE 0 END AS lane_priority,
+                    SUM(CASE WHEN status = ?1 THEN 1 ELSE 0 END) AS new_total,
+                    SUM(CASE WHEN status = ?1 AND next_attempt_at <= ?2 THEN 1 ELSE 0 END) AS new_due,
+                    SUM(CASE WHEN status = ?3 THEN 1 ELSE 0 END) AS processing,
+                    SUM(CASE WHEN status = ?4 THEN 1 ELSE 0 END) AS dead_letter
+                FROM outbox
+                GROUP BY lane_priority
+                ORDER BY lane_priority DESC
+                ",
+            )?;
+            let rows = stmt.query_map(
+                params![
+                    QueueEventStatus::New.as_str(),
+                    now,
+                    QueueEventStatus::Processing.as_str(),
+                    QueueEventStatus::DeadLetter.as_str()
+                ],
+                |row| {
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, i64>(1)?,
+                        row.get::<_, i64>(2)?,
+                        row.get::<_, i64>(3)?,
+                        row.get::<_, i64>(4)?,
+                    ))
+                },
+            )?;
+
+            let mut lanes = Vec::new();
+            for row in rows {
+                let (priority, new_total, new_due, processing, dead_letter) = row?;
+                lanes.push(QueuePriorityLaneCounts {
+                    priority: QueuePriority::from_i64(priority),
+                    new_total: i64_to_u64_saturating(new_total),
+                    new_due: i64_to_u64_saturating(new_due),
+                    processing: i64_to_u64_saturating(processing),
+                    dead_letter: i64_to_u64_saturating(dead_letter),
+                });
+            }
+            Ok(lanes)
+        })
+    }
+
     pub fn queue_dead_letter_rates_by_event_type(&self) -> Result<Vec<QueueDeadLetterRate>> {
         let status_dead_letter = QueueEventStatus::DeadLetter.as_str();
         self.with_conn(|conn| {
@@ -534,6 +679,7 @@ fn outbox_event_from_row(row: &Row<'_>) -> rusqlite::Result<OutboxEvent> {
         status,
         attempt_count: i64_to_u32_saturating(row.get::<_, i64>(5)?),
         next_attempt_at: row.get(6)?,
+        priority: QueuePriority::from_i64(row.get(7)?),
     })
 }
 
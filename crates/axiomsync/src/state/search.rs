@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use chrono::Utc;
 use rusqlite::{OptionalExtension, params, types::Type};
 
@@ -7,6 +9,38 @@ use crate::models::IndexRecord;
 
 use super::SqliteStateStore;
 
+/// Embedding vector persisted for a search document, keyed by the hash of its embedded text.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PersistedSearchVector {
+    pub(crate) text_hash: String,
+    pub(crate) vector: Vec<f32>,
+}
+
+/// Per-document tag additions and removals applied over inferred tags on reindex.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct DocumentTagOverrides {
+    pub(crate) added: Vec<String>,
+    pub(crate) removed: Vec<String>,
+}
+
+/// Ranked snapshot behind a search cursor; `expires_at` is unix seconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SearchCursorRecord {
+    pub(crate) snapshot_id: String,
+    pub(crate) fingerprint: String,
+    pub(crate) snapshot_json: String,
+    pub(crate) expires_at: i64,
+}
+
+impl DocumentTagOverrides {
+    pub(crate) fn apply(&self, tags: &mut Vec<String>) {
+        tags.extend(self.added.iter().cloned());
+        tags.retain(|tag| !self.removed.contains(tag));
+        tags.sort();
+        tags.dedup();
+    }
+}
+
 impl SqliteStateStore {
     pub fn search_documents_fts(&self, query: &str, limit: usize) -> Result<Vec<String>> {
         let query = query.trim();
@@ -182,6 +216,10 @@ impl SqliteStateStore {
                 )?;
                 tx.execute("DELETE FROM search_docs WHERE id = ?1", params![doc_id])?;
             }
+            tx.execute(
+                "DELETE FROM search_doc_vectors WHERE uri = ?1",
+                params![uri],
+            )?;
 
             Ok(())
         })
@@ -211,10 +249,412 @@ impl SqliteStateStore {
                 )?;
                 tx.execute("DELETE FROM search_docs WHERE id = ?1", params![doc_id])?;
             }
+            tx.execute(
+                "DELETE FROM search_doc_vectors WHERE uri = ?1 OR uri LIKE ?2",
+                params![uri_prefix, format!("{uri_prefix}/%")],
+            )?;
+
+            Ok(())
+        })
+    }
+
+    pub(crate) fn get_search_vector(
+        &self,
+        uri: &str,
+        vector_version: &str,
+    ) -> Result<Option<PersistedSearchVector>> {
+        self.with_conn(|conn| {
+            let row = conn
+                .query_row(
+                    r"
+                    SELECT text_hash, vector
+                    FROM search_doc_vectors
+                    WHERE uri = ?1 AND vector_version = ?2
+                    ",
+                    params![uri, vector_version],
+                    |row| Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?)),
+                )
+                .optional()?;
+            Ok(row.map(|(text_hash, blob)| PersistedSearchVector {
+                text_hash,
+                vector: decode_vector_blob(&blob),
+            }))
+        })
+    }
+
+    pub(crate) fn list_search_vectors(
+        &self,
+        vector_version: &str,
+    ) -> Result<HashMap<String, PersistedSearchVector>> {
+        self.with_conn(|conn| {
+            let mut stmt = conn.prepare(
+                r"
+                SELECT uri, text_hash, vector
+                FROM search_doc_vectors
+                WHERE vector_version = ?1
+                ",
+            )?;
+            let rows = stmt.query_map(params![vector_version], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Vec<u8>>(2)?,
+                ))
+            })?;
+
+            let mut out = HashMap::new();
+            for row in rows {
+                let (uri, text_hash, blob) = row?;
+                out.insert(
+                    uri,
+                    PersistedSearchVector {
+                        text_hash,
+                        vector: decode_vector_blob(&blob),
+                    },
+                );
+            }
+            Ok(out)
+        })
+    }
+
+    pub(crate) fn upsert_search_vector(
+        &self,
+        uri: &str,
+        vector_version: &str,
+        text_hash: &str,
+        vector: &[f32],
+    ) -> Result<()> {
+        self.with_conn(|conn| {
+            conn.execute(
+                r"
+                INSERT INTO search_doc_vectors(uri, vector_version, text_hash, vector, updated_at)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT(uri) DO UPDATE SET
+                  vector_version=excluded.vector_version,
+                  text_hash=excluded.text_hash,
+                  vector=excluded.vector,
+                  updated_at=excluded.updated_at
+                ",
+                params![
+                    uri,
+                    vector_version,
+                    text_hash,
+                    encode_vector_blob(vector),
+                    Utc::now().to_rfc3339(),
+                ],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Finds a stored vector of any document whose embedded text hashes to `text_hash`.
+    pub(crate) fn get_cached_embedding(
+        &self,
+        text_hash: &str,
+        vector_version: &str,
+    ) -> Result<Option<Vec<f32>>> {
+        self.with_conn(|conn| {
+            let blob = conn
+                .query_row(
+                    r"
+                    SELECT vector
+                    FROM search_doc_vectors
+                    WHERE text_hash = ?1 AND vector_version = ?2
+                    LIMIT 1
+                    ",
+                    params![text_hash, vector_version],
+                    |row| row.get::<_, Vec<u8>>(0),
+                )
+                .optional()?;
+            Ok(blob.map(|blob| decode_vector_blob(&blob)))
+        })
+    }
+
+    /// Drops vectors written under any vector version other than `vector_version`.
+    pub(crate) fn prune_search_vectors(&self, vector_version: &str) -> Result<usize> {
+        self.with_conn(|conn| {
+            let removed = conn.execute(
+                "DELETE FROM search_doc_vectors WHERE vector_version != ?1",
+                params![vector_version],
+            )?;
+            Ok(removed)
+        })
+    }
+
+    pub(crate) fn clear_search_vectors(&self) -> Result<usize> {
+        self.with_conn(|conn| {
+            let removed = conn.execute("DELETE FROM search_doc_vectors", [])?;
+            Ok(removed)
+        })
+    }
+
+    pub(crate) fn search_vector_count(&self, vector_version: &str) -> Result<usize> {
+        self.with_conn(|conn| {
+            let count = conn.query_row(
+                "SELECT COUNT(*) FROM search_doc_vectors WHERE vector_version = ?1",
+                params![vector_version],
+                |row| row.get::<_, i64>(0),
+            )?;
+            Ok(usize::try_from(count).unwrap_or(0))
+        })
+    }
+
+    pub(crate) fn upsert_search_profile(&self, name: &str, profile_json: &str) -> Result<()> {
+        self.with_conn(|conn| {
+            conn.execute(
+                r"
+                INSERT INTO search_profiles(name, profile_json, updated_at)
+                VALUES (?1, ?2, ?3)
+                ON CONFLICT(name) DO UPDATE SET
+                  profile_json=excluded.profile_json,
+                  updated_at=excluded.updated_at
+                ",
+                params![name, profile_json, Utc::now().to_rfc3339()],
+            )?;
+            Ok(())
+        })
+    }
+
+    pub(crate) fn get_search_profile(&self, name: &str) -> Result<Option<String>> {
+        self.with_conn(|conn| {
+            let profile_json = conn
+                .query_row(
+                    "SELECT profile_json FROM search_profiles WHERE name = ?1",
+                    params![name],
+                    |row| row.get::<_, String>(0),
+                )
+                .optional()?;
+            Ok(profile_json)
+        })
+    }
+
+    pub(crate) fn list_search_profiles(&self) -> Result<Vec<String>> {
+        self.with_conn(|conn| {
+            let mut stmt =
+                conn.prepare("SELECT profile_json FROM search_profiles ORDER BY name ASC")?;
+            let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+            let mut out = Vec::new();
+            for row in rows {
+                out.push(row?);
+            }
+            Ok(out)
+        })
+    }
+
+    pub(crate) fn remove_search_profile(&self, name: &str) -> Result<bool> {
+        self.with_conn(|conn| {
+            let removed =
+                conn.execute("DELETE FROM search_profiles WHERE name = ?1", params![name])?;
+            Ok(removed > 0)
+        })
+    }
 
+    /// Saves a cursor snapshot and prunes expired ones.
+    pub(crate) fn upsert_search_cursor(&self, record: &SearchCursorRecord, now: i64) -> Result<()> {
+        self.with_tx(|tx| {
+            tx.execute(
+                "DELETE FROM search_cursors WHERE expires_at <= ?1",
+                params![now],
+            )?;
+            tx.execute(
+                r"
+                INSERT INTO search_cursors(snapshot_id, fingerprint, snapshot_json, expires_at, updated_at)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT(snapshot_id) DO UPDATE SET
+                  fingerprint=excluded.fingerprint,
+                  snapshot_json=excluded.snapshot_json,
+                  expires_at=excluded.expires_at,
+                  updated_at=excluded.updated_at
+                ",
+                params![
+                    record.snapshot_id,
+                    record.fingerprint,
+                    record.snapshot_json,
+                    record.expires_at,
+                    Utc::now().to_rfc3339()
+                ],
+            )?;
             Ok(())
         })
     }
+
+    pub(crate) fn get_search_cursor(
+        &self,
+        snapshot_id: &str,
+    ) -> Result<Option<SearchCursorRecord>> {
+        self.with_conn(|conn| {
+            let record = conn
+                .query_row(
+                    r"
+                    SELECT snapshot_id, fingerprint, snapshot_json, expires_at
+                    FROM search_cursors
+                    WHERE snapshot_id = ?1
+                    ",
+                    params![snapshot_id],
+                    |row| {
+                        Ok(SearchCursorRecord {
+                            snapshot_id: row.get(0)?,
+                            fingerprint: row.get(1)?,
+                            snapshot_json: row.get(2)?,
+                            expires_at: row.get(3)?,
+                        })
+                    },
+                )
+                .optional()?;
+            Ok(record)
+        })
+    }
+
+    /// Records explicit `add` tags per resource root, replacing earlier ones.
+    pub(crate) fn upsert_resource_tags(&self, root_uri: &str, tags: &[String]) -> Result<()> {
+        let tags_json = serde_json::to_string(tags)?;
+        self.with_conn(|conn| {
+            conn.execute(
+                r"
+                INSERT INTO resource_tags(root_uri, tags_json, updated_at)
+                VALUES (?1, ?2, ?3)
+                ON CONFLICT(root_uri) DO UPDATE SET
+                  tags_json=excluded.tags_json,
+                  updated_at=excluded.updated_at
+                ",
+                params![root_uri, tags_json, Utc::now().to_rfc3339()],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Explicit tags recorded for `uri` or any ancestor root.
+    pub(crate) fn resource_tags_for_uri(&self, uri: &str) -> Result<Vec<String>> {
+        let rows = self.with_conn(|conn| {
+            let mut stmt = conn.prepare("SELECT root_uri, tags_json FROM resource_tags")?;
+            let rows = stmt.query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?;
+            let mut out = Vec::new();
+            for row in rows {
+                out.push(row?);
+            }
+            Ok(out)
+        })?;
+        let mut tags = Vec::new();
+        for (root_uri, tags_json) in rows {
+            let covers = uri == root_uri
+                || uri
+                    .strip_prefix(root_uri.as_str())
+                    .is_some_and(|rest| rest.starts_with('/'));
+            if covers {
+                tags.extend(serde_json::from_str::<Vec<String>>(&tags_json)?);
+            }
+        }
+        tags.sort();
+        tags.dedup();
+        Ok(tags)
+    }
+
+    pub(crate) fn document_tag_overrides(&self, uri: &str) -> Result<DocumentTagOverrides> {
+        let row = self.with_conn(|conn| {
+            let row = conn
+                .query_row(
+                    "SELECT added_json, removed_json FROM document_tags WHERE uri = ?1",
+                    params![uri],
+                    |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+                )
+                .optional()?;
+            Ok(row)
+        })?;
+        let Some((added_json, removed_json)) = row else {
+            return Ok(DocumentTagOverrides::default());
+        };
+        Ok(DocumentTagOverrides {
+            added: serde_json::from_str(&added_json)?,
+            removed: serde_json::from_str(&removed_json)?,
+        })
+    }
+
+    /// Replaces a document's tag overrides; deletes the row when both are empty.
+    pub(crate) fn upsert_document_tag_overrides(
+        &self,
+        uri: &str,
+        overrides: &DocumentTagOverrides,
+    ) -> Result<()> {
+        if overrides.added.is_empty() && overrides.removed.is_empty() {
+            return self.with_conn(|conn| {
+                conn.execute("DELETE FROM document_tags WHERE uri = ?1", params![uri])?;
+                Ok(())
+            });
+        }
+        let added_json = serde_json::to_string(&overrides.added)?;
+        let removed_json = serde_json::to_string(&overrides.removed)?;
+        self.with_conn(|conn| {
+            conn.execute(
+                r"
+                INSERT INTO document_tags(uri, added_json, removed_json, updated_at)
+                VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT(uri) DO UPDATE SET
+                  added_json=excluded.added_json,
+                  removed_json=excluded.removed_json,
+                  updated_at=excluded.updated_at
+                ",
+                params![uri, added_json, removed_json, Utc::now().to_rfc3339()],
+            )?;
+            Ok(())
+        })
+    }
+
+    pub(crate) fn remove_document_tags_with_prefix(&self, uri_prefix: &str) -> Result<()> {
+        self.with_conn(|conn| {
+            conn.execute(
+                "DELETE FROM document_tags WHERE uri = ?1 OR uri LIKE ?2",
+                params![uri_prefix, format!("{uri_prefix}/%")],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Re-keys resource and document tag rows under `from_prefix` to `to_prefix`.
+    pub(crate) fn move_tags_with_prefix(&self, from_prefix: &str, to_prefix: &str) -> Result<()> {
+        self.with_tx(|tx| {
+            for (table, column) in [("resource_tags", "root_uri"), ("document_tags", "uri")] {
+                tx.execute(
+                    &format!(
+                        "UPDATE OR REPLACE {table} SET {column} = ?1 || substr({column}, ?2) \
+                         WHERE {column} = ?3 OR {column} LIKE ?4"
+                    ),
+                    params![
+                        to_prefix,
+                        // `substr` counts characters, not bytes.
+                        i64::try_from(from_prefix.chars().count() + 1).unwrap_or(i64::MAX),
+                        from_prefix,
+                        format!("{from_prefix}/%")
+                    ],
+                )?;
+            }
+            Ok(())
+        })
+    }
+
+    pub(crate) fn remove_resource_tags_with_prefix(&self, uri_prefix: &str) -> Result<()> {
+        self.with_conn(|conn| {
+            conn.execute(
+                "DELETE FROM resource_tags WHERE root_uri = ?1 OR root_uri LIKE ?2",
+                params![uri_prefix, format!("{uri_prefix}/%")],
+            )?;
+            Ok(())
+        })
+    }
+}
+
+fn encode_vector_blob(vector: &[f32]) -> Vec<u8> {
+    vector
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect()
+}
+
+fn decode_vector_blob(blob: &[u8]) -> Vec<f32> {
+    blob.chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
 }
 
 fn parse_required_rfc3339(idx: usize, raw: &str) -> rusqlite::Result<chrono::DateTime<Utc>> {
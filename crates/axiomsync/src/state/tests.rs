@@ -2,7 +2,9 @@ use chrono::{Duration, Utc};
 use rusqlite::Connection;
 use tempfile::tempdir;
 
-use crate::models::{IndexRecord, OmReflectionApplyMetrics, QueueEventStatus, ReconcileRunStatus};
+use crate::models::{
+    IndexRecord, OmReflectionApplyMetrics, QueueEventStatus, QueuePriority, ReconcileRunStatus,
+};
 use crate::om::{OM_PROTOCOL_VERSION, OmObservationChunk, OmOriginType, OmRecord, OmScope};
 
 use super::*;
@@ -67,6 +69,123 @@ fn open_hardens_state_db_permissions() {
     }
 }
 
+#[cfg(feature = "sqlcipher")]
+#[test]
+fn open_with_key_encrypts_file_and_rejects_wrong_key() {
+    let temp = tempdir().expect("tempdir");
+    let path = temp.path().join("state.db");
+    let store = SqliteStateStore::open_with_key(&path, "correct-key").expect("open keyed");
+    store
+        .set_system_value("cipher_probe", "secret")
+        .expect("set value");
+    drop(store);
+
+    let header = std::fs::read(&path).expect("read db file");
+    assert!(!header.starts_with(b"SQLite format 3\0"));
+
+    let reopened = SqliteStateStore::open_with_key(&path, "correct-key").expect("reopen keyed");
+    assert_eq!(
+        reopened
+            .get_system_value("cipher_probe")
+            .expect("get value")
+            .as_deref(),
+        Some("secret")
+    );
+    drop(reopened);
+
+    let err = SqliteStateStore::open_with_key(&path, "wrong-key").expect_err("wrong key");
+    assert!(matches!(err, AxiomError::PermissionDenied(_)));
+}
+
+#[test]
+fn backup_to_writes_consistent_copy_that_reopens() {
+    let temp = tempdir().expect("tempdir");
+    let store = SqliteStateStore::open(temp.path().join("state.db")).expect("open failed");
+    store
+        .set_system_value("backup_probe", "before")
+        .expect("set value");
+    let event_id = store
+        .enqueue(
+            "upsert",
+            "axiom://resources/demo",
+            serde_json::json!({"x": 1}),
+        )
+        .expect("enqueue");
+
+    let backup_path = temp.path().join("backups").join("state.db");
+    store.backup_to(&backup_path).expect("backup");
+    store
+        .set_system_value("backup_probe", "after")
+        .expect("update value");
+
+    let restored = SqliteStateStore::open(&backup_path).expect("open backup");
+    assert_eq!(
+        restored
+            .get_system_value("backup_probe")
+            .expect("get value")
+            .as_deref(),
+        Some("before")
+    );
+    let events = restored
+        .fetch_outbox(QueueEventStatus::New, 10)
+        .expect("fetch outbox");
+    assert_eq!(
+        events.iter().map(|event| event.id).collect::<Vec<_>>(),
+        vec![event_id]
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&backup_path)
+            .expect("metadata")
+            .permissions()
+            .mode()
+            & 0o777;
+        assert_eq!(mode, 0o600);
+    }
+}
+
+#[test]
+fn compact_shrinks_file_after_churn_and_keeps_data_readable() {
+    let temp = tempdir().expect("tempdir");
+    let store = SqliteStateStore::open(temp.path().join("state.db")).expect("open failed");
+    store.set_system_value("kept", "value").expect("set kept");
+    let padding = "x".repeat(4_096);
+    store
+        .with_tx(|tx| {
+            for i in 0..500 {
+                tx.execute(
+                    "INSERT INTO system_kv(key, value, updated_at) VALUES (?1, ?2, ?3)",
+                    params![format!("churn:{i}"), padding, Utc::now().to_rfc3339()],
+                )?;
+            }
+            Ok(())
+        })
+        .expect("insert churn");
+    store
+        .with_conn(|conn| {
+            conn.execute("DELETE FROM system_kv WHERE key LIKE 'churn:%'", [])?;
+            Ok(())
+        })
+        .expect("delete churn");
+
+    let report = store.compact().expect("compact");
+    assert!(report.before_bytes > report.after_bytes, "{report:?}");
+    assert_eq!(
+        report.reclaimed_bytes,
+        report.before_bytes - report.after_bytes
+    );
+    assert_eq!(
+        store.get_system_value("kept").expect("get kept").as_deref(),
+        Some("value")
+    );
+    assert_eq!(store.get_system_value("churn:0").expect("get churn"), None);
+    store
+        .set_system_value("after", "write")
+        .expect("write after compact");
+}
+
 #[test]
 fn open_sets_busy_timeout_and_hot_path_indexes() {
     let temp = tempdir().expect("tempdir");
@@ -105,18 +224,18 @@ fn open_sets_busy_timeout_and_hot_path_indexes() {
         &conn,
         r"
         EXPLAIN QUERY PLAN
-        SELECT id, event_type, uri, payload_json, status, attempt_count, next_attempt_at
+        SELECT id, event_type, uri, payload_json, status, attempt_count, next_attempt_at, priority
         FROM outbox
         WHERE status = 'new'
           AND next_attempt_at <= '9999-12-31T23:59:59Z'
-        ORDER BY id ASC
+        ORDER BY priority DESC, id ASC
         LIMIT 10
         ",
     );
     assert!(
         outbox_plan
             .iter()
-            .any(|detail| detail.contains("idx_outbox_status_next_attempt_id")),
+            .any(|detail| detail.contains("idx_outbox_status_")),
         "outbox fetch should use composite hot-path index: {outbox_plan:?}"
     );
 
@@ -252,6 +371,114 @@ fn queue_checkpoint_roundtrip() {
     assert_eq!(store.get_checkpoint("replay").expect("get2"), Some(42));
 }
 
+#[test]
+fn fetch_outbox_drains_higher_priority_first_then_fifo() {
+    let temp = tempdir().expect("tempdir");
+    let store = SqliteStateStore::open(temp.path().join("state.db")).expect("open failed");
+
+    let low_a = store
+        .enqueue_with_priority(
+            "reindex",
+            "axiom://resources/a",
+            serde_json::json!({}),
+            QueuePriority::Low,
+        )
+        .expect("low a");
+    let normal = store
+        .enqueue("reindex", "axiom://resources/b", serde_json::json!({}))
+        .expect("normal");
+    let low_b = store
+        .enqueue_with_priority(
+            "reindex",
+            "axiom://resources/c",
+            serde_json::json!({}),
+            QueuePriority::Low,
+        )
+        .expect("low b");
+    let high = store
+        .enqueue_with_priority(
+            "semantic_scan",
+            "axiom://resources/d",
+            serde_json::json!({}),
+            QueuePriority::High,
+        )
+        .expect("high");
+
+    let fetched = store
+        .fetch_outbox(QueueEventStatus::New, 10)
+        .expect("fetch");
+    assert_eq!(
+        fetched.iter().map(|event| event.id).collect::<Vec<_>>(),
+        vec![high, normal, low_a, low_b]
+    );
+    assert_eq!(fetched[0].priority, QueuePriority::High);
+    assert_eq!(
+        store
+            .get_outbox_event(normal)
+            .expect("get")
+            .expect("event")
+            .priority,
+        QueuePriority::Normal
+    );
+
+    let lanes = store.queue_priority_lane_counts().expect("lane counts");
+    assert_eq!(
+        lanes
+            .iter()
+            .map(|lane| (lane.priority, lane.new_total))
+            .collect::<Vec<_>>(),
+        vec![
+            (QueuePriority::High, 1),
+            (QueuePriority::Normal, 1),
+            (QueuePriority::Low, 2),
+        ]
+    );
+}
+
+#[test]
+fn migrate_adds_priority_column_to_existing_outbox_with_default_priority() {
+    let temp = tempdir().expect("tempdir");
+    let db_path = temp.path().join("state.db");
+    {
+        let conn = Connection::open(&db_path).expect("open raw");
+        conn.execute_batch(
+            r"
+            CREATE TABLE outbox (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                event_type TEXT NOT NULL,
+                uri TEXT NOT NULL,
+                payload_json TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                attempt_count INTEGER NOT NULL DEFAULT 0,
+                status TEXT NOT NULL,
+                next_attempt_at TEXT NOT NULL,
+                lane TEXT NOT NULL
+            );
+            INSERT INTO outbox(event_type, uri, payload_json, created_at, status, next_attempt_at, lane)
+            VALUES ('reindex', 'axiom://resources/legacy', '{}', '2024-01-01T00:00:00Z', 'new', '2024-01-01T00:00:00Z', 'semantic');
+            ",
+        )
+        .expect("legacy schema");
+    }
+
+    let store = SqliteStateStore::open(&db_path).expect("open migrates");
+    let urgent = store
+        .enqueue_with_priority(
+            "semantic_scan",
+            "axiom://resources/urgent",
+            serde_json::json!({}),
+            QueuePriority::High,
+        )
+        .expect("enqueue");
+    let fetched = store
+        .fetch_outbox(QueueEventStatus::New, 10)
+        .expect("fetch");
+    assert_eq!(fetched.len(), 2);
+    assert_eq!(fetched[0].id, urgent);
+    assert_eq!(fetched[1].uri, "axiom://resources/legacy");
+    assert_eq!(fetched[1].priority, QueuePriority::Normal);
+}
+
 #[test]
 fn system_value_roundtrip() {
     let temp = tempdir().expect("tempdir");
@@ -1624,6 +1851,40 @@ fn recover_timed_out_processing_events_requeues_stale_events() {
     assert_eq!(visible[0].id, id);
 }
 
+#[test]
+fn claim_outbox_event_grants_single_lease_per_event() {
+    let temp = tempdir().expect("tempdir");
+    let store = SqliteStateStore::open(temp.path().join("state.db")).expect("open failed");
+
+    let id = store
+        .enqueue("delete", "axiom://resources/claim", serde_json::json!({}))
+        .expect("enqueue");
+
+    assert!(
+        store
+            .claim_outbox_event(id, QueueEventStatus::New)
+            .expect("first claim")
+    );
+    assert!(
+        !store
+            .claim_outbox_event(id, QueueEventStatus::New)
+            .expect("second claim")
+    );
+
+    let event = store
+        .get_outbox_event(id)
+        .expect("lookup")
+        .expect("event missing");
+    assert_eq!(event.status, QueueEventStatus::Processing);
+    assert_eq!(event.attempt_count, 1);
+    assert!(
+        store
+            .fetch_outbox(QueueEventStatus::New, 10)
+            .expect("fetch new")
+            .is_empty()
+    );
+}
+
 #[test]
 fn open_rejects_outbox_without_next_attempt_at() {
     let temp = tempdir().expect("tempdir");
@@ -2539,6 +2800,52 @@ fn remove_search_documents_with_prefix_prunes_descendants() {
     assert_eq!(remaining[0].uri, "axiom://resources/other/c.md");
 }
 
+#[test]
+fn search_vectors_round_trip_by_version_and_prune_with_documents() {
+    let temp = tempdir().expect("tempdir");
+    let db_path = temp.path().join("state.db");
+    let store = SqliteStateStore::open(db_path).expect("open failed");
+
+    let uri = "axiom://resources/docs/a.md";
+    store
+        .upsert_search_vector(uri, "hash@v1:3", "h1", &[0.25, -1.0, 3.5])
+        .expect("upsert vector");
+    store
+        .upsert_search_vector(
+            "axiom://resources/other/c.md",
+            "hash@v1:3",
+            "h2",
+            &[1.0, 0.0, 0.0],
+        )
+        .expect("upsert other vector");
+
+    let stored = store
+        .get_search_vector(uri, "hash@v1:3")
+        .expect("get vector")
+        .expect("vector present");
+    assert_eq!(stored.text_hash, "h1");
+    assert_eq!(stored.vector, vec![0.25, -1.0, 3.5]);
+    assert!(
+        store
+            .get_search_vector(uri, "hash@v2:3")
+            .expect("get other version")
+            .is_none()
+    );
+    assert_eq!(
+        store.list_search_vectors("hash@v1:3").expect("list").len(),
+        2
+    );
+
+    store
+        .remove_search_documents_with_prefix("axiom://resources/docs")
+        .expect("remove prefix");
+    let remaining = store
+        .list_search_vectors("hash@v1:3")
+        .expect("list remaining");
+    assert_eq!(remaining.len(), 1);
+    assert!(remaining.contains_key("axiom://resources/other/c.md"));
+}
+
 fn explain_query_plan(conn: &Connection, sql: &str) -> Vec<String> {
     let mut stmt = conn.prepare(sql).expect("prepare explain");
     let rows = stmt
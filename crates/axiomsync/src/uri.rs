@@ -14,6 +14,7 @@ pub enum Scope {
     Session,
     Temp,
     Queue,
+    Trash,
 }
 
 impl Scope {
@@ -26,12 +27,13 @@ impl Scope {
             Self::Session => "session",
             Self::Temp => "temp",
             Self::Queue => "queue",
+            Self::Trash => "trash",
         }
     }
 
     #[must_use]
     pub const fn is_internal(&self) -> bool {
-        matches!(self, Self::Temp | Self::Queue)
+        matches!(self, Self::Temp | Self::Queue | Self::Trash)
     }
 }
 
@@ -52,6 +54,7 @@ impl FromStr for Scope {
             "session" => Ok(Self::Session),
             "temp" => Ok(Self::Temp),
             "queue" => Ok(Self::Queue),
+            "trash" => Ok(Self::Trash),
             _ => Err(AxiomError::InvalidScope(s.to_string())),
         }
     }
@@ -0,0 +1,48 @@
+// The active embedder is process-wide, so provider switching is exercised in its own test
+// binary instead of alongside the parallel lib tests.
+
+use axiomsync::AxiomSync;
+use axiomsync::embedding::EmbedderRuntimeConfig;
+use axiomsync::error::AxiomError;
+use tempfile::tempdir;
+
+#[test]
+fn set_embedding_provider_switches_backend_status_without_reopening() {
+    let temp = tempdir().expect("tempdir");
+    let app = AxiomSync::new(temp.path()).expect("app");
+    app.initialize().expect("init");
+    let before = app.backend_status().expect("status").embedding;
+
+    app.set_embedding_provider(EmbedderRuntimeConfig {
+        kind: Some("hash".to_string()),
+        ..EmbedderRuntimeConfig::default()
+    })
+    .expect("switch to hash");
+    let switched = app.backend_status().expect("status").embedding;
+    assert_eq!(switched.provider, "hash");
+    assert_eq!(switched.vector_version, "hash-v1");
+    assert_eq!(switched.dim, before.dim);
+
+    let err = app
+        .set_embedding_provider(EmbedderRuntimeConfig {
+            kind: Some("semantic-model-http".to_string()),
+            model_endpoint: Some("http://example.com/api/embeddings".to_string()),
+            ..EmbedderRuntimeConfig::default()
+        })
+        .expect_err("non-local endpoint must be rejected");
+    assert!(matches!(err, AxiomError::Validation(_)));
+    assert_eq!(
+        app.backend_status().expect("status").embedding.provider,
+        "hash"
+    );
+
+    app.set_embedding_provider(EmbedderRuntimeConfig {
+        kind: Some("semantic-lite".to_string()),
+        ..EmbedderRuntimeConfig::default()
+    })
+    .expect("switch back");
+    assert_eq!(
+        app.backend_status().expect("status").embedding.provider,
+        "semantic-lite"
+    );
+}